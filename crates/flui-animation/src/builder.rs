@@ -1,11 +1,19 @@
 //! Builder pattern for `AnimationController`.
 //!
 //! This module provides a fluent builder API for constructing [`AnimationController`]
-//! instances with various configuration options.
+//! instances with various configuration options, and [`StaggeredAnimationBuilder`]
+//! for carving one parent animation into interval-scoped sub-animations.
 
+use crate::animation::Animation;
 use crate::controller::AnimationController;
+use crate::curve::Interval;
+use crate::curved::CurvedAnimation;
 use crate::error::AnimationError;
+use crate::tween::TweenAnimation;
+use crate::tween_types::Animatable;
 use flui_scheduler::Scheduler;
+use std::fmt;
+use std::ops::Range;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -275,6 +283,134 @@ pub fn builder(duration: Duration, scheduler: Arc<Scheduler>) -> AnimationContro
     }
 }
 
+/// Builder for a staggered sequence of animations driven by one parent.
+///
+/// Each entry pairs a sub-range of the parent's `0.0..=1.0` progress with a
+/// tween. [`build`](Self::build) produces one [`TweenAnimation`] per entry, in
+/// insertion order: before its range the entry reports the tween's begin
+/// value, after it the end value, and inside it the tween is interpolated
+/// linearly over the range (an [`Interval`] curve on the parent). Ranges may
+/// overlap, so e.g. a fade can still be finishing while a slide starts.
+///
+/// Every sub-animation shares the parent's status and re-emits its value
+/// changes, so each can be listened to (or handed to a transition widget)
+/// independently.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), flui_animation::AnimationError> {
+/// use flui_animation::builder::StaggeredAnimationBuilder;
+/// use flui_animation::{Animation, AnimationController, FloatTween};
+/// use flui_scheduler::Scheduler;
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// let scheduler = Arc::new(Scheduler::new());
+/// let controller = Arc::new(AnimationController::new(Duration::from_millis(900), scheduler));
+///
+/// let [fade, slide, scale] = StaggeredAnimationBuilder::new(controller.clone())
+///     .entry(0.0..0.3, FloatTween::new(0.0, 1.0))
+///     .entry(0.3..0.7, FloatTween::new(-20.0, 0.0))
+///     .entry(0.7..1.0, FloatTween::new(0.8, 1.0))
+///     .build()?
+///     .try_into()
+///     .expect("three entries");
+///
+/// controller.set_value(0.5);
+/// assert_eq!(fade.value(), 1.0);
+/// assert_eq!(slide.value(), -10.0);
+/// assert_eq!(scale.value(), 0.8);
+/// # controller.dispose();
+/// # Ok(())
+/// # }
+/// ```
+pub struct StaggeredAnimationBuilder<T, A>
+where
+    T: Clone + Send + Sync + 'static,
+    A: Animatable<T> + Clone + Send + Sync + 'static,
+{
+    parent: Arc<dyn Animation<f32>>,
+    entries: Vec<(Range<f32>, A)>,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<T, A> fmt::Debug for StaggeredAnimationBuilder<T, A>
+where
+    T: Clone + Send + Sync + 'static,
+    A: Animatable<T> + Clone + Send + Sync + fmt::Debug + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StaggeredAnimationBuilder")
+            .field("entries", &self.entries)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, A> StaggeredAnimationBuilder<T, A>
+where
+    T: Clone + Send + Sync + 'static,
+    A: Animatable<T> + Clone + Send + Sync + 'static,
+{
+    /// Create a builder whose entries are all driven by `parent`.
+    #[must_use]
+    pub fn new(parent: Arc<dyn Animation<f32>>) -> Self {
+        Self {
+            parent,
+            entries: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Add a tween that runs while the parent moves through `range`.
+    ///
+    /// The range is validated by [`build`](Self::build), not here.
+    #[must_use]
+    pub fn entry(mut self, range: Range<f32>, tween: A) -> Self {
+        self.entries.push((range, tween));
+        self
+    }
+
+    /// Add several `(range, tween)` entries at once, in iteration order.
+    #[must_use]
+    pub fn entries(mut self, entries: impl IntoIterator<Item = (Range<f32>, A)>) -> Self {
+        self.entries.extend(entries);
+        self
+    }
+
+    /// Build one sub-animation per entry, in insertion order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AnimationError::InvalidInterval`] if any range is not a
+    /// finite, non-reversed interval within `0.0..=1.0`.
+    pub fn build(self) -> Result<Vec<TweenAnimation<T, A>>, AnimationError> {
+        for (index, range) in self.entries.iter().map(|(range, _)| range).enumerate() {
+            let valid = range.start.is_finite()
+                && range.end.is_finite()
+                && (0.0..=1.0).contains(&range.start)
+                && (0.0..=1.0).contains(&range.end)
+                && range.start <= range.end;
+            if !valid {
+                return Err(AnimationError::InvalidInterval(format!(
+                    "entry {index}: {range:?} must be an ordered range within 0.0..=1.0"
+                )));
+            }
+        }
+
+        Ok(self
+            .entries
+            .into_iter()
+            .map(|(range, tween)| {
+                let interval = Interval::linear(range.start, range.end);
+                let curved: Arc<dyn Animation<f32>> =
+                    Arc::new(CurvedAnimation::new(Arc::clone(&self.parent), interval));
+                TweenAnimation::new(tween, curved)
+            })
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -363,4 +499,85 @@ fn test_builder_full_configuration() {
         assert_eq!(controller.value(), 50.0);
         controller.dispose();
     }
+
+    // ---- StaggeredAnimationBuilder ----
+
+    use crate::tween_types::FloatTween;
+
+    fn staggered_parent() -> Arc<AnimationController> {
+        let scheduler = Arc::new(Scheduler::new());
+        Arc::new(AnimationController::new(
+            Duration::from_millis(100),
+            scheduler,
+        ))
+    }
+
+    #[test]
+    fn staggered_entries_report_boundary_values() {
+        let controller = staggered_parent();
+        let animations = StaggeredAnimationBuilder::new(controller.clone())
+            .entry(0.0..0.3, FloatTween::new(0.0, 1.0))
+            .entry(0.3..0.7, FloatTween::new(10.0, 20.0))
+            .entry(0.7..1.0, FloatTween::new(100.0, 200.0))
+            .build()
+            .unwrap();
+        let values = || animations.iter().map(Animation::value).collect::<Vec<_>>();
+
+        controller.set_value(0.0);
+        assert_eq!(values(), [0.0, 10.0, 100.0]);
+
+        controller.set_value(0.3);
+        assert_eq!(values(), [1.0, 10.0, 100.0]);
+
+        controller.set_value(0.5);
+        let mid = values();
+        assert_eq!(mid[0], 1.0);
+        assert!(
+            (mid[1] - 15.0).abs() < 1e-3,
+            "halfway through 0.3..0.7: {mid:?}"
+        );
+        assert_eq!(mid[2], 100.0);
+
+        controller.set_value(0.7);
+        assert_eq!(values(), [1.0, 20.0, 100.0]);
+
+        controller.set_value(1.0);
+        assert_eq!(values(), [1.0, 20.0, 200.0]);
+        controller.dispose();
+    }
+
+    #[test]
+    fn staggered_overlapping_entries_advance_together() {
+        let controller = staggered_parent();
+        let animations = StaggeredAnimationBuilder::new(controller.clone())
+            .entries([
+                (0.0..0.6, FloatTween::new(0.0, 1.0)),
+                (0.4..1.0, FloatTween::new(0.0, 1.0)),
+            ])
+            .build()
+            .unwrap();
+
+        controller.set_value(0.5);
+        let first = animations[0].value();
+        let second = animations[1].value();
+        assert!((first - 0.5 / 0.6).abs() < 1e-4, "first: {first}");
+        assert!((second - 0.1 / 0.6).abs() < 1e-4, "second: {second}");
+        controller.dispose();
+    }
+
+    #[test]
+    fn staggered_rejects_out_of_range_or_reversed_intervals() {
+        let controller = staggered_parent();
+        for range in [-0.1..0.5, 0.5..1.2, 0.8..0.2, f32::NAN..0.5] {
+            let result = StaggeredAnimationBuilder::new(controller.clone())
+                .entry(0.0..1.0, FloatTween::new(0.0, 1.0))
+                .entry(range, FloatTween::new(0.0, 1.0))
+                .build();
+            assert!(
+                matches!(result, Err(AnimationError::InvalidInterval(ref msg)) if msg.starts_with("entry 1")),
+                "expected an interval error, got {result:?}"
+            );
+        }
+        controller.dispose();
+    }
 }
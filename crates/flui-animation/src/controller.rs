@@ -6,7 +6,6 @@
 use crate::simulation::{Simulation, SpringDescription, SpringSimulation, SpringType, Tolerance};
 use crate::status::AnimationStatus;
 use flui_foundation::{ChangeNotifier, Listenable, ListenerCallback, ListenerId};
-use flui_scheduler::config::time_dilation;
 use flui_scheduler::{Scheduler, Ticker};
 use parking_lot::Mutex;
 use smallvec::SmallVec;
@@ -59,8 +58,9 @@ fn default_fling_spring() -> SpringDescription {
 /// # Time model
 ///
 /// The controller advances on the elapsed time delivered by its [`Ticker`]
-/// callback, not on wall-clock reads. Elapsed time is scaled by the global
-/// [`time_dilation`] factor, and muting the ticker (e.g. when the view is
+/// callback, not on wall-clock reads. The ticker already slows that time by
+/// the global [`time_dilation`](flui_scheduler::config::time_dilation)
+/// factor, and muting the ticker (e.g. when the view is
 /// hidden) freezes progress — so lifecycle gating is handled at the ticker
 /// layer rather than re-derived here. A per-run epoch (`run_epoch_secs`) marks
 /// where the current run or repeat cycle began on the ticker timeline.
@@ -137,14 +137,14 @@ struct AnimationControllerInner {
     /// Target value for the current run.
     target_value: f32,
 
-    /// Ticker-timeline epoch (dilated seconds) at which the current run or
-    /// repeat cycle began. `cycle_elapsed = dilated_elapsed - run_epoch_secs`.
+    /// Ticker-timeline epoch (seconds) at which the current run or repeat
+    /// cycle began. `cycle_elapsed = elapsed - run_epoch_secs`.
     run_epoch_secs: f64,
 
-    /// Most recent raw (pre-dilation) elapsed seconds seen by
+    /// Most recent ticker elapsed seconds seen by
     /// [`AnimationController::tick_at`], so `velocity()` can report the
     /// in-progress rate without a fresh tick.
-    last_raw_elapsed_secs: f64,
+    last_elapsed_secs: f64,
 
     /// Monotonically increasing counter, bumped once each time a fresh run is
     /// established (every [`restart_ticker`](AnimationController::restart_ticker),
@@ -197,6 +197,14 @@ struct AnimationControllerInner {
     /// threads `curve` straight into `_InterpolationSimulation`.
     run_curve: Option<Arc<dyn Curve + Send + Sync>>,
 
+    /// Velocity-carry coefficient for an [`animate_to`](AnimationController::animate_to)
+    /// run that interrupted another run: the weight of the `t·(1-t)²`
+    /// Hermite term added on top of the eased interpolation so the run starts
+    /// with the velocity the previous run had at the moment of retargeting.
+    /// Zero for every other run;
+    /// cleared by [`clear_run_modes`](AnimationControllerInner::clear_run_modes).
+    run_velocity_blend: f32,
+
     /// Status most recently delivered to status listeners. The emission seam
     /// ([`take_status_change`](AnimationControllerInner::take_status_change))
     /// compares against this before firing, so a call that leaves `status`
@@ -280,7 +288,7 @@ pub fn with_bounds(
             start_value: lower_bound,
             target_value: upper_bound,
             run_epoch_secs: 0.0,
-            last_raw_elapsed_secs: 0.0,
+            last_elapsed_secs: 0.0,
             run_generation: 0,
             run_duration: None,
             disposed: false,
@@ -294,6 +302,7 @@ pub fn with_bounds(
             repeat_done: 0,
             simulation: None,
             run_curve: None,
+            run_velocity_blend: 0.0,
             last_reported_status: AnimationStatus::Dismissed,
         };
 
@@ -485,6 +494,14 @@ pub fn reset(&self) -> Result<(), AnimationError> {
     /// modify the controller's base duration. An explicit `duration` is used
     /// as-is, without remaining-fraction scaling.
     ///
+    /// Called while another run is in progress, the new run carries that
+    /// run's current rate of change: the value and its velocity are continuous
+    /// at the retarget, so repeatedly retargeting (e.g. a slider thumb
+    /// following a drag) never produces a visible kick. The carried velocity
+    /// decays over the run, which still ends exactly at `target`; the value is
+    /// kept within the bounds if it would briefly overshoot. From rest the run
+    /// is a plain interpolation.
+    ///
     /// # Arguments
     ///
     /// * `target` - The target value (clamped to bounds)
@@ -498,7 +515,7 @@ pub fn animate_to(
         target: f32,
         duration: Option<Duration>,
     ) -> Result<(), AnimationError> {
-        self.drive_to(target, duration, false, None)
+        self.drive_to(target, duration, false, None, true)
     }
 
     /// Animate back to a specific value, defaulting to the reverse duration.
@@ -515,11 +532,12 @@ pub fn animate_back(
         target: f32,
         duration: Option<Duration>,
     ) -> Result<(), AnimationError> {
-        self.drive_to(target, duration, true, None)
+        self.drive_to(target, duration, true, None, false)
     }
 
-    /// Like [`animate_to`](Self::animate_to), but eases the run through
-    /// `curve` instead of running linearly. Flutter parity:
+    /// Like [`animate_to`](Self::animate_to) — including carrying the velocity
+    /// of an interrupted run — but eases the run through `curve` instead of
+    /// running linearly. Flutter parity:
     /// `AnimationController.animateTo(target, duration: ..., curve: ...)`,
     /// which threads `curve` into `_InterpolationSimulation`.
     ///
@@ -532,7 +550,7 @@ pub fn animate_to_curved(
         duration: Option<Duration>,
         curve: Arc<dyn Curve + Send + Sync>,
     ) -> Result<(), AnimationError> {
-        self.drive_to(target, duration, false, Some(curve))
+        self.drive_to(target, duration, false, Some(curve), true)
     }
 
     /// Like [`animate_back`](Self::animate_back), but eases the run through
@@ -548,7 +566,7 @@ pub fn animate_back_curved(
         duration: Option<Duration>,
         curve: Arc<dyn Curve + Send + Sync>,
     ) -> Result<(), AnimationError> {
-        self.drive_to(target, duration, true, Some(curve))
+        self.drive_to(target, duration, true, Some(curve), false)
     }
 
     /// Shared driver for [`animate_to`](Self::animate_to)/[`animate_back`](Self::animate_back)
@@ -556,18 +574,26 @@ pub fn animate_back_curved(
     /// `target`, picking direction from their order and easing through
     /// `curve` (`None` = linear). `prefer_reverse_duration` makes the
     /// `None`-duration default the reverse duration regardless of the run's
-    /// direction (the `animate_back` contract).
+    /// direction (the `animate_back` contract). `carry_velocity` blends the
+    /// velocity of an interrupted run into the new one (the `animate_to`
+    /// contract).
     fn drive_to(
         &self,
         target: f32,
         duration: Option<Duration>,
         prefer_reverse_duration: bool,
         curve: Option<Arc<dyn Curve + Send + Sync>>,
+        carry_velocity: bool,
     ) -> Result<(), AnimationError> {
         let mut inner = self.inner.lock();
         Self::check_disposed(&inner)?;
 
         let target = target.clamp(inner.lower_bound, inner.upper_bound);
+        // Only a live run has a velocity to carry: `set_value` can leave a
+        // directional status behind on a stopped controller.
+        let interrupting = inner.ticker.as_ref().is_some_and(Ticker::is_running);
+        let carried_velocity =
+            (carry_velocity && interrupting).then(|| inner.instantaneous_velocity());
         inner.clear_run_modes();
         inner.run_curve = curve;
         inner.start_value = inner.value;
@@ -602,6 +628,15 @@ fn drive_to(
             };
             inner.scaled_run_duration(base)
         }));
+        if let Some(carried_velocity) = carried_velocity {
+            // Pick the Hermite weight so the run's initial slope equals the
+            // carried velocity: d/dt of `range·curve(t) + blend·t(1-t)²` at
+            // t = 0 is `range·curve'(0) + blend`, in value per unit of t.
+            let secs = inner.current_duration().as_secs_f32();
+            let range = inner.target_value - inner.start_value;
+            let initial_slope = inner.run_curve_slope(0.0);
+            inner.run_velocity_blend = carried_velocity * secs - range * initial_slope;
+        }
         self.restart_ticker(&mut inner);
 
         let status = inner.status;
@@ -878,33 +913,36 @@ pub fn run_generation(&self) -> u64 {
     ///
     /// Normally driven by the ticker callback; exposed for manual stepping.
     pub fn tick(&self) {
-        let raw = {
+        let elapsed = {
             let inner = self.inner.lock();
             inner.ticker.as_ref().map_or(0.0, Ticker::elapsed_secs)
         };
-        self.tick_at(raw);
+        self.tick_at(elapsed);
     }
 
-    /// Advance the animation to `raw_elapsed_secs` seconds (ticker timeline,
-    /// pre-dilation) since the ticker started.
+    /// Advance the animation to `elapsed_secs` seconds (ticker timeline)
+    /// since the ticker started.
+    ///
+    /// The time is taken as-is: a [`Ticker`] already applies the global
+    /// [`time_dilation`](flui_scheduler::config::time_dilation), so a manual
+    /// driver that wants dilation must apply it itself.
     ///
     /// This is the single time-driven entry point: time-based runs interpolate
     /// `start_value -> target_value`, simulations sample `x(t)`, and repeats
     /// advance their cycle epoch. Value and status listeners are fired only
     /// after the inner lock is released.
-    pub fn tick_at(&self, raw_elapsed_secs: f64) {
+    pub fn tick_at(&self, elapsed_secs: f64) {
         let mut inner = self.inner.lock();
         if !inner.status.is_running() {
             return;
         }
-        inner.last_raw_elapsed_secs = raw_elapsed_secs;
-        let dilated = raw_elapsed_secs / time_dilation().max(f64::MIN_POSITIVE);
-        let cycle = (dilated - inner.run_epoch_secs).max(0.0);
+        inner.last_elapsed_secs = elapsed_secs;
+        let cycle = (elapsed_secs - inner.run_epoch_secs).max(0.0);
 
         if inner.simulation.is_some() {
             self.tick_simulation(inner, narrow_f32(cycle));
         } else {
-            self.tick_time_based(inner, dilated, cycle);
+            self.tick_time_based(inner, elapsed_secs, cycle);
         }
     }
 
@@ -946,7 +984,7 @@ fn tick_simulation(
     fn tick_time_based(
         &self,
         mut inner: parking_lot::MutexGuard<'_, AnimationControllerInner>,
-        dilated: f64,
+        elapsed_secs: f64,
         cycle: f64,
     ) {
         let duration = inner.current_duration();
@@ -967,6 +1005,10 @@ fn tick_time_based(
             (None, t) => t,
         };
         inner.value = inner.start_value + range * eased_t;
+        if inner.run_velocity_blend != 0.0 && t < 1.0 {
+            let carry = inner.run_velocity_blend * t * (1.0 - t) * (1.0 - t);
+            inner.value = (inner.value + carry).clamp(inner.lower_bound, inner.upper_bound);
+        }
 
         if t < 1.0 {
             drop(inner);
@@ -1043,7 +1085,7 @@ fn tick_time_based(
             // Advance the epoch past every retired cycle (phase-preserving — the
             // remainder within the new cycle is interpolated on the next tick).
             inner.run_epoch_secs += f64::from(cycles) * period;
-            let _ = dilated; // boundary time available if a future modulo path needs it
+            let _ = elapsed_secs; // boundary time available if a future modulo path needs it
             // `begin_next_repeat_cycle` is an idempotent reset in restart mode
             // and a pure direction flip in bounce mode, so only the parity of the
             // retired-cycle count matters — collapse N cycles to at most one
@@ -1154,7 +1196,7 @@ pub(crate) fn debug_status_listener_count(&self) -> usize {
     /// Reset run state and (re)start the ticker for a fresh run from epoch 0.
     fn restart_ticker(&self, inner: &mut AnimationControllerInner) {
         inner.run_epoch_secs = 0.0;
-        inner.last_raw_elapsed_secs = 0.0;
+        inner.last_elapsed_secs = 0.0;
         // A fresh run's `t = 0` is established here — bump the generation so an
         // external frame driver re-anchors its per-run epoch (see
         // [`run_generation`](Self::run_generation)). `restart_ticker` is the
@@ -1203,6 +1245,47 @@ fn clear_run_modes(&mut self) {
         self.run_duration = None;
         self.simulation = None;
         self.run_curve = None;
+        self.run_velocity_blend = 0.0;
+    }
+
+    /// Rate of change of the value, in units per second (0.0 when idle).
+    ///
+    /// Time-based runs differentiate the eased interpolation (including any
+    /// carried velocity) at the current point of the run; simulations report
+    /// their own `dx`. Unlike the public
+    /// [`velocity`](AnimationController::velocity), which reports a
+    /// time-based run's average rate, this is what a retarget must carry.
+    fn instantaneous_velocity(&self) -> f32 {
+        if !self.status.is_running() {
+            return 0.0;
+        }
+
+        let cycle = self.cycle_elapsed_secs();
+        if let Some(sim) = &self.simulation {
+            return sim.dx(narrow_f32(cycle));
+        }
+
+        let duration = self.current_duration();
+        if duration.is_zero() {
+            return 0.0;
+        }
+        let secs = duration.as_secs_f64();
+        let t = narrow_f32((cycle / secs).clamp(0.0, 1.0));
+        let range = self.target_value - self.start_value;
+        let carry_slope = self.run_velocity_blend * (1.0 - t) * (1.0 - 3.0 * t);
+        (range * self.run_curve_slope(t) + carry_slope) / narrow_f32(secs)
+    }
+
+    /// Slope of the run's easing curve at `t` (1.0 for a linear run),
+    /// estimated with a finite difference kept inside `[0, 1]`.
+    fn run_curve_slope(&self, t: f32) -> f32 {
+        const H: f32 = 1e-3;
+        let Some(curve) = &self.run_curve else {
+            return 1.0;
+        };
+        let lo = (t - H).max(0.0);
+        let hi = (t + H).min(1.0);
+        (curve.transform(hi) - curve.transform(lo)) / (hi - lo)
     }
 
     /// Halt any active run at the current value: stop the ticker and clear
@@ -1274,10 +1357,9 @@ fn scaled_run_duration(&self, base: Duration) -> Duration {
         base.mul_f64(fraction)
     }
 
-    /// Dilated elapsed within the current cycle, from the last observed tick.
+    /// Elapsed within the current cycle, from the last observed tick.
     fn cycle_elapsed_secs(&self) -> f64 {
-        let dilated = self.last_raw_elapsed_secs / time_dilation().max(f64::MIN_POSITIVE);
-        (dilated - self.run_epoch_secs).max(0.0)
+        (self.last_elapsed_secs - self.run_epoch_secs).max(0.0)
     }
 
     /// Whether the current value is at (or indistinguishable from) the upper bound.
@@ -1878,12 +1960,24 @@ fn time_dilation_scales_progress() {
         let _serial = serial();
         let _restore = DilationRestore(time_dilation());
         set_time_dilation(2.0).unwrap(); // half speed
-        let c = controller(100); // 100ms
-        c.animate_to(1.0, Some(Duration::from_millis(100))).unwrap();
-        c.tick_at(0.10); // 100ms raw -> dilated 50ms -> ~0.5, NOT complete
-        let value = c.value();
+
+        // Drive through the controller's own scheduler ticker, which is where
+        // dilation is applied: it must slow the run once, not twice.
+        let scheduler = Arc::new(Scheduler::new());
+        let c = AnimationController::new(Duration::from_secs(1), scheduler.clone());
+        let wall = std::time::Instant::now();
+        c.forward().unwrap();
+        std::thread::sleep(Duration::from_millis(200));
+        let before_frame = wall.elapsed().as_secs_f64();
+        scheduler.execute_frame();
+        let after_frame = wall.elapsed().as_secs_f64();
+        let value = f64::from(c.value());
         c.dispose();
-        assert!((value - 0.5).abs() < 1e-3, "value={value}");
+
+        // Undilated would be ~0.2 and double-dilated ~0.05; half speed over a
+        // 1s run is ~0.1. The lower bound allows for ticker start-up slack.
+        assert!(value >= (before_frame - 0.02) / 2.0, "value={value}");
+        assert!(value <= after_frame / 2.0 + 1e-3, "value={value}");
     }
 
     // ---- set_value parity: stops an active run, change-detects status ----
@@ -2056,4 +2150,65 @@ fn animate_back_curved_eases_toward_the_lower_bound() {
         );
         c.dispose();
     }
+
+    // ---- animate_to carries velocity across a retarget ----
+
+    #[test]
+    fn animate_to_mid_run_keeps_value_and_velocity_continuous() {
+        use crate::curve::Curves;
+        let _serial = serial();
+        let c = controller(1000);
+        c.forward().unwrap();
+        c.tick_at(0.4);
+        let before = c.value();
+
+        // Retarget behind the current value: without the carry the run would
+        // immediately head down at the curve's slope; instead it keeps moving
+        // up at the interrupted run's speed and only then turns around.
+        c.animate_to_curved(
+            0.2,
+            Some(Duration::from_secs(1)),
+            Arc::new(Curves::EaseInOut),
+        )
+        .unwrap();
+        assert_eq!(c.value(), before, "retargeting must not move the value");
+
+        c.tick_at(0.01);
+        let jump = c.value() - before;
+        assert!(
+            (jump - 0.01).abs() < 2e-3,
+            "first frame after the retarget must continue the old motion: moved {jump}"
+        );
+
+        // Past the run's end (1s + float headroom).
+        c.tick_at(1.01);
+        assert_eq!(c.value(), 0.2, "the run must still land on the new target");
+        c.dispose();
+    }
+
+    #[test]
+    fn animate_to_mid_run_keeps_reporting_the_average_velocity() {
+        let _serial = serial();
+        let c = controller(1000);
+        c.forward().unwrap();
+        c.tick_at(0.5);
+        c.animate_to(0.0, Some(Duration::from_secs(1))).unwrap();
+        // `velocity()` keeps its contract: a time-based run reports
+        // range / duration, whatever velocity the run carried in.
+        assert!((c.velocity() + 0.5).abs() < 1e-4, "got {}", c.velocity());
+        c.dispose();
+    }
+
+    #[test]
+    fn animate_to_from_rest_interpolates_linearly() {
+        let _serial = serial();
+        let c = controller(100);
+        c.animate_to(1.0, None).unwrap();
+        c.tick_at(0.05);
+        assert!((c.value() - 0.5).abs() < 1e-3, "got {}", c.value());
+        c.tick_at(0.1);
+        assert_eq!(c.value(), 1.0);
+        assert_eq!(c.status(), AnimationStatus::Completed);
+        c.dispose();
+    }
 }
@@ -124,6 +124,20 @@ pub fn with_reverse_curve(mut self, reverse_curve: C) -> Self {
         self
     }
 
+    /// The curve applied to runs entered in the forward direction.
+    #[inline]
+    #[must_use]
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    /// The curve applied to runs entered in reverse, if one was set.
+    #[inline]
+    #[must_use]
+    pub fn reverse_curve(&self) -> Option<&C> {
+        self.reverse_curve.as_ref()
+    }
+
     /// Get the current curve being used (respects reverse).
     ///
     /// Uses the direction captured at run start when running (so a mid-run
@@ -454,4 +468,55 @@ fn dropping_curved_removes_parent_subscription() {
         );
         controller.dispose();
     }
+
+    #[test]
+    fn ticked_mid_flight_reverse_stays_continuous_then_reverse_run_uses_reverse_curve() {
+        let scheduler = Arc::new(Scheduler::new());
+        let controller = Arc::new(AnimationController::new(
+            Duration::from_millis(100),
+            scheduler,
+        ));
+        let curved = CurvedAnimation::new(
+            controller.clone() as Arc<dyn Animation<f32>>,
+            Curves::EaseOut,
+        )
+        .with_reverse_curve(Curves::EaseIn);
+
+        controller.forward().unwrap();
+        controller.tick_at(0.06);
+        let before_flip = curved.value();
+        assert!((before_flip - Curves::EaseOut.transform(0.6)).abs() < 1e-3);
+
+        // Reverse mid-flight and sample the way back: the first sample sits
+        // on the forward curve and the values fall monotonically, with no
+        // jump onto the reverse curve at the direction change.
+        controller.reverse().unwrap();
+        let mut previous = curved.value();
+        assert!((previous - before_flip).abs() < 1e-3);
+        for ms in (5..=55).step_by(5) {
+            controller.tick_at(f64::from(ms) / 1000.0);
+            let value = curved.value();
+            assert!(value <= previous, "{value} after {previous} at {ms}ms");
+            assert!(
+                previous - value < 0.2,
+                "jump from {previous} to {value} at {ms}ms"
+            );
+            previous = value;
+        }
+        controller.tick_at(0.07);
+        assert_eq!(controller.status(), AnimationStatus::Dismissed);
+
+        // A run entered from Completed in reverse uses the reverse curve.
+        controller.set_value(1.0);
+        controller.reverse().unwrap();
+        controller.tick_at(0.05);
+        let expected = Curves::EaseIn.transform(controller.value());
+        assert!(
+            (curved.value() - expected).abs() < 1e-3,
+            "reverse run must follow the reverse curve ({} vs {expected})",
+            curved.value()
+        );
+
+        controller.dispose();
+    }
 }
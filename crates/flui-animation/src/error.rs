@@ -56,6 +56,21 @@ pub enum AnimationError {
     /// for oscillating springs.
     #[error("Invalid spring configuration: {0}")]
     InvalidSpring(String),
+
+    /// An interval outside `0.0..=1.0` (or with `end < begin`) was provided.
+    ///
+    /// This error occurs when building a
+    /// [`StaggeredAnimationBuilder`](crate::StaggeredAnimationBuilder) whose
+    /// entries do not fit within the parent animation's progress.
+    #[error("Invalid animation interval: {0}")]
+    InvalidInterval(String),
+
+    /// A [`TweenSequence`](crate::TweenSequence) is empty or has an item with
+    /// a zero, negative, or non-finite weight.
+    ///
+    /// Reported by [`TweenSequence::validate()`](crate::TweenSequence::validate).
+    #[error("Invalid tween sequence: {0}")]
+    InvalidTweenSequence(String),
 }
 
 #[cfg(test)]
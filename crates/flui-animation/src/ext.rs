@@ -148,6 +148,38 @@ fn curved<C>(self: Arc<Self>, curve: C) -> CurvedAnimation<C>
         CurvedAnimation::new(self as Arc<dyn Animation<f32>>, curve)
     }
 
+    /// Apply `curve` to forward runs and `reverse_curve` to reverse runs.
+    ///
+    /// Shorthand for [`curved`](Self::curved) followed by
+    /// [`CurvedAnimation::with_reverse_curve`]. The curve is chosen by the
+    /// direction a run *starts* in, so reversing mid-flight stays continuous.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flui_animation::{AnimationController, Animation};
+    /// use flui_animation::ext::AnimationExt;
+    /// use flui_animation::Curves;
+    /// use flui_scheduler::Scheduler;
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// let scheduler = Arc::new(Scheduler::new());
+    /// let controller = Arc::new(AnimationController::new(
+    ///     Duration::from_millis(300),
+    ///     scheduler,
+    /// ));
+    ///
+    /// // Material-style motion: decelerate in, accelerate out.
+    /// let curved = controller.curved_with_reverse(Curves::EaseOut, Curves::EaseIn);
+    /// ```
+    fn curved_with_reverse<C>(self: Arc<Self>, curve: C, reverse_curve: C) -> CurvedAnimation<C>
+    where
+        C: Curve + Clone + Send + Sync + fmt::Debug + 'static,
+    {
+        self.curved(curve).with_reverse_curve(reverse_curve)
+    }
+
     /// Reverse this animation.
     ///
     /// Creates a [`ReverseAnimation`] that inverts the animation values:
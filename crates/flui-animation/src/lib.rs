@@ -111,7 +111,7 @@
 
 // Re-exports from animation modules
 pub use animation::{Animation, AnimationDirection, StatusCallback};
-pub use builder::AnimationControllerBuilder;
+pub use builder::{AnimationControllerBuilder, StaggeredAnimationBuilder};
 pub use compound::{AnimationOperator, CompoundAnimation};
 pub use constant::{ALWAYS_COMPLETE, ALWAYS_DISMISSED, ConstantAnimation};
 pub use controller::AnimationController;
@@ -165,7 +165,7 @@
 /// Prelude module for convenient imports
 pub mod prelude {
     pub use crate::animation::{Animation, AnimationDirection};
-    pub use crate::builder::AnimationControllerBuilder;
+    pub use crate::builder::{AnimationControllerBuilder, StaggeredAnimationBuilder};
     pub use crate::compound::{AnimationOperator, CompoundAnimation};
     pub use crate::constant::{ALWAYS_COMPLETE, ALWAYS_DISMISSED, ConstantAnimation};
     pub use crate::controller::AnimationController;
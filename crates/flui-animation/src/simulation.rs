@@ -22,6 +22,7 @@
 //! ```
 
 use std::f32::consts::PI;
+use std::time::Duration;
 
 /// Tolerance for determining when simulations are "done".
 ///
@@ -352,6 +353,49 @@ pub fn spring_type(&self) -> SpringType {
     pub fn end_position(&self) -> f32 {
         self.end_position
     }
+
+    /// The furthest the simulation travels past [`end_position`](Self::end_position),
+    /// as a non-negative distance (0.0 if it never crosses it).
+    ///
+    /// "Past" is the side of the end position opposite the start, or the side
+    /// the initial velocity points to when starting at the end. Computed from
+    /// the extrema of the closed-form solution, so layout can reserve room for
+    /// an overshooting spring without sampling it.
+    #[must_use]
+    pub fn max_overshoot(&self) -> f32 {
+        let start = self.solution.x(0.0);
+        let side = if start == 0.0 {
+            self.solution.dx(0.0).signum()
+        } else {
+            -start.signum()
+        };
+        self.solution
+            .extremum_times()
+            .into_iter()
+            .flatten()
+            .map(|time| side * self.solution.x(time))
+            .fold(0.0, f32::max)
+    }
+
+    /// Time after which the simulation is guaranteed to stay within
+    /// `tolerance` of rest: its distance from the end and its speed are below
+    /// `tolerance.distance` and `tolerance.velocity` from then on.
+    ///
+    /// Computed from the decay envelope of the closed-form solution, so it is
+    /// a tight upper bound rather than the first instant
+    /// [`is_done`](Simulation::is_done) happens to hold (an underdamped
+    /// spring can pass through the tolerance band mid-oscillation). Returns
+    /// [`Duration::MAX`] for an undamped spring, which never settles.
+    #[must_use]
+    pub fn settling_time(&self, tolerance: &Tolerance) -> Duration {
+        let secs = self.solution.settling_time(tolerance);
+        if secs <= 0.0 {
+            Duration::ZERO
+        } else {
+            // Infinite, NaN and finite-but-unrepresentable times all saturate.
+            Duration::try_from_secs_f32(secs).unwrap_or(Duration::MAX)
+        }
+    }
 }
 
 impl Simulation for SpringSimulation {
@@ -430,6 +474,77 @@ fn dx(&self, time: f32) -> f32 {
         }
     }
 
+    /// Times `t > 0` at which `dx(t) == 0`. Critically damped and overdamped
+    /// responses have at most one; for an underdamped response the first two
+    /// are returned, one on each side of the end, and they dominate every later
+    /// extremum because the amplitude decays monotonically.
+    fn extremum_times(&self) -> [Option<f32>; 2] {
+        let positive = |time: f32| (time.is_finite() && time > 0.0).then_some(time);
+        match self {
+            // d/dt (c1 + c2·t)·e^{rt} = 0  ⇔  t = -c1/c2 - 1/r
+            SpringSolution::Critical(s) if s.c2 != 0.0 => {
+                [positive(-s.c1 / s.c2 - 1.0 / s.r), None]
+            }
+            SpringSolution::Critical(_) => [None, None],
+            // c1·r1·e^{r1·t} + c2·r2·e^{r2·t} = 0  ⇔  e^{(r1-r2)t} = -c2·r2 / (c1·r1)
+            SpringSolution::Overdamped(s) => {
+                let ratio = -(s.c2 * s.r2) / (s.c1 * s.r1);
+                [positive(ratio.ln() / (s.r1 - s.r2)), None]
+            }
+            // dx ∝ a·cos(wt) + b·sin(wt), which vanishes at wt = atan2(b, a) + π/2 + kπ.
+            SpringSolution::Underdamped(s) => {
+                let a = s.c2 * s.w + s.r * s.c1;
+                let b = s.r * s.c2 - s.c1 * s.w;
+                if a == 0.0 && b == 0.0 {
+                    return [None, None];
+                }
+                let mut phase = b.atan2(a) + PI / 2.0;
+                while phase <= 0.0 {
+                    phase += PI;
+                }
+                [positive(phase / s.w), positive((phase + PI) / s.w)]
+            }
+        }
+    }
+
+    /// Seconds after which both envelopes of `|x|` and `|dx|` stay below the
+    /// tolerance (infinite if the response never decays).
+    fn settling_time(&self, tolerance: &Tolerance) -> f32 {
+        match self {
+            SpringSolution::Underdamped(s) => {
+                let amplitude = s.c1.hypot(s.c2);
+                if amplitude == 0.0 {
+                    return 0.0;
+                }
+                if s.r >= 0.0 {
+                    return f32::INFINITY;
+                }
+                // |x| ≤ A·e^{rt} and |dx| ≤ A·√(w² + r²)·e^{rt}.
+                let until = |bound: f32, limit: f32| (limit / bound).ln() / s.r;
+                until(amplitude, tolerance.distance)
+                    .max(until(amplitude * s.w.hypot(s.r), tolerance.velocity))
+                    .max(0.0)
+            }
+            SpringSolution::Critical(s) => {
+                // |x| ≤ (|c1| + |c2|·t)·e^{rt}; dx = (r·c1 + c2 + r·c2·t)·e^{rt}.
+                let distance = |t: f32| (s.c1.abs() + s.c2.abs() * t) * (s.r * t).exp();
+                let speed =
+                    |t: f32| ((s.r * s.c1 + s.c2).abs() + (s.r * s.c2).abs() * t) * (s.r * t).exp();
+                decay_crossing(distance, tolerance.distance)
+                    .max(decay_crossing(speed, tolerance.velocity))
+            }
+            SpringSolution::Overdamped(s) => {
+                let distance =
+                    |t: f32| s.c1.abs() * (s.r1 * t).exp() + s.c2.abs() * (s.r2 * t).exp();
+                let speed = |t: f32| {
+                    (s.c1 * s.r1).abs() * (s.r1 * t).exp() + (s.c2 * s.r2).abs() * (s.r2 * t).exp()
+                };
+                decay_crossing(distance, tolerance.distance)
+                    .max(decay_crossing(speed, tolerance.velocity))
+            }
+        }
+    }
+
     fn spring_type(&self) -> SpringType {
         match self {
             SpringSolution::Critical(_) => SpringType::CriticallyDamped,
@@ -525,6 +640,42 @@ fn dx(&self, time: f32) -> f32 {
     }
 }
 
+/// Last time at which a unimodal, eventually decaying envelope drops to
+/// `limit`: bracket by doubling, then bisect. 0.0 if it never exceeds it,
+/// infinite if it never decays below it.
+fn decay_crossing(envelope: impl Fn(f32) -> f32, limit: f32) -> f32 {
+    let mut prev = envelope(0.0);
+    // Last sampled time still above `limit`; the crossing lies after it.
+    let mut above = (prev > limit).then_some(0.0_f32);
+    let mut hi = 1e-3_f32;
+    loop {
+        let value = envelope(hi);
+        if value > limit {
+            above = Some(hi);
+        } else if value <= prev {
+            // Below the limit and decreasing: past the peak for good.
+            break;
+        }
+        if hi > 1e9 {
+            return f32::INFINITY;
+        }
+        prev = value;
+        hi *= 2.0;
+    }
+    let Some(mut lo) = above else {
+        return 0.0;
+    };
+    for _ in 0..48 {
+        let mid = 0.5 * (lo + hi);
+        if envelope(mid) > limit {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    hi
+}
+
 /// Checks if a value is near zero within the given threshold.
 #[inline]
 fn near_zero(value: f32, threshold: f32) -> bool {
@@ -1121,4 +1272,128 @@ fn test_spring_with_duration_and_bounce() {
         // Should be underdamped
         assert!(bouncy.damping_ratio() < 1.0);
     }
+
+    // ---- overshoot / settling-time queries against closed-form responses ----
+
+    /// Samples `sim` densely over `[0, until]` and returns the largest signed
+    /// excursion past `end` on the `side` (+1/-1) of it.
+    fn sampled_overshoot(sim: &SpringSimulation, side: f32, until: f32) -> f32 {
+        (0..=20_000)
+            .map(|i| side * (sim.x(until * i as f32 / 20_000.0) - sim.end_position()))
+            .fold(0.0, f32::max)
+    }
+
+    #[test]
+    fn underdamped_step_overshoot_matches_closed_form() {
+        // Step response from rest: peak overshoot = e^{-ζπ/√(1-ζ²)} of the step.
+        let zeta = 0.3_f32;
+        let spring = SpringDescription::with_damping_ratio(1.0, 100.0, zeta);
+        let sim = SpringSimulation::new(spring, 0.0, 10.0, 0.0);
+        let expected = 10.0 * (-zeta * PI / (1.0 - zeta * zeta).sqrt()).exp();
+        let overshoot = sim.max_overshoot();
+        assert!(
+            (overshoot - expected).abs() < 1e-3,
+            "overshoot {overshoot}, closed form {expected}"
+        );
+        assert!((overshoot - sampled_overshoot(&sim, 1.0, 2.0)).abs() < 1e-2);
+    }
+
+    #[test]
+    fn critically_damped_overshoot_from_rest_is_zero_and_impulse_peak_matches() {
+        // c = 2·√(k·m) exactly, so the discriminant is exactly zero.
+        let spring = SpringDescription::new(1.0, 100.0, 20.0);
+        assert_eq!(spring.spring_type(), SpringType::CriticallyDamped);
+        assert_eq!(
+            SpringSimulation::new(spring, 0.0, 1.0, 0.0).max_overshoot(),
+            0.0
+        );
+
+        // Impulse response x = v·t·e^{-ωt} peaks at v/(e·ω), with ω = 10.
+        let sim = SpringSimulation::new(spring, 0.0, 0.0, 5.0);
+        let expected = 5.0 / (std::f32::consts::E * 10.0);
+        assert!(
+            (sim.max_overshoot() - expected).abs() < 1e-4,
+            "overshoot {}, closed form {expected}",
+            sim.max_overshoot()
+        );
+    }
+
+    #[test]
+    fn overdamped_overshoot_matches_sampled_response() {
+        let spring = SpringDescription::with_damping_ratio(1.0, 100.0, 2.0);
+        assert_eq!(spring.spring_type(), SpringType::Overdamped);
+        assert_eq!(
+            SpringSimulation::new(spring, 0.0, 1.0, 0.0).max_overshoot(),
+            0.0
+        );
+
+        // A strong push toward the target carries an overdamped spring past it.
+        let sim = SpringSimulation::new(spring, 0.0, 1.0, 60.0);
+        let overshoot = sim.max_overshoot();
+        assert!(overshoot > 0.0);
+        assert!(
+            (overshoot - sampled_overshoot(&sim, 1.0, 3.0)).abs() < 1e-3,
+            "analytic {overshoot} vs sampled {}",
+            sampled_overshoot(&sim, 1.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn underdamped_settling_time_matches_envelope_formula() {
+        // From rest the envelope is d·e^{-ζωt}/√(1-ζ²), so the 2% band is
+        // reached at t = ln(1 / (0.02·√(1-ζ²))) / (ζ·ω).
+        let (zeta, omega) = (0.2_f32, 10.0_f32);
+        let spring = SpringDescription::with_damping_ratio(1.0, omega * omega, zeta);
+        let sim = SpringSimulation::new(spring, 0.0, 1.0, 0.0);
+        let tolerance = Tolerance::new(0.02, f32::INFINITY, 1e-3);
+        let expected = (1.0 / (0.02 * (1.0 - zeta * zeta).sqrt())).ln() / (zeta * omega);
+        let settling = sim.settling_time(&tolerance).as_secs_f32();
+        assert!(
+            (settling - expected).abs() < 1e-3,
+            "settling {settling}, closed form {expected}"
+        );
+    }
+
+    #[test]
+    fn settling_time_bounds_every_later_sample() {
+        let tolerance = Tolerance::new(1e-3, 1e-2, 1e-3);
+        for spring in [
+            SpringDescription::with_damping_ratio(1.0, 200.0, 0.4),
+            SpringDescription::new(1.0, 100.0, 20.0),
+            SpringDescription::with_damping_ratio(1.0, 200.0, 3.0),
+        ] {
+            let sim = SpringSimulation::with_tolerance(spring, 0.0, 1.0, -4.0, tolerance);
+            let settling = sim.settling_time(&tolerance).as_secs_f32();
+            assert!(settling > 0.0 && settling < 10.0, "{spring:?}: {settling}");
+            for i in 0..1000 {
+                let t = settling + i as f32 * 0.01;
+                assert!(
+                    sim.is_done(t),
+                    "{spring:?} not at rest at {t} (settling {settling})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn undamped_spring_never_settles() {
+        let sim = SpringSimulation::new(SpringDescription::new(1.0, 100.0, 0.0), 0.0, 1.0, 0.0);
+        assert_eq!(sim.settling_time(&Tolerance::DEFAULT), Duration::MAX);
+        assert!((sim.max_overshoot() - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn barely_damped_spring_saturates_settling_time() {
+        // Finite, but far beyond what a `Duration` can hold.
+        let sim = SpringSimulation::new(SpringDescription::new(1.0, 100.0, 1e-30), 0.0, 1.0, 0.0);
+        assert_eq!(sim.settling_time(&Tolerance::DEFAULT), Duration::MAX);
+    }
+
+    #[test]
+    fn duration_and_bounce_derive_stiffness_and_damping() {
+        let spring = SpringDescription::with_duration_and_bounce(0.5, 0.3);
+        assert!((spring.stiffness - 4.0 * PI * PI / 0.25).abs() < 1e-3);
+        assert!((spring.damping_ratio() - 0.7).abs() < 1e-5);
+        assert!((spring.bounce() - 0.3).abs() < 1e-5);
+    }
 }
@@ -26,6 +26,7 @@
 //! ```
 
 use crate::curve::Curve;
+use crate::error::AnimationError;
 use flui_types::geometry::{Edges, Lerp, Matrix4, Offset, Pixels, Rect, Size};
 use flui_types::layout::Alignment;
 use flui_types::styling::{BorderRadius, Color};
@@ -402,22 +403,62 @@ pub fn items(&self) -> &[TweenSequenceItem<T, A>] {
     pub fn total_weight(&self) -> f32 {
         self.total_weight
     }
-}
 
-impl<T, A: Animatable<T>> Animatable<T> for TweenSequence<T, A> {
-    /// Unlike a plain [`Tween`], a sequence **clamps** `t` to `[0, 1]`:
-    /// overshoot (elastic/spring `t` outside the unit range) saturates at the
-    /// first/last item's endpoint rather than extrapolating, because there is
-    /// no meaningful item to attribute out-of-range progress to.
-    fn transform(&self, t: f32) -> T {
+    /// Checks that every item has a finite, positive weight.
+    ///
+    /// [`new`](Self::new) only requires the *total* weight to be positive, and
+    /// [`TweenSequenceItem::weight`] is a public field, so an item can end up
+    /// with a zero or negative weight (a segment that is skipped or runs
+    /// backwards). Editors building sequences from user input should call this
+    /// before scrubbing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AnimationError::InvalidTweenSequence`] naming the first
+    /// offending item.
+    pub fn validate(&self) -> Result<(), AnimationError> {
+        for (index, item) in self.items.iter().enumerate() {
+            if !(item.weight.is_finite() && item.weight > 0.0) {
+                return Err(AnimationError::InvalidTweenSequence(format!(
+                    "item {index} has weight {}, must be finite and positive",
+                    item.weight
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Index of the item that is active at progress `t` (clamped to `[0, 1]`).
+    ///
+    /// A `t` landing exactly on the edge between two items belongs to the item
+    /// that *ends* there, so `active_segment(0.0)` is `0` and
+    /// `active_segment(1.0)` is the last index.
+    #[must_use]
+    pub fn active_segment(&self, t: f32) -> usize {
+        self.locate(t).0
+    }
+
+    /// The sequence's value at progress `t` together with the index of the
+    /// active item (see [`active_segment`](Self::active_segment)).
+    ///
+    /// Equivalent to [`Animatable::transform`] plus the segment lookup, for
+    /// tooling that scrubs a sequence and highlights the current item.
+    #[must_use]
+    pub fn value_at(&self, t: f32) -> (usize, T) {
+        let (index, local_t) = self.locate(t);
+        (index, self.items[index].tween.transform(local_t))
+    }
+
+    /// Resolve `t` to the active item index and the item-local progress.
+    fn locate(&self, t: f32) -> (usize, f32) {
         let t = t.clamp(0.0, 1.0);
+        let last = self.items.len() - 1;
 
-        // Find which item we're in
         let mut accumulated_weight = 0.0;
         for (i, item) in self.items.iter().enumerate() {
             let item_end = (accumulated_weight + item.weight) / self.total_weight;
 
-            if t <= item_end || i == self.items.len() - 1 {
+            if t <= item_end || i == last {
                 // Calculate local t within this item
                 let item_start = accumulated_weight / self.total_weight;
                 let local_t = if (item_end - item_start).abs() < 1e-6 {
@@ -425,21 +466,26 @@ fn transform(&self, t: f32) -> T {
                 } else {
                     ((t - item_start) / (item_end - item_start)).clamp(0.0, 1.0)
                 };
-
-                return item.tween.transform(local_t);
+                return (i, local_t);
             }
 
             accumulated_weight += item.weight;
         }
 
         // Unreachable: `new()` (the only constructor) asserts `items` is
-        // non-empty, and the loop's `i == len - 1` arm returns on the final
+        // non-empty, and the loop's `i == last` arm returns on the final
         // iteration. Kept as a typed fallthrough for the compiler.
-        self.items
-            .last()
-            .expect("BUG: TweenSequence items are non-empty (asserted in new), so the loop above returns on its final iteration")
-            .tween
-            .transform(1.0)
+        (last, 1.0)
+    }
+}
+
+impl<T, A: Animatable<T>> Animatable<T> for TweenSequence<T, A> {
+    /// Unlike a plain [`Tween`], a sequence **clamps** `t` to `[0, 1]`:
+    /// overshoot (elastic/spring `t` outside the unit range) saturates at the
+    /// first/last item's endpoint rather than extrapolating, because there is
+    /// no meaningful item to attribute out-of-range progress to.
+    fn transform(&self, t: f32) -> T {
+        self.value_at(t).1
     }
 }
 
@@ -846,6 +892,52 @@ fn test_tween_sequence_weighted() {
         assert_eq!(sequence.transform(1.0), 100.0);
     }
 
+    #[test]
+    fn tween_sequence_active_segment_on_edges() {
+        let sequence = TweenSequence::new(vec![
+            TweenSequenceItem::new(FloatTween::new(0.0, 50.0), 1.0),
+            TweenSequenceItem::new(FloatTween::new(50.0, 60.0), 1.0),
+            TweenSequenceItem::new(FloatTween::new(60.0, 100.0), 2.0),
+        ]);
+
+        assert_eq!(sequence.active_segment(-1.0), 0);
+        assert_eq!(sequence.active_segment(0.0), 0);
+        // Exactly on an edge: the item ending there is active.
+        assert_eq!(sequence.value_at(0.25), (0, 50.0));
+        assert_eq!(sequence.active_segment(0.26), 1);
+        assert_eq!(sequence.value_at(0.5), (1, 60.0));
+        assert_eq!(sequence.active_segment(0.51), 2);
+        assert_eq!(sequence.value_at(1.0), (2, 100.0));
+        assert_eq!(sequence.active_segment(2.0), 2);
+
+        let (index, value) = sequence.value_at(0.75);
+        assert_eq!(index, 2);
+        assert!((value - 80.0).abs() < 1e-4);
+        assert_eq!(value, sequence.transform(0.75));
+    }
+
+    #[test]
+    fn tween_sequence_validate_rejects_non_positive_weights() {
+        let valid = TweenSequence::new(vec![
+            TweenSequenceItem::new(FloatTween::new(0.0, 1.0), 1.0),
+            TweenSequenceItem::new(FloatTween::new(1.0, 2.0), 0.5),
+        ]);
+        assert_eq!(valid.validate(), Ok(()));
+
+        for bad_weight in [0.0, -1.0] {
+            let mut skipped = TweenSequenceItem::new(FloatTween::new(1.0, 2.0), 1.0);
+            skipped.weight = bad_weight;
+            let sequence = TweenSequence::new(vec![
+                TweenSequenceItem::new(FloatTween::new(0.0, 1.0), 2.0),
+                skipped,
+            ]);
+            assert!(matches!(
+                sequence.validate(),
+                Err(AnimationError::InvalidTweenSequence(ref msg)) if msg.starts_with("item 1")
+            ));
+        }
+    }
+
     // ========================================================================
     // Tests for new types: CurveTween, ChainedTween, extension traits
     // ========================================================================
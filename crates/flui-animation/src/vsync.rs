@@ -29,6 +29,7 @@
 
 use std::sync::Arc;
 
+use flui_scheduler::config::time_dilation;
 use parking_lot::Mutex;
 
 use crate::{Animation, AnimationController};
@@ -250,7 +251,8 @@ pub fn has_running(&self) -> bool {
     /// For each controller: if its `run_generation` advanced since the last
     /// observation (a fresh run was just established) or it has no anchor yet,
     /// re-anchor `t = 0` to `now_secs`; then, if the controller reports running,
-    /// tick it with the raw seconds elapsed since that anchor. A non-running
+    /// tick it with the seconds elapsed since that anchor, slowed by the global
+    /// [`time_dilation`] as a [`Ticker`](flui_scheduler::Ticker) would. A non-running
     /// controller is skipped (its anchor is set on the frame it next starts), so
     /// a disposed-but-not-unregistered controller is simply not ticked.
     ///
@@ -321,7 +323,10 @@ pub fn tick_all(&self, now_secs: f64) {
                     // `run_start_secs` is `Some` here — set in the branch above on
                     // this same call if it was `None`.
                     let run_start = registered.run_start_secs.unwrap_or(now_secs);
-                    Some((registered.controller.clone(), now_secs - run_start))
+                    Some((
+                        registered.controller.clone(),
+                        (now_secs - run_start) / time_dilation(),
+                    ))
                 } else {
                     None
                 }
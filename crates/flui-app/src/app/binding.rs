@@ -39,19 +39,23 @@
     ClientToken, ImeEventCallback, OpaqueWindowHandle, TextInputRegistry, binding::GestureBinding,
     routing::FocusManager,
 };
-use flui_layer::Scene;
+use flui_layer::{Scene, SceneCompositor, TextureFrameSignal};
 use flui_platform::traits::{Clipboard, PlatformInput, PlatformWindow};
 use flui_rendering::binding::RendererBinding;
 use flui_rendering::constraints::BoxConstraints;
+use flui_rendering::view::ViewConfiguration;
 use flui_scheduler::{AppLifecycleState, Scheduler};
 use flui_types::{
     HapticFeedback, Size,
     geometry::{Bounds, Pixels, px},
+    painting::TextureId,
 };
 use flui_view::View;
-use flui_widgets::VsyncScope;
+use flui_widgets::{Brightness, VsyncScope};
 use parking_lot::{Mutex, RwLock};
 
+use super::media_query::{RootMediaQuery, RootMediaQuerySource};
+use super::{AppHandle, AppScope};
 use crate::bindings::RenderingFlutterBinding;
 
 /// Transitional process service host.
@@ -105,6 +109,11 @@ pub struct AppBinding {
     /// Active platform window (set during run_desktop).
     active_window: Arc<Mutex<Option<Arc<dyn PlatformWindow>>>>,
 
+    /// Redraw-driven vsync source the Linux runner installs; `None` until
+    /// then, in which case wakes go straight to the window.
+    #[cfg(target_os = "linux")]
+    frame_vsync: Arc<Mutex<Option<Arc<FrameVsync>>>>,
+
     /// The platform's clipboard capability (ADR-0034), resolved from
     /// `Platform::clipboard()` — a required method every backend already
     /// implements — before `Platform::run()` consumes ownership of the
@@ -114,6 +123,24 @@ pub struct AppBinding {
     /// stay pinned behind this `Arc` past the event loop's exit.
     platform_clipboard: Arc<Mutex<Option<Arc<dyn Clipboard>>>>,
 
+    /// Shutdown hooks and programmatic quit; the runner installs the
+    /// platform's quit capability into it once the platform is up.
+    app_handle: AppHandle,
+
+    /// Compositor state carried across frames; its texture frame registry
+    /// wakes the frame loop on every producer signal and is flushed against
+    /// each frame's layer tree before the scene is built.
+    compositor: Mutex<SceneCompositor>,
+
+    /// The last presented scene, handed back by `render_frame_entered`. A
+    /// frame whose only news is a texture signal re-composites this retained
+    /// layer tree instead of repainting the render tree.
+    retained_scene: Mutex<Option<Arc<Scene>>>,
+
+    /// Window metrics the root `MediaQuery` provides; refreshed from the
+    /// frame's view configuration before every build phase.
+    media_query: RootMediaQuerySource,
+
     /// Controller registry for implicit animations (VsyncScope-driven).
     ///
     /// Wrapped in a `Mutex` so `set_vsync` can replace the shared `Arc` handle
@@ -150,15 +177,48 @@ pub struct AppBinding {
     now_secs_override: AtomicU64,
 }
 
+/// Vsync source behind [`AppBinding::install_frame_vsync`]: winit redraws,
+/// coalesced, with a refresh-pegged fallback while the window is occluded.
+#[cfg(target_os = "linux")]
+pub(crate) type FrameVsync = flui_scheduler::WinitVsyncScheduler<WindowRedrawProxy>;
+
+/// Requests redraws from the active window, the way the winit backend's
+/// event loop expects them.
+#[cfg(target_os = "linux")]
+pub(crate) struct WindowRedrawProxy {
+    active_window: Arc<Mutex<Option<Arc<dyn PlatformWindow>>>>,
+}
+
+#[cfg(target_os = "linux")]
+impl flui_scheduler::RedrawProxy for WindowRedrawProxy {
+    fn request_redraw(&self) -> bool {
+        // Clone out before calling through: see `FrameWakeHandle::wake_frame`.
+        let Some(window) = self.active_window.lock().as_ref().cloned() else {
+            return false;
+        };
+        window.request_redraw();
+        true
+    }
+}
+
 #[derive(Clone)]
 struct FrameWakeHandle {
     needs_redraw: Arc<AtomicBool>,
     active_window: Arc<Mutex<Option<Arc<dyn PlatformWindow>>>>,
+    #[cfg(target_os = "linux")]
+    frame_vsync: Arc<Mutex<Option<Arc<FrameVsync>>>>,
 }
 
 impl FrameWakeHandle {
     fn wake_frame(&self) {
         self.needs_redraw.store(true, Ordering::Relaxed);
+        #[cfg(target_os = "linux")]
+        if let Some(vsync) = self.frame_vsync.lock().as_ref().cloned() {
+            if vsync.request_frame() {
+                tracing::trace!("wake_frame: vsync redraw request sent");
+            }
+            return;
+        }
         let window = self.active_window.lock().as_ref().cloned();
         if let Some(window) = window {
             window.request_redraw();
@@ -309,6 +369,9 @@ pub(crate) fn apply(
 enum FramePaintOutcome {
     /// A fresh layer tree was painted and turned into a `Scene`.
     Painted(Arc<Scene>),
+    /// Only external textures signaled new frames: the retained scene, with
+    /// just its signaled texture layers marked, is presented again.
+    TextureFrame(Arc<Scene>),
     /// Nothing was dirty this frame; no new content to composite.
     Idle,
     /// The build/layout/paint transaction failed (e.g. a render object
@@ -329,9 +392,13 @@ pub(crate) fn new() -> Self {
 
         let needs_redraw = Arc::new(AtomicBool::new(false));
         let active_window = Arc::new(Mutex::new(None));
+        #[cfg(target_os = "linux")]
+        let frame_vsync = Arc::new(Mutex::new(None));
         let wake_handle = FrameWakeHandle {
             needs_redraw: Arc::clone(&needs_redraw),
             active_window: Arc::clone(&active_window),
+            #[cfg(target_os = "linux")]
+            frame_vsync: Arc::clone(&frame_vsync),
         };
 
         // Create shared pipeline owner first (elements need Arc access)
@@ -363,6 +430,16 @@ pub(crate) fn new() -> Self {
         // that method's doc for why a per-construction install is wrong both
         // for steal-proofing and for cross-thread wake correctness.
 
+        // Texture-wake wiring: a decoder or camera signaling a new frame must
+        // get it composited even while nothing else is dirty. The hook runs
+        // on the producer thread, so it only wakes; `draw_frame_entered`
+        // re-composites the retained scene for the pending signal.
+        let compositor = SceneCompositor::new();
+        let texture_wake = wake_handle.clone();
+        compositor
+            .texture_frames()
+            .set_frame_scheduler(move || texture_wake.wake_frame());
+
         // Create RendererBinding sharing the SAME PipelineOwner
         let renderer =
             RenderingFlutterBinding::new_with_pipeline(Arc::clone(&shared_pipeline_owner));
@@ -376,7 +453,13 @@ pub(crate) fn new() -> Self {
             frames_dropped: AtomicU64::new(0),
             shared_pipeline_owner,
             active_window,
+            #[cfg(target_os = "linux")]
+            frame_vsync,
             platform_clipboard: Arc::new(Mutex::new(None)),
+            app_handle: AppHandle::new(),
+            compositor: Mutex::new(compositor),
+            retained_scene: Mutex::new(None),
+            media_query: RootMediaQuerySource::default(),
             vsync_slot: Mutex::new(Vsync::new()),
             start: web_time::Instant::now(),
             #[cfg(test)]
@@ -477,6 +560,8 @@ fn wake_handle(&self) -> FrameWakeHandle {
         FrameWakeHandle {
             needs_redraw: Arc::clone(&self.needs_redraw),
             active_window: Arc::clone(&self.active_window),
+            #[cfg(target_os = "linux")]
+            frame_vsync: Arc::clone(&self.frame_vsync),
         }
     }
 
@@ -579,9 +664,11 @@ fn attach_root_widget_entered<V>(
     {
         // Auto-wrap: inject a VsyncScope carrying the binding's registry so
         // every implicitly-animated widget below can register its controller
-        // without any app-author boilerplate. VsyncScope is an InheritedView
-        // with no render object, so the render/hit-test root is unchanged.
-        let wrapped = VsyncScope::new(self.vsync(), view.clone());
+        // without any app-author boilerplate, an AppScope so any widget
+        // can reach `AppHandle::of(ctx)`, and the root MediaQuery carrying
+        // the window's metrics. None of them has a render object, so the
+        // render/hit-test root is unchanged.
+        let wrapped = self.wrap_root(view);
         let widgets = realm.widgets();
         widgets.attach_root_widget(&wrapped)?;
         self.initialized.store(true, Ordering::Relaxed);
@@ -590,6 +677,15 @@ fn attach_root_widget_entered<V>(
         Ok(())
     }
 
+    /// The scopes every attached root view is wrapped in.
+    fn wrap_root<V>(&self, view: &V) -> VsyncScope
+    where
+        V: View + Clone + 'static,
+    {
+        let root = RootMediaQuery::new(self.media_query.clone(), view.clone());
+        VsyncScope::new(self.vsync(), AppScope::new(self.app_handle(), root))
+    }
+
     /// Attach a root widget sizing the root view to an explicit logical
     /// `width` × `height` — the platform window's surface size.
     ///
@@ -628,8 +724,8 @@ fn attach_root_widget_with_size_entered<V>(
     where
         V: View + Clone + 'static,
     {
-        // Auto-wrap: same VsyncScope injection as attach_root_widget.
-        let wrapped = VsyncScope::new(self.vsync(), view.clone());
+        // Auto-wrap: same injection as attach_root_widget.
+        let wrapped = self.wrap_root(view);
         let widgets = realm.widgets();
         widgets.attach_root_widget_with_size(&wrapped, width, height)?;
         self.initialized.store(true, Ordering::Relaxed);
@@ -997,6 +1093,37 @@ pub(crate) fn platform_clipboard_slot(&self) -> Arc<Mutex<Option<Arc<dyn Clipboa
         Arc::clone(&self.platform_clipboard)
     }
 
+    /// A handle for registering shutdown hooks and quitting the app.
+    ///
+    /// Cloneable and `Send`: keep it in widget state or move it to a
+    /// background thread. See [`AppHandle`].
+    pub fn app_handle(&self) -> AppHandle {
+        self.app_handle.clone()
+    }
+
+    /// A handle an external texture producer (video decoder, camera) calls
+    /// per decoded frame. Each signal wakes the frame loop; the next frame
+    /// re-pushes the texture layers showing `texture_id`.
+    pub fn texture_frame_signal(&self, texture_id: TextureId) -> TextureFrameSignal {
+        self.compositor
+            .lock()
+            .texture_frames()
+            .frame_signal(texture_id)
+    }
+
+    /// Registers `callback` to run on the frame thread, once per frame in
+    /// which `texture_id` signaled, before the scene is built.
+    pub fn on_texture_frame_available(
+        &self,
+        texture_id: TextureId,
+        callback: impl FnMut(TextureId) + Send + 'static,
+    ) {
+        self.compositor
+            .lock()
+            .texture_frames_mut()
+            .on_frame_available(texture_id, callback);
+    }
+
     // ========================================================================
     // Frame Management
     // ========================================================================
@@ -1024,6 +1151,39 @@ pub fn wake_frame(&self) {
         self.wake_handle().wake_frame();
     }
 
+    /// Route [`wake_frame`](Self::wake_frame) through a winit-backed vsync
+    /// source pegged to `refresh_rate`.
+    ///
+    /// Wakes before the next redraw then coalesce into one
+    /// `PlatformWindow::request_redraw`. The runner reports each delivered
+    /// redraw through [`FrameVsync::handle_redraw`] and occlusion through
+    /// [`FrameVsync::set_occluded`]; while occluded, a redraw the compositor
+    /// never delivers is retired by the fallback timer so later wakes are
+    /// not swallowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`flui_scheduler::vsync::InvalidVsyncConfig`] for a zero
+    /// refresh rate; wakes keep going straight to the window.
+    #[cfg(target_os = "linux")]
+    pub(crate) fn install_frame_vsync(
+        &self,
+        refresh_rate: u32,
+    ) -> Result<Arc<FrameVsync>, flui_scheduler::vsync::InvalidVsyncConfig> {
+        let proxy = WindowRedrawProxy {
+            active_window: Arc::clone(&self.active_window),
+        };
+        let vsync = Arc::new(FrameVsync::try_new(proxy, refresh_rate)?);
+        *self.frame_vsync.lock() = Some(Arc::clone(&vsync));
+        Ok(vsync)
+    }
+
+    /// The vsync source installed by [`install_frame_vsync`](Self::install_frame_vsync).
+    #[cfg(target_os = "linux")]
+    pub(crate) fn frame_vsync(&self) -> Option<Arc<FrameVsync>> {
+        self.frame_vsync.lock().as_ref().cloned()
+    }
+
     /// Check if a redraw is needed.
     pub fn needs_redraw(&self) -> bool {
         self.needs_redraw.load(Ordering::Relaxed)
@@ -1098,7 +1258,8 @@ pub fn send_frames_to_engine(&self) -> bool {
     /// 3. Paint phase - generate display lists
     /// 4. Create Scene from LayerTree
     ///
-    /// Returns `Some(Scene)` if a new scene was produced. Returns `None` if
+    /// Returns `Some(Scene)` if a new scene was produced, or the retained
+    /// one re-composited for texture frames. Returns `None` if
     /// the frame was dropped (`FramePaintOutcome::Errored`) or nothing was
     /// dirty (`FramePaintOutcome::Idle`) — there is no scene cache to fall
     /// back to.
@@ -1109,7 +1270,9 @@ pub(crate) fn draw_frame(
         constraints: BoxConstraints,
     ) -> Option<Arc<Scene>> {
         match realm.enter(|realm| self.draw_frame_entered(realm, constraints)) {
-            FramePaintOutcome::Painted(scene) => Some(scene),
+            FramePaintOutcome::Painted(scene) | FramePaintOutcome::TextureFrame(scene) => {
+                Some(scene)
+            }
             FramePaintOutcome::Idle | FramePaintOutcome::Errored => None,
         }
     }
@@ -1172,6 +1335,22 @@ fn draw_frame_entered(
         // `Scheduler::drive_frame` polls no async tasks. Every frame driver goes
         // through `drive_frame`.
 
+        // The window's metrics feed the root MediaQuery before the build
+        // phase, so a resize rebuilds its dependents in this same frame.
+        {
+            let owner = self.shared_pipeline_owner.read();
+            let dpr = owner.device_pixel_ratio();
+            let logical = constraints.biggest();
+            let configuration = ViewConfiguration::new(
+                BoxConstraints::tight(Size::new(logical.width * dpr, logical.height * dpr)),
+                constraints,
+                dpr,
+            )
+            .with_text_scale_factor(owner.text_scale_factor());
+            drop(owner);
+            self.media_query.set_view_configuration(&configuration);
+        }
+
         // Phase 1: Build (WidgetsBinding)
         {
             let w = realm.widgets();
@@ -1266,6 +1445,11 @@ fn draw_frame_entered(
             // the binding thread is the sole reader of this `Arc<Scene>`,
             // so the lint is suppressed with an honest justification.
             let root = layer_tree.root();
+            // Runs the frame-available callbacks and marks the signaled
+            // texture layers for re-push before the scene captures the tree.
+            self.compositor.lock().flush_texture_frames(&layer_tree);
+            // Superseded by this frame's scene, retained once it is rendered.
+            *self.retained_scene.lock() = None;
             // `with_links` (not `Scene::new`, which always builds an empty
             // registry) — `link_registry` is this SAME frame's paint-phase
             // byproduct, letting the engine resolve `Layer::Follower`
@@ -1288,12 +1472,38 @@ fn draw_frame_entered(
             // above, not because nothing was dirty — the caller must retry
             // rather than treat this as a settled, up-to-date frame.
             FramePaintOutcome::Errored
+        } else if let Some(outcome) = self.recomposite_texture_frames() {
+            outcome
         } else {
             // No new layer tree, and no error: nothing was dirty this frame.
             FramePaintOutcome::Idle
         }
     }
 
+    /// Re-composites the retained scene for pending texture frames, with
+    /// only the signaled texture layers (and their ancestors) marked for
+    /// re-push. The render tree is not repainted. `None` when no texture signaled or no scene is
+    /// retained; the signals then wait for the next painted frame.
+    fn recomposite_texture_frames(&self) -> Option<FramePaintOutcome> {
+        let mut compositor = self.compositor.lock();
+        if !compositor.texture_frames().has_pending() {
+            return None;
+        }
+        let scene = self.retained_scene.lock().take()?;
+        if let Some(root) = scene.root() {
+            scene.layer_tree().clear_needs_add_to_scene_subtree(root);
+        }
+        compositor.flush_texture_frames(scene.layer_tree());
+        Some(FramePaintOutcome::TextureFrame(scene))
+    }
+
+    /// Keeps a rendered scene for [`recomposite_texture_frames`].
+    ///
+    /// [`recomposite_texture_frames`]: Self::recomposite_texture_frames
+    fn retain_scene(&self, scene: Arc<Scene>) {
+        *self.retained_scene.lock() = Some(scene);
+    }
+
     /// Render while the platform dispatcher already owns the realm entry.
     /// This keeps scheduler callbacks and the full build/layout/paint/raster
     /// transaction under one activation instead of creating a nested scope.
@@ -1379,16 +1589,18 @@ pub(crate) fn render_frame_entered<R: RasterBackend>(
         // gate already observes), not a concern of this retry flag.
         let mut retry_needed = errored;
         if send_to_engine
-            && let FramePaintOutcome::Painted(ref scene) = outcome
+            && let FramePaintOutcome::Painted(ref scene)
+            | FramePaintOutcome::TextureFrame(ref scene) = outcome
             && scene.has_content()
         {
-            // The pipeline painted a FRESH scene this frame, so the
-            // on-screen content is stale. The engine's damage tracker is
-            // only marked by resize/surface-create paths; without this
-            // mark, `render_scene` early-returns on "no damage" and every
-            // animation frame is silently dropped — the screen then only
-            // updates on resize. Until fine-grained damage from the layer
-            // diff lands, a new scene is a full repaint.
+            // The pipeline painted a FRESH scene this frame, or re-composited
+            // the retained one for texture frames, so the on-screen content is
+            // stale. The engine's damage tracker is only marked by
+            // resize/surface-create paths; without this mark, `render_scene`
+            // early-returns on "no damage" and every animation frame is
+            // silently dropped — the screen then only updates on resize. The
+            // engine cannot yet repaint just the texture rects (the swapchain
+            // is undefined after acquire), so every frame is a full repaint.
             renderer.mark_full_repaint();
             match renderer.render_scene(scene) {
                 Ok(did_present) => {
@@ -1430,10 +1642,11 @@ pub(crate) fn render_frame_entered<R: RasterBackend>(
                     // Surface misconfig (wgpu Validation). Drop this frame and
                     // log; reconfiguration is NOT automatic — it requires an
                     // external trigger (window resize / surface recreate).
-                    // `render_scene` only reconfigures in the Outdated/Lost arm,
-                    // so without such a trigger this would drop + error-log
-                    // every frame. We do not retry blindly: re-reconfiguring the
-                    // same bad config would re-validate and loop forever.
+                    // `render_scene` only reconfigures in the Outdated/Lost arm;
+                    // if no trigger arrives, consecutive failures escalate to
+                    // `DeviceLost` and the runner rebuilds the GPU stack. We do
+                    // not retry blindly: re-reconfiguring the same bad config
+                    // would re-validate and loop forever.
                     self.frames_dropped.fetch_add(1, Ordering::Relaxed);
                     tracing::error!(
                         "Surface validation error — surface misconfig; external reconfigure required"
@@ -1446,6 +1659,11 @@ pub(crate) fn render_frame_entered<R: RasterBackend>(
             }
         }
 
+        if let FramePaintOutcome::Painted(scene) | FramePaintOutcome::TextureFrame(scene) = outcome
+        {
+            self.retain_scene(scene);
+        }
+
         // 4. Mark rendered — unless this frame was dropped rather than
         // settled, in which case `wake_frame()` re-arms `needs_redraw` AND
         // schedules an actual platform wake (see `retry_needed` above).
@@ -1463,6 +1681,17 @@ pub(crate) fn has_pending_work(&self, realm: &super::ui_realm::UiRealm) -> bool
         realm.widgets().has_pending_builds() || self.shared_pipeline_owner.read().has_dirty_nodes()
     }
 
+    /// Handle a change of the platform's light/dark preference.
+    ///
+    /// Updates the root `MediaQuery`, so `MediaQuery::platform_brightness_of`
+    /// readers rebuild in the next frame.
+    ///
+    /// Flutter parity: `WidgetsBinding.handlePlatformBrightnessChanged`.
+    pub fn handle_platform_brightness_changed(&self, brightness: Brightness) {
+        self.media_query.set_platform_brightness(brightness);
+        self.request_redraw();
+    }
+
     // ========================================================================
     // Input Handling
     // ========================================================================
@@ -1727,6 +1956,160 @@ fn attach_root_widget_bootstraps_shared_render_tree() {
         );
     }
 
+    /// Widgets reach the app's [`AppHandle`] through their build context:
+    /// the binding wraps the root in an [`AppScope`].
+    #[test]
+    fn attached_root_reaches_the_app_handle_through_its_context() {
+        #[derive(Clone)]
+        struct ReadsAppHandle {
+            seen: Arc<Mutex<Option<AppHandle>>>,
+        }
+
+        impl flui_view::StatelessView for ReadsAppHandle {
+            fn build(&self, ctx: &dyn flui_view::BuildContext) -> impl flui_view::IntoView {
+                *self.seen.lock() = AppHandle::of(ctx);
+                LeafView
+            }
+        }
+
+        impl View for ReadsAppHandle {
+            fn create_element(&self) -> flui_view::element::ElementKind {
+                flui_view::element::ElementKind::stateless(self)
+            }
+        }
+
+        let app = AppBinding::new();
+        let realm = test_realm(&app);
+        let seen = Arc::new(Mutex::new(None));
+        realm
+            .enter(|realm| {
+                app.attach_root_widget(
+                    realm,
+                    &ReadsAppHandle {
+                        seen: Arc::clone(&seen),
+                    },
+                )
+            })
+            .expect("attach succeeds");
+        let _ = app.draw_frame(
+            &realm,
+            BoxConstraints::tight(flui_types::Size::new(px(200.0), px(100.0))),
+        );
+
+        let handle = seen
+            .lock()
+            .take()
+            .expect("AppHandle::of finds the AppScope");
+        handle.quit(4);
+        assert_eq!(
+            app.app_handle().exit_code(),
+            std::process::ExitCode::from(4)
+        );
+    }
+
+    /// The binding installs a root `MediaQuery` from the frame's view
+    /// configuration; resizing the window rebuilds its size dependents.
+    #[test]
+    fn window_resize_rebuilds_root_media_query_dependents() {
+        #[derive(Clone)]
+        struct ReadsSize {
+            seen: Arc<Mutex<Vec<flui_types::Size>>>,
+        }
+
+        impl flui_view::StatelessView for ReadsSize {
+            fn build(&self, ctx: &dyn flui_view::BuildContext) -> impl flui_view::IntoView {
+                self.seen
+                    .lock()
+                    .push(flui_widgets::MediaQuery::size_of(ctx));
+                LeafView
+            }
+        }
+
+        impl View for ReadsSize {
+            fn create_element(&self) -> flui_view::element::ElementKind {
+                flui_view::element::ElementKind::stateless(self)
+            }
+        }
+
+        let app = AppBinding::new();
+        let realm = test_realm(&app);
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        realm
+            .enter(|realm| {
+                app.attach_root_widget(
+                    realm,
+                    &ReadsSize {
+                        seen: Arc::clone(&seen),
+                    },
+                )
+            })
+            .expect("attach succeeds");
+
+        let small = flui_types::Size::new(px(200.0), px(100.0));
+        let large = flui_types::Size::new(px(640.0), px(480.0));
+        let _ = app.draw_frame(&realm, BoxConstraints::tight(small));
+        let _ = app.draw_frame(&realm, BoxConstraints::tight(small));
+        let _ = app.draw_frame(&realm, BoxConstraints::tight(large));
+
+        assert_eq!(
+            *seen.lock(),
+            vec![small, large],
+            "built once at the first size, rebuilt once by the resize"
+        );
+    }
+
+    /// A platform brightness change reaches the root `MediaQuery` and
+    /// rebuilds its brightness dependents.
+    #[test]
+    fn platform_brightness_change_rebuilds_root_media_query_dependents() {
+        #[derive(Clone)]
+        struct ReadsBrightness {
+            seen: Arc<Mutex<Vec<Brightness>>>,
+        }
+
+        impl flui_view::StatelessView for ReadsBrightness {
+            fn build(&self, ctx: &dyn flui_view::BuildContext) -> impl flui_view::IntoView {
+                self.seen
+                    .lock()
+                    .push(flui_widgets::MediaQuery::platform_brightness_of(ctx));
+                LeafView
+            }
+        }
+
+        impl View for ReadsBrightness {
+            fn create_element(&self) -> flui_view::element::ElementKind {
+                flui_view::element::ElementKind::stateless(self)
+            }
+        }
+
+        let app = AppBinding::new();
+        let realm = test_realm(&app);
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        realm
+            .enter(|realm| {
+                app.attach_root_widget(
+                    realm,
+                    &ReadsBrightness {
+                        seen: Arc::clone(&seen),
+                    },
+                )
+            })
+            .expect("attach succeeds");
+
+        let size = flui_types::Size::new(px(200.0), px(100.0));
+        let _ = app.draw_frame(&realm, BoxConstraints::tight(size));
+        app.handle_platform_brightness_changed(Brightness::Light);
+        let _ = app.draw_frame(&realm, BoxConstraints::tight(size));
+        app.handle_platform_brightness_changed(Brightness::Dark);
+        let _ = app.draw_frame(&realm, BoxConstraints::tight(size));
+
+        assert_eq!(
+            *seen.lock(),
+            vec![Brightness::Light, Brightness::Dark],
+            "an unchanged brightness does not rebuild; a change does"
+        );
+    }
+
     /// Root-hop parent-link regression: after a standard `AppBinding`
     /// bootstrap (`attach_root_widget` + a build/layout/paint `draw_frame`),
     /// the mounted leaf's render node must have a working parent link back
@@ -1840,6 +2223,163 @@ fn wake_frame_sets_needs_redraw_without_window() {
         );
     }
 
+    /// A texture producer signaling from its own thread must wake the frame
+    /// loop; otherwise a video frame sits in the registry until unrelated
+    /// input forces a redraw.
+    #[test]
+    fn texture_frame_signal_wakes_the_frame_loop() {
+        let binding = AppBinding::new();
+        binding.mark_rendered();
+        let signal = binding.texture_frame_signal(TextureId::new(4));
+
+        std::thread::spawn(move || signal.signal()).join().unwrap();
+
+        assert!(
+            binding.needs_redraw(),
+            "a texture frame signal must schedule a frame"
+        );
+    }
+
+    /// A texture frame signaled while the tree is settled must still yield a
+    /// painted frame, and that frame must flush the registry (running the
+    /// frame-available callbacks) before the scene is built.
+    #[test]
+    fn texture_frame_signal_is_flushed_by_the_next_frame() {
+        use std::sync::atomic::AtomicUsize;
+
+        let app = AppBinding::new();
+        let realm = test_realm(&app);
+        realm
+            .enter(|realm| app.attach_root_widget(realm, &LeafView))
+            .expect("attach succeeds");
+        let constraints = flui_rendering::constraints::BoxConstraints::tight(
+            flui_types::Size::new(px(200.0), px(100.0)),
+        );
+        // As `render_frame_entered` does once the scene is presented.
+        let scene = app.draw_frame(&realm, constraints).expect("first frame");
+        let painted_frame = scene.frame_number();
+        app.retain_scene(scene);
+        assert!(
+            app.draw_frame(&realm, constraints).is_none(),
+            "precondition: a settled tree goes idle"
+        );
+
+        let video = TextureId::new(4);
+        let frames = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&frames);
+        app.on_texture_frame_available(video, move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+        app.texture_frame_signal(video).signal();
+
+        let scene = app
+            .draw_frame(&realm, constraints)
+            .expect("a pending texture frame must produce a scene, not an idle frame");
+        assert_eq!(
+            scene.frame_number(),
+            painted_frame,
+            "the retained scene is re-composited"
+        );
+        assert_eq!(frames.load(Ordering::Relaxed), 1);
+        assert!(!app.compositor.lock().texture_frames().has_pending());
+    }
+
+    /// A texture signal re-composites the retained layer tree; it must not
+    /// schedule a render-tree repaint.
+    #[test]
+    fn texture_frame_signal_does_not_repaint_the_render_tree() {
+        let app = AppBinding::new();
+        let realm = test_realm(&app);
+        realm
+            .enter(|realm| app.attach_root_widget(realm, &LeafView))
+            .expect("attach succeeds");
+        let constraints = flui_rendering::constraints::BoxConstraints::tight(
+            flui_types::Size::new(px(200.0), px(100.0)),
+        );
+        let scene = app.draw_frame(&realm, constraints).expect("first frame");
+        app.retain_scene(scene);
+
+        let root = app
+            .shared_pipeline_owner
+            .read()
+            .root_id()
+            .expect("root is mounted");
+        let root_repaints = || {
+            app.shared_pipeline_owner
+                .read()
+                .render_tree()
+                .get(root)
+                .expect("root node")
+                .repaint_count()
+        };
+        let repaints_before = root_repaints();
+
+        app.texture_frame_signal(TextureId::new(4)).signal();
+        assert!(app.draw_frame(&realm, constraints).is_some());
+
+        let owner = app.shared_pipeline_owner.read();
+        assert!(
+            owner
+                .nodes_needing_paint()
+                .iter()
+                .all(|dirty| dirty.id != root),
+            "a texture signal must not put the root in the paint list"
+        );
+        drop(owner);
+        assert_eq!(
+            root_repaints(),
+            repaints_before,
+            "the root was not repainted"
+        );
+    }
+
+    /// A texture-only frame re-presents the retained scene — even while
+    /// another holder still shares it — with just the signaled texture's
+    /// rect damaged in its layer tree.
+    #[test]
+    fn texture_frame_damages_only_the_signaled_texture_rect() {
+        use flui_layer::{Layer, LayerTree, OffsetLayer, TextureLayer};
+        use flui_types::geometry::Rect;
+
+        let app = AppBinding::new();
+        let realm = test_realm(&app);
+        let constraints = flui_rendering::constraints::BoxConstraints::tight(
+            flui_types::Size::new(px(200.0), px(100.0)),
+        );
+
+        let video = TextureId::new(8);
+        let mut tree = LayerTree::new();
+        let root = tree.insert(Layer::Offset(OffsetLayer::from_xy(10.0, 20.0)));
+        tree.set_root(Some(root));
+        let texture = tree.insert(Layer::Texture(TextureLayer::new(
+            video,
+            Rect::from_xywh(px(0.0), px(0.0), px(64.0), px(36.0)),
+        )));
+        tree.add_child(root, texture);
+        #[expect(
+            clippy::arc_with_non_send_sync,
+            reason = "Scene: Send but !Sync; the test thread is the sole reader."
+        )]
+        let scene = Arc::new(Scene::new(
+            flui_types::Size::new(px(200.0), px(100.0)),
+            tree,
+            Some(root),
+            1,
+        ));
+        app.retain_scene(Arc::clone(&scene));
+
+        app.texture_frame_signal(video).signal();
+        let outcome = realm.enter(|realm| app.draw_frame_entered(realm, constraints));
+        let FramePaintOutcome::TextureFrame(presented) = outcome else {
+            panic!("a texture signal must re-present the shared retained scene");
+        };
+        assert!(Arc::ptr_eq(&presented, &scene));
+        assert_eq!(
+            presented.layer_tree().texture_frame_damage(),
+            vec![Rect::from_xywh(px(10.0), px(20.0), px(64.0), px(36.0))]
+        );
+    }
+
     /// `wake_frame` must call `PlatformWindow::request_redraw` when a window
     /// is installed, and must NOT acquire `widgets` or `inner`.
     ///
@@ -1904,6 +2444,65 @@ fn as_any(&self) -> &dyn std::any::Any {
         );
     }
 
+    /// With the winit vsync installed, wakes before the next redraw coalesce
+    /// into one `PlatformWindow::request_redraw`; the delivered redraw
+    /// reopens the gate for the next one.
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn installed_frame_vsync_coalesces_wakes_until_the_redraw_arrives() {
+        use std::sync::{
+            Arc,
+            atomic::{AtomicU32, Ordering},
+        };
+
+        use flui_platform::traits::PlatformWindow;
+        use flui_types::geometry::{DevicePixels, Pixels, Size, device_px, px};
+
+        struct CountingWindow {
+            redraw_count: Arc<AtomicU32>,
+        }
+
+        impl PlatformWindow for CountingWindow {
+            fn physical_size(&self) -> Size<DevicePixels> {
+                Size::new(device_px(800), device_px(600))
+            }
+            fn logical_size(&self) -> Size<Pixels> {
+                Size::new(px(800.0), px(600.0))
+            }
+            fn scale_factor(&self) -> f64 {
+                1.0
+            }
+            fn request_redraw(&self) {
+                self.redraw_count.fetch_add(1, Ordering::Relaxed);
+            }
+            fn is_focused(&self) -> bool {
+                false
+            }
+            fn is_visible(&self) -> bool {
+                true
+            }
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+
+        let redraw_count = Arc::new(AtomicU32::new(0));
+        let binding = AppBinding::new();
+        binding.set_window(Box::new(CountingWindow {
+            redraw_count: Arc::clone(&redraw_count),
+        }));
+        let vsync = binding.install_frame_vsync(60).expect("60 Hz is valid");
+
+        binding.wake_frame();
+        binding.wake_frame();
+        binding.wake_frame();
+        assert_eq!(redraw_count.load(Ordering::Relaxed), 1);
+
+        assert!(vsync.handle_redraw());
+        binding.wake_frame();
+        assert_eq!(redraw_count.load(Ordering::Relaxed), 2);
+    }
+
     #[test]
     fn input_dispatches_through_the_exposed_gesture_binding() {
         use std::sync::atomic::{AtomicBool, Ordering};
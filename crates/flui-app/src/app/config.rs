@@ -2,7 +2,10 @@
 
 use std::path::PathBuf;
 
-use flui_types::{Size, geometry::px};
+use flui_types::{
+    Size,
+    geometry::{Point, px},
+};
 
 /// Application configuration.
 ///
@@ -37,16 +40,13 @@ pub struct AppConfig {
     /// Whether to show the window decorations.
     pub decorations: bool,
 
-    /// Whether to start in fullscreen mode.
-    ///
-    /// Not currently wired: `From<&AppConfig> for flui_platform::WindowOptions`
-    /// has no fullscreen field to carry this into, and nothing calls
-    /// `PlatformWindow::toggle_fullscreen` at startup to honor it. The intended
-    /// consumer is the desktop bootstrap in `runner.rs`, once `WindowOptions`
-    /// (or a post-creation `toggle_fullscreen` call keyed off this field)
-    /// grows the plumbing end to end.
+    /// Whether to start in (borderless) fullscreen mode.
     pub fullscreen: bool,
 
+    /// Initial outer window position in logical pixels; `None` lets the
+    /// window manager place the window.
+    pub position: Option<(i32, i32)>,
+
     /// Whether to enable vsync.
     ///
     /// Not currently wired: `From<&AppConfig> for flui_platform::WindowOptions`
@@ -111,6 +111,7 @@ fn default() -> Self {
             resizable: true,
             decorations: true,
             fullscreen: false,
+            position: None,
             vsync: true,
             target_fps: 60,
             show_performance_overlay: false,
@@ -168,6 +169,13 @@ pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
         self
     }
 
+    /// Set the initial window position, or `None` to let the window manager
+    /// place the window.
+    pub fn with_position(mut self, position: Option<(i32, i32)>) -> Self {
+        self.position = position;
+        self
+    }
+
     /// Set whether to enable vsync.
     pub fn with_vsync(mut self, vsync: bool) -> Self {
         self.vsync = vsync;
@@ -198,18 +206,62 @@ pub fn with_worker_plugin_path(mut self, path: impl Into<PathBuf>) -> Self {
         self.worker_plugin_path = Some(path.into());
         self
     }
+
+    /// Resolve `(size, min_size, max_size)` so that `min <= size <= max`
+    /// holds on both axes.
+    ///
+    /// A maximum below the minimum is raised to the minimum, and the initial
+    /// size is then clamped into the range — the builders accept any values,
+    /// and handing contradictory bounds to the window system gives
+    /// platform-dependent results.
+    pub fn window_size_bounds(&self) -> (Size, Option<Size>, Option<Size>) {
+        let min_size = self.min_size;
+        let max_size = match (min_size, self.max_size) {
+            (Some(min), Some(max)) => Some(Size::new(
+                max.width.max(min.width),
+                max.height.max(min.height),
+            )),
+            (_, max) => max,
+        };
+
+        let mut size = self.size;
+        if let Some(min) = min_size {
+            size = Size::new(size.width.max(min.width), size.height.max(min.height));
+        }
+        if let Some(max) = max_size {
+            size = Size::new(size.width.min(max.width), size.height.min(max.height));
+        }
+
+        if size != self.size || max_size != self.max_size {
+            tracing::warn!(
+                requested_size = ?self.size,
+                min_size = ?self.min_size,
+                requested_max_size = ?self.max_size,
+                size = ?size,
+                max_size = ?max_size,
+                "Inconsistent window size bounds; clamped"
+            );
+        }
+
+        (size, min_size, max_size)
+    }
 }
 
 impl From<&AppConfig> for flui_platform::WindowOptions {
     fn from(config: &AppConfig) -> Self {
+        let (size, min_size, max_size) = config.window_size_bounds();
         flui_platform::WindowOptions {
             title: config.title.clone(),
-            size: config.size,
+            size,
             resizable: config.resizable,
             visible: true,
             decorated: config.decorations,
-            min_size: config.min_size,
-            max_size: config.max_size,
+            min_size,
+            max_size,
+            position: config
+                .position
+                .map(|(x, y)| Point::new(px(x as f32), px(y as f32))),
+            fullscreen: config.fullscreen,
         }
     }
 }
@@ -249,4 +301,83 @@ fn test_worker_plugin_path() {
             Some(PathBuf::from("target/debug/libworker.so"))
         );
     }
+
+    #[test]
+    fn test_window_options_mapping() {
+        let config = AppConfig::new()
+            .with_title("Editor")
+            .with_size(1024, 768)
+            .with_min_size(640, 480)
+            .with_max_size(1920, 1080)
+            .with_resizable(false)
+            .with_decorations(false)
+            .with_fullscreen(true)
+            .with_position(Some((40, -20)));
+
+        let options = flui_platform::WindowOptions::from(&config);
+
+        assert_eq!(options.title, "Editor");
+        assert_eq!(options.size, Size::new(px(1024.0), px(768.0)));
+        assert_eq!(options.min_size, Some(Size::new(px(640.0), px(480.0))));
+        assert_eq!(options.max_size, Some(Size::new(px(1920.0), px(1080.0))));
+        assert_eq!(options.position, Some(Point::new(px(40.0), px(-20.0))));
+        assert!(options.fullscreen);
+        assert!(!options.resizable);
+        assert!(!options.decorated);
+
+        let options = flui_platform::WindowOptions::from(&AppConfig::new());
+        assert_eq!(options.position, None);
+        assert!(!options.fullscreen);
+    }
+
+    #[test]
+    fn test_window_options_open_a_matching_window() {
+        let platform = flui_platform::headless_platform();
+        let config = AppConfig::new()
+            .with_size(1024, 768)
+            .with_fullscreen(true)
+            .with_position(Some((100, 50)));
+
+        let window = platform
+            .open_window((&config).into())
+            .expect("headless windows always open");
+
+        assert!(window.is_fullscreen());
+        let bounds = window.bounds();
+        assert_eq!(bounds.origin, Point::new(px(100.0), px(50.0)));
+        assert_eq!(bounds.size, Size::new(px(1024.0), px(768.0)));
+    }
+
+    #[test]
+    fn test_window_size_clamped_into_bounds() {
+        let config = AppConfig::new()
+            .with_size(300, 2000)
+            .with_min_size(400, 300)
+            .with_max_size(1600, 1200);
+
+        let (size, min, max) = config.window_size_bounds();
+        assert_eq!(size, Size::new(px(400.0), px(1200.0)));
+        assert_eq!(min, config.min_size);
+        assert_eq!(max, config.max_size);
+
+        let unbounded = AppConfig::new().with_size(300, 2000);
+        assert_eq!(unbounded.window_size_bounds().0, unbounded.size);
+    }
+
+    #[test]
+    fn test_window_max_below_min_is_raised() {
+        let config = AppConfig::new()
+            .with_size(800, 600)
+            .with_min_size(1000, 200)
+            .with_max_size(500, 400);
+
+        let (size, min, max) = config.window_size_bounds();
+        assert_eq!(min, Some(Size::new(px(1000.0), px(200.0))));
+        assert_eq!(max, Some(Size::new(px(1000.0), px(400.0))));
+        assert_eq!(size, Size::new(px(1000.0), px(400.0)));
+
+        let options = flui_platform::WindowOptions::from(&config);
+        assert_eq!(options.size, size);
+        assert_eq!(options.max_size, max);
+    }
 }
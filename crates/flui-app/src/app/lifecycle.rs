@@ -0,0 +1,316 @@
+//! Application shutdown: [`AppHandle`] and shutdown hooks.
+//!
+//! An app registers [`on_shutdown`](AppHandle::on_shutdown) hooks to flush
+//! state (save documents, write settings, close connections) before the
+//! process exits. The hooks run exactly once, on whichever comes first:
+//!
+//! - the window closing (the user clicked the close button),
+//! - [`AppHandle::quit`] (a menu item, a keyboard shortcut, a background
+//!   task deciding the app is done),
+//! - the platform quitting the event loop for any other reason.
+//!
+//! Widgets reach the handle through their build context with
+//! [`AppHandle::of`]; the binding provides it above the root view via
+//! [`AppScope`].
+//!
+//! `run_app` / `run_app_with_config` then return the [`ExitCode`] passed to
+//! `quit`, or [`ExitCode::SUCCESS`] for a plain window close:
+//!
+//! ```rust,ignore
+//! use flui_app::{AppBinding, run_app};
+//!
+//! fn main() -> std::process::ExitCode {
+//!     let app = AppBinding::instance().app_handle();
+//!     app.on_shutdown(|| save_session());
+//!     run_app(MyApp { app })
+//! }
+//! ```
+
+use std::fmt;
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use flui_platform::QuitRequester;
+use flui_view::{
+    BoxedView, BuildContext, BuildContextExt, InheritedView, IntoView, View, impl_inherited_view,
+};
+use parking_lot::Mutex;
+
+type ShutdownHook = Box<dyn FnOnce() + Send>;
+
+/// Cloneable, `Send` handle for ending the application.
+///
+/// Obtained from [`AppBinding::app_handle`](super::AppBinding::app_handle),
+/// or inside the widget tree from [`AppHandle::of`]; every clone controls
+/// the same application. Safe to keep in widget state
+/// and to move onto background threads.
+#[derive(Clone)]
+pub struct AppHandle {
+    inner: Arc<AppHandleInner>,
+}
+
+struct AppHandleInner {
+    /// Pending hooks in registration order. Its lock also orders hook
+    /// registration against `shut_down` flipping.
+    hooks: Mutex<Vec<ShutdownHook>>,
+
+    /// Set once the hooks have been taken for running.
+    shut_down: AtomicBool,
+
+    /// The code of the first [`AppHandle::quit`] call.
+    exit_code: Mutex<Option<u8>>,
+
+    /// Installed by the runner once the platform is up; cleared when the
+    /// event loop exits.
+    quit_requester: Mutex<Option<QuitRequester>>,
+}
+
+impl AppHandle {
+    pub(crate) fn new() -> Self {
+        Self {
+            inner: Arc::new(AppHandleInner {
+                hooks: Mutex::new(Vec::new()),
+                shut_down: AtomicBool::new(false),
+                exit_code: Mutex::new(None),
+                quit_requester: Mutex::new(None),
+            }),
+        }
+    }
+
+    /// The handle of the application `ctx` belongs to, provided by the
+    /// [`AppScope`] the binding wraps the root view in.
+    ///
+    /// `None` for a tree mounted without the app binding (e.g. a bare
+    /// test harness). Registers no dependency: the handle never changes.
+    pub fn of(ctx: &dyn BuildContext) -> Option<Self> {
+        ctx.get::<AppScope, _>(|scope| scope.handle.clone())
+    }
+
+    /// Register a hook to run once when the application shuts down.
+    ///
+    /// Hooks run in registration order, on the thread that triggered the
+    /// shutdown — the event-loop thread for a window close, the caller's
+    /// thread for [`quit`](Self::quit). A hook registered after shutdown
+    /// has begun runs immediately instead of being lost.
+    pub fn on_shutdown(&self, hook: impl FnOnce() + Send + 'static) {
+        {
+            let mut hooks = self.inner.hooks.lock();
+            if !self.inner.shut_down.load(Ordering::Acquire) {
+                hooks.push(Box::new(hook));
+                return;
+            }
+        }
+        tracing::debug!("AppHandle::on_shutdown after shutdown; running the hook now");
+        hook();
+    }
+
+    /// Run the shutdown hooks and ask the platform to exit with `code`.
+    ///
+    /// Only the first call's code is kept; later calls are no-ops. Callable
+    /// from any thread.
+    pub fn quit(&self, code: u8) {
+        {
+            let mut exit_code = self.inner.exit_code.lock();
+            if exit_code.is_some() {
+                tracing::debug!(code, "AppHandle::quit: already quitting, ignored");
+                return;
+            }
+            *exit_code = Some(code);
+        }
+        tracing::info!(code, "Application quit requested");
+
+        self.shutdown();
+
+        let requester = self.inner.quit_requester.lock().clone();
+        if let Some(request_quit) = requester {
+            request_quit();
+        } else {
+            tracing::warn!(
+                "AppHandle::quit: the platform cannot be asked to quit (not running, or no \
+                 quit support on this backend); shutdown hooks ran, the event loop keeps going"
+            );
+        }
+    }
+
+    /// Whether shutdown has begun (the hooks have run or are running).
+    pub fn is_shutting_down(&self) -> bool {
+        self.inner.shut_down.load(Ordering::Acquire)
+    }
+
+    /// The process exit code: the one passed to [`quit`](Self::quit), or
+    /// [`ExitCode::SUCCESS`] when the app ended any other way.
+    pub fn exit_code(&self) -> ExitCode {
+        self.inner
+            .exit_code
+            .lock()
+            .map_or(ExitCode::SUCCESS, ExitCode::from)
+    }
+
+    /// Install (or, with `None`, clear) the platform's quit capability.
+    pub(crate) fn set_quit_requester(&self, requester: Option<QuitRequester>) {
+        *self.inner.quit_requester.lock() = requester;
+    }
+
+    /// Run the pending hooks; every call after the first is a no-op.
+    pub(crate) fn shutdown(&self) {
+        let hooks = {
+            let mut hooks = self.inner.hooks.lock();
+            if self.inner.shut_down.swap(true, Ordering::AcqRel) {
+                return;
+            }
+            std::mem::take(&mut *hooks)
+        };
+        tracing::info!(hooks = hooks.len(), "Running shutdown hooks");
+        for hook in hooks {
+            hook();
+        }
+    }
+
+    /// Run the hooks when `window` closes.
+    pub(crate) fn shutdown_on_close(&self, window: &dyn flui_platform::PlatformWindow) {
+        let handle = self.clone();
+        window.on_close(Box::new(move || {
+            tracing::info!("Window closed");
+            handle.shutdown();
+        }));
+    }
+}
+
+impl fmt::Debug for AppHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AppHandle")
+            .field("hooks", &self.inner.hooks.lock().len())
+            .field("shutting_down", &self.is_shutting_down())
+            .field("exit_code", &*self.inner.exit_code.lock())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Provides the application's [`AppHandle`] to every view below it.
+///
+/// The binding wraps the root view in one when attaching it; read it with
+/// [`AppHandle::of`]. The handle is fixed for the app's lifetime, so
+/// [`update_should_notify`](InheritedView::update_should_notify) is always
+/// `false`.
+#[derive(Clone)]
+pub struct AppScope {
+    handle: AppHandle,
+    child: BoxedView,
+}
+
+impl AppScope {
+    /// Wrap `child` in a scope that provides `handle` to its descendants.
+    #[must_use]
+    pub fn new(handle: AppHandle, child: impl IntoView) -> Self {
+        Self {
+            handle,
+            child: BoxedView(Box::new(child.into_view())),
+        }
+    }
+}
+
+impl fmt::Debug for AppScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AppScope")
+            .field("handle", &self.handle)
+            .finish_non_exhaustive()
+    }
+}
+
+impl InheritedView for AppScope {
+    type Data = AppHandle;
+
+    fn data(&self) -> &Self::Data {
+        &self.handle
+    }
+
+    fn child(&self) -> &dyn View {
+        &self.child
+    }
+
+    fn update_should_notify(&self, _old: &Self) -> bool {
+        false
+    }
+}
+
+impl_inherited_view!(AppScope);
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use flui_platform::WindowOptions;
+
+    use super::*;
+
+    fn counting_hook(handle: &AppHandle) -> Arc<AtomicUsize> {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&runs);
+        handle.on_shutdown(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        runs
+    }
+
+    #[test]
+    fn window_close_runs_hooks_exactly_once() {
+        let platform = flui_platform::headless_platform();
+        let window = platform
+            .open_window(WindowOptions::default())
+            .expect("headless windows always open");
+        let handle = AppHandle::new();
+        let runs = counting_hook(&handle);
+        handle.shutdown_on_close(window.as_ref());
+
+        window.close();
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert!(handle.is_shutting_down());
+
+        // A second close event, then the runner's post-loop shutdown.
+        window.close();
+        handle.shutdown();
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert_eq!(handle.exit_code(), ExitCode::SUCCESS);
+    }
+
+    #[test]
+    fn quit_runs_hooks_in_order_then_requests_platform_quit() {
+        let handle = AppHandle::new();
+        let order = Arc::new(Mutex::new(Vec::new()));
+        for name in ["flush", "close"] {
+            let order = Arc::clone(&order);
+            handle.on_shutdown(move || order.lock().push(name));
+        }
+        let requester_order = Arc::clone(&order);
+        handle.set_quit_requester(Some(Arc::new(move || {
+            requester_order.lock().push("quit");
+        })));
+
+        let remote = handle.clone();
+        std::thread::spawn(move || remote.quit(3)).join().unwrap();
+        handle.quit(7);
+
+        assert_eq!(*order.lock(), ["flush", "close", "quit"]);
+        assert_eq!(handle.exit_code(), ExitCode::from(3));
+    }
+
+    #[test]
+    fn hook_registered_after_shutdown_runs_immediately() {
+        let handle = AppHandle::new();
+        handle.shutdown();
+
+        let runs = counting_hook(&handle);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn quit_without_a_platform_still_runs_hooks() {
+        let handle = AppHandle::new();
+        let runs = counting_hook(&handle);
+
+        handle.quit(1);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert_eq!(handle.exit_code(), ExitCode::from(1));
+    }
+}
@@ -0,0 +1,154 @@
+//! The root [`MediaQuery`] the binding installs above the app's view.
+//!
+//! [`RootMediaQuery`] is a stateful wrapper that rebuilds into a
+//! [`MediaQuery`] carrying the window's current [`MediaQueryData`]. The
+//! binding keeps the matching [`RootMediaQuerySource`] and feeds it the
+//! window's [`ViewConfiguration`] at the start of every frame; a change
+//! schedules the wrapper's rebuild through its [`RebuildHandle`], so a
+//! resize reaches `MediaQuery::size_of` readers in the same frame. The
+//! platform's light/dark preference arrives separately, through
+//! [`RootMediaQuerySource::set_platform_brightness`].
+//!
+//! Flutter parity: the `MediaQuery.fromView` that `View` inserts above
+//! `runApp`'s widget.
+
+use std::fmt;
+use std::sync::Arc;
+
+use flui_rendering::view::ViewConfiguration;
+use flui_view::element::ElementKind;
+use flui_view::{BoxedView, BuildContext, IntoView, RebuildHandle, StatefulView, View, ViewState};
+use flui_widgets::{Brightness, MediaQuery, MediaQueryData};
+use parking_lot::Mutex;
+
+/// Shared between the binding and the mounted [`RootMediaQuery`] state.
+#[derive(Clone, Default)]
+pub(crate) struct RootMediaQuerySource {
+    inner: Arc<Mutex<SourceInner>>,
+}
+
+#[derive(Default)]
+struct SourceInner {
+    data: MediaQueryData,
+    /// Set while a [`RootMediaQuery`] is mounted.
+    rebuild: Option<RebuildHandle>,
+}
+
+impl RootMediaQuerySource {
+    /// The data the root `MediaQuery` currently provides.
+    pub(crate) fn data(&self) -> MediaQueryData {
+        self.inner.lock().data.clone()
+    }
+
+    /// Update the window-derived fields from `configuration`, scheduling a
+    /// rebuild of the root `MediaQuery` when any of them changed.
+    ///
+    /// Fields a [`ViewConfiguration`] does not carry (padding, insets,
+    /// brightness) keep their current values.
+    pub(crate) fn set_view_configuration(&self, configuration: &ViewConfiguration) {
+        let from_view = MediaQueryData::from_view_configuration(configuration);
+        let mut inner = self.inner.lock();
+        let mut data = inner.data.clone();
+        data.size = from_view.size;
+        data.device_pixel_ratio = from_view.device_pixel_ratio;
+        data.text_scale_factor = from_view.text_scale_factor;
+        if data == inner.data {
+            return;
+        }
+        inner.data = data;
+        if let Some(rebuild) = &inner.rebuild {
+            rebuild.schedule();
+        }
+    }
+
+    /// Update the platform brightness, scheduling a rebuild of the root
+    /// `MediaQuery` when it changed.
+    pub(crate) fn set_platform_brightness(&self, brightness: Brightness) {
+        let mut inner = self.inner.lock();
+        if inner.data.platform_brightness == brightness {
+            return;
+        }
+        inner.data.platform_brightness = brightness;
+        if let Some(rebuild) = &inner.rebuild {
+            rebuild.schedule();
+        }
+    }
+
+    fn attach(&self, rebuild: RebuildHandle) {
+        self.inner.lock().rebuild = Some(rebuild);
+    }
+
+    fn detach(&self) {
+        self.inner.lock().rebuild = None;
+    }
+}
+
+impl fmt::Debug for RootMediaQuerySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inner = self.inner.lock();
+        f.debug_struct("RootMediaQuerySource")
+            .field("data", &inner.data)
+            .field("mounted", &inner.rebuild.is_some())
+            .finish()
+    }
+}
+
+/// Provides the window's [`MediaQueryData`] from `source` to `child`.
+#[derive(Clone)]
+pub(crate) struct RootMediaQuery {
+    source: RootMediaQuerySource,
+    child: BoxedView,
+}
+
+impl RootMediaQuery {
+    pub(crate) fn new(source: RootMediaQuerySource, child: impl IntoView) -> Self {
+        Self {
+            source,
+            child: BoxedView(Box::new(child.into_view())),
+        }
+    }
+}
+
+impl fmt::Debug for RootMediaQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RootMediaQuery")
+            .field("source", &self.source)
+            .finish_non_exhaustive()
+    }
+}
+
+impl StatefulView for RootMediaQuery {
+    type State = RootMediaQueryState;
+
+    fn create_state(&self) -> Self::State {
+        RootMediaQueryState {
+            source: self.source.clone(),
+        }
+    }
+}
+
+impl View for RootMediaQuery {
+    fn create_element(&self) -> ElementKind {
+        ElementKind::stateful(self)
+    }
+}
+
+/// State of [`RootMediaQuery`]: registers its rebuild handle with the source.
+#[derive(Debug)]
+pub(crate) struct RootMediaQueryState {
+    source: RootMediaQuerySource,
+}
+
+impl ViewState<RootMediaQuery> for RootMediaQueryState {
+    fn init_state(&mut self, ctx: &dyn BuildContext) {
+        self.source.attach(ctx.rebuild_handle());
+    }
+
+    fn build(&self, view: &RootMediaQuery, _ctx: &dyn BuildContext) -> impl IntoView {
+        MediaQuery::new(self.source.data(), view.child.clone())
+    }
+
+    fn dispose(&mut self) {
+        self.source.detach();
+    }
+}
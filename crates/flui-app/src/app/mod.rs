@@ -12,12 +12,15 @@
 mod binding;
 mod config;
 pub mod direct;
+mod lifecycle;
+mod media_query;
 pub mod runner;
 pub(crate) mod ui_realm;
 
 pub use binding::AppBinding;
 pub use config::AppConfig;
 pub use direct::run_direct;
+pub use lifecycle::{AppHandle, AppScope};
 #[cfg(target_os = "android")]
 pub use runner::{run_app_android, run_app_android_with_config};
 pub use runner::{run_app_impl as run_app, run_app_with_config_impl as run_app_with_config};
@@ -3,6 +3,8 @@
 //! This module provides platform-agnostic entry points that delegate
 //! to platform-specific implementations via flui-platform.
 
+use std::process::ExitCode;
+
 use flui_view::{StatelessView, View};
 
 use super::{AppBinding, AppConfig};
@@ -15,17 +17,22 @@
 /// Run a FLUI application with default configuration.
 ///
 /// This is the internal implementation called by `run_app()`.
-pub fn run_app_impl<V>(root: V)
+///
+/// Returns the code passed to [`AppHandle::quit`](super::AppHandle::quit),
+/// or [`ExitCode::SUCCESS`] when the app ended any other way.
+pub fn run_app_impl<V>(root: V) -> ExitCode
 where
     V: View + StatelessView + Clone + 'static,
 {
-    run_app_with_config_impl(root, AppConfig::default());
+    run_app_with_config_impl(root, AppConfig::default())
 }
 
 /// Run a FLUI application with custom configuration.
 ///
 /// This is the internal implementation called by `run_app_with_config()`.
-pub fn run_app_with_config_impl<V>(root: V, config: AppConfig)
+///
+/// Returns the app's exit code; see [`run_app_impl`].
+pub fn run_app_with_config_impl<V>(root: V, config: AppConfig) -> ExitCode
 where
     V: View + StatelessView + Clone + 'static,
 {
@@ -50,7 +57,7 @@ pub fn run_app_with_config_impl<V>(root: V, config: AppConfig)
         not(target_arch = "wasm32")
     ))]
     {
-        run_desktop(root, config);
+        run_desktop(root, config)
     }
 
     #[cfg(target_os = "android")]
@@ -65,11 +72,13 @@ pub fn run_app_with_config_impl<V>(root: V, config: AppConfig)
     #[cfg(target_os = "ios")]
     {
         run_ios(config);
+        ExitCode::SUCCESS
     }
 
     #[cfg(target_arch = "wasm32")]
     {
         run_web(root, config);
+        AppBinding::instance().app_handle().exit_code()
     }
 }
 
@@ -178,6 +187,11 @@ enum RealmEvent {
     // its `on_active_status_change` registration).
     #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
     WindowVisibility(bool),
+    /// The platform's light/dark preference changed
+    /// (`WindowEvent::PlatformBrightnessChanged`).
+    // Only the desktop runner subscribes to platform window events.
+    #[cfg_attr(any(target_arch = "wasm32", target_os = "android"), allow(dead_code))]
+    PlatformBrightness(flui_types::platform::Brightness),
     Frame(Box<dyn FnOnce(&super::ui_realm::UiRealm)>),
 }
 
@@ -216,6 +230,9 @@ fn run(self, realm: &super::ui_realm::UiRealm) {
                 });
                 emit_lifecycle_transition(realm, old, new);
             }
+            Self::PlatformBrightness(brightness) => {
+                AppBinding::instance().handle_platform_brightness_changed(brightness);
+            }
             Self::Frame(run) => run(realm),
         }
     }
@@ -1340,7 +1357,7 @@ fn no_present_fallback_bounds_repeating_no_present_wakes() {
     not(target_os = "ios"),
     not(target_arch = "wasm32")
 ))]
-fn run_desktop<V>(root: V, config: AppConfig)
+fn run_desktop<V>(root: V, config: AppConfig) -> ExitCode
 where
     V: View + StatelessView + Clone + 'static,
 {
@@ -1431,6 +1448,8 @@ fn bootstrap_desktop<V>(
         // need, just an early call on a reference that stays valid for the
         // rest of this function.
         AppBinding::instance().set_platform_clipboard(platform.clipboard());
+        let app_handle = AppBinding::instance().app_handle();
+        app_handle.set_quit_requester(platform.quit_requester());
 
         // 1. Open window now that the event loop is running. Window creation is
         // an environment failure (display server hiccup, resource exhaustion),
@@ -1476,6 +1495,10 @@ fn bootstrap_desktop<V>(
         AppBinding::instance()
             .render_pipeline_mut()
             .set_device_pixel_ratio(scale_factor);
+        // Seed the platform brightness the same way, so an app launched in
+        // dark mode builds dark; later changes arrive as
+        // `PlatformBrightnessChanged` (step 8).
+        AppBinding::instance().handle_platform_brightness_changed(window.appearance().brightness());
         let ui_realm =
             match super::ui_realm::UiRealm::new(AppBinding::instance().frame_wake_callback()) {
                 Ok(realm) => realm,
@@ -1571,6 +1594,13 @@ fn bootstrap_desktop<V>(
         let renderer_frame = Arc::clone(&renderer);
         let worker_driver_frame = Arc::clone(&worker_driver);
         window.on_request_frame(Box::new(move || {
+        // Retire the pending vsync request first, so a wake raised while this
+        // frame runs asks for the next redraw instead of being coalesced
+        // into this one.
+        #[cfg(target_os = "linux")]
+        if let Some(vsync) = AppBinding::instance().frame_vsync() {
+            vsync.handle_redraw();
+        }
         let renderer_frame = Arc::clone(&renderer_frame);
         let worker_driver_frame = Arc::clone(&worker_driver_frame);
         let _ = dispatch_platform_realm(realm_dispatch, RealmEvent::Frame(Box::new(move |realm| {
@@ -1751,11 +1781,10 @@ fn bootstrap_desktop<V>(
             Scheduler::instance().handle_app_lifecycle_state_change(AppLifecycleState::Detached);
         }));
 
-        // Window close -> log and let the platform handle quit
-        // (Windows window proc already calls PostQuitMessage on WM_DESTROY)
-        window.on_close(Box::new(move || {
-            tracing::info!("Window closed");
-        }));
+        // Window close -> run the shutdown hooks and let the platform handle
+        // quit (Windows window proc already calls PostQuitMessage on
+        // WM_DESTROY)
+        app_handle.shutdown_on_close(window.as_ref());
 
         // Window should-close -> allow by default
         window.on_should_close(Box::new(|| {
@@ -1773,9 +1802,48 @@ fn bootstrap_desktop<V>(
             let _ = dispatch_platform_realm(realm_dispatch, RealmEvent::WindowFocus(focused));
         }));
         window.on_visibility_status_change(Box::new(move |visible| {
+            #[cfg(target_os = "linux")]
+            if let Some(vsync) = AppBinding::instance().frame_vsync() {
+                vsync.set_occluded(!visible);
+            }
             let _ = dispatch_platform_realm(realm_dispatch, RealmEvent::WindowVisibility(visible));
         }));
 
+        // OS light/dark preference -> the root MediaQuery's platform brightness.
+        platform.on_window_event(Box::new(move |event| {
+            if let flui_platform::traits::WindowEvent::PlatformBrightnessChanged {
+                brightness,
+                ..
+            } = event
+            {
+                let _ = dispatch_platform_realm(
+                    realm_dispatch,
+                    RealmEvent::PlatformBrightness(brightness),
+                );
+            }
+        }));
+
+        // Pace wakes through winit redraws (see `AppBinding::install_frame_vsync`),
+        // pegged to the refresh rate of the display the window opened on.
+        #[cfg(target_os = "linux")]
+        {
+            let refresh_rate = window
+                .display()
+                .map_or(60.0, |display| display.refresh_rate())
+                .round() as u32;
+            match AppBinding::instance().install_frame_vsync(refresh_rate) {
+                Ok(vsync) => {
+                    if let Err(error) = vsync.spawn_fallback_timer() {
+                        tracing::warn!(%error, "vsync fallback timer unavailable");
+                    }
+                    tracing::debug!(refresh_rate, "winit vsync installed");
+                }
+                Err(error) => {
+                    tracing::warn!(%error, refresh_rate, "winit vsync not installed");
+                }
+            }
+        }
+
         // 9. Store window in AppBinding for runtime access — BEFORE
         // marking the lifecycle Resumed or requesting the initial redraw.
         // Both of those can synchronously run the first frame through
@@ -1823,9 +1891,16 @@ fn bootstrap_desktop<V>(
         );
     }));
 
-    // Event loop exited: drop the runtime now (releases the at-most-one
-    // claim; outstanding senders turn `OwnerGone`) instead of at thread
-    // death.
+    // Event loop exited. Hooks have normally run already (window close or
+    // `AppHandle::quit`); this covers every other way out of the loop, and
+    // is a no-op otherwise. Not run from `on_quit`: that fires under the
+    // platform's state lock, where a hook touching the platform deadlocks.
+    let app_handle = AppBinding::instance().app_handle();
+    app_handle.set_quit_requester(None);
+    app_handle.shutdown();
+
+    // Drop the runtime now (releases the at-most-one claim; outstanding
+    // senders turn `OwnerGone`) instead of at thread death.
     drop(rebuild_registration.borrow_mut().take());
     teardown_platform_realm();
 
@@ -1837,6 +1912,8 @@ fn bootstrap_desktop<V>(
     if let Some(err) = bootstrap_error.borrow_mut().take() {
         panic!("desktop bootstrap failed: {err:?}");
     }
+
+    app_handle.exit_code()
 }
 
 // ============================================================================
@@ -2265,6 +2342,8 @@ fn run_web<V>(root: V, config: AppConfig)
     AppBinding::instance()
         .render_pipeline_mut()
         .set_device_pixel_ratio(scale_factor);
+    // The browser's `prefers-color-scheme` at startup.
+    AppBinding::instance().handle_platform_brightness_changed(window.appearance().brightness());
     let ui_realm = match super::ui_realm::UiRealm::new(AppBinding::instance().frame_wake_callback())
     {
         Ok(realm) => realm,
@@ -2509,9 +2588,9 @@ fn create_element(&self) -> flui_view::element::ElementKind {
     fn runner_entrypoints_accept_owner_local_root_state() {
         static_assertions::assert_not_impl_any!(OwnerLocalRoot: Send, Sync);
 
-        std::hint::black_box(run_app_impl::<OwnerLocalRoot> as fn(OwnerLocalRoot));
+        std::hint::black_box(run_app_impl::<OwnerLocalRoot> as fn(OwnerLocalRoot) -> ExitCode);
         std::hint::black_box(
-            run_app_with_config_impl::<OwnerLocalRoot> as fn(OwnerLocalRoot, AppConfig),
+            run_app_with_config_impl::<OwnerLocalRoot> as fn(OwnerLocalRoot, AppConfig) -> ExitCode,
         );
     }
 
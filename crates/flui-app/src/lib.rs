@@ -36,8 +36,8 @@
 // Primary exports - Flutter naming
 // Legacy alias
 pub use app::{
-    AppBinding, AppConfig, RootRenderElement, RootRenderView, WidgetsFlutterBinding, run_app,
-    run_app_with_config, run_direct,
+    AppBinding, AppConfig, AppHandle, AppScope, RootRenderElement, RootRenderView,
+    WidgetsFlutterBinding, run_app, run_app_with_config, run_direct,
 };
 // Android-specific entry points
 #[cfg(target_os = "android")]
@@ -71,7 +71,9 @@ pub mod prelude {
     // Logging
     pub use flui_foundation::log::{debug, error, info, trace, warn};
 
-    pub use crate::{AppConfig, WidgetsFlutterBinding, run_app, run_app_with_config, run_direct};
+    pub use crate::{
+        AppConfig, AppHandle, WidgetsFlutterBinding, run_app, run_app_with_config, run_direct,
+    };
     // Bindings
     pub use crate::{
         GestureBinding, PaintingBinding, PipelineOwner, RenderingFlutterBinding, Scheduler,
@@ -10,7 +10,7 @@
 ///
 /// This provides semantic meaning to colors rather than raw values.
 /// Inspired by Material Design but simplified.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AppColorScheme {
     /// Primary brand color.
     pub primary: Color,
@@ -75,6 +75,42 @@ pub const fn dark() -> Self {
             outline: Color::from_argb(0xFF_93_8F_99),
         }
     }
+
+    /// Linearly interpolate between two color schemes.
+    ///
+    /// Every token is blended through Oklab ([`Color::lerp_oklab`]), so a
+    /// light↔dark transition passes through even grays instead of the dark,
+    /// desaturated midpoints a raw sRGB blend produces. `t` is clamped to
+    /// `0.0..=1.0`; the endpoints are returned exactly.
+    pub fn lerp(a: &Self, b: &Self, t: f64) -> Self {
+        let color = |from: Color, to: Color| lerp_color(from, to, t);
+        Self {
+            primary: color(a.primary, b.primary),
+            on_primary: color(a.on_primary, b.on_primary),
+            secondary: color(a.secondary, b.secondary),
+            on_secondary: color(a.on_secondary, b.on_secondary),
+            background: color(a.background, b.background),
+            on_background: color(a.on_background, b.on_background),
+            surface: color(a.surface, b.surface),
+            on_surface: color(a.on_surface, b.on_surface),
+            error: color(a.error, b.error),
+            on_error: color(a.on_error, b.on_error),
+            outline: color(a.outline, b.outline),
+        }
+    }
+}
+
+/// Oklab blend that returns the endpoints untouched, so `t == 0.0` and
+/// `t == 1.0` never pick up a round-trip rounding error.
+fn lerp_color(a: Color, b: Color, t: f64) -> Color {
+    if t <= 0.0 {
+        a
+    } else if t >= 1.0 {
+        b
+    } else {
+        #[allow(clippy::cast_possible_truncation)] // t is within 0..1 here
+        Color::lerp_oklab(a, b, t as f32)
+    }
 }
 
 impl Default for AppColorScheme {
@@ -95,4 +131,32 @@ fn light_background_is_lighter_than_dark_background() {
         // Light background should be lighter than dark
         assert!(light.background.r > dark.background.r);
     }
+
+    #[test]
+    fn lerp_returns_endpoints() {
+        let light = AppColorScheme::light();
+        let dark = AppColorScheme::dark();
+
+        assert_eq!(AppColorScheme::lerp(&light, &dark, 0.0), light);
+        assert_eq!(AppColorScheme::lerp(&light, &dark, 1.0), dark);
+        assert_eq!(AppColorScheme::lerp(&light, &dark, -0.5), light);
+        assert_eq!(AppColorScheme::lerp(&light, &dark, 1.5), dark);
+    }
+
+    #[test]
+    fn lerp_midpoint_is_between_endpoints_perceptually() {
+        let light = AppColorScheme::light();
+        let dark = AppColorScheme::dark();
+        let mid = AppColorScheme::lerp(&light, &dark, 0.5);
+
+        // Gray stays gray and lands between the endpoints per channel.
+        let bg = mid.background;
+        assert!(bg.r < light.background.r && bg.r > dark.background.r);
+        assert_eq!((bg.r, bg.g), (bg.b, bg.b));
+
+        // Perceived lightness lands halfway between the endpoints.
+        let lightness = |c: Color| c.to_oklab().l;
+        let halfway = f32::midpoint(lightness(light.background), lightness(dark.background));
+        assert!((lightness(bg) - halfway).abs() < 0.01);
+    }
 }
@@ -33,7 +33,7 @@ pub enum ThemeMode {
 ///     .mode(ThemeMode::Dark)
 ///     .build();
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AppTheme {
     /// Theme mode.
     pub mode: ThemeMode,
@@ -94,6 +94,34 @@ pub fn dark() -> Self {
     pub fn builder() -> AppThemeBuilder {
         AppThemeBuilder::default()
     }
+
+    /// Linearly interpolate between two themes, as for an animated
+    /// light↔dark transition driven by an `AnimationController`.
+    ///
+    /// Colors blend perceptually (see [`AppColorScheme::lerp`]) and numeric
+    /// fields blend linearly. Fields with no meaningful midpoint — `mode`
+    /// and `font_family` — switch from `a` to `b` at `t = 0.5`, as in
+    /// Flutter's `ThemeData.lerp`. `t` is clamped to `0.0..=1.0`.
+    pub fn lerp(a: &Self, b: &Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let discrete = if t < 0.5 { a } else { b };
+        #[allow(clippy::cast_possible_truncation)] // t is within 0..1
+        let number = |from: f32, to: f32| from + (to - from) * t as f32;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // between the endpoints
+        let duration = (f64::from(a.animation_duration_ms)
+            + (f64::from(b.animation_duration_ms) - f64::from(a.animation_duration_ms)) * t)
+            .round() as u32;
+
+        Self {
+            mode: discrete.mode,
+            colors: AppColorScheme::lerp(&a.colors, &b.colors, t),
+            font_family: discrete.font_family.clone(),
+            font_size: number(a.font_size, b.font_size),
+            border_radius: number(a.border_radius, b.border_radius),
+            spacing: number(a.spacing, b.spacing),
+            animation_duration_ms: duration,
+        }
+    }
 }
 
 /// Builder for creating custom [`AppTheme`] values.
@@ -199,4 +227,41 @@ fn test_theme_builder() {
         assert_eq!(theme.font_size, 16.0);
         assert_eq!(theme.spacing, 12.0);
     }
+
+    #[test]
+    fn test_theme_lerp_endpoints() {
+        let light = AppTheme::light();
+        let dark = AppTheme::builder()
+            .mode(ThemeMode::Dark)
+            .font_family("Inter")
+            .font_size(16.0)
+            .animation_duration_ms(300)
+            .build();
+
+        assert_eq!(AppTheme::lerp(&light, &dark, 0.0), light);
+        assert_eq!(AppTheme::lerp(&light, &dark, 1.0), dark);
+    }
+
+    #[test]
+    fn test_theme_lerp_midpoint() {
+        let light = AppTheme::light();
+        let dark = AppTheme::builder()
+            .mode(ThemeMode::Dark)
+            .font_family("Inter")
+            .font_size(16.0)
+            .animation_duration_ms(300)
+            .build();
+
+        let early = AppTheme::lerp(&light, &dark, 0.25);
+        assert_eq!(early.mode, ThemeMode::Light);
+        assert_eq!(early.font_family, "system-ui");
+        assert_eq!(early.font_size, 14.5);
+        assert_eq!(early.animation_duration_ms, 225);
+
+        let mid = AppTheme::lerp(&light, &dark, 0.5);
+        assert_eq!(mid.mode, ThemeMode::Dark);
+        assert_eq!(mid.font_family, "Inter");
+        let surface = mid.colors.surface;
+        assert!(surface.r < light.colors.surface.r && surface.r > dark.colors.surface.r);
+    }
 }
@@ -7,10 +7,12 @@
 use crate::core::{Asset, AssetMetadata};
 use crate::error::AssetError;
 use crate::types::AssetKey;
+use crate::types::font_data::{self, FontData};
 
 /// Font asset for loading fonts from various sources.
 ///
-/// Supports TrueType (TTF) and OpenType (OTF) formats.
+/// Supports TrueType (TTF) and OpenType (OTF) formats, and TrueType
+/// Collections (TTC) via [`FontAsset::file_with_index`].
 ///
 /// # Examples
 ///
@@ -32,6 +34,9 @@ pub struct FontAsset {
 
     /// Optional pre-loaded bytes (for embedded fonts)
     bytes: Option<Vec<u8>>,
+
+    /// Face to select within a collection (0 for single fonts)
+    face_index: u32,
 }
 
 impl FontAsset {
@@ -43,9 +48,24 @@ impl FontAsset {
     /// let font = FontAsset::file("fonts/Roboto-Regular.ttf");
     /// ```
     pub fn file(path: impl Into<String>) -> Self {
+        Self::file_with_index(path, 0)
+    }
+
+    /// Creates a font asset for one face of a TrueType Collection file.
+    ///
+    /// Loading fails with [`AssetError::InvalidData`] if `face_index` is
+    /// out of range for the collection (or non-zero for a single font).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let bold = FontAsset::file_with_index("fonts/NotoSansCJK.ttc", 1);
+    /// ```
+    pub fn file_with_index(path: impl Into<String>, face_index: u32) -> Self {
         Self {
             path: path.into(),
             bytes: None,
+            face_index,
         }
     }
 
@@ -61,17 +81,27 @@ pub fn from_bytes(name: impl Into<String>, bytes: Vec<u8>) -> Self {
         Self {
             path: name.into(),
             bytes: Some(bytes),
+            face_index: 0,
         }
     }
 }
 
 impl Asset for FontAsset {
-    type Data = crate::types::FontData;
+    type Data = FontData;
     type Key = AssetKey;
     type Error = AssetError;
 
     fn key(&self) -> AssetKey {
-        AssetKey::new(&self.path)
+        // Each face of a collection is its own cache entry.
+        if self.face_index == 0 {
+            AssetKey::new(&self.path)
+        } else {
+            AssetKey::new(&format!("{}#{}", self.path, self.face_index))
+        }
+    }
+
+    fn key_label(key: &AssetKey) -> String {
+        key.to_string()
     }
 
     async fn load(&self) -> Result<Self::Data, Self::Error> {
@@ -113,7 +143,25 @@ async fn load(&self) -> Result<Self::Data, Self::Error> {
             });
         }
 
-        Ok(crate::types::FontData::from_bytes(bytes))
+        let face_count = font_data::face_count(&bytes).ok_or_else(|| AssetError::InvalidData {
+            path: self.path.clone(),
+            reason: "Truncated TrueType Collection header".to_string(),
+        })?;
+        if self.face_index >= face_count {
+            return Err(AssetError::InvalidData {
+                path: self.path.clone(),
+                reason: format!(
+                    "Face index {} out of range (font has {face_count} face(s))",
+                    self.face_index
+                ),
+            });
+        }
+
+        Ok(FontData::from_bytes(bytes).with_face_index(self.face_index))
+    }
+
+    fn data_size(data: &Self::Data) -> Option<usize> {
+        Some(data.len())
     }
 
     fn metadata(&self) -> Option<AssetMetadata> {
@@ -169,6 +217,49 @@ fn test_font_asset_key() {
         assert_eq!(key.as_str(), "Roboto-Regular.ttf");
     }
 
+    /// A two-face collection whose faces are minimal TrueType headers.
+    fn two_face_ttc() -> Vec<u8> {
+        let mut bytes = b"ttcf".to_vec();
+        bytes.extend_from_slice(&[0x00, 0x01, 0x00, 0x00]); // version 1.0
+        bytes.extend_from_slice(&2u32.to_be_bytes()); // numFonts
+        bytes.extend_from_slice(&20u32.to_be_bytes()); // offset of face 0
+        bytes.extend_from_slice(&32u32.to_be_bytes()); // offset of face 1
+        for _ in 0..2 {
+            bytes.extend_from_slice(&[0x00, 0x01, 0x00, 0x00]);
+            bytes.extend_from_slice(&[0; 8]);
+        }
+        bytes
+    }
+
+    #[tokio::test]
+    async fn test_font_asset_collection_face_selection() {
+        let temp_dir = std::env::temp_dir().join("flui_assets_font_test");
+        fs::create_dir_all(&temp_dir).await.unwrap();
+        let path = temp_dir.join("collection.ttc");
+        fs::write(&path, two_face_ttc()).await.unwrap();
+        let path = path.to_string_lossy().into_owned();
+
+        for index in 0..2 {
+            let asset = FontAsset::file_with_index(&path, index);
+            let font = asset.load().await.unwrap();
+            assert_eq!(font.face_index, index);
+            assert_eq!(font.face_count(), 2);
+        }
+        assert_ne!(
+            FontAsset::file_with_index(&path, 0).key(),
+            FontAsset::file_with_index(&path, 1).key()
+        );
+
+        let result = FontAsset::file_with_index(&path, 2).load().await;
+        let _ = fs::remove_dir_all(&temp_dir).await;
+        match result {
+            Err(AssetError::InvalidData { reason, .. }) => {
+                assert!(reason.contains("out of range"), "{reason}");
+            }
+            other => panic!("expected InvalidData, got {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn test_font_asset_invalid_format() {
         let invalid_bytes = vec![0xFF, 0xFF, 0xFF, 0xFF];
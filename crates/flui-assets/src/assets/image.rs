@@ -2,6 +2,7 @@
 
 use std::path::Path;
 
+use flui_types::Size;
 use tokio::fs;
 
 #[cfg(feature = "images")]
@@ -25,6 +26,9 @@
 ///
 /// // Load from bytes
 /// let image = ImageAsset::from_bytes("logo.png", image_bytes);
+///
+/// // Decode a large photo straight to thumbnail size
+/// let thumb = ImageAsset::file_scaled("photos/beach.jpg", Size::new(px(128.0), px(128.0)));
 /// ```
 #[derive(Debug, Clone)]
 pub struct ImageAsset {
@@ -33,6 +37,9 @@ pub struct ImageAsset {
 
     /// Optional pre-loaded bytes (for in-memory images)
     bytes: Option<Vec<u8>>,
+
+    /// Bounding box to downsample into during decode (`None` = full size)
+    target: Option<Size>,
 }
 
 impl ImageAsset {
@@ -47,6 +54,28 @@ pub fn file(path: impl Into<String>) -> Self {
         Self {
             path: path.into(),
             bytes: None,
+            target: None,
+        }
+    }
+
+    /// Creates an image asset that is downsampled to fit `target` while
+    /// decoding.
+    ///
+    /// The aspect ratio is preserved, so the loaded image fits within
+    /// `target` rather than matching it exactly. A target larger than the
+    /// source is clamped to the source size (no upscaling). Each target size
+    /// is cached separately from the full-size image and from other sizes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let thumb = ImageAsset::file_scaled("photo.jpg", Size::new(px(200.0), px(150.0)));
+    /// ```
+    pub fn file_scaled(path: impl Into<String>, target: Size) -> Self {
+        Self {
+            path: path.into(),
+            bytes: None,
+            target: Some(target),
         }
     }
 
@@ -62,8 +91,17 @@ pub fn from_bytes(name: impl Into<String>, bytes: Vec<u8>) -> Self {
         Self {
             path: name.into(),
             bytes: Some(bytes),
+            target: None,
         }
     }
+
+    /// The target as whole pixels, at least 1×1.
+    fn target_pixels(&self) -> Option<(u32, u32)> {
+        // Float-to-int `as` saturates (NaN becomes 0), hence the `max(1)`.
+        let whole = |v: f32| (v.round() as u32).max(1);
+        self.target
+            .map(|size| (whole(size.width.get()), whole(size.height.get())))
+    }
 }
 
 impl Asset for ImageAsset {
@@ -72,7 +110,14 @@ impl Asset for ImageAsset {
     type Error = AssetError;
 
     fn key(&self) -> AssetKey {
-        AssetKey::new(&self.path)
+        match self.target_pixels() {
+            Some((width, height)) => AssetKey::new(&format!("{}@{width}x{height}", self.path)),
+            None => AssetKey::new(&self.path),
+        }
+    }
+
+    fn key_label(key: &AssetKey) -> String {
+        key.to_string()
     }
 
     async fn load(&self) -> Result<Self::Data, Self::Error> {
@@ -98,6 +143,15 @@ async fn load(&self) -> Result<Self::Data, Self::Error> {
                 reason: format!("Failed to decode image: {e}"),
             })?;
 
+            // Downsample to the target box, never past the source size
+            let img = match self.target_pixels() {
+                Some((width, height)) if width < img.width() || height < img.height() => {
+                    let (width, height) = (width.min(img.width()), height.min(img.height()));
+                    img.resize(width, height, image::imageops::FilterType::Triangle)
+                }
+                _ => img,
+            };
+
             // Convert to RGBA8
             let rgba = img.to_rgba8();
             let (width, height) = rgba.dimensions();
@@ -115,6 +169,10 @@ async fn load(&self) -> Result<Self::Data, Self::Error> {
         }
     }
 
+    fn data_size(data: &Self::Data) -> Option<usize> {
+        Some(data.byte_count())
+    }
+
     fn metadata(&self) -> Option<AssetMetadata> {
         // Extract format from file extension
         let format = Path::new(&self.path)
@@ -160,6 +218,46 @@ async fn test_image_asset_from_bytes() {
         assert_eq!(loaded.data().len(), 2 * 2 * 4);
     }
 
+    #[tokio::test]
+    #[cfg(feature = "images")]
+    async fn test_image_asset_scaled_sizes_are_distinct_cache_entries() {
+        use flui_types::geometry::px;
+        use image::{ImageBuffer, Rgba};
+
+        use crate::AssetRegistry;
+
+        let temp_dir = std::env::temp_dir().join("flui_assets_image_scaled_test");
+        fs::create_dir_all(&temp_dir).await.unwrap();
+        let path = temp_dir.join("photo.png");
+        let img: ImageBuffer<Rgba<u8>, Vec<u8>> =
+            ImageBuffer::from_fn(40, 20, |_, _| Rgba([0, 128, 255, 255]));
+        img.save(&path).unwrap();
+        let path = path.to_string_lossy().into_owned();
+
+        let registry = AssetRegistry::default();
+        let small = ImageAsset::file_scaled(&path, Size::new(px(10.0), px(10.0)));
+        let medium = ImageAsset::file_scaled(&path, Size::new(px(20.0), px(20.0)));
+        // Larger than the source: clamped, not upscaled.
+        let huge = ImageAsset::file_scaled(&path, Size::new(px(400.0), px(400.0)));
+        let (small_key, medium_key) = (small.key(), medium.key());
+        assert_ne!(small_key, medium_key);
+
+        let small = registry.load(small).await.unwrap();
+        let medium = registry.load(medium).await.unwrap();
+        let huge = registry.load(huge).await.unwrap();
+        let _ = fs::remove_dir_all(&temp_dir).await;
+
+        // Aspect ratio kept: 40x20 fits 10x10 as 10x5 and 20x20 as 20x10.
+        assert_eq!((small.width(), small.height()), (10, 5));
+        assert_eq!((medium.width(), medium.height()), (20, 10));
+        assert_eq!((huge.width(), huge.height()), (40, 20));
+
+        let cached_small = registry.get::<ImageAsset>(&small_key).await.unwrap();
+        let cached_medium = registry.get::<ImageAsset>(&medium_key).await.unwrap();
+        assert_eq!(cached_small.width(), 10);
+        assert_eq!(cached_medium.width(), 20);
+    }
+
     #[test]
     fn test_image_asset_metadata() {
         let asset = ImageAsset::file("test.png");
@@ -6,15 +6,23 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use moka::future::Cache as MokaCache;
+use moka::future::{Cache as MokaCache, CacheBuilder};
+use moka::notification::RemovalCause;
 
 use crate::core::Asset;
+use crate::registry::{AssetEvent, AssetEvents};
 use crate::types::AssetHandle;
 
 pub mod stats;
 
 pub use stats::CacheStats;
 
+type MokaBuilder<T> = CacheBuilder<
+    <T as Asset>::Key,
+    Arc<<T as Asset>::Data>,
+    MokaCache<<T as Asset>::Key, Arc<<T as Asset>::Data>>,
+>;
+
 /// High-performance asset cache using Moka.
 ///
 /// This cache uses the TinyLFU admission policy which provides better hit rates
@@ -69,11 +77,7 @@ impl<T: Asset> AssetCache<T> {
     /// let cache = AssetCache::<ImageAsset>::new(100 * 1024 * 1024);
     /// ```
     pub fn new(capacity_bytes: usize) -> Self {
-        // Estimate capacity in number of items (rough heuristic)
-        // Assume average asset is ~10KB
-        let estimated_items = (capacity_bytes / 10_240).max(100);
-
-        Self::with_config(estimated_items, Duration::from_mins(5))
+        Self::from_builder(Self::builder_for_capacity(capacity_bytes))
     }
 
     /// Creates a cache with custom configuration.
@@ -83,14 +87,43 @@ pub fn new(capacity_bytes: usize) -> Self {
     /// * `max_capacity` - Maximum number of items to cache
     /// * `time_to_live` - How long items stay in cache after insertion
     pub fn with_config(max_capacity: usize, time_to_live: Duration) -> Self {
-        let cache = MokaCache::builder()
+        Self::from_builder(Self::builder(max_capacity, time_to_live))
+    }
+
+    /// As [`new`](Self::new), reporting every removal except replacement as
+    /// an [`AssetEvent::Evicted`].
+    pub(crate) fn with_events(capacity_bytes: usize, events: AssetEvents) -> Self {
+        Self::from_builder(
+            Self::builder_for_capacity(capacity_bytes).eviction_listener(
+                move |key: Arc<T::Key>, _, cause| {
+                    if cause != RemovalCause::Replaced {
+                        events.emit(|| AssetEvent::Evicted {
+                            key: T::key_label(&key),
+                        });
+                    }
+                },
+            ),
+        )
+    }
+
+    fn builder_for_capacity(capacity_bytes: usize) -> MokaBuilder<T> {
+        // Estimate capacity in number of items (rough heuristic)
+        // Assume average asset is ~10KB
+        let estimated_items = (capacity_bytes / 10_240).max(100);
+
+        Self::builder(estimated_items, Duration::from_mins(5))
+    }
+
+    fn builder(max_capacity: usize, time_to_live: Duration) -> MokaBuilder<T> {
+        MokaCache::builder()
             .max_capacity(max_capacity as u64)
             .time_to_live(time_to_live)
             .time_to_idle(Duration::from_mins(1))
-            .build();
+    }
 
+    fn from_builder(builder: MokaBuilder<T>) -> Self {
         Self {
-            cache,
+            cache: builder.build(),
             stats: Arc::new(parking_lot::RwLock::new(CacheStats::default())),
         }
     }
@@ -85,6 +85,27 @@ fn metadata(&self) -> Option<AssetMetadata> {
         None
     }
 
+    /// Returns the in-memory size of loaded data, in bytes.
+    ///
+    /// Reported by the registry's load events. The default implementation
+    /// returns `None` (unknown).
+    fn data_size(_data: &Self::Data) -> Option<usize> {
+        None
+    }
+
+    /// Returns the label that identifies `key` in registry events.
+    ///
+    /// The default implementation combines the asset type name with a hash
+    /// of the key. Override it when the key has a readable form.
+    fn key_label(key: &Self::Key) -> String {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{BuildHasher, BuildHasherDefault};
+
+        // A default-seeded hasher keeps the label stable for the same key.
+        let hash = BuildHasherDefault::<DefaultHasher>::default().hash_one(key);
+        format!("{}#{hash:016x}", std::any::type_name::<Self>())
+    }
+
     /// Validates the asset before loading.
     ///
     /// This is called before `load()` and can be used for early validation,
@@ -179,4 +200,13 @@ fn test_asset_metadata() {
         // Default metadata is None
         assert!(asset.metadata().is_none());
     }
+
+    #[test]
+    fn test_asset_key_label_default() {
+        let label = TestAsset::key_label(&TestKey("a".to_string()));
+
+        assert!(label.contains("TestAsset#"));
+        assert_eq!(label, TestAsset::key_label(&TestKey("a".to_string())));
+        assert_ne!(label, TestAsset::key_label(&TestKey("b".to_string())));
+    }
 }
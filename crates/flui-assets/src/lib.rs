@@ -155,6 +155,8 @@
 //!
 //! - `images` - Enable image loading (PNG, JPEG, GIF, WebP)
 //! - `network` - Enable HTTP/HTTPS asset loading
+//! - `serde` - Enable asset manifests (`AssetManifest`, JSON/TOML) and
+//!   `AssetRegistry::preload_manifest`
 //! - `full` - Enable all stable features
 //!
 //! # API Compliance
@@ -188,6 +190,10 @@
 // Asset registry and orchestration
 pub mod registry;
 
+// Optional: Asset manifests
+#[cfg(feature = "serde")]
+pub mod manifest;
+
 // Optional: Asset bundles
 // TODO: Implement bundle module
 // #[cfg(feature = "bundles")]
@@ -202,7 +208,9 @@
 pub use crate::cache::{AssetCache, AssetCacheCore, AssetCacheExt};
 pub use crate::core::{Asset, AssetLoader, AssetMetadata};
 pub use crate::error::{AssetError, Result};
-pub use crate::registry::{AssetRegistry, AssetRegistryBuilder, HasCapacity, NoCapacity};
+pub use crate::registry::{
+    AssetEvent, AssetRegistry, AssetRegistryBuilder, HasCapacity, NoCapacity,
+};
 pub use crate::types::{
     AssetHandle, AssetHandleCore, AssetHandleExt, AssetKey, FontData, LoadState,
 };
@@ -213,6 +221,8 @@
 // Re-export concrete asset types
 pub use crate::assets::font::FontAsset;
 pub use crate::assets::image::ImageAsset;
+#[cfg(feature = "serde")]
+pub use crate::manifest::{AssetManifest, ManifestAssetKind, ManifestEntry, PreloadReport};
 
 // Re-export Image from flui_types
 pub use flui_types::painting::Image;
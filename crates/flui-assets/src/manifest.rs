@@ -0,0 +1,267 @@
+//! Asset manifests: a declared set of assets to preload.
+//!
+//! A manifest lists assets by key, path, and type, typically shipped next to
+//! the app as JSON or TOML. [`AssetRegistry::preload_manifest`] loads the
+//! whole set and reports per-asset results, which is what a loading screen
+//! needs:
+//!
+//! ```toml
+//! [[assets]]
+//! key = "logo"
+//! path = "assets/logo.png"
+//! type = "image"
+//! priority = 10
+//!
+//! [[assets]]
+//! key = "body-font"
+//! path = "assets/Roboto-Regular.ttf"
+//! type = "font"
+//! ```
+//!
+//! ```rust,ignore
+//! let manifest = AssetManifest::from_toml(&std::fs::read_to_string("assets.toml")?)?;
+//! let report = registry.preload_manifest(&manifest).await;
+//! for (key, error) in report.failed() {
+//!     tracing::warn!(key, %error, "Asset failed to preload");
+//! }
+//!
+//! // Preloaded assets are cached under their manifest key
+//! let logo = registry.get::<ImageAsset>(&AssetKey::new("logo")).await;
+//! ```
+
+use futures_util::future::join_all;
+use serde::Deserialize;
+use tokio::fs;
+
+use crate::assets::{FontAsset, ImageAsset};
+use crate::error::AssetError;
+use crate::registry::AssetRegistry;
+
+/// A declared set of assets, deserialized from JSON or TOML.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct AssetManifest {
+    /// The declared assets.
+    #[serde(default)]
+    pub assets: Vec<ManifestEntry>,
+}
+
+/// One asset declared in an [`AssetManifest`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ManifestEntry {
+    /// Key the asset is cached under once loaded.
+    pub key: String,
+
+    /// File path to load the asset from.
+    pub path: String,
+
+    /// Asset type, which selects the loader.
+    #[serde(rename = "type")]
+    pub kind: ManifestAssetKind,
+
+    /// Load order: higher priorities load (and finish) before lower ones.
+    /// Entries with equal priority load concurrently. Defaults to 0.
+    #[serde(default)]
+    pub priority: i32,
+}
+
+/// The asset types a manifest can declare.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ManifestAssetKind {
+    /// Loaded as an [`ImageAsset`] (needs the `images` feature to decode).
+    Image,
+    /// Loaded as a [`FontAsset`].
+    Font,
+}
+
+impl AssetManifest {
+    /// Parses a manifest from JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AssetError::DecodeFailed`] if the JSON is malformed or does
+    /// not match the manifest schema.
+    pub fn from_json(json: &str) -> Result<Self, AssetError> {
+        serde_json::from_str(json).map_err(|e| AssetError::DecodeFailed {
+            path: "<manifest>".to_string(),
+            reason: e.to_string(),
+        })
+    }
+
+    /// Parses a manifest from TOML.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AssetError::DecodeFailed`] if the TOML is malformed or does
+    /// not match the manifest schema.
+    pub fn from_toml(toml: &str) -> Result<Self, AssetError> {
+        toml::from_str(toml).map_err(|e| AssetError::DecodeFailed {
+            path: "<manifest>".to_string(),
+            reason: e.to_string(),
+        })
+    }
+}
+
+/// Outcome of [`AssetRegistry::preload_manifest`].
+#[derive(Debug, Clone, Default)]
+pub struct PreloadReport {
+    /// Per-asset results in load order (highest priority first): the source
+    /// size in bytes on success.
+    pub results: Vec<(String, Result<usize, AssetError>)>,
+
+    /// Total source bytes of the assets that loaded.
+    pub total_bytes: usize,
+}
+
+impl PreloadReport {
+    /// Keys of the assets that loaded.
+    pub fn succeeded(&self) -> impl Iterator<Item = &str> {
+        self.results
+            .iter()
+            .filter(|(_, result)| result.is_ok())
+            .map(|(key, _)| key.as_str())
+    }
+
+    /// Keys and errors of the assets that failed to load.
+    pub fn failed(&self) -> impl Iterator<Item = (&str, &AssetError)> {
+        self.results
+            .iter()
+            .filter_map(|(key, result)| result.as_ref().err().map(|e| (key.as_str(), e)))
+    }
+
+    /// Whether every asset in the manifest loaded.
+    pub fn is_complete(&self) -> bool {
+        self.results.iter().all(|(_, result)| result.is_ok())
+    }
+}
+
+impl AssetRegistry {
+    /// Loads every asset in `manifest` into the cache.
+    ///
+    /// Entries are grouped by priority, highest first; each group loads
+    /// concurrently and finishes before the next starts. A failing entry
+    /// does not stop the others — it is recorded in the returned report.
+    /// Each asset is cached under its manifest key, not its path.
+    pub async fn preload_manifest(&self, manifest: &AssetManifest) -> PreloadReport {
+        let mut entries: Vec<&ManifestEntry> = manifest.assets.iter().collect();
+        // Stable: equal priorities keep manifest order.
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.priority));
+
+        let mut report = PreloadReport::default();
+        for group in entries.chunk_by(|a, b| a.priority == b.priority) {
+            let results = join_all(group.iter().map(|entry| self.preload_entry(entry))).await;
+            for (entry, result) in group.iter().zip(results) {
+                if let Ok(bytes) = result {
+                    report.total_bytes += bytes;
+                }
+                report.results.push((entry.key.clone(), result));
+            }
+        }
+        report
+    }
+
+    async fn preload_entry(&self, entry: &ManifestEntry) -> Result<usize, AssetError> {
+        let bytes = fs::read(&entry.path)
+            .await
+            .map_err(|e| AssetError::LoadFailed {
+                path: entry.path.clone(),
+                reason: format!("Failed to read file: {e}"),
+            })?;
+        let len = bytes.len();
+        match entry.kind {
+            ManifestAssetKind::Image => {
+                self.load(ImageAsset::from_bytes(&entry.key, bytes)).await?;
+            }
+            ManifestAssetKind::Font => {
+                self.load(FontAsset::from_bytes(&entry.key, bytes)).await?;
+            }
+        }
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AssetKey;
+
+    #[test]
+    fn test_manifest_parses_json_and_toml() {
+        let json = r#"{"assets": [
+            {"key": "logo", "path": "logo.png", "type": "image", "priority": 5},
+            {"key": "body", "path": "body.ttf", "type": "font"}
+        ]}"#;
+        let toml = r#"
+            [[assets]]
+            key = "logo"
+            path = "logo.png"
+            type = "image"
+            priority = 5
+
+            [[assets]]
+            key = "body"
+            path = "body.ttf"
+            type = "font"
+        "#;
+
+        let manifest = AssetManifest::from_json(json).unwrap();
+        assert_eq!(manifest, AssetManifest::from_toml(toml).unwrap());
+        assert_eq!(manifest.assets.len(), 2);
+        assert_eq!(manifest.assets[0].kind, ManifestAssetKind::Image);
+        assert_eq!(manifest.assets[0].priority, 5);
+        assert_eq!(manifest.assets[1].kind, ManifestAssetKind::Font);
+        assert_eq!(manifest.assets[1].priority, 0);
+
+        assert!(matches!(
+            AssetManifest::from_json(r#"{"assets": [{"key": "x", "path": "x", "type": "audio"}]}"#),
+            Err(AssetError::DecodeFailed { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_preload_manifest_reports_missing_file() {
+        let temp_dir = std::env::temp_dir().join("flui_assets_manifest_test");
+        fs::create_dir_all(&temp_dir).await.unwrap();
+        let font_bytes = vec![0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        for name in ["title.ttf", "body.ttf"] {
+            fs::write(temp_dir.join(name), &font_bytes).await.unwrap();
+        }
+        let path = |name: &str| temp_dir.join(name).to_string_lossy().into_owned();
+        let entry = |key: &str, file: &str, priority| ManifestEntry {
+            key: key.to_string(),
+            path: path(file),
+            kind: ManifestAssetKind::Font,
+            priority,
+        };
+        let manifest = AssetManifest {
+            assets: vec![
+                entry("body", "body.ttf", 0),
+                entry("missing", "missing.ttf", 0),
+                entry("title", "title.ttf", 10),
+            ],
+        };
+
+        let registry = AssetRegistry::default();
+        let report = registry.preload_manifest(&manifest).await;
+        let _ = fs::remove_dir_all(&temp_dir).await;
+
+        // Highest priority first, then manifest order.
+        let keys: Vec<_> = report.results.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(keys, ["title", "body", "missing"]);
+        assert_eq!(report.succeeded().collect::<Vec<_>>(), ["title", "body"]);
+        let failed: Vec<_> = report.failed().collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].0, "missing");
+        assert!(matches!(failed[0].1, AssetError::LoadFailed { .. }));
+        assert!(!report.is_complete());
+        assert_eq!(report.total_bytes, 2 * font_bytes.len());
+
+        // Cached under the manifest key.
+        assert!(
+            registry
+                .get::<FontAsset>(&AssetKey::new("title"))
+                .await
+                .is_some()
+        );
+    }
+}
@@ -0,0 +1,111 @@
+//! Registry activity events for observability (devtools, slow-load logging).
+//!
+//! [`AssetRegistry::subscribe`](super::AssetRegistry::subscribe) hands out a
+//! [`tokio::sync::broadcast`] receiver. Emission checks for receivers first,
+//! so a registry nobody subscribes to never formats a key or reads the clock.
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use tokio::sync::broadcast;
+
+use crate::error::{AssetError, Result};
+
+/// Events buffered per subscriber before the slowest one starts lagging
+/// (its next `recv` then reports [`broadcast::error::RecvError::Lagged`]).
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// Something the [`AssetRegistry`](super::AssetRegistry) did.
+#[derive(Debug, Clone)]
+pub enum AssetEvent {
+    /// A load missed the cache and started loading from the source.
+    LoadStarted {
+        /// The asset's key.
+        key: String,
+    },
+
+    /// A load finished; the registry caches the asset next.
+    LoadCompleted {
+        /// The asset's key.
+        key: String,
+        /// In-memory size of the loaded data, if the asset type reports it
+        /// (see [`Asset::data_size`](crate::Asset::data_size)).
+        bytes: Option<usize>,
+        /// Time from [`LoadStarted`](Self::LoadStarted) to completion.
+        duration: Duration,
+    },
+
+    /// A load failed; nothing was cached.
+    LoadFailed {
+        /// The asset's key.
+        key: String,
+        /// Why the load failed.
+        error: AssetError,
+    },
+
+    /// An asset left the cache: it expired, was evicted for capacity, or was
+    /// invalidated. Replacing a cached value does not count.
+    Evicted {
+        /// The asset's key.
+        key: String,
+    },
+}
+
+/// Sending half shared by the registry, its caches, and bridged loads.
+#[derive(Clone)]
+pub(crate) struct AssetEvents {
+    tx: broadcast::Sender<AssetEvent>,
+}
+
+impl AssetEvents {
+    pub(crate) fn new() -> Self {
+        let (tx, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        Self { tx }
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<AssetEvent> {
+        self.tx.subscribe()
+    }
+
+    /// Whether anyone is listening; gate event construction on this.
+    pub(crate) fn is_observed(&self) -> bool {
+        self.tx.receiver_count() > 0
+    }
+
+    /// Builds and sends the event only if anyone is listening.
+    pub(crate) fn emit(&self, event: impl FnOnce() -> AssetEvent) {
+        if self.is_observed() {
+            // Every receiver dropping between the check and the send is fine.
+            let _ = self.tx.send(event());
+        }
+    }
+
+    /// Runs `load`, bracketing it with started/completed/failed events when
+    /// observed. `key` is only rendered when someone is listening.
+    pub(crate) async fn observe<D>(
+        &self,
+        key: impl FnOnce() -> String,
+        data_size: impl FnOnce(&D) -> Option<usize>,
+        load: impl Future<Output = Result<D>>,
+    ) -> Result<D> {
+        let Some(key) = self.is_observed().then(key) else {
+            return load.await;
+        };
+        self.emit(|| AssetEvent::LoadStarted { key: key.clone() });
+        let started = Instant::now();
+
+        let outcome = load.await;
+        match &outcome {
+            Ok(data) => self.emit(|| AssetEvent::LoadCompleted {
+                key,
+                bytes: data_size(data),
+                duration: started.elapsed(),
+            }),
+            Err(error) => self.emit(|| AssetEvent::LoadFailed {
+                key,
+                error: error.clone(),
+            }),
+        }
+        outcome
+    }
+}
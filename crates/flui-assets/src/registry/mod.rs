@@ -15,8 +15,11 @@
 
 #[cfg(feature = "images")]
 mod bridge;
+mod events;
 #[cfg(feature = "images")]
 use bridge::BridgeRuntime;
+pub use events::AssetEvent;
+pub(crate) use events::AssetEvents;
 
 /// Asset registry for central asset management.
 ///
@@ -46,6 +49,9 @@ pub struct AssetRegistry {
     /// Default cache capacity in bytes.
     pub(crate) default_capacity: usize,
 
+    /// Activity events handed out by [`subscribe`](Self::subscribe).
+    events: AssetEvents,
+
     /// A host-supplied runtime handle for [`load_image_bridged`](Self::load_image_bridged)
     /// to spawn onto, set at construction via
     /// [`AssetRegistryBuilder::with_runtime_handle`]. `None` defers to an
@@ -94,6 +100,7 @@ fn new(default_capacity: usize) -> Self {
         Self {
             caches: Arc::new(RwLock::new(HashMap::new())),
             default_capacity,
+            events: AssetEvents::new(),
             #[cfg(feature = "images")]
             injected_runtime_handle: None,
             #[cfg(feature = "images")]
@@ -111,6 +118,7 @@ fn with_injected_handle(
         Self {
             caches: Arc::new(RwLock::new(HashMap::new())),
             default_capacity,
+            events: AssetEvents::new(),
             injected_runtime_handle,
             bridge_runtime: BridgeRuntime::new(),
         }
@@ -162,9 +170,16 @@ pub fn load_image_bridged(
         let (tx, rx) = tokio::sync::oneshot::channel();
 
         let spawn_path = path.clone();
+        let events = self.events.clone();
         handle.spawn(async move {
-            let asset = crate::assets::image::ImageAsset::file(spawn_path);
-            let outcome = Asset::load(&asset).await;
+            let asset = crate::assets::image::ImageAsset::file(spawn_path.clone());
+            let outcome = events
+                .observe(
+                    || spawn_path,
+                    crate::assets::image::ImageAsset::data_size,
+                    Asset::load(&asset),
+                )
+                .await;
             // A dropped receiver just means the observer future was abandoned
             // (e.g. its subscriber unmounted); the load itself still ran to
             // completion and there is nothing useful to report to.
@@ -207,14 +222,21 @@ pub fn load_network_image_bridged(
         let (tx, rx) = tokio::sync::oneshot::channel();
 
         let spawn_url = url.clone();
+        let events = self.events.clone();
         handle.spawn(async move {
-            let outcome = async {
+            let load = async {
                 let loader = crate::loaders::NetworkLoader::new();
                 let bytes = loader.load_url(&spawn_url).await?;
                 let asset = crate::assets::image::ImageAsset::from_bytes(spawn_url.clone(), bytes);
                 Asset::load(&asset).await
-            }
-            .await;
+            };
+            let outcome = events
+                .observe(
+                    || spawn_url.clone(),
+                    crate::assets::image::ImageAsset::data_size,
+                    load,
+                )
+                .await;
             let _ = tx.send(outcome);
         });
 
@@ -260,7 +282,10 @@ pub async fn load<T>(&self, asset: T) -> Result<AssetHandle<T::Data, T::Key>>
         }
 
         // Not in cache, load the asset
-        let data = asset.load().await?;
+        let data = self
+            .events
+            .observe(|| T::key_label(&key), T::data_size, asset.load())
+            .await?;
 
         // Insert into cache and return handle
         Ok(cache.insert(key, data).await)
@@ -401,11 +426,35 @@ fn get_or_create_cache<T>(&self) -> AssetCache<T>
         }
 
         // Create new cache
-        let cache = AssetCache::<T>::new(self.default_capacity);
+        let cache = AssetCache::<T>::with_events(self.default_capacity, self.events.clone());
         caches.insert(type_id, Box::new(cache.clone()));
         cache
     }
 
+    /// Subscribes to this registry's activity: loads starting, completing,
+    /// and failing, and assets leaving the cache.
+    ///
+    /// Events are only built while at least one receiver is alive, so an
+    /// unobserved registry pays nothing. A receiver that falls more than a
+    /// few hundred events behind skips ahead and gets
+    /// [`RecvError::Lagged`](tokio::sync::broadcast::error::RecvError::Lagged).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let mut events = registry.subscribe();
+    /// while let Ok(event) = events.recv().await {
+    ///     if let AssetEvent::LoadCompleted { key, duration, .. } = event
+    ///         && duration > Duration::from_millis(100)
+    ///     {
+    ///         tracing::warn!(key, ?duration, "Slow asset load");
+    ///     }
+    /// }
+    /// ```
+    pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<AssetEvent> {
+        self.events.subscribe()
+    }
+
     /// Returns statistics for all caches.
     ///
     /// Returns a map of asset type names to their cache stats.
@@ -731,6 +780,51 @@ async fn test_registry_clear() {
         );
     }
 
+    #[tokio::test]
+    async fn test_registry_load_emits_started_then_completed() {
+        let registry = AssetRegistry::default();
+        let mut events = registry.subscribe();
+
+        let ttf_bytes = vec![0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let font = FontAsset::from_bytes("events.ttf", ttf_bytes);
+        let _handle = registry.load(font).await.unwrap();
+
+        match events.try_recv().unwrap() {
+            AssetEvent::LoadStarted { key } => assert_eq!(key, "events.ttf"),
+            other => panic!("expected LoadStarted, got {other:?}"),
+        }
+        match events.try_recv().unwrap() {
+            AssetEvent::LoadCompleted { key, bytes, .. } => {
+                assert_eq!(key, "events.ttf");
+                assert_eq!(bytes, Some(10));
+            }
+            other => panic!("expected LoadCompleted, got {other:?}"),
+        }
+
+        // A cache hit doesn't load, so it emits nothing.
+        let font = FontAsset::from_bytes("events.ttf", vec![0; 10]);
+        let _handle = registry.load(font).await.unwrap();
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_registry_missing_file_emits_failed() {
+        let registry = AssetRegistry::default();
+        let mut events = registry.subscribe();
+
+        let font = FontAsset::file("definitely/missing/font.ttf");
+        assert!(registry.load(font).await.is_err());
+
+        assert!(matches!(
+            events.try_recv().unwrap(),
+            AssetEvent::LoadStarted { .. }
+        ));
+        match events.try_recv().unwrap() {
+            AssetEvent::LoadFailed { key, .. } => assert_eq!(key, "definitely/missing/font.ttf"),
+            other => panic!("expected LoadFailed, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_global_registry() {
         let registry1 = AssetRegistry::global();
@@ -4,23 +4,47 @@
 
 /// Font data loaded from an asset.
 ///
-/// Contains the raw font bytes (TTF/OTF format) that can be used
-/// by text rendering backends.
+/// Contains the raw font bytes (TTF/OTF format, or a TrueType Collection)
+/// that can be used by text rendering backends. For a collection,
+/// `face_index` selects the face to shape with.
 #[derive(Clone, Debug)]
 pub struct FontData {
-    /// Raw font bytes (TTF/OTF format)
+    /// Raw font bytes (TTF/OTF format, or a TTC collection)
     pub bytes: Arc<Vec<u8>>,
+
+    /// Index of the face within a collection (always 0 for single fonts)
+    pub face_index: u32,
 }
 
 impl FontData {
-    /// Creates font data from raw bytes.
+    /// Creates font data from raw bytes, selecting the first face.
     #[inline]
     pub fn from_bytes(bytes: Vec<u8>) -> Self {
         Self {
             bytes: Arc::new(bytes),
+            face_index: 0,
         }
     }
 
+    /// Selects the face at `face_index` within a collection.
+    ///
+    /// Not validated here; [`FontAsset`](crate::FontAsset) checks the index
+    /// against [`face_count`](Self::face_count) on load.
+    #[inline]
+    #[must_use]
+    pub fn with_face_index(mut self, face_index: u32) -> Self {
+        self.face_index = face_index;
+        self
+    }
+
+    /// Returns the number of faces in the font data.
+    ///
+    /// 1 for a single TTF/OTF font, the header's face count for a TrueType
+    /// Collection, and 0 when the collection header is truncated.
+    pub fn face_count(&self) -> u32 {
+        face_count(&self.bytes).unwrap_or(0)
+    }
+
     /// Returns a reference to the font bytes.
     #[inline]
     pub fn as_bytes(&self) -> &[u8] {
@@ -41,14 +65,29 @@ pub fn is_empty(&self) -> bool {
 }
 
 impl PartialEq for FontData {
-    /// Font data is equal if it points to the same underlying bytes.
+    /// Font data is equal if it points to the same underlying bytes and
+    /// selects the same face.
     fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.bytes, &other.bytes)
+        Arc::ptr_eq(&self.bytes, &other.bytes) && self.face_index == other.face_index
     }
 }
 
 impl Eq for FontData {}
 
+/// Number of faces in `bytes`, or `None` if a TrueType Collection header
+/// (`ttcf` tag, version, face count, one offset per face) is truncated.
+pub(crate) fn face_count(bytes: &[u8]) -> Option<u32> {
+    if !bytes.starts_with(b"ttcf") {
+        return Some(1);
+    }
+    let count = u32::from_be_bytes(bytes.get(8..12)?.try_into().ok()?);
+    let header_len = usize::try_from(count)
+        .ok()?
+        .checked_mul(4)?
+        .checked_add(12)?;
+    (bytes.len() >= header_len).then_some(count)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,6 +113,21 @@ fn test_font_data_equality() {
         assert_ne!(font1, font3); // Different Arc
     }
 
+    #[test]
+    fn test_font_data_face_count() {
+        let single = FontData::from_bytes(vec![0x00, 0x01, 0x00, 0x00]);
+        assert_eq!(single.face_count(), 1);
+
+        let mut collection = b"ttcf".to_vec();
+        collection.extend_from_slice(&[0, 1, 0, 0, 0, 0, 0, 2]);
+        collection.extend_from_slice(&[0, 0, 0, 20, 0, 0, 0, 32]);
+        assert_eq!(FontData::from_bytes(collection.clone()).face_count(), 2);
+
+        // Offset table cut short.
+        collection.truncate(16);
+        assert_eq!(FontData::from_bytes(collection).face_count(), 0);
+    }
+
     #[test]
     fn test_font_data_empty() {
         let font_data = FontData::from_bytes(vec![]);
@@ -1,9 +1,174 @@
+use std::fmt;
 use std::path::{Path, PathBuf};
 
+use crate::cache;
 use crate::error::{BuildError, BuildResult};
-use crate::platform::{BuildArtifacts, BuilderContext, FinalArtifacts, PlatformBuilder, private};
+use crate::platform::{
+    BuildArtifacts, BuilderContext, FinalArtifacts, PlatformBuilder, Profile, private,
+};
+use crate::util::process::{CommandRunner, SystemRunner};
 use crate::util::{check_command_exists, environment, process};
 
+/// Keystore used to sign Android release APKs.
+///
+/// Passwords are handed to Gradle through the environment rather than the
+/// command line, and are redacted from `Debug` output.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AndroidSigningConfig {
+    /// Path to the keystore (`.jks` / `.keystore`)
+    pub keystore: PathBuf,
+    /// Alias of the signing key within the keystore
+    pub key_alias: String,
+    /// Keystore password
+    pub store_password: String,
+    /// Key password
+    pub key_password: String,
+}
+
+impl AndroidSigningConfig {
+    /// Environment variable holding the keystore path.
+    pub const KEYSTORE_VAR: &'static str = "FLUI_ANDROID_KEYSTORE";
+    /// Environment variable holding the key alias.
+    pub const KEY_ALIAS_VAR: &'static str = "FLUI_ANDROID_KEY_ALIAS";
+    /// Environment variable holding the keystore password.
+    pub const STORE_PASSWORD_VAR: &'static str = "FLUI_ANDROID_STORE_PASSWORD";
+    /// Environment variable holding the key password (defaults to the
+    /// keystore password).
+    pub const KEY_PASSWORD_VAR: &'static str = "FLUI_ANDROID_KEY_PASSWORD";
+
+    /// Creates a signing config
+    pub fn new(
+        keystore: impl Into<PathBuf>,
+        key_alias: impl Into<String>,
+        store_password: impl Into<String>,
+        key_password: impl Into<String>,
+    ) -> Self {
+        Self {
+            keystore: keystore.into(),
+            key_alias: key_alias.into(),
+            store_password: store_password.into(),
+            key_password: key_password.into(),
+        }
+    }
+
+    /// Reads a signing config from the `FLUI_ANDROID_*` environment variables.
+    ///
+    /// Returns `Ok(None)` if [`KEYSTORE_VAR`](Self::KEYSTORE_VAR) is not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::EnvVarError`] if the keystore is set but the
+    /// alias or keystore password is missing.
+    pub fn from_env() -> BuildResult<Option<Self>> {
+        let Ok(keystore) = std::env::var(Self::KEYSTORE_VAR) else {
+            return Ok(None);
+        };
+        let key_alias = environment::get_env_var(Self::KEY_ALIAS_VAR)?;
+        let store_password = environment::get_env_var(Self::STORE_PASSWORD_VAR)?;
+        let key_password =
+            std::env::var(Self::KEY_PASSWORD_VAR).unwrap_or_else(|_| store_password.clone());
+        Ok(Some(Self::new(
+            keystore,
+            key_alias,
+            store_password,
+            key_password,
+        )))
+    }
+}
+
+impl fmt::Debug for AndroidSigningConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AndroidSigningConfig")
+            .field("keystore", &self.keystore)
+            .field("key_alias", &self.key_alias)
+            .field("store_password", &"<redacted>")
+            .field("key_password", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Map a Rust target triple to its Android ABI name; ABI names pass through.
+fn android_abi(target: &str) -> &str {
+    match target {
+        "aarch64-linux-android" => "arm64-v8a",
+        "armv7-linux-androideabi" => "armeabi-v7a",
+        "x86_64-linux-android" => "x86_64",
+        "i686-linux-android" => "x86",
+        other => other,
+    }
+}
+
+/// Arguments and environment for one Gradle APK build.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct GradleInvocation {
+    args: Vec<String>,
+    env: Vec<(String, String)>,
+}
+
+/// Build the Gradle invocation for `profile`, producing one APK per ABI.
+///
+/// Signing uses the Android Gradle plugin's `android.injected.signing.*`
+/// properties; passwords go through `ORG_GRADLE_PROJECT_*` variables so they
+/// never appear in logs or the process list. Debug builds without an explicit
+/// config keep Gradle's default debug keystore.
+fn gradle_invocation(
+    profile: Profile,
+    abis: &[&str],
+    signing: Option<&AndroidSigningConfig>,
+) -> BuildResult<GradleInvocation> {
+    let task = match profile {
+        Profile::Debug => "assembleDebug",
+        Profile::Release => "assembleRelease",
+    };
+    let mut args = vec![task.to_string(), format!("-Pflui.abis={}", abis.join(","))];
+    let mut env = Vec::new();
+
+    let signing = match (profile, signing) {
+        (_, Some(signing)) => signing,
+        (Profile::Debug, None) => return Ok(GradleInvocation { args, env }),
+        (Profile::Release, None) => {
+            return Err(BuildError::invalid_config(
+                "android_signing",
+                format!(
+                    "release builds must be signed; set {} (plus {} and {}) or use \
+                     BuilderContextBuilder::with_android_signing",
+                    AndroidSigningConfig::KEYSTORE_VAR,
+                    AndroidSigningConfig::KEY_ALIAS_VAR,
+                    AndroidSigningConfig::STORE_PASSWORD_VAR,
+                ),
+            ));
+        }
+    };
+
+    if !signing.keystore.is_file() {
+        return Err(BuildError::path_not_found(
+            signing.keystore.clone(),
+            "Android signing keystore",
+        ));
+    }
+    // Gradle resolves relative paths against the project dir, not ours.
+    let keystore = std::path::absolute(&signing.keystore)?;
+
+    args.push(format!(
+        "-Pandroid.injected.signing.store.file={}",
+        keystore.display()
+    ));
+    args.push(format!(
+        "-Pandroid.injected.signing.key.alias={}",
+        signing.key_alias
+    ));
+    env.push((
+        "ORG_GRADLE_PROJECT_android.injected.signing.store.password".to_string(),
+        signing.store_password.clone(),
+    ));
+    env.push((
+        "ORG_GRADLE_PROJECT_android.injected.signing.key.password".to_string(),
+        signing.key_password.clone(),
+    ));
+
+    Ok(GradleInvocation { args, env })
+}
+
 /// Builder for Android platform (APK builds via Gradle and cargo-ndk)
 #[derive(Debug)]
 pub struct AndroidBuilder {
@@ -150,73 +315,121 @@ pub async fn push_scene_plugin(
     }
 }
 
-impl private::Sealed for AndroidBuilder {}
-
-impl PlatformBuilder for AndroidBuilder {
-    fn platform_name(&self) -> &'static str {
-        "android"
-    }
-
-    fn validate_environment(&self) -> BuildResult<()> {
-        // Check cargo-ndk
-        check_command_exists("cargo")?;
-
-        // Try to find cargo-ndk
-        let cargo_ndk_ok = std::process::Command::new("cargo")
-            .args(["ndk", "--version"])
-            .output()
-            .is_ok_and(|output| output.status.success());
-
-        if !cargo_ndk_ok {
-            return Err(BuildError::ToolNotFound {
-                tool: "cargo-ndk".to_string(),
-                install_hint: "cargo install cargo-ndk".to_string(),
-            });
-        }
-
-        // Check Gradle (optional - warn if not found)
-        let gradle_wrapper = self.workspace_root.join("platforms").join("android").join(
-            if cfg!(target_os = "windows") {
+impl AndroidBuilder {
+    /// Absolute path of the Gradle wrapper in `platforms/android`.
+    fn gradle_wrapper(&self) -> PathBuf {
+        self.workspace_root
+            .join("platforms")
+            .join("android")
+            .join(if cfg!(target_os = "windows") {
                 "gradlew.bat"
             } else {
                 "gradlew"
-            },
-        );
-
-        if !gradle_wrapper.exists() {
-            tracing::warn!("Gradle wrapper not found - will build native libraries only");
-            tracing::warn!("To build APK, ensure Gradle is set up in platforms/android/");
-        }
+            })
+    }
 
-        // Check Android targets are installed
-        let output = std::process::Command::new("rustup")
-            .args(["target", "list", "--installed"])
-            .output()?;
+    /// Run Gradle and collect one APK per target ABI into the output dir.
+    async fn assemble_apks<R: CommandRunner>(
+        &self,
+        runner: &R,
+        ctx: &BuilderContext,
+        gradle_wrapper_path: &Path,
+    ) -> BuildResult<FinalArtifacts> {
+        let crate::platform::Platform::Android { targets } = &ctx.platform else {
+            return Err(BuildError::InvalidPlatform {
+                reason: "Expected Android platform".to_string(),
+            });
+        };
+        let abis: Vec<&str> = targets.iter().map(|target| android_abi(target)).collect();
+        let invocation = gradle_invocation(ctx.profile, &abis, ctx.android_signing.as_ref())?;
 
-        let installed_targets = String::from_utf8_lossy(&output.stdout);
+        // Use absolute path for gradle wrapper; it is spawned as a UTF-8
+        // command string, so a non-UTF-8 workspace root must surface as an
+        // error, not a panic.
+        let gradle_wrapper_str = gradle_wrapper_path.to_str().ok_or_else(|| {
+            BuildError::invalid_config(
+                "workspace_root",
+                format!(
+                    "Gradle wrapper path {} is not valid UTF-8",
+                    gradle_wrapper_path.display()
+                ),
+            )
+        })?;
+        let android_dir = self.workspace_root.join("platforms").join("android");
+        runner
+            .run_in_dir(
+                gradle_wrapper_str,
+                &invocation.args,
+                &invocation.env,
+                &android_dir,
+            )
+            .await?;
 
-        // Check for at least one Android target
-        if !installed_targets.contains("android") {
-            return Err(BuildError::TargetNotInstalled {
-                target: "aarch64-linux-android".to_string(),
-                install_cmd: "rustup target add aarch64-linux-android".to_string(),
-            });
+        // Find the per-ABI APKs (`app-<abi>-<profile>.apk`)
+        let apk_dir = android_dir
+            .join("app")
+            .join("build")
+            .join("outputs")
+            .join("apk")
+            .join(ctx.profile.as_str());
+        let apks: Vec<PathBuf> = std::fs::read_dir(&apk_dir)
+            .map_err(|_| BuildError::path_not_found(apk_dir.clone(), "Gradle APK output dir"))?
+            .filter_map(std::result::Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "apk"))
+            .collect();
+
+        std::fs::create_dir_all(&ctx.output_dir)?;
+        let mut split_binaries = Vec::with_capacity(abis.len());
+        for abi in &abis {
+            let needle = format!("-{abi}-");
+            let apk_path = apks
+                .iter()
+                .find(|path| {
+                    path.file_name()
+                        .is_some_and(|name| name.to_string_lossy().contains(&needle))
+                })
+                .ok_or_else(|| {
+                    BuildError::path_not_found(
+                        apk_dir.clone(),
+                        format!("APK for ABI {abi} not found in build output"),
+                    )
+                })?;
+
+            let output_apk = ctx
+                .output_dir
+                .join(format!("flui-{}-{abi}.apk", ctx.profile.as_str()));
+            std::fs::copy(apk_path, &output_apk)?;
+            tracing::info!("APK copied to: {:?}", output_apk);
+            split_binaries.push(output_apk);
         }
 
-        tracing::debug!("Android environment validation passed");
-        tracing::debug!("  ANDROID_HOME: {:?}", self.android_home);
-        tracing::debug!("  NDK: {:?}", self.ndk_home);
+        let app_binary = split_binaries
+            .first()
+            .cloned()
+            .ok_or_else(|| BuildError::Other("No Android targets configured".to_string()))?;
+        let size_bytes = std::fs::metadata(&app_binary)?.len();
 
-        Ok(())
+        Ok(FinalArtifacts {
+            app_binary,
+            size_bytes,
+            split_binaries,
+        })
     }
 
-    async fn build_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts> {
+    /// Compile the Rust libraries, bypassing the build cache.
+    async fn compile_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts> {
         let crate::platform::Platform::Android { targets } = &ctx.platform else {
             return Err(BuildError::InvalidPlatform {
                 reason: "Expected Android platform".to_string(),
             });
         };
 
+        // Fail before the native build if the APK could not be signed.
+        if self.gradle_wrapper().exists() {
+            gradle_invocation(ctx.profile, &[], ctx.android_signing.as_ref())?;
+        }
+
         let jni_libs_dir = self
             .workspace_root
             .join("platforms")
@@ -289,6 +502,71 @@ async fn build_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts>
             metadata: serde_json::json!({}),
         })
     }
+}
+
+impl private::Sealed for AndroidBuilder {}
+
+impl PlatformBuilder for AndroidBuilder {
+    fn platform_name(&self) -> &'static str {
+        "android"
+    }
+
+    fn validate_environment(&self) -> BuildResult<()> {
+        // Check cargo-ndk
+        check_command_exists("cargo")?;
+
+        // Try to find cargo-ndk
+        let cargo_ndk_ok = std::process::Command::new("cargo")
+            .args(["ndk", "--version"])
+            .output()
+            .is_ok_and(|output| output.status.success());
+
+        if !cargo_ndk_ok {
+            return Err(BuildError::ToolNotFound {
+                tool: "cargo-ndk".to_string(),
+                install_hint: "cargo install cargo-ndk".to_string(),
+            });
+        }
+
+        // Check Gradle (optional - warn if not found)
+        let gradle_wrapper = self.workspace_root.join("platforms").join("android").join(
+            if cfg!(target_os = "windows") {
+                "gradlew.bat"
+            } else {
+                "gradlew"
+            },
+        );
+
+        if !gradle_wrapper.exists() {
+            tracing::warn!("Gradle wrapper not found - will build native libraries only");
+            tracing::warn!("To build APK, ensure Gradle is set up in platforms/android/");
+        }
+
+        // Check Android targets are installed
+        let output = std::process::Command::new("rustup")
+            .args(["target", "list", "--installed"])
+            .output()?;
+
+        let installed_targets = String::from_utf8_lossy(&output.stdout);
+
+        // Check for at least one Android target
+        if !installed_targets.contains("android") {
+            return Err(BuildError::TargetNotInstalled {
+                target: "aarch64-linux-android".to_string(),
+                install_cmd: "rustup target add aarch64-linux-android".to_string(),
+            });
+        }
+
+        tracing::debug!("Android environment validation passed");
+        tracing::debug!("  ANDROID_HOME: {:?}", self.android_home);
+        tracing::debug!("  NDK: {:?}", self.ndk_home);
+
+        Ok(())
+    }
+
+    async fn build_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts> {
+        cache::cached_build(ctx, self.compile_rust(ctx)).await
+    }
 
     async fn build_platform(
         &self,
@@ -297,16 +575,8 @@ async fn build_platform(
     ) -> BuildResult<FinalArtifacts> {
         tracing::info!("Building APK with Gradle...");
 
-        let android_dir = self.workspace_root.join("platforms").join("android");
-
-        let gradle_wrapper_name = if cfg!(target_os = "windows") {
-            "gradlew.bat"
-        } else {
-            "gradlew"
-        };
-
         // Check if gradle wrapper exists
-        let gradle_wrapper_path = android_dir.join(gradle_wrapper_name);
+        let gradle_wrapper_path = self.gradle_wrapper();
         if !gradle_wrapper_path.exists() {
             tracing::warn!("Gradle wrapper not found, skipping APK build");
             tracing::info!(
@@ -323,59 +593,12 @@ async fn build_platform(
             return Ok(FinalArtifacts {
                 app_binary: so_file.clone(),
                 size_bytes,
+                split_binaries: artifacts.rust_libs.clone(),
             });
         }
 
-        let gradle_task = match ctx.profile {
-            crate::platform::Profile::Debug => "assembleDebug",
-            crate::platform::Profile::Release => "assembleRelease",
-        };
-
-        // Use absolute path for gradle wrapper; it is spawned as a UTF-8
-        // command string, so a non-UTF-8 workspace root must surface as an
-        // error, not a panic.
-        let gradle_wrapper_str = gradle_wrapper_path.to_str().ok_or_else(|| {
-            BuildError::invalid_config(
-                "workspace_root",
-                format!(
-                    "Gradle wrapper path {} is not valid UTF-8",
-                    gradle_wrapper_path.display()
-                ),
-            )
-        })?;
-        process::run_command_in_dir(gradle_wrapper_str, &[gradle_task], &android_dir).await?;
-
-        // Find the APK
-        let apk_dir = android_dir
-            .join("app")
-            .join("build")
-            .join("outputs")
-            .join("apk")
-            .join(ctx.profile.as_str());
-
-        let apk_path = std::fs::read_dir(&apk_dir)?
-            .filter_map(std::result::Result::ok)
-            .map(|entry| entry.path())
-            .find(|path| path.extension().is_some_and(|ext| ext == "apk"))
-            .ok_or_else(|| BuildError::PathNotFound {
-                path: apk_dir.clone(),
-                context: "APK file not found in build output".to_string(),
-            })?;
-
-        let size_bytes = std::fs::metadata(&apk_path)?.len();
-
-        // Copy to output directory
-        let output_apk = ctx
-            .output_dir
-            .join(format!("flui-{}.apk", ctx.profile.as_str()));
-        std::fs::copy(&apk_path, &output_apk)?;
-
-        tracing::info!("APK copied to: {:?}", output_apk);
-
-        Ok(FinalArtifacts {
-            app_binary: output_apk,
-            size_bytes,
-        })
+        self.assemble_apks(&SystemRunner, ctx, &gradle_wrapper_path)
+            .await
     }
 
     async fn clean(&self, ctx: &BuilderContext) -> BuildResult<()> {
@@ -412,3 +635,142 @@ async fn clean(&self, ctx: &BuilderContext) -> BuildResult<()> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::{BuilderContextBuilder, Platform};
+
+    /// Records the Gradle call and fakes its per-ABI APK output.
+    #[derive(Default)]
+    struct MockRunner {
+        calls: Mutex<Vec<(String, GradleInvocation)>>,
+    }
+
+    impl CommandRunner for MockRunner {
+        async fn run_in_dir(
+            &self,
+            program: &str,
+            args: &[String],
+            env: &[(String, String)],
+            dir: &Path,
+        ) -> BuildResult<()> {
+            let apk_dir = dir.join("app/build/outputs/apk/release");
+            std::fs::create_dir_all(&apk_dir)?;
+            for abi in ["arm64-v8a", "x86_64"] {
+                std::fs::write(apk_dir.join(format!("app-{abi}-release.apk")), abi)?;
+            }
+            self.calls.lock().unwrap().push((
+                program.to_string(),
+                GradleInvocation {
+                    args: args.to_vec(),
+                    env: env.to_vec(),
+                },
+            ));
+            Ok(())
+        }
+    }
+
+    fn builder(workspace_root: &Path) -> AndroidBuilder {
+        AndroidBuilder {
+            workspace_root: workspace_root.to_path_buf(),
+            android_home: PathBuf::new(),
+            ndk_home: PathBuf::new(),
+            _java_home: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn signed_multi_abi_release_passes_signing_and_splits() {
+        let root = tempfile::tempdir().unwrap();
+        let keystore = root.path().join("release.jks");
+        std::fs::write(&keystore, b"keystore").unwrap();
+        let ctx = BuilderContextBuilder::new(root.path().to_path_buf())
+            .with_platform(Platform::Android {
+                targets: vec!["aarch64-linux-android".to_string(), "x86_64".to_string()],
+            })
+            .with_profile(Profile::Release)
+            .with_android_signing(AndroidSigningConfig::new(
+                &keystore, "upload", "store-pw", "key-pw",
+            ))
+            .build();
+        let android = builder(root.path());
+        let runner = MockRunner::default();
+
+        let artifacts = android
+            .assemble_apks(&runner, &ctx, &android.gradle_wrapper())
+            .await
+            .unwrap();
+
+        let calls = runner.calls.lock().unwrap();
+        let (program, GradleInvocation { args, env }) = &calls[0];
+        assert!(program.ends_with("gradlew") || program.ends_with("gradlew.bat"));
+        assert_eq!(
+            args,
+            &[
+                "assembleRelease".to_string(),
+                "-Pflui.abis=arm64-v8a,x86_64".to_string(),
+                format!(
+                    "-Pandroid.injected.signing.store.file={}",
+                    keystore.display()
+                ),
+                "-Pandroid.injected.signing.key.alias=upload".to_string(),
+            ]
+        );
+        assert!(args.iter().all(|arg| !arg.contains("-pw")));
+        assert_eq!(
+            env,
+            &[
+                (
+                    "ORG_GRADLE_PROJECT_android.injected.signing.store.password".to_string(),
+                    "store-pw".to_string()
+                ),
+                (
+                    "ORG_GRADLE_PROJECT_android.injected.signing.key.password".to_string(),
+                    "key-pw".to_string()
+                ),
+            ]
+        );
+
+        let out = &ctx.output_dir;
+        assert_eq!(
+            artifacts.split_binaries,
+            vec![
+                out.join("flui-release-arm64-v8a.apk"),
+                out.join("flui-release-x86_64.apk")
+            ]
+        );
+        assert_eq!(artifacts.app_binary, artifacts.split_binaries[0]);
+        assert_eq!(
+            std::fs::read_to_string(&artifacts.split_binaries[1]).unwrap(),
+            "x86_64"
+        );
+    }
+
+    #[test]
+    fn release_without_signing_fails_and_debug_uses_default_keystore() {
+        let err = gradle_invocation(Profile::Release, &["arm64-v8a"], None).unwrap_err();
+        assert!(
+            matches!(err, BuildError::InvalidConfig { ref field, .. } if field == "android_signing")
+        );
+
+        let missing = AndroidSigningConfig::new("/nonexistent/release.jks", "upload", "a", "b");
+        let err = gradle_invocation(Profile::Release, &["arm64-v8a"], Some(&missing)).unwrap_err();
+        assert!(matches!(err, BuildError::PathNotFound { .. }));
+
+        let debug = gradle_invocation(Profile::Debug, &["arm64-v8a"], None).unwrap();
+        assert_eq!(debug.args, ["assembleDebug", "-Pflui.abis=arm64-v8a"]);
+        assert!(debug.env.is_empty());
+    }
+
+    #[test]
+    fn signing_config_debug_redacts_passwords() {
+        let config = AndroidSigningConfig::new("release.jks", "upload", "store-pw", "key-pw");
+        let debug = format!("{config:?}");
+        assert!(debug.contains("upload"));
+        assert!(!debug.contains("store-pw"));
+        assert!(!debug.contains("key-pw"));
+    }
+}
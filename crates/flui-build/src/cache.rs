@@ -0,0 +1,248 @@
+//! Incremental build cache for `PlatformBuilder::build_rust`.
+//!
+//! A fingerprint is a content hash over the workspace's Rust sources and
+//! manifests, `Cargo.lock`, the platform, profile and features. It is stored
+//! with the produced artifact paths in the output dir; when the next build
+//! computes the same fingerprint and every artifact is still on disk, cargo
+//! is skipped and the previous artifacts are reused.
+
+use std::future::Future;
+use std::path::{Path, PathBuf};
+
+use crate::error::BuildResult;
+use crate::platform::{BuildArtifacts, BuilderContext};
+
+/// File in the output dir holding the last fingerprint and its artifacts.
+pub(crate) const FINGERPRINT_FILE: &str = ".flui-fingerprint.json";
+
+/// File extensions hashed into the fingerprint.
+const SOURCE_EXTENSIONS: &[&str] = &["rs", "toml", "lock", "wgsl"];
+
+/// Directories never hashed: build output, VCS metadata and platform
+/// projects (which hold generated artifacts such as `jniLibs/` and `dist/`).
+const SKIPPED_DIRS: &[&str] = &["target", "platforms", "node_modules"];
+
+/// 64-bit FNV-1a; stable across Rust versions, unlike `DefaultHasher`.
+struct Fnv64(u64);
+
+impl Fnv64 {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    /// Write a length-prefixed field so adjacent fields cannot run together.
+    fn field(&mut self, bytes: &[u8]) {
+        self.write(&(bytes.len() as u64).to_le_bytes());
+        self.write(bytes);
+    }
+}
+
+/// Compute the fingerprint of `ctx` as a hex string.
+pub(crate) fn fingerprint(ctx: &BuilderContext) -> BuildResult<String> {
+    let mut hasher = Fnv64::new();
+    hasher.field(ctx.platform.name().as_bytes());
+    hasher.field(format!("{:?}", ctx.platform).as_bytes());
+    hasher.field(ctx.profile.as_str().as_bytes());
+
+    let mut features = ctx.features.clone();
+    features.sort();
+    features.dedup();
+    for feature in &features {
+        hasher.field(feature.as_bytes());
+    }
+
+    let mut sources = Vec::new();
+    collect_sources(&ctx.workspace_root, &ctx.output_dir, &mut sources)?;
+    sources.sort();
+    for path in &sources {
+        let relative = path.strip_prefix(&ctx.workspace_root).unwrap_or(path);
+        hasher.field(relative.to_string_lossy().as_bytes());
+        hasher.field(&std::fs::read(path)?);
+    }
+
+    Ok(format!("{:016x}", hasher.0))
+}
+
+fn collect_sources(dir: &Path, output_dir: &Path, sources: &mut Vec<PathBuf>) -> BuildResult<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        let file_type = entry.file_type()?;
+
+        if file_type.is_dir() {
+            if !name.starts_with('.')
+                && !SKIPPED_DIRS.contains(&name.as_ref())
+                && path != output_dir
+            {
+                collect_sources(&path, output_dir, sources)?;
+            }
+        } else if file_type.is_file()
+            && path
+                .extension()
+                .is_some_and(|ext| SOURCE_EXTENSIONS.iter().any(|known| ext == *known))
+        {
+            sources.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Artifacts from the previous build if its fingerprint matches and every
+/// artifact still exists.
+fn load(output_dir: &Path, fingerprint: &str) -> Option<BuildArtifacts> {
+    let contents = std::fs::read_to_string(output_dir.join(FINGERPRINT_FILE)).ok()?;
+    let mut stored: serde_json::Value = serde_json::from_str(&contents).ok()?;
+    if stored["fingerprint"] != fingerprint {
+        return None;
+    }
+
+    let rust_libs: Vec<PathBuf> = stored["rust_libs"]
+        .as_array()?
+        .iter()
+        .map(|path| path.as_str().map(PathBuf::from))
+        .collect::<Option<_>>()?;
+    if let Some(missing) = rust_libs.iter().find(|path| !path.exists()) {
+        tracing::info!(
+            "Cached artifact {} is missing, rebuilding",
+            missing.display()
+        );
+        return None;
+    }
+
+    Some(BuildArtifacts {
+        rust_libs,
+        metadata: stored["metadata"].take(),
+    })
+}
+
+fn store(output_dir: &Path, fingerprint: &str, artifacts: &BuildArtifacts) -> BuildResult<()> {
+    let stored = serde_json::json!({
+        "fingerprint": fingerprint,
+        "rust_libs": artifacts
+            .rust_libs
+            .iter()
+            .map(|path| path.to_string_lossy())
+            .collect::<Vec<_>>(),
+        "metadata": artifacts.metadata,
+    });
+    std::fs::create_dir_all(output_dir)?;
+    std::fs::write(output_dir.join(FINGERPRINT_FILE), stored.to_string())?;
+    Ok(())
+}
+
+/// Run `build` unless the fingerprint shows the previous artifacts are
+/// still current.
+///
+/// Caching is skipped entirely when [`BuilderContext::use_cache`] is off.
+pub(crate) async fn cached_build(
+    ctx: &BuilderContext,
+    build: impl Future<Output = BuildResult<BuildArtifacts>>,
+) -> BuildResult<BuildArtifacts> {
+    if !ctx.use_cache {
+        return build.await;
+    }
+
+    let fingerprint = fingerprint(ctx)?;
+    if let Some(artifacts) = load(&ctx.output_dir, &fingerprint) {
+        tracing::info!(
+            "Sources unchanged ({}), reusing previous build",
+            fingerprint
+        );
+        return Ok(artifacts);
+    }
+
+    let artifacts = build.await?;
+    if let Err(err) = store(&ctx.output_dir, &fingerprint, &artifacts) {
+        tracing::warn!("Failed to write build fingerprint: {}", err);
+    }
+    Ok(artifacts)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::{BuilderContextBuilder, Platform, Profile};
+
+    fn workspace() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join("src")).unwrap();
+        std::fs::write(root.path().join("src/lib.rs"), "pub fn app() {}").unwrap();
+        std::fs::write(root.path().join("Cargo.lock"), "version = 4").unwrap();
+        root
+    }
+
+    fn context(root: &Path, features: &[&str]) -> BuilderContext {
+        BuilderContextBuilder::new(root.to_path_buf())
+            .with_platform(Platform::Desktop { target: None })
+            .with_profile(Profile::Debug)
+            .with_features(features.iter().map(ToString::to_string).collect())
+            .build()
+    }
+
+    #[test]
+    fn fingerprint_is_stable_and_tracks_inputs() {
+        let root = workspace();
+        let ctx = context(root.path(), &["webgpu"]);
+        let first = fingerprint(&ctx).unwrap();
+
+        // No-op: build output and ignored dirs do not count.
+        std::fs::create_dir_all(root.path().join("target/debug")).unwrap();
+        std::fs::write(root.path().join("target/debug/gen.rs"), "x").unwrap();
+        std::fs::write(root.path().join("README.md"), "docs").unwrap();
+        assert_eq!(fingerprint(&ctx).unwrap(), first);
+
+        let duplicated = context(root.path(), &["webgpu", "webgpu"]);
+        assert_eq!(fingerprint(&duplicated).unwrap(), first);
+
+        let more_features = context(root.path(), &["webgpu", "hot-reload"]);
+        assert_ne!(fingerprint(&more_features).unwrap(), first);
+
+        std::fs::write(root.path().join("Cargo.lock"), "version = 4\n# bumped").unwrap();
+        assert_ne!(fingerprint(&ctx).unwrap(), first);
+    }
+
+    #[tokio::test]
+    async fn reuses_artifacts_until_they_are_deleted() {
+        let root = workspace();
+        let ctx = context(root.path(), &[]);
+        let lib = root.path().join("libapp.so");
+        let builds = AtomicUsize::new(0);
+        let build = || async {
+            builds.fetch_add(1, Ordering::SeqCst);
+            std::fs::write(&lib, b"elf")?;
+            Ok(BuildArtifacts {
+                rust_libs: vec![lib.clone()],
+                metadata: serde_json::json!({ "target": "host" }),
+            })
+        };
+
+        cached_build(&ctx, build()).await.unwrap();
+        let reused = cached_build(&ctx, build()).await.unwrap();
+        assert_eq!(builds.load(Ordering::SeqCst), 1);
+        assert_eq!(reused.rust_libs, vec![lib.clone()]);
+        assert_eq!(reused.metadata["target"], "host");
+
+        std::fs::remove_file(&lib).unwrap();
+        cached_build(&ctx, build()).await.unwrap();
+        assert_eq!(builds.load(Ordering::SeqCst), 2);
+
+        let uncached = BuilderContextBuilder::new(root.path().to_path_buf())
+            .with_platform(Platform::Desktop { target: None })
+            .with_profile(Profile::Debug)
+            .with_cache(false)
+            .build();
+        cached_build(&uncached, build()).await.unwrap();
+        assert_eq!(builds.load(Ordering::SeqCst), 3);
+    }
+}
@@ -28,6 +28,7 @@
 /// ```
 use std::path::PathBuf;
 
+use crate::android::AndroidSigningConfig;
 use crate::platform::{BuilderContext, Platform, Profile};
 
 /// Type state: No platform set
@@ -101,6 +102,8 @@ pub struct BuilderContextBuilder<P = NoPlatform, Pr = NoProfile> {
     profile: Pr,
     features: Vec<String>,
     output_dir: Option<PathBuf>,
+    android_signing: Option<AndroidSigningConfig>,
+    use_cache: bool,
 }
 
 // Initial builder creation
@@ -127,6 +130,8 @@ pub fn new(workspace_root: PathBuf) -> Self {
             profile: NoProfile,
             features: Vec::new(),
             output_dir: None,
+            android_signing: None,
+            use_cache: true,
         }
     }
 }
@@ -157,6 +162,8 @@ pub fn with_platform(self, platform: Platform) -> BuilderContextBuilder<HasPlatf
             profile: self.profile,
             features: self.features,
             output_dir: self.output_dir,
+            android_signing: self.android_signing,
+            use_cache: self.use_cache,
         }
     }
 }
@@ -185,6 +192,8 @@ pub fn with_profile(self, profile: Profile) -> BuilderContextBuilder<P, HasProfi
             profile: HasProfile(profile),
             features: self.features,
             output_dir: self.output_dir,
+            android_signing: self.android_signing,
+            use_cache: self.use_cache,
         }
     }
 }
@@ -256,6 +265,52 @@ pub fn with_output_dir(mut self, output_dir: PathBuf) -> Self {
         self.output_dir = Some(output_dir);
         self
     }
+
+    /// Set the keystore used to sign Android APKs.
+    ///
+    /// Required for Android release builds; debug builds fall back to the
+    /// SDK's default debug keystore.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use flui_build::*;
+    /// use std::path::PathBuf;
+    ///
+    /// let builder = BuilderContextBuilder::new(PathBuf::from("."))
+    ///     .with_android_signing(AndroidSigningConfig::new(
+    ///         "release.jks",
+    ///         "upload",
+    ///         "store-secret",
+    ///         "key-secret",
+    ///     ));
+    /// ```
+    #[must_use]
+    pub fn with_android_signing(mut self, signing: AndroidSigningConfig) -> Self {
+        self.android_signing = Some(signing);
+        self
+    }
+
+    /// Enable or disable the incremental build cache (enabled by default).
+    ///
+    /// With the cache on, `build_rust` skips cargo when the
+    /// [fingerprint](crate::BuilderContextExt::fingerprint) matches the
+    /// previous build and its artifacts still exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use flui_build::*;
+    /// use std::path::PathBuf;
+    ///
+    /// let builder = BuilderContextBuilder::new(PathBuf::from("."))
+    ///     .with_cache(false);
+    /// ```
+    #[must_use]
+    pub fn with_cache(mut self, enabled: bool) -> Self {
+        self.use_cache = enabled;
+        self
+    }
 }
 
 // Build only when both platform and profile are set
@@ -299,6 +354,8 @@ pub fn build(self) -> BuilderContext {
             profile: self.profile.0,
             features: self.features,
             output_dir,
+            android_signing: self.android_signing,
+            use_cache: self.use_cache,
         }
     }
 }
@@ -25,6 +25,7 @@
 /// ```
 use std::path::PathBuf;
 
+use crate::error::BuildResult;
 use crate::platform::{BuilderContext, Platform, Profile};
 
 /// Extension trait providing convenient methods for `BuilderContext`.
@@ -351,6 +352,33 @@ pub trait BuilderContextExt {
     /// assert!(!ctx.is_android());
     /// ```
     fn is_desktop(&self) -> bool;
+
+    /// Content hash of everything that affects the Rust build.
+    ///
+    /// Covers the workspace's `.rs`, `.toml` and `.wgsl` files, `Cargo.lock`,
+    /// the platform, profile and (sorted) features. Build output, hidden
+    /// directories and `platforms/` are excluded. `build_rust` reuses the
+    /// previous artifacts while this value is unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a source file cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use flui_build::*;
+    /// use std::path::PathBuf;
+    ///
+    /// let ctx = BuilderContextBuilder::new(PathBuf::from("."))
+    ///     .with_platform(Platform::Desktop { target: None })
+    ///     .with_profile(Profile::Debug)
+    ///     .build();
+    ///
+    /// println!("fingerprint: {}", ctx.fingerprint()?);
+    /// # Ok::<(), BuildError>(())
+    /// ```
+    fn fingerprint(&self) -> BuildResult<String>;
 }
 
 // Blanket implementation for BuilderContext
@@ -415,6 +443,10 @@ fn is_web(&self) -> bool {
     fn is_desktop(&self) -> bool {
         matches!(self.platform, Platform::Desktop { .. })
     }
+
+    fn fingerprint(&self) -> BuildResult<String> {
+        crate::cache::fingerprint(self)
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 use std::path::{Path, PathBuf};
 
+use crate::cache;
 use crate::error::{BuildError, BuildResult};
 use crate::platform::{BuildArtifacts, BuilderContext, FinalArtifacts, PlatformBuilder, private};
 use crate::util::process;
@@ -63,20 +64,9 @@ fn detect_host_target() -> BuildResult<String> {
     }
 }
 
-impl private::Sealed for DesktopBuilder {}
-
-impl PlatformBuilder for DesktopBuilder {
-    fn platform_name(&self) -> &'static str {
-        "desktop"
-    }
-
-    fn validate_environment(&self) -> BuildResult<()> {
-        // Just need cargo
-        crate::util::check_command_exists("cargo")?;
-        Ok(())
-    }
-
-    async fn build_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts> {
+impl DesktopBuilder {
+    /// Compile the Rust libraries, bypassing the build cache.
+    async fn compile_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts> {
         let target = match &ctx.platform {
             crate::platform::Platform::Desktop { target } => match target {
                 Some(t) => t.clone(),
@@ -103,7 +93,7 @@ async fn build_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts>
             args.push(profile_flag);
         }
 
-        process::run_command("cargo", &args).await?;
+        process::run_command_with_progress("cargo", &args, None, false).await?;
 
         // Find the library (flui_app builds as a library, not executable)
         let lib_name = if cfg!(target_os = "windows") {
@@ -135,6 +125,24 @@ async fn build_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts>
             }),
         })
     }
+}
+
+impl private::Sealed for DesktopBuilder {}
+
+impl PlatformBuilder for DesktopBuilder {
+    fn platform_name(&self) -> &'static str {
+        "desktop"
+    }
+
+    fn validate_environment(&self) -> BuildResult<()> {
+        // Just need cargo
+        crate::util::check_command_exists("cargo")?;
+        Ok(())
+    }
+
+    async fn build_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts> {
+        cache::cached_build(ctx, self.compile_rust(ctx)).await
+    }
 
     async fn build_platform(
         &self,
@@ -166,6 +174,7 @@ async fn build_platform(
         Ok(FinalArtifacts {
             app_binary: output_lib,
             size_bytes,
+            split_binaries: Vec::new(),
         })
     }
 
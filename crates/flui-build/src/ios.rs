@@ -1,5 +1,6 @@
 use std::path::{Path, PathBuf};
 
+use crate::cache;
 use crate::error::{BuildError, BuildResult};
 use crate::platform::{BuildArtifacts, BuilderContext, FinalArtifacts, PlatformBuilder, private};
 use crate::util::{check_command_exists, process};
@@ -23,40 +24,9 @@ pub fn new(workspace_root: &Path) -> BuildResult<Self> {
     }
 }
 
-impl private::Sealed for IOSBuilder {}
-
-impl PlatformBuilder for IOSBuilder {
-    fn platform_name(&self) -> &'static str {
-        "ios"
-    }
-
-    fn validate_environment(&self) -> BuildResult<()> {
-        // Check xcodebuild
-        check_command_exists("xcodebuild")?;
-
-        // Check for iOS targets
-        let output = std::process::Command::new("rustup")
-            .args(["target", "list", "--installed"])
-            .output()?;
-
-        let installed_targets = String::from_utf8_lossy(&output.stdout);
-
-        // Check for at least one iOS target
-        if !installed_targets.contains("aarch64-apple-ios")
-            && !installed_targets.contains("x86_64-apple-ios")
-        {
-            return Err(BuildError::TargetNotInstalled {
-                target: "aarch64-apple-ios".to_string(),
-                install_cmd: "rustup target add aarch64-apple-ios".to_string(),
-            });
-        }
-
-        tracing::debug!("iOS environment validation passed");
-
-        Ok(())
-    }
-
-    async fn build_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts> {
+impl IOSBuilder {
+    /// Compile the Rust libraries, bypassing the build cache.
+    async fn compile_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts> {
         let crate::platform::Platform::IOS { targets } = &ctx.platform else {
             return Err(BuildError::InvalidPlatform {
                 reason: "Expected iOS platform".to_string(),
@@ -94,7 +64,7 @@ async fn build_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts>
                 args.push(profile_flag);
             }
 
-            process::run_command("cargo", &args).await?;
+            process::run_command_with_progress("cargo", &args, None, false).await?;
 
             // Find the .a static library
             let lib_path = self
@@ -127,6 +97,44 @@ async fn build_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts>
             metadata: serde_json::json!({}),
         })
     }
+}
+
+impl private::Sealed for IOSBuilder {}
+
+impl PlatformBuilder for IOSBuilder {
+    fn platform_name(&self) -> &'static str {
+        "ios"
+    }
+
+    fn validate_environment(&self) -> BuildResult<()> {
+        // Check xcodebuild
+        check_command_exists("xcodebuild")?;
+
+        // Check for iOS targets
+        let output = std::process::Command::new("rustup")
+            .args(["target", "list", "--installed"])
+            .output()?;
+
+        let installed_targets = String::from_utf8_lossy(&output.stdout);
+
+        // Check for at least one iOS target
+        if !installed_targets.contains("aarch64-apple-ios")
+            && !installed_targets.contains("x86_64-apple-ios")
+        {
+            return Err(BuildError::TargetNotInstalled {
+                target: "aarch64-apple-ios".to_string(),
+                install_cmd: "rustup target add aarch64-apple-ios".to_string(),
+            });
+        }
+
+        tracing::debug!("iOS environment validation passed");
+
+        Ok(())
+    }
+
+    async fn build_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts> {
+        cache::cached_build(ctx, self.compile_rust(ctx)).await
+    }
 
     async fn build_platform(
         &self,
@@ -153,6 +161,7 @@ async fn build_platform(
             return Ok(FinalArtifacts {
                 app_binary: lib_file.clone(),
                 size_bytes,
+                split_binaries: Vec::new(),
             });
         }
 
@@ -215,6 +224,7 @@ async fn build_platform(
         Ok(FinalArtifacts {
             app_binary: output_app,
             size_bytes,
+            split_binaries: Vec::new(),
         })
     }
 
@@ -50,6 +50,8 @@
 
 /// Android platform build support
 pub mod android;
+/// Incremental build cache keyed on a source fingerprint
+pub(crate) mod cache;
 /// Type-state builder for `BuilderContext`
 pub mod context_builder;
 /// Extension trait with utility methods for `BuilderContext`
@@ -73,13 +75,13 @@
 /// Web/WASM platform build support
 pub mod web;
 
-pub use android::AndroidBuilder;
+pub use android::{AndroidBuilder, AndroidSigningConfig};
 pub use context_builder::BuilderContextBuilder;
 pub use context_ext::BuilderContextExt;
 pub use desktop::DesktopBuilder;
 pub use error::{BuildError, BuildResult};
 pub use ios::IOSBuilder;
-pub use output_parser::{BuildEvent, OutputParser, get_parser};
+pub use output_parser::{BuildEvent, DiagnosticLevel, DiagnosticSpan, OutputParser, get_parser};
 pub use platform::*;
 pub use progress::{BuildPhase, BuildProgress, ProgressManager};
 pub use web::WebBuilder;
@@ -2,6 +2,12 @@
 //!
 //! This module provides parsers that extract meaningful progress information
 //! from the output of various build tools (cargo, gradle, wasm-pack).
+//!
+//! [`CargoParser`] also understands `--message-format=json` output and turns
+//! compiler messages into [`BuildEvent::Diagnostic`]s with source locations.
+
+use std::fmt;
+use std::path::PathBuf;
 
 /// Parsed build event from tool output
 #[derive(Debug, Clone)]
@@ -40,6 +46,80 @@ pub enum BuildEvent {
         /// Info message text
         message: String,
     },
+    /// Compiler diagnostic with its source location
+    Diagnostic {
+        /// Severity
+        level: DiagnosticLevel,
+        /// Diagnostic message (first line, without the location)
+        message: String,
+        /// File of the primary span, relative to the package root
+        file: Option<PathBuf>,
+        /// 1-based line of the primary span
+        line: Option<u32>,
+        /// 1-based column of the primary span
+        column: Option<u32>,
+        /// Full extent of the primary span
+        span: Option<DiagnosticSpan>,
+    },
+}
+
+/// Severity of a compiler diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticLevel {
+    /// Compilation error (including internal compiler errors)
+    Error,
+    /// Warning
+    Warning,
+    /// Informational note
+    Note,
+    /// Suggestion
+    Help,
+}
+
+impl DiagnosticLevel {
+    /// Map a rustc level string (`"error"`, `"warning"`, ...)
+    #[must_use]
+    pub fn from_rustc(level: &str) -> Option<Self> {
+        match level {
+            "error" | "error: internal compiler error" => Some(Self::Error),
+            "warning" => Some(Self::Warning),
+            "note" | "failure-note" => Some(Self::Note),
+            "help" => Some(Self::Help),
+            _ => None,
+        }
+    }
+
+    /// Returns the level name as a string
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Note => "note",
+            Self::Help => "help",
+        }
+    }
+}
+
+impl fmt::Display for DiagnosticLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Source range of a diagnostic's primary span (1-based, inclusive start)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticSpan {
+    /// First line
+    pub line_start: u32,
+    /// Last line
+    pub line_end: u32,
+    /// Column on the first line
+    pub column_start: u32,
+    /// Column one past the end on the last line
+    pub column_end: u32,
+    /// Label attached to the span, if any
+    pub label: Option<String>,
 }
 
 /// Trait for parsing build tool output
@@ -55,10 +135,82 @@ pub trait OutputParser: Send + Sync {
 #[derive(Debug)]
 pub struct CargoParser;
 
+impl CargoParser {
+    /// Parse one line of `cargo --message-format=json` output.
+    ///
+    /// Compiler messages become [`BuildEvent::Diagnostic`]. Progress comes
+    /// from the rest of the stream, since cargo prints its `Compiling` status
+    /// lines to stderr in this mode: each built artifact is a
+    /// [`BuildEvent::Info`] naming its target, and `build-finished` is
+    /// [`BuildEvent::Completed`] or [`BuildEvent::Error`]. Other messages
+    /// (build-script output) yield `None`.
+    fn parse_json(line: &str) -> Option<BuildEvent> {
+        let value: serde_json::Value = serde_json::from_str(line).ok()?;
+        match value["reason"].as_str()? {
+            "compiler-message" => Self::parse_diagnostic(&value["message"]),
+            "compiler-artifact" => Some(BuildEvent::Info {
+                message: format!("Compiled {}", value["target"]["name"].as_str()?),
+            }),
+            "build-finished" if value["success"] == true => Some(BuildEvent::Completed {
+                task: "Rust compilation".to_string(),
+                duration_ms: None,
+            }),
+            "build-finished" => Some(BuildEvent::Error {
+                message: "Build failed".to_string(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn parse_diagnostic(message: &serde_json::Value) -> Option<BuildEvent> {
+        let level = DiagnosticLevel::from_rustc(message["level"].as_str()?)?;
+        let mut text = message["message"].as_str()?.to_string();
+        if let Some(code) = message["code"]["code"].as_str() {
+            text = format!("[{code}] {text}");
+        }
+
+        let spans = message["spans"].as_array();
+        let primary = spans.and_then(|spans| {
+            spans
+                .iter()
+                .find(|span| span["is_primary"] == true)
+                .or_else(|| spans.first())
+        });
+        let as_u32 = |value: &serde_json::Value| {
+            value.as_u64().and_then(|number| u32::try_from(number).ok())
+        };
+        let span = primary.and_then(|span| {
+            Some(DiagnosticSpan {
+                line_start: as_u32(&span["line_start"])?,
+                line_end: as_u32(&span["line_end"])?,
+                column_start: as_u32(&span["column_start"])?,
+                column_end: as_u32(&span["column_end"])?,
+                label: span["label"].as_str().map(str::to_string),
+            })
+        });
+
+        Some(BuildEvent::Diagnostic {
+            level,
+            message: text,
+            file: primary
+                .and_then(|span| span["file_name"].as_str())
+                .map(PathBuf::from),
+            line: span.as_ref().map(|span| span.line_start),
+            column: span.as_ref().map(|span| span.column_start),
+            span,
+        })
+    }
+}
+
 impl OutputParser for CargoParser {
     fn parse_line(&self, line: &str) -> Option<BuildEvent> {
         let line = line.trim();
 
+        // `--message-format=json`: one JSON object per line
+        if line.starts_with('{') {
+            return Self::parse_json(line);
+        }
+
         // Cargo format: "   Compiling crate_name v1.0.0"
         if line.starts_with("Compiling") {
             let parts: Vec<&str> = line.split_whitespace().collect();
@@ -267,6 +419,71 @@ fn test_cargo_parser_warning() {
         assert!(matches!(event, Some(BuildEvent::Warning { .. })));
     }
 
+    #[test]
+    fn test_cargo_parser_json_error() {
+        let parser = CargoParser;
+        let line = r#"{"reason":"compiler-message","package_id":"app 0.1.0","message":{"rendered":"error[E0308]: mismatched types","code":{"code":"E0308","explanation":null},"level":"error","message":"mismatched types","spans":[{"file_name":"src/lib.rs","byte_start":10,"byte_end":17,"line_start":3,"line_end":3,"column_start":18,"column_end":25,"is_primary":true,"label":"expected `u32`, found `&str`","text":[]}],"children":[]}}"#;
+
+        let Some(BuildEvent::Diagnostic {
+            level,
+            message,
+            file,
+            line,
+            column,
+            span,
+        }) = parser.parse_line(line)
+        else {
+            panic!("expected a diagnostic");
+        };
+        assert_eq!(level, DiagnosticLevel::Error);
+        assert_eq!(message, "[E0308] mismatched types");
+        assert_eq!(file, Some(PathBuf::from("src/lib.rs")));
+        assert_eq!((line, column), (Some(3), Some(18)));
+        let span = span.expect("primary span");
+        assert_eq!((span.line_end, span.column_end), (3, 25));
+        assert_eq!(span.label.as_deref(), Some("expected `u32`, found `&str`"));
+    }
+
+    #[test]
+    fn test_cargo_parser_json_warning_and_fallback() {
+        let parser = CargoParser;
+        let line = r#"{"reason":"compiler-message","message":{"code":{"code":"unused_variables"},"level":"warning","message":"unused variable: `x`","spans":[{"file_name":"src/main.rs","line_start":7,"line_end":7,"column_start":9,"column_end":10,"is_primary":false,"label":null},{"file_name":"src/main.rs","line_start":8,"line_end":8,"column_start":5,"column_end":6,"is_primary":true,"label":null}],"children":[]}}"#;
+        assert!(matches!(
+            parser.parse_line(line),
+            Some(BuildEvent::Diagnostic {
+                level: DiagnosticLevel::Warning,
+                line: Some(8),
+                column: Some(5),
+                ..
+            })
+        ));
+
+        let script = r#"{"reason":"build-script-executed","package_id":"app 0.1.0"}"#;
+        assert!(parser.parse_line(script).is_none());
+        assert!(matches!(
+            parser.parse_line("error[E0308]: mismatched types"),
+            Some(BuildEvent::Error { .. })
+        ));
+    }
+
+    #[test]
+    fn test_cargo_parser_json_progress() {
+        let parser = CargoParser;
+        let artifact = r#"{"reason":"compiler-artifact","package_id":"app 0.1.0","target":{"kind":["lib"],"name":"app"},"fresh":false}"#;
+        assert!(matches!(
+            parser.parse_line(artifact),
+            Some(BuildEvent::Info { message }) if message == "Compiled app"
+        ));
+        assert!(matches!(
+            parser.parse_line(r#"{"reason":"build-finished","success":true}"#),
+            Some(BuildEvent::Completed { .. })
+        ));
+        assert!(matches!(
+            parser.parse_line(r#"{"reason":"build-finished","success":false}"#),
+            Some(BuildEvent::Error { .. })
+        ));
+    }
+
     #[test]
     fn test_gradle_parser_task() {
         let parser = GradleParser;
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use crate::android::AndroidSigningConfig;
 use crate::error::{BuildError, BuildResult};
 
 /// Private module to seal the `PlatformBuilder` trait.
@@ -27,6 +28,10 @@ pub struct BuilderContext {
     pub features: Vec<String>,
     /// Output directory for build artifacts
     pub output_dir: PathBuf,
+    /// Keystore for signing Android APKs (required for Android release builds)
+    pub android_signing: Option<AndroidSigningConfig>,
+    /// Reuse the previous Rust build when its fingerprint still matches
+    pub use_cache: bool,
 }
 
 /// Platform to build for
@@ -159,6 +164,9 @@ pub struct FinalArtifacts {
     pub app_binary: PathBuf,
     /// Size of the final artifact in bytes
     pub size_bytes: u64,
+    /// Per-target artifacts (e.g. one APK per Android ABI), including
+    /// `app_binary`; empty when the build produces a single artifact
+    pub split_binaries: Vec<PathBuf>,
 }
 
 /// Platform-specific builder trait.
@@ -4,7 +4,7 @@
 use tokio::process::Command;
 
 use crate::error::{BuildError, BuildResult};
-use crate::output_parser::{BuildEvent, get_parser};
+use crate::output_parser::{BuildEvent, DiagnosticLevel, get_parser};
 use crate::progress::BuildProgress;
 
 /// Run a command and stream output to console
@@ -82,13 +82,30 @@ pub async fn run_command_in_dir<S: AsRef<str>>(
     program: &str,
     args: &[S],
     dir: &Path,
+) -> BuildResult<()> {
+    run_command_in_dir_with_env(program, args, &[], dir).await
+}
+
+/// Run a command in a specific directory with extra environment variables.
+///
+/// Only the variable names are logged, so secrets can be passed this way.
+pub async fn run_command_in_dir_with_env<S: AsRef<str>>(
+    program: &str,
+    args: &[S],
+    env: &[(String, String)],
+    dir: &Path,
 ) -> BuildResult<()> {
     let args_str: Vec<&str> = args.iter().map(std::convert::AsRef::as_ref).collect();
 
     tracing::info!("Running in {:?}: {} {}", dir, program, args_str.join(" "));
+    if !env.is_empty() {
+        let names: Vec<&str> = env.iter().map(|(name, _)| name.as_str()).collect();
+        tracing::debug!("  with env: {}", names.join(", "));
+    }
 
     let status = Command::new(program)
         .args(&args_str)
+        .envs(env.iter().map(|(name, value)| (name, value)))
         .current_dir(dir)
         .stdin(Stdio::null())
         .stdout(Stdio::inherit())
@@ -112,15 +129,75 @@ pub async fn run_command_in_dir<S: AsRef<str>>(
     Ok(())
 }
 
+/// Runs external commands for a builder.
+///
+/// Builders take a runner instead of spawning processes directly so tests
+/// can record the invocation without the real toolchain.
+pub(crate) trait CommandRunner: Send + Sync {
+    /// Run `program` with `args` and extra `env` in `dir`.
+    fn run_in_dir(
+        &self,
+        program: &str,
+        args: &[String],
+        env: &[(String, String)],
+        dir: &Path,
+    ) -> impl Future<Output = BuildResult<()>> + Send;
+}
+
+/// [`CommandRunner`] that spawns real processes.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct SystemRunner;
+
+impl CommandRunner for SystemRunner {
+    async fn run_in_dir(
+        &self,
+        program: &str,
+        args: &[String],
+        env: &[(String, String)],
+        dir: &Path,
+    ) -> BuildResult<()> {
+        run_command_in_dir_with_env(program, args, env, dir).await
+    }
+}
+
+/// Cargo subcommands that compile and accept `--message-format`.
+const CARGO_BUILD_SUBCOMMANDS: &[&str] = &["build", "check", "rustc"];
+
+/// Add `--message-format=json` to a cargo build so its output can be parsed
+/// line by line. Left alone for other programs, other subcommands, and
+/// commands that already choose a format. Inserted ahead of a `--`
+/// separator so it reaches cargo rather than the compiled program.
+fn with_cargo_json_format<'a>(program: &str, args: &[&'a str]) -> (Vec<&'a str>, bool) {
+    let is_build = program == "cargo"
+        && args
+            .first()
+            .is_some_and(|subcommand| CARGO_BUILD_SUBCOMMANDS.contains(subcommand));
+    let has_format = args.iter().any(|arg| arg.starts_with("--message-format"));
+    let mut args = args.to_vec();
+    if !is_build || has_format {
+        return (args, false);
+    }
+    let at = args
+        .iter()
+        .position(|&arg| arg == "--")
+        .unwrap_or(args.len());
+    args.insert(at, "--message-format=json");
+    (args, true)
+}
+
 /// Run a command with progress reporting and output parsing
 ///
+/// A cargo build runs with `--message-format=json`; see
+/// [`CargoParser`](crate::output_parser::CargoParser) for the events it
+/// yields. Stderr is drained alongside stdout and returned in
+/// [`BuildError::CommandFailed`] when the command fails.
+///
 /// # Arguments
 ///
 /// * `program` - Command to run
 /// * `args` - Command arguments
 /// * `progress` - Optional progress reporter
 /// * `verbose` - If true, show all output; if false, only show parsed events
-#[allow(dead_code)]
 pub(crate) async fn run_command_with_progress<S: AsRef<str>>(
     program: &str,
     args: &[S],
@@ -128,6 +205,7 @@ pub(crate) async fn run_command_with_progress<S: AsRef<str>>(
     verbose: bool,
 ) -> BuildResult<()> {
     let args_str: Vec<&str> = args.iter().map(std::convert::AsRef::as_ref).collect();
+    let (args_str, _) = with_cargo_json_format(program, &args_str);
 
     tracing::debug!("Running: {} {}", program, args_str.join(" "));
 
@@ -144,59 +222,39 @@ pub(crate) async fn run_command_with_progress<S: AsRef<str>>(
         })?;
 
     let parser = get_parser(program);
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
 
-    if let Some(stdout) = child.stdout.take() {
-        let reader = BufReader::new(stdout);
-        let mut lines = reader.lines();
-
+    // Both pipes are read at once: leaving either unread would stall the
+    // child once its buffer fills.
+    let read_stdout = async {
+        let Some(stdout) = stdout else { return };
+        let mut lines = BufReader::new(stdout).lines();
         while let Ok(Some(line)) = lines.next_line().await {
             if verbose {
                 tracing::info!("{}", line);
             }
-
-            if let Some(event) = parser.parse_line(&line)
-                && let Some(ref mut prog) = progress
-            {
-                match event {
-                    BuildEvent::Started { task } => {
-                        if !verbose {
-                            prog.set_message(&task);
-                        }
-                    }
-                    BuildEvent::Progress { current, total } => {
-                        #[allow(clippy::cast_possible_truncation)]
-                        let percent = (current * 100 / total.max(1)) as u8;
-                        prog.set_progress(percent);
-                    }
-                    BuildEvent::Completed { task, duration_ms } => {
-                        let msg = if let Some(ms) = duration_ms {
-                            format!("{} ({:.2}s)", task, ms as f64 / 1000.0)
-                        } else {
-                            task
-                        };
-                        if !verbose {
-                            prog.finish_phase(msg);
-                        }
-                    }
-                    BuildEvent::Warning { message } => {
-                        if !verbose {
-                            tracing::warn!("{}", message);
-                        }
-                    }
-                    BuildEvent::Error { message } => {
-                        if !verbose {
-                            tracing::error!("{}", message);
-                        }
-                    }
-                    BuildEvent::Info { message } => {
-                        if !verbose {
-                            prog.set_message(&message);
-                        }
-                    }
-                }
+            if let Some(event) = parser.parse_line(&line) {
+                report_event(event, progress.as_deref_mut(), verbose);
             }
         }
-    }
+    };
+    let read_stderr = async {
+        let mut captured = String::new();
+        let Some(stderr) = stderr else {
+            return captured;
+        };
+        let mut lines = BufReader::new(stderr).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            if verbose {
+                tracing::info!("{}", line);
+            }
+            captured.push_str(&line);
+            captured.push('\n');
+        }
+        captured
+    };
+    let ((), stderr) = tokio::join!(read_stdout, read_stderr);
 
     let status = child.wait().await.map_err(|e| BuildError::CommandFailed {
         command: format!("{} {}", program, args_str.join(" ")),
@@ -208,9 +266,129 @@ pub(crate) async fn run_command_with_progress<S: AsRef<str>>(
         return Err(BuildError::CommandFailed {
             command: format!("{} {}", program, args_str.join(" ")),
             exit_code: status.code().unwrap_or(-1),
-            stderr: format!("Command failed with exit code: {:?}", status.code()),
+            stderr,
         });
     }
 
     Ok(())
 }
+
+/// Log a parsed build event and forward it to `progress`, if any.
+///
+/// Warnings, errors and diagnostics are logged even without a progress
+/// reporter; in verbose mode the raw line has already been shown.
+fn report_event(event: BuildEvent, progress: Option<&mut BuildProgress>, verbose: bool) {
+    match event {
+        BuildEvent::Started { task } => {
+            if !verbose && let Some(prog) = progress {
+                prog.set_message(&task);
+            }
+        }
+        BuildEvent::Progress { current, total } => {
+            if let Some(prog) = progress {
+                #[allow(clippy::cast_possible_truncation)]
+                let percent = (current * 100 / total.max(1)) as u8;
+                prog.set_progress(percent);
+            }
+        }
+        BuildEvent::Completed { task, duration_ms } => {
+            if !verbose && let Some(prog) = progress {
+                let msg = if let Some(ms) = duration_ms {
+                    format!("{} ({:.2}s)", task, ms as f64 / 1000.0)
+                } else {
+                    task
+                };
+                prog.finish_phase(msg);
+            }
+        }
+        BuildEvent::Warning { message } => {
+            if !verbose {
+                tracing::warn!("{}", message);
+            }
+        }
+        BuildEvent::Error { message } => {
+            if !verbose {
+                tracing::error!("{}", message);
+            }
+        }
+        BuildEvent::Diagnostic {
+            level,
+            message,
+            file,
+            line,
+            column,
+            ..
+        } => {
+            if !verbose {
+                let location = match (file, line, column) {
+                    (Some(file), Some(line), Some(column)) => {
+                        format!("{}:{line}:{column}: ", file.display())
+                    }
+                    (Some(file), _, _) => format!("{}: ", file.display()),
+                    _ => String::new(),
+                };
+                match level {
+                    DiagnosticLevel::Error => {
+                        tracing::error!("{}{}: {}", location, level, message);
+                    }
+                    DiagnosticLevel::Warning => {
+                        tracing::warn!("{}{}: {}", location, level, message);
+                    }
+                    DiagnosticLevel::Note | DiagnosticLevel::Help => {
+                        tracing::info!("{}{}: {}", location, level, message);
+                    }
+                }
+            }
+        }
+        BuildEvent::Info { message } => {
+            if !verbose && let Some(prog) = progress {
+                prog.set_message(&message);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cargo_build_gets_json_messages_before_the_separator() {
+        let (args, json) = with_cargo_json_format("cargo", &["build", "--release", "--", "-v"]);
+        assert!(json);
+        assert_eq!(
+            args,
+            ["build", "--release", "--message-format=json", "--", "-v"]
+        );
+    }
+
+    #[test]
+    fn other_commands_and_explicit_formats_are_untouched() {
+        for (program, args) in [
+            ("cargo", &["build", "--message-format=short"][..]),
+            ("cargo", &["run"][..]),
+            ("wasm-pack", &["build"][..]),
+        ] {
+            let (rewritten, json) = with_cargo_json_format(program, args);
+            assert!(!json, "{program} {args:?}");
+            assert_eq!(rewritten, args);
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn failed_command_reports_its_stderr() {
+        let err = run_command_with_progress("sh", &["-c", "echo broken >&2; exit 3"], None, false)
+            .await
+            .expect_err("command exits non-zero");
+        match err {
+            BuildError::CommandFailed {
+                exit_code, stderr, ..
+            } => {
+                assert_eq!(exit_code, 3);
+                assert_eq!(stderr, "broken\n");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+}
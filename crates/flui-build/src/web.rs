@@ -1,5 +1,6 @@
 use std::path::{Path, PathBuf};
 
+use crate::cache;
 use crate::error::{BuildError, BuildResult};
 use crate::platform::{BuildArtifacts, BuilderContext, FinalArtifacts, PlatformBuilder, private};
 use crate::util::{check_command_exists, process};
@@ -23,41 +24,16 @@ pub fn new(workspace_root: &Path) -> BuildResult<Self> {
     }
 }
 
-impl private::Sealed for WebBuilder {}
-
-impl PlatformBuilder for WebBuilder {
-    fn platform_name(&self) -> &'static str {
-        "web"
-    }
-
-    fn validate_environment(&self) -> BuildResult<()> {
-        // Check wasm-pack
-        check_command_exists("wasm-pack")?;
-
-        // Check WASM target
-        let output = std::process::Command::new("rustup")
-            .args(["target", "list", "--installed"])
-            .output()?;
-
-        let installed_targets = String::from_utf8_lossy(&output.stdout);
-
-        if !installed_targets.contains("wasm32-unknown-unknown") {
-            return Err(BuildError::TargetNotInstalled {
-                target: "wasm32-unknown-unknown".to_string(),
-                install_cmd: "rustup target add wasm32-unknown-unknown".to_string(),
-            });
-        }
-
-        Ok(())
-    }
-
-    async fn build_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts> {
+impl WebBuilder {
+    /// Compile the Rust libraries, bypassing the build cache.
+    async fn compile_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts> {
         let crate::platform::Platform::Web { target } = &ctx.platform else {
             return Err(BuildError::InvalidPlatform {
                 reason: "Expected Web platform".to_string(),
             });
         };
 
+        require_es_module_target(target)?;
         tracing::info!("Building WASM for target: {}", target);
 
         let web_dist_dir = self
@@ -123,42 +99,54 @@ async fn build_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts>
             metadata: serde_json::json!({}),
         })
     }
+}
+
+impl private::Sealed for WebBuilder {}
+
+impl PlatformBuilder for WebBuilder {
+    fn platform_name(&self) -> &'static str {
+        "web"
+    }
+
+    fn validate_environment(&self) -> BuildResult<()> {
+        // Check wasm-pack
+        check_command_exists("wasm-pack")?;
+
+        // Check WASM target
+        let output = std::process::Command::new("rustup")
+            .args(["target", "list", "--installed"])
+            .output()?;
+
+        let installed_targets = String::from_utf8_lossy(&output.stdout);
+
+        if !installed_targets.contains("wasm32-unknown-unknown") {
+            return Err(BuildError::TargetNotInstalled {
+                target: "wasm32-unknown-unknown".to_string(),
+                install_cmd: "rustup target add wasm32-unknown-unknown".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn build_rust(&self, ctx: &BuilderContext) -> BuildResult<BuildArtifacts> {
+        cache::cached_build(ctx, self.compile_rust(ctx)).await
+    }
 
     async fn build_platform(
         &self,
         ctx: &BuilderContext,
         artifacts: &BuildArtifacts,
     ) -> BuildResult<FinalArtifacts> {
-        // Copy HTML and other web assets to dist
-        let web_dir = self.workspace_root.join("platforms").join("web");
-        let dist_dir = web_dir.join("dist");
-
-        // Copy index.html
-        let index_html = web_dir.join("index.html");
-        if index_html.exists() {
-            std::fs::copy(&index_html, dist_dir.join("index.html"))?;
-            tracing::debug!("Copied index.html");
-        }
-
-        // Copy manifest.json if exists
-        let manifest = web_dir.join("manifest.json");
-        if manifest.exists() {
-            std::fs::copy(&manifest, dist_dir.join("manifest.json"))?;
-            tracing::debug!("Copied manifest.json");
-        }
-
-        // Copy icons directory if exists
-        let icons_dir = web_dir.join("icons");
-        if icons_dir.exists() {
-            let dist_icons = dist_dir.join("icons");
-            std::fs::create_dir_all(&dist_icons)?;
-            for entry in std::fs::read_dir(&icons_dir)? {
-                let entry = entry?;
-                let dest = dist_icons.join(entry.file_name());
-                std::fs::copy(entry.path(), dest)?;
-            }
-            tracing::debug!("Copied icons directory");
+        if let crate::platform::Platform::Web { target } = &ctx.platform {
+            require_es_module_target(target)?;
         }
+        let dist_dir = self
+            .workspace_root
+            .join("platforms")
+            .join("web")
+            .join("dist");
+        write_web_bundle(&self.workspace_root, &dist_dir)?;
 
         // Copy dist to output directory
         let output_dir = &ctx.output_dir;
@@ -180,6 +168,7 @@ async fn build_platform(
         Ok(FinalArtifacts {
             app_binary: output_dir.join("index.html"),
             size_bytes,
+            split_binaries: Vec::new(),
         })
     }
 
@@ -204,6 +193,197 @@ async fn clean(&self, ctx: &BuilderContext) -> BuildResult<()> {
     }
 }
 
+/// Default page, used when the project has no `web/index.html`.
+///
+/// Templates may use `{{title}}`, `{{loader}}`, `{{js_file}}` and
+/// `{{wasm_file}}`; see [`render_index_html`].
+pub const DEFAULT_INDEX_HTML: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="utf-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1">
+    <title>{{title}}</title>
+    <link rel="preload" href="./{{wasm_file}}" as="fetch" type="application/wasm" crossorigin>
+    <style>
+        html, body { margin: 0; height: 100%; overflow: hidden; }
+        #flui-canvas { display: block; width: 100%; height: 100%; }
+    </style>
+</head>
+<body>
+    <canvas id="flui-canvas"></canvas>
+    <script type="module" src="./{{loader}}"></script>
+</body>
+</html>
+"#;
+
+/// File name of the generated JS bootstrap.
+pub const LOADER_FILE: &str = "bootstrap.js";
+
+/// Filenames of the wasm-bindgen output that the page loads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasmBundle {
+    /// The JS glue module (`<name>.js`).
+    pub js_file: String,
+    /// The WASM module (`<name>_bg.wasm`).
+    pub wasm_file: String,
+}
+
+impl WasmBundle {
+    /// Locate `<name>_bg.wasm` and its `<name>.js` glue in `dist_dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::PathNotFound`] if either file is missing.
+    pub fn find(dist_dir: &Path) -> BuildResult<Self> {
+        let mut wasm_files: Vec<String> = std::fs::read_dir(dist_dir)
+            .map_err(|_| {
+                BuildError::path_not_found(dist_dir.to_path_buf(), "looking for wasm-pack output")
+            })?
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter(|name| name.ends_with("_bg.wasm"))
+            .collect();
+        wasm_files.sort();
+
+        let Some(wasm_file) = wasm_files.into_iter().next() else {
+            return Err(BuildError::path_not_found(
+                dist_dir.join("*_bg.wasm"),
+                "wasm-pack produced no WASM module",
+            ));
+        };
+
+        let js_file = format!("{}.js", wasm_file.trim_end_matches("_bg.wasm"));
+        if !dist_dir.join(&js_file).is_file() {
+            return Err(BuildError::path_not_found(
+                dist_dir.join(&js_file),
+                "wasm-pack JS glue for the WASM module",
+            ));
+        }
+
+        Ok(Self { js_file, wasm_file })
+    }
+}
+
+/// The project's `index.html` template, `web/index.html`. `None` means use
+/// [`DEFAULT_INDEX_HTML`].
+///
+/// `platforms/web/index.html` is deliberately not consulted: it is the
+/// Flutter-style page loading `flutter_bootstrap.js`, with none of the
+/// placeholders [`render_index_html`] fills.
+#[must_use]
+pub fn index_template(workspace_root: &Path) -> Option<PathBuf> {
+    Some(workspace_root.join("web").join("index.html")).filter(|path| path.is_file())
+}
+
+/// The only wasm-pack target [`bootstrap_js`] can load.
+pub const ES_MODULE_TARGET: &str = "web";
+
+/// Reject wasm-pack targets whose output the generated page cannot load.
+///
+/// [`bootstrap_js`] `import`s the glue's default `init` export, which only
+/// `--target web` emits; `bundler`, `nodejs` and `no-modules` output needs a
+/// different loader.
+///
+/// # Errors
+///
+/// Returns [`BuildError::InvalidConfig`] for any target other than
+/// [`ES_MODULE_TARGET`].
+pub fn require_es_module_target(target: &str) -> BuildResult<()> {
+    if target == ES_MODULE_TARGET {
+        Ok(())
+    } else {
+        Err(BuildError::invalid_config(
+            "target",
+            format!(
+                "wasm-pack target `{target}` is not supported; the generated page loads \
+                 `--target {ES_MODULE_TARGET}` ES-module output"
+            ),
+        ))
+    }
+}
+
+/// Fill an `index.html` template's placeholders.
+#[must_use]
+pub fn render_index_html(template: &str, title: &str, bundle: &WasmBundle) -> String {
+    template
+        .replace("{{title}}", title)
+        .replace("{{loader}}", LOADER_FILE)
+        .replace("{{js_file}}", &bundle.js_file)
+        .replace("{{wasm_file}}", &bundle.wasm_file)
+}
+
+/// JS bootstrap that makes sure a canvas exists and initializes the module.
+///
+/// Expects `--target web` output (see [`require_es_module_target`]).
+#[must_use]
+pub fn bootstrap_js(bundle: &WasmBundle) -> String {
+    format!(
+        r"// Generated by `flui build web`.
+import init from './{js}';
+
+if (!document.getElementById('flui-canvas')) {{
+    const canvas = document.createElement('canvas');
+    canvas.id = 'flui-canvas';
+    document.body.appendChild(canvas);
+}}
+
+await init({{ module_or_path: new URL('./{wasm}', import.meta.url) }});
+",
+        js = bundle.js_file,
+        wasm = bundle.wasm_file,
+    )
+}
+
+/// Write `index.html`, the JS bootstrap and project assets next to the
+/// wasm-pack output in `dist_dir`.
+///
+/// The WASM output is validated first, so nothing is written for a failed
+/// build.
+///
+/// # Errors
+///
+/// Returns an error if the wasm-pack output is missing or a file cannot be
+/// read or written.
+pub fn write_web_bundle(workspace_root: &Path, dist_dir: &Path) -> BuildResult<WasmBundle> {
+    let bundle = WasmBundle::find(dist_dir)?;
+
+    let template = match index_template(workspace_root) {
+        Some(path) => {
+            tracing::debug!("Using index.html template {:?}", path);
+            std::fs::read_to_string(path)?
+        }
+        None => DEFAULT_INDEX_HTML.to_string(),
+    };
+    let title = workspace_root
+        .file_name()
+        .map_or_else(|| "FLUI".into(), |name| name.to_string_lossy());
+    std::fs::write(
+        dist_dir.join("index.html"),
+        render_index_html(&template, &title, &bundle),
+    )?;
+    std::fs::write(dist_dir.join(LOADER_FILE), bootstrap_js(&bundle))?;
+
+    // Copy manifest.json if exists
+    let web_dir = workspace_root.join("platforms").join("web");
+    let manifest = web_dir.join("manifest.json");
+    if manifest.exists() {
+        std::fs::copy(&manifest, dist_dir.join("manifest.json"))?;
+        tracing::debug!("Copied manifest.json");
+    }
+
+    // Copy icons and project assets if present
+    for (src, name) in [
+        (web_dir.join("icons"), "icons"),
+        (workspace_root.join("assets"), "assets"),
+    ] {
+        if src.is_dir() {
+            copy_dir_recursive(&src, &dist_dir.join(name))?;
+            tracing::debug!("Copied {} directory", name);
+        }
+    }
+
+    Ok(bundle)
+}
+
 /// Recursively copy directory
 fn copy_dir_recursive(src: &Path, dst: &Path) -> BuildResult<()> {
     std::fs::create_dir_all(dst)?;
@@ -222,3 +402,97 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> BuildResult<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wasm_pack_output(dist: &Path) {
+        std::fs::create_dir_all(dist).unwrap();
+        std::fs::write(dist.join("my_app_bg.wasm"), b"\0asm").unwrap();
+        std::fs::write(dist.join("my_app.js"), "export default function init() {}").unwrap();
+    }
+
+    #[test]
+    fn generated_html_and_loader_reference_the_bundle() {
+        let root = tempfile::tempdir().unwrap();
+        let dist = root.path().join("dist");
+        wasm_pack_output(&dist);
+        std::fs::create_dir_all(root.path().join("assets")).unwrap();
+        std::fs::write(root.path().join("assets").join("logo.png"), b"png").unwrap();
+
+        let bundle = write_web_bundle(root.path(), &dist).unwrap();
+        assert_eq!(bundle.js_file, "my_app.js");
+        assert_eq!(bundle.wasm_file, "my_app_bg.wasm");
+
+        let html = std::fs::read_to_string(dist.join("index.html")).unwrap();
+        assert!(html.contains(r#"src="./bootstrap.js""#));
+        assert!(html.contains(r#"href="./my_app_bg.wasm""#));
+        let loader = std::fs::read_to_string(dist.join(LOADER_FILE)).unwrap();
+        assert!(loader.contains("from './my_app.js'"));
+        assert!(loader.contains("'./my_app_bg.wasm'"));
+        assert!(dist.join("assets").join("logo.png").is_file());
+    }
+
+    #[test]
+    fn project_template_is_preferred() {
+        let root = tempfile::tempdir().unwrap();
+        let dist = root.path().join("dist");
+        wasm_pack_output(&dist);
+        std::fs::create_dir_all(root.path().join("web")).unwrap();
+        std::fs::write(
+            root.path().join("web").join("index.html"),
+            r#"<main id="custom"><script type="module" src="./{{loader}}"></script></main>"#,
+        )
+        .unwrap();
+
+        write_web_bundle(root.path(), &dist).unwrap();
+
+        let html = std::fs::read_to_string(dist.join("index.html")).unwrap();
+        assert_eq!(
+            html,
+            r#"<main id="custom"><script type="module" src="./bootstrap.js"></script></main>"#
+        );
+    }
+
+    #[test]
+    fn flutter_platform_template_is_not_used() {
+        let root = tempfile::tempdir().unwrap();
+        let dist = root.path().join("dist");
+        wasm_pack_output(&dist);
+        let platform_web = root.path().join("platforms").join("web");
+        std::fs::create_dir_all(&platform_web).unwrap();
+        std::fs::write(
+            platform_web.join("index.html"),
+            r#"<script src="flutter_bootstrap.js" async></script>"#,
+        )
+        .unwrap();
+
+        assert_eq!(index_template(root.path()), None);
+        write_web_bundle(root.path(), &dist).unwrap();
+        let html = std::fs::read_to_string(dist.join("index.html")).unwrap();
+        assert!(html.contains(r#"src="./bootstrap.js""#));
+    }
+
+    #[test]
+    fn only_the_es_module_target_is_accepted() {
+        assert!(require_es_module_target("web").is_ok());
+        for target in ["bundler", "nodejs", "no-modules"] {
+            let err = require_es_module_target(target).unwrap_err();
+            assert!(matches!(err, BuildError::InvalidConfig { .. }), "{target}");
+        }
+    }
+
+    #[test]
+    fn missing_wasm_output_writes_nothing() {
+        let root = tempfile::tempdir().unwrap();
+        let dist = root.path().join("dist");
+        std::fs::create_dir_all(&dist).unwrap();
+        std::fs::write(dist.join("my_app.js"), "").unwrap();
+
+        let err = write_web_bundle(root.path(), &dist).unwrap_err();
+        assert!(matches!(err, BuildError::PathNotFound { .. }));
+        assert!(!dist.join("index.html").exists());
+        assert!(!dist.join(LOADER_FILE).exists());
+    }
+}
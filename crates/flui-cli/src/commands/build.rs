@@ -4,8 +4,8 @@
 use crate::error::{CliResult, ResultExt};
 use console::style;
 use flui_build::{
-    AndroidBuilder, BuildPhase, BuilderContextBuilder, DesktopBuilder, Platform, PlatformBuilder,
-    Profile, ProgressManager, WebBuilder,
+    AndroidBuilder, AndroidSigningConfig, BuildPhase, BuilderContextBuilder, DesktopBuilder,
+    Platform, PlatformBuilder, Profile, ProgressManager, WebBuilder,
 };
 use std::path::PathBuf;
 
@@ -26,6 +26,8 @@ pub struct BuildOptions {
     pub universal: bool,
     /// Use verbose output with progress bars.
     pub verbose: bool,
+    /// Rebuild even if the build fingerprint is unchanged.
+    pub no_cache: bool,
 }
 
 /// Execute the build command.
@@ -48,6 +50,7 @@ pub fn execute(
     split_per_abi: bool,
     optimize_wasm: bool,
     universal: bool,
+    no_cache: bool,
 ) -> CliResult<()> {
     let options = BuildOptions {
         release,
@@ -55,6 +58,7 @@ pub fn execute(
         optimize_wasm,
         universal,
         verbose: false,
+        no_cache,
     };
 
     let mode = if release { "release" } else { "debug" };
@@ -105,6 +109,7 @@ pub fn execute_with_progress(
     split_per_abi: bool,
     optimize_wasm: bool,
     universal: bool,
+    no_cache: bool,
 ) -> CliResult<()> {
     let options = BuildOptions {
         release,
@@ -112,6 +117,7 @@ pub fn execute_with_progress(
         optimize_wasm,
         universal,
         verbose: true,
+        no_cache,
     };
 
     let progress_manager = ProgressManager::new();
@@ -139,6 +145,17 @@ pub fn execute_with_progress(
     result
 }
 
+/// Android ABIs to build: every supported ABI with `--split-per-abi`,
+/// otherwise just arm64.
+fn android_targets(options: BuildOptions) -> Vec<String> {
+    let abis: &[&str] = if options.split_per_abi {
+        &["arm64-v8a", "armeabi-v7a", "x86_64", "x86"]
+    } else {
+        &["arm64-v8a"]
+    };
+    abis.iter().map(ToString::to_string).collect()
+}
+
 fn build_android(options: BuildOptions, output: Option<&PathBuf>) -> CliResult<()> {
     let spinner = cliclack::spinner();
     spinner.start("Building Android APK...");
@@ -155,13 +172,19 @@ fn build_android(options: BuildOptions, output: Option<&PathBuf>) -> CliResult<(
 
     let mut builder = BuilderContextBuilder::new(workspace_root)
         .with_platform(Platform::Android {
-            targets: vec!["arm64-v8a".to_string()],
+            targets: android_targets(options),
         })
-        .with_profile(profile);
+        .with_profile(profile)
+        .with_cache(!options.no_cache);
 
     if let Some(out) = output {
         builder = builder.with_output_dir(out.clone());
     }
+    if let Some(signing) =
+        AndroidSigningConfig::from_env().context("Invalid Android signing configuration")?
+    {
+        builder = builder.with_android_signing(signing);
+    }
 
     let ctx = builder.build();
 
@@ -186,6 +209,9 @@ fn build_android(options: BuildOptions, output: Option<&PathBuf>) -> CliResult<(
         "APK location: {}",
         final_artifacts.app_binary.display()
     ))?;
+    for apk in final_artifacts.split_binaries.iter().skip(1) {
+        cliclack::log::success(format!("APK location: {}", apk.display()))?;
+    }
     cliclack::log::info(format!(
         "Size: {:.2} MB",
         final_artifacts.size_bytes as f64 / 1_048_576.0
@@ -213,13 +239,19 @@ fn build_android_with_progress(
 
     let mut builder = BuilderContextBuilder::new(workspace_root)
         .with_platform(Platform::Android {
-            targets: vec!["arm64-v8a".to_string()],
+            targets: android_targets(options),
         })
-        .with_profile(profile);
+        .with_profile(profile)
+        .with_cache(!options.no_cache);
 
     if let Some(out) = output {
         builder = builder.with_output_dir(out.clone());
     }
+    if let Some(signing) =
+        AndroidSigningConfig::from_env().context("Invalid Android signing configuration")?
+    {
+        builder = builder.with_android_signing(signing);
+    }
 
     let ctx = builder.build();
     std::fs::create_dir_all(&ctx.output_dir)?;
@@ -289,7 +321,8 @@ fn build_web(options: BuildOptions, output: Option<&PathBuf>) -> CliResult<()> {
         .with_platform(Platform::Web {
             target: "web".to_string(),
         })
-        .with_profile(profile);
+        .with_profile(profile)
+        .with_cache(!options.no_cache);
 
     if let Some(out) = output {
         builder = builder.with_output_dir(out.clone());
@@ -344,7 +377,8 @@ fn build_web_with_progress(
         .with_platform(Platform::Web {
             target: "web".to_string(),
         })
-        .with_profile(profile);
+        .with_profile(profile)
+        .with_cache(!options.no_cache);
 
     if let Some(out) = output {
         builder = builder.with_output_dir(out.clone());
@@ -399,7 +433,8 @@ fn build_desktop(options: BuildOptions, output: Option<&PathBuf>) -> CliResult<(
 
     let mut builder = BuilderContextBuilder::new(workspace_root)
         .with_platform(Platform::Desktop { target: None })
-        .with_profile(profile);
+        .with_profile(profile)
+        .with_cache(!options.no_cache);
 
     if let Some(out) = output {
         builder = builder.with_output_dir(out.clone());
@@ -455,7 +490,8 @@ fn build_desktop_with_progress(
 
     let mut builder = BuilderContextBuilder::new(workspace_root)
         .with_platform(Platform::Desktop { target: None })
-        .with_profile(profile);
+        .with_profile(profile)
+        .with_cache(!options.no_cache);
 
     if let Some(out) = output {
         builder = builder.with_output_dir(out.clone());
@@ -518,7 +554,8 @@ fn build_specific_platform(
         .with_platform(Platform::Desktop {
             target: Some(target_triple.to_string()),
         })
-        .with_profile(profile);
+        .with_profile(profile)
+        .with_cache(!options.no_cache);
 
     if let Some(out) = output {
         builder = builder.with_output_dir(out.clone());
@@ -578,7 +615,8 @@ fn build_specific_platform_with_progress(
         .with_platform(Platform::Desktop {
             target: Some(target_triple.to_string()),
         })
-        .with_profile(profile);
+        .with_profile(profile)
+        .with_cache(!options.no_cache);
 
     if let Some(out) = output {
         builder = builder.with_output_dir(out.clone());
@@ -1,12 +1,148 @@
 //! Doctor command for checking FLUI environment setup.
 //!
-//! Verifies that all required tools and SDKs are properly installed.
+//! Verifies that all required tools and SDKs are properly installed. Each
+//! probe produces a [`Check`]; the report is rendered either for humans
+//! (cliclack) or as JSON (`--json`) for CI and editor integrations.
 
-use crate::error::CliResult;
+use crate::error::{CliError, CliResult, ResultExt};
 use console::style;
+use serde::Serialize;
 use std::fmt::Write;
 use std::process::Command;
 
+/// Outcome of a single doctor check.
+///
+/// Ordered by severity, so the overall status is the maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckResult {
+    /// The tool is installed and usable.
+    Ok,
+    /// Usable, but optional or not in the recommended state.
+    Warn,
+    /// A required tool or SDK is missing.
+    Missing,
+}
+
+impl CheckResult {
+    fn icon(self) -> console::StyledObject<&'static str> {
+        match self {
+            Self::Ok => style("✓").green(),
+            Self::Warn => style("⚠").yellow(),
+            Self::Missing => style("✗").red(),
+        }
+    }
+}
+
+/// A single toolchain check.
+#[derive(Debug, Clone, Serialize)]
+pub struct Check {
+    /// Human-readable name (e.g. "Rust", "Android SDK").
+    pub name: &'static str,
+    /// Outcome of the check.
+    pub status: CheckResult,
+    /// Whether a [`CheckResult::Missing`] status fails the run. Platform
+    /// checks are optional unless their platform was asked for.
+    pub required: bool,
+    /// What was found (version, path, or why it failed).
+    pub detail: String,
+    /// How to fix a failing check.
+    pub fix_hint: Option<String>,
+    /// Extra lines shown with `--verbose`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+}
+
+impl Check {
+    fn new(name: &'static str, status: CheckResult, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            required: true,
+            detail: detail.into(),
+            fix_hint: None,
+            notes: Vec::new(),
+        }
+    }
+
+    fn ok(name: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckResult::Ok, detail)
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckResult::Warn, detail)
+    }
+
+    fn missing(name: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckResult::Missing, detail)
+    }
+
+    fn with_fix(mut self, fix_hint: impl Into<String>) -> Self {
+        self.fix_hint = Some(fix_hint.into());
+        self
+    }
+
+    fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Whether this check fails the run.
+    fn failed(&self) -> bool {
+        self.required && self.status == CheckResult::Missing
+    }
+
+    fn render(&self, verbose: bool) -> String {
+        let detail = match self.status {
+            CheckResult::Ok => style(self.detail.as_str()).cyan(),
+            CheckResult::Warn => style(self.detail.as_str()).yellow(),
+            CheckResult::Missing => style(self.detail.as_str()).red(),
+        };
+        let mut result = format!("{} {}: {}", self.status.icon(), self.name, detail);
+        if verbose {
+            for note in &self.notes {
+                write!(result, "\n  {}", style(note).dim()).ok();
+            }
+        }
+        if let Some(fix_hint) = &self.fix_hint {
+            write!(result, "\n  {}", style(fix_hint).dim()).ok();
+        }
+        result
+    }
+}
+
+/// Structured doctor report, serialized by `flui doctor --json`.
+#[derive(Debug, Clone, Serialize)]
+pub struct DoctorReport {
+    /// Checks in the order they ran.
+    pub checks: Vec<Check>,
+    /// Worst status across all checks, optional ones included.
+    pub overall: CheckResult,
+    /// `false` if any required check is [`CheckResult::Missing`].
+    pub passed: bool,
+}
+
+impl DoctorReport {
+    fn new(checks: Vec<Check>) -> Self {
+        let overall = checks
+            .iter()
+            .map(|check| check.status)
+            .max()
+            .unwrap_or(CheckResult::Ok);
+        let passed = !checks.iter().any(Check::failed);
+        Self {
+            checks,
+            overall,
+            passed,
+        }
+    }
+}
+
 /// Execute the doctor command.
 ///
 /// # Arguments
@@ -15,388 +151,251 @@
 /// * `android` - Check only Android toolchain
 /// * `ios` - Check only iOS toolchain
 /// * `web` - Check only Web toolchain
+/// * `json` - Print a machine-readable report to stdout
+///
+/// # Errors
+///
+/// Returns [`CliError::DoctorFailed`] (non-zero exit) if any required check
+/// reports [`CheckResult::Missing`], in both human-readable and JSON mode. Missing platform SDKs
+/// only count when that platform was selected (`android`, `ios`, `web`).
 #[expect(
     clippy::fn_params_excessive_bools,
     reason = "matches CLI arg structure"
 )]
-pub fn execute(verbose: bool, android: bool, ios: bool, web: bool) -> CliResult<()> {
+pub fn execute(verbose: bool, android: bool, ios: bool, web: bool, json: bool) -> CliResult<()> {
+    let report = DoctorReport::new(collect_checks(verbose, android, ios, web));
+
+    if json {
+        let output =
+            serde_json::to_string_pretty(&report).context("Failed to serialize doctor report")?;
+        println!("{output}");
+        return if report.passed {
+            Ok(())
+        } else {
+            Err(CliError::DoctorFailed)
+        };
+    }
+
     cliclack::intro(style(" flui doctor ").on_cyan().black())?;
 
-    let mut results = Vec::with_capacity(10);
-    let mut all_ok = true;
+    let output = report
+        .checks
+        .iter()
+        .map(|check| check.render(verbose))
+        .collect::<Vec<_>>()
+        .join("\n");
+    cliclack::note("Environment Check", output)?;
 
-    // Core checks (always run)
-    results.push(check_rust(verbose, &mut all_ok));
-    results.push(check_cargo(&mut all_ok));
-    results.push(check_flui());
+    if !report.passed {
+        cliclack::outro_cancel("Some checks failed. Please fix the issues above.")?;
+        return Err(CliError::DoctorFailed);
+    } else if report.overall == CheckResult::Missing {
+        cliclack::outro(style("Required checks passed; some optional SDKs are missing.").yellow())?;
+    } else {
+        cliclack::outro(style("All checks passed!").green())?;
+    }
+
+    Ok(())
+}
 
-    // Platform-specific checks
+/// Run the checks selected by the platform flags. With no flag set, the
+/// core checks and every platform run, the platform ones as optional;
+/// otherwise only the named platforms, as required.
+#[expect(
+    clippy::fn_params_excessive_bools,
+    reason = "matches CLI arg structure"
+)]
+fn collect_checks(verbose: bool, android: bool, ios: bool, web: bool) -> Vec<Check> {
+    let mut checks = Vec::with_capacity(10);
     let check_all = !android && !ios && !web;
 
+    if check_all {
+        checks.push(check_rust(verbose));
+        checks.push(check_cargo());
+        checks.push(check_flui());
+    }
+
     if android || check_all {
-        results.push(check_java(verbose, &mut all_ok));
-        results.push(check_android(verbose, &mut all_ok));
+        checks.push(check_java(verbose).with_required(android));
+        checks.push(check_android_sdk(verbose).with_required(android));
+        checks.push(
+            check_targets(
+                "Android targets",
+                &[
+                    "aarch64-linux-android",
+                    "armv7-linux-androideabi",
+                    "i686-linux-android",
+                    "x86_64-linux-android",
+                ],
+            )
+            .with_required(android),
+        );
     }
 
     if ios || check_all {
         #[cfg(target_os = "macos")]
         {
-            results.push(check_ios(verbose, &mut all_ok));
+            checks.push(check_xcode(verbose).with_required(ios));
+            checks.push(
+                check_targets(
+                    "iOS targets",
+                    &[
+                        "aarch64-apple-ios",
+                        "aarch64-apple-ios-sim",
+                        "x86_64-apple-ios",
+                    ],
+                )
+                .with_required(ios),
+            );
         }
         #[cfg(not(target_os = "macos"))]
         {
             if ios {
-                results.push(format!(
-                    "{} iOS: Not available on non-macOS",
-                    style("⚠").yellow()
-                ));
+                checks.push(Check::warn("iOS", "Not available on non-macOS"));
             }
         }
     }
 
     if web || check_all {
-        results.push(check_web(&mut all_ok));
+        checks.push(check_wasm_pack().with_required(web));
+        checks.push(check_targets("WASM target", &["wasm32-unknown-unknown"]).with_required(web));
     }
 
-    // Check wgpu support
-    results.push(check_wgpu(verbose));
-
-    // Display all results
-    let output = results.join("\n");
-    cliclack::note("Environment Check", output)?;
-
-    if all_ok {
-        cliclack::outro(style("All checks passed!").green())?;
-    } else {
-        cliclack::outro_cancel("Some checks failed. Please fix the issues above.")?;
+    if check_all {
+        checks.push(check_wgpu());
     }
 
-    Ok(())
+    checks
 }
 
-fn check_rust(verbose: bool, all_ok: &mut bool) -> String {
+fn check_rust(verbose: bool) -> Check {
     let Ok(output) = Command::new("rustc").arg("--version").output() else {
-        *all_ok = false;
-        return format!(
-            "{} Rust: {}\n  {}",
-            style("✗").red(),
-            style("Not found").red(),
-            style("Install from https://rustup.rs/").dim()
-        );
+        return Check::missing("Rust", "Not found").with_fix("Install from https://rustup.rs/");
     };
 
     let version = String::from_utf8_lossy(&output.stdout);
-    let mut result = format!(
-        "{} Rust: {}",
-        style("✓").green(),
-        style(version.trim()).cyan()
-    );
+    let mut check = Check::ok("Rust", version.trim());
 
     if verbose && let Ok(path) = which::which("rustc") {
-        write!(result, "\n  Path: {}", style(path.display()).dim()).ok();
+        check = check.with_note(format!("Path: {}", path.display()));
     }
 
-    result
+    check
 }
 
-fn check_cargo(all_ok: &mut bool) -> String {
+fn check_cargo() -> Check {
     let Ok(output) = Command::new("cargo").arg("--version").output() else {
-        *all_ok = false;
-        return format!("{} Cargo: {}", style("✗").red(), style("Not found").red());
+        return Check::missing("Cargo", "Not found").with_fix("Install from https://rustup.rs/");
     };
 
     let version = String::from_utf8_lossy(&output.stdout);
-    format!(
-        "{} Cargo: {}",
-        style("✓").green(),
-        style(version.trim()).cyan()
-    )
+    Check::ok("Cargo", version.trim())
 }
 
-fn check_flui() -> String {
+fn check_flui() -> Check {
     let version = env!("CARGO_PKG_VERSION");
-    format!(
-        "{} FLUI CLI: {}",
-        style("✓").green(),
-        style(format!("v{version}")).cyan()
-    )
+    Check::ok("FLUI CLI", format!("v{version}"))
 }
 
-fn check_java(verbose: bool, all_ok: &mut bool) -> String {
+fn check_java(verbose: bool) -> Check {
     let Ok(output) = Command::new("java").arg("-version").output() else {
-        *all_ok = false;
-        return format!(
-            "{} Java: {}\n  {}",
-            style("✗").red(),
-            style("Not found").red(),
-            style("Download from https://adoptium.net/").dim()
-        );
+        return Check::missing("Java", "Not found").with_fix("Download from https://adoptium.net/");
     };
 
     let version_output = String::from_utf8_lossy(&output.stderr);
     let Some(line) = version_output.lines().next() else {
-        return format!(
-            "{} Java: {}",
-            style("⚠").yellow(),
-            style("Version not detected").yellow()
-        );
+        return Check::warn("Java", "Version not detected");
     };
 
-    let mut result = format!("{} Java: {}", style("✓").green(), style(line.trim()).cyan());
+    let mut check = if version_output.contains("version \"1.8") {
+        Check::warn("Java", line.trim()).with_fix("Java 11+ recommended")
+    } else {
+        Check::ok("Java", line.trim())
+    };
 
     if verbose && let Ok(java_home) = std::env::var("JAVA_HOME") {
-        write!(result, "\n  JAVA_HOME: {}", style(java_home).dim()).ok();
-    }
-
-    if version_output.contains("version \"1.8") {
-        write!(result, "\n  {}", style("⚠ Java 11+ recommended").yellow()).ok();
+        check = check.with_note(format!("JAVA_HOME: {java_home}"));
     }
 
-    result
+    check
 }
 
-fn check_android(verbose: bool, all_ok: &mut bool) -> String {
-    let mut results = Vec::with_capacity(3);
+fn check_android_sdk(verbose: bool) -> Check {
+    let Ok(android_home) = std::env::var("ANDROID_HOME") else {
+        return Check::missing("Android SDK", "Not configured")
+            .with_fix("Set ANDROID_HOME environment variable");
+    };
 
-    // Check ANDROID_HOME
-    if let Ok(android_home) = std::env::var("ANDROID_HOME") {
-        let sdk_path = std::path::Path::new(&android_home);
-        if sdk_path.exists() {
-            results.push(format!(
-                "{} Android SDK: {}",
-                style("✓").green(),
-                style(&android_home).cyan()
-            ));
+    let sdk_path = std::path::Path::new(&android_home);
+    if !sdk_path.exists() {
+        return Check::missing("Android SDK", "Path not found")
+            .with_fix(format!("ANDROID_HOME points to missing {android_home}"));
+    }
 
-            if verbose {
-                let ndk_path = sdk_path.join("ndk");
-                if ndk_path.exists() {
-                    results.push(format!("  {} NDK installed", style("✓").green()));
-                } else {
-                    results.push(format!("  {} NDK not found", style("⚠").yellow()));
-                }
-            }
-        } else {
-            *all_ok = false;
-            results.push(format!(
-                "{} Android SDK: {}",
-                style("⚠").yellow(),
-                style("Path not found").yellow()
-            ));
-        }
+    let check = Check::ok("Android SDK", android_home.as_str());
+    if !verbose {
+        return check;
+    }
+    if sdk_path.join("ndk").exists() {
+        check.with_note("NDK installed")
     } else {
-        *all_ok = false;
-        results.push(format!(
-            "{} Android SDK: {}\n  {}",
-            style("⚠").yellow(),
-            style("Not configured").yellow(),
-            style("Set ANDROID_HOME environment variable").dim()
-        ));
+        check.with_note("NDK not found")
     }
-
-    // Check Android Rust targets
-    results.push(check_android_targets(all_ok));
-
-    results.join("\n")
 }
 
-fn check_android_targets(all_ok: &mut bool) -> String {
-    if let Ok(output) = Command::new("rustup")
+/// Check that every target in `required` is installed via rustup.
+fn check_targets(name: &'static str, required: &[&str]) -> Check {
+    let Ok(output) = Command::new("rustup")
         .args(["target", "list", "--installed"])
         .output()
-    {
-        let targets = String::from_utf8_lossy(&output.stdout);
-        let android_targets = [
-            "aarch64-linux-android",
-            "armv7-linux-androideabi",
-            "i686-linux-android",
-            "x86_64-linux-android",
-        ];
-
-        let missing: Vec<&str> = android_targets
-            .iter()
-            .filter(|t| !targets.contains(*t))
-            .copied()
-            .collect();
-
-        if missing.is_empty() {
-            format!(
-                "{} Android targets: {}",
-                style("✓").green(),
-                style("All installed").cyan()
-            )
-        } else {
-            *all_ok = false;
-            let mut result = format!(
-                "{} Android targets: {}",
-                style("⚠").yellow(),
-                style("Missing").yellow()
-            );
-            for target in &missing {
-                write!(result, "\n  {} {}", style("⚠").yellow(), target).ok();
-            }
-            write!(
-                result,
-                "\n  {}",
-                style(format!("rustup target add {}", missing.join(" "))).dim()
-            )
-            .ok();
-            result
-        }
+    else {
+        return Check::warn(name, "rustup not found; targets not checked")
+            .with_fix("Install from https://rustup.rs/");
+    };
+
+    let installed = String::from_utf8_lossy(&output.stdout);
+    let missing: Vec<&str> = required
+        .iter()
+        .filter(|target| !installed.contains(*target))
+        .copied()
+        .collect();
+
+    if missing.is_empty() {
+        Check::ok(name, "All installed")
     } else {
-        String::new()
+        Check::missing(name, format!("Missing {}", missing.join(", ")))
+            .with_fix(format!("rustup target add {}", missing.join(" ")))
     }
 }
 
 #[cfg(target_os = "macos")]
-fn check_ios(verbose: bool, all_ok: &mut bool) -> String {
-    let mut results = Vec::with_capacity(3);
-
+fn check_xcode(verbose: bool) -> Check {
     match Command::new("xcode-select").arg("-p").output() {
-        Ok(output) => {
-            if output.status.success() {
+        Ok(output) if output.status.success() => {
+            let check = Check::ok("Xcode", "Installed");
+            if verbose {
                 let path = String::from_utf8_lossy(&output.stdout);
-                results.push(format!(
-                    "{} Xcode: {}",
-                    style("✓").green(),
-                    style("Installed").cyan()
-                ));
-
-                if verbose {
-                    results.push(format!("  Path: {}", style(path.trim()).dim()));
-                }
+                check.with_note(format!("Path: {}", path.trim()))
             } else {
-                *all_ok = false;
-                results.push(format!(
-                    "{} Xcode: {}\n  {}",
-                    style("✗").red(),
-                    style("Not found").red(),
-                    style("xcode-select --install").dim()
-                ));
+                check
             }
         }
-        Err(_) => {
-            *all_ok = false;
-            results.push(format!(
-                "{} Xcode: {}",
-                style("✗").red(),
-                style("Not found").red()
-            ));
-        }
+        _ => Check::missing("Xcode", "Not found").with_fix("xcode-select --install"),
     }
-
-    results.push(check_ios_targets(all_ok));
-    results.join("\n")
 }
 
-#[cfg(target_os = "macos")]
-fn check_ios_targets(all_ok: &mut bool) -> String {
-    if let Ok(output) = Command::new("rustup")
-        .args(["target", "list", "--installed"])
-        .output()
-    {
-        let targets = String::from_utf8_lossy(&output.stdout);
-        let ios_targets = [
-            "aarch64-apple-ios",
-            "aarch64-apple-ios-sim",
-            "x86_64-apple-ios",
-        ];
-
-        let missing: Vec<&str> = ios_targets
-            .iter()
-            .filter(|t| !targets.contains(*t))
-            .copied()
-            .collect();
-
-        if missing.is_empty() {
-            format!(
-                "{} iOS targets: {}",
-                style("✓").green(),
-                style("All installed").cyan()
-            )
-        } else {
-            *all_ok = false;
-            let mut result = format!(
-                "{} iOS targets: {}",
-                style("⚠").yellow(),
-                style("Missing").yellow()
-            );
-            for target in &missing {
-                result.push_str(&format!("\n  {} {}", style("⚠").yellow(), target));
-            }
-            result
-        }
-    } else {
-        String::new()
-    }
-}
-
-#[cfg(not(target_os = "macos"))]
-#[expect(dead_code, reason = "cross-platform stub, called only on macOS")]
-fn check_ios(_verbose: bool, _all_ok: &mut bool) -> String {
-    String::new()
-}
-
-fn check_web(all_ok: &mut bool) -> String {
-    let mut results = Vec::with_capacity(2);
-
-    // Check wasm-pack
+fn check_wasm_pack() -> Check {
     match Command::new("wasm-pack").arg("--version").output() {
         Ok(output) => {
             let version = String::from_utf8_lossy(&output.stdout);
-            results.push(format!(
-                "{} wasm-pack: {}",
-                style("✓").green(),
-                style(version.trim()).cyan()
-            ));
+            Check::ok("wasm-pack", version.trim())
         }
         Err(_) => {
-            results.push(format!(
-                "{} wasm-pack: {}\n  {}",
-                style("⚠").yellow(),
-                style("Not found (optional)").yellow(),
-                style("cargo install wasm-pack").dim()
-            ));
+            Check::warn("wasm-pack", "Not found (optional)").with_fix("cargo install wasm-pack")
         }
     }
-
-    // Check wasm32 target
-    if let Ok(output) = Command::new("rustup")
-        .args(["target", "list", "--installed"])
-        .output()
-    {
-        let targets = String::from_utf8_lossy(&output.stdout);
-        if targets.contains("wasm32-unknown-unknown") {
-            results.push(format!(
-                "{} WASM target: {}",
-                style("✓").green(),
-                style("Installed").cyan()
-            ));
-        } else {
-            *all_ok = false;
-            results.push(format!(
-                "{} WASM target: {}\n  {}",
-                style("⚠").yellow(),
-                style("Not installed").yellow(),
-                style("rustup target add wasm32-unknown-unknown").dim()
-            ));
-        }
-    }
-
-    results.join("\n")
 }
 
-fn check_wgpu(verbose: bool) -> String {
-    let mut result = format!("{} wgpu: {}", style("✓").green(), style("Available").cyan());
-
-    if verbose {
-        write!(
-            result,
-            "\n  {}",
-            style("Provided via Rust dependencies").dim()
-        )
-        .ok();
-    }
-
-    result
+fn check_wgpu() -> Check {
+    Check::ok("wgpu", "Available").with_note("Provided via Rust dependencies")
 }
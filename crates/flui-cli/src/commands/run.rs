@@ -11,10 +11,11 @@
 use crate::runner::{CargoCommand, OutputStyle};
 use console::style;
 use flui_hot_reload::{
-    dev::SourceWatcher,
+    dev::{ChangeDebouncer, SourceWatcher},
     engine::env as worker_env,
     strategy::{env, timing},
 };
+use std::fmt::Write;
 use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
 use std::sync::mpsc::RecvTimeoutError;
@@ -24,10 +25,17 @@
 ///
 /// When `hot_reload` is true (and not in release mode), watches `src/` and
 /// `Cargo.toml` for changes and rebuilds/restarts the app automatically.
+/// `watch` forces that process-restart loop, also in release mode and for
+/// worker-host projects.
+#[expect(
+    clippy::fn_params_excessive_bools,
+    reason = "matches CLI arg structure"
+)]
 pub fn execute(
     device: Option<String>,
     release: bool,
     hot_reload: bool,
+    watch: bool,
     profile: Option<String>,
     verbose: bool,
 ) -> CliResult<()> {
@@ -42,7 +50,15 @@ pub fn execute(
     let target_device = device.map_or_else(select_default_device, Ok)?;
     cliclack::log::info(format!("Target device: {}", style(&target_device).cyan()))?;
 
-    if hot_reload && !release {
+    if watch {
+        cliclack::log::success("Watch mode: rebuild and restart on change")?;
+        let profile = if release {
+            Some("release")
+        } else {
+            profile.as_deref()
+        };
+        watch_and_rebuild(profile, verbose)?;
+    } else if hot_reload && !release {
         if let Some(project) = find_worker_hot_reload_project()? {
             cliclack::log::success(format!(
                 "Worker hot reload: {} → {}",
@@ -82,6 +98,10 @@ fn run_once(release: bool, profile: Option<String>, verbose: bool) -> CliResult<
 }
 
 /// Watch for file changes and rebuild/restart the application.
+///
+/// Changes are coalesced by [`ChangeDebouncer`] (ignoring `target/` and
+/// editor temp files); each flush kills the running instance, rebuilds, and
+/// starts a fresh one.
 fn watch_and_rebuild(profile: Option<&str>, verbose: bool) -> CliResult<()> {
     // Initial build.
     cliclack::log::step("Building project...")?;
@@ -94,81 +114,114 @@ fn watch_and_rebuild(profile: Option<&str>, verbose: bool) -> CliResult<()> {
     }
 
     // Spawn the app.
-    let mut child = spawn_app(profile, verbose)?;
-    cliclack::log::success(format!("Application started (PID {})", child.id()))?;
+    let mut child = Some(spawn_app(profile, verbose)?);
+    if let Some(child) = &child {
+        cliclack::log::success(format!("Application started (PID {})", child.id()))?;
+    }
     cliclack::log::info("Watching src/ for changes...")?;
 
     let mut watcher =
         SourceWatcher::new().map_err(|e| CliError::context(e, "Failed to create file watcher"))?;
 
     if let Err(e) = watcher.watch(Path::new("src"), true) {
-        let _ = child.kill();
-        wait_with_timeout(&mut child, Duration::from_secs(5));
+        stop_app(&mut child);
         return Err(CliError::context(e, "Failed to watch src/"));
     }
     if let Err(e) = watcher.watch(Path::new("Cargo.toml"), false) {
         tracing::debug!("Could not watch Cargo.toml: {e}");
     }
 
+    // Ignore globs apply to project-relative paths, so a project that itself
+    // lives under a directory named `target` still rebuilds.
+    let project_root = std::env::current_dir().context("Failed to read current directory")?;
+    let mut changes = ChangeDebouncer::new(timing::REBUILD_DEBOUNCE);
+
     // Watch loop.
     loop {
         // Check if the child exited on its own.
-        match child.try_wait() {
-            Ok(Some(status)) => {
-                tracing::debug!("Application exited with: {:?}", status);
-                cliclack::log::info("Application exited. Watching for changes to restart...")?;
-
-                if let Some(paths) = watcher.recv() {
-                    log_changed_paths(&paths);
+        if let Some(running) = &mut child {
+            match running.try_wait() {
+                Ok(Some(status)) => {
+                    tracing::debug!("Application exited with: {:?}", status);
+                    cliclack::log::info("Application exited. Watching for changes to restart...")?;
+                    child = None;
                 }
-
-                if run_cargo_build(profile, verbose) {
-                    child = spawn_app(profile, verbose)?;
-                    cliclack::log::success(format!("Application restarted (PID {})", child.id()))?;
-                } else {
-                    cliclack::log::warning(
-                        "Build failed. Watching for changes... (fix errors and save to retry)",
-                    )?;
+                Ok(None) => { /* Still running */ }
+                Err(e) => {
+                    tracing::warn!("Error checking child status: {}", e);
                 }
-                continue;
-            }
-            Ok(None) => { /* Still running */ }
-            Err(e) => {
-                tracing::warn!("Error checking child status: {}", e);
             }
         }
 
-        match watcher.recv_timeout(Duration::from_millis(200)) {
-            Ok(Some(paths)) => {
-                log_changed_paths(&paths);
-
-                if let Err(e) = child.kill() {
-                    tracing::debug!("Could not kill child process: {e}");
-                }
-                wait_with_timeout(&mut child, Duration::from_secs(5));
-
-                cliclack::log::step("Rebuilding...")?;
-                if run_cargo_build(profile, verbose) {
-                    child = spawn_app(profile, verbose)?;
-                    cliclack::log::success(format!("Application restarted (PID {})", child.id()))?;
-                } else {
-                    cliclack::log::warning(
-                        "Build failed. Watching for changes... (fix errors and save to retry)",
-                    )?;
-                    child = spawn_wait_dummy()?;
-                }
-            }
+        match watcher.recv_timeout(Duration::from_millis(100)) {
+            Ok(Some(paths)) => changes.push(
+                paths.into_iter().map(|path| {
+                    path.strip_prefix(&project_root)
+                        .map(Path::to_path_buf)
+                        .unwrap_or(path)
+                }),
+                Instant::now(),
+            ),
             Ok(None) | Err(RecvTimeoutError::Timeout) => {}
             Err(RecvTimeoutError::Disconnected) => break,
         }
+
+        let Some(paths) = changes.poll(Instant::now()) else {
+            continue;
+        };
+
+        stop_app(&mut child);
+        cliclack::log::step(format!("Rebuilding ({})...", describe_changes(&paths)))?;
+        let started = Instant::now();
+        if run_cargo_build(profile, verbose) {
+            let running = spawn_app(profile, verbose)?;
+            cliclack::log::success(format!(
+                "Rebuilt in {:.1}s, application restarted (PID {})",
+                started.elapsed().as_secs_f32(),
+                running.id()
+            ))?;
+            child = Some(running);
+        } else {
+            cliclack::log::warning(format!(
+                "Build failed after {:.1}s. Watching for changes... (fix errors and save to retry)",
+                started.elapsed().as_secs_f32()
+            ))?;
+        }
     }
 
-    let _ = child.kill();
-    wait_with_timeout(&mut child, Duration::from_secs(5));
+    stop_app(&mut child);
 
     Ok(())
 }
 
+/// Kill the running app instance, if any, and wait for it to exit.
+///
+/// `cargo run` execs the binary on Unix (and uses a job object on Windows),
+/// so killing the spawned process stops the app itself.
+fn stop_app(child: &mut Option<Child>) {
+    if let Some(mut running) = child.take() {
+        if let Err(e) = running.kill() {
+            tracing::debug!("Could not kill child process: {e}");
+        }
+        wait_with_timeout(&mut running, Duration::from_secs(5));
+    }
+}
+
+/// One-line summary of a change batch: up to three paths, then a count.
+fn describe_changes(paths: &[PathBuf]) -> String {
+    const SHOWN: usize = 3;
+    let mut summary = paths
+        .iter()
+        .take(SHOWN)
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    if paths.len() > SHOWN {
+        write!(summary, " +{} more", paths.len() - SHOWN).ok();
+    }
+    summary
+}
+
 /// Run `cargo build` and return whether it succeeded.
 fn run_cargo_build(profile: Option<&str>, verbose: bool) -> bool {
     let mut cmd = Command::new("cargo");
@@ -240,24 +293,6 @@ fn wait_with_timeout(child: &mut Child, timeout: Duration) {
     }
 }
 
-/// Spawn a dummy process that exits immediately (used as placeholder after build failure).
-fn spawn_wait_dummy() -> CliResult<Child> {
-    // On Windows, use `cmd /c exit 0`; on Unix, use `true`.
-    #[cfg(windows)]
-    let mut cmd = Command::new("cmd");
-    #[cfg(windows)]
-    cmd.args(["/c", "exit", "0"]);
-
-    #[cfg(not(windows))]
-    let mut cmd = Command::new("true");
-
-    cmd.stdin(Stdio::null())
-        .stdout(Stdio::null())
-        .stderr(Stdio::null())
-        .spawn()
-        .context("Failed to spawn dummy process")
-}
-
 fn log_changed_paths(paths: &[PathBuf]) {
     for path in paths {
         let _ = cliclack::log::info(format!("Change detected: {}", style(path.display()).dim()));
@@ -804,7 +839,8 @@ fn select_default_device() -> CliResult<String> {
 
 #[cfg(test)]
 mod tests {
-    use super::has_flui_dependency;
+    use super::{describe_changes, has_flui_dependency};
+    use std::path::PathBuf;
 
     /// `flui create` emits hyphenated dep names; `flui run` must recognise the
     /// project it just generated.
@@ -816,4 +852,17 @@ fn generated_manifest_is_recognised_as_a_flui_project() {
         assert!(has_flui_dependency(r#"flui_widgets = "0.2.0""#));
         assert!(!has_flui_dependency(r#"serde = "1.0""#));
     }
+
+    #[test]
+    fn change_summary_lists_a_few_paths_then_counts() {
+        let paths: Vec<PathBuf> = ["src/a.rs", "src/b.rs", "src/c.rs", "src/d.rs", "src/e.rs"]
+            .into_iter()
+            .map(PathBuf::from)
+            .collect();
+        assert_eq!(describe_changes(&paths[..1]), "src/a.rs");
+        assert_eq!(
+            describe_changes(&paths),
+            "src/a.rs, src/b.rs, src/c.rs +2 more"
+        );
+    }
 }
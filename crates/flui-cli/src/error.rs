@@ -154,6 +154,10 @@ pub enum CliError {
     #[error("Tests failed")]
     TestsFailed,
 
+    /// `flui doctor` found a missing required tool.
+    #[error("Some required checks failed")]
+    DoctorFailed,
+
     // ========================================================================
     // Update/Upgrade Errors
     // ========================================================================
@@ -125,6 +125,10 @@ enum Commands {
         #[arg(long, default_value = "true")]
         hot_reload: bool,
 
+        /// Rebuild and restart the app whenever `src/` changes (desktop)
+        #[arg(long)]
+        watch: bool,
+
         /// Scene-only hot-reload mode (Android): rebuild and push scene plugin
         /// without restarting the app. Much faster than full rebuild.
         #[arg(long)]
@@ -175,6 +179,10 @@ enum Commands {
         /// iOS: Build universal binary (arm64 + simulator)
         #[arg(long)]
         universal: bool,
+
+        /// Rebuild even if sources, features and profile are unchanged
+        #[arg(long)]
+        no_cache: bool,
     },
 
     /// Run tests
@@ -206,7 +214,7 @@ enum Commands {
         pedantic: bool,
     },
 
-    /// Check FLUI environment setup
+    /// Check FLUI environment setup (non-zero exit if a required check fails)
     Doctor {
         /// Show detailed information
         #[arg(short, long)]
@@ -223,6 +231,10 @@ enum Commands {
         /// Check only Web toolchain
         #[arg(long)]
         web: bool,
+
+        /// Print a machine-readable JSON report
+        #[arg(long)]
+        json: bool,
     },
 
     /// List available devices
@@ -580,6 +592,7 @@ fn main() {
             device,
             release,
             hot_reload,
+            watch,
             scene,
             scene_crate,
             package,
@@ -590,7 +603,7 @@ fn main() {
             if scene {
                 commands::run::execute_scene(&scene_crate, &package, &target, release, verbose)
             } else {
-                commands::run::execute(device, release, hot_reload, profile, verbose)
+                commands::run::execute(device, release, hot_reload, watch, profile, verbose)
             }
         }
 
@@ -601,6 +614,7 @@ fn main() {
             split_per_abi,
             optimize_wasm,
             universal,
+            no_cache,
         } => commands::build::execute(
             platform,
             release,
@@ -608,6 +622,7 @@ fn main() {
             split_per_abi,
             optimize_wasm,
             universal,
+            no_cache,
         ),
 
         Commands::Test {
@@ -624,7 +639,8 @@ fn main() {
             android,
             ios,
             web,
-        } => commands::doctor::execute(verbose, android, ios, web),
+            json,
+        } => commands::doctor::execute(verbose, android, ios, web, json),
 
         Commands::Devices { details, platform } => commands::devices::execute(details, platform),
 
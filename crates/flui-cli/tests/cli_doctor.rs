@@ -1,6 +1,7 @@
 //! Integration tests for `flui doctor` command.
 //!
-//! Tests that the doctor command runs successfully and produces expected output.
+//! Tests that the doctor command reports the environment and that its exit
+//! code follows the required checks.
 //! Note: cliclack writes all interactive output to stderr.
 
 use assert_cmd::Command;
@@ -12,51 +13,129 @@ fn flui() -> Command {
     cargo_bin_cmd!("flui")
 }
 
+/// Run `flui doctor` with `args` and parse its JSON report.
+fn doctor_json(args: &[&str]) -> (bool, serde_json::Value) {
+    let output = flui()
+        .arg("doctor")
+        .args(args)
+        .output()
+        .expect("run flui doctor");
+    let report =
+        serde_json::from_slice(&output.stdout).expect("doctor --json prints a JSON report");
+    (output.status.success(), report)
+}
+
+fn as_str(value: &serde_json::Value) -> &str {
+    value.as_str().expect("a JSON string")
+}
+
+/// Whether any required check in `report` is missing.
+fn required_check_missing(report: &serde_json::Value) -> bool {
+    report["checks"]
+        .as_array()
+        .expect("checks array")
+        .iter()
+        .any(|check| check["required"] == true && check["status"] == "missing")
+}
+
+/// Run `flui doctor` with `args`, asserting it exits non-zero exactly when
+/// the JSON report for the same flags has a missing required check.
+fn doctor_human(args: &[&str]) -> assert_cmd::assert::Assert {
+    let mut json_args = args.to_vec();
+    json_args.push("--json");
+    let (_, report) = doctor_json(&json_args);
+    let code = i32::from(required_check_missing(&report));
+    flui().arg("doctor").args(args).assert().code(code)
+}
+
 #[test]
-fn doctor_runs_successfully() {
-    flui().args(["doctor"]).assert().success();
+fn doctor_runs() {
+    doctor_human(&[]);
 }
 
 #[test]
 fn doctor_detects_rust() {
     // cliclack outputs to stderr
-    flui()
-        .args(["doctor"])
-        .assert()
-        .success()
-        .stderr(predicate::str::contains("Rust"));
+    doctor_human(&[]).stderr(predicate::str::contains("Rust"));
 }
 
 #[test]
 fn doctor_detects_cargo() {
-    flui()
-        .args(["doctor"])
-        .assert()
-        .success()
-        .stderr(predicate::str::contains("Cargo"));
+    doctor_human(&[]).stderr(predicate::str::contains("Cargo"));
 }
 
 #[test]
 fn doctor_shows_flui_version() {
-    flui()
-        .args(["doctor"])
-        .assert()
-        .success()
-        .stderr(predicate::str::contains("FLUI CLI"));
+    doctor_human(&[]).stderr(predicate::str::contains("FLUI CLI"));
 }
 
 #[test]
-fn doctor_verbose_runs_successfully() {
-    flui().args(["doctor", "--verbose"]).assert().success();
+fn doctor_verbose_runs() {
+    doctor_human(&["--verbose"]);
 }
 
 #[test]
 fn doctor_android_only() {
-    // Should succeed even if Android SDK is not installed (just reports status)
-    flui().args(["doctor", "--android"]).assert().success();
+    // Selected platforms are required, so a missing Android SDK fails the run.
+    doctor_human(&["--android"]);
 }
 
 #[test]
 fn doctor_web_only() {
-    flui().args(["doctor", "--web"]).assert().success();
+    doctor_human(&["--web"]);
+}
+
+#[test]
+fn doctor_json_has_checks_and_overall() {
+    let (_, report) = doctor_json(&["--json"]);
+
+    let checks = report["checks"].as_array().expect("checks array");
+    assert!(checks.iter().any(|check| check["name"] == "Rust"));
+    for check in checks {
+        assert!(check["name"].is_string());
+        assert!(["ok", "warn", "missing"].contains(&as_str(&check["status"])));
+        assert!(check["detail"].is_string());
+        assert!(check["required"].is_boolean());
+        assert!(check["fix_hint"].is_null() || check["fix_hint"].is_string());
+    }
+    assert!(["ok", "warn", "missing"].contains(&as_str(&report["overall"])));
+    assert!(report["passed"].is_boolean());
+}
+
+#[test]
+fn doctor_json_exit_code_follows_required_checks() {
+    // Which checks pass depends on the host toolchain; the exit code must
+    // agree with the required checks either way.
+    for args in [&["--json"][..], &["--android", "--json"]] {
+        let (success, report) = doctor_json(args);
+        assert_eq!(success, !required_check_missing(&report), "{args:?}");
+        assert_eq!(report["passed"], success, "{args:?}");
+    }
+}
+
+#[test]
+fn doctor_json_platform_checks_are_optional_unless_selected() {
+    let (_, report) = doctor_json(&["--json"]);
+    for check in report["checks"].as_array().expect("checks array") {
+        let core = ["Rust", "Cargo", "FLUI CLI", "wgpu"].contains(&as_str(&check["name"]));
+        assert_eq!(check["required"], core, "{}", check["name"]);
+    }
+
+    let (_, report) = doctor_json(&["--android", "--json"]);
+    for check in report["checks"].as_array().expect("checks array") {
+        assert_eq!(check["required"], true, "{}", check["name"]);
+    }
+}
+
+#[test]
+fn doctor_android_json_only_includes_android_checks() {
+    let (_, report) = doctor_json(&["--android", "--json"]);
+
+    let names: Vec<&str> = report["checks"]
+        .as_array()
+        .expect("checks array")
+        .iter()
+        .map(|check| as_str(&check["name"]))
+        .collect();
+    assert_eq!(names, ["Java", "Android SDK", "Android targets"]);
 }
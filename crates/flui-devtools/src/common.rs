@@ -22,6 +22,9 @@ pub struct DevToolsConfig {
 
     /// Maximum number of frames to keep in history
     pub max_frame_history: usize,
+
+    /// Number of frames kept in the profiler's per-phase timeline
+    pub timeline_capacity: usize,
 }
 
 impl Default for DevToolsConfig {
@@ -32,6 +35,7 @@ fn default() -> Self {
             target_fps: 60,
             jank_threshold_ms: 16.0, // 60 FPS = 16.67ms per frame
             max_frame_history: 300,  // 5 seconds at 60 FPS
+            timeline_capacity: 120,  // 2 seconds at 60 FPS
         }
     }
 }
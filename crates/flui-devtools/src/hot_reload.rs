@@ -2,50 +2,113 @@
 //!
 //! Callback-oriented wrapper around [`flui_hot_reload::dev::SourceWatcher`].
 //! For direct channel access, use `SourceWatcher` from `flui_hot_reload::dev`.
+//!
+//! Bursts of filesystem events (an editor save touching several files, a
+//! formatter rewriting a crate) are coalesced: the callback fires once the
+//! watched tree has been quiet for the debounce window, with every path that
+//! changed during the burst. Include/exclude globs keep editor temp files
+//! and build output from triggering reloads.
 
 use std::{
+    collections::BTreeSet,
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{Arc, mpsc::RecvTimeoutError},
     thread::{self, JoinHandle},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use flui_hot_reload::dev::{SourceWatcher, WatchError};
 use flui_hot_reload::strategy::timing;
+use glob::{MatchOptions, Pattern, PatternError};
 use parking_lot::RwLock;
 
-/// Callback invoked with the changed path when a watched file changes.
-type OnChangeCallback = Box<dyn Fn(&Path) + Send + Sync>;
+/// Callback invoked with the sorted, deduplicated paths of one coalesced
+/// burst of changes.
+type OnChangeCallback = Box<dyn Fn(&[PathBuf]) + Send + Sync>;
+
+/// Exclude globs applied until [`HotReloader::with_patterns`] replaces them:
+/// build output, VCS metadata, and common editor swap/backup files.
+pub const DEFAULT_EXCLUDE_PATTERNS: &[&str] = &[
+    "**/target/**",
+    "**/.git/**",
+    "**/*~",
+    "**/*.swp",
+    "**/*.swx",
+    "**/.#*",
+    "**/#*#",
+    "**/*.tmp",
+];
+
+/// Debounce of the raw OS watcher feeding the coalescer. Kept short so the
+/// configured window, not this one, decides the reload latency.
+const RAW_EVENT_DEBOUNCE: Duration = Duration::from_millis(50);
 
 /// Hot reloader for watching file changes.
 ///
-/// Monitors directories for changes and invokes a callback. Internally uses
-/// [`SourceWatcher`] (layer 1 of the hot-reload stack).
+/// Monitors directories (or single files) for changes and invokes a
+/// callback. Internally uses [`SourceWatcher`] (layer 1 of the hot-reload
+/// stack).
+///
+/// # Atomic saves
+///
+/// Editors that save by writing a temp file and renaming it over the
+/// original replace the file's inode, which silently ends an OS watch on
+/// the file itself. A watched *file* is therefore watched through its parent
+/// directory, with events narrowed back to that file, so the watch survives
+/// the replace.
 pub struct HotReloader {
     watched_paths: Arc<RwLock<Vec<PathBuf>>>,
     on_change_callback: Arc<RwLock<Option<OnChangeCallback>>>,
-    watcher: Arc<RwLock<Option<SourceWatcher>>>,
     debounce_duration: Duration,
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
     watch_handle: Arc<RwLock<Option<JoinHandle<()>>>>,
 }
 
 impl HotReloader {
-    /// Create a new hot reloader with the default debounce interval.
+    /// Create a new hot reloader with the default debounce interval and
+    /// [`DEFAULT_EXCLUDE_PATTERNS`].
     pub fn new() -> Self {
-        Self::with_debounce(timing::SOURCE_DEBOUNCE)
-    }
-
-    /// Create a hot reloader with a custom debounce duration.
-    pub fn with_debounce(debounce: Duration) -> Self {
         Self {
             watched_paths: Arc::new(RwLock::new(Vec::new())),
             on_change_callback: Arc::new(RwLock::new(None)),
-            watcher: Arc::new(RwLock::new(None)),
-            debounce_duration: debounce,
+            debounce_duration: timing::SOURCE_DEBOUNCE,
+            include: Vec::new(),
+            exclude: DEFAULT_EXCLUDE_PATTERNS
+                .iter()
+                .map(|glob| Pattern::new(glob).expect("BUG: default exclude globs are valid"))
+                .collect(),
             watch_handle: Arc::new(RwLock::new(None)),
         }
     }
 
+    /// Set the quiet period that ends a burst of changes (builder form).
+    ///
+    /// The callback fires once no event has arrived for `debounce`.
+    #[must_use]
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce_duration = debounce;
+        self
+    }
+
+    /// Replace the include and exclude globs (builder form).
+    ///
+    /// Globs match paths relative to the watched directory (`src/**/*.rs`,
+    /// `**/target/**`). An empty `include` accepts every path; `exclude`
+    /// wins over `include`. This replaces [`DEFAULT_EXCLUDE_PATTERNS`] —
+    /// pass them again to keep them.
+    pub fn with_patterns<I, E, S, T>(mut self, include: I, exclude: E) -> Result<Self, PatternError>
+    where
+        I: IntoIterator<Item = S>,
+        E: IntoIterator<Item = T>,
+        S: AsRef<str>,
+        T: AsRef<str>,
+    {
+        self.include = compile_globs(include)?;
+        self.exclude = compile_globs(exclude)?;
+        Ok(self)
+    }
+
     /// Watch a directory or file for changes.
     pub fn watch(&mut self, path: impl AsRef<Path>) -> Result<(), WatchError> {
         let path = path.as_ref().to_path_buf();
@@ -53,10 +116,10 @@ pub fn watch(&mut self, path: impl AsRef<Path>) -> Result<(), WatchError> {
         Ok(())
     }
 
-    /// Set the callback for file changes.
+    /// Set the callback for coalesced file changes.
     pub fn on_change<F>(&mut self, callback: F)
     where
-        F: Fn(&Path) + Send + Sync + 'static,
+        F: Fn(&[PathBuf]) + Send + Sync + 'static,
     {
         *self.on_change_callback.write() = Some(Box::new(callback));
     }
@@ -65,7 +128,7 @@ pub fn on_change<F>(&mut self, callback: F)
     pub fn watch_blocking(&mut self) -> Result<(), WatchError> {
         self.start_watcher()?;
         loop {
-            thread::sleep(Duration::from_secs(3600));
+            thread::sleep(Duration::from_hours(1));
         }
     }
 
@@ -82,7 +145,6 @@ pub fn watch_async(&mut self) -> WatchHandle {
     /// Stop watching all paths.
     pub fn stop(&mut self) {
         *self.watch_handle.write() = None;
-        *self.watcher.write() = None;
         self.watched_paths.write().clear();
     }
 
@@ -101,23 +163,41 @@ fn start_watcher(&mut self) -> Result<(), WatchError> {
             return Ok(());
         }
 
-        let mut source = SourceWatcher::with_debounce(self.debounce_duration)?;
+        let mut source =
+            SourceWatcher::with_debounce(RAW_EVENT_DEBOUNCE.min(self.debounce_duration))?;
+        let mut roots = Vec::new();
         for path in self.watched_paths.read().iter() {
-            source.watch(path, true)?;
+            let root = WatchRoot::resolve(path);
+            match &root {
+                WatchRoot::Dir(dir) => source.watch(dir, true)?,
+                // Watch the parent so an atomic rename-over keeps reporting.
+                WatchRoot::File(file) => {
+                    source.watch(file.parent().unwrap_or(file), false)?;
+                }
+            }
+            roots.push(root);
         }
 
+        let filter = ChangeFilter {
+            roots,
+            include: self.include.clone(),
+            exclude: self.exclude.clone(),
+        };
+        let coalescer = ChangeCoalescer::new(self.debounce_duration);
         let callback = self.on_change_callback.clone();
         let handle = thread::spawn(move || {
-            while let Some(paths) = source.recv() {
-                if let Some(ref cb) = *callback.read() {
-                    for path in paths {
-                        cb(path.as_path());
+            run_event_loop(
+                |timeout| source.recv_timeout(timeout),
+                &filter,
+                coalescer,
+                |paths| {
+                    if let Some(ref cb) = *callback.read() {
+                        cb(paths);
                     }
-                }
-            }
+                },
+            );
         });
 
-        *self.watcher.write() = None;
         *self.watch_handle.write() = Some(handle);
         Ok(())
     }
@@ -133,8 +213,11 @@ impl std::fmt::Debug for HotReloader {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("HotReloader")
             .field("watched_paths", &self.watched_paths.read().len())
+            .field("debounce", &self.debounce_duration)
+            .field("include", &self.include.len())
+            .field("exclude", &self.exclude.len())
             .field("is_watching", &self.is_watching())
-            .finish()
+            .finish_non_exhaustive()
     }
 }
 
@@ -149,15 +232,174 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     }
 }
 
+fn compile_globs<I, S>(globs: I) -> Result<Vec<Pattern>, PatternError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    globs
+        .into_iter()
+        .map(|glob| Pattern::new(glob.as_ref()))
+        .collect()
+}
+
+/// A registered watch target, canonicalized when it exists.
+#[derive(Debug)]
+enum WatchRoot {
+    Dir(PathBuf),
+    File(PathBuf),
+}
+
+impl WatchRoot {
+    fn resolve(path: &Path) -> Self {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        if path.is_file() {
+            Self::File(path)
+        } else {
+            Self::Dir(path)
+        }
+    }
+}
+
+/// Decides which reported paths count as changes.
+#[derive(Debug)]
+struct ChangeFilter {
+    roots: Vec<WatchRoot>,
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+}
+
+impl ChangeFilter {
+    fn accepts(&self, path: &Path) -> bool {
+        // Globs see the path relative to the root that reported it; a
+        // watched file's parent also reports its siblings, which are not
+        // ours.
+        let relative = self.roots.iter().find_map(|root| match root {
+            WatchRoot::Dir(dir) => path.strip_prefix(dir).ok(),
+            WatchRoot::File(file) => (path == file)
+                .then(|| file.file_name().map(Path::new))
+                .flatten(),
+        });
+        let Some(relative) = relative else {
+            return false;
+        };
+
+        let options = MatchOptions {
+            require_literal_separator: true,
+            ..MatchOptions::new()
+        };
+        let matches = |pattern: &Pattern| pattern.matches_path_with(relative, options);
+        (self.include.is_empty() || self.include.iter().any(matches))
+            && !self.exclude.iter().any(matches)
+    }
+}
+
+/// Accumulates changed paths until the stream goes quiet for `window`.
+#[derive(Debug)]
+struct ChangeCoalescer {
+    window: Duration,
+    pending: BTreeSet<PathBuf>,
+    last_event: Option<Instant>,
+}
+
+impl ChangeCoalescer {
+    fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: BTreeSet::new(),
+            last_event: None,
+        }
+    }
+
+    fn push(&mut self, paths: impl IntoIterator<Item = PathBuf>, now: Instant) {
+        let before = self.pending.len();
+        self.pending.extend(paths);
+        if self.pending.len() > before || self.last_event.is_some() {
+            self.last_event = Some(now);
+        }
+    }
+
+    /// Time left until the pending burst is due, or `None` when idle.
+    fn time_until_due(&self, now: Instant) -> Option<Duration> {
+        self.last_event
+            .map(|last| (last + self.window).saturating_duration_since(now))
+    }
+
+    /// Take the pending burst if the window has elapsed since its last
+    /// event.
+    fn take_if_due(&mut self, now: Instant) -> Option<Vec<PathBuf>> {
+        match self.time_until_due(now) {
+            Some(remaining) if remaining.is_zero() => self.take(),
+            _ => None,
+        }
+    }
+
+    /// Take the pending burst regardless of timing.
+    fn take(&mut self) -> Option<Vec<PathBuf>> {
+        self.last_event = None;
+        if self.pending.is_empty() {
+            return None;
+        }
+        Some(std::mem::take(&mut self.pending).into_iter().collect())
+    }
+}
+
+/// Drives the coalescer from a batch source until it disconnects.
+///
+/// `next` has the shape of [`SourceWatcher::recv_timeout`]; a pending
+/// burst is flushed before returning so no change is dropped on shutdown.
+fn run_event_loop(
+    mut next: impl FnMut(Duration) -> Result<Option<Vec<PathBuf>>, RecvTimeoutError>,
+    filter: &ChangeFilter,
+    mut coalescer: ChangeCoalescer,
+    mut emit: impl FnMut(&[PathBuf]),
+) {
+    /// Poll interval while no burst is pending.
+    const IDLE_POLL: Duration = Duration::from_secs(1);
+
+    loop {
+        let timeout = coalescer
+            .time_until_due(Instant::now())
+            .unwrap_or(IDLE_POLL);
+        match next(timeout) {
+            Ok(Some(paths)) => {
+                let accepted = paths.into_iter().filter(|path| filter.accepts(path));
+                coalescer.push(accepted, Instant::now());
+            }
+            Ok(None) | Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => {
+                if let Some(paths) = coalescer.take() {
+                    emit(&paths);
+                }
+                return;
+            }
+        }
+        if let Some(paths) = coalescer.take_if_due(Instant::now()) {
+            emit(&paths);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
         fs,
-        sync::atomic::{AtomicUsize, Ordering},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc,
+        },
     };
 
     use super::*;
 
+    fn filter_for(root: &str, reloader: &HotReloader) -> ChangeFilter {
+        ChangeFilter {
+            roots: vec![WatchRoot::Dir(PathBuf::from(root))],
+            include: reloader.include.clone(),
+            exclude: reloader.exclude.clone(),
+        }
+    }
+
     #[test]
     fn test_hot_reloader_creation() {
         let reloader = HotReloader::new();
@@ -183,7 +425,7 @@ fn test_on_change_callback() {
         let counter = Arc::new(AtomicUsize::new(0));
         let counter_clone = counter.clone();
 
-        reloader.on_change(move |_path| {
+        reloader.on_change(move |_paths| {
             counter_clone.fetch_add(1, Ordering::SeqCst);
         });
 
@@ -203,4 +445,131 @@ fn test_stop() {
 
         fs::remove_dir_all(&temp_dir).ok();
     }
+
+    #[test]
+    fn test_burst_coalesces_into_one_callback() {
+        let reloader = HotReloader::new().with_debounce(Duration::from_millis(50));
+        let filter = filter_for("/app", &reloader);
+        let (tx, rx) = mpsc::channel::<Vec<PathBuf>>();
+
+        // One save touching several files, with duplicates and a swap file.
+        tx.send(vec![
+            "/app/src/main.rs".into(),
+            "/app/src/.main.rs.swp".into(),
+        ])
+        .unwrap();
+        tx.send(vec!["/app/src/lib.rs".into()]).unwrap();
+        tx.send(vec![
+            "/app/src/main.rs".into(),
+            "/app/target/debug/app".into(),
+        ])
+        .unwrap();
+        drop(tx);
+
+        let mut calls = Vec::new();
+        run_event_loop(
+            |timeout| rx.recv_timeout(timeout).map(Some),
+            &filter,
+            ChangeCoalescer::new(reloader.debounce_duration),
+            |paths| calls.push(paths.to_vec()),
+        );
+
+        assert_eq!(
+            calls,
+            vec![vec![
+                PathBuf::from("/app/src/lib.rs"),
+                PathBuf::from("/app/src/main.rs"),
+            ]]
+        );
+    }
+
+    #[test]
+    fn test_quiet_window_splits_bursts() {
+        let window = Duration::from_millis(20);
+        let filter = filter_for("/app", &HotReloader::new());
+        let (tx, rx) = mpsc::channel::<Vec<PathBuf>>();
+
+        let sender = thread::spawn(move || {
+            tx.send(vec!["/app/a.rs".into()]).unwrap();
+            tx.send(vec!["/app/b.rs".into()]).unwrap();
+            thread::sleep(window * 10);
+            tx.send(vec!["/app/c.rs".into()]).unwrap();
+        });
+
+        let mut calls = Vec::new();
+        run_event_loop(
+            |timeout| rx.recv_timeout(timeout).map(Some),
+            &filter,
+            ChangeCoalescer::new(window),
+            |paths| calls.push(paths.len()),
+        );
+        sender.join().unwrap();
+
+        assert_eq!(calls, vec![2, 1]);
+    }
+
+    #[test]
+    fn test_coalescer_waits_for_quiet_window() {
+        let mut coalescer = ChangeCoalescer::new(Duration::from_millis(100));
+        let start = Instant::now();
+
+        coalescer.push([PathBuf::from("a")], start);
+        coalescer.push([PathBuf::from("b")], start + Duration::from_millis(80));
+
+        // 100ms after the first event, but only 20ms after the last one.
+        assert!(
+            coalescer
+                .take_if_due(start + Duration::from_millis(100))
+                .is_none()
+        );
+        assert_eq!(
+            coalescer.take_if_due(start + Duration::from_millis(180)),
+            Some(vec![PathBuf::from("a"), PathBuf::from("b")])
+        );
+        assert!(coalescer.time_until_due(start).is_none());
+    }
+
+    #[test]
+    fn test_patterns_filter_paths() {
+        let reloader = HotReloader::new()
+            .with_patterns(["src/**/*.rs", "*.toml"], ["**/generated/**"])
+            .unwrap();
+        let filter = filter_for("/app", &reloader);
+
+        assert!(filter.accepts(Path::new("/app/src/main.rs")));
+        assert!(filter.accepts(Path::new("/app/src/widgets/button.rs")));
+        assert!(filter.accepts(Path::new("/app/Cargo.toml")));
+        assert!(!filter.accepts(Path::new("/app/src/generated/bindings.rs")));
+        assert!(!filter.accepts(Path::new("/app/README.md")));
+        assert!(!filter.accepts(Path::new("/app/crates/x/Cargo.toml")));
+        assert!(!filter.accepts(Path::new("/elsewhere/src/main.rs")));
+
+        assert!(HotReloader::new().with_patterns(["[bad"], [""; 0]).is_err());
+    }
+
+    #[test]
+    fn test_default_excludes_skip_temp_files_and_target() {
+        let filter = filter_for("/app", &HotReloader::new());
+
+        assert!(filter.accepts(Path::new("/app/src/main.rs")));
+        assert!(!filter.accepts(Path::new("/app/target/debug/build.rs")));
+        assert!(!filter.accepts(Path::new("/app/src/main.rs~")));
+        assert!(!filter.accepts(Path::new("/app/src/.main.rs.swp")));
+        assert!(!filter.accepts(Path::new("/app/src/.#main.rs")));
+    }
+
+    #[test]
+    fn test_file_root_survives_atomic_replace() {
+        let filter = ChangeFilter {
+            roots: vec![WatchRoot::File(PathBuf::from("/app/config.toml"))],
+            include: Vec::new(),
+            exclude: HotReloader::new().exclude,
+        };
+
+        // The rename target is reported through the parent directory watch;
+        // the editor's temp sibling is not ours.
+        assert!(filter.accepts(Path::new("/app/config.toml")));
+        assert!(!filter.accepts(Path::new("/app/config.toml.tmp")));
+        assert!(!filter.accepts(Path::new("/app/other.toml")));
+    }
 }
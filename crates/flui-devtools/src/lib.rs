@@ -28,12 +28,12 @@
 //! - Performance metrics
 //!
 //! ## 💾 Memory Profiler (feature: memory-profiler)
-//! - Heap allocation tracking
+//! - Per-site allocation snapshots and snapshot diffs
 //! - Memory usage over time
-//! - Leak detection
+//! - Leak detection (monotonic live-count growth)
 //!
 //! ## 🔌 Remote Debug (feature: remote-debug)
-//! - WebSocket-based debugging protocol
+//! - Versioned JSON debugging protocol (WebSocket-ready)
 //! - Connect from browser DevTools
 //! - Remote widget inspection
 //!
@@ -69,12 +69,15 @@
 //!
 //! #[cfg(feature = "hot-reload")]
 //! {
-//!     let mut reloader = HotReloader::new("./src");
-//!     reloader.on_change(|path| {
-//!         println!("File changed: {:?}", path);
-//!         // Trigger rebuild
+//!     let mut reloader = HotReloader::new()
+//!         .with_debounce(std::time::Duration::from_millis(200))
+//!         .with_patterns(["src/**/*.rs"], ["**/target/**"])?;
+//!     reloader.watch("./src")?;
+//!     reloader.on_change(|paths| {
+//!         println!("Files changed: {:?}", paths);
+//!         // Trigger rebuild once per burst
 //!     });
-//!     reloader.watch();
+//!     let _handle = reloader.watch_async();
 //! }
 //! ```
 //!
@@ -85,8 +88,10 @@
 //! - `timeline`: Timeline view for events
 //! - `hot-reload`: File watching and hot reload
 //! - `network-monitor`: HTTP request monitoring
-//! - `memory-profiler`: Memory usage tracking
-//! - `remote-debug`: WebSocket debugging server
+//! - `memory-profiler`: Memory snapshot diffing and leak detection (needs an
+//!   application-installed allocation tracker)
+//! - `remote-debug`: Remote debug protocol (transport-agnostic; the app
+//!   supplies the socket)
 //! - `tracing-support`: Integration with `tracing` crate
 //! - `full`: All features enabled
 //!
@@ -99,17 +104,15 @@
 mod common;
 #[cfg(feature = "hot-reload")]
 pub mod hot_reload;
-// TODO: Add memory profiler module
-// #[cfg(feature = "memory-profiler")]
-// pub mod memory;
+#[cfg(feature = "memory-profiler")]
+pub mod memory;
 // TODO: Add network monitor module
 // #[cfg(feature = "network-monitor")]
 // pub mod network;
-// TODO: Add remote debug module
-// #[cfg(feature = "remote-debug")]
-// pub mod remote;
 #[cfg(feature = "profiling")]
 pub mod profiler;
+#[cfg(feature = "remote-debug")]
+pub mod remote;
 #[cfg(feature = "timeline")]
 pub mod timeline;
 
@@ -136,9 +139,8 @@ pub mod prelude {
     #[cfg(feature = "timeline")]
     pub use crate::timeline::{Timeline, TimelineEvent};
 
-    // TODO: Add memory profiler re-exports
-    // #[cfg(feature = "memory-profiler")]
-    // pub use crate::memory::{MemoryProfiler, MemorySnapshot};
+    #[cfg(feature = "memory-profiler")]
+    pub use crate::memory::{MemoryProfiler, MemorySnapshot};
 }
 
 #[cfg(test)]
@@ -0,0 +1,436 @@
+//! Memory profiler for FLUI applications
+//!
+//! Keeps a rolling history of per-allocation-site snapshots, diffs them, and
+//! flags sites whose live allocation count keeps growing — the signature of
+//! retained widgets, elements, or render objects.
+//!
+//! # Allocation tracker
+//!
+//! This module only analyses snapshots; it does not observe the heap.
+//! Snapshots must be produced by an allocation tracker (a counting global
+//! allocator or an instrumented arena) installed by the application and
+//! handed to [`MemoryProfiler::record_snapshot`]. Without one installed the
+//! profiler has no data, and [`MemoryProfiler::detect_leaks`] reports
+//! nothing.
+//!
+//! # Example
+//!
+//! ```rust
+//! use flui_devtools::memory::{MemoryProfiler, MemorySnapshot};
+//!
+//! let profiler = MemoryProfiler::new();
+//!
+//! for frame in 1..=4u64 {
+//!     let mut snapshot = MemorySnapshot::new();
+//!     snapshot.record_site("Element", frame * 10, frame * 640);
+//!     profiler.record_snapshot(snapshot);
+//! }
+//!
+//! let leaks = profiler.detect_leaks(4);
+//! assert_eq!(leaks[0].site, "Element");
+//! ```
+
+use std::{collections::BTreeMap, collections::VecDeque, sync::Arc};
+
+use parking_lot::Mutex;
+use web_time::Instant;
+
+/// Default number of snapshots kept by a [`MemoryProfiler`]
+const DEFAULT_MAX_SNAPSHOTS: usize = 64;
+
+/// Live allocations attributed to one allocation site
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SiteStats {
+    /// Number of live allocations
+    pub live_count: u64,
+    /// Bytes held by the live allocations
+    pub live_bytes: u64,
+}
+
+/// Point-in-time view of live allocations, keyed by allocation site
+#[derive(Debug, Clone)]
+pub struct MemorySnapshot {
+    /// When the snapshot was taken
+    pub timestamp: Instant,
+    /// Live allocations per site (type name, module path, or any label the
+    /// tracker uses)
+    pub sites: BTreeMap<String, SiteStats>,
+}
+
+impl MemorySnapshot {
+    /// Create an empty snapshot stamped with the current time
+    pub fn new() -> Self {
+        Self {
+            timestamp: Instant::now(),
+            sites: BTreeMap::new(),
+        }
+    }
+
+    /// Record the live allocations of a site, replacing any previous entry
+    pub fn record_site(&mut self, site: impl Into<String>, live_count: u64, live_bytes: u64) {
+        self.sites.insert(
+            site.into(),
+            SiteStats {
+                live_count,
+                live_bytes,
+            },
+        );
+    }
+
+    /// Get the live allocations of a site (zero when absent)
+    pub fn site(&self, site: &str) -> SiteStats {
+        self.sites.get(site).copied().unwrap_or_default()
+    }
+
+    /// Total live allocations across all sites
+    pub fn total_count(&self) -> u64 {
+        self.sites.values().map(|s| s.live_count).sum()
+    }
+
+    /// Total live bytes across all sites
+    pub fn total_bytes(&self) -> u64 {
+        self.sites.values().map(|s| s.live_bytes).sum()
+    }
+
+    /// Compare this snapshot against an earlier `baseline`
+    ///
+    /// Sites missing from either side count as zero live allocations, so a
+    /// site that disappeared reports all of its allocations as freed.
+    pub fn diff(&self, baseline: &MemorySnapshot) -> MemoryDelta {
+        let mut delta = MemoryDelta::default();
+
+        let site_names = baseline.sites.keys().chain(
+            self.sites
+                .keys()
+                .filter(|site| !baseline.sites.contains_key(*site)),
+        );
+        for site in site_names {
+            let before = baseline.site(site);
+            let after = self.site(site);
+            if before == after {
+                continue;
+            }
+
+            let count_delta = signed_delta(after.live_count, before.live_count);
+            let bytes_delta = signed_delta(after.live_bytes, before.live_bytes);
+            if count_delta > 0 {
+                delta.allocations_added += count_delta.unsigned_abs();
+            } else {
+                delta.allocations_freed += count_delta.unsigned_abs();
+            }
+            delta.net_bytes += bytes_delta;
+            delta.sites.push(SiteDelta {
+                site: site.clone(),
+                count_delta,
+                bytes_delta,
+            });
+        }
+
+        delta.sites.sort_by(|a, b| a.site.cmp(&b.site));
+        delta
+    }
+}
+
+impl Default for MemorySnapshot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Change in live allocations of one site between two snapshots
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SiteDelta {
+    /// Allocation site
+    pub site: String,
+    /// Change in live allocation count
+    pub count_delta: i64,
+    /// Change in live bytes
+    pub bytes_delta: i64,
+}
+
+/// Difference between two [`MemorySnapshot`]s
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryDelta {
+    /// Live allocations gained, summed over sites that grew
+    pub allocations_added: u64,
+    /// Live allocations lost, summed over sites that shrank
+    pub allocations_freed: u64,
+    /// Net change in live bytes
+    pub net_bytes: i64,
+    /// Per-site changes, sorted by site; unchanged sites are omitted
+    pub sites: Vec<SiteDelta>,
+}
+
+impl MemoryDelta {
+    /// Check whether nothing changed between the two snapshots
+    pub fn is_empty(&self) -> bool {
+        self.sites.is_empty()
+    }
+}
+
+/// Allocation site whose live count grew across every snapshot in the
+/// leak-detection window
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeakSuspect {
+    /// Allocation site
+    pub site: String,
+    /// Live count at the start of the window
+    pub first_count: u64,
+    /// Live count at the end of the window
+    pub last_count: u64,
+    /// Change in live bytes over the window
+    pub bytes_growth: i64,
+}
+
+/// Internal profiler state
+#[derive(Debug)]
+struct MemoryProfilerInner {
+    /// Snapshot history, oldest first
+    snapshots: VecDeque<MemorySnapshot>,
+    /// Maximum number of snapshots to keep
+    max_snapshots: usize,
+}
+
+impl MemoryProfilerInner {
+    fn record_snapshot(&mut self, snapshot: MemorySnapshot) {
+        if self.max_snapshots == 0 {
+            return;
+        }
+        if self.snapshots.len() >= self.max_snapshots {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(snapshot);
+    }
+
+    fn detect_leaks(&self, window: usize) -> Vec<LeakSuspect> {
+        if window < 2 || self.snapshots.len() < window {
+            return Vec::new();
+        }
+
+        let recent: Vec<&MemorySnapshot> = self
+            .snapshots
+            .iter()
+            .skip(self.snapshots.len() - window)
+            .collect();
+        let first = recent[0];
+        let last = recent[window - 1];
+
+        // A leaking site is present at the end of the window; sites that
+        // vanished cannot have grown.
+        last.sites
+            .keys()
+            .filter(|site| {
+                recent
+                    .windows(2)
+                    .all(|pair| pair[1].site(site).live_count > pair[0].site(site).live_count)
+            })
+            .map(|site| {
+                let before = first.site(site);
+                let after = last.site(site);
+                LeakSuspect {
+                    site: site.clone(),
+                    first_count: before.live_count,
+                    last_count: after.live_count,
+                    bytes_growth: signed_delta(after.live_bytes, before.live_bytes),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Memory profiler for FLUI applications
+///
+/// Thread-safe history of [`MemorySnapshot`]s fed by an allocation tracker.
+/// See the [module docs](self) for the tracker requirement.
+#[derive(Clone)]
+pub struct MemoryProfiler {
+    inner: Arc<Mutex<MemoryProfilerInner>>,
+}
+
+impl MemoryProfiler {
+    /// Create a profiler keeping the default number of snapshots
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_MAX_SNAPSHOTS)
+    }
+
+    /// Create a profiler keeping at most `max_snapshots` snapshots
+    pub fn with_capacity(max_snapshots: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(MemoryProfilerInner {
+                snapshots: VecDeque::with_capacity(max_snapshots),
+                max_snapshots,
+            })),
+        }
+    }
+
+    /// Record a snapshot produced by the allocation tracker
+    ///
+    /// The oldest snapshot is dropped once the history is full.
+    pub fn record_snapshot(&self, snapshot: MemorySnapshot) {
+        self.inner.lock().record_snapshot(snapshot);
+    }
+
+    /// Get all recorded snapshots, oldest first
+    pub fn snapshots(&self) -> Vec<MemorySnapshot> {
+        self.inner.lock().snapshots.iter().cloned().collect()
+    }
+
+    /// Get the most recent snapshot
+    pub fn latest(&self) -> Option<MemorySnapshot> {
+        self.inner.lock().snapshots.back().cloned()
+    }
+
+    /// Flag allocation sites whose live count strictly increased between
+    /// every consecutive pair of the last `window` snapshots
+    ///
+    /// A site absent from a snapshot counts as zero live allocations.
+    /// Returns nothing when `window` is below 2 or fewer than `window`
+    /// snapshots have been recorded. Suspects are sorted by site.
+    pub fn detect_leaks(&self, window: usize) -> Vec<LeakSuspect> {
+        self.inner.lock().detect_leaks(window)
+    }
+
+    /// Discard all recorded snapshots
+    pub fn clear(&self) {
+        self.inner.lock().snapshots.clear();
+    }
+}
+
+impl Default for MemoryProfiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for MemoryProfiler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemoryProfiler")
+            .field("snapshot_count", &self.inner.lock().snapshots.len())
+            .finish()
+    }
+}
+
+/// `after - before` as a signed value, saturating at the `i64` range
+fn signed_delta(after: u64, before: u64) -> i64 {
+    if after >= before {
+        i64::try_from(after - before).unwrap_or(i64::MAX)
+    } else {
+        i64::try_from(before - after).map_or(i64::MIN, |d| -d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(sites: &[(&str, u64, u64)]) -> MemorySnapshot {
+        let mut snapshot = MemorySnapshot::new();
+        for &(site, count, bytes) in sites {
+            snapshot.record_site(site, count, bytes);
+        }
+        snapshot
+    }
+
+    #[test]
+    fn test_diff_math() {
+        let baseline = snapshot(&[("Element", 10, 1000), ("Layer", 4, 400), ("Gone", 2, 64)]);
+        let current = snapshot(&[("Element", 15, 1500), ("Layer", 1, 100), ("New", 3, 30)]);
+
+        let delta = current.diff(&baseline);
+
+        // +5 Element, +3 New; -3 Layer, -2 Gone
+        assert_eq!(delta.allocations_added, 8);
+        assert_eq!(delta.allocations_freed, 5);
+        assert_eq!(delta.net_bytes, 1630 - 1464);
+        assert_eq!(
+            delta.sites,
+            vec![
+                SiteDelta {
+                    site: "Element".into(),
+                    count_delta: 5,
+                    bytes_delta: 500,
+                },
+                SiteDelta {
+                    site: "Gone".into(),
+                    count_delta: -2,
+                    bytes_delta: -64,
+                },
+                SiteDelta {
+                    site: "Layer".into(),
+                    count_delta: -3,
+                    bytes_delta: -300,
+                },
+                SiteDelta {
+                    site: "New".into(),
+                    count_delta: 3,
+                    bytes_delta: 30,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_identical_snapshots_is_empty() {
+        let a = snapshot(&[("Element", 10, 1000)]);
+        let b = snapshot(&[("Element", 10, 1000)]);
+        assert!(b.diff(&a).is_empty());
+        assert_eq!(b.diff(&a).net_bytes, 0);
+    }
+
+    #[test]
+    fn test_detect_leaks_requires_strict_growth() {
+        let profiler = MemoryProfiler::new();
+        let frames: [&[(&str, u64, u64)]; 4] = [
+            &[("Leaky", 1, 10), ("Stable", 5, 50), ("Plateau", 1, 10)],
+            &[("Leaky", 2, 20), ("Stable", 5, 50), ("Plateau", 2, 20)],
+            &[("Leaky", 3, 30), ("Stable", 5, 50), ("Plateau", 2, 20)],
+            &[("Leaky", 4, 40), ("Stable", 5, 50), ("Plateau", 3, 30)],
+        ];
+        for sites in frames {
+            profiler.record_snapshot(snapshot(sites));
+        }
+
+        assert_eq!(
+            profiler.detect_leaks(4),
+            vec![LeakSuspect {
+                site: "Leaky".into(),
+                first_count: 1,
+                last_count: 4,
+                bytes_growth: 30,
+            }]
+        );
+        // The last two snapshots alone show Plateau growing too
+        let recent: Vec<String> = profiler
+            .detect_leaks(2)
+            .into_iter()
+            .map(|s| s.site)
+            .collect();
+        assert_eq!(recent, vec!["Leaky".to_string(), "Plateau".to_string()]);
+    }
+
+    #[test]
+    fn test_detect_leaks_needs_a_full_window() {
+        let profiler = MemoryProfiler::new();
+        profiler.record_snapshot(snapshot(&[("Leaky", 1, 10)]));
+        profiler.record_snapshot(snapshot(&[("Leaky", 2, 20)]));
+
+        assert!(profiler.detect_leaks(3).is_empty());
+        assert!(profiler.detect_leaks(1).is_empty());
+        assert_eq!(profiler.detect_leaks(2).len(), 1);
+    }
+
+    #[test]
+    fn test_snapshot_history_limit() {
+        let profiler = MemoryProfiler::with_capacity(3);
+        for count in 0..5 {
+            profiler.record_snapshot(snapshot(&[("Element", count, 0)]));
+        }
+
+        let counts: Vec<u64> = profiler
+            .snapshots()
+            .iter()
+            .map(|s| s.site("Element").live_count)
+            .collect();
+        assert_eq!(counts, vec![2, 3, 4]);
+    }
+}
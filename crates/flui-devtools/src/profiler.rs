@@ -144,6 +144,87 @@ pub fn phase_duration_ms(&self, phase: FramePhase) -> Option<f64> {
     }
 }
 
+/// Compact timing record for one frame in the profiler timeline
+///
+/// Unlike [`FrameStats`], a record is `Copy` and holds a fixed set of
+/// phases, so the timeline ring buffer stores it without allocating.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameRecord {
+    /// Frame number
+    pub frame_number: u64,
+    /// When the frame began
+    pub start: Instant,
+    /// Total build time (sum of all build phases in the frame)
+    pub build: Duration,
+    /// Total layout time
+    pub layout: Duration,
+    /// Total paint time
+    pub paint: Duration,
+    /// Total frame time
+    pub total: Duration,
+    /// Whether this frame exceeded the jank threshold
+    pub is_jank: bool,
+}
+
+impl FrameRecord {
+    /// Get total frame time in milliseconds
+    pub fn total_time_ms(&self) -> f64 {
+        self.total.as_secs_f64() * 1000.0
+    }
+
+    /// Get the duration of a built-in phase
+    ///
+    /// Returns `None` for [`FramePhase::Custom`], which the timeline does
+    /// not track.
+    pub fn phase_duration(&self, phase: FramePhase) -> Option<Duration> {
+        match phase {
+            FramePhase::Build => Some(self.build),
+            FramePhase::Layout => Some(self.layout),
+            FramePhase::Paint => Some(self.paint),
+            FramePhase::Custom(_) => None,
+        }
+    }
+}
+
+/// Fixed-capacity ring buffer of the most recent frame records
+///
+/// Storage is allocated once up front; recording overwrites the oldest
+/// slot when full.
+struct FrameTimeline {
+    records: Vec<FrameRecord>,
+    capacity: usize,
+    /// Slot the next record overwrites once the buffer is full
+    head: usize,
+}
+
+impl FrameTimeline {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            records: Vec::with_capacity(capacity),
+            capacity,
+            head: 0,
+        }
+    }
+
+    fn push(&mut self, record: FrameRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.records.len() < self.capacity {
+            self.records.push(record);
+        } else {
+            self.records[self.head] = record;
+            self.head = (self.head + 1) % self.capacity;
+        }
+    }
+
+    /// Records oldest first
+    fn iter(&self) -> impl Iterator<Item = &FrameRecord> {
+        let (newer, older) = self.records.split_at(self.head);
+        older.iter().chain(newer)
+    }
+}
+
 /// RAII guard for profiling a phase
 ///
 /// Automatically records the phase duration when dropped.
@@ -183,6 +264,8 @@ struct ProfilerInner {
     current_phases: Vec<PhaseInfo>,
     /// Frame history
     frame_history: VecDeque<FrameStats>,
+    /// Per-phase timeline of the most recent frames
+    timeline: FrameTimeline,
     /// Total frames processed
     total_frames: u64,
     /// Total jank frames
@@ -192,12 +275,14 @@ struct ProfilerInner {
 impl ProfilerInner {
     fn new(config: DevToolsConfig) -> Self {
         let max_history = config.max_frame_history;
+        let timeline_capacity = config.timeline_capacity;
         Self {
             config,
             frame_number: 0,
             frame_start: None,
             current_phases: Vec::new(),
             frame_history: VecDeque::with_capacity(max_history),
+            timeline: FrameTimeline::with_capacity(timeline_capacity),
             total_frames: 0,
             jank_frames: 0,
         }
@@ -229,6 +314,25 @@ fn end_frame(&mut self) {
             0.0
         };
 
+        let mut record = FrameRecord {
+            frame_number: self.frame_number,
+            start,
+            build: Duration::ZERO,
+            layout: Duration::ZERO,
+            paint: Duration::ZERO,
+            total: total_time,
+            is_jank,
+        };
+        for info in &self.current_phases {
+            match info.phase {
+                FramePhase::Build => record.build += info.duration,
+                FramePhase::Layout => record.layout += info.duration,
+                FramePhase::Paint => record.paint += info.duration,
+                FramePhase::Custom(_) => {}
+            }
+        }
+        self.timeline.push(record);
+
         let stats = FrameStats {
             frame_number: self.frame_number,
             total_time,
@@ -273,6 +377,19 @@ fn frame_history(&self) -> Vec<FrameStats> {
         self.frame_history.iter().cloned().collect()
     }
 
+    fn timeline(&self) -> Vec<FrameRecord> {
+        self.timeline.iter().copied().collect()
+    }
+
+    fn jank_frames(&self) -> Vec<usize> {
+        self.timeline
+            .iter()
+            .enumerate()
+            .filter(|(_, record)| record.is_jank)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
     fn average_fps(&self) -> f64 {
         if self.frame_history.is_empty() {
             return 0.0;
@@ -366,6 +483,21 @@ pub fn frame_history(&self) -> Vec<FrameStats> {
         self.inner.lock().frame_history()
     }
 
+    /// Get the per-phase timeline of the most recent frames, oldest first
+    ///
+    /// Holds at most [`DevToolsConfig::timeline_capacity`] records.
+    /// Recording into the timeline never allocates; this accessor copies
+    /// the records out because they live behind the profiler's lock.
+    pub fn timeline(&self) -> Vec<FrameRecord> {
+        self.inner.lock().timeline()
+    }
+
+    /// Get the indices into [`timeline`](Self::timeline) of frames that
+    /// exceeded the jank threshold
+    pub fn jank_frames(&self) -> Vec<usize> {
+        self.inner.lock().jank_frames()
+    }
+
     /// Get average FPS across all frames in history
     pub fn average_fps(&self) -> f64 {
         self.inner.lock().average_fps()
@@ -573,6 +705,60 @@ fn test_frame_history_limit() {
         assert_eq!(history[4].frame_number, 9);
     }
 
+    #[test]
+    fn test_timeline_keeps_last_frames() {
+        let config = DevToolsConfig {
+            timeline_capacity: 30,
+            ..Default::default()
+        };
+
+        let profiler = Profiler::with_config(config);
+
+        for _ in 0..120 {
+            profiler.begin_frame();
+            {
+                let _guard = profiler.profile_phase(FramePhase::Layout);
+            }
+            profiler.end_frame();
+        }
+
+        let timeline = profiler.timeline();
+        assert_eq!(timeline.len(), 30);
+        // Oldest first: frames 90-119
+        for (i, record) in timeline.iter().enumerate() {
+            assert_eq!(record.frame_number, 90 + i as u64);
+            assert!(record.layout <= record.total);
+        }
+        // The preallocated ring never grew
+        assert_eq!(profiler.inner.lock().timeline.records.capacity(), 30);
+    }
+
+    #[test]
+    fn test_timeline_jank_frames() {
+        let config = DevToolsConfig {
+            timeline_capacity: 4,
+            ..Default::default()
+        };
+        let mut inner = ProfilerInner::new(config);
+        let start = Instant::now();
+
+        for frame_number in 0..6 {
+            inner.timeline.push(FrameRecord {
+                frame_number,
+                start,
+                build: Duration::ZERO,
+                layout: Duration::ZERO,
+                paint: Duration::ZERO,
+                total: Duration::ZERO,
+                is_jank: frame_number % 3 == 0,
+            });
+        }
+
+        // Timeline holds frames 2-5; frame 3 janked
+        assert_eq!(inner.jank_frames(), vec![1]);
+        assert_eq!(inner.timeline()[1].frame_number, 3);
+    }
+
     #[test]
     fn test_custom_phase() {
         let profiler = Profiler::new();
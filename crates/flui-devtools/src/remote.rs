@@ -0,0 +1,341 @@
+//! Remote debug protocol for FLUI applications
+//!
+//! JSON messages exchanged between a running app and a browser inspector.
+//! Every message carries a protocol `version` and a `type` tag; a request's
+//! `id` is echoed on its response so the client can match them up.
+//!
+//! The protocol layer is transport-agnostic: [`RemoteDebugServer`] turns one
+//! incoming text frame into one outgoing text frame, and whatever socket the
+//! app embeds (a WebSocket on desktop, `postMessage` on web) moves the
+//! frames.
+//!
+//! # Tree inspection
+//!
+//! This crate has no dependency on the framework core, so it cannot walk
+//! the element tree itself. The app implements [`TreeProvider`] and hands it
+//! to the server, which calls it for every [`Request::InspectTree`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use flui_devtools::remote::{
+//!     NodeLifecycle, RemoteDebugServer, TreeNode, TreeProvider,
+//! };
+//!
+//! struct App;
+//!
+//! impl TreeProvider for App {
+//!     fn tree_snapshot(&self) -> Option<TreeNode> {
+//!         Some(TreeNode::new(1, "MyApp", NodeLifecycle::Active))
+//!     }
+//! }
+//!
+//! let server = RemoteDebugServer::new(App);
+//! let reply = server.handle_message(r#"{"version":1,"id":7,"type":"inspectTree"}"#);
+//! assert!(reply.contains(r#""type":"treeSnapshot""#));
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// Protocol version spoken by this build
+///
+/// Bumped on breaking message changes. Newer clients are still served:
+/// unknown fields are ignored, and the response carries this version so
+/// the client can downgrade.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Lifecycle state of an element in a [`TreeNode`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NodeLifecycle {
+    /// Created but not yet mounted
+    Initial,
+    /// Mounted and part of the tree
+    Active,
+    /// Deactivated, may be reinserted this frame
+    Inactive,
+    /// Unmounted for good
+    Defunct,
+}
+
+/// One element in a [`Response::TreeSnapshot`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreeNode {
+    /// Element id
+    pub id: u64,
+    /// Widget type name
+    pub widget_type: String,
+    /// Element lifecycle state
+    pub lifecycle: NodeLifecycle,
+    /// Widget key, if any, in its debug form
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// Child elements in order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<TreeNode>,
+}
+
+impl TreeNode {
+    /// Create a node without key or children
+    pub fn new(id: u64, widget_type: impl Into<String>, lifecycle: NodeLifecycle) -> Self {
+        Self {
+            id,
+            widget_type: widget_type.into(),
+            lifecycle,
+            key: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Set the widget key (builder form)
+    #[must_use]
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Append a child (builder form)
+    #[must_use]
+    pub fn with_child(mut self, child: TreeNode) -> Self {
+        self.children.push(child);
+        self
+    }
+}
+
+/// Supplies element-tree snapshots to the remote debug server
+///
+/// Implemented by the app, which owns the element tree.
+pub trait TreeProvider: Send + Sync {
+    /// Snapshot the current element tree, or `None` when nothing is mounted
+    fn tree_snapshot(&self) -> Option<TreeNode>;
+}
+
+/// Client-to-app request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Request {
+    /// Ask for a snapshot of the element tree
+    InspectTree,
+}
+
+/// App-to-client response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Response {
+    /// Answer to [`Request::InspectTree`]
+    TreeSnapshot {
+        /// Root element, or `None` when nothing is mounted
+        root: Option<TreeNode>,
+    },
+    /// The request could not be served
+    Error {
+        /// Machine-readable failure kind
+        code: ErrorCode,
+        /// Human-readable detail
+        message: String,
+    },
+}
+
+/// Failure kind carried by [`Response::Error`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ErrorCode {
+    /// The frame was not a valid protocol message
+    Malformed,
+    /// The message `type` is not known to this build
+    UnknownMessageType,
+}
+
+/// Versioned wrapper around every message on the wire
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Envelope<T> {
+    /// Protocol version of the sender
+    pub version: u32,
+    /// Request id, echoed on the matching response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The message itself, flattened next to `version` and `id`
+    #[serde(flatten)]
+    pub message: T,
+}
+
+impl<T> Envelope<T> {
+    /// Wrap a message with the current [`PROTOCOL_VERSION`]
+    pub fn new(id: Option<u64>, message: T) -> Self {
+        Self {
+            version: PROTOCOL_VERSION,
+            id,
+            message,
+        }
+    }
+}
+
+/// Serves remote debug requests from a [`TreeProvider`]
+#[derive(Debug)]
+pub struct RemoteDebugServer<P> {
+    provider: P,
+}
+
+impl<P: TreeProvider> RemoteDebugServer<P> {
+    /// Create a server answering from `provider`
+    pub fn new(provider: P) -> Self {
+        Self { provider }
+    }
+
+    /// Answer one decoded request
+    pub fn handle_request(&self, request: &Request) -> Response {
+        match request {
+            Request::InspectTree => Response::TreeSnapshot {
+                root: self.provider.tree_snapshot(),
+            },
+        }
+    }
+
+    /// Answer one incoming text frame with one outgoing text frame
+    ///
+    /// Never fails: undecodable frames and unknown message types produce
+    /// a [`Response::Error`], echoing the request id when one was readable.
+    pub fn handle_message(&self, text: &str) -> String {
+        let response = match decode_request(text) {
+            Ok(envelope) => Envelope::new(envelope.id, self.handle_request(&envelope.message)),
+            Err((id, response)) => Envelope::new(id, response),
+        };
+        serde_json::to_string(&response).expect("BUG: protocol responses always serialize")
+    }
+}
+
+/// Decode a request frame, or build the error response for it
+fn decode_request(text: &str) -> Result<Envelope<Request>, (Option<u64>, Response)> {
+    let value: serde_json::Value = serde_json::from_str(text)
+        .map_err(|err| (None, error(ErrorCode::Malformed, err.to_string())))?;
+    let id = value.get("id").and_then(serde_json::Value::as_u64);
+
+    let Some(message_type) = value.get("type").and_then(serde_json::Value::as_str) else {
+        return Err((id, error(ErrorCode::Malformed, "missing message type")));
+    };
+    if !matches!(message_type, "inspectTree") {
+        return Err((
+            id,
+            error(
+                ErrorCode::UnknownMessageType,
+                format!("unknown message type `{message_type}`"),
+            ),
+        ));
+    }
+
+    serde_json::from_value(value).map_err(|err| (id, error(ErrorCode::Malformed, err.to_string())))
+}
+
+fn error(code: ErrorCode, message: impl Into<String>) -> Response {
+    Response::Error {
+        code,
+        message: message.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedTree(Option<TreeNode>);
+
+    impl TreeProvider for FixedTree {
+        fn tree_snapshot(&self) -> Option<TreeNode> {
+            self.0.clone()
+        }
+    }
+
+    fn sample_tree() -> TreeNode {
+        TreeNode::new(1, "MaterialApp", NodeLifecycle::Active).with_child(
+            TreeNode::new(2, "Column", NodeLifecycle::Active)
+                .with_child(TreeNode::new(3, "Text", NodeLifecycle::Active).with_key("[<'title'>]"))
+                .with_child(TreeNode::new(4, "Text", NodeLifecycle::Inactive)),
+        )
+    }
+
+    #[test]
+    fn test_tree_snapshot_round_trip() {
+        let response = Envelope::new(
+            Some(3),
+            Response::TreeSnapshot {
+                root: Some(sample_tree()),
+            },
+        );
+
+        let json = serde_json::to_string(&response).unwrap();
+        let decoded: Envelope<Response> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, response);
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], PROTOCOL_VERSION);
+        assert_eq!(value["type"], "treeSnapshot");
+        assert_eq!(value["root"]["children"][0]["widgetType"], "Column");
+        assert_eq!(
+            value["root"]["children"][0]["children"][1]["lifecycle"],
+            "inactive"
+        );
+    }
+
+    #[test]
+    fn test_inspect_tree_request() {
+        let server = RemoteDebugServer::new(FixedTree(Some(sample_tree())));
+
+        let reply = server.handle_message(r#"{"version":1,"id":42,"type":"inspectTree"}"#);
+        let decoded: Envelope<Response> = serde_json::from_str(&reply).unwrap();
+
+        assert_eq!(decoded.id, Some(42));
+        assert_eq!(
+            decoded.message,
+            Response::TreeSnapshot {
+                root: Some(sample_tree()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_newer_client_fields_are_ignored() {
+        let server = RemoteDebugServer::new(FixedTree(None));
+
+        let reply =
+            server.handle_message(r#"{"version":9,"id":1,"type":"inspectTree","depthLimit":3}"#);
+        let decoded: Envelope<Response> = serde_json::from_str(&reply).unwrap();
+
+        assert_eq!(decoded.version, PROTOCOL_VERSION);
+        assert_eq!(decoded.message, Response::TreeSnapshot { root: None });
+    }
+
+    #[test]
+    fn test_unknown_message_type() {
+        let server = RemoteDebugServer::new(FixedTree(None));
+
+        let reply = server.handle_message(r#"{"version":1,"id":5,"type":"hotRestart"}"#);
+        let decoded: Envelope<Response> = serde_json::from_str(&reply).unwrap();
+
+        assert_eq!(decoded.id, Some(5));
+        assert!(matches!(
+            decoded.message,
+            Response::Error {
+                code: ErrorCode::UnknownMessageType,
+                ref message,
+            } if message.contains("hotRestart")
+        ));
+    }
+
+    #[test]
+    fn test_malformed_frame() {
+        let server = RemoteDebugServer::new(FixedTree(None));
+
+        for frame in ["not json", r#"{"version":1,"id":2}"#] {
+            let decoded: Envelope<Response> =
+                serde_json::from_str(&server.handle_message(frame)).unwrap();
+            assert!(matches!(
+                decoded.message,
+                Response::Error {
+                    code: ErrorCode::Malformed,
+                    ..
+                }
+            ));
+        }
+    }
+}
@@ -28,10 +28,11 @@
 //!     println!("{}: {:.2}ms", event.name, event.duration_ms());
 //! }
 //!
-//! // Export to Chrome DevTools format
-//! let json = timeline.export_chrome_trace();
-//! std::fs::write("trace.json", json).unwrap();
-//! // Load trace.json in chrome://tracing
+//! // Export to Chrome Trace Event format
+//! let json = timeline.to_chrome_trace();
+//! let path = std::env::temp_dir().join("flui-timeline-trace.json");
+//! std::fs::write(&path, json).unwrap();
+//! // Load the file in chrome://tracing or Perfetto
 //! ```
 
 use std::sync::Arc;
@@ -133,14 +134,13 @@ pub fn duration(&self) -> Duration {
 pub struct EventGuard {
     timeline: Arc<Mutex<TimelineInner>>,
     event_index: usize,
-    start: Instant,
 }
 
 impl Drop for EventGuard {
     fn drop(&mut self) {
-        let duration = self.start.elapsed();
+        let end = Instant::now();
         let mut inner = self.timeline.lock();
-        inner.end_event(self.event_index, duration);
+        inner.end_event(self.event_index, end);
     }
 }
 
@@ -164,9 +164,8 @@ fn new(max_events: usize) -> Self {
         }
     }
 
-    fn start_event(&mut self, name: String, category: EventCategory) -> usize {
-        let now = Instant::now();
-        let start_micros = (now - self.start_time).as_micros();
+    fn start_event(&mut self, name: String, category: EventCategory, now: Instant) -> usize {
+        let start_micros = self.micros_since_start(now);
 
         let event = TimelineEvent {
             name,
@@ -190,12 +189,24 @@ fn start_event(&mut self, name: String, category: EventCategory) -> usize {
         }
     }
 
-    fn end_event(&mut self, index: usize, duration: Duration) {
+    /// Close the event at `index` at instant `end`.
+    ///
+    /// The duration is derived from the truncated start and end offsets
+    /// rather than measured separately, so an event that ends inside
+    /// another one never appears to outlive it after rounding.
+    fn end_event(&mut self, index: usize, end: Instant) {
+        let end_micros = self.micros_since_start(end);
         if let Some(event) = self.events.get_mut(index) {
-            event.duration_micros = duration.as_micros();
+            event.duration_micros = end_micros.saturating_sub(event.start_micros);
         }
     }
 
+    fn micros_since_start(&self, instant: Instant) -> u128 {
+        instant
+            .saturating_duration_since(self.start_time)
+            .as_micros()
+    }
+
     fn get_events(&self) -> Vec<TimelineEvent> {
         self.events.clone()
     }
@@ -255,13 +266,11 @@ pub fn with_capacity(max_events: usize) -> Self {
     /// ```
     pub fn record_event(&self, name: impl Into<String>, category: EventCategory) -> EventGuard {
         let mut inner = self.inner.lock();
-        let event_index = inner.start_event(name.into(), category);
-        let start = Instant::now();
+        let event_index = inner.start_event(name.into(), category, Instant::now());
 
         EventGuard {
             timeline: self.inner.clone(),
             event_index,
-            start,
         }
     }
 
@@ -270,9 +279,10 @@ pub fn record_event(&self, name: impl Into<String>, category: EventCategory) ->
     /// Use this for events that happen at a point in time rather than over a
     /// duration.
     pub fn record_instant(&self, name: impl Into<String>, category: EventCategory) {
+        let now = Instant::now();
         let mut inner = self.inner.lock();
-        let event_index = inner.start_event(name.into(), category);
-        inner.end_event(event_index, Duration::ZERO);
+        let event_index = inner.start_event(name.into(), category, now);
+        inner.end_event(event_index, now);
     }
 
     /// Get all recorded events
@@ -323,57 +333,110 @@ pub fn event_count(&self) -> usize {
 
     /// Export events to Chrome DevTools trace format
     ///
-    /// Returns a JSON string that can be loaded in chrome://tracing for
-    /// visualization.
+    /// Same output as [`to_chrome_trace`](Self::to_chrome_trace); kept for
+    /// existing callers.
+    pub fn export_chrome_trace(&self) -> String {
+        self.to_chrome_trace()
+    }
+
+    /// Export events to the Chrome Trace Event format
+    ///
+    /// Every span becomes a single complete (`"ph": "X"`) event with a
+    /// microsecond `ts` and `dur`, and threads get small numeric `tid`s with
+    /// `thread_name` metadata, which is what Perfetto expects. Spans recorded
+    /// inside a frame span nest under it; instant events become `"ph": "i"`.
+    ///
+    /// Events are emitted per thread in begin order, with enclosing spans
+    /// ahead of the spans they contain.
     ///
     /// # Example
     ///
     /// ```rust
-    /// # use flui_devtools::timeline::Timeline;
+    /// # use flui_devtools::timeline::{EventCategory, Timeline};
     /// # let timeline = Timeline::new();
-    /// let json = timeline.export_chrome_trace();
-    /// std::fs::write("trace.json", json).unwrap();
-    /// // Then open chrome://tracing and load trace.json
+    /// {
+    ///     let _frame = timeline.record_event("Frame 1", EventCategory::Frame);
+    ///     let _build = timeline.record_event("Build", EventCategory::Build);
+    /// }
+    /// let json = timeline.to_chrome_trace();
+    /// // Load in chrome://tracing or https://ui.perfetto.dev
+    /// # assert!(json.contains(r#""ph":"X""#));
     /// ```
-    pub fn export_chrome_trace(&self) -> String {
-        let events = self.get_events();
+    pub fn to_chrome_trace(&self) -> String {
+        const PID: u32 = 1;
 
-        let trace_events: Vec<_> = events
-            .iter()
-            .flat_map(|event| {
-                // Chrome trace format uses "B" (begin) and "E" (end) events
-                let thread_id = format!("{:?}", event.thread_id);
-
-                vec![
-                    // Begin event
-                    json!({
-                        "name": event.name,
-                        "cat": event.category.name(),
-                        "ph": "B", // Begin
-                        "ts": event.start_micros,
-                        "pid": 1,
-                        "tid": thread_id,
-                        "args": {
-                            "category": event.category.name(),
-                        }
-                    }),
-                    // End event
-                    json!({
-                        "name": event.name,
-                        "cat": event.category.name(),
-                        "ph": "E", // End
-                        "ts": event.start_micros + event.duration_micros,
-                        "pid": 1,
-                        "tid": thread_id,
-                    }),
-                ]
-            })
-            .collect();
+        let mut events = self.get_events();
+
+        // Chrome wants integer tids; number threads in order of first use.
+        let mut threads: Vec<std::thread::ThreadId> = Vec::new();
+        for event in &events {
+            if !threads.contains(&event.thread_id) {
+                threads.push(event.thread_id);
+            }
+        }
+        let tid_of = |thread_id| {
+            threads
+                .iter()
+                .position(|&known| known == thread_id)
+                .expect("BUG: every event thread was numbered")
+                + 1
+        };
+
+        // Parents start no later and end no earlier than their children, so
+        // sorting by start then longest-first puts each parent first.
+        events.sort_by(|a, b| {
+            tid_of(a.thread_id)
+                .cmp(&tid_of(b.thread_id))
+                .then(a.start_micros.cmp(&b.start_micros))
+                .then(b.duration_micros.cmp(&a.duration_micros))
+        });
+
+        let mut trace_events = vec![json!({
+            "name": "process_name",
+            "ph": "M",
+            "pid": PID,
+            "tid": 0,
+            "args": { "name": "FLUI" },
+        })];
+        for (index, thread_id) in threads.iter().enumerate() {
+            let tid = index + 1;
+            trace_events.push(json!({
+                "name": "thread_name",
+                "ph": "M",
+                "pid": PID,
+                "tid": tid,
+                "args": { "name": format!("{thread_id:?}") },
+            }));
+            trace_events.push(json!({
+                "name": "thread_sort_index",
+                "ph": "M",
+                "pid": PID,
+                "tid": tid,
+                "args": { "sort_index": tid },
+            }));
+        }
+
+        trace_events.extend(events.iter().map(|event| {
+            let mut trace_event = json!({
+                "name": event.name,
+                "cat": event.category.name(),
+                "ts": event.start_micros,
+                "pid": PID,
+                "tid": tid_of(event.thread_id),
+            });
+            if event.duration_micros == 0 {
+                trace_event["ph"] = json!("i");
+                trace_event["s"] = json!("t");
+            } else {
+                trace_event["ph"] = json!("X");
+                trace_event["dur"] = json!(event.duration_micros);
+            }
+            trace_event
+        }));
 
         json!({
             "traceEvents": trace_events,
             "displayTimeUnit": "ms",
-            "systemTraceEvents": "SystemTraceData",
             "otherData": {
                 "version": "FLUI DevTools Timeline"
             }
@@ -572,9 +635,99 @@ fn test_export_chrome_trace() {
 
         let json = timeline.export_chrome_trace();
         assert!(json.contains("Test Event"));
-        assert!(json.contains("\"ph\":\"B\"")); // Begin event
-        assert!(json.contains("\"ph\":\"E\"")); // End event
         assert!(json.contains("traceEvents"));
+        assert_eq!(json, timeline.to_chrome_trace());
+    }
+
+    #[test]
+    fn test_to_chrome_trace_parses() {
+        let timeline = Timeline::new();
+
+        {
+            let _guard = timeline.record_event("Paint", EventCategory::Paint);
+            thread::sleep(Duration::from_millis(1));
+        }
+        timeline.record_instant("Vsync", EventCategory::Custom);
+
+        let trace: serde_json::Value = serde_json::from_str(&timeline.to_chrome_trace()).unwrap();
+        let events = trace["traceEvents"].as_array().unwrap();
+
+        let metadata: Vec<_> = events.iter().filter(|e| e["ph"] == "M").collect();
+        assert!(metadata.iter().any(|e| e["name"] == "process_name"));
+        assert!(
+            metadata
+                .iter()
+                .any(|e| e["name"] == "thread_name" && e["tid"] == 1)
+        );
+
+        let paint = events.iter().find(|e| e["name"] == "Paint").unwrap();
+        assert_eq!(paint["ph"], "X");
+        assert_eq!(paint["cat"], "Paint");
+        assert_eq!(paint["pid"], 1);
+        assert_eq!(paint["tid"], 1);
+        assert!(paint["dur"].as_u64().unwrap() >= 1_000);
+
+        let vsync = events.iter().find(|e| e["name"] == "Vsync").unwrap();
+        assert_eq!(vsync["ph"], "i");
+        assert!(vsync["ts"].as_u64().unwrap() >= paint["ts"].as_u64().unwrap());
+    }
+
+    #[test]
+    fn test_to_chrome_trace_nests_spans_in_frames() {
+        let timeline = Timeline::new();
+
+        for _ in 0..2 {
+            let _frame = timeline.record_event("Frame", EventCategory::Frame);
+            {
+                let _build = timeline.record_event("Build", EventCategory::Build);
+                let _child = timeline.record_event("Rebuild child", EventCategory::Custom);
+                thread::sleep(Duration::from_millis(1));
+            }
+            let _layout = timeline.record_event("Layout", EventCategory::Layout);
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        let trace: serde_json::Value = serde_json::from_str(&timeline.to_chrome_trace()).unwrap();
+        let spans: Vec<(String, u64, u64)> = trace["traceEvents"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|e| e["ph"] == "X")
+            .map(|e| {
+                let ts = e["ts"].as_u64().unwrap();
+                let dur = e["dur"].as_u64().unwrap();
+                (e["name"].as_str().unwrap().to_owned(), ts, ts + dur)
+            })
+            .collect();
+
+        let names: Vec<_> = spans.iter().map(|(name, ..)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "Frame",
+                "Build",
+                "Rebuild child",
+                "Layout",
+                "Frame",
+                "Build",
+                "Rebuild child",
+                "Layout",
+            ]
+        );
+
+        // Every span begins no earlier than, and ends within, its parent.
+        for frame in spans.chunks(4) {
+            let (_, frame_start, frame_end) = frame[0];
+            let (_, build_start, build_end) = frame[1];
+            let (_, child_start, child_end) = frame[2];
+            let (_, layout_start, layout_end) = frame[3];
+
+            assert!(frame_start <= build_start && build_end <= frame_end);
+            assert!(build_start <= child_start && child_end <= build_end);
+            assert!(build_end <= layout_start);
+            assert!(frame_start <= layout_start && layout_end <= frame_end);
+        }
+        assert!(spans[0].2 <= spans[4].1);
     }
 
     #[test]
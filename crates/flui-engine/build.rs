@@ -12,7 +12,7 @@
 //! | Shader              | OUT_DIR file                  | Uniform size | Imports        |
 //! |---------------------|-------------------------------|--------------|----------------|
 //! | gamma.wgsl          | gamma_generated.rs            | 16 bytes     | —              |
-//! | blur.wgsl           | blur_generated.rs             | 32 bytes     | —              |
+//! | blur.wgsl           | blur_generated.rs             | 48 bytes     | —              |
 //! | morphology.wgsl     | morphology_generated.rs       | 48 bytes     | —              |
 //! | color_matrix.wgsl   | color_matrix_generated.rs     | 80 bytes     | —              |
 //! | effects/mode.wgsl   | mode_generated.rs             | 32 bytes     | blend_helpers  |
@@ -169,6 +169,17 @@ pub enum EngineError {
     #[error("Text render error: {0}")]
     TextRender(#[source] Box<dyn Error + Send + Sync>),
 
+    // ========================================================================
+    // Capture errors
+    // ========================================================================
+    /// Reading rendered pixels back to the CPU failed.
+    ///
+    /// Boxes the backend's capture error (for wgpu, `ImageCaptureError`:
+    /// unsupported format, oversized capture, poll or map failure) via
+    /// `#[source]`. Use [`EngineError::readback`] to construct it.
+    #[error("Readback failed: {0}")]
+    Readback(#[source] Box<dyn Error + Send + Sync>),
+
     // ========================================================================
     // State errors
     // ========================================================================
@@ -228,7 +239,8 @@ pub fn recoverability(&self) -> Recoverability {
             Self::SurfaceValidation
             | Self::ResourceIo { .. }
             | Self::TextPrepare(_)
-            | Self::TextRender(_) => Recoverability::Unrecoverable,
+            | Self::TextRender(_)
+            | Self::Readback(_) => Recoverability::Unrecoverable,
         }
     }
 }
@@ -309,6 +321,15 @@ pub fn text_render<E>(error: E) -> Self
     {
         EngineError::TextRender(Box::new(error))
     }
+
+    /// Create a pixel-readback error from any error type.
+    #[must_use]
+    pub fn readback<E>(error: E) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        EngineError::Readback(Box::new(error))
+    }
 }
 
 // ============================================================================
@@ -400,6 +421,10 @@ fn test_recoverability_table() {
             EngineError::text_render(std::io::Error::other("render boom")).recoverability(),
             Recoverability::Unrecoverable
         );
+        assert_eq!(
+            EngineError::readback(std::io::Error::other("map boom")).recoverability(),
+            Recoverability::Unrecoverable
+        );
     }
 
     #[cfg(feature = "wgpu-backend")]
@@ -153,4 +153,6 @@
 pub use wgpu::DebugBackend;
 // wgpu backend exports
 #[cfg(feature = "wgpu-backend")]
-pub use wgpu::{Backend, FontLoader, LayerRender, WgpuPainter};
+pub use wgpu::{
+    Backend, FontLoader, GlyphAtlasStats, ImageCaptureError, LayerRender, RenderStats, WgpuPainter,
+};
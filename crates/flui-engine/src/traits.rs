@@ -250,7 +250,7 @@ fn render_vertices(
         vertices: &[Point<Pixels>],
         colors: Option<&[Color]>,
         tex_coords: Option<&[Point<Pixels>]>,
-        indices: &[u16],
+        indices: &[u32],
         paint: &Paint,
         transform: &Matrix4,
     );
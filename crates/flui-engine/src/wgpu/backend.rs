@@ -6,7 +6,7 @@
 use flui_painting::{BlendMode, DisplayListCore, Paint, PointMode};
 use flui_types::{
     geometry::{Matrix4, Offset, Pixels, Point, RRect, Rect, Size, Transform, px},
-    painting::{Image, Path},
+    painting::{Image, Path, TileMode},
     styling::Color,
     typography::TextStyle,
 };
@@ -352,6 +352,42 @@ fn flush_active_transform(&mut self) {
         }
     }
 
+    /// Sigma of a backdrop `filter` the shared blur pipeline can apply, or
+    /// `None` (with a `warn!`) if it cannot.
+    ///
+    /// The backdrop blur samples the copied surface region with clamped
+    /// edges, so only [`TileMode::Clamp`] — the backdrop default when
+    /// `tile_mode` is `None` — is supported; other modes are rejected rather
+    /// than silently rendered as `Clamp`. Non-blur filters are rejected too.
+    pub(crate) fn backdrop_blur_sigma(filter: &flui_types::painting::ImageFilter) -> Option<f32> {
+        use flui_types::painting::ImageFilter;
+
+        match filter {
+            ImageFilter::Blur {
+                sigma_x,
+                sigma_y,
+                tile_mode: None | Some(TileMode::Clamp),
+            } => Some(f32::midpoint(*sigma_x, *sigma_y)),
+            ImageFilter::Blur {
+                tile_mode: Some(tile_mode),
+                ..
+            } => {
+                tracing::warn!(
+                    ?tile_mode,
+                    "Backdrop blur only supports TileMode::Clamp; rendering children only"
+                );
+                None
+            }
+            other => {
+                tracing::warn!(
+                    "Backdrop filter type {:?} not supported for GPU blur; rendering children only",
+                    other
+                );
+                None
+            }
+        }
+    }
+
     /// Shared backdrop-filter blur pipeline used by both backdrop entry points:
     /// the layer-tree path (`Renderer::handle_backdrop_filter`, "Path A") and the
     /// display-list path ([`render_backdrop_filter`](CommandRenderer::render_backdrop_filter),
@@ -659,10 +695,15 @@ fn render_text_span(
             .unwrap_or(Color::BLACK);
 
         // Flatten the span tree into per-run (text, merged style) pairs with
-        // text_scale_factor baked into every effective font size.
+        // text_scale_factor baked into every effective font size, then lower
+        // word spacing exactly as `TextLayout` does so wrapping matches the
+        // measured layout.
         // Average and worst case O(total spans + text bytes): one pre-order walk.
         #[allow(clippy::cast_possible_truncation)] // same truncation guard as above
-        let runs = crate::wgpu::text::collect_styled_spans(span, text_scale_factor as f32);
+        let runs = flui_painting::expand_word_spacing(crate::wgpu::text::collect_styled_spans(
+            span,
+            text_scale_factor as f32,
+        ));
 
         if runs.is_empty() {
             return;
@@ -1168,7 +1209,6 @@ fn render_backdrop_filter(
         // `_blend_mode` is intentionally dropped here. Advanced blend on a
         // BackdropFilter is a separate future Path-A backdrop-compositor seam,
         // out of PR-5 scope. PR-5 covers shape/gradient/image producers only.
-        use flui_painting::display_list::ImageFilter;
 
         // Dispatch the child display list (or no-op when None). Used both as the
         // fall-back when no blur is applied (non-blur filter / no surface) AND as
@@ -1195,18 +1235,11 @@ fn render_backdrop_filter(
         // layer-tree level) via `Backend::apply_backdrop_blur` — the clamp +
         // copy + blur + composite live there once, so the off-screen-clamp
         // handling can no longer drift between the two paths. Non-blur filters
-        // and a missing surface fall back to passthrough with a `warn!` so the
-        // gap stays observable.
-        let sigma = match filter {
-            ImageFilter::Blur { sigma_x, sigma_y } => f32::midpoint(*sigma_x, *sigma_y),
-            other => {
-                tracing::warn!(
-                    "Backdrop filter type {:?} not supported in DisplayList path; passthrough",
-                    other
-                );
-                dispatch_children(self);
-                return;
-            }
+        // (and blur tile modes other than `Clamp`) and a missing surface fall
+        // back to passthrough with a `warn!` so the gap stays observable.
+        let Some(sigma) = Self::backdrop_blur_sigma(filter) else {
+            dispatch_children(self);
+            return;
         };
 
         let (Some(surface_view), Some(surface_texture)) = (self.surface_view, self.surface_texture)
@@ -1237,7 +1270,7 @@ fn render_vertices(
         vertices: &[Point<Pixels>],
         colors: Option<&[Color]>,
         tex_coords: Option<&[Point<Pixels>]>,
-        indices: &[u16],
+        indices: &[u32],
         paint: &Paint,
         transform: &Matrix4,
     ) {
@@ -1611,17 +1644,26 @@ fn push_image_filter(&mut self, filter: &flui_painting::display_list::ImageFilte
         self.flush_active_transform();
 
         match filter {
-            ImageFilter::Blur { sigma_x, sigma_y } => {
+            ImageFilter::Blur {
+                sigma_x,
+                sigma_y,
+                tile_mode,
+            } => {
                 // Full GPU separable Gaussian blur via two H/V sub-passes
                 // (PINNED #2: premultiplied-direct, sRGB-encoded, √3·σ kernel).
+                // A layer blur without an explicit tile mode decals (Flutter's
+                // `tileMode: null` default for layers).
+                let tile_mode = tile_mode.unwrap_or(TileMode::Decal);
                 self.painter
                     .save_layer_with_image_filter(ImageFilterSpec::Blur {
                         sigma_x: *sigma_x,
                         sigma_y: *sigma_y,
+                        tile_mode,
                     });
                 tracing::trace!(
                     sigma_x,
                     sigma_y,
+                    ?tile_mode,
                     "push_image_filter(Blur): GPU Gaussian blur layer opened"
                 );
             }
@@ -1747,10 +1789,15 @@ pub(crate) fn flatten_compose(
     use flui_painting::display_list::ImageFilter;
     for filter in filters {
         match filter {
-            ImageFilter::Blur { sigma_x, sigma_y } => {
+            ImageFilter::Blur {
+                sigma_x,
+                sigma_y,
+                tile_mode,
+            } => {
                 out.push(ImageFilterPass::Blur {
                     sigma_x: *sigma_x,
                     sigma_y: *sigma_y,
+                    tile_mode: tile_mode.unwrap_or(TileMode::Decal),
                 });
             }
             ImageFilter::Dilate { radius } => {
@@ -2394,10 +2441,7 @@ fn backdrop_filter_composites_at_clamped_rect_when_crossing_edge() {
         // Clamped to 64×64 surface: right→64, bottom→64, so w=32, h=32.
         // The discriminating assertion: composite_rect.width() must be 32, not 68.
         let filter_bounds = Rect::from_xywh(px(32.0), px(32.0), px(68.0), px(68.0));
-        let filter = ImageFilter::Blur {
-            sigma_x: 4.0,
-            sigma_y: 4.0,
-        };
+        let filter = ImageFilter::blur(4.0);
 
         let mut backend = Backend::with_offscreen(&mut painter, &mut offscreen);
         backend.bind_surface(&surface_view, &surface_texture);
@@ -2451,4 +2495,33 @@ fn backdrop_filter_composites_at_clamped_rect_when_crossing_edge() {
             composite_rect.height().0
         );
     }
+
+    /// The backdrop blur only samples with clamped edges, so explicit
+    /// non-`Clamp` tile modes are rejected instead of silently ignored.
+    #[test]
+    fn backdrop_blur_sigma_rejects_non_clamp_tile_modes() {
+        use flui_types::painting::ImageFilter;
+
+        assert_eq!(
+            Backend::backdrop_blur_sigma(&ImageFilter::blur_directional(2.0, 4.0)),
+            Some(3.0)
+        );
+        assert_eq!(
+            Backend::backdrop_blur_sigma(&ImageFilter::blur_with_tile_mode(
+                2.0,
+                4.0,
+                TileMode::Clamp
+            )),
+            Some(3.0)
+        );
+        for tile_mode in [TileMode::Decal, TileMode::Repeat, TileMode::Mirror] {
+            assert_eq!(
+                Backend::backdrop_blur_sigma(&ImageFilter::blur_with_tile_mode(
+                    2.0, 4.0, tile_mode
+                )),
+                None,
+                "{tile_mode:?}"
+            );
+        }
+    }
 }
@@ -585,30 +585,6 @@ pub(in super::super) fn draw_image_nine_slice(
             return;
         }
 
-        // Slice boundaries in image space.
-        let sl = center_slice.left().0;
-        let st = center_slice.top().0;
-        let sr = center_slice.right().0;
-        let sb = center_slice.bottom().0;
-
-        // Destination boundaries.
-        let dl = dst.left().0;
-        let dt = dst.top().0;
-        let dr = dst.right().0;
-        let db = dst.bottom().0;
-
-        // Inner destination boundaries (corners keep their pixel size).
-        let d_inner_left = dl + sl;
-        let d_inner_top = dt + st;
-        let d_inner_right = dr - (img_w - sr);
-        let d_inner_bottom = db - (img_h - sb);
-
-        // Clamp: if dst is too small, inner edges collapse.
-        let d_inner_left = d_inner_left.min(dr);
-        let d_inner_top = d_inner_top.min(db);
-        let d_inner_right = d_inner_right.max(d_inner_left);
-        let d_inner_bottom = d_inner_bottom.max(d_inner_top);
-
         // Helper: draw a sub-image region to a destination rect.
         // Since draw_image draws the full image into dst_rect, we use it per-slice.
         // For a proper 9-slice we'd need draw_image_src_dst (src rect -> dst rect).
@@ -643,108 +619,25 @@ pub(in super::super) fn draw_image_nine_slice(
             }
         };
 
-        // 9 slices: (src_x, src_y, src_w, src_h) -> dst rect.
-        let slices: [(f32, f32, f32, f32, f32, f32, f32, f32); 9] = [
-            // Top-left corner
-            (
-                0.0,
-                0.0,
-                sl,
-                st,
-                dl,
-                dt,
-                d_inner_left - dl,
-                d_inner_top - dt,
-            ),
-            // Top center
-            (
-                sl,
-                0.0,
-                sr - sl,
-                st,
-                d_inner_left,
-                dt,
-                d_inner_right - d_inner_left,
-                d_inner_top - dt,
-            ),
-            // Top-right corner
-            (
-                sr,
-                0.0,
-                img_w - sr,
-                st,
-                d_inner_right,
-                dt,
-                dr - d_inner_right,
-                d_inner_top - dt,
-            ),
-            // Middle-left
-            (
-                0.0,
-                st,
-                sl,
-                sb - st,
-                dl,
-                d_inner_top,
-                d_inner_left - dl,
-                d_inner_bottom - d_inner_top,
-            ),
-            // Center
-            (
-                sl,
-                st,
-                sr - sl,
-                sb - st,
-                d_inner_left,
-                d_inner_top,
-                d_inner_right - d_inner_left,
-                d_inner_bottom - d_inner_top,
-            ),
-            // Middle-right
-            (
-                sr,
-                st,
-                img_w - sr,
-                sb - st,
-                d_inner_right,
-                d_inner_top,
-                dr - d_inner_right,
-                d_inner_bottom - d_inner_top,
-            ),
-            // Bottom-left corner
-            (
-                0.0,
-                sb,
-                sl,
-                img_h - sb,
-                dl,
-                d_inner_bottom,
-                d_inner_left - dl,
-                db - d_inner_bottom,
-            ),
-            // Bottom center
-            (
-                sl,
-                sb,
-                sr - sl,
-                img_h - sb,
-                d_inner_left,
-                d_inner_bottom,
-                d_inner_right - d_inner_left,
-                db - d_inner_bottom,
-            ),
-            // Bottom-right corner
+        // 9 slices: (src_x, src_y, src_w, src_h) -> dst rect, from the
+        // geometry shared with the recording side.
+        let slices = flui_painting::display_list::nine_slice_regions(
+            flui_types::Size::new(px(img_w), px(img_h)),
+            center_slice,
+            dst,
+        )
+        .map(|(src, dst)| {
             (
-                sr,
-                sb,
-                img_w - sr,
-                img_h - sb,
-                d_inner_right,
-                d_inner_bottom,
-                dr - d_inner_right,
-                db - d_inner_bottom,
-            ),
-        ];
+                src.left().0,
+                src.top().0,
+                src.width().0,
+                src.height().0,
+                dst.left().0,
+                dst.top().0,
+                dst.width().0,
+                dst.height().0,
+            )
+        });
 
         if blend_mode.is_advanced() {
             // ── Advanced: collect all slices into one segment → one AdvancedShape ──
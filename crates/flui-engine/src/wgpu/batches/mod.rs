@@ -1315,4 +1315,59 @@ fn prior_srcover_content_sealed_before_advanced_shape() {
             std::mem::discriminant(&draw_order[1])
         );
     }
+
+    // ── B1: batch boundaries under alternating blend modes ───────────────────
+
+    /// Records `modes` as one quad each and returns the replay statistics of
+    /// the sealed draw order.
+    fn stats_for_blend_sequence(modes: &[BlendMode]) -> super::super::RenderStats {
+        let mut segment = DrawSegment::new();
+        let mut draw_order: Vec<DrawItem> = Vec::new();
+        let state = GpuStateStack::new_for_test();
+
+        for (i, &mode) in modes.iter().enumerate() {
+            let x = i as f32 * 20.0;
+            let key = if mode == BlendMode::SrcOver {
+                PipelineKey::alpha_blend()
+            } else {
+                PipelineKey::with_blend(mode)
+            };
+            DrawBatcher::add_tessellated_with_key(
+                &mut segment,
+                &mut draw_order,
+                &state,
+                rect_vertices(x, 0.0, x + 10.0, 10.0),
+                &rect_indices(),
+                key,
+            );
+        }
+        DrawBatcher::finish_current_segment(&mut segment, &mut draw_order);
+        super::super::RenderStats::from_items(&draw_order)
+    }
+
+    /// B1: consecutive shapes with the same blend mode and clip coalesce into
+    /// one buffer and one draw call.
+    #[test]
+    fn same_blend_mode_run_is_one_batch_and_one_draw() {
+        let stats = stats_for_blend_sequence(&[BlendMode::SrcOver; 6]);
+        assert_eq!(stats.batches, 1);
+        assert_eq!(stats.draw_calls, 1, "six SrcOver quads must share one draw");
+        assert_eq!(stats.vertices, 6 * 4);
+    }
+
+    /// B2: alternating blend modes break the batch at every mode change.
+    ///
+    /// `SrcOver SrcOver Plus SrcOver Plus SrcOver`: the leading SrcOver pair
+    /// merges; each Plus is its own draw and seals its segment (the
+    /// non-`SrcOver` draw-order contract), so the sequence flushes as
+    /// `[SrcOver×2, Plus] [SrcOver, Plus] [SrcOver]`.
+    #[test]
+    fn alternating_blend_modes_break_batches_at_each_change() {
+        use BlendMode::{Plus, SrcOver};
+
+        let stats = stats_for_blend_sequence(&[SrcOver, SrcOver, Plus, SrcOver, Plus, SrcOver]);
+        assert_eq!(stats.batches, 3, "one buffer per sealed segment");
+        assert_eq!(stats.draw_calls, 5, "a draw per blend-mode run");
+        assert_eq!(stats.vertices, 6 * 4);
+    }
 }
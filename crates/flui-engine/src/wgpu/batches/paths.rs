@@ -395,7 +395,7 @@ pub(in super::super) fn draw_vertices(
         vertices: &[Point<Pixels>],
         colors: Option<&[Color]>,
         tex_coords: Option<&[Point<Pixels>]>,
-        indices: &[u16],
+        indices: &[u32],
         paint: &Paint,
     ) {
         #[cfg(debug_assertions)]
@@ -445,9 +445,6 @@ pub(in super::super) fn draw_vertices(
             })
             .collect();
 
-        // Convert indices to u32
-        let gpu_indices: Vec<u32> = indices.iter().map(|&i| u32::from(i)).collect();
-
         // Add to tessellated geometry (bypassing tessellator since we already have
         // triangles).  Bake current_transform into vertex positions: shape.wgsl has
         // no model-matrix uniform.
@@ -456,7 +453,7 @@ pub(in super::super) fn draw_vertices(
             draw_order,
             state,
             gpu_vertices,
-            &gpu_indices,
+            indices,
             pipeline::pipeline_key_from_paint(paint),
         );
     }
@@ -6,14 +6,13 @@
 //! authoritative source of truth for the blur bind-group layout.
 //!
 //! The generated module `blur` exposes:
-//! - `BlurUniforms` — `#[repr(C, align(...))] Pod + Zeroable` uniform struct (32 bytes).
+//! - `BlurUniforms` — `#[repr(C, align(...))] Pod + Zeroable` uniform struct (48 bytes).
 //! - `WgpuBindGroup0` / `WgpuBindGroup0Entries` — typed bind-group helpers.
 //! - `BlurUniformsInit` — pad-free constructor (zero-fills `_pad*` automatically).
 //!
 //! ## Byte-identity guarantee (GO/NO-GO gate)
 //!
-//! The generated `BlurUniforms` struct MUST be layout-identical to the
-//! former hand-written `BlurUniform` that it replaces:
+//! The generated `BlurUniforms` struct MUST have this layout:
 //!
 //! | Byte offset | Size | Field              |
 //! |-------------|------|--------------------|
@@ -21,9 +20,10 @@
 //! | 8           | 4    | `sigma`            |
 //! | 12          | 4    | `direction`        |
 //! | 16          | 16   | `content_rect_uv`  |
+//! | 32          | 4    | `tile_mode`        |
 //!
-//! Total = 32 bytes.  The `const` assertions in `pipeline.rs` enforce this at
-//! compile time.
+//! Total = 48 bytes (padded to align 16).  The `const` assertions in
+//! `pipeline.rs` enforce this at compile time.
 
 // JUSTIFICATION: all lints below apply to code generated by wgsl_bindgen 0.22.
 // Names, derives, and patterns are controlled by the codegen tool, not by this
@@ -11,7 +11,7 @@
 //!
 //! | Binding | Stage  | Type                     | Content                          |
 //! |---------|--------|--------------------------|----------------------------------|
-//! | 0       | VS+FS  | Uniform buffer           | `BlurUniforms` (32 bytes)        |
+//! | 0       | VS+FS  | Uniform buffer           | `BlurUniforms` (48 bytes)        |
 //! | 1       | VS+FS  | 2D float texture         | Source (premultiplied RGBA)      |
 //! | 2       | VS+FS  | Linear-filtering sampler | Bilinear + ClampToEdge           |
 //!
@@ -25,8 +25,10 @@
 //! | 8           | 4    | `sigma`            | `f32`          |
 //! | 12          | 4    | `direction`        | `f32`          |
 //! | 16          | 16   | `content_rect_uv`  | `vec4<f32>`    |
+//! | 32          | 4    | `tile_mode`        | `f32`          |
+//! | 36          | 12   | (padding)          | —              |
 //!
-//! Total = 32 bytes (multiple of 16 ✓).
+//! Total = 48 bytes (multiple of 16 ✓).
 //!
 //! ## Sampler
 //!
@@ -43,10 +45,10 @@
 // struct whose layout differs from the hand-written `BlurUniform`, this file
 // will fail to compile — which is the correct signal to stop and report BLOCKED.
 
-/// The generated `BlurUniforms` must be exactly 32 bytes — matching the WGSL
-/// `BlurUniforms` struct and the former hand-written `BlurUniform`.
+/// The generated `BlurUniforms` must be exactly 48 bytes — matching the WGSL
+/// `BlurUniforms` struct (the 36 bytes of fields round up to align 16).
 const _GENERATED_BLUR_UNIFORMS_SIZE_CHECK: () = {
-    assert!(std::mem::size_of::<blur::BlurUniforms>() == 32);
+    assert!(std::mem::size_of::<blur::BlurUniforms>() == 48);
 };
 
 /// `texture_size` must be at byte offset 0.
@@ -69,6 +71,11 @@
     assert!(std::mem::offset_of!(blur::BlurUniforms, content_rect_uv) == 16);
 };
 
+/// `tile_mode` must be at byte offset 32.
+const _GENERATED_BLUR_TILE_MODE_OFFSET_CHECK: () = {
+    assert!(std::mem::offset_of!(blur::BlurUniforms, tile_mode) == 32);
+};
+
 // ── Pipeline ──────────────────────────────────────────────────────────────────
 
 /// Render pipeline for the Gaussian blur filter pass.
@@ -165,16 +172,16 @@ pub(crate) fn new(device: &wgpu::Device, surface_format: wgpu::TextureFormat) ->
 mod cpu_tests {
     use super::blur;
 
-    /// The generated `BlurUniforms` struct must be 32 bytes to match the WGSL
-    /// `BlurUniforms` block and the former hand-written `BlurUniform`.
+    /// The generated `BlurUniforms` struct must be 48 bytes to match the WGSL
+    /// `BlurUniforms` block.
     /// The `const` asserts above fire at compile time; this test makes the
     /// requirement visible in test output.
     #[test]
-    fn blur_uniforms_size_is_32_bytes() {
+    fn blur_uniforms_size_is_48_bytes() {
         assert_eq!(
             std::mem::size_of::<blur::BlurUniforms>(),
-            32,
-            "BlurUniforms must be 32 bytes to match BlurUniforms in blur.wgsl"
+            48,
+            "BlurUniforms must be 48 bytes to match BlurUniforms in blur.wgsl"
         );
     }
 
@@ -201,6 +208,11 @@ fn blur_uniforms_field_offsets_match_wgsl() {
             16,
             "content_rect_uv must be at offset 16"
         );
+        assert_eq!(
+            std::mem::offset_of!(blur::BlurUniforms, tile_mode),
+            32,
+            "tile_mode must be at offset 32"
+        );
     }
 
     /// Round-trip: values written into the struct are readable from the same
@@ -211,11 +223,12 @@ fn blur_uniforms_field_offsets_match_wgsl() {
     )]
     #[test]
     fn blur_uniforms_field_round_trips() {
-        let u = blur::BlurUniforms::new([640.0, 480.0], 4.0, 1.0, [0.1, 0.2, 0.9, 0.8]);
+        let u = blur::BlurUniforms::new([640.0, 480.0], 4.0, 1.0, [0.1, 0.2, 0.9, 0.8], 3.0);
         assert_eq!(u.texture_size, [640.0, 480.0]);
         assert_eq!(u.sigma, 4.0);
         assert_eq!(u.direction, 1.0);
         assert_eq!(u.content_rect_uv, [0.1, 0.2, 0.9, 0.8]);
+        assert_eq!(u.tile_mode, 3.0);
     }
 }
 
@@ -9,6 +9,8 @@
 //! | B3 | GPU | Oracle match ±3 LSB on an opaque-colour content rect |
 //! | B4 | GPU | Zero-sigma identity (ABSOLUTE — not GPU==oracle) |
 //! | B5 | GPU | grown_bounds halo extent: pixels at col=3 or col=57 are non-zero for sigma=4 |
+//! | B13 | GPU | Clamp tile mode: edges stay opaque, nothing spreads past the content rect |
+//! | B14 | GPU | Downsampled large sigma (σ=20) matches the full-resolution oracle ±6 LSB |
 //!
 //! ## Premultiplied-direct invariant (PINNED #2)
 //!
@@ -34,7 +36,7 @@ mod gpu_tests {
     use std::sync::Arc;
 
     use flui_painting::Paint;
-    use flui_types::{Color, Point, Rect, geometry::Pixels};
+    use flui_types::{Color, Point, Rect, geometry::Pixels, painting::TileMode};
     use smallvec::smallvec;
 
     use crate::wgpu::{
@@ -465,6 +467,7 @@ fn blur_half_alpha_no_dark_halo_premul_direct() {
         painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
             sigma_x: SIGMA,
             sigma_y: SIGMA,
+            tile_mode: TileMode::Decal,
         });
         painter.rect(disc_rect, &Paint::fill(half_alpha_white));
         painter.restore_layer();
@@ -559,6 +562,7 @@ fn blur_anisotropic_sigma_x_wide_sigma_y_narrow() {
         painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
             sigma_x: SIGMA_X,
             sigma_y: SIGMA_Y,
+            tile_mode: TileMode::Decal,
         });
         painter.rect(content_rect, &Paint::fill(source_color));
         painter.restore_layer();
@@ -665,6 +669,7 @@ fn blur_oracle_match_within_3_lsb() {
         painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
             sigma_x: SIGMA,
             sigma_y: SIGMA,
+            tile_mode: TileMode::Decal,
         });
         painter.rect(content_rect, &Paint::fill(source_color));
         painter.restore_layer();
@@ -806,6 +811,7 @@ fn blur_zero_sigma_is_identity() {
             painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
                 sigma_x: 0.0,
                 sigma_y: 0.0,
+                tile_mode: TileMode::Decal,
             });
             painter.rect(bounds, &Paint::fill(source_color));
             painter.restore_layer();
@@ -936,6 +942,7 @@ fn blur_grown_bounds_includes_halo_pixels() {
             passes: smallvec![ImageFilterPass::Blur {
                 sigma_x: SIGMA,
                 sigma_y: SIGMA,
+                tile_mode: TileMode::Decal,
             }],
             content_bounds: content_rect,
             grown_bounds: grown_rect,
@@ -1082,6 +1089,7 @@ fn blur_off_origin_halo_centred_on_content() {
         painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
             sigma_x: SIGMA,
             sigma_y: SIGMA,
+            tile_mode: TileMode::Decal,
         });
         painter.rect(content_rect, &Paint::fill(source_color));
         painter.restore_layer();
@@ -1259,6 +1267,7 @@ fn blur_restore_layer_emits_correct_fb_dim() {
         painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
             sigma_x: SIGMA,
             sigma_y: SIGMA,
+            tile_mode: TileMode::Decal,
         });
         // Draw a baked RectInstance that does NOT fill the surface.
         // The baked path (identity M, zero translate) makes bounds trackable via
@@ -1370,6 +1379,7 @@ fn blur_content_aabb_does_not_clip_edge_pixels() {
             painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
                 sigma_x: SIGMA,
                 sigma_y: SIGMA,
+                tile_mode: TileMode::Decal,
             });
             painter.rect(
                 Rect::from_xywh(
@@ -1403,6 +1413,7 @@ fn blur_content_aabb_does_not_clip_edge_pixels() {
         painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
             sigma_x: SIGMA,
             sigma_y: SIGMA,
+            tile_mode: TileMode::Decal,
         });
         // Opaque rect filling [20,20]→[44,44].
         painter.rect(
@@ -1495,6 +1506,7 @@ fn blur_circle_content_aabb_includes_radius() {
             painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
                 sigma_x: SIGMA,
                 sigma_y: SIGMA,
+                tile_mode: TileMode::Decal,
             });
             painter.circle(
                 Point::new(Pixels(CENTER_COL as f32), Pixels(CENTER_ROW as f32)),
@@ -1530,6 +1542,7 @@ fn blur_circle_content_aabb_includes_radius() {
         painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
             sigma_x: SIGMA,
             sigma_y: SIGMA,
+            tile_mode: TileMode::Decal,
         });
         painter.circle(
             Point::new(Pixels(CENTER_COL as f32), Pixels(CENTER_ROW as f32)),
@@ -1626,6 +1639,7 @@ fn blur_gradient_fallback_forces_viewport_fb() {
             painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
                 sigma_x: SIGMA,
                 sigma_y: SIGMA,
+                tile_mode: TileMode::Decal,
             });
             let stops = [
                 GradientStop::new(flui_types::Color::rgba(255, 0, 0, 255), 0.0),
@@ -1676,6 +1690,7 @@ fn blur_gradient_fallback_forces_viewport_fb() {
         painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
             sigma_x: SIGMA,
             sigma_y: SIGMA,
+            tile_mode: TileMode::Decal,
         });
         let stops = [
             GradientStop::new(flui_types::Color::rgba(200, 100, 0, 255), 0.0),
@@ -1741,6 +1756,7 @@ fn blur_rect_only_layer_still_sub_viewport_after_gradient_gate() {
         painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
             sigma_x: SIGMA,
             sigma_y: SIGMA,
+            tile_mode: TileMode::Decal,
         });
         painter.rect(
             Rect::from_xywh(
@@ -1862,6 +1878,7 @@ fn blur_clipped_rect_scissor_rebased_non_identity() {
             painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
                 sigma_x: SIGMA,
                 sigma_y: SIGMA,
+                tile_mode: TileMode::Decal,
             });
             // Apply clip then draw the inset content rect (clip is nested inside).
             painter.clip_rect(Rect::from_xywh(
@@ -1921,6 +1938,7 @@ fn blur_clipped_rect_scissor_rebased_non_identity() {
         painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
             sigma_x: SIGMA,
             sigma_y: SIGMA,
+            tile_mode: TileMode::Decal,
         });
         painter.clip_rect(Rect::from_xywh(
             px(CLIP_LEFT as f32),
@@ -1982,4 +2000,151 @@ fn blur_clipped_rect_scissor_rebased_non_identity() {
              (full-frame scissor against fb attachment clips at the wrong position)."
         );
     }
+
+    // ── B13: Clamp tile mode ─────────────────────────────────────────────────
+
+    /// B13: With `TileMode::Clamp` the kernel reads the edge texel beyond the
+    /// content rect, so an opaque rect stays opaque up to its edge (decal would
+    /// drop the edge alpha to ~50%) and the output does not spread past it.
+    ///
+    /// **Fails if:** the tile mode is not threaded to the shader, the clamp
+    /// folds taps outside the content rect, or the non-decal output mask is
+    /// missing.
+    #[test]
+    fn blur_clamp_tile_mode_keeps_edges_opaque_and_bounded() {
+        const SIGMA: f32 = 4.0;
+        const CONTENT_MARGIN_PX: u32 = 12;
+
+        let (device, queue) = acquire_test_device_and_queue();
+        let (surface_tex, surface_view) = create_surface(&device);
+        clear_surface(&device, &queue, &surface_view, wgpu::Color::TRANSPARENT);
+
+        let mut painter = build_painter(Arc::clone(&device), Arc::clone(&queue));
+        painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
+            sigma_x: SIGMA,
+            sigma_y: SIGMA,
+            tile_mode: TileMode::Clamp,
+        });
+        painter.rect(
+            center_rect(CONTENT_MARGIN_PX),
+            &Paint::fill(Color::rgba(40, 160, 220, 255)),
+        );
+        painter.restore_layer();
+
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        painter
+            .render(
+                RenderTarget::sampleable(&surface_view, &surface_tex),
+                &mut encoder,
+            )
+            .expect("B13 clamp blur render must succeed");
+        queue.submit(std::iter::once(encoder.finish()));
+
+        let pixels = readback_pixels(&device, &queue, &surface_tex);
+        let w = SURFACE_WIDTH as usize;
+        let margin = CONTENT_MARGIN_PX as usize;
+        let mid = SURFACE_HEIGHT as usize / 2;
+
+        let edge = pixels[mid * w + margin];
+        let corner = pixels[margin * w + margin];
+        let outside = pixels[mid * w + margin - 2];
+        assert!(
+            edge[3] >= 250 && corner[3] >= 250,
+            "B13: clamp-mode edge alpha={} corner alpha={} — edges must stay opaque",
+            edge[3],
+            corner[3]
+        );
+        assert_eq!(
+            outside[3], 0,
+            "B13: clamp-mode output must not spread past the content rect"
+        );
+    }
+
+    // ── B14: Downsampled large sigma ─────────────────────────────────────────
+
+    /// B14: σ=20 exceeds `DOWNSAMPLE_SIGMA_THRESHOLD`, so the blur runs at half
+    /// resolution and is upsampled. The result must still match the
+    /// full-resolution CPU oracle within ±6 LSB (decimation error plus 8-bit
+    /// quantisation of the two half-size intermediates).
+    ///
+    /// **Fails if:** the downsampled sigma is not rescaled (blur twice as
+    /// wide), the work-size `texture_size` is wrong, or the upsample pass is
+    /// skipped (quarter-size image in the top-left corner).
+    #[test]
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "test surface dims are 64 px; usize→u32 casts are exact"
+    )]
+    fn blur_large_sigma_downsampled_matches_oracle() {
+        const SIGMA: f32 = 20.0;
+        const CONTENT_MARGIN_PX: u32 = 16;
+
+        assert!(crate::wgpu::blur::downsample_factor(SIGMA) > 1);
+
+        let (device, queue) = acquire_test_device_and_queue();
+        let (surface_tex, surface_view) = create_surface(&device);
+        clear_surface(&device, &queue, &surface_view, wgpu::Color::TRANSPARENT);
+
+        let source_color = Color::rgba(200, 100, 50, 255);
+        let mut painter = build_painter(Arc::clone(&device), Arc::clone(&queue));
+        painter.save_layer_with_image_filter(ImageFilterSpec::Blur {
+            sigma_x: SIGMA,
+            sigma_y: SIGMA,
+            tile_mode: TileMode::Decal,
+        });
+        painter.rect(center_rect(CONTENT_MARGIN_PX), &Paint::fill(source_color));
+        painter.restore_layer();
+
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        painter
+            .render(
+                RenderTarget::sampleable(&surface_view, &surface_tex),
+                &mut encoder,
+            )
+            .expect("B14 large-sigma blur render must succeed");
+        queue.submit(std::iter::once(encoder.finish()));
+        let gpu_pixels = readback_pixels(&device, &queue, &surface_tex);
+
+        let (w, h) = (SURFACE_WIDTH as usize, SURFACE_HEIGHT as usize);
+        let margin = CONTENT_MARGIN_PX as usize;
+        let source_grid: Vec<[u8; 4]> = (0..h)
+            .flat_map(|row| {
+                (0..w).map(move |col| {
+                    let inside =
+                        (margin..w - margin).contains(&col) && (margin..h - margin).contains(&row);
+                    if inside {
+                        [source_color.r, source_color.g, source_color.b, 255]
+                    } else {
+                        [0, 0, 0, 0]
+                    }
+                })
+            })
+            .collect();
+        let oracle = blur_oracle_premul(
+            &source_grid,
+            SURFACE_WIDTH,
+            SURFACE_HEIGHT,
+            SIGMA,
+            SIGMA,
+            (
+                margin as u32,
+                margin as u32,
+                (w - margin) as u32,
+                (h - margin) as u32,
+            ),
+        );
+
+        let max_diff = gpu_pixels
+            .iter()
+            .zip(&oracle)
+            .flat_map(|(gpu, cpu)| gpu.iter().zip(cpu).map(|(a, b)| a.abs_diff(*b)))
+            .max()
+            .unwrap_or(0);
+        assert!(
+            max_diff <= 6,
+            "B14: downsampled σ={SIGMA} blur deviates from the oracle by {max_diff} LSB"
+        );
+    }
 }
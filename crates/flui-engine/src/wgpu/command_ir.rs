@@ -10,7 +10,9 @@
 //! and future batcher/compositor modules can import from one place.
 
 use flui_types::{
-    Rect, geometry::Pixels, painting::BlendMode, painting::TextureId as ExternalTextureId,
+    Rect,
+    geometry::Pixels,
+    painting::{BlendMode, TextureId as ExternalTextureId, TileMode},
 };
 use smallvec::SmallVec;
 
@@ -184,12 +186,15 @@ pub(crate) enum ImageFilterSpec {
     ///
     /// Half-radius = `ceil(sigma × √3)` per [`super::effects::kernel_radius`].
     /// The `grown_bounds` expansion in `restore_layer` uses
-    /// `kernel_radius(max(sigma_x, sigma_y))` as a conservative per-axis pad.
+    /// `kernel_radius(max(sigma_x, sigma_y))` as a conservative per-axis pad
+    /// for [`TileMode::Decal`]; the other tile modes do not grow.
     Blur {
         /// Gaussian sigma for the horizontal sub-pass.
         sigma_x: f32,
         /// Gaussian sigma for the vertical sub-pass.
         sigma_y: f32,
+        /// Edge handling at the content bounds (the layer default is decal).
+        tile_mode: TileMode,
     },
     /// A pre-flattened ordered chain of [`ImageFilterPass`]es produced by
     /// `flatten_compose` in `backend.rs` for `ImageFilter::Compose`.
@@ -251,12 +256,16 @@ pub(crate) enum ImageFilterPass {
     ///
     /// Half-radius = `ceil(sigma × √3)` per [`super::effects::kernel_radius`].
     /// Grows `FilterOp::grown_bounds` by `kernel_radius(max(sigma_x, sigma_y))`
-    /// pixels on each side (conservative per-axis pad).
+    /// pixels on each side (conservative per-axis pad) for
+    /// [`TileMode::Decal`]. Clamp/repeat/mirror output stays within the
+    /// content bounds and grows by 0.
     Blur {
         /// Gaussian sigma for the horizontal sub-pass.
         sigma_x: f32,
         /// Gaussian sigma for the vertical sub-pass.
         sigma_y: f32,
+        /// Edge handling at the content bounds.
+        tile_mode: TileMode,
     },
     /// 5×4 row-major color matrix applied per-pixel on un-premultiplied color.
     ///
@@ -388,6 +397,38 @@ pub(crate) struct TessellatedBatch {
     pub(crate) index_count: u32,
 }
 
+/// Consecutive image draws that share a texture and a scissor, and so can
+/// go out as one instanced batch.
+#[derive(Debug, PartialEq)]
+pub(crate) struct ImageRun<T, I> {
+    pub(crate) texture_id: T,
+    pub(crate) scissor: ScissorRect,
+    pub(crate) instances: Vec<I>,
+}
+
+/// Split recorded `(texture, instance, scissor)` draws into [`ImageRun`]s,
+/// preserving draw order: a new run starts whenever the texture or the clip
+/// changes. Shared by the replay's image flush and
+/// [`RenderStats`](super::render_stats::RenderStats) so both count the same runs.
+pub(crate) fn image_runs<T: PartialEq, I>(
+    images: impl IntoIterator<Item = (T, I, ScissorRect)>,
+) -> Vec<ImageRun<T, I>> {
+    let mut runs: Vec<ImageRun<T, I>> = Vec::new();
+    for (texture_id, instance, scissor) in images {
+        match runs.last_mut() {
+            Some(run) if run.texture_id == texture_id && run.scissor == scissor => {
+                run.instances.push(instance);
+            }
+            _ => runs.push(ImageRun {
+                texture_id,
+                scissor,
+                instances: vec![instance],
+            }),
+        }
+    }
+    runs
+}
+
 // ─── Offscreen / layer snapshots ─────────────────────────────────────────────
 
 /// A pending offscreen texture waiting to be composited into the main render target.
@@ -816,7 +857,7 @@ mod task0_ir_witnesses {
     use flui_types::{Rect, geometry::px};
     use smallvec::smallvec;
 
-    use flui_types::painting::BlendMode;
+    use flui_types::painting::{BlendMode, TileMode};
 
     use super::{
         DrawItem, DrawSegment, FilterOp, GammaDirection, ImageFilterPass, ImageFilterSpec,
@@ -971,12 +1012,14 @@ fn blur_ir_variants_are_constructable() {
         let spec = ImageFilterSpec::Blur {
             sigma_x: 4.0,
             sigma_y: 2.0,
+            tile_mode: TileMode::Decal,
         };
         assert!(matches!(spec, ImageFilterSpec::Blur { .. }));
 
         let pass = ImageFilterPass::Blur {
             sigma_x: 4.0,
             sigma_y: 2.0,
+            tile_mode: TileMode::Decal,
         };
         assert!(matches!(pass, ImageFilterPass::Blur { .. }));
     }
@@ -990,6 +1033,7 @@ fn filter_op_with_blur_pass_is_pure_cpu_data() {
             passes: smallvec![ImageFilterPass::Blur {
                 sigma_x: 4.0,
                 sigma_y: 2.0,
+                tile_mode: TileMode::Decal,
             }],
             content_bounds: bounds,
             grown_bounds: bounds,
@@ -1002,7 +1046,8 @@ fn filter_op_with_blur_pass_is_pure_cpu_data() {
             cloned.passes[0],
             ImageFilterPass::Blur {
                 sigma_x: _,
-                sigma_y: _
+                sigma_y: _,
+                tile_mode: TileMode::Decal,
             }
         ));
     }
@@ -40,7 +40,7 @@ mod gpu_tests {
     use std::sync::Arc;
 
     use flui_painting::Paint;
-    use flui_types::{Color, Rect, geometry::Pixels};
+    use flui_types::{Color, Rect, geometry::Pixels, painting::TileMode};
     use smallvec::smallvec;
 
     use crate::wgpu::{
@@ -351,7 +351,8 @@ fn compose_order_matters_alpha_to_red_then_blur_vs_blur_then_alpha_to_red() {
                 ImageFilterPass::ColorMatrix(alpha_to_red),
                 ImageFilterPass::Blur {
                     sigma_x: BLUR_SIGMA,
-                    sigma_y: BLUR_SIGMA
+                    sigma_y: BLUR_SIGMA,
+                    tile_mode: TileMode::Decal,
                 },
             ]));
             painter.rect(content_rect, &Paint::fill(opaque_white));
@@ -373,7 +374,8 @@ fn compose_order_matters_alpha_to_red_then_blur_vs_blur_then_alpha_to_red() {
             painter.save_layer_with_image_filter(ImageFilterSpec::Chain(smallvec![
                 ImageFilterPass::Blur {
                     sigma_x: BLUR_SIGMA,
-                    sigma_y: BLUR_SIGMA
+                    sigma_y: BLUR_SIGMA,
+                    tile_mode: TileMode::Decal,
                 },
                 ImageFilterPass::ColorMatrix(alpha_to_red),
             ]));
@@ -482,6 +484,7 @@ fn flatten_nested_compose_produces_correct_pass_sequence() {
         let blur_pass = ImageFilterPass::Blur {
             sigma_x: sigma_blur,
             sigma_y: sigma_blur,
+            tile_mode: TileMode::Decal,
         };
         let dilate_pass = ImageFilterPass::Morph {
             radius: dilate_radius,
@@ -492,6 +495,7 @@ fn flatten_nested_compose_produces_correct_pass_sequence() {
         let blur_filter = ImageFilter::Blur {
             sigma_x: sigma_blur,
             sigma_y: sigma_blur,
+            tile_mode: None,
         };
         let dilate_filter = ImageFilter::Dilate {
             radius: dilate_radius,
@@ -679,11 +683,13 @@ fn cumulative_growth_sums_blur_radii_and_ignores_color_matrix() {
             ImageFilterPass::Blur {
                 sigma_x: SIGMA,
                 sigma_y: SIGMA,
+                tile_mode: TileMode::Decal,
             },
             ImageFilterPass::ColorMatrix(identity_matrix()),
             ImageFilterPass::Blur {
                 sigma_x: SIGMA,
                 sigma_y: SIGMA,
+                tile_mode: TileMode::Decal,
             },
         ];
         let growth_two_blurs =
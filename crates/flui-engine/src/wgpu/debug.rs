@@ -316,7 +316,7 @@ fn render_vertices(
         vertices: &[Point<Pixels>],
         _colors: Option<&[Color]>,
         _tex_coords: Option<&[Point<Pixels>]>,
-        indices: &[u16],
+        indices: &[u32],
         _paint: &Paint,
         _transform: &Matrix4,
     ) {
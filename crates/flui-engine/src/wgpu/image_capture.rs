@@ -0,0 +1,273 @@
+//! GPU texture → CPU [`Image`] readback for [`Renderer::render_to_image`].
+//!
+//! [`Renderer::render_to_image`]: super::Renderer::render_to_image
+//!
+//! The renderer's pipelines are built for the painter's surface format, so a
+//! captured frame is rendered in that format and converted here:
+//!
+//! - **Row padding** — `copy_texture_to_buffer` requires `bytes_per_row` to be
+//!   a multiple of [`wgpu::COPY_BYTES_PER_ROW_ALIGNMENT`]; the padding is
+//!   stripped while decoding.
+//! - **Channel order / encoding** — BGRA formats are swizzled, `Rgba16Float`
+//!   (the HDR surface format) is clamped to `[0, 1]` and quantised. `*Srgb`
+//!   formats store encoded bytes and are copied verbatim.
+//! - **Alpha** — the painter writes premultiplied color; [`Image`] holds
+//!   straight RGBA, so every texel is unpremultiplied.
+//!
+//! The buffer map is awaited by blocking on `device.poll`, matching the
+//! synchronous readbacks elsewhere in the engine.
+
+use flui_types::painting::Image;
+
+use crate::error::{EngineError, EngineResult};
+
+/// Why [`Renderer::render_to_image`](super::Renderer::render_to_image) could
+/// not produce pixels. Carried as the `#[source]` of
+/// [`EngineError::Readback`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ImageCaptureError {
+    /// The render format has no RGBA8 conversion.
+    #[error("cannot read back texture format {0:?}")]
+    UnsupportedFormat(wgpu::TextureFormat),
+
+    /// The requested image exceeds the device's maximum texture size.
+    #[error("capture size {width}x{height} exceeds the device texture limit {max}")]
+    TooLarge {
+        /// Requested width in pixels.
+        width: u32,
+        /// Requested height in pixels.
+        height: u32,
+        /// `max_texture_dimension_2d` of the device.
+        max: u32,
+    },
+
+    /// Waiting for the readback copy failed.
+    #[error("device poll failed during readback: {0}")]
+    Poll(#[source] wgpu::PollError),
+
+    /// Mapping the readback buffer failed.
+    #[error("readback buffer map failed: {0}")]
+    Map(#[source] wgpu::BufferAsyncError),
+}
+
+impl From<ImageCaptureError> for EngineError {
+    fn from(error: ImageCaptureError) -> Self {
+        EngineError::readback(error)
+    }
+}
+
+/// Bytes per texel of the formats [`decode_texel`] understands.
+fn bytes_per_texel(format: wgpu::TextureFormat) -> Option<u32> {
+    match format {
+        wgpu::TextureFormat::Rgba8Unorm
+        | wgpu::TextureFormat::Rgba8UnormSrgb
+        | wgpu::TextureFormat::Bgra8Unorm
+        | wgpu::TextureFormat::Bgra8UnormSrgb => Some(4),
+        wgpu::TextureFormat::Rgba16Float => Some(8),
+        _ => None,
+    }
+}
+
+/// Copies `texture` to the CPU and returns it as a straight-alpha RGBA8
+/// [`Image`], blocking until the GPU copy completes.
+pub(crate) fn read_texture_to_image(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+) -> EngineResult<Image> {
+    let format = texture.format();
+    let texel_size = bytes_per_texel(format).ok_or(ImageCaptureError::UnsupportedFormat(format))?;
+    let (width, height) = (texture.width(), texture.height());
+    let unpadded_bytes_per_row = width * texel_size;
+    let bytes_per_row = unpadded_bytes_per_row.div_ceil(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT)
+        * wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+
+    let staging = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("FLUI Image Capture Readback"),
+        size: u64::from(bytes_per_row) * u64::from(height),
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("FLUI Image Capture Encoder"),
+    });
+    encoder.copy_texture_to_buffer(
+        wgpu::TexelCopyTextureInfo {
+            texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        wgpu::TexelCopyBufferInfo {
+            buffer: &staging,
+            layout: wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(bytes_per_row),
+                rows_per_image: Some(height),
+            },
+        },
+        wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+    );
+    let submission = queue.submit(std::iter::once(encoder.finish()));
+
+    let slice = staging.slice(..);
+    let (sender, receiver) = std::sync::mpsc::channel();
+    slice.map_async(wgpu::MapMode::Read, move |result| {
+        // The receiver outlives the blocking poll below; a send failure
+        // means the capture was abandoned and the result is moot.
+        let _ = sender.send(result);
+    });
+    device
+        .poll(wgpu::PollType::Wait {
+            submission_index: Some(submission),
+            timeout: None,
+        })
+        .map_err(ImageCaptureError::Poll)?;
+    receiver
+        .recv()
+        .map_err(|_| ImageCaptureError::Map(wgpu::BufferAsyncError))?
+        .map_err(ImageCaptureError::Map)?;
+
+    let rgba = {
+        let mapped = slice.get_mapped_range();
+        decode_rows(
+            &mapped,
+            format,
+            bytes_per_row as usize,
+            unpadded_bytes_per_row as usize,
+        )
+    };
+    staging.unmap();
+
+    Ok(Image::from_rgba8(width, height, rgba))
+}
+
+/// Strips row padding and converts every texel to straight-alpha RGBA8.
+fn decode_rows(
+    data: &[u8],
+    format: wgpu::TextureFormat,
+    padded_bytes_per_row: usize,
+    unpadded_bytes_per_row: usize,
+) -> Vec<u8> {
+    let texel_size = bytes_per_texel(format).map_or(4, |size| size as usize);
+    let mut rgba = Vec::with_capacity(data.len() / padded_bytes_per_row * unpadded_bytes_per_row);
+    for row in data.chunks_exact(padded_bytes_per_row) {
+        for texel in row[..unpadded_bytes_per_row].chunks_exact(texel_size) {
+            rgba.extend_from_slice(&unpremultiply(decode_texel(format, texel)));
+        }
+    }
+    rgba
+}
+
+/// Converts one texel of `format` to premultiplied RGBA8.
+fn decode_texel(format: wgpu::TextureFormat, texel: &[u8]) -> [u8; 4] {
+    match format {
+        wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => {
+            [texel[2], texel[1], texel[0], texel[3]]
+        }
+        wgpu::TextureFormat::Rgba16Float => {
+            let channel = |i: usize| {
+                let value = f16_to_f32(u16::from_le_bytes([texel[2 * i], texel[2 * i + 1]]));
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_sign_loss,
+                    reason = "clamped to [0,1]*255 then rounded"
+                )]
+                let byte = (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+                byte
+            };
+            [channel(0), channel(1), channel(2), channel(3)]
+        }
+        _ => [texel[0], texel[1], texel[2], texel[3]],
+    }
+}
+
+/// Premultiplied → straight alpha; fully transparent texels become zero.
+fn unpremultiply([r, g, b, a]: [u8; 4]) -> [u8; 4] {
+    match a {
+        0 => [0, 0, 0, 0],
+        255 => [r, g, b, a],
+        _ => {
+            let alpha = u32::from(a);
+            #[allow(
+                clippy::cast_possible_truncation,
+                reason = "min(255) keeps the value in u8 range"
+            )]
+            let channel = |c: u8| ((u32::from(c) * 255 + alpha / 2) / alpha).min(255) as u8;
+            [channel(r), channel(g), channel(b), a]
+        }
+    }
+}
+
+/// IEEE 754 binary16 → binary32.
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = if bits & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exponent = i32::from((bits >> 10) & 0x1f);
+    let mantissa = f32::from(bits & 0x03ff);
+    match exponent {
+        0 => sign * mantissa * 2f32.powi(-24),
+        0x1f if mantissa == 0.0 => sign * f32::INFINITY,
+        0x1f => f32::NAN,
+        _ => sign * (1.0 + mantissa / 1024.0) * 2f32.powi(exponent - 15),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_rows, decode_texel, f16_to_f32, unpremultiply};
+
+    #[test]
+    fn unpremultiply_restores_straight_color() {
+        assert_eq!(unpremultiply([128, 0, 64, 128]), [255, 0, 128, 128]);
+        assert_eq!(unpremultiply([10, 20, 30, 255]), [10, 20, 30, 255]);
+        assert_eq!(unpremultiply([7, 7, 7, 0]), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn bgra_texels_are_swizzled() {
+        assert_eq!(
+            decode_texel(wgpu::TextureFormat::Bgra8Unorm, &[1, 2, 3, 4]),
+            [3, 2, 1, 4]
+        );
+        assert_eq!(
+            decode_texel(wgpu::TextureFormat::Rgba8Unorm, &[1, 2, 3, 4]),
+            [1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn rgba16_float_texels_are_clamped_and_quantised() {
+        assert!((f16_to_f32(0x3c00) - 1.0).abs() < f32::EPSILON);
+        assert!((f16_to_f32(0x3800) - 0.5).abs() < f32::EPSILON);
+        assert!((f16_to_f32(0xc000) + 2.0).abs() < f32::EPSILON);
+
+        // (2.0, 0.5, -2.0, 1.0): over-range and negative values clamp.
+        let texel = [0x00, 0x40, 0x00, 0x38, 0x00, 0xc0, 0x00, 0x3c];
+        assert_eq!(
+            decode_texel(wgpu::TextureFormat::Rgba16Float, &texel),
+            [255, 128, 0, 255]
+        );
+    }
+
+    #[test]
+    fn row_padding_is_stripped() {
+        // 2x2 RGBA8 texture with rows padded to 12 bytes.
+        let data = [
+            1, 1, 1, 255, 2, 2, 2, 255, 0xee, 0xee, 0xee, 0xee, //
+            3, 3, 3, 255, 4, 4, 4, 255, 0xee, 0xee, 0xee, 0xee,
+        ];
+        let rgba = decode_rows(&data, wgpu::TextureFormat::Rgba8Unorm, 12, 8);
+        assert_eq!(
+            rgba,
+            [
+                1, 1, 1, 255, 2, 2, 2, 255, //
+                3, 3, 3, 255, 4, 4, 4, 255,
+            ]
+        );
+    }
+}
@@ -7,7 +7,7 @@
     BackdropFilterLayer, CanvasLayer, ClipPathLayer, ClipRRectLayer, ClipRectLayer,
     ColorFilterLayer, FollowerLayer, ImageFilterLayer, Layer, LeaderLayer, OffsetLayer,
     OpacityLayer, PerformanceOverlayLayer, PictureLayer, PlatformViewLayer, ShaderMaskLayer,
-    TextureLayer, TransformLayer,
+    TextureFormat, TextureLayer, TransformLayer,
 };
 use flui_painting::DisplayListCore;
 
@@ -375,14 +375,29 @@ fn render(&self, renderer: &mut R) {
         if self.is_invisible() {
             return;
         }
-        renderer.render_texture(
-            self.texture_id(),
-            self.rect(),
-            None,
-            self.filter_quality(),
-            self.opacity(),
-            &flui_types::geometry::Matrix4::IDENTITY,
-        );
+        match self.format() {
+            // Producers register these as `Rgba8*` / `Bgra8*` wgpu textures,
+            // which the RGBA texture shader samples directly; the hardware
+            // swizzles BGRA on read.
+            TextureFormat::Rgba8 | TextureFormat::Bgra8 => renderer.render_texture(
+                self.texture_id(),
+                self.rect(),
+                None,
+                self.filter_quality(),
+                self.opacity(),
+                &flui_types::geometry::Matrix4::IDENTITY,
+            ),
+            // No YUV → RGB sampling pipeline yet. `TextureLayer::try_new`
+            // rejects these formats, so only a layer built around that check
+            // gets here.
+            format @ (TextureFormat::Nv12 | TextureFormat::I420) => {
+                tracing::warn!(
+                    texture_id = self.texture_id().get(),
+                    ?format,
+                    "TextureLayer: no sampling path for this format — skipped"
+                );
+            }
+        }
     }
 
     fn cleanup(&self, _renderer: &mut R) {
@@ -610,6 +625,7 @@ fn render_texture(
             _opacity: f32,
             _transform: &Matrix4,
         ) {
+            self.calls.push("render_texture".to_string());
         }
 
         // ===== Effects (no-ops) =====
@@ -664,7 +680,7 @@ fn render_vertices(
             _vertices: &[Point<Pixels>],
             _colors: Option<&[Color]>,
             _tex_coords: Option<&[Point<Pixels>]>,
-            _indices: &[u16],
+            _indices: &[u32],
             _paint: &Paint,
             _transform: &Matrix4,
         ) {
@@ -803,6 +819,23 @@ fn test_offset_layer_zero_is_noop() {
         assert!(renderer.calls.is_empty(), "zero offset should not pop");
     }
 
+    // ========================================================================
+    // TextureLayer tests
+    // ========================================================================
+
+    #[test]
+    fn test_texture_layer_samples_rgba_and_bgra_frames() {
+        let rect = Rect::from_xywh(px(0.0), px(0.0), px(64.0), px(36.0));
+        for format in [TextureFormat::Rgba8, TextureFormat::Bgra8] {
+            let mut renderer = MockRenderer::new();
+            let layer =
+                TextureLayer::try_new(TextureId::new(1), rect, format).expect("supported format");
+
+            layer.render(&mut renderer);
+            assert_eq!(renderer.calls, vec!["render_texture"], "{format:?}");
+        }
+    }
+
     // ========================================================================
     // TransformLayer tests
     // ========================================================================
@@ -118,6 +118,9 @@
 mod effects_pipeline;
 mod external_texture_registry;
 pub mod font_loader;
+/// Texture → CPU `Image` readback behind `Renderer::render_to_image`: row
+/// padding, BGRA / `Rgba16Float` conversion, and unpremultiplication.
+mod image_capture;
 /// GPU instance-buffer types: `RectInstance`, `CircleInstance`,
 /// `ArcInstance`, `TextureInstance`, gradient instances. All
 /// surviving items are consumed by `painter`. A cleanup pass
@@ -159,6 +162,9 @@
 pub(super) mod opacity_layer;
 pub(crate) mod pipelines;
 mod profiler;
+/// Per-frame draw statistics (`RenderStats`) derived from the command IR
+/// before replay: draw calls, buffer-upload batches, and vertices.
+mod render_stats;
 /// Frame render-target descriptor: `view` + optional back-reference `texture`
 /// for dst-read blend passes.  Frame-scoped borrow, never stored in IR types.
 pub(crate) mod render_target;
@@ -194,6 +200,9 @@
 /// field.
 pub(super) mod state_stack;
 pub mod superellipse_cache;
+/// Swapchain-acquire retry policy: reconfigure-and-retry once per frame,
+/// escalate persistent surface failures to device loss.
+mod surface_recovery;
 mod tessellator;
 mod text;
 pub mod texture_cache;
@@ -337,6 +346,13 @@
 // GPU frame profile — feature-independent type, always available so callers
 // can store/display profiling results without gating on `gpu-profiler`.
 pub use profiler::{GpuFrameProfile, PassTiming};
+// Draw statistics — CPU-side, read back via `WgpuPainter::render_stats` /
+// `Renderer::latest_render_stats`.
+pub use render_stats::RenderStats;
+// Glyph atlas occupancy — read back via `WgpuPainter::glyph_atlas_stats`.
+pub use text::GlyphAtlasStats;
+// Source error of `EngineError::Readback` from `Renderer::render_to_image`.
+pub use image_capture::ImageCaptureError;
 
 // Offscreen renderer + texture pool — re-exported ONLY under the
 // `enable-wgpu-tests` feature for the `offscreen_resource_cache` criterion bench.
@@ -1150,14 +1150,20 @@ pub(in crate::wgpu) fn apply_image_filter_passes(
                 // the prior intermediate to the pool.
             }
 
-            ImageFilterPass::Blur { sigma_x, sigma_y } => {
+            ImageFilterPass::Blur {
+                sigma_x,
+                sigma_y,
+                tile_mode,
+            } => {
                 // Two separable sub-passes (H then V) inside apply_blur.
-                // The H pass decals at `content_bounds` rebased to fb-local UV
-                // (non-negotiable #3): samples outside contribute transparent black.
-                // The V pass decals at the texture edge [0,1] to read the full H halo.
+                // Decal: the H pass decals at `content_bounds` rebased to fb-local
+                // UV (non-negotiable #3): samples outside contribute transparent
+                // black. The V pass decals at the texture edge [0,1] to read the
+                // full H halo. Other tile modes fold taps into `content_bounds`.
                 apply_blur(
                     *sigma_x,
                     *sigma_y,
+                    *tile_mode,
                     &acc,
                     content_bounds,
                     fb_origin,
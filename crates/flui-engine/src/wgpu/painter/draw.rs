@@ -480,7 +480,7 @@ pub fn draw_vertices(
         vertices: &[flui_types::Point<flui_types::geometry::Pixels>],
         colors: Option<&[flui_types::styling::Color]>,
         tex_coords: Option<&[flui_types::Point<flui_types::geometry::Pixels>]>,
-        indices: &[u16],
+        indices: &[u32],
         paint: &flui_painting::Paint,
     ) {
         super::super::batches::DrawBatcher::draw_vertices(
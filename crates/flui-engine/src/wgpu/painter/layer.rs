@@ -637,10 +637,15 @@ pub fn restore_layer(&mut self) {
                             fb_dim,
                         }));
                     }
-                    Some(ImageFilterSpec::Blur { sigma_x, sigma_y }) => {
+                    Some(ImageFilterSpec::Blur {
+                        sigma_x,
+                        sigma_y,
+                        tile_mode,
+                    }) => {
                         // Gaussian blur via two H/V sub-passes (separable, anisotropic).
                         // Identical seam to Morph: grow by kernel_radius(max(σx,σy))
-                        // on each side, clip to viewport, emit DrawItem::Filter.
+                        // on each side (decal only), clip to viewport, emit
+                        // DrawItem::Filter.
                         //
                         // Growth via the shared `cumulative_growth` helper (one source
                         // of truth for Blur; `kernel_radius` uses Impeller's √3·σ rule).
@@ -662,7 +667,11 @@ pub fn restore_layer(&mut self) {
                                 .unwrap_or(vp)
                         };
 
-                        let single_pass = ImageFilterPass::Blur { sigma_x, sigma_y };
+                        let single_pass = ImageFilterPass::Blur {
+                            sigma_x,
+                            sigma_y,
+                            tile_mode,
+                        };
                         let halo_px =
                             px(super::cumulative_growth(std::slice::from_ref(&single_pass)));
                         let grown = composite_bounds.expand(halo_px);
@@ -676,6 +685,7 @@ pub fn restore_layer(&mut self) {
                         tracing::trace!(
                             sigma_x,
                             sigma_y,
+                            ?tile_mode,
                             content_bounds = ?composite_bounds,
                             grown_bounds = ?grown_bounds,
                             fb_origin = ?fb_origin,
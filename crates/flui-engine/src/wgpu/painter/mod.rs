@@ -19,7 +19,7 @@
     state_stack::GpuStateStack,
     text::TextRenderer,
 };
-use flui_types::{Rect, geometry::Pixels};
+use flui_types::{Rect, geometry::Pixels, painting::TileMode};
 
 /// GPU painter for wgpu-based rendering.
 ///
@@ -89,6 +89,11 @@ pub struct WgpuPainter {
 
     /// Ordered list of completed draw items (segments and offscreen textures)
     draw_order: Vec<DrawItem>,
+
+    /// Draw statistics accumulated by every `render` pass since the last
+    /// `reset_frame_state` (backdrop-filter flushes render more than once
+    /// per frame).
+    render_stats: super::RenderStats,
 }
 
 // GPU rendering routinely converts between numeric types for pixel coordinates,
@@ -165,6 +170,7 @@ pub fn with_shared_device(
             compositor: LayerCompositor::new(),
             current_segment: DrawSegment::new(),
             draw_order: Vec::new(),
+            render_stats: super::RenderStats::default(),
         }
     }
 
@@ -215,10 +221,25 @@ pub fn reset_frame_state(&mut self) {
 
         self.state.reset();
         self.compositor.reset();
+        self.render_stats = super::RenderStats::default();
 
         tracing::trace!("WgpuPainter::reset_frame_state: per-frame state cleared");
     }
 
+    /// Draw statistics for the work rendered since the last
+    /// [`reset_frame_state`](Self::reset_frame_state) — i.e. the current (or,
+    /// between frames, the most recent) frame.
+    #[must_use]
+    pub fn render_stats(&self) -> super::RenderStats {
+        self.render_stats
+    }
+
+    /// Glyph-atlas page occupancy of the text renderer.
+    #[must_use]
+    pub fn glyph_atlas_stats(&self) -> super::GlyphAtlasStats {
+        self.text_renderer.atlas_stats()
+    }
+
     /// Returns the current scissor rect for testing purposes.
     ///
     /// Gated to match its sole consumer (`reset_frame_state_clears_damage_scissor`)
@@ -551,6 +572,15 @@ pub(crate) fn render(
         self.finish_current_segment();
         let items: Vec<DrawItem> = self.draw_order.drain(..).collect();
 
+        let stats = super::RenderStats::from_items(&items);
+        tracing::trace!(
+            draw_calls = stats.draw_calls,
+            batches = stats.batches,
+            vertices = stats.vertices,
+            "Render stats"
+        );
+        self.render_stats += stats;
+
         // Dispatch all items + text via GpuReplay::submit.
         // text_renderer.render is the final phase inside submit.
         self.replay.submit(
@@ -807,7 +837,8 @@ pub fn flush_texture_batch(
 /// ## Formulas
 ///
 /// - [`ImageFilterPass::Blur`] → `kernel_radius(max(sigma_x, sigma_y)) as f32`
-///   (the conservative per-axis pad used by the standalone Blur arm, PINNED #2).
+///   (the conservative per-axis pad used by the standalone Blur arm, PINNED #2)
+///   for decal; `0.0` for clamp/repeat/mirror, whose output stays in bounds.
 /// - [`ImageFilterPass::Morph`] → `radius.ceil()` (pixel expansion per `restore_layer`).
 /// - [`ImageFilterPass::ColorMatrix`] → `0.0` (full-viewport REPLACE, no growth).
 /// - [`ImageFilterPass::Identity`] → `0.0` (passthrough, no growth).
@@ -820,12 +851,17 @@ pub(super) fn cumulative_growth(passes: &[ImageFilterPass]) -> f32 {
     passes
         .iter()
         .map(|pass| match pass {
-            ImageFilterPass::Blur { sigma_x, sigma_y } => {
-                super::effects::kernel_radius(sigma_x.max(*sigma_y)) as f32
-            }
+            ImageFilterPass::Blur {
+                sigma_x,
+                sigma_y,
+                tile_mode: TileMode::Decal,
+            } => super::effects::kernel_radius(sigma_x.max(*sigma_y)) as f32,
             ImageFilterPass::Morph { radius, .. } => radius.ceil(),
-            // Both are bounds-PRESERVING: neither grows the filter extent.
-            ImageFilterPass::ColorMatrix(_) | ImageFilterPass::Identity => 0.0,
+            // Bounds-PRESERVING: none grows the filter extent (a non-decal
+            // blur's output is limited to the content bounds).
+            ImageFilterPass::Blur { .. }
+            | ImageFilterPass::ColorMatrix(_)
+            | ImageFilterPass::Identity => 0.0,
         })
         .sum()
 }
@@ -0,0 +1,242 @@
+//! Per-frame draw statistics derived from the recorded command IR.
+//!
+//! [`RenderStats`] reports how much GPU work a frame's draw order turns into:
+//! how many buffer-upload groups (batches) the replay builds, how many
+//! `draw_indexed` calls it issues for them, and how many vertices those
+//! calls submit. The numbers are computed from the [`DrawItem`] list right
+//! before `GpuReplay::submit`, mirroring the replay's grouping rules, so
+//! they are available — and unit-testable — without a GPU.
+//!
+//! # Batching rules mirrored here
+//!
+//! - Instanced primitives (shadow / rect / circle / arc) share one combined
+//!   instance buffer per segment; each scissor region of each kind is one
+//!   draw (shadows are always one draw).
+//! - Gradients (linear / radial / sweep) likewise share one buffer per
+//!   segment with one draw per scissor region.
+//! - Tessellated geometry shares one vertex/index buffer per segment; each
+//!   [`TessellatedBatch`](super::command_ir::TessellatedBatch) — a run with
+//!   the same pipeline key (blend mode) and scissor — is one draw.
+//! - Cached images batch consecutive draws of the same texture under the
+//!   same scissor; external textures draw one instance at a time.
+//! - Isolated items (offscreen textures, opacity layers, advanced-blend
+//!   shapes, SSAA paths, image filters) count their nested content plus one
+//!   composite draw; filter passes count one draw each.
+//!
+//! Counts are predictions of the replay, taken before scissor culling: a
+//! region whose clip clamps to zero area still counts as a draw.
+
+use std::ops::{Add, AddAssign};
+
+use super::command_ir::{DrawItem, DrawSegment, image_runs};
+
+/// Vertices per instanced quad (the shared unit quad).
+const QUAD_VERTICES: usize = 4;
+
+/// Draw statistics for the work submitted by
+/// [`WgpuPainter`](super::WgpuPainter) since the last
+/// [`reset_frame_state`](super::WgpuPainter::reset_frame_state).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RenderStats {
+    /// `draw_indexed` calls the replay issues.
+    pub draw_calls: usize,
+    /// Buffer-upload groups: each shares one vertex/instance buffer and is
+    /// drawn by one or more draw calls that differ only in pipeline or clip.
+    pub batches: usize,
+    /// Vertices submitted — tessellated vertices plus four per instanced or
+    /// textured quad.
+    pub vertices: usize,
+}
+
+impl RenderStats {
+    /// One textured full-quad composite of an isolated item.
+    const COMPOSITE: Self = Self {
+        draw_calls: 1,
+        batches: 1,
+        vertices: QUAD_VERTICES,
+    };
+
+    /// Statistics for replaying `items` in order.
+    pub(crate) fn from_items(items: &[DrawItem]) -> Self {
+        items
+            .iter()
+            .map(Self::from_item)
+            .fold(Self::default(), Add::add)
+    }
+
+    fn from_item(item: &DrawItem) -> Self {
+        match item {
+            DrawItem::Segment(segment) => Self::from_segment(segment),
+            DrawItem::OffscreenTexture(_) => Self::COMPOSITE,
+            DrawItem::OpacityLayer(layer) => {
+                Self::from_items(&layer.items)
+                    + Self::from_segment(&layer.final_segment)
+                    + Self::passes(layer.filters.len())
+                    + Self::COMPOSITE
+            }
+            DrawItem::AdvancedShape(op) => Self::from_segment(&op.segment) + Self::COMPOSITE,
+            DrawItem::SsaaPath(op) => Self::from_segment(&op.segment) + Self::COMPOSITE,
+            DrawItem::Filter(op) => {
+                Self::from_segment(&op.input) + Self::passes(op.passes.len()) + Self::COMPOSITE
+            }
+        }
+    }
+
+    /// Statistics for flushing one segment through the five replay phases.
+    pub(crate) fn from_segment(segment: &DrawSegment) -> Self {
+        let mut stats = Self::default();
+
+        // Phase 1: instanced primitives, one combined buffer.
+        let instances = segment.shadow_batch.len()
+            + segment.rect_batch.len()
+            + segment.circle_batch.len()
+            + segment.arc_batch.len();
+        if instances > 0 {
+            stats.batches += 1;
+            stats.draw_calls += usize::from(!segment.shadow_batch.is_empty())
+                + segment.rect_scissors.len()
+                + segment.circle_scissors.len()
+                + segment.arc_scissors.len();
+            stats.vertices += instances * QUAD_VERTICES;
+        }
+
+        // Phase 2: gradients, one combined buffer.
+        let gradients = segment.linear_gradient_batch.len()
+            + segment.radial_gradient_batch.len()
+            + segment.sweep_gradient_batch.len();
+        if gradients > 0 {
+            stats.batches += 1;
+            stats.draw_calls += segment.linear_grad_scissors.len()
+                + segment.radial_grad_scissors.len()
+                + segment.sweep_grad_scissors.len();
+            stats.vertices += gradients * QUAD_VERTICES;
+        }
+
+        // Phase 3: tessellated geometry, one vertex/index buffer.
+        if !segment.vertices.is_empty() && !segment.tess_batches.is_empty() {
+            stats.batches += 1;
+            stats.draw_calls += segment.tess_batches.len();
+            stats.vertices += segment.vertices.len();
+        }
+
+        // Phase 4: cached images, one batch per (texture, scissor) run.
+        let image_runs = image_runs(
+            segment
+                .cached_images
+                .iter()
+                .map(|(texture, _, scissor)| (texture, (), *scissor)),
+        )
+        .len();
+        stats.batches += image_runs;
+        stats.draw_calls += image_runs;
+        stats.vertices += segment.cached_images.len() * QUAD_VERTICES;
+
+        // Phase 5: external textures, one draw each.
+        let external = segment.external_images.len();
+        stats.batches += external;
+        stats.draw_calls += external;
+        stats.vertices += external * QUAD_VERTICES;
+
+        stats
+    }
+
+    /// `count` full-quad filter passes.
+    fn passes(count: usize) -> Self {
+        Self {
+            draw_calls: count,
+            batches: count,
+            vertices: count * QUAD_VERTICES,
+        }
+    }
+}
+
+impl Add for RenderStats {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            draw_calls: self.draw_calls + rhs.draw_calls,
+            batches: self.batches + rhs.batches,
+            vertices: self.vertices + rhs.vertices,
+        }
+    }
+}
+
+impl AddAssign for RenderStats {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use flui_types::{Color, Rect, geometry::px};
+
+    use super::super::{
+        command_ir::DrawSegment,
+        instancing::{RectInstance, TextureInstance},
+        texture_cache::TextureId,
+    };
+    use super::RenderStats;
+
+    fn unit_rect() -> RectInstance {
+        RectInstance::rect(
+            Rect::from_ltrb(px(0.0), px(0.0), px(10.0), px(10.0)),
+            Color::rgba(255, 0, 0, 255),
+        )
+    }
+
+    fn image_instance() -> TextureInstance {
+        TextureInstance::new(
+            Rect::from_ltrb(px(0.0), px(0.0), px(10.0), px(10.0)),
+            Color::rgba(255, 255, 255, 255),
+        )
+    }
+
+    #[test]
+    fn empty_segment_has_no_work() {
+        assert_eq!(
+            RenderStats::from_segment(&DrawSegment::new()),
+            RenderStats::default()
+        );
+    }
+
+    #[test]
+    fn rects_under_one_clip_are_one_draw_and_a_clip_change_adds_one() {
+        let mut segment = DrawSegment::new();
+        for scissor in [None, None, None, Some((0, 0, 5, 5)), None] {
+            let _ = segment.rect_batch.add(unit_rect());
+            DrawSegment::push_scissor_region(&mut segment.rect_scissors, scissor);
+        }
+
+        assert_eq!(
+            RenderStats::from_segment(&segment),
+            RenderStats {
+                draw_calls: 3,
+                batches: 1,
+                vertices: 5 * 4,
+            },
+            "None×3 | clip | None is three scissor runs over one instance buffer",
+        );
+    }
+
+    #[test]
+    fn cached_images_break_on_texture_or_clip_change() {
+        let mut segment = DrawSegment::new();
+        let a = TextureId::Named("a".into());
+        let b = TextureId::Named("b".into());
+        let clip = Some((0, 0, 5, 5));
+        segment.cached_images = vec![
+            (a.clone(), image_instance(), None),
+            (a.clone(), image_instance(), None),
+            (b, image_instance(), None),
+            (a.clone(), image_instance(), None),
+            (a, image_instance(), clip),
+        ];
+
+        let stats = RenderStats::from_segment(&segment);
+        assert_eq!(stats.draw_calls, 4, "a a | b | a | a(clipped)");
+        assert_eq!(stats.batches, 4);
+        assert_eq!(stats.vertices, 5 * 4);
+    }
+}
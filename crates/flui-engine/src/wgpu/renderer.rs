@@ -36,6 +36,7 @@
 
 use wgpu;
 
+use super::surface_recovery::{AcquireOutcome, SurfaceRecovery};
 use crate::error::{EngineError, EngineResult};
 
 /// GPU backend capabilities
@@ -252,6 +253,11 @@ pub struct Renderer {
     /// re-record or a precomputed `Scene` bit would be the upgrade path once
     /// partial damage becomes hot.
     force_full_repaint_next_frame: bool,
+
+    /// Swapchain-acquire retry state: counts consecutive failed acquires so
+    /// a surface that never recovers escalates to device loss instead of
+    /// dropping frames forever.
+    surface_recovery: SurfaceRecovery,
 }
 
 // SAFETY: `Renderer` stores `Option<RawWindowHandle>` and
@@ -339,6 +345,7 @@ pub async fn new<W>(window: &W) -> EngineResult<Self>
             #[cfg(test)]
             force_intermediate: false,
             force_full_repaint_next_frame: false,
+            surface_recovery: SurfaceRecovery::default(),
         })
     }
 
@@ -595,6 +602,7 @@ pub async fn new_offscreen() -> EngineResult<Self> {
             #[cfg(test)]
             force_intermediate: false,
             force_full_repaint_next_frame: false,
+            surface_recovery: SurfaceRecovery::default(),
         })
     }
 
@@ -653,8 +661,9 @@ pub(crate) fn force_intermediate_for_testing(&mut self) {
     /// without a separate resize call.
     ///
     /// On the **offscreen** path (`raw_window_handle` is `None`) only the
-    /// device/queue are replaced; surface, painter, and offscreen are left as
-    /// `None`.
+    /// device/queue are replaced; surface, painter, and offscreen are reset to
+    /// `None` (a painter built by `render_to_image` belongs to the lost device
+    /// and is rebuilt on the next capture).
     ///
     /// On success the device-lost flag is cleared (the fresh device starts
     /// healthy). On failure the underlying [`EngineError`] is returned — the
@@ -739,8 +748,13 @@ pub async fn recover(&mut self) -> EngineResult<()> {
             self.queue = Arc::new(queue);
             self.capabilities = capabilities;
             self.device_lost = fresh_flag;
+            self.painter = None;
+            self.offscreen = None;
         }
 
+        // The rebuilt surface starts with a clean retry budget.
+        self.surface_recovery = SurfaceRecovery::default();
+
         tracing::info!(
             width = self.config.as_ref().map_or(0, |c| c.width),
             height = self.config.as_ref().map_or(0, |c| c.height),
@@ -1041,6 +1055,16 @@ pub fn latest_gpu_frame_profile(&self) -> Option<&super::profiler::GpuFrameProfi
         }
     }
 
+    /// Draw statistics for the most recently rendered frame (draw calls,
+    /// batches, vertices). Zeroed until the first frame renders.
+    #[must_use]
+    pub fn latest_render_stats(&self) -> super::RenderStats {
+        self.painter
+            .as_ref()
+            .map(super::WgpuPainter::render_stats)
+            .unwrap_or_default()
+    }
+
     /// Get current surface size as `(width, height)`.
     ///
     /// Returns `(0, 0)` if no surface is configured (e.g., offscreen renderer).
@@ -1185,7 +1209,7 @@ pub fn render_scene(&mut self, scene: &flui_layer::Scene) -> Result<bool, Engine
 
         // 1. Clear pass — submit immediately so the render target is ready for
         //    mid-frame copy operations (backdrop blur needs pixels on the target).
-        self.run_clear_pass(render_view);
+        self.run_clear_pass(render_view, wgpu::Color::WHITE);
 
         // 2. Build render context for backdrop filter support.
         //    `surface_format` was already computed above when selecting the
@@ -1195,8 +1219,15 @@ pub fn render_scene(&mut self, scene: &flui_layer::Scene) -> Result<bool, Engine
             intermediate_active: effective_intermediate_active,
         };
 
-        // 3. Render scene content via LayerTree traversal
-        self.render_scene_content(scene, render_view, render_texture, &ctx);
+        // 3. Render scene content via LayerTree traversal, scissored to the
+        //    damage rect when only part of the screen changed.
+        //    `damage_rect()` returns `None` for full repaint (no scissor needed),
+        //    `Some(rect)` for partial damage.
+        let partial_damage = self
+            .damage_tracker
+            .damage_rect()
+            .filter(|r| r.width().0 > 0.0 && r.height().0 > 0.0);
+        self.render_scene_content(scene, render_view, render_texture, &ctx, partial_damage);
 
         // If the intermediate path was active, blit the fully-rendered
         // intermediate onto the real swapchain surface now.  This is the only
@@ -1232,8 +1263,133 @@ pub fn render_scene(&mut self, scene: &flui_layer::Scene) -> Result<bool, Engine
         Ok(true)
     }
 
+    /// Renders `scene` into an off-screen texture of `size` physical pixels
+    /// and reads it back as a straight-alpha RGBA8 [`Image`] — the engine
+    /// half of Flutter's `Scene.toImage` / `RepaintBoundary.toImage`, and the
+    /// capture step for golden-image tests.
+    ///
+    /// The capture starts from a transparent target and always repaints the
+    /// whole scene: the damage tracker and the surface are left untouched, so
+    /// it can run between window frames. Works on offscreen renderers too
+    /// (their painter is created on first use, in `Rgba8Unorm`). Blocks until
+    /// the GPU copy completes. A zero-area `size` yields an empty image.
+    ///
+    /// # Errors
+    ///
+    /// [`EngineError::DeviceLost`] when the device is gone, and
+    /// [`EngineError::Readback`] (source: [`ImageCaptureError`]) when `size`
+    /// exceeds the device texture limit or the readback fails.
+    ///
+    /// [`Image`]: flui_types::painting::Image
+    /// [`ImageCaptureError`]: super::ImageCaptureError
+    pub fn render_to_image(
+        &mut self,
+        scene: &flui_layer::Scene,
+        size: flui_types::Size<flui_types::geometry::Pixels>,
+    ) -> EngineResult<flui_types::painting::Image> {
+        if self.is_device_lost() {
+            return Err(EngineError::DeviceLost);
+        }
+
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "ceil of a non-negative pixel extent; oversize is rejected below"
+        )]
+        let (width, height) = (
+            size.width.0.max(0.0).ceil() as u32,
+            size.height.0.max(0.0).ceil() as u32,
+        );
+        if width == 0 || height == 0 {
+            return Ok(flui_types::painting::Image::default());
+        }
+        let max = self.device.limits().max_texture_dimension_2d;
+        if width > max || height > max {
+            return Err(
+                super::image_capture::ImageCaptureError::TooLarge { width, height, max }.into(),
+            );
+        }
+
+        self.ensure_capture_painter();
+        let Some(format) = self
+            .painter
+            .as_ref()
+            .map(super::painter::WgpuPainter::surface_format)
+        else {
+            return Err(EngineError::NotInitialized);
+        };
+        let Some(target) = self
+            .offscreen
+            .as_ref()
+            .map(|offscreen| offscreen.texture_pool().acquire(width, height, format))
+        else {
+            return Err(EngineError::NotInitialized);
+        };
+
+        // Render at the capture size, then restore the window viewport.
+        let window_size = self.painter.as_ref().map(super::painter::WgpuPainter::size);
+        if let Some(painter) = self.painter.as_mut() {
+            painter.resize(width, height);
+        }
+        // The capture is not a presented frame: keep its passes out of the
+        // per-frame GPU profile.
+        #[cfg(feature = "gpu-profiler")]
+        let profiler = self.gpu_profiler.take();
+
+        self.run_clear_pass(target.view(), wgpu::Color::TRANSPARENT);
+        // Pool textures carry COPY_SRC, so backdrop filters and advanced
+        // blends read the capture target directly.
+        let ctx = RenderContext {
+            supports_copy_src: true,
+            intermediate_active: false,
+        };
+        self.render_scene_content(scene, target.view(), target.texture(), &ctx, None);
+
+        #[cfg(feature = "gpu-profiler")]
+        {
+            self.gpu_profiler = profiler;
+        }
+        if let (Some(painter), Some((w, h))) = (self.painter.as_mut(), window_size) {
+            painter.resize(w, h);
+        }
+
+        super::image_capture::read_texture_to_image(&self.device, &self.queue, target.texture())
+    }
+
+    /// Gives an offscreen renderer (built without a surface) the painter and
+    /// offscreen renderer `render_to_image` draws with. Windowed renderers
+    /// already have both.
+    fn ensure_capture_painter(&mut self) {
+        const CAPTURE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
+
+        if self.painter.is_none() {
+            self.painter = Some(super::painter::WgpuPainter::with_shared_device(
+                Arc::clone(&self.device),
+                Arc::clone(&self.queue),
+                CAPTURE_FORMAT,
+                (1, 1),
+            ));
+        }
+        if self.offscreen.is_none() {
+            let format = self
+                .painter
+                .as_ref()
+                .map_or(CAPTURE_FORMAT, super::painter::WgpuPainter::surface_format);
+            self.offscreen = Some(super::offscreen::OffscreenRenderer::new(
+                Arc::clone(&self.device),
+                Arc::clone(&self.queue),
+                format,
+            ));
+        }
+    }
+
     /// Acquire the current swapchain texture, handling device-lost and all
-    /// `CurrentSurfaceTexture` variants with a single retry on Outdated/Lost.
+    /// `CurrentSurfaceTexture` variants.
+    ///
+    /// Outdated/Lost surfaces are reconfigured and retried once per frame;
+    /// consecutive failed frames escalate to [`EngineError::DeviceLost`] (and
+    /// set the device-lost flag, so the runner calls [`Self::recover`]) —
+    /// see [`super::surface_recovery`].
     ///
     /// Returns `Ok(None)` when the frame should be silently skipped (Occluded).
     fn acquire_surface_texture(&mut self) -> Result<Option<wgpu::SurfaceTexture>, EngineError> {
@@ -1245,46 +1401,23 @@ fn acquire_surface_texture(&mut self) -> Result<Option<wgpu::SurfaceTexture>, En
             tracing::warn!("Device lost detected; returning DeviceLost error");
             return Err(EngineError::DeviceLost);
         }
+        if self.surface.is_none() {
+            return Err(EngineError::SurfaceLost);
+        }
 
-        // wgpu 28+: get_current_texture() returns CurrentSurfaceTexture enum
-        // instead of Result<SurfaceTexture, SurfaceError>.
-        let surface = self.surface.as_ref().ok_or(EngineError::SurfaceLost)?;
-        match surface.get_current_texture() {
-            wgpu::CurrentSurfaceTexture::Success(frame) => Ok(Some(frame)),
-            wgpu::CurrentSurfaceTexture::Suboptimal(frame) => {
-                // Suboptimal is still renderable; schedule a reconfigure next frame.
-                tracing::debug!("Surface suboptimal; will reconfigure on next resize");
-                Ok(Some(frame))
-            }
-            wgpu::CurrentSurfaceTexture::Outdated | wgpu::CurrentSurfaceTexture::Lost => {
-                // Auto-reconfigure and retry once.
-                self.reconfigure_surface()?;
-                let surface = self.surface.as_ref().ok_or(EngineError::SurfaceLost)?;
-                match surface.get_current_texture() {
-                    wgpu::CurrentSurfaceTexture::Success(frame)
-                    | wgpu::CurrentSurfaceTexture::Suboptimal(frame) => Ok(Some(frame)),
-                    _ => Err(EngineError::SurfaceLost),
-                }
-            }
-            wgpu::CurrentSurfaceTexture::Timeout => Err(EngineError::Timeout),
-            wgpu::CurrentSurfaceTexture::Occluded => {
-                // Window is minimized or fully occluded — skip this frame
-                // entirely rather than producing garbage frames.
-                tracing::trace!("Surface occluded; skipping frame");
-                Ok(None)
-            }
-            wgpu::CurrentSurfaceTexture::Validation => {
-                // Validation error — the surface texture could not be produced
-                // due to a validation failure (surface misconfig: incompatible
-                // format/usage/present mode). This is NOT a recoverable
-                // SurfaceLost: retrying `get_current_texture` without
-                // reconfiguring loops forever. Log and surface a distinct
-                // non-recoverable error so the caller drops the frame and
-                // reconfigures on the next pass.
-                tracing::error!("Surface texture validation error");
-                Err(EngineError::SurfaceValidation)
-            }
+        // The policy borrows `self` as its `SurfaceAcquire`, so its own state
+        // is moved out for the call.
+        let mut recovery = std::mem::take(&mut self.surface_recovery);
+        let result = recovery.acquire(self);
+        self.surface_recovery = recovery;
+
+        if matches!(result, Err(EngineError::DeviceLost)) {
+            // Escalated by the retry policy: flag it like a real device loss
+            // so the platform runner rebuilds surface, swapchain, and device.
+            self.device_lost
+                .store(true, std::sync::atomic::Ordering::Release);
         }
+        result
     }
 
     /// Warn when the acquired swapchain texture dimensions differ from the
@@ -1312,7 +1445,7 @@ fn warn_on_size_mismatch(&self, output_texture: &wgpu::Texture) {
 
     /// Submit the clear render pass, cleaning the render target before scene
     /// traversal so backdrop-blur mid-frame copies see a cleared surface.
-    fn run_clear_pass(&mut self, render_view: &wgpu::TextureView) {
+    fn run_clear_pass(&mut self, render_view: &wgpu::TextureView, clear_color: wgpu::Color) {
         let mut clear_encoder =
             self.device
                 .create_command_encoder(&wgpu::CommandEncoderDescriptor {
@@ -1326,7 +1459,7 @@ fn run_clear_pass(&mut self, render_view: &wgpu::TextureView) {
             resolve_target: None,
             depth_slice: None,
             ops: wgpu::Operations {
-                load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
+                load: wgpu::LoadOp::Clear(clear_color),
                 store: wgpu::StoreOp::Store,
             },
         })];
@@ -1371,12 +1504,16 @@ fn run_clear_pass(&mut self, render_view: &wgpu::TextureView) {
 
     /// Traverse the scene's layer tree and flush all painter batches to the GPU,
     /// including the damage-straddle self-heal check and final encoder submission.
+    ///
+    /// `partial_damage` scissors the frame to the damaged region; `None`
+    /// repaints the whole target.
     fn render_scene_content(
         &mut self,
         scene: &flui_layer::Scene,
         render_view: &wgpu::TextureView,
         render_texture: &wgpu::Texture,
         ctx: &RenderContext,
+        partial_damage: Option<flui_types::geometry::Rect<flui_types::geometry::Pixels>>,
     ) {
         use super::backend::Backend;
 
@@ -1408,18 +1545,12 @@ fn render_scene_content(
 
         // Apply damage rect as scissor optimization: when only part of the
         // screen changed, limit GPU work to the damaged region.
-        // `damage_rect()` returns `None` for full repaint (no scissor needed),
-        // `Some(rect)` for partial damage.
         //
-        // We capture `partial_damage` separately: after `render_layer_recursive`
-        // populates `draw_order`, we check whether any advanced shape (or SSAA
-        // path with an advanced blend) straddles the damage edge.  If so, we
-        // schedule a full repaint next frame to self-heal stale pixels outside
-        // the damage rect that `flush_advanced_layer` may have written.
-        let partial_damage = self
-            .damage_tracker
-            .damage_rect()
-            .filter(|r| r.width().0 > 0.0 && r.height().0 > 0.0);
+        // After `render_layer_recursive` populates `draw_order`, we check
+        // whether any advanced shape (or SSAA path with an advanced blend)
+        // straddles the damage edge.  If so, we schedule a full repaint next
+        // frame to self-heal stale pixels outside the damage rect that
+        // `flush_advanced_layer` may have written.
         if let Some(damage) = partial_damage {
             backend.painter_mut().clip_rect(damage);
             tracing::trace!(
@@ -1683,18 +1814,11 @@ fn handle_backdrop_filter(
         surface_texture: &wgpu::Texture,
         surface_view: &wgpu::TextureView,
     ) {
-        use flui_types::painting::ImageFilter;
-
         let bounds = bf_layer.bounds();
 
-        // Extract sigma from blur filter; other filter types fall back to
-        // normal child rendering (no GPU blur support yet).
-        let sigma = if let ImageFilter::Blur { sigma_x, sigma_y } = bf_layer.filter() {
-            f32::midpoint(*sigma_x, *sigma_y)
-        } else {
-            tracing::warn!(
-                "Backdrop filter type not supported for GPU blur, rendering children only"
-            );
+        // Extract sigma from a clamp-mode blur filter; anything else falls back
+        // to normal child rendering (no GPU support yet).
+        let Some(sigma) = super::backend::Backend::backdrop_blur_sigma(bf_layer.filter()) else {
             for &child_id in node.children() {
                 Self::render_layer_recursive(
                     tree,
@@ -1941,6 +2065,50 @@ fn handle_shader_mask(
     }
 }
 
+impl super::surface_recovery::SurfaceAcquire for Renderer {
+    type Frame = wgpu::SurfaceTexture;
+
+    fn acquire(&mut self) -> AcquireOutcome<wgpu::SurfaceTexture> {
+        // wgpu 28+: get_current_texture() returns CurrentSurfaceTexture enum
+        // instead of Result<SurfaceTexture, SurfaceError>.
+        let Some(surface) = self.surface.as_ref() else {
+            return AcquireOutcome::Failed(EngineError::SurfaceLost);
+        };
+        match surface.get_current_texture() {
+            wgpu::CurrentSurfaceTexture::Success(frame) => AcquireOutcome::Acquired(frame),
+            wgpu::CurrentSurfaceTexture::Suboptimal(frame) => {
+                // Suboptimal is still renderable; schedule a reconfigure next frame.
+                tracing::debug!("Surface suboptimal; will reconfigure on next resize");
+                AcquireOutcome::Acquired(frame)
+            }
+            wgpu::CurrentSurfaceTexture::Outdated | wgpu::CurrentSurfaceTexture::Lost => {
+                AcquireOutcome::Stale
+            }
+            wgpu::CurrentSurfaceTexture::Timeout => AcquireOutcome::Failed(EngineError::Timeout),
+            wgpu::CurrentSurfaceTexture::Occluded => {
+                // Window is minimized or fully occluded — skip this frame
+                // entirely rather than producing garbage frames.
+                tracing::trace!("Surface occluded; skipping frame");
+                AcquireOutcome::Occluded
+            }
+            wgpu::CurrentSurfaceTexture::Validation => {
+                // Validation error — the surface texture could not be produced
+                // due to a validation failure (surface misconfig: incompatible
+                // format/usage/present mode). Retrying `get_current_texture`
+                // without reconfiguring loops forever, so surface a distinct
+                // error; persistent validation failures escalate to a full
+                // GPU-stack rebuild via the retry policy.
+                tracing::error!("Surface texture validation error");
+                AcquireOutcome::Failed(EngineError::SurfaceValidation)
+            }
+        }
+    }
+
+    fn reconfigure(&mut self) -> Result<(), EngineError> {
+        self.reconfigure_surface()
+    }
+}
+
 #[cfg(all(test, feature = "enable-wgpu-tests"))]
 mod tests {
     use super::*;
@@ -4029,4 +4197,60 @@ fn shader_mask_without_offscreen_renderer_falls_through_to_inert_clip() {
              (opaque red); got {pixel:?}"
         );
     }
+
+    /// `render_to_image` on an offscreen renderer renders the scene into a
+    /// transparent target and returns straight-alpha RGBA: an opaque fill
+    /// reads back verbatim, a translucent fill is unpremultiplied, and
+    /// uncovered pixels stay transparent. The 17px width forces row padding.
+    #[test]
+    fn render_to_image_reads_back_a_solid_color_scene() {
+        use flui_layer::{CanvasLayer, Layer, Scene};
+        use flui_painting::Paint;
+        use flui_types::{Color, Rect, Size, geometry::px};
+
+        let Ok(mut renderer) = pollster::block_on(Renderer::new_offscreen()) else {
+            // No GPU in this environment (common in CI); skip gracefully.
+            return;
+        };
+
+        let (width, height) = (17u32, 8u32);
+        let mut canvas = CanvasLayer::new();
+        canvas.canvas_mut().draw_rect(
+            Rect::from_ltrb(px(0.0), px(0.0), px(8.0), px(8.0)),
+            &Paint::fill(Color::rgba(200, 40, 10, 255)),
+        );
+        canvas.canvas_mut().draw_rect(
+            Rect::from_ltrb(px(8.0), px(0.0), px(16.0), px(8.0)),
+            &Paint::fill(Color::rgba(0, 0, 255, 128)),
+        );
+        let size = Size::new(px(width as f32), px(height as f32));
+        let scene = Scene::from_layer(size, Layer::from(canvas), 0);
+
+        let image = renderer
+            .render_to_image(&scene, size)
+            .expect("render_to_image must succeed on a healthy device");
+        assert_eq!((image.width(), image.height()), (width, height));
+
+        let pixel = |x: u32, y: u32| {
+            let i = ((y * width + x) * 4) as usize;
+            let data = image.data();
+            [data[i], data[i + 1], data[i + 2], data[i + 3]]
+        };
+        assert_eq!(pixel(3, 4), [200, 40, 10, 255], "opaque fill");
+        let translucent = pixel(12, 4);
+        assert!(
+            translucent[2] >= 253 && translucent[0] <= 2 && translucent[3].abs_diff(128) <= 1,
+            "translucent fill must unpremultiply to straight blue, got {translucent:?}"
+        );
+        assert_eq!(
+            pixel(16, 4),
+            [0, 0, 0, 0],
+            "uncovered column is transparent"
+        );
+
+        let empty = renderer
+            .render_to_image(&scene, Size::new(px(0.0), px(8.0)))
+            .expect("zero-area capture is not an error");
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+    }
 }
@@ -12,7 +12,7 @@
 use std::sync::Arc;
 
 use super::super::{
-    command_ir::{DrawSegment, ScissorRect},
+    command_ir::{DrawSegment, ScissorRect, image_runs},
     pipeline::PipelineKey,
     pipelines::PipelineSet,
     resources::GpuResources,
@@ -672,9 +672,10 @@ fn flush_tessellated_geometry(
 
     /// Flush all texture-cache image draws recorded in the segment.
     ///
-    /// Groups consecutive draws by `TextureId` to minimise draw calls.  When
-    /// a texture-ID change forces an early flush, the previous batch is
-    /// submitted before the new `TextureId` takes over.
+    /// Groups consecutive draws by `TextureId` and scissor to minimise draw
+    /// calls.  When a texture-ID or clip change forces an early flush, the
+    /// previous batch is submitted under its own scissor before the new
+    /// state takes over.
     fn flush_segment_cached_images(
         &mut self,
         segment: &mut DrawSegment,
@@ -686,25 +687,19 @@ fn flush_segment_cached_images(
         encoder: &mut wgpu::CommandEncoder,
         view: &wgpu::TextureView,
     ) {
-        let mut pending_images: Vec<(
-            super::super::texture_cache::TextureId,
-            super::super::instancing::TextureInstance,
-            ScissorRect,
-        )> = segment.cached_images.drain(..).collect();
-
-        if pending_images.is_empty() {
-            return;
-        }
-
-        let mut active_texture_id: Option<super::super::texture_cache::TextureId> = None;
-        let mut active_texture_view: Option<wgpu::TextureView> = None;
-        // Scissor of the most-recently buffered instance — forwarded when a
-        // texture-change forces an early flush.
-        let mut active_scissor: ScissorRect = None;
+        let pending_images: Vec<_> = segment.cached_images.drain(..).collect();
+
+        for run in image_runs(pending_images) {
+            let Some(texture_view) = resources
+                .texture_cache_mut()
+                .get(&run.texture_id)
+                .map(|cached| cached.view.clone())
+            else {
+                continue;
+            };
 
-        for (texture_id, instance, scissor) in pending_images.drain(..) {
-            if active_texture_id.as_ref() != Some(&texture_id) {
-                if let Some(texture_view) = active_texture_view.as_ref() {
+            for instance in run.instances {
+                if self.texture_batch.add(instance) {
                     self.flush_texture_batch(
                         device,
                         queue,
@@ -713,36 +708,11 @@ fn flush_segment_cached_images(
                         viewport_size,
                         encoder,
                         view,
-                        texture_view,
-                        active_scissor,
+                        &texture_view,
+                        run.scissor,
                     );
                 }
-                active_texture_id = Some(texture_id.clone());
-                active_texture_view = resources
-                    .texture_cache_mut()
-                    .get(&texture_id)
-                    .map(|cached| cached.view.clone());
-            }
-
-            active_scissor = scissor;
-            if let Some(texture_view) = active_texture_view.as_ref()
-                && self.texture_batch.add(instance)
-            {
-                self.flush_texture_batch(
-                    device,
-                    queue,
-                    pipelines,
-                    resources,
-                    viewport_size,
-                    encoder,
-                    view,
-                    texture_view,
-                    active_scissor,
-                );
             }
-        }
-
-        if let Some(texture_view) = active_texture_view.as_ref() {
             self.flush_texture_batch(
                 device,
                 queue,
@@ -751,8 +721,8 @@ fn flush_segment_cached_images(
                 viewport_size,
                 encoder,
                 view,
-                texture_view,
-                active_scissor,
+                &texture_view,
+                run.scissor,
             );
         }
     }
@@ -1105,7 +1075,8 @@ pub(in crate::wgpu) fn flush_texture_batch_premultiplied_with_mode(
 
 #[cfg(test)]
 mod tests {
-    use super::clamp_scissor_to_attachment;
+    use super::super::super::command_ir::ImageRun;
+    use super::{clamp_scissor_to_attachment, image_runs};
 
     /// The off-target sentinel `(full_w, full_h, 1, 1)` that
     /// `opacity_layer.rs`'s `render_segment_to_grown_offscreen` and
@@ -1184,4 +1155,36 @@ fn set_clamped_scissor_is_the_only_scissor_rect_call_site() {
              {call_sites}"
         );
     }
+
+    /// Images sharing a texture are still split when the clip changes, and
+    /// each run keeps the scissor it was recorded under — otherwise a whole
+    /// batch would be drawn under the last image's clip.
+    #[test]
+    fn image_runs_split_on_scissor_change_and_keep_each_scissor() {
+        let left = Some((0, 0, 32, 64));
+        let right = Some((32, 0, 32, 64));
+        let runs = image_runs([
+            ('a', 0, left),
+            ('a', 1, left),
+            ('a', 2, right),
+            ('b', 3, right),
+            ('b', 4, None),
+            ('b', 5, None),
+        ]);
+
+        let run = |texture_id, scissor, instances: &[i32]| ImageRun {
+            texture_id,
+            scissor,
+            instances: instances.to_vec(),
+        };
+        assert_eq!(
+            runs,
+            vec![
+                run('a', left, &[0, 1]),
+                run('a', right, &[2]),
+                run('b', right, &[3]),
+                run('b', None, &[4, 5]),
+            ]
+        );
+    }
 }
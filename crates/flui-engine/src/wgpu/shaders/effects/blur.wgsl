@@ -22,10 +22,21 @@
 // rather than the theoretical integral. This exactly renormalises the truncated
 // kernel: the result is correct even when the kernel is small relative to sigma.
 //
-// **Decal semantics.**
-// wgpu has no `AddressMode::Decal`.  The H pass implements decal in-shader:
-// samples outside `content_rect_uv` → `vec4(0.0)`.  The V pass decals at the
-// texture edge `[0,1]` (reads the H-pass halo).
+// **Tile modes (Flutter `ImageFilter.blur(tileMode:)`).**
+// `tile_mode` selects how taps outside `content_rect_uv` are sampled:
+//
+// - 0 = decal: wgpu has no `AddressMode::Decal`, so it is implemented
+//   in-shader — samples outside `content_rect_uv` → `vec4(0.0)`.  The V pass
+//   decals at the texture edge `[0,1]` (reads the H-pass halo).
+// - 1 = clamp, 2 = repeat, 3 = mirror: taps are folded back into the content
+//   rect.  The output is limited to the content rect (Skia/Impeller semantics
+//   for non-decal blurs), so both passes use the content rect.
+//
+// **Output-sized texel step.**
+// `texture_size` is the size of the *target* texture.  For a large sigma the
+// CPU driver renders into a downsampled target with a proportionally smaller
+// sigma, so the same shader performs the downsample while blurring.  The
+// zero-sigma upsample back to full size passes the downsampled size instead.
 //
 // **Anisotropic.**
 // `sigma` is the sigma for *this* sub-pass: `sigma_x` for the H pass, `sigma_y`
@@ -35,7 +46,7 @@
 //
 // | Binding | Stage | Type                     | Content                          |
 // |---------|-------|--------------------------|----------------------------------|
-// | 0       | FS    | Uniform buffer           | `BlurUniforms` (32 bytes)        |
+// | 0       | FS    | Uniform buffer           | `BlurUniforms` (48 bytes)        |
 // | 1       | FS    | 2D float texture         | Source (premultiplied RGBA)      |
 // | 2       | FS    | Linear-filtering sampler | Bilinear + ClampToEdge           |
 //
@@ -45,16 +56,18 @@
 // covering the full viewport ([0,0]→[1,1] NDC quad, two CCW triangles).
 // Mirrors morphology.wgsl exactly.
 //
-// ## Uniform layout (`BlurUniforms`, 32 bytes)
+// ## Uniform layout (`BlurUniforms`, 48 bytes)
 //
 // | Byte offset | Size | Field            | Semantics                         |
 // |-------------|------|------------------|-----------------------------------|
-// | 0           | 8    | texture_size     | Source texture size in pixels     |
+// | 0           | 8    | texture_size     | Target (upsample: source) size    |
 // | 8           | 4    | sigma            | Gaussian σ for this sub-pass      |
 // | 12          | 4    | direction        | 0.0 = horizontal, 1.0 = vertical  |
 // | 16          | 16   | content_rect_uv  | [min_u, min_v, max_u, max_v]      |
+// | 32          | 4    | tile_mode        | 0 decal, 1 clamp, 2 repeat, 3 mirror |
+// | 36          | 12   | (padding)        | struct rounds up to align 16      |
 //
-// Total = 32 bytes ✓
+// Total = 48 bytes ✓
 
 // ─── Uniforms ────────────────────────────────────────────────────────────────
 
@@ -63,9 +76,10 @@
 //   f32        →  4 bytes  (align 4)
 //   f32        →  4 bytes  (align 4)   — texture_size + sigma + direction = 16 bytes at offset 0
 //   vec4<f32>  →  16 bytes (align 16)  — content_rect_uv at offset 16
-// Total = 32 bytes ✓
+//   f32        →  4 bytes  (align 4)   — tile_mode at offset 32, padded to 48
+// Total = 48 bytes ✓
 struct BlurUniforms {
-    /// Source texture size in pixels — used to convert kernel offsets to UV.
+    /// Target texture size in pixels — used to convert kernel offsets to UV.
     texture_size: vec2<f32>,
     /// Gaussian sigma for this sub-pass (sigma_x for H, sigma_y for V).
     sigma: f32,
@@ -73,9 +87,12 @@ struct BlurUniforms {
     direction: f32,
     /// Content rectangle in UV space `[min_u, min_v, max_u, max_v]`.
     ///
-    /// H pass: decal at the content rect — samples outside → `vec4(0.0)`.
-    /// V pass: `[0.0, 0.0, 1.0, 1.0]` — decal at texture edge to include H halo.
+    /// Decal H pass: samples outside → `vec4(0.0)`.
+    /// Decal V pass: `[0.0, 0.0, 1.0, 1.0]` — decal at texture edge to include H halo.
+    /// Other tile modes: the content rect in both passes.
     content_rect_uv: vec4<f32>,
+    /// Edge handling: 0.0 = decal, 1.0 = clamp, 2.0 = repeat, 3.0 = mirror.
+    tile_mode: f32,
 }
 
 @group(0) @binding(0)
@@ -112,26 +129,66 @@ fn vs_main(@builtin(vertex_index) vi: u32) -> VertexOutput {
 
 // ─── Fragment stage ───────────────────────────────────────────────────────────
 
+// ─── Tiling ───────────────────────────────────────────────────────────────────
+
+fn inside_content(uv: vec2<f32>) -> bool {
+    return uv.x >= u.content_rect_uv.x &&
+        uv.y >= u.content_rect_uv.y &&
+        uv.x <= u.content_rect_uv.z &&
+        uv.y <= u.content_rect_uv.w;
+}
+
+// Folds one UV coordinate into `[lo, hi]` for the clamp / repeat / mirror
+// modes.  Clamp stops half a texel inside the edge so bilinear filtering
+// reads the edge texel only; repeat and mirror are exact at texel centres
+// (mirror repeats the edge texel, like Skia's `kMirror`).
+fn tile_axis(t: f32, lo: f32, hi: f32, half_texel: f32) -> f32 {
+    let extent = hi - lo;
+    if extent <= 0.0 {
+        return lo;
+    }
+    if u.tile_mode < 1.5 {
+        return clamp(t, lo + half_texel, max(lo + half_texel, hi - half_texel));
+    }
+    let offset = t - lo;
+    if u.tile_mode < 2.5 {
+        return lo + offset - extent * floor(offset / extent);
+    }
+    let period = 2.0 * extent;
+    let folded = offset - period * floor(offset / period);
+    return lo + select(folded, period - folded, folded > extent);
+}
+
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let decal: bool = u.tile_mode < 0.5;
+
+    // Non-decal modes never spread past the content rect.  Applied as a final
+    // mask rather than an early return: `textureSample` below requires
+    // uniform control flow.
+    let masked_out: bool = !decal && !inside_content(in.uv);
+
+    // Texel step size in UV for one pixel.
+    let texel_size: vec2<f32> = vec2<f32>(1.0) / u.texture_size;
+
     // Degenerate case: sigma <= 0 → identity (kernel_radius = 0, only centre tap).
+    // Also the bilinear upsample after a downsampled blur, where `texture_size`
+    // is the *source* (work) size: non-decal modes clamp the tap half a work
+    // texel inside the content rect so the bilinear footprint never reaches
+    // the masked-out texels beyond it.
     if u.sigma <= 0.0 {
-        let centre_uv: vec2<f32> = in.uv;
-        let inside_centre: bool =
-            centre_uv.x >= u.content_rect_uv.x &&
-            centre_uv.y >= u.content_rect_uv.y &&
-            centre_uv.x <= u.content_rect_uv.z &&
-            centre_uv.y <= u.content_rect_uv.w;
+        var centre_uv: vec2<f32> = in.uv;
+        if !decal {
+            let lo: vec2<f32> = u.content_rect_uv.xy + 0.5 * texel_size;
+            centre_uv = clamp(centre_uv, lo, max(lo, u.content_rect_uv.zw - 0.5 * texel_size));
+        }
         return select(
             vec4<f32>(0.0),
             textureSample(src_texture, src_sampler, centre_uv),
-            inside_centre,
+            inside_content(in.uv),
         );
     }
 
-    // Texel step size in UV for one pixel.
-    let texel_size: vec2<f32> = vec2<f32>(1.0) / u.texture_size;
-
     // Direction vector: (1, 0) for horizontal (U), (0, 1) for vertical (V).
     let dir: vec2<f32> = select(vec2<f32>(1.0, 0.0), vec2<f32>(0.0, 1.0), u.direction > 0.5);
 
@@ -146,16 +203,21 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     var tally: f32 = 0.0;
 
     for (var i: i32 = -r; i <= r; i++) {
-        let sample_uv: vec2<f32> = in.uv + dir * f32(i) * texel_size;
+        var sample_uv: vec2<f32> = in.uv + dir * f32(i) * texel_size;
 
         // Decal guard: samples outside content_rect_uv → vec4(0.0).
         // H pass: content_rect_uv = actual content bounds in UV.
         // V pass: content_rect_uv = [0,1] (texture-edge decal — reads the H halo).
-        let inside: bool =
-            sample_uv.x >= u.content_rect_uv.x &&
-            sample_uv.y >= u.content_rect_uv.y &&
-            sample_uv.x <= u.content_rect_uv.z &&
-            sample_uv.y <= u.content_rect_uv.w;
+        // Other modes fold the tap back into the content rect instead.
+        var inside: bool = true;
+        if decal {
+            inside = inside_content(sample_uv);
+        } else {
+            sample_uv = vec2<f32>(
+                tile_axis(sample_uv.x, u.content_rect_uv.x, u.content_rect_uv.z, 0.5 * texel_size.x),
+                tile_axis(sample_uv.y, u.content_rect_uv.y, u.content_rect_uv.w, 0.5 * texel_size.y),
+            );
+        }
 
         let texel: vec4<f32> = select(
             vec4<f32>(0.0),
@@ -174,7 +236,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     // Renormalise: divide accumulated colour by sum of weights.
     // Guard against near-zero tally (only reachable at extreme sigma → r=0).
     if tally > 0.0 {
-        return acc / tally;
+        acc = acc / tally;
     }
-    return acc;
+    return select(acc, vec4<f32>(0.0), masked_out);
 }
@@ -0,0 +1,232 @@
+//! Swapchain-acquire retry policy for [`Renderer::render_scene`].
+//!
+//! [`Renderer::render_scene`]: super::Renderer::render_scene
+//!
+//! A surface commonly goes `Outdated` / `Lost` on resize, minimize/restore,
+//! or system sleep. Within a frame the policy reconfigures the surface and
+//! retries the acquire **once**; across frames it counts consecutive failed
+//! acquires. A surface that keeps failing even after reconfiguration will
+//! not heal by retrying, so after [`MAX_CONSECUTIVE_SURFACE_FAILURES`] the
+//! failure escalates to [`EngineError::DeviceLost`]. The caller then rebuilds
+//! the whole GPU stack (surface, swapchain, device) via `Renderer::recover`
+//! instead of dropping frames forever.
+//!
+//! The policy only sees [`AcquireOutcome`]s through [`SurfaceAcquire`], so it
+//! is unit-tested against a scripted surface without a GPU.
+
+use crate::error::EngineError;
+
+/// Consecutive failed acquires (each already retried once after a
+/// reconfigure) before the surface is declared unrecoverable.
+pub(crate) const MAX_CONSECUTIVE_SURFACE_FAILURES: u32 = 3;
+
+/// One swapchain acquire, reduced to what the retry policy acts on.
+pub(crate) enum AcquireOutcome<F> {
+    /// A renderable frame (`Success` or `Suboptimal`).
+    Acquired(F),
+    /// The window is minimized / occluded: skip the frame. Not a failure.
+    Occluded,
+    /// `Outdated` / `Lost`: reconfiguring the surface may fix it.
+    Stale,
+    /// Any other failure (`Timeout`, `Validation`).
+    Failed(EngineError),
+}
+
+/// The surface operations the retry policy drives.
+pub(crate) trait SurfaceAcquire {
+    /// The acquired frame (`wgpu::SurfaceTexture` for the real renderer).
+    type Frame;
+
+    /// Requests the next swapchain frame.
+    fn acquire(&mut self) -> AcquireOutcome<Self::Frame>;
+
+    /// Reconfigures (recreates the swapchain of) the surface.
+    fn reconfigure(&mut self) -> Result<(), EngineError>;
+}
+
+/// Cross-frame state of the retry policy.
+#[derive(Debug, Default)]
+pub(crate) struct SurfaceRecovery {
+    consecutive_failures: u32,
+}
+
+impl SurfaceRecovery {
+    /// Acquires a frame from `surface`, reconfiguring and retrying once on a
+    /// stale surface.
+    ///
+    /// Returns `Ok(None)` when the frame should be skipped (occluded). A
+    /// failure returns the underlying error — `SurfaceLost` for a surface
+    /// still stale after the retry — until
+    /// [`MAX_CONSECUTIVE_SURFACE_FAILURES`] consecutive frames have failed,
+    /// then [`EngineError::DeviceLost`].
+    pub(crate) fn acquire<S: SurfaceAcquire>(
+        &mut self,
+        surface: &mut S,
+    ) -> Result<Option<S::Frame>, EngineError> {
+        let outcome = match surface.acquire() {
+            AcquireOutcome::Stale => match surface.reconfigure() {
+                Ok(()) => surface.acquire(),
+                Err(error) => AcquireOutcome::Failed(error),
+            },
+            outcome => outcome,
+        };
+
+        match outcome {
+            AcquireOutcome::Acquired(frame) => {
+                if self.consecutive_failures > 0 {
+                    tracing::debug!(
+                        failures = self.consecutive_failures,
+                        "surface recovered after consecutive acquire failures"
+                    );
+                }
+                self.consecutive_failures = 0;
+                Ok(Some(frame))
+            }
+            AcquireOutcome::Occluded => Ok(None),
+            AcquireOutcome::Stale => Err(self.record_failure(EngineError::SurfaceLost)),
+            AcquireOutcome::Failed(error) => Err(self.record_failure(error)),
+        }
+    }
+
+    /// Consecutive failed acquires since the last acquired frame.
+    #[cfg(test)]
+    pub(crate) fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    fn record_failure(&mut self, error: EngineError) -> EngineError {
+        if matches!(error, EngineError::DeviceLost) {
+            return error;
+        }
+        self.consecutive_failures += 1;
+        if self.consecutive_failures >= MAX_CONSECUTIVE_SURFACE_FAILURES {
+            tracing::error!(
+                failures = self.consecutive_failures,
+                %error,
+                "surface acquire keeps failing after reconfiguration; \
+                 escalating to device loss so the GPU context is rebuilt"
+            );
+            EngineError::DeviceLost
+        } else {
+            tracing::warn!(
+                failures = self.consecutive_failures,
+                %error,
+                "surface acquire failed; frame dropped"
+            );
+            error
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::{
+        AcquireOutcome, MAX_CONSECUTIVE_SURFACE_FAILURES, SurfaceAcquire, SurfaceRecovery,
+    };
+    use crate::error::EngineError;
+
+    /// Mock surface replaying scripted acquire outcomes (frames are `u32`).
+    #[derive(Default)]
+    struct ScriptedSurface {
+        outcomes: VecDeque<AcquireOutcome<u32>>,
+        acquires: usize,
+        reconfigures: usize,
+    }
+
+    impl ScriptedSurface {
+        fn new(outcomes: impl IntoIterator<Item = AcquireOutcome<u32>>) -> Self {
+            Self {
+                outcomes: outcomes.into_iter().collect(),
+                ..Self::default()
+            }
+        }
+    }
+
+    impl SurfaceAcquire for ScriptedSurface {
+        type Frame = u32;
+
+        fn acquire(&mut self) -> AcquireOutcome<u32> {
+            self.acquires += 1;
+            self.outcomes
+                .pop_front()
+                .unwrap_or(AcquireOutcome::Acquired(0))
+        }
+
+        fn reconfigure(&mut self) -> Result<(), EngineError> {
+            self.reconfigures += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn lost_once_is_reconfigured_and_retried_within_the_frame() {
+        let mut surface =
+            ScriptedSurface::new([AcquireOutcome::Stale, AcquireOutcome::Acquired(7)]);
+        let mut recovery = SurfaceRecovery::default();
+
+        let frame = recovery.acquire(&mut surface).expect("retry must succeed");
+
+        assert_eq!(frame, Some(7));
+        assert_eq!((surface.acquires, surface.reconfigures), (2, 1));
+        assert_eq!(recovery.consecutive_failures(), 0);
+    }
+
+    #[test]
+    fn occluded_skips_the_frame_without_counting_a_failure() {
+        let mut surface = ScriptedSurface::new([AcquireOutcome::Occluded]);
+        let mut recovery = SurfaceRecovery::default();
+
+        assert_eq!(recovery.acquire(&mut surface).ok(), Some(None));
+        assert_eq!(surface.reconfigures, 0);
+        assert_eq!(recovery.consecutive_failures(), 0);
+    }
+
+    #[test]
+    fn persistent_loss_escalates_to_device_lost_after_the_limit() {
+        // Every acquire (including each in-frame retry) reports Lost.
+        let stale = (0..2 * MAX_CONSECUTIVE_SURFACE_FAILURES).map(|_| AcquireOutcome::Stale);
+        let mut surface = ScriptedSurface::new(stale);
+        let mut recovery = SurfaceRecovery::default();
+
+        for failures in 1..MAX_CONSECUTIVE_SURFACE_FAILURES {
+            assert!(matches!(
+                recovery.acquire(&mut surface),
+                Err(EngineError::SurfaceLost)
+            ));
+            assert_eq!(recovery.consecutive_failures(), failures);
+        }
+        assert!(matches!(
+            recovery.acquire(&mut surface),
+            Err(EngineError::DeviceLost)
+        ));
+        assert_eq!(
+            surface.reconfigures, MAX_CONSECUTIVE_SURFACE_FAILURES as usize,
+            "one reconfigure per frame, never a tight retry loop"
+        );
+    }
+
+    #[test]
+    fn a_successful_frame_resets_the_failure_count() {
+        let mut surface = ScriptedSurface::new([
+            AcquireOutcome::Failed(EngineError::Timeout),
+            AcquireOutcome::Stale,
+            AcquireOutcome::Stale,
+            AcquireOutcome::Acquired(1),
+        ]);
+        let mut recovery = SurfaceRecovery::default();
+
+        assert!(matches!(
+            recovery.acquire(&mut surface),
+            Err(EngineError::Timeout)
+        ));
+        assert!(matches!(
+            recovery.acquire(&mut surface),
+            Err(EngineError::SurfaceLost)
+        ));
+        assert_eq!(recovery.consecutive_failures(), 2);
+        assert_eq!(recovery.acquire(&mut surface).ok(), Some(Some(1)));
+        assert_eq!(recovery.consecutive_failures(), 0);
+    }
+}
@@ -9,10 +9,24 @@
 //! Text layout is expensive (shaping, line breaking, metrics calculation).
 //! We cache `Buffer` objects keyed by (text, font_size) to avoid re-layout
 //! when the same text is rendered in subsequent frames.
+//!
+//! # Glyph atlas pages
+//!
+//! Rasterized glyphs live in a glyphon [`TextAtlas`], which grows up to the
+//! device's maximum texture size and reuses least-recently-used slots once
+//! [`TextRenderer::atlas_trim`] has released them. A frame whose glyph set
+//! still does not fit (large CJK text, many font sizes) spills onto further
+//! atlas *pages* — each its own atlas + glyphon renderer — instead of
+//! failing the whole text pass: [`pack_text_areas`] assigns each page a
+//! contiguous run of the frame's text areas, and every page computes its
+//! glyph UVs against its own texture, so a text area that moves pages is
+//! simply re-prepared there. Pages beyond the first are freed once idle for
+//! [`PAGE_IDLE_FRAMES`] frames; [`GlyphAtlasStats`] reports occupancy.
 
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
 use std::hash::{Hash, Hasher};
+use std::ops::Range;
 
 use flui_foundation::HasInstance;
 use flui_painting::{PaintingBinding, SharedFontSystem};
@@ -74,6 +88,9 @@ fn walk(
                 if let Some(spacing) = style.letter_spacing {
                     style.letter_spacing = Some(spacing * f64::from(scale));
                 }
+                if let Some(spacing) = style.word_spacing {
+                    style.word_spacing = Some(spacing * f64::from(scale));
+                }
             }
             out.push((text.clone(), effective));
         }
@@ -335,6 +352,113 @@ enum BatchEntry {
     },
 }
 
+// ---------------------------------------------------------------------------
+// Glyph atlas pages
+// ---------------------------------------------------------------------------
+
+/// Upper bound on glyph atlas pages. Each page can grow to the device's
+/// maximum texture size before the next one is opened, so this caps the
+/// worst-case glyph memory; text areas that fit in no page are skipped (and
+/// counted in [`GlyphAtlasStats::dropped_text_areas`]).
+const MAX_ATLAS_PAGES: usize = 4;
+
+/// Frames a page beyond the first may go unused before
+/// [`TextRenderer::atlas_trim`] frees it (~2 s at 60 fps).
+const PAGE_IDLE_FRAMES: u64 = 120;
+
+/// One glyph atlas page: a glyphon atlas and the renderer whose vertex data
+/// (glyph UVs) is computed against it.
+struct GlyphAtlasPage {
+    atlas: TextAtlas,
+    renderer: GlyphonRenderer,
+    /// Frame this page last prepared text for.
+    last_used_frame: u64,
+}
+
+impl GlyphAtlasPage {
+    fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        cache: &Cache,
+        format: wgpu::TextureFormat,
+        frame: u64,
+    ) -> Self {
+        let mut atlas = TextAtlas::new(device, queue, cache, format);
+        let renderer =
+            GlyphonRenderer::new(&mut atlas, device, wgpu::MultisampleState::default(), None);
+        Self {
+            atlas,
+            renderer,
+            last_used_frame: frame,
+        }
+    }
+}
+
+/// Glyph-atlas occupancy, from [`WgpuPainter::glyph_atlas_stats`](super::WgpuPainter::glyph_atlas_stats).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GlyphAtlasStats {
+    /// Atlas pages currently allocated (always at least one).
+    pub pages: usize,
+    /// Text areas each page prepared in the most recent text pass, by page
+    /// index. Shorter than `pages` when trailing pages sat idle.
+    pub text_areas_per_page: Vec<usize>,
+    /// Text areas skipped since creation because no page could hold them.
+    pub dropped_text_areas: u64,
+}
+
+/// How [`pack_text_areas`] spread a frame's text areas over atlas pages.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct PagePlan {
+    /// `runs[i]` is the contiguous range of text areas page `i` prepared.
+    runs: Vec<Range<usize>>,
+    /// Text areas that fit in no page.
+    dropped: usize,
+}
+
+/// Assigns `area_count` text areas, in order, to atlas pages.
+///
+/// `try_prepare(page, run)` prepares `run` on `page` and reports whether its
+/// glyphs fit. Each page takes the longest run it accepts, halving the
+/// attempt on overflow; the remainder spills onto the next page. An area that
+/// overflows a page on its own is dropped. Areas left over once
+/// `max_pages` are used up are dropped too.
+fn pack_text_areas(
+    area_count: usize,
+    max_pages: usize,
+    mut try_prepare: impl FnMut(usize, Range<usize>) -> bool,
+) -> PagePlan {
+    let mut plan = PagePlan::default();
+    let mut start = 0;
+    while start < area_count {
+        let page = plan.runs.len();
+        if page == max_pages {
+            plan.dropped += area_count - start;
+            break;
+        }
+
+        let mut len = area_count - start;
+        let fitted = loop {
+            if try_prepare(page, start..start + len) {
+                break len;
+            }
+            if len == 1 {
+                break 0;
+            }
+            len /= 2;
+        };
+
+        if fitted == 0 {
+            // Too large for a page by itself; retry the rest on this page.
+            plan.dropped += 1;
+            start += 1;
+        } else {
+            plan.runs.push(start..start + fitted);
+            start += fitted;
+        }
+    }
+    plan
+}
+
 /// Text rendering system using glyphon
 ///
 /// Manages font loading, text layout, and GPU-accelerated glyph rasterization.
@@ -369,11 +493,20 @@ pub struct TextRenderer {
     /// Swash cache (rasterizes glyphs)
     swash_cache: SwashCache,
 
-    /// Text atlas (texture atlas for glyphs)
-    text_atlas: TextAtlas,
+    /// Shared glyphon pipeline cache, used to open new atlas pages.
+    cache: Cache,
 
-    /// Glyphon renderer
-    renderer: GlyphonRenderer,
+    /// Target format new atlas pages render to.
+    format: wgpu::TextureFormat,
+
+    /// Glyph atlas pages; page 0 always exists. See the module docs.
+    pages: Vec<GlyphAtlasPage>,
+
+    /// Text areas each page prepared in the most recent text pass.
+    page_text_areas: Vec<usize>,
+
+    /// Text areas skipped because no atlas page could hold them.
+    dropped_text_areas: u64,
 
     /// Viewport (manages resolution and transforms)
     viewport: Viewport,
@@ -443,20 +576,17 @@ pub fn new(device: &wgpu::Device, queue: &wgpu::Queue, format: wgpu::TextureForm
         font_system.with_mut(Self::ensure_fonts_available);
         let swash_cache = SwashCache::new();
         let cache = Cache::new(device);
-        let mut text_atlas = TextAtlas::new(device, queue, &cache, format);
-        let renderer = GlyphonRenderer::new(
-            &mut text_atlas,
-            device,
-            wgpu::MultisampleState::default(),
-            None,
-        );
+        let first_page = GlyphAtlasPage::new(device, queue, &cache, format, 0);
         let viewport = Viewport::new(device, &cache);
 
         Self {
             font_system,
             swash_cache,
-            text_atlas,
-            renderer,
+            cache,
+            format,
+            pages: vec![first_page],
+            page_text_areas: Vec::new(),
+            dropped_text_areas: 0,
             viewport,
             batch: Vec::new(),
             plain_cache: HashMap::new(),
@@ -726,38 +856,81 @@ pub fn render(
             bottom,
         };
 
-        // Build TextArea values by field-splitting `self` so that the
-        // immutable borrows into the two caches are disjoint from the
-        // mutable borrows `prepare` needs.  All five fields accessed here
-        // (`batch`, `plain_cache`, `rich_cache`, `renderer`, `font_system`,
-        // `text_atlas`, `viewport`, `swash_cache`) are distinct struct
-        // fields; Rust's borrow checker accepts simultaneous borrows of
-        // disjoint fields when they are named directly (not through `self`).
-        let text_areas: Vec<TextArea<'_>> = build_text_areas(
-            &self.batch,
-            &self.plain_cache,
-            &self.rich_cache,
-            full_bounds,
-        );
-
+        // Build TextArea values per page run by field-splitting `self` so
+        // that the immutable borrows into the batch and the two caches are
+        // disjoint from the mutable borrows `prepare` needs (`pages`,
+        // `swash_cache`).  Rust's borrow checker accepts simultaneous borrows
+        // of disjoint fields when they are named directly (not through `self`).
+        //
         // Clone the shared handle first so the `with_mut` lock guard is the
         // only borrow of `font_system` in play; the closure then freely takes
-        // disjoint `&mut` borrows of the renderer / atlas / swash cache
-        // (edition-2024 closures capture individual fields, not all of `self`).
+        // disjoint `&mut` borrows of the pages / swash cache (edition-2024
+        // closures capture individual fields, not all of `self`).
+        let frame = self.current_frame;
         let font_system = self.font_system.clone();
-        font_system
-            .with_mut(|font_system| {
-                self.renderer.prepare(
+        let mut prepare_error = None;
+        let plan = font_system.with_mut(|font_system| {
+            pack_text_areas(self.batch.len(), MAX_ATLAS_PAGES, |page_index, run| {
+                if page_index == self.pages.len() {
+                    tracing::debug!(page = page_index, "opening glyph atlas page");
+                    self.pages.push(GlyphAtlasPage::new(
+                        device,
+                        queue,
+                        &self.cache,
+                        self.format,
+                        frame,
+                    ));
+                }
+                let page = &mut self.pages[page_index];
+                let text_areas = build_text_areas(
+                    &self.batch[run],
+                    &self.plain_cache,
+                    &self.rich_cache,
+                    full_bounds,
+                );
+                // `AtlasFull` is glyphon's only prepare error: the run's glyphs
+                // do not fit even at the maximum texture size. Glyphs the failed
+                // attempt cached stay marked in use until the frame-end trim.
+                let result = page.renderer.prepare(
                     device,
                     queue,
                     font_system,
-                    &mut self.text_atlas,
+                    &mut page.atlas,
                     &self.viewport,
                     text_areas,
                     &mut self.swash_cache,
-                )
+                );
+                match result {
+                    Ok(()) => {
+                        page.last_used_frame = frame;
+                        true
+                    }
+                    Err(err) => {
+                        let err = crate::error::EngineError::text_prepare(err);
+                        tracing::debug!(
+                            page = page_index,
+                            error = %err,
+                            "text run overflowed atlas page"
+                        );
+                        prepare_error = Some(err);
+                        false
+                    }
+                }
             })
-            .map_err(crate::error::EngineError::text_prepare)?;
+        });
+
+        if plan.dropped > 0 {
+            self.dropped_text_areas += plan.dropped as u64;
+            // A run only goes unplaced after `prepare` rejected it.
+            let error = prepare_error.map(|err| err.to_string()).unwrap_or_default();
+            tracing::warn!(
+                dropped = plan.dropped,
+                pages = self.pages.len(),
+                %error,
+                "glyph atlas pages exhausted; skipping text areas"
+            );
+        }
+        self.page_text_areas = plan.runs.iter().map(ExactSizeIterator::len).collect();
 
         let mut text_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
             label: Some("Text Render Pass"),
@@ -776,9 +949,11 @@ pub fn render(
             multiview_mask: None,
         });
 
-        self.renderer
-            .render(&self.text_atlas, &self.viewport, &mut text_pass)
-            .map_err(crate::error::EngineError::text_render)?;
+        for page in &self.pages[..plan.runs.len()] {
+            page.renderer
+                .render(&page.atlas, &self.viewport, &mut text_pass)
+                .map_err(crate::error::EngineError::text_render)?;
+        }
 
         self.batch.clear();
 
@@ -806,8 +981,23 @@ pub fn render(
     /// runs multiple times per frame for backdrop-filter flushes).  The single
     /// correct caller is `WgpuPainter::end_frame_maintenance`, the same
     /// once-per-frame seam that drives texture-cache maintenance.
+    ///
+    /// The same seam frees trailing atlas pages idle for more than
+    /// [`PAGE_IDLE_FRAMES`] frames (pages fill in order, so idle pages are
+    /// always the trailing ones); page 0 is never freed.
     pub(crate) fn atlas_trim(&mut self) {
-        self.text_atlas.trim();
+        for page in &mut self.pages {
+            page.atlas.trim();
+        }
+        while self.pages.len() > 1
+            && self.pages.last().is_some_and(|page| {
+                self.current_frame.saturating_sub(page.last_used_frame) > PAGE_IDLE_FRAMES
+            })
+        {
+            self.pages.pop();
+            tracing::debug!(pages = self.pages.len(), "freed idle glyph atlas page");
+        }
+        self.page_text_areas.truncate(self.pages.len());
     }
 
     // ------------------------------------------------------------------
@@ -820,6 +1010,15 @@ pub fn text_count(&self) -> usize {
         self.batch.len()
     }
 
+    /// Returns glyph-atlas page occupancy.
+    pub fn atlas_stats(&self) -> GlyphAtlasStats {
+        GlyphAtlasStats {
+            pages: self.pages.len(),
+            text_areas_per_page: self.page_text_areas.clone(),
+            dropped_text_areas: self.dropped_text_areas,
+        }
+    }
+
     /// Returns `(hits, misses, plain_cache_size, rich_cache_size)`.
     #[allow(dead_code)] // exposed for diagnostics / tests
     pub fn cache_stats(&self) -> (u64, u64, usize, usize) {
@@ -840,7 +1039,7 @@ pub fn cache_stats(&self) -> (u64, u64, usize, usize) {
 ///
 /// Extracted as a free function so that `render` can simultaneously hold
 /// immutable borrows into `plain_cache` / `rich_cache` AND mutable borrows
-/// into `font_system` / `text_atlas` / `swash_cache` — all disjoint
+/// into `font_system` / `pages` / `swash_cache` — all disjoint
 /// `TextRenderer` fields.  The borrow checker accepts this when the borrows
 /// are named at the call site rather than going through `&mut self`.
 ///
@@ -892,7 +1091,9 @@ fn build_text_areas<'cache>(
 
 #[cfg(test)]
 mod tests {
-    use super::collect_styled_spans;
+    use std::ops::Range;
+
+    use super::{PagePlan, collect_styled_spans, pack_text_areas};
     use flui_types::typography::{FontWeight, InlineSpan, TextSpan, TextStyle};
 
     /// A bold child of a sized parent must carry both bold weight and the
@@ -1135,6 +1336,46 @@ fn rich_cache_key_distinguishes_wrap_width() {
         );
         assert_eq!(key(None), key(None), "None must be stable");
     }
+
+    /// Capacity model for [`pack_text_areas`]: area `i` needs `costs[i]` atlas
+    /// units and every page holds `capacity`.
+    fn pack(costs: &[usize], capacity: usize, max_pages: usize) -> PagePlan {
+        pack_text_areas(costs.len(), max_pages, |_, run: Range<usize>| {
+            costs[run].iter().sum::<usize>() <= capacity
+        })
+    }
+
+    #[test]
+    fn pack_text_areas_keeps_everything_on_one_page_when_it_fits() {
+        let plan = pack(&[3, 3, 3], 10, 4);
+        assert_eq!(plan.runs, vec![0..3]);
+        assert_eq!(plan.dropped, 0);
+        assert_eq!(pack(&[], 10, 4), PagePlan::default());
+    }
+
+    #[test]
+    fn pack_text_areas_spills_the_remainder_onto_further_pages() {
+        // Page 0: 0..5 overflows, halves to 0..2. Page 1: 2..5 overflows,
+        // halves to 2..3. Page 2: 3..5 fits.
+        let plan = pack(&[4, 4, 4, 4, 4], 10, 4);
+        assert_eq!(plan.runs, vec![0..2, 2..3, 3..5]);
+        assert_eq!(plan.dropped, 0);
+        let covered: usize = plan.runs.iter().map(ExactSizeIterator::len).sum();
+        assert_eq!(covered, 5, "every area lands on exactly one page");
+    }
+
+    #[test]
+    fn pack_text_areas_drops_oversized_areas_and_the_overflow_past_max_pages() {
+        // Area 1 exceeds a page on its own; it is dropped and packing resumes.
+        let plan = pack(&[2, 20, 2], 10, 4);
+        assert_eq!(plan.runs, vec![0..1, 2..3]);
+        assert_eq!(plan.dropped, 1);
+
+        // Two pages of one area each; the third area has nowhere to go.
+        let plan = pack(&[8, 8, 8], 10, 2);
+        assert_eq!(plan.runs, vec![0..1, 1..2]);
+        assert_eq!(plan.dropped, 1);
+    }
 }
 
 #[cfg(all(test, feature = "enable-wgpu-tests"))]
@@ -1150,6 +1391,10 @@ mod gpu_tests {
     const H: u32 = 32;
 
     fn device_queue() -> (Arc<wgpu::Device>, Arc<wgpu::Queue>) {
+        device_queue_with_limits(wgpu::Limits::default())
+    }
+
+    fn device_queue_with_limits(limits: wgpu::Limits) -> (Arc<wgpu::Device>, Arc<wgpu::Queue>) {
         let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle());
         let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
             power_preference: wgpu::PowerPreference::LowPower,
@@ -1159,6 +1404,7 @@ fn device_queue() -> (Arc<wgpu::Device>, Arc<wgpu::Queue>) {
         .expect("a GPU adapter must be available on a GPU-enabled test host");
         let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
             label: Some("TextRenderer Test Device"),
+            required_limits: limits,
             ..Default::default()
         }))
         .expect("GPU device creation succeeded when adapter was found");
@@ -1327,4 +1573,55 @@ fn atlas_trim_each_frame_keeps_text_rendering() {
              composite (found {lit} non-transparent pixels)"
         );
     }
+
+    /// A frame with more unique glyphs than one atlas page can hold (the device
+    /// caps textures at 256², glyphon's initial atlas size, so pages cannot
+    /// grow) spills onto further pages instead of failing, and text areas on
+    /// the later pages still composite with UVs from their own page.
+    #[test]
+    fn glyphs_beyond_one_page_spill_onto_further_pages() {
+        let (device, queue) = device_queue_with_limits(wgpu::Limits {
+            max_texture_dimension_2d: 256,
+            ..wgpu::Limits::default()
+        });
+        let (target, view) = make_target(&device);
+        let mut tr = TextRenderer::new(&device, &queue, FORMAT);
+        let red = Color::rgba(255, 0, 0, 255);
+        let green = Color::rgba(0, 255, 0, 255);
+
+        // 94 printable ASCII glyphs at two large sizes: far more glyph area
+        // than a single 256² page, in small areas so runs split cleanly.
+        let ascii: Vec<char> = ('!'..='~').collect();
+        let mut areas = 0;
+        for size in [40.0, 44.0] {
+            for chunk in ascii.chunks(12) {
+                let text: String = chunk.iter().collect();
+                tr.add_text(&text, Point::new(Pixels(0.0), Pixels(0.0)), size, red);
+                areas += 1;
+            }
+        }
+        // Drawn last, so it lands on the final page and paints over the red.
+        tr.add_text("HH", Point::new(Pixels(4.0), Pixels(4.0)), 20.0, green);
+        areas += 1;
+
+        render_one(&mut tr, &device, &queue, &view);
+
+        let stats = tr.atlas_stats();
+        assert!(
+            stats.pages >= 2,
+            "the glyph set must overflow page 0, got {stats:?}"
+        );
+        assert_eq!(stats.dropped_text_areas, 0, "{stats:?}");
+        assert_eq!(
+            stats.text_areas_per_page.iter().sum::<usize>(),
+            areas,
+            "every text area is prepared on exactly one page"
+        );
+
+        let pixels = readback(&device, &queue, &target);
+        assert!(
+            pixels.iter().any(|p| p[1] > 200 && p[0] < 50),
+            "the green area prepared on a spill page must composite"
+        );
+    }
 }
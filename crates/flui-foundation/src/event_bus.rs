@@ -0,0 +1,349 @@
+//! `EventBus` — typed, in-process publish/subscribe.
+//!
+//! Inherited widgets carry data *down* the tree; an `EventBus` carries events
+//! *sideways* between parts of an app that should not know about each other
+//! (e.g. a network layer announcing "session expired" to whichever screens
+//! care). Events are routed by their Rust type: publishing an `E` only reaches
+//! handlers subscribed to exactly `E`.
+//!
+//! Handlers run synchronously on the publishing thread, in registration
+//! order. A [`Subscription`] unsubscribes its handler when dropped.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::sync::{
+//!     Arc,
+//!     atomic::{AtomicU32, Ordering},
+//! };
+//!
+//! use flui_foundation::EventBus;
+//!
+//! struct SessionExpired;
+//!
+//! let bus = EventBus::new();
+//! let expired = Arc::new(AtomicU32::new(0));
+//! let expired2 = Arc::clone(&expired);
+//! let subscription = bus.subscribe(move |_: &SessionExpired| {
+//!     expired2.fetch_add(1, Ordering::Relaxed);
+//! });
+//!
+//! assert_eq!(bus.publish(SessionExpired), 1);
+//! drop(subscription);
+//! assert_eq!(bus.publish(SessionExpired), 0);
+//! assert_eq!(expired.load(Ordering::Relaxed), 1);
+//! ```
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt,
+    hash::{BuildHasher, BuildHasherDefault, DefaultHasher},
+    panic::{AssertUnwindSafe, catch_unwind},
+    sync::{
+        Arc, Weak,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use parking_lot::RwLock;
+
+/// Number of independently locked handler tables. Publishing or subscribing
+/// to one event type only contends with types that hash to the same shard.
+const SHARD_COUNT: usize = 16;
+
+/// Type-erased handler; downcasts the event back to its concrete type.
+type Handler = Arc<dyn Fn(&dyn Any) + Send + Sync + 'static>;
+
+/// Handlers for one shard, keyed by event type, each list in registration
+/// order (subscription ids are monotonically increasing).
+type Shard = RwLock<HashMap<TypeId, Vec<(u64, Handler)>>>;
+
+struct Inner {
+    shards: [Shard; SHARD_COUNT],
+    next_id: AtomicU64,
+}
+
+impl Inner {
+    fn shard(&self, type_id: TypeId) -> &Shard {
+        // `TypeId` hashes are stable within a process, which is all routing
+        // needs. The cast only keeps the low bits, which is the intent.
+        #[allow(clippy::cast_possible_truncation)]
+        let index = BuildHasherDefault::<DefaultHasher>::default().hash_one(type_id) as usize;
+        &self.shards[index % SHARD_COUNT]
+    }
+
+    fn unsubscribe(&self, type_id: TypeId, id: u64) {
+        let mut shard = self.shard(type_id).write();
+        if let Some(handlers) = shard.get_mut(&type_id) {
+            handlers.retain(|(handler_id, _)| *handler_id != id);
+            if handlers.is_empty() {
+                shard.remove(&type_id);
+            }
+        }
+    }
+}
+
+/// A typed publish/subscribe hub.
+///
+/// Cloning an `EventBus` is cheap and yields a handle to the *same* bus, so
+/// it can be handed to every subsystem that publishes or listens.
+///
+/// Publishing snapshots the handler list and releases the lock before calling
+/// out, so a handler may subscribe, unsubscribe or publish again without
+/// deadlocking; such changes take effect from the next publish. A panicking
+/// handler is logged via `tracing::error!` and does not stop the remaining
+/// handlers.
+#[derive(Clone)]
+pub struct EventBus {
+    inner: Arc<Inner>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (event_types, handlers) = self
+            .inner
+            .shards
+            .iter()
+            .map(|shard| {
+                let shard = shard.read();
+                (shard.len(), shard.values().map(Vec::len).sum::<usize>())
+            })
+            .fold((0, 0), |(t, h), (st, sh)| (t + st, h + sh));
+        f.debug_struct("EventBus")
+            .field("event_types", &event_types)
+            .field("handlers", &handlers)
+            .finish()
+    }
+}
+
+impl EventBus {
+    /// Creates a bus with no subscribers.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                shards: std::array::from_fn(|_| RwLock::new(HashMap::new())),
+                next_id: AtomicU64::new(1),
+            }),
+        }
+    }
+
+    /// Registers `handler` for events of type `E`.
+    ///
+    /// The handler stays registered for as long as the returned
+    /// [`Subscription`] is alive.
+    #[must_use = "dropping the Subscription immediately unsubscribes the handler"]
+    pub fn subscribe<E, F>(&self, handler: F) -> Subscription
+    where
+        E: 'static + Send + Sync,
+        F: Fn(&E) + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<E>();
+        let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        let erased: Handler = Arc::new(move |event: &dyn Any| {
+            if let Some(event) = event.downcast_ref::<E>() {
+                handler(event);
+            }
+        });
+        self.inner
+            .shard(type_id)
+            .write()
+            .entry(type_id)
+            .or_default()
+            .push((id, erased));
+
+        Subscription {
+            bus: Arc::downgrade(&self.inner),
+            type_id,
+            id,
+            event_type: std::any::type_name::<E>(),
+        }
+    }
+
+    /// Delivers `event` to every handler subscribed to `E`, in registration
+    /// order. Returns how many handlers were invoked.
+    pub fn publish<E>(&self, event: E) -> usize
+    where
+        E: 'static + Send + Sync,
+    {
+        let type_id = TypeId::of::<E>();
+        let snapshot: smallvec::SmallVec<[Handler; 4]> =
+            match self.inner.shard(type_id).read().get(&type_id) {
+                Some(handlers) => handlers.iter().map(|(_, h)| Arc::clone(h)).collect(),
+                None => return 0,
+            };
+
+        for handler in &snapshot {
+            if let Err(payload) = catch_unwind(AssertUnwindSafe(|| handler(&event))) {
+                tracing::error!(
+                    event_type = std::any::type_name::<E>(),
+                    panic_payload = ?payload,
+                    "EventBus handler panicked; continuing with remaining handlers"
+                );
+            }
+        }
+        snapshot.len()
+    }
+
+    /// Returns how many handlers are currently subscribed to `E`.
+    #[must_use]
+    pub fn subscriber_count<E: 'static>(&self) -> usize {
+        let type_id = TypeId::of::<E>();
+        self.inner
+            .shard(type_id)
+            .read()
+            .get(&type_id)
+            .map_or(0, Vec::len)
+    }
+}
+
+/// Handle for a handler registered with [`EventBus::subscribe`].
+///
+/// Dropping it unsubscribes the handler. It only holds a weak reference to
+/// the bus, so it never keeps a bus alive and is safe to drop after the bus.
+#[must_use = "dropping the Subscription immediately unsubscribes the handler"]
+pub struct Subscription {
+    bus: Weak<Inner>,
+    type_id: TypeId,
+    id: u64,
+    event_type: &'static str,
+}
+
+impl fmt::Debug for Subscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subscription")
+            .field("event_type", &self.event_type)
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(bus) = self.bus.upgrade() {
+            bus.unsubscribe(self.type_id, self.id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use parking_lot::Mutex;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Ping(u32);
+
+    #[derive(Debug)]
+    struct Reset;
+
+    #[test]
+    fn subscribe_publish_and_unsubscribe_on_drop() {
+        let bus = EventBus::new();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen2 = Arc::clone(&seen);
+        let subscription = bus.subscribe(move |ping: &Ping| seen2.lock().push(ping.0));
+
+        assert_eq!(bus.publish(Ping(1)), 1);
+        assert_eq!(bus.publish(Ping(2)), 1);
+        assert_eq!(*seen.lock(), vec![1, 2]);
+
+        drop(subscription);
+        assert_eq!(bus.subscriber_count::<Ping>(), 0);
+        assert_eq!(bus.publish(Ping(3)), 0);
+        assert_eq!(*seen.lock(), vec![1, 2]);
+    }
+
+    #[test]
+    fn handlers_run_in_registration_order() {
+        let bus = EventBus::new();
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let subscriptions: Vec<Subscription> = (0..5)
+            .map(|i| {
+                let order = Arc::clone(&order);
+                bus.subscribe(move |_: &Ping| order.lock().push(i))
+            })
+            .collect();
+
+        bus.publish(Ping(0));
+        assert_eq!(*order.lock(), vec![0, 1, 2, 3, 4]);
+        drop(subscriptions);
+    }
+
+    #[test]
+    fn event_types_are_isolated() {
+        let bus = EventBus::new();
+        let ping_hits = Arc::new(AtomicUsize::new(0));
+        let reset_hits = Arc::new(AtomicUsize::new(0));
+        let (ping_counter, reset_counter) = (Arc::clone(&ping_hits), Arc::clone(&reset_hits));
+        let _ping_sub = bus.subscribe(move |_: &Ping| {
+            ping_counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let _reset_sub = bus.subscribe(move |_: &Reset| {
+            reset_counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        bus.publish(Ping(7));
+        assert_eq!(ping_hits.load(Ordering::SeqCst), 1);
+        assert_eq!(reset_hits.load(Ordering::SeqCst), 0);
+
+        bus.publish(Reset);
+        bus.publish(Reset);
+        assert_eq!(ping_hits.load(Ordering::SeqCst), 1);
+        assert_eq!(reset_hits.load(Ordering::SeqCst), 2);
+
+        assert_eq!(bus.publish("unsubscribed type"), 0);
+    }
+
+    #[test]
+    fn handler_may_subscribe_and_publish_while_handling() {
+        let bus = EventBus::new();
+        let subscriptions = Arc::new(Mutex::new(Vec::new()));
+        let hits = Arc::new(AtomicUsize::new(0));
+
+        let (bus2, subs2, hits2) = (bus.clone(), Arc::clone(&subscriptions), Arc::clone(&hits));
+        let _sub = bus.subscribe(move |_: &Ping| {
+            let hits = Arc::clone(&hits2);
+            subs2.lock().push(bus2.subscribe(move |_: &Reset| {
+                hits.fetch_add(1, Ordering::SeqCst);
+            }));
+            bus2.publish(Reset);
+        });
+
+        assert_eq!(bus.publish(Ping(0)), 1);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+        assert_eq!(bus.subscriber_count::<Reset>(), 1);
+    }
+
+    #[test]
+    fn panicking_handler_does_not_stop_the_rest() {
+        let bus = EventBus::new();
+        let reached = Arc::new(AtomicUsize::new(0));
+        let reached2 = Arc::clone(&reached);
+        let _bad = bus.subscribe(|_: &Ping| panic!("handler failure"));
+        let _good = bus.subscribe(move |_: &Ping| {
+            reached2.fetch_add(1, Ordering::SeqCst);
+        });
+
+        assert_eq!(bus.publish(Ping(0)), 2);
+        assert_eq!(reached.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn subscription_may_outlive_the_bus() {
+        let bus = EventBus::new();
+        let subscription = bus.subscribe(|_: &Ping| {});
+        drop(bus);
+        drop(subscription);
+    }
+}
@@ -686,7 +686,7 @@ impl super::Marker for $gmarker {}
 
         /// Observer ID - identifier for registered observers.
         ///
-        /// Used by `ObserverList` to track registered observers.
+        /// Used by `WeakObserverList` to track registered observers.
         pub type ObserverId Observer;
 
         /// Frame Callback ID - scheduler frame callback identifier.
@@ -164,6 +164,10 @@
 
 // Reactive programming - change notification and observables
 pub mod notifier;
+pub mod observer;
+
+// Typed pub/sub for decoupled cross-widget messaging
+pub mod event_bus;
 
 // Generic typed notification channel + unified listener registry
 pub mod listener_registry;
@@ -192,6 +196,8 @@
 pub use consts::{DEBUG_MODE, EPSILON, EPSILON_F32, IS_DESKTOP, IS_MOBILE, IS_WEB, RELEASE_MODE};
 // Window-runtime generation/version counters + commit-time freshness gate.
 pub use epoch::{FrameEpoch, GenerationGate, ResourceGeneration, SurfaceGeneration};
+// Typed pub/sub
+pub use event_bus::{EventBus, Subscription};
 // Diagnostics
 pub use debug::{
     DebugPaintConfig, DiagnosticLevel, Diagnosticable, DiagnosticsBuilder, DiagnosticsNode,
@@ -226,7 +232,10 @@
 };
 pub use key::{Key, KeyRef, Keyed, UniqueKey, ValueKey, ViewKey, WithKey};
 // Change notification (Listenable pattern)
-pub use notifier::{ChangeNotifier, Listenable, ListenerCallback, ValueListenable, ValueNotifier};
+pub use notifier::{
+    ChangeNotifier, Listenable, ListenerCallback, MergedListenable, ValueListenable, ValueNotifier,
+};
+pub use observer::WeakObserverList;
 // Generic typed channel + unified listener registry
 pub use listener_registry::{ListenerRegistry, ListenerSubscription};
 pub use notifier_generic::{ArgCallback, Notifier};
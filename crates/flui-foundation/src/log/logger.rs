@@ -1,5 +1,25 @@
+use std::sync::OnceLock;
+
 use tracing::Level;
-use tracing_subscriber::util::{SubscriberInitExt, TryInitError};
+use tracing_subscriber::{
+    EnvFilter, Registry,
+    filter::{LevelFilter, ParseError},
+    layer::Layered,
+    reload,
+    util::{SubscriberInitExt, TryInitError},
+};
+
+/// Reload handle for the `EnvFilter`, the first layer on the registry.
+type EnvFilterHandle = reload::Handle<EnvFilter, Registry>;
+
+/// Reload handle for the desktop level cap, layered on top of the filter.
+#[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+type LevelCapHandle =
+    reload::Handle<LevelFilter, Layered<reload::Layer<EnvFilter, Registry>, Registry>>;
+
+/// Handle installed by the first successful [`Logger::try_init`]; backs
+/// [`set_filter`].
+static GLOBAL_FILTER: OnceLock<LogFilterHandle> = OnceLock::new();
 
 /// Error returned when logging initialization fails.
 ///
@@ -27,6 +47,103 @@ fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
     }
 }
 
+/// Error returned when swapping the log filter at runtime fails.
+///
+/// On error the previously installed filter stays active.
+#[derive(Debug)]
+pub enum FilterError {
+    /// The filter string is not valid `EnvFilter` syntax.
+    Parse(ParseError),
+    /// The subscriber that owned the filter has been dropped.
+    Reload(reload::Error),
+    /// [`set_filter`] was called before a [`Logger`] was initialized.
+    NotInitialized,
+}
+
+impl core::fmt::Display for FilterError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "invalid log filter: {err}"),
+            Self::Reload(err) => write!(f, "failed to reload log filter: {err}"),
+            Self::NotInitialized => write!(f, "logger has not been initialized"),
+        }
+    }
+}
+
+impl std::error::Error for FilterError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(err) => Some(err),
+            Self::Reload(err) => Some(err),
+            Self::NotInitialized => None,
+        }
+    }
+}
+
+/// Handle for replacing the active log filter without reinitializing.
+///
+/// Returned by [`Logger::init`] / [`Logger::try_init`]. Cheap to clone and
+/// safe to share across threads, so devtools can bump a module to `trace`
+/// while the app is running:
+///
+/// ```rust,no_run
+/// use flui_foundation::log::Logger;
+///
+/// let handle = Logger::default().init();
+/// handle
+///     .set_filter("info,flui_rendering=trace")
+///     .expect("valid filter");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LogFilterHandle {
+    filter: EnvFilterHandle,
+    #[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+    level_cap: LevelCapHandle,
+}
+
+impl LogFilterHandle {
+    /// Replace the active filter with `filter` (`EnvFilter` syntax).
+    ///
+    /// The new filter becomes the sole authority: the level passed to
+    /// [`Logger::with_level`] no longer caps output. An invalid string is
+    /// rejected and the old filter kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FilterError::Parse`] for invalid syntax and
+    /// [`FilterError::Reload`] if the subscriber is gone.
+    pub fn set_filter(&self, filter: &str) -> Result<(), FilterError> {
+        let filter = EnvFilter::try_new(filter).map_err(FilterError::Parse)?;
+        #[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+        self.level_cap
+            .reload(LevelFilter::TRACE)
+            .map_err(FilterError::Reload)?;
+        self.filter.reload(filter).map_err(FilterError::Reload)
+    }
+
+    /// The active filter, formatted in `EnvFilter` syntax.
+    #[must_use]
+    pub fn current_filter(&self) -> Option<String> {
+        self.filter.with_current(ToString::to_string).ok()
+    }
+}
+
+/// Replace the filter of the globally initialized [`Logger`].
+///
+/// Equivalent to calling [`LogFilterHandle::set_filter`] on the handle
+/// returned by the first successful [`Logger::try_init`].
+///
+/// # Errors
+///
+/// Returns [`FilterError::NotInitialized`] if no logger has been
+/// initialized, otherwise as [`LogFilterHandle::set_filter`].
+pub fn set_filter(filter: &str) -> Result<(), FilterError> {
+    GLOBAL_FILTER
+        .get()
+        .ok_or(FilterError::NotInitialized)?
+        .set_filter(filter)
+}
+
 /// Cross-platform logging for FLUI
 ///
 /// Automatically configures the appropriate logging backend for each platform:
@@ -147,6 +264,10 @@ pub struct Logger {
     /// Automatically enabled in debug builds if feature is present.
     #[cfg(feature = "pretty")]
     use_pretty: bool,
+
+    /// In-memory buffer of recent records, composed alongside the backend.
+    #[cfg(feature = "ring-buffer")]
+    ring_buffer: super::RingBufferHandle,
 }
 
 impl Default for Logger {
@@ -157,6 +278,8 @@ fn default() -> Self {
             level: Level::INFO,
             #[cfg(feature = "pretty")]
             use_pretty: cfg!(debug_assertions), // auto-enable in debug mode
+            #[cfg(feature = "ring-buffer")]
+            ring_buffer: super::RingBufferHandle::new(super::DEFAULT_RING_BUFFER_CAPACITY),
         }
     }
 }
@@ -237,6 +360,34 @@ pub fn use_pretty(&self) -> bool {
         self.use_pretty
     }
 
+    /// Handle to the in-memory buffer of recent log records.
+    ///
+    /// Grab it before [`init`](Self::init) to show logs in a debug overlay.
+    /// Only available with the "ring-buffer" feature flag.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(feature = "ring-buffer")]
+    /// # {
+    /// use flui_foundation::log::Logger;
+    ///
+    /// let logger = Logger::new().with_ring_buffer_capacity(100);
+    /// let recent = logger.ring_buffer();
+    /// logger.init();
+    ///
+    /// for record in recent.iter() {
+    ///     println!("{} {}: {}", record.level, record.target, record.message);
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "ring-buffer")]
+    #[inline]
+    #[must_use]
+    pub fn ring_buffer(&self) -> super::RingBufferHandle {
+        self.ring_buffer.clone()
+    }
+
     /// Set the application name
     ///
     /// The application name is used for platform-specific logging:
@@ -310,6 +461,23 @@ pub fn with_pretty(mut self, pretty: bool) -> Self {
         self
     }
 
+    /// Keep the most recent `capacity` records in the ring buffer.
+    ///
+    /// Replaces the buffer, so call this before taking
+    /// [`ring_buffer`](Self::ring_buffer). Only available with the
+    /// "ring-buffer" feature flag.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    #[cfg(feature = "ring-buffer")]
+    #[inline]
+    #[must_use]
+    pub fn with_ring_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.ring_buffer = super::RingBufferHandle::new(capacity);
+        self
+    }
+
     /// Initialize the logging system
     ///
     /// This should be called once at application startup, before any logging
@@ -329,6 +497,8 @@ pub fn with_pretty(mut self, pretty: bool) -> Self {
     /// Panics if the global tracing subscriber has already been set.
     /// Use [`try_init`](Self::try_init) for a non-panicking alternative.
     ///
+    /// Returns a [`LogFilterHandle`] for changing the filter at runtime.
+    ///
     /// # Examples
     ///
     /// ```rust,no_run
@@ -340,8 +510,9 @@ pub fn with_pretty(mut self, pretty: bool) -> Self {
     /// // With custom config
     /// Logger::new().with_filter("debug,wgpu=error").init();
     /// ```
-    pub fn init(&self) {
-        self.try_init().expect("Failed to initialize logger");
+    #[allow(clippy::must_use_candidate)] // most callers only want the side effect
+    pub fn init(&self) -> LogFilterHandle {
+        self.try_init().expect("Failed to initialize logger")
     }
 
     /// Try to initialize the logging system, returning an error on failure.
@@ -368,29 +539,44 @@ pub fn init(&self) {
     ///     .try_init()
     ///     .expect("logging already initialized");
     /// ```
-    pub fn try_init(&self) -> Result<(), InitError> {
-        use tracing_subscriber::{Registry, layer::SubscriberExt};
-
-        // Create filter layer from environment or config
-        let filter_layer = tracing_subscriber::EnvFilter::try_from_default_env()
-            .or_else(|_| tracing_subscriber::EnvFilter::try_new(&self.filter))
-            .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    pub fn try_init(&self) -> Result<LogFilterHandle, InitError> {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        // Create filter layer from environment or config, reloadable so the
+        // returned handle can swap it later.
+        let filter = EnvFilter::try_from_default_env()
+            .or_else(|_| EnvFilter::try_new(&self.filter))
+            .unwrap_or_else(|_| EnvFilter::new("info"));
+        let (filter_layer, filter_handle) = reload::Layer::new(filter);
+
+        // Optional in-memory capture, filtered like the backend.
+        #[cfg(feature = "ring-buffer")]
+        let ring_layer = Some(self.ring_buffer.layer());
+        #[cfg(not(feature = "ring-buffer"))]
+        let ring_layer: Option<tracing_subscriber::layer::Identity> = None;
 
         // === DESKTOP (not Android/iOS/WASM) ===
         #[cfg(not(any(target_os = "android", target_os = "ios", target_arch = "wasm32")))]
         {
             // Pretty hierarchical logging with tracing-forest (if feature enabled)
+            let (level_layer, level_cap) = reload::Layer::new(LevelFilter::from_level(self.level));
+            let handle = LogFilterHandle {
+                filter: filter_handle,
+                level_cap,
+            };
+
             #[cfg(feature = "pretty")]
             if self.use_pretty {
-                use tracing_forest::{ForestLayer, util::LevelFilter};
+                use tracing_forest::ForestLayer;
 
                 let subscriber = Registry::default()
                     .with(filter_layer)
-                    .with(LevelFilter::from_level(self.level))
+                    .with(level_layer)
+                    .with(ring_layer)
                     .with(ForestLayer::default());
 
                 subscriber.try_init()?;
-                return Ok(());
+                return Ok(GLOBAL_FILTER.get_or_init(|| handle).clone());
             }
 
             // Standard fmt layer for desktop (fallback or when "pretty" not enabled)
@@ -401,12 +587,12 @@ pub fn try_init(&self) -> Result<(), InitError> {
 
             let subscriber = Registry::default()
                 .with(filter_layer)
-                .with(tracing_subscriber::filter::LevelFilter::from_level(
-                    self.level,
-                ))
+                .with(level_layer)
+                .with(ring_layer)
                 .with(fmt_layer);
 
             subscriber.try_init()?;
+            Ok(GLOBAL_FILTER.get_or_init(|| handle).clone())
         }
 
         // === ANDROID ===
@@ -414,11 +600,19 @@ pub fn try_init(&self) -> Result<(), InitError> {
         {
             let android_layer = crate::log::android_layer::AndroidLayer::new(&self.app_name);
 
-            let subscriber = Registry::default().with(filter_layer).with(android_layer);
+            let subscriber = Registry::default()
+                .with(filter_layer)
+                .with(ring_layer)
+                .with(android_layer);
 
             subscriber.try_init()?;
 
             tracing::info!("Logging initialized (Android/logcat)");
+            Ok(GLOBAL_FILTER
+                .get_or_init(|| LogFilterHandle {
+                    filter: filter_handle,
+                })
+                .clone())
         }
 
         // === iOS ===
@@ -429,11 +623,19 @@ pub fn try_init(&self) -> Result<(), InitError> {
             let subsystem = format!("com.{}.app", self.app_name);
             let os_logger = OsLogger::new(&subsystem, "default");
 
-            let subscriber = Registry::default().with(filter_layer).with(os_logger);
+            let subscriber = Registry::default()
+                .with(filter_layer)
+                .with(ring_layer)
+                .with(os_logger);
 
             subscriber.try_init()?;
 
             tracing::info!("Logging initialized (iOS/os_log)");
+            Ok(GLOBAL_FILTER
+                .get_or_init(|| LogFilterHandle {
+                    filter: filter_handle,
+                })
+                .clone())
         }
 
         // === WASM ===
@@ -447,14 +649,20 @@ pub fn try_init(&self) -> Result<(), InitError> {
                     .build(),
             );
 
-            let subscriber = Registry::default().with(filter_layer).with(wasm_layer);
+            let subscriber = Registry::default()
+                .with(filter_layer)
+                .with(ring_layer)
+                .with(wasm_layer);
 
             subscriber.try_init()?;
 
             tracing::info!("Logging initialized (WASM/browser console)");
+            Ok(GLOBAL_FILTER
+                .get_or_init(|| LogFilterHandle {
+                    filter: filter_handle,
+                })
+                .clone())
         }
-
-        Ok(())
     }
 }
 
@@ -57,6 +57,11 @@
 //! // └─ INFO ｉ Ready
 //! ```
 //!
+//! # In-App Log Viewer
+//!
+//! Enable the `"ring-buffer"` feature to keep the most recent records in
+//! memory, readable through `Logger::ring_buffer` for debug overlays.
+//!
 //! # Platform Behavior
 //!
 //! The logger automatically selects the appropriate backend:
@@ -68,6 +73,19 @@
 //! | iOS      | `tracing-oslog` | Xcode Console / Console.app |
 //! | WASM     | `tracing-wasm` | Browser `DevTools` Console |
 //!
+//! # Changing the Filter at Runtime
+//!
+//! `init` returns a [`LogFilterHandle`]; [`set_filter`] does the same through
+//! the global logger. Invalid filter strings are rejected and the old filter
+//! kept.
+//!
+//! ```rust,no_run
+//! use flui_foundation::log::{Logger, set_filter};
+//!
+//! Logger::default().init();
+//! set_filter("info,flui_rendering=trace").expect("valid filter");
+//! ```
+//!
 //! # Environment Variables
 //!
 //! The `RUST_LOG` environment variable can override the default filter:
@@ -84,8 +102,14 @@
 
 #[cfg(target_os = "android")]
 pub mod android_layer;
+#[cfg(feature = "ring-buffer")]
+mod ring_buffer;
 
-pub use logger::{InitError, Logger};
+pub use logger::{FilterError, InitError, LogFilterHandle, Logger, set_filter};
+#[cfg(feature = "ring-buffer")]
+pub use ring_buffer::{
+    DEFAULT_RING_BUFFER_CAPACITY, LogRecord, MAX_MESSAGE_LEN, RingBufferHandle, RingBufferLayer,
+};
 // Re-export common types
 pub use tracing::{Instrument, Span, event, span};
 // Re-export tracing macros for convenience
@@ -0,0 +1,484 @@
+//! In-memory ring buffer of recent log records
+//!
+//! [`RingBufferLayer`] captures formatted records into a fixed-size buffer
+//! so apps can show recent logs in a debug overlay without scraping stdout.
+//! It composes alongside the platform backend installed by
+//! [`Logger::try_init`](super::Logger::try_init); read it back through the
+//! [`RingBufferHandle`] from [`Logger::ring_buffer`](super::Logger::ring_buffer).
+//!
+//! The buffer has no global lock. Writers claim sequence numbers from an
+//! atomic counter and publish each record by stamping its slot with that
+//! sequence; readers trust a slot only when its stamp matches the sequence
+//! they want. Each slot's stamp doubles as a per-slot spinlock: its busy
+//! flag is held while a record is moved in or cloned out, so a writer (or
+//! a second reader) spins when it reaches a slot that is being copied at
+//! that instant. Cloning a record allocates, so that wait is short but not
+//! bounded by a few instructions.
+// The slots hand out `&mut` access to their records through `UnsafeCell`,
+// guarded by the stamp's busy flag acting as a per-slot spinlock.
+#![expect(
+    unsafe_code,
+    reason = "ring buffer slots guard their records with a spinlock bit in an atomic stamp"
+)]
+
+use std::{
+    cell::UnsafeCell,
+    fmt::{self, Write as _},
+    hint,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::SystemTime,
+};
+
+use tracing::{
+    Event, Level, Subscriber,
+    field::{Field, Visit},
+};
+use tracing_subscriber::{Layer, layer::Context};
+
+/// Default number of records kept by [`Logger`](super::Logger).
+pub const DEFAULT_RING_BUFFER_CAPACITY: usize = 512;
+
+/// Messages and field values longer than this many bytes are truncated.
+pub const MAX_MESSAGE_LEN: usize = 1024;
+
+/// A captured log record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogRecord {
+    /// Severity of the event.
+    pub level: Level,
+    /// Event target (usually the module path).
+    pub target: String,
+    /// Wall-clock time the event was recorded.
+    pub timestamp: SystemTime,
+    /// The formatted `message` field.
+    pub message: String,
+    /// Remaining fields as `(name, value)` pairs, values `Debug`-formatted.
+    pub fields: Vec<(String, String)>,
+}
+
+/// Shared handle to a ring buffer of [`LogRecord`]s.
+///
+/// Cheap to clone and safe to read from any thread while events are being
+/// recorded.
+#[derive(Clone)]
+pub struct RingBufferHandle {
+    inner: Arc<RingBuffer>,
+}
+
+/// Set in a slot's stamp while one thread has exclusive access to its
+/// record.
+const BUSY: u64 = 1 << 63;
+
+/// One record position.
+struct Slot {
+    /// One past the sequence number of the last record published here
+    /// (`0` = never written), plus [`BUSY`] while a writer or reader owns
+    /// `record`.
+    stamp: AtomicU64,
+    /// `None` once drained.
+    record: UnsafeCell<Option<LogRecord>>,
+}
+
+// SAFETY: `record` is only touched by the thread that set `BUSY` in `stamp`
+// (acquire on claim, release on hand-back), so accesses never overlap, and
+// `LogRecord` is `Send`.
+unsafe impl Sync for Slot {}
+
+/// What [`Slot::read`] found for a sequence number.
+enum SlotRead {
+    Record(LogRecord),
+    /// Overwritten by a later record, or already drained.
+    Gone,
+    /// Claimed by a writer that has not published it yet.
+    Pending,
+}
+
+impl Slot {
+    fn new() -> Self {
+        Self {
+            stamp: AtomicU64::new(0),
+            record: UnsafeCell::new(None),
+        }
+    }
+
+    /// Publish `record` as sequence number `seq`, unless a later lap has
+    /// already published a newer record here (which would overwrite it
+    /// anyway).
+    fn write(&self, seq: u64, record: LogRecord) {
+        let published = seq + 1;
+        loop {
+            let stamp = self.stamp.load(Ordering::Acquire);
+            if stamp & BUSY != 0 {
+                hint::spin_loop();
+                continue;
+            }
+            if stamp > published {
+                return;
+            }
+            if self
+                .stamp
+                .compare_exchange_weak(stamp, stamp | BUSY, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                // SAFETY: setting `BUSY` gave this thread exclusive access.
+                unsafe { *self.record.get() = Some(record) };
+                self.stamp.store(published, Ordering::Release);
+                return;
+            }
+        }
+    }
+
+    /// Clone (or, with `take`, move) out the record published as `seq`.
+    fn read(&self, seq: u64, take: bool) -> SlotRead {
+        let published = seq + 1;
+        loop {
+            let stamp = self.stamp.load(Ordering::Acquire);
+            if stamp == published | BUSY {
+                // Another reader is copying this very record.
+                hint::spin_loop();
+                continue;
+            }
+            if stamp & BUSY != 0 || stamp < published {
+                return SlotRead::Pending;
+            }
+            if stamp > published {
+                return SlotRead::Gone;
+            }
+            if self
+                .stamp
+                .compare_exchange_weak(stamp, stamp | BUSY, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                // SAFETY: setting `BUSY` gave this thread exclusive access.
+                let record = unsafe {
+                    let slot = &mut *self.record.get();
+                    if take { slot.take() } else { slot.clone() }
+                };
+                self.stamp.store(published, Ordering::Release);
+                return record.map_or(SlotRead::Gone, SlotRead::Record);
+            }
+        }
+    }
+}
+
+struct RingBuffer {
+    slots: Box<[Slot]>,
+    /// Sequence number of the next record to be written.
+    next: AtomicU64,
+    /// Records with a lower sequence number have been drained.
+    drained: AtomicU64,
+}
+
+impl RingBufferHandle {
+    /// Create an empty buffer that keeps the most recent `capacity` records.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "ring buffer capacity must be non-zero");
+        Self {
+            inner: Arc::new(RingBuffer {
+                slots: (0..capacity).map(|_| Slot::new()).collect(),
+                next: AtomicU64::new(0),
+                drained: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Maximum number of records retained.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.inner.slots.len()
+    }
+
+    /// A [`Layer`] that records events into this buffer.
+    #[must_use]
+    pub fn layer(&self) -> RingBufferLayer {
+        RingBufferLayer {
+            buffer: self.clone(),
+        }
+    }
+
+    /// Append a record, overwriting the oldest one when full.
+    pub fn push(&self, record: LogRecord) {
+        let seq = self.inner.next.fetch_add(1, Ordering::AcqRel);
+        self.inner.slots[self.slot(seq)].write(seq, record);
+    }
+
+    /// Snapshot of the retained records, oldest first.
+    ///
+    /// Records still being written by a concurrent [`push`](Self::push) are
+    /// left out.
+    #[must_use]
+    pub fn iter(&self) -> std::vec::IntoIter<LogRecord> {
+        let end = self.inner.next.load(Ordering::Acquire);
+        let records: Vec<_> = (self.start(end)..end)
+            .filter_map(
+                |seq| match self.inner.slots[self.slot(seq)].read(seq, false) {
+                    SlotRead::Record(record) => Some(record),
+                    SlotRead::Gone | SlotRead::Pending => None,
+                },
+            )
+            .collect();
+        records.into_iter()
+    }
+
+    /// Remove and return the retained records, oldest first.
+    ///
+    /// Records still being written by a concurrent [`push`](Self::push) are
+    /// skipped, not lost: they are returned by the next call.
+    #[must_use]
+    pub fn drain(&self) -> Vec<LogRecord> {
+        let end = self.inner.next.load(Ordering::Acquire);
+        let mut records = Vec::new();
+        let mut drained_to = end;
+        for seq in self.start(end)..end {
+            match self.inner.slots[self.slot(seq)].read(seq, true) {
+                SlotRead::Record(record) => records.push(record),
+                SlotRead::Gone => {}
+                SlotRead::Pending => drained_to = drained_to.min(seq),
+            }
+        }
+        self.inner.drained.fetch_max(drained_to, Ordering::AcqRel);
+        records
+    }
+
+    /// Number of records currently retained.
+    ///
+    /// Counted from the sequence numbers, so records a concurrent
+    /// [`push`](Self::push) has claimed but not yet written are included.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        let end = self.inner.next.load(Ordering::Acquire);
+        // At most `capacity`, which came from a `usize`.
+        #[allow(clippy::cast_possible_truncation)]
+        let len = end.saturating_sub(self.start(end)) as usize;
+        len
+    }
+
+    /// Returns `true` if no records are retained.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn slot(&self, seq: u64) -> usize {
+        // The remainder is below `capacity`, which came from a `usize`.
+        #[allow(clippy::cast_possible_truncation)]
+        let index = (seq % self.capacity() as u64) as usize;
+        index
+    }
+
+    /// Sequence number of the oldest retained record, given `end`, one past
+    /// the newest.
+    fn start(&self, end: u64) -> u64 {
+        end.saturating_sub(self.capacity() as u64)
+            .max(self.inner.drained.load(Ordering::Acquire))
+    }
+}
+
+impl IntoIterator for &RingBufferHandle {
+    type Item = LogRecord;
+    type IntoIter = std::vec::IntoIter<LogRecord>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl fmt::Debug for RingBufferHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RingBufferHandle")
+            .field("capacity", &self.capacity())
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// [`Layer`] that captures events into a [`RingBufferHandle`].
+#[derive(Debug, Clone)]
+pub struct RingBufferLayer {
+    buffer: RingBufferHandle,
+}
+
+impl<S: Subscriber> Layer<S> for RingBufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut visitor = RecordVisitor::default();
+        event.record(&mut visitor);
+        self.buffer.push(LogRecord {
+            level: *metadata.level(),
+            target: metadata.target().to_owned(),
+            timestamp: SystemTime::now(),
+            message: visitor.message,
+            fields: visitor.fields,
+        });
+    }
+}
+
+#[derive(Default)]
+struct RecordVisitor {
+    message: String,
+    fields: Vec<(String, String)>,
+}
+
+impl RecordVisitor {
+    fn record(&mut self, field: &Field, mut text: String) {
+        truncate(&mut text);
+        if field.name() == "message" {
+            self.message = text;
+        } else {
+            self.fields.push((field.name().to_owned(), text));
+        }
+    }
+}
+
+impl Visit for RecordVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, value.to_owned());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        let mut text = String::new();
+        let _ = write!(text, "{value:?}");
+        self.record(field, text);
+    }
+}
+
+/// Cut `text` to at most [`MAX_MESSAGE_LEN`] bytes on a char boundary,
+/// marking the cut with an ellipsis.
+fn truncate(text: &mut String) {
+    if text.len() <= MAX_MESSAGE_LEN {
+        return;
+    }
+    let mut end = MAX_MESSAGE_LEN;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    text.truncate(end);
+    text.push('…');
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing_subscriber::{Registry, layer::SubscriberExt};
+
+    use super::*;
+
+    #[test]
+    fn keeps_only_the_most_recent_records() {
+        let buffer = RingBufferHandle::new(3);
+        let subscriber = Registry::default().with(buffer.layer());
+        tracing::subscriber::with_default(subscriber, || {
+            for i in 0..5 {
+                tracing::info!(target: "app::net", attempt = i, "request {i}");
+            }
+            tracing::warn!(target: "app::ui", "frame dropped");
+        });
+
+        let records: Vec<_> = buffer.iter().collect();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].message, "request 3");
+        assert_eq!(records[0].level, Level::INFO);
+        assert_eq!(records[0].target, "app::net");
+        assert_eq!(records[0].fields, vec![("attempt".into(), "3".into())]);
+        assert_eq!(records[2].message, "frame dropped");
+        assert_eq!(records[2].level, Level::WARN);
+        assert_eq!(records[2].target, "app::ui");
+    }
+
+    #[test]
+    fn drain_empties_and_long_messages_are_truncated() {
+        let buffer = RingBufferHandle::new(4);
+        let subscriber = Registry::default().with(buffer.layer());
+        let long = "é".repeat(MAX_MESSAGE_LEN);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::error!("{long}");
+        });
+
+        let drained = buffer.drain();
+        assert_eq!(drained.len(), 1);
+        assert!(drained[0].message.len() <= MAX_MESSAGE_LEN + '…'.len_utf8());
+        assert!(drained[0].message.ends_with('…'));
+        assert!(buffer.is_empty());
+
+        buffer.push(drained[0].clone());
+        assert_eq!(buffer.len(), 1);
+    }
+
+    fn record(message: &str) -> LogRecord {
+        LogRecord {
+            timestamp: SystemTime::now(),
+            level: Level::INFO,
+            target: "test".into(),
+            message: message.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn len_counts_from_the_sequence_numbers() {
+        let buffer = RingBufferHandle::new(3);
+        assert!(buffer.is_empty());
+        for i in 0..2 {
+            buffer.push(record(&i.to_string()));
+        }
+        assert_eq!(buffer.len(), 2);
+        for i in 2..7 {
+            buffer.push(record(&i.to_string()));
+        }
+        assert_eq!(buffer.len(), 3);
+        let _ = buffer.drain();
+        assert_eq!(buffer.len(), 0);
+    }
+
+    #[test]
+    fn drain_keeps_records_that_are_still_being_written() {
+        let buffer = RingBufferHandle::new(4);
+        buffer.push(record("first"));
+        // Claim a sequence number the way `push` does, without publishing.
+        let pending = buffer.inner.next.fetch_add(1, Ordering::AcqRel);
+        buffer.push(record("third"));
+
+        let drained: Vec<_> = buffer.drain().into_iter().map(|r| r.message).collect();
+        assert_eq!(drained, ["first", "third"]);
+
+        buffer.inner.slots[buffer.slot(pending)].write(pending, record("second"));
+        let drained: Vec<_> = buffer.drain().into_iter().map(|r| r.message).collect();
+        assert_eq!(drained, ["second"]);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn concurrent_pushes_and_drains_lose_nothing() {
+        const THREADS: usize = 4;
+        const PER_THREAD: usize = 500;
+
+        let buffer = RingBufferHandle::new(THREADS * PER_THREAD);
+        let mut drained = std::thread::scope(|scope| {
+            for t in 0..THREADS {
+                let buffer = buffer.clone();
+                scope.spawn(move || {
+                    for i in 0..PER_THREAD {
+                        buffer.push(record(&format!("{t}:{i}")));
+                    }
+                });
+            }
+            let mut drained = Vec::new();
+            while drained.len() < THREADS * PER_THREAD {
+                drained.extend(buffer.drain().into_iter().map(|r| r.message));
+            }
+            drained
+        });
+
+        drained.sort();
+        drained.dedup();
+        assert_eq!(drained.len(), THREADS * PER_THREAD);
+        assert!(buffer.is_empty());
+    }
+}
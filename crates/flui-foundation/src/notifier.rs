@@ -426,6 +426,140 @@ fn remove_all_listeners(&self) {
     }
 }
 
+/// A [`Listenable`] that fires whenever any of its current sources fires.
+///
+/// Similar to Flutter's `Listenable.merge`, with a source set that can change
+/// over the merged listenable's life — e.g. one source per item in an
+/// animated list. Each source carries one forwarding listener while it is
+/// part of the set; [`remove_source`](Self::remove_source) and `Drop` take it
+/// off again, so a removed or outlived source never reaches this merge's
+/// listeners.
+///
+/// Sources are compared by identity (`Arc` pointer): adding the same source
+/// twice is a no-op, as is removing one that is not present.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::{
+///     Arc,
+///     atomic::{AtomicU32, Ordering},
+/// };
+///
+/// use flui_foundation::notifier::{ChangeNotifier, Listenable, MergedListenable};
+///
+/// let a = Arc::new(ChangeNotifier::new());
+/// let b = Arc::new(ChangeNotifier::new());
+/// let merged = MergedListenable::new([a.clone() as Arc<dyn Listenable>]);
+///
+/// let count = Arc::new(AtomicU32::new(0));
+/// let count2 = Arc::clone(&count);
+/// merged.add_listener(Arc::new(move || {
+///     count2.fetch_add(1, Ordering::Relaxed);
+/// }));
+///
+/// merged.add_source(b.clone());
+/// a.notify_listeners();
+/// b.notify_listeners();
+/// assert_eq!(count.load(Ordering::Relaxed), 2);
+/// ```
+pub struct MergedListenable {
+    /// Downstream listeners of the merge.
+    downstream: ChangeNotifier,
+    /// Current sources and the forwarding listener registered on each.
+    sources: Mutex<Vec<(Arc<dyn Listenable>, ListenerId)>>,
+}
+
+impl fmt::Debug for MergedListenable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergedListenable")
+            .field("sources", &self.source_count())
+            .field("listeners", &self.downstream.len())
+            .finish()
+    }
+}
+
+impl MergedListenable {
+    /// Creates a merge of `sources` (duplicates are ignored).
+    #[must_use]
+    pub fn new(sources: impl IntoIterator<Item = Arc<dyn Listenable>>) -> Self {
+        let merged = Self {
+            downstream: ChangeNotifier::new(),
+            sources: Mutex::new(Vec::new()),
+        };
+        for source in sources {
+            merged.add_source(source);
+        }
+        merged
+    }
+
+    /// Starts forwarding `source`'s notifications. Returns `false` (and
+    /// changes nothing) if `source` is already part of the merge.
+    pub fn add_source(&self, source: Arc<dyn Listenable>) -> bool {
+        let mut sources = self.sources.lock();
+        if sources.iter().any(|(s, _)| same_source(s, &source)) {
+            return false;
+        }
+        let downstream = self.downstream.clone();
+        let forward = source.add_listener(Arc::new(move || downstream.notify_listeners()));
+        sources.push((source, forward));
+        true
+    }
+
+    /// Stops forwarding `source`'s notifications. Returns `false` if
+    /// `source` was not part of the merge.
+    pub fn remove_source(&self, source: &Arc<dyn Listenable>) -> bool {
+        let removed = {
+            let mut sources = self.sources.lock();
+            sources
+                .iter()
+                .position(|(s, _)| same_source(s, source))
+                .map(|index| sources.remove(index))
+        };
+        match removed {
+            Some((source, forward)) => {
+                source.remove_listener(forward);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the number of sources currently merged.
+    #[must_use]
+    pub fn source_count(&self) -> usize {
+        self.sources.lock().len()
+    }
+}
+
+/// Identity comparison on the data pointer (vtable pointers may differ for
+/// the same object across codegen units).
+fn same_source(a: &Arc<dyn Listenable>, b: &Arc<dyn Listenable>) -> bool {
+    std::ptr::addr_eq(Arc::as_ptr(a), Arc::as_ptr(b))
+}
+
+impl Listenable for MergedListenable {
+    fn add_listener(&self, listener: ListenerCallback) -> ListenerId {
+        self.downstream.add_listener(listener)
+    }
+
+    fn remove_listener(&self, id: ListenerId) {
+        self.downstream.remove_listener(id);
+    }
+
+    fn remove_all_listeners(&self) {
+        self.downstream.remove_all_listeners();
+    }
+}
+
+impl Drop for MergedListenable {
+    fn drop(&mut self) {
+        for (source, forward) in self.sources.get_mut().drain(..) {
+            source.remove_listener(forward);
+        }
+    }
+}
+
 /// A `ChangeNotifier` that holds a single value.
 ///
 /// Similar to Flutter's `ValueNotifier`.
@@ -1103,4 +1237,66 @@ fn dispose_during_notify_iteration_safe() {
         assert!(notifier.is_disposed());
         assert_eq!(notifier.len(), 0, "dispose cleared listeners");
     }
+
+    // ── MergedListenable ─────────────────────────────────────────────────────
+
+    fn counting(listenable: &impl Listenable) -> Arc<AtomicUsize> {
+        let count = Arc::new(AtomicUsize::new(0));
+        let count_clone = Arc::clone(&count);
+        listenable.add_listener(Arc::new(move || {
+            count_clone.fetch_add(1, Ordering::SeqCst);
+        }));
+        count
+    }
+
+    #[test]
+    fn merged_listenable_source_added_mid_life_is_wired_and_removal_stops_it() {
+        let a = Arc::new(ChangeNotifier::new());
+        let b = Arc::new(ChangeNotifier::new());
+        let merged = MergedListenable::new([a.clone() as Arc<dyn Listenable>]);
+        let count = counting(&merged);
+
+        b.notify_listeners();
+        assert_eq!(count.load(Ordering::SeqCst), 0, "b is not merged yet");
+
+        let b_dyn: Arc<dyn Listenable> = b.clone();
+        assert!(merged.add_source(Arc::clone(&b_dyn)));
+        b.notify_listeners();
+        a.notify_listeners();
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+
+        assert!(merged.remove_source(&b_dyn));
+        assert!(b.is_empty(), "forwarding listener is unregistered");
+        b.notify_listeners();
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+        a.notify_listeners();
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn merged_listenable_dedups_sources_and_ignores_unknown_removal() {
+        let a = Arc::new(ChangeNotifier::new());
+        let a_dyn: Arc<dyn Listenable> = a.clone();
+        let merged = MergedListenable::new([Arc::clone(&a_dyn), Arc::clone(&a_dyn)]);
+        assert_eq!(merged.source_count(), 1);
+        assert!(!merged.add_source(Arc::clone(&a_dyn)));
+        assert_eq!(a.len(), 1);
+
+        let count = counting(&merged);
+        a.notify_listeners();
+        assert_eq!(count.load(Ordering::SeqCst), 1, "one forward per change");
+
+        let stranger: Arc<dyn Listenable> = Arc::new(ChangeNotifier::new());
+        assert!(!merged.remove_source(&stranger));
+        assert_eq!(merged.source_count(), 1);
+    }
+
+    #[test]
+    fn merged_listenable_drop_unsubscribes_from_sources() {
+        let a = Arc::new(ChangeNotifier::new());
+        let merged = MergedListenable::new([a.clone() as Arc<dyn Listenable>]);
+        assert_eq!(a.len(), 1);
+        drop(merged);
+        assert!(a.is_empty());
+    }
 }
@@ -0,0 +1,253 @@
+//! `WeakObserverList<T>` — an observer list that does not keep its observers
+//! alive.
+//!
+//! [`ChangeNotifier`](crate::ChangeNotifier) owns its listener closures, so a
+//! listener that forgets to unregister outlives the scope it was meant for —
+//! the classic `ChangeNotifier` leak. A `WeakObserverList` holds only
+//! [`Weak`] references: once the last strong reference to an observer is
+//! dropped, it stops being notified and its slot is pruned lazily on the next
+//! [`notify`](WeakObserverList::notify), or eagerly via
+//! [`prune`](WeakObserverList::prune).
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::sync::{
+//!     Arc,
+//!     atomic::{AtomicU32, Ordering},
+//! };
+//!
+//! use flui_foundation::WeakObserverList;
+//!
+//! let observers: WeakObserverList<AtomicU32> = WeakObserverList::new();
+//! let counter = Arc::new(AtomicU32::new(0));
+//! observers.add(&counter);
+//!
+//! observers.notify(|c| {
+//!     c.fetch_add(1, Ordering::Relaxed);
+//! });
+//! assert_eq!(counter.load(Ordering::Relaxed), 1);
+//!
+//! drop(counter); // no unregister needed
+//! assert_eq!(observers.notify(|_| {}), 0);
+//! assert!(observers.is_empty());
+//! ```
+
+use std::{
+    fmt,
+    sync::{
+        Arc, Weak,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use parking_lot::Mutex;
+
+use crate::id::ObserverId;
+
+/// A thread-safe list of weakly-held observers.
+///
+/// Observers fire in registration order. Notification snapshots the live
+/// observers under the lock and releases it before calling out, so an
+/// observer may add or remove entries (or drop itself) while being notified.
+pub struct WeakObserverList<T: ?Sized> {
+    entries: Mutex<Vec<(ObserverId, Weak<T>)>>,
+    next_id: AtomicUsize,
+}
+
+impl<T: ?Sized> Default for WeakObserverList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for WeakObserverList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakObserverList")
+            .field("len", &self.len())
+            .field("live", &self.live_count())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> WeakObserverList<T> {
+    /// Creates an empty list.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            entries: Mutex::new(Vec::new()),
+            next_id: AtomicUsize::new(1),
+        }
+    }
+
+    /// Registers `observer` without taking ownership of it.
+    ///
+    /// The returned id can be passed to [`remove`](Self::remove) for early
+    /// removal; dropping the observer is enough otherwise.
+    pub fn add(&self, observer: &Arc<T>) -> ObserverId {
+        let id = ObserverId::new(self.next_id.fetch_add(1, Ordering::Relaxed));
+        self.entries.lock().push((id, Arc::downgrade(observer)));
+        id
+    }
+
+    /// Unregisters the observer with `id`. Returns `false` if it was not
+    /// registered (or has already been pruned).
+    pub fn remove(&self, id: ObserverId) -> bool {
+        let mut entries = self.entries.lock();
+        let before = entries.len();
+        entries.retain(|(entry_id, _)| *entry_id != id);
+        entries.len() != before
+    }
+
+    /// Calls `f` with every observer that is still alive, pruning the dropped
+    /// ones along the way. Returns how many observers were notified.
+    pub fn notify(&self, mut f: impl FnMut(&T)) -> usize {
+        let snapshot: smallvec::SmallVec<[Arc<T>; 4]> = {
+            let mut entries = self.entries.lock();
+            let mut live = smallvec::SmallVec::new();
+            entries.retain(|(_, weak)| match weak.upgrade() {
+                Some(observer) => {
+                    live.push(observer);
+                    true
+                }
+                None => false,
+            });
+            live
+        };
+
+        for observer in &snapshot {
+            f(observer);
+        }
+        snapshot.len()
+    }
+
+    /// Drops the slots of observers that no longer exist. Returns how many
+    /// were removed.
+    pub fn prune(&self) -> usize {
+        let mut entries = self.entries.lock();
+        let before = entries.len();
+        entries.retain(|(_, weak)| weak.strong_count() > 0);
+        before - entries.len()
+    }
+
+    /// Returns how many registered observers are still alive.
+    #[must_use]
+    pub fn live_count(&self) -> usize {
+        self.entries
+            .lock()
+            .iter()
+            .filter(|(_, weak)| weak.strong_count() > 0)
+            .count()
+    }
+
+    /// Returns how many slots are registered, including observers dropped
+    /// since the last prune.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.lock().len()
+    }
+
+    /// Returns `true` if no slots are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.lock().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicU32;
+
+    use super::*;
+
+    trait Observer: Send + Sync {
+        fn on_change(&self);
+        fn calls(&self) -> u32;
+    }
+
+    #[derive(Default)]
+    struct Counter(AtomicU32);
+
+    impl Observer for Counter {
+        fn on_change(&self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn calls(&self) -> u32 {
+            self.0.load(Ordering::Relaxed)
+        }
+    }
+
+    #[test]
+    fn dropped_observer_stops_being_notified_and_is_pruned() {
+        let list: WeakObserverList<dyn Observer> = WeakObserverList::new();
+        let kept: Arc<dyn Observer> = Arc::new(Counter::default());
+        let dropped: Arc<dyn Observer> = Arc::new(Counter::default());
+        list.add(&kept);
+        list.add(&dropped);
+
+        assert_eq!(list.notify(Observer::on_change), 2);
+        assert_eq!(dropped.calls(), 1);
+
+        drop(dropped);
+        assert_eq!(list.live_count(), 1);
+        assert_eq!(list.len(), 2, "pruning is lazy");
+
+        assert_eq!(list.notify(Observer::on_change), 1);
+        assert_eq!(kept.calls(), 2);
+        assert_eq!(list.len(), 1, "notify pruned the dead slot");
+    }
+
+    #[test]
+    fn prune_removes_only_dead_slots() {
+        let list = WeakObserverList::new();
+        let a = Arc::new(1);
+        let b = Arc::new(2);
+        let c = Arc::new(3);
+        list.add(&a);
+        list.add(&b);
+        list.add(&c);
+
+        drop(a);
+        drop(c);
+        assert_eq!(list.prune(), 2);
+        assert_eq!(list.prune(), 0);
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.live_count(), 1);
+
+        let mut seen = Vec::new();
+        list.notify(|v| seen.push(*v));
+        assert_eq!(seen, vec![2]);
+        drop(b);
+    }
+
+    #[test]
+    fn notifies_in_registration_order_and_supports_remove() {
+        let list = WeakObserverList::new();
+        let values: Vec<Arc<u32>> = (0..5).map(Arc::new).collect();
+        let ids: Vec<ObserverId> = values.iter().map(|v| list.add(v)).collect();
+
+        assert!(list.remove(ids[1]));
+        assert!(!list.remove(ids[1]));
+
+        let mut seen = Vec::new();
+        list.notify(|v| seen.push(*v));
+        assert_eq!(seen, vec![0, 2, 3, 4]);
+    }
+
+    #[test]
+    fn observer_may_mutate_the_list_while_notified() {
+        let list = Arc::new(WeakObserverList::new());
+        let first = Arc::new(0u32);
+        let late = Arc::new(1u32);
+        list.add(&first);
+
+        let mut notified = 0;
+        list.notify(|_| {
+            notified += 1;
+            list.add(&late);
+        });
+        assert_eq!(notified, 1, "additions wait for the next notify");
+        assert_eq!(list.live_count(), 2);
+    }
+}
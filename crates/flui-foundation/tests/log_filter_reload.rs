@@ -0,0 +1,35 @@
+//! Runtime filter reload for `flui_foundation::log`.
+//!
+//! Installs the global subscriber, so it lives in its own test binary.
+
+use flui_foundation::log::{FilterError, Logger, set_filter};
+
+#[test]
+fn reloading_the_filter_enables_suppressed_events_and_rejects_invalid_ones() {
+    assert!(matches!(
+        set_filter("debug"),
+        Err(FilterError::NotInitialized)
+    ));
+
+    let handle = Logger::new()
+        .with_filter("info")
+        .try_init()
+        .expect("first logger init in this process");
+    // Pin the starting point in case RUST_LOG is set in the environment.
+    handle.set_filter("info").expect("valid filter");
+    assert!(!tracing::enabled!(tracing::Level::DEBUG));
+
+    set_filter("info,log_filter_reload=debug").expect("valid filter");
+    assert!(tracing::enabled!(tracing::Level::DEBUG));
+    tracing::debug!("emitted after reload");
+
+    let err = handle
+        .set_filter("log_filter_reload=notalevel")
+        .expect_err("invalid filter is rejected");
+    assert!(matches!(err, FilterError::Parse(_)));
+    assert!(tracing::enabled!(tracing::Level::DEBUG));
+    assert_eq!(
+        handle.current_filter().as_deref(),
+        Some("log_filter_reload=debug,info")
+    );
+}
@@ -224,7 +224,7 @@ pub mod prelude {
 // =============================================================================
 // TRANSFORMATION TYPES
 // =============================================================================
-pub use matrix4::Matrix4;
+pub use matrix4::{Matrix4, TransformComponents};
 /// Generic 2D offset (Flutter-compatible displacement).
 ///
 /// See [`offset`] module for full documentation.
@@ -104,7 +104,7 @@
 use glam::Mat4;
 
 use super::Pixels;
-use crate::Rect;
+use crate::{Offset, Rect};
 
 /// A 4x4 transformation matrix stored in column-major order.
 ///
@@ -579,6 +579,119 @@ pub fn invert(&mut self) -> bool {
     pub fn determinant(&self) -> f32 {
         self.to_glam().determinant()
     }
+
+    /// Decomposes a 2D affine matrix into translation, rotation, skew and
+    /// scale, such that `translate * rotate * skew_x * scale` reproduces it.
+    ///
+    /// Returns `None` when the matrix carries perspective or Z-axis content,
+    /// or when its 2D part is degenerate (zero area). A reflection is folded
+    /// into a negative `scale.1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flui_geometry::Matrix4;
+    ///
+    /// let m = Matrix4::rotation_z(0.5) * Matrix4::scaling(2.0, 3.0, 1.0);
+    /// let parts = m.decompose().unwrap();
+    /// assert!((parts.rotation - 0.5).abs() < 1e-5);
+    /// assert!(parts.recompose().approx_eq_eps(&m, 1e-5));
+    /// ```
+    #[must_use]
+    pub fn decompose(&self) -> Option<TransformComponents> {
+        const EPS: f32 = 1e-6;
+        let m = &self.m;
+
+        // Only the upper-left 2x2 and the XY translation may be non-trivial.
+        let z_free = [m[2], m[3], m[6], m[7], m[8], m[9], m[11], m[14]]
+            .iter()
+            .all(|v| v.abs() <= EPS);
+        if !z_free || (m[10] - 1.0).abs() > EPS || (m[15] - 1.0).abs() > EPS {
+            return None;
+        }
+
+        let (a, b, c, d) = (m[0], m[1], m[4], m[5]);
+        let scale_x = a.hypot(b);
+        if scale_x <= EPS {
+            return None;
+        }
+        let rotation = b.atan2(a);
+        let (sin, cos) = (b / scale_x, a / scale_x);
+
+        // Project the second column onto the rotated axes: the component along
+        // X is the shear, the perpendicular one is the signed Y scale.
+        let scale_y = d * cos - c * sin;
+        if scale_y.abs() <= EPS {
+            return None;
+        }
+        let shear = (c * cos + d * sin) / scale_y;
+
+        Some(TransformComponents {
+            translation: Offset::new(Pixels(m[12]), Pixels(m[13])),
+            rotation,
+            scale: (scale_x, scale_y),
+            skew: shear.atan(),
+        })
+    }
+
+    /// Rebuilds a matrix from its decomposed parts. Inverse of
+    /// [`decompose`](Self::decompose).
+    #[inline]
+    #[must_use]
+    pub fn recompose(components: &TransformComponents) -> Self {
+        components.recompose()
+    }
+}
+
+/// The parts of a 2D affine [`Matrix4`], as returned by
+/// [`Matrix4::decompose`].
+///
+/// The transform is applied as scale, then X-skew, then rotation, then
+/// translation. Interpolating these parts keeps rotations rigid, where
+/// lerping raw matrix entries shrinks and shears the midpoint.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransformComponents {
+    /// Translation applied last.
+    pub translation: Offset<Pixels>,
+    /// Rotation around the Z axis, in radians.
+    pub rotation: f32,
+    /// Scale along X and Y; a negative Y scale encodes a reflection.
+    pub scale: (f32, f32),
+    /// Skew angle along the X axis, in radians (see [`Matrix4::skew_2d`]).
+    pub skew: f32,
+}
+
+impl TransformComponents {
+    /// Rebuilds the matrix these components describe.
+    #[must_use]
+    pub fn recompose(&self) -> Matrix4 {
+        Matrix4::translation(self.translation.dx.0, self.translation.dy.0, 0.0)
+            * Matrix4::rotation_z(self.rotation)
+            * Matrix4::skew_2d(self.skew, 0.0)
+            * Matrix4::scaling(self.scale.0, self.scale.1, 1.0)
+    }
+
+    /// Interpolates each component linearly. Rotation takes the shorter arc.
+    #[must_use]
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        use std::f32::consts::{PI, TAU};
+
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        let mut delta = (other.rotation - self.rotation).rem_euclid(TAU);
+        if delta > PI {
+            delta -= TAU;
+        }
+
+        Self {
+            translation: self.translation.lerp(other.translation, t),
+            rotation: self.rotation + delta * t,
+            scale: (
+                lerp(self.scale.0, other.scale.0),
+                lerp(self.scale.1, other.scale.1),
+            ),
+            skew: lerp(self.skew, other.skew),
+        }
+    }
 }
 
 impl Default for Matrix4 {
@@ -800,4 +913,47 @@ fn singular_matrix_has_no_inverse() {
         assert!(singular.try_inverse().is_none());
         assert!(Matrix4::identity().try_inverse().is_some());
     }
+
+    #[test]
+    fn decompose_recompose_round_trips() {
+        let m = Matrix4::translation(12.0, -4.0, 0.0)
+            * Matrix4::rotation_z(0.8)
+            * Matrix4::skew_2d(0.3, 0.0)
+            * Matrix4::scaling(2.0, -1.5, 1.0);
+        let parts = m.decompose().expect("2D affine");
+
+        assert_eq!(parts.translation, Offset::new(Pixels(12.0), Pixels(-4.0)));
+        assert!((parts.rotation - 0.8).abs() < 1e-5);
+        assert!((parts.skew - 0.3).abs() < 1e-5);
+        assert!((parts.scale.0 - 2.0).abs() < 1e-5);
+        assert!((parts.scale.1 + 1.5).abs() < 1e-5);
+        assert!(Matrix4::recompose(&parts).approx_eq_eps(&m, 1e-5));
+    }
+
+    #[test]
+    fn decompose_rejects_perspective_and_degenerate() {
+        let mut perspective = Matrix4::identity();
+        perspective.m[11] = -0.002;
+        assert!(perspective.decompose().is_none());
+        assert!(Matrix4::rotation_x(0.5).decompose().is_none());
+        assert!(Matrix4::scaling(1.0, 0.0, 1.0).decompose().is_none());
+    }
+
+    #[test]
+    fn component_lerp_keeps_rotate_scale_rigid_at_midpoint() {
+        let from = Matrix4::identity().decompose().unwrap();
+        let to = (Matrix4::rotation_z(std::f32::consts::FRAC_PI_2)
+            * Matrix4::scaling(2.0, 2.0, 1.0))
+        .decompose()
+        .unwrap();
+        let mid = from.lerp(&to, 0.5).recompose();
+
+        // The unit X axis is rotated 45° and scaled by 1.5, with no shear.
+        let (x, y) = mid.transform_point(Pixels(1.0), Pixels(0.0));
+        assert!((x.0.hypot(y.0) - 1.5).abs() < 1e-5);
+        assert!((y.0.atan2(x.0) - std::f32::consts::FRAC_PI_4).abs() < 1e-5);
+        let (x2, y2) = mid.transform_point(Pixels(0.0), Pixels(1.0));
+        assert!((x.0 * x2.0 + y.0 * y2.0).abs() < 1e-4);
+        assert!((mid.determinant() - 2.25).abs() < 1e-4);
+    }
 }
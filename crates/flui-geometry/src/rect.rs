@@ -508,8 +508,12 @@ impl<T: NumericUnit> Rect<T>
 where
     T: PartialOrd + std::ops::Sub<Output = T> + Clone + fmt::Debug + Default + PartialEq,
 {
-    /// Returns the intersection of two rectangles, or `None` if they don't
-    /// overlap.
+    /// Returns the intersection of two rectangles, or `None` if they are
+    /// disjoint.
+    ///
+    /// Inclusive: rectangles that only share an edge or a corner yield the
+    /// zero-area rectangle along it. Use [`overlap`](Self::overlap) to treat
+    /// those as disjoint.
     #[must_use]
     #[inline]
     pub fn intersect(&self, other: &Self) -> Option<Self> {
@@ -544,6 +548,19 @@ pub fn intersect(&self, other: &Self) -> Option<Self> {
         }
     }
 
+    /// Returns the region the two rectangles share, or `None` if it encloses
+    /// no area.
+    ///
+    /// Unlike [`intersect`](Self::intersect), rectangles that only share an
+    /// edge or a corner yield `None`, consistent with
+    /// [`overlaps`](Self::overlaps).
+    #[must_use]
+    #[inline]
+    pub fn overlap(&self, other: &Self) -> Option<Self> {
+        self.intersect(other)
+            .filter(|shared| shared.min.x < shared.max.x && shared.min.y < shared.max.y)
+    }
+
     /// Returns the smallest rectangle containing both rectangles.
     #[inline]
     #[must_use]
@@ -1051,6 +1068,29 @@ fn test_intersect() {
         assert!(r1.intersect(&r3).is_none());
     }
 
+    #[test]
+    fn test_intersect_shared_edge_is_zero_area() {
+        let r1 = Rect::from_xywh(px(0.0), px(0.0), px(100.0), px(100.0));
+        let beside = Rect::from_xywh(px(100.0), px(0.0), px(50.0), px(100.0));
+        let diagonal = Rect::from_xywh(px(100.0), px(100.0), px(50.0), px(50.0));
+        assert_eq!(
+            r1.intersect(&beside),
+            Some(Rect::from_xywh(px(100.0), px(0.0), px(0.0), px(100.0)))
+        );
+        assert_eq!(
+            r1.intersect(&diagonal),
+            Some(Rect::from_xywh(px(100.0), px(100.0), px(0.0), px(0.0)))
+        );
+        assert!(r1.overlap(&beside).is_none());
+        assert!(r1.overlap(&diagonal).is_none());
+
+        // A sliver of overlap is both.
+        let overlapping = Rect::from_xywh(px(99.5), px(0.0), px(50.0), px(100.0));
+        let sliver = Some(Rect::from_xywh(px(99.5), px(0.0), px(0.5), px(100.0)));
+        assert_eq!(r1.intersect(&overlapping), sliver);
+        assert_eq!(r1.overlap(&overlapping), sliver);
+    }
+
     #[test]
     fn test_union() {
         let r1 = Rect::from_xywh(px(0.0), px(0.0), px(50.0), px(50.0));
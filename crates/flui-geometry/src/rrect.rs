@@ -3,7 +3,7 @@
 //! API design inspired by Flutter and kurbo.
 
 use super::{
-    Pixels, Point, Rect, Size, px,
+    Offset, Pixels, Point, Rect, Size, px,
     traits::{NumericUnit, Unit},
 };
 
@@ -399,7 +399,92 @@ pub fn area(&self) -> Pixels {
 // Hit Testing
 // ============================================================================
 
-impl RRect {}
+impl RRect {
+    /// Checks if the point lies inside the rounded rectangle, honouring each
+    /// corner's elliptical radius.
+    ///
+    /// Mirrors Flutter `RRect.contains`: the radii are first scaled with
+    /// [`scale_radii_to_fit`](Self::scale_radii_to_fit), so oversized radii
+    /// behave the way they paint. A corner with a zero (or negative) radius
+    /// on either axis is square. Edges are inclusive, matching
+    /// [`Rect::contains`].
+    #[must_use]
+    pub fn contains(&self, point: Point<Pixels>) -> bool {
+        if !self.rect.contains(point) {
+            return false;
+        }
+
+        let scaled = self.scale_radii_to_fit();
+        let (x, y) = (point.x.get(), point.y.get());
+        let (left, top) = (self.left().get(), self.top().get());
+        let (right, bottom) = (self.right().get(), self.bottom().get());
+
+        // Each corner: (radius, signed distance past the ellipse centre on
+        // each axis). Positive on both axes means the point sits in the
+        // corner's square, where the ellipse decides.
+        let corners = [
+            (scaled.top_left, left - x, top - y),
+            (scaled.top_right, x - right, top - y),
+            (scaled.bottom_right, x - right, y - bottom),
+            (scaled.bottom_left, left - x, y - bottom),
+        ];
+        corners.into_iter().all(|(radius, dx, dy)| {
+            let (rx, ry) = (radius.x.get(), radius.y.get());
+            if rx <= 0.0 || ry <= 0.0 {
+                return true;
+            }
+            let (nx, ny) = ((dx + rx) / rx, (dy + ry) / ry);
+            nx <= 0.0 || ny <= 0.0 || nx * nx + ny * ny <= 1.0
+        })
+    }
+
+    /// Same as [`contains`](Self::contains), for a position expressed as an
+    /// offset from the origin.
+    #[inline]
+    #[must_use]
+    pub fn contains_offset(&self, offset: Offset<Pixels>) -> bool {
+        self.contains(Point::new(offset.dx, offset.dy))
+    }
+
+    /// Scales every radius down by one common factor so that adjacent
+    /// corners never overlap along any side, and clamps negative radii to
+    /// zero.
+    ///
+    /// Unlike [`clamp_radii`](Self::clamp_radii), which caps each radius at
+    /// half the side independently, this keeps the corners' proportions —
+    /// the shape a renderer actually draws for oversized radii.
+    #[must_use]
+    pub fn scale_radii_to_fit(&self) -> Self {
+        let non_negative = |r: Radius<Pixels>| Radius::new(r.x.max(px(0.0)), r.y.max(px(0.0)));
+        let (tl, tr) = (non_negative(self.top_left), non_negative(self.top_right));
+        let (br, bl) = (
+            non_negative(self.bottom_right),
+            non_negative(self.bottom_left),
+        );
+        let (width, height) = (self.width().get().abs(), self.height().get().abs());
+
+        let fit = |scale: f32, radii: f32, limit: f32| {
+            if radii > limit {
+                scale.min(limit / radii)
+            } else {
+                scale
+            }
+        };
+        let mut scale = 1.0;
+        scale = fit(scale, tl.x.get() + tr.x.get(), width);
+        scale = fit(scale, tr.y.get() + br.y.get(), height);
+        scale = fit(scale, bl.x.get() + br.x.get(), width);
+        scale = fit(scale, tl.y.get() + bl.y.get(), height);
+
+        Self::new(
+            self.rect,
+            tl.scale(scale),
+            tr.scale(scale),
+            br.scale(scale),
+            bl.scale(scale),
+        )
+    }
+}
 
 // ============================================================================
 // Transformations
@@ -425,7 +510,7 @@ pub fn scale_radii(&self, factor: f32) -> Self {
     /// Mirrors [`Rect::translate_offset`] (Flutter `RRect.shift`).
     #[inline]
     #[must_use]
-    pub fn translate_offset(&self, offset: crate::Offset<Pixels>) -> Self {
+    pub fn translate_offset(&self, offset: Offset<Pixels>) -> Self {
         Self {
             rect: self.rect.translate_offset(offset),
             ..*self
@@ -520,7 +605,6 @@ fn from(rect: Rect<Pixels>) -> Self {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Offset;
 
     #[test]
     fn translate_offset_moves_rect_and_keeps_radii() {
@@ -539,4 +623,83 @@ fn translate_offset_moves_rect_and_keeps_radii() {
         assert_eq!(moved.top_left, rrect.top_left);
         assert_eq!(moved.bottom_right, rrect.bottom_right);
     }
+
+    fn square(size: f32) -> Rect<Pixels> {
+        Rect::from_origin_size(Point::ZERO, Size::new(px(size), px(size)))
+    }
+
+    #[test]
+    fn contains_excludes_each_rounded_corner_but_not_its_interior() {
+        let rrect = RRect::from_rect_circular(square(100.0), px(20.0));
+        // Just outside / just inside the arc along each corner's diagonal:
+        // the arc crosses it at centre ± 20/√2 ≈ 14.14.
+        for (cx, cy, sx, sy) in [
+            (20.0, 20.0, -1.0, -1.0),
+            (80.0, 20.0, 1.0, -1.0),
+            (80.0, 80.0, 1.0, 1.0),
+            (20.0, 80.0, -1.0, 1.0),
+        ] {
+            let outside = Point::new(px(cx + sx * 14.5), px(cy + sy * 14.5));
+            let inside = Point::new(px(cx + sx * 13.8), px(cy + sy * 13.8));
+            assert!(!rrect.contains(outside), "{outside:?} is past the arc");
+            assert!(rrect.contains(inside), "{inside:?} is inside the arc");
+        }
+        assert!(
+            rrect.contains(Point::new(px(0.0), px(50.0))),
+            "straight edge"
+        );
+        assert!(!rrect.contains(Point::new(px(101.0), px(50.0))));
+    }
+
+    #[test]
+    fn contains_offset_includes_points_on_the_arc() {
+        let rrect = RRect::from_rect_circular(square(100.0), px(20.0));
+        // The arc meets the straight edges at its tangent points; those lie
+        // exactly on the curve and are inside (edges are inclusive).
+        assert!(rrect.contains_offset(Offset::new(px(20.0), px(0.0))));
+        assert!(rrect.contains_offset(Offset::new(px(0.0), px(20.0))));
+        assert!(rrect.contains_offset(Offset::new(px(100.0), px(80.0))));
+        // The bounding corner itself is outside the rounded shape.
+        assert!(!rrect.contains_offset(Offset::new(px(0.0), px(0.0))));
+        assert!(!rrect.contains_offset(Offset::new(px(100.0), px(100.0))));
+    }
+
+    #[test]
+    fn contains_uses_elliptical_radii_per_axis() {
+        let rrect = RRect::from_rect_elliptical(square(100.0), px(40.0), px(10.0));
+        // Wide, shallow corner: (5, 5) is outside the ellipse centred at
+        // (40, 10); (30, 2) is inside it.
+        assert!(!rrect.contains(Point::new(px(5.0), px(5.0))));
+        assert!(rrect.contains(Point::new(px(30.0), px(2.0))));
+    }
+
+    #[test]
+    fn contains_treats_zero_radius_corners_as_square() {
+        let rrect = RRect::from_rect_and_corners(
+            square(100.0),
+            Radius::zero(),
+            Radius::circular(px(30.0)),
+            Radius::elliptical(px(30.0), px(0.0)),
+            Radius::zero(),
+        );
+        assert!(rrect.contains(Point::new(px(0.0), px(0.0))));
+        assert!(rrect.contains(Point::new(px(100.0), px(100.0))));
+        assert!(!rrect.contains(Point::new(px(99.0), px(1.0))));
+    }
+
+    #[test]
+    fn oversized_radii_scale_down_together_before_hit_testing() {
+        // 80 + 80 on a 100 side: both radii scale by 100/160 to 50.
+        let rrect = RRect::from_rect_circular(square(100.0), px(80.0));
+        let fitted = rrect.scale_radii_to_fit();
+        assert_eq!(fitted.top_left, Radius::circular(px(50.0)));
+        assert_eq!(fitted.bottom_right, Radius::circular(px(50.0)));
+        // The fitted shape is a circle of radius 50: (50, 1) is inside,
+        // (10, 10) is outside. Unscaled radii would misplace the arc.
+        assert!(rrect.contains(Point::new(px(50.0), px(1.0))));
+        assert!(!rrect.contains(Point::new(px(10.0), px(10.0))));
+        // Radii that already fit are untouched.
+        let small = RRect::from_rect_circular(square(100.0), px(20.0));
+        assert_eq!(small.scale_radii_to_fit(), small);
+    }
 }
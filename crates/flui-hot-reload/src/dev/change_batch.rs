@@ -0,0 +1,226 @@
+//! Filtering and coalescing of source-change events.
+//!
+//! [`SourceWatcher`](super::SourceWatcher) debounces raw filesystem events;
+//! editors that save several files (or write a temp file, then rename) still
+//! produce a burst of batches. [`ChangeDebouncer`] merges those into a single
+//! rebuild once the tree has been quiet for a window, and [`IgnoreGlobs`]
+//! drops paths a rebuild should never react to, such as `target/`.
+//!
+//! Both are pure and clock-agnostic so they can be driven by a simulated
+//! event stream in tests.
+
+use std::{
+    collections::BTreeSet,
+    path::{Component, Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// Path-component globs ignored by default: build output, VCS metadata and
+/// editor swap/backup files.
+pub const DEFAULT_IGNORE_GLOBS: &[&str] = &["target", ".git", "*.swp", "*.swx", "*~", ".#*"];
+
+/// Set of globs matched against each component of a changed path.
+///
+/// A glob is a single path component where `*` matches any run of
+/// characters (`target`, `*.swp`, `.#*`). A path is ignored when any of its
+/// components matches any glob.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IgnoreGlobs {
+    globs: Vec<String>,
+}
+
+impl IgnoreGlobs {
+    /// Create a set from the given globs.
+    pub fn new<I, S>(globs: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            globs: globs.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Whether `path` should be ignored.
+    #[must_use]
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        path.components().any(|component| match component {
+            Component::Normal(name) => {
+                let name = name.to_string_lossy();
+                self.globs.iter().any(|glob| glob_matches(glob, &name))
+            }
+            _ => false,
+        })
+    }
+
+    /// Keep only the paths that are not ignored.
+    pub fn filter(&self, paths: impl IntoIterator<Item = PathBuf>) -> Vec<PathBuf> {
+        paths
+            .into_iter()
+            .filter(|path| !self.is_ignored(path))
+            .collect()
+    }
+}
+
+impl Default for IgnoreGlobs {
+    fn default() -> Self {
+        Self::new(DEFAULT_IGNORE_GLOBS.iter().copied())
+    }
+}
+
+/// Match a single-component glob where `*` matches any run of characters.
+fn glob_matches(glob: &str, name: &str) -> bool {
+    let mut parts = glob.split('*');
+    let Some(first) = parts.next() else {
+        return name.is_empty();
+    };
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = parts.collect();
+    let Some(last) = parts.pop() else {
+        // No `*` in the glob: the whole name must match.
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+/// Trailing-edge debouncer for batches of changed paths.
+///
+/// Feed batches with [`push`](Self::push) and call [`poll`](Self::poll)
+/// periodically; once no batch has arrived for `window`, `poll` returns every
+/// path seen since the last flush (sorted, deduplicated).
+#[derive(Debug, Clone)]
+pub struct ChangeDebouncer {
+    window: Duration,
+    ignore: IgnoreGlobs,
+    pending: BTreeSet<PathBuf>,
+    last_change: Option<Instant>,
+}
+
+impl ChangeDebouncer {
+    /// Create a debouncer with the default [`IgnoreGlobs`].
+    #[must_use]
+    pub fn new(window: Duration) -> Self {
+        Self::with_ignore(window, IgnoreGlobs::default())
+    }
+
+    /// Create a debouncer with custom ignore globs.
+    #[must_use]
+    pub fn with_ignore(window: Duration, ignore: IgnoreGlobs) -> Self {
+        Self {
+            window,
+            ignore,
+            pending: BTreeSet::new(),
+            last_change: None,
+        }
+    }
+
+    /// Record a batch of changed paths observed at `now`.
+    ///
+    /// Ignored paths are dropped and do not restart the quiet window.
+    pub fn push(&mut self, paths: impl IntoIterator<Item = PathBuf>, now: Instant) {
+        let relevant = self.ignore.filter(paths);
+        if relevant.is_empty() {
+            return;
+        }
+        self.pending.extend(relevant);
+        self.last_change = Some(now);
+    }
+
+    /// Whether changes are waiting to be flushed.
+    #[must_use]
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Return the pending paths if the tree has been quiet for the window.
+    pub fn poll(&mut self, now: Instant) -> Option<Vec<PathBuf>> {
+        let last_change = self.last_change?;
+        if now.saturating_duration_since(last_change) < self.window {
+            return None;
+        }
+        self.last_change = None;
+        Some(std::mem::take(&mut self.pending).into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignore_globs_match_any_component() {
+        let ignore = IgnoreGlobs::default();
+
+        assert!(ignore.is_ignored(Path::new("target/debug/app")));
+        assert!(ignore.is_ignored(Path::new("/work/app/target/debug/build.rs")));
+        assert!(ignore.is_ignored(Path::new("src/.main.rs.swp")));
+        assert!(ignore.is_ignored(Path::new("src/main.rs~")));
+        assert!(ignore.is_ignored(Path::new("src/.#main.rs")));
+        assert!(!ignore.is_ignored(Path::new("src/main.rs")));
+        assert!(!ignore.is_ignored(Path::new("src/targets.rs")));
+        assert!(!ignore.is_ignored(Path::new("Cargo.toml")));
+
+        let custom = IgnoreGlobs::new(["gen_*_out.rs"]);
+        assert!(custom.is_ignored(Path::new("src/gen_ui_out.rs")));
+        assert!(!custom.is_ignored(Path::new("src/gen_ui.rs")));
+    }
+
+    #[test]
+    fn rapid_saves_coalesce_into_one_batch() {
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let mut debouncer = ChangeDebouncer::new(Duration::from_millis(300));
+
+        debouncer.push([PathBuf::from("src/main.rs")], at(0));
+        assert_eq!(debouncer.poll(at(200)), None);
+        debouncer.push([PathBuf::from("src/app.rs")], at(250));
+        debouncer.push([PathBuf::from("src/main.rs")], at(400));
+        assert_eq!(
+            debouncer.poll(at(650)),
+            None,
+            "window restarts on each save"
+        );
+
+        assert_eq!(
+            debouncer.poll(at(700)),
+            Some(vec![
+                PathBuf::from("src/app.rs"),
+                PathBuf::from("src/main.rs")
+            ])
+        );
+        assert!(!debouncer.has_pending());
+        assert_eq!(debouncer.poll(at(2000)), None);
+    }
+
+    #[test]
+    fn ignored_events_neither_flush_nor_extend_the_window() {
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let mut debouncer = ChangeDebouncer::new(Duration::from_millis(300));
+
+        debouncer.push([PathBuf::from("target/debug/app")], at(0));
+        assert!(!debouncer.has_pending());
+        assert_eq!(debouncer.poll(at(500)), None);
+
+        debouncer.push([PathBuf::from("src/lib.rs")], at(500));
+        debouncer.push(
+            [
+                PathBuf::from("target/debug/deps/app.d"),
+                PathBuf::from("src/.lib.rs.swp"),
+            ],
+            at(700),
+        );
+        assert_eq!(
+            debouncer.poll(at(800)),
+            Some(vec![PathBuf::from("src/lib.rs")])
+        );
+    }
+}
@@ -4,8 +4,10 @@
 //! source files and triggers rebuilds. Runtime hosts use [`crate::HotReloadDriver`]
 //! instead.
 
+mod change_batch;
 #[cfg(feature = "source-watch")]
 mod source_watch;
 
+pub use change_batch::{ChangeDebouncer, DEFAULT_IGNORE_GLOBS, IgnoreGlobs};
 #[cfg(feature = "source-watch")]
 pub use source_watch::{SourceWatcher, WatchError};
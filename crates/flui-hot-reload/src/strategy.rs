@@ -79,6 +79,10 @@ pub mod timing {
     /// Debounce for source-file watchers in `flui run` desktop mode.
     pub const SOURCE_DEBOUNCE: Duration = Duration::from_millis(500);
 
+    /// Quiet period after the last source change before `flui run --watch`
+    /// rebuilds, coalescing multi-file saves.
+    pub const REBUILD_DEBOUNCE: Duration = Duration::from_millis(300);
+
     /// Debounce for `flui run --scene` Android scene rebuild loop.
     pub const ANDROID_SCENE_DEBOUNCE: Duration = Duration::from_millis(300);
 }
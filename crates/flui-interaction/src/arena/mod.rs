@@ -47,7 +47,9 @@
 pub mod signal_resolver;
 pub mod team;
 
-pub use signal_resolver::{PointerSignalResolver, SignalPriority};
+pub use signal_resolver::{
+    PointerSignalResolver, ScrollSignal, ScrollSignalCallback, SignalPriority,
+};
 pub use team::{GestureArenaTeam, TeamEntry};
 
 use std::{
@@ -66,7 +68,9 @@
 /// Default timeout for gesture disambiguation (100ms).
 ///
 /// If no recognizer accepts within this time, the first member wins.
-/// This matches Flutter's default arena timeout behavior.
+/// This matches Flutter's default arena timeout behavior. A pointer's window
+/// can be changed with [`GestureArena::set_timeout_for_pointer`], and members
+/// can extend it via [`GestureArenaMember::arena_timeout`].
 pub const DEFAULT_DISAMBIGUATION_TIMEOUT: Duration = Duration::from_millis(100);
 
 // ============================================================================
@@ -152,6 +156,19 @@ pub trait GestureArenaMember: crate::sealed::arena_member::Sealed {
     /// (long press) override it. Implementations must be idempotent across
     /// frames (firing at most once per deadline).
     fn poll_deadline(&self) {}
+
+    /// Minimum disambiguation window this member needs before the arena may
+    /// force-resolve on timeout.
+    ///
+    /// The arena closes a pointer's competition after the *maximum* of its
+    /// base timeout and every current member's hint, so a long press can keep
+    /// a tap from winning by timeout before its hold deadline. A member that
+    /// resigns stops contributing. Called under the arena's entry lock, so it
+    /// must be a plain getter that never calls back into the arena. Defaults
+    /// to `None` (no requirement).
+    fn arena_timeout(&self) -> Option<Duration> {
+        None
+    }
 }
 
 // ============================================================================
@@ -170,6 +187,11 @@ fn accept_gesture(&self, pointer: PointerId) {
     fn reject_gesture(&self, pointer: PointerId) {
         self.on_arena_reject(pointer);
     }
+
+    #[inline]
+    fn arena_timeout(&self) -> Option<Duration> {
+        crate::sealed::CustomGestureRecognizer::arena_timeout(self)
+    }
 }
 
 // ============================================================================
@@ -294,8 +316,12 @@ struct ArenaEntryData {
     /// Winners of the arena (if resolved). Multiple winners possible with
     /// teams.
     winners: SmallVec<[Arc<dyn GestureArenaMember>; 2]>,
-    /// When this arena entry was created (for timeout calculation).
+    /// When this arena entry was created (for timeout calculation), on the
+    /// arena's clock.
     created_at: Instant,
+    /// Base disambiguation timeout for this pointer, replacing
+    /// [`DEFAULT_DISAMBIGUATION_TIMEOUT`] when set.
+    timeout_override: Option<Duration>,
 }
 
 impl std::fmt::Debug for ArenaEntryData {
@@ -309,6 +335,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             .field("has_pending_sweep", &self.has_pending_sweep)
             .field("winner_count", &self.winners.len())
             .field("age_ms", &self.created_at.elapsed().as_millis())
+            .field("timeout_override", &self.timeout_override)
             .finish()
     }
 }
@@ -325,7 +352,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 type PendingNotifications = SmallVec<[(Arc<dyn GestureArenaMember>, GestureDisposition); 4]>;
 
 impl ArenaEntryData {
-    fn new() -> Self {
+    fn new(created_at: Instant) -> Self {
         Self {
             members: SmallVec::new(),
             is_open: true,
@@ -334,7 +361,8 @@ fn new() -> Self {
             eager_winner: None,
             has_pending_sweep: false,
             winners: SmallVec::new(),
-            created_at: Instant::now(),
+            created_at,
+            timeout_override: None,
         }
     }
 
@@ -435,16 +463,28 @@ fn try_to_resolve(&mut self) -> PendingNotifications {
         }
     }
 
-    /// Check if this arena has exceeded the given timeout.
+    /// Check if this arena has exceeded the given timeout at `now`.
     #[inline]
-    fn has_timed_out(&self, timeout: Duration) -> bool {
-        self.created_at.elapsed() >= timeout
+    fn has_timed_out(&self, timeout: Duration, now: Instant) -> bool {
+        self.elapsed(now) >= timeout
     }
 
     /// Get the elapsed time since this arena was created.
     #[inline]
-    fn elapsed(&self) -> Duration {
-        self.created_at.elapsed()
+    fn elapsed(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.created_at)
+    }
+
+    /// The effective disambiguation timeout: the base timeout (override or
+    /// default) extended to the largest hint of any remaining member.
+    fn timeout(&self) -> Duration {
+        let base = self
+            .timeout_override
+            .unwrap_or(DEFAULT_DISAMBIGUATION_TIMEOUT);
+        self.members
+            .iter()
+            .filter_map(|member| member.arena_timeout())
+            .fold(base, Duration::max)
     }
 
     /// Add a member to this arena.
@@ -767,7 +807,7 @@ pub fn add(
         // site, and the critical section is a single `SmallVec` push.
         self.entries
             .entry(pointer)
-            .or_insert_with(|| Mutex::new(ArenaEntryData::new()))
+            .or_insert_with(|| Mutex::new(ArenaEntryData::new(self.clock.now())))
             .lock()
             .add(member.clone());
 
@@ -1183,32 +1223,82 @@ pub fn member_count(&self, pointer: PointerId) -> usize {
     // Timeout-based disambiguation
     // ========================================================================
 
+    /// Override the base disambiguation timeout for a pointer.
+    ///
+    /// Replaces [`DEFAULT_DISAMBIGUATION_TIMEOUT`] for this pointer's arena
+    /// (creating the entry if the pointer has none yet, so it can be set at
+    /// pointer-down before recognizers join). Members can still extend the
+    /// window via [`GestureArenaMember::arena_timeout`]; the effective timeout
+    /// is the maximum. The override is dropped with the entry on sweep.
+    pub fn set_timeout_for_pointer(&self, pointer: PointerId, timeout: Duration) {
+        self.entries
+            .entry(pointer)
+            .or_insert_with(|| Mutex::new(ArenaEntryData::new(self.clock.now())))
+            .lock()
+            .timeout_override = Some(timeout);
+    }
+
+    /// The effective disambiguation timeout for a pointer.
+    ///
+    /// This is the pointer's base timeout (see
+    /// [`set_timeout_for_pointer`](Self::set_timeout_for_pointer)) extended to
+    /// the largest [`arena_timeout`](GestureArenaMember::arena_timeout) hint of
+    /// the members still competing. Returns `None` if no arena exists.
+    pub fn timeout_for_pointer(&self, pointer: PointerId) -> Option<Duration> {
+        self.entries
+            .get(&pointer)
+            .map(|entry_ref| entry_ref.lock().timeout())
+    }
+
+    /// The instant this pointer's arena times out: its creation time plus the
+    /// effective [`timeout_for_pointer`](Self::timeout_for_pointer), on this
+    /// arena's clock.
+    ///
+    /// Drivers that sleep between frames can schedule a wake-up for it (e.g.
+    /// with [`GestureTimerService::schedule_at`](crate::timer::GestureTimerService::schedule_at))
+    /// and then call [`force_resolve_if_default_timeout`](Self::force_resolve_if_default_timeout).
+    /// The deadline moves when members join or resign, so re-query it after
+    /// arena changes. Returns `None` if no arena exists or it is resolved.
+    pub fn timeout_deadline(&self, pointer: PointerId) -> Option<Instant> {
+        self.entries.get(&pointer).and_then(|entry_ref| {
+            let entry = entry_ref.lock();
+            (!entry.is_resolved).then(|| entry.created_at + entry.timeout())
+        })
+    }
+
     /// Check if an arena has exceeded its timeout.
     ///
     /// Returns `true` if the arena exists, is not resolved, and has been
     /// waiting longer than the specified timeout.
     pub fn has_timed_out(&self, pointer: PointerId, timeout: Duration) -> bool {
+        let now = self.clock.now();
         self.entries.get(&pointer).is_some_and(|entry_ref| {
             let entry = entry_ref.lock();
-            !entry.is_resolved && entry.has_timed_out(timeout)
+            !entry.is_resolved && entry.has_timed_out(timeout, now)
         })
     }
 
-    /// Check if an arena has exceeded the default timeout.
+    /// Check if an arena has exceeded its effective timeout.
     ///
-    /// Uses [`DEFAULT_DISAMBIGUATION_TIMEOUT`] (100ms).
-    #[inline]
+    /// Uses [`timeout_for_pointer`](Self::timeout_for_pointer), which is
+    /// [`DEFAULT_DISAMBIGUATION_TIMEOUT`] (100ms) unless overridden or
+    /// extended by a member.
     pub fn has_default_timeout(&self, pointer: PointerId) -> bool {
-        self.has_timed_out(pointer, DEFAULT_DISAMBIGUATION_TIMEOUT)
+        let now = self.clock.now();
+        self.entries.get(&pointer).is_some_and(|entry_ref| {
+            let entry = entry_ref.lock();
+            !entry.is_resolved && entry.has_timed_out(entry.timeout(), now)
+        })
     }
 
     /// Get the elapsed time for an arena.
     ///
     /// Returns `None` if the arena doesn't exist.
     pub fn elapsed(&self, pointer: PointerId) -> Option<Duration> {
+        let now = self.clock.now();
         self.entries
             .get(&pointer)
-            .map(|entry_ref| entry_ref.lock().elapsed())
+            .map(|entry_ref| entry_ref.lock().elapsed(now))
     }
 
     /// Force resolve an arena due to timeout.
@@ -1219,6 +1309,13 @@ pub fn elapsed(&self, pointer: PointerId) -> Option<Duration> {
     ///
     /// Returns `true` if the arena was force-resolved.
     pub fn force_resolve_if_timed_out(&self, pointer: PointerId, timeout: Duration) -> bool {
+        self.force_resolve_after(pointer, Some(timeout))
+    }
+
+    /// Force resolve after `timeout`, or after the entry's effective timeout
+    /// when `None`.
+    fn force_resolve_after(&self, pointer: PointerId, timeout: Option<Duration>) -> bool {
+        let now = self.clock.now();
         let pending = if let Some(entry_ref) = self.entries.get(&pointer) {
             let mut entry = entry_ref.lock();
 
@@ -1228,13 +1325,14 @@ pub fn force_resolve_if_timed_out(&self, pointer: PointerId, timeout: Duration)
             }
 
             // Check timeout
-            if !entry.has_timed_out(timeout) {
+            let timeout = timeout.unwrap_or_else(|| entry.timeout());
+            if !entry.has_timed_out(timeout, now) {
                 return false;
             }
 
             tracing::trace!(
                 ?pointer,
-                elapsed_ms = entry.elapsed().as_millis(),
+                elapsed_ms = entry.elapsed(now).as_millis(),
                 member_count = entry.members.len(),
                 "Force resolving arena due to timeout"
             );
@@ -1250,12 +1348,14 @@ pub fn force_resolve_if_timed_out(&self, pointer: PointerId, timeout: Duration)
         true
     }
 
-    /// Force resolve with default timeout.
+    /// Force resolve with the pointer's effective timeout.
     ///
-    /// Uses [`DEFAULT_DISAMBIGUATION_TIMEOUT`] (100ms).
+    /// Uses [`timeout_for_pointer`](Self::timeout_for_pointer), which is
+    /// [`DEFAULT_DISAMBIGUATION_TIMEOUT`] (100ms) unless overridden or
+    /// extended by a member.
     #[inline]
     pub fn force_resolve_if_default_timeout(&self, pointer: PointerId) -> bool {
-        self.force_resolve_if_timed_out(pointer, DEFAULT_DISAMBIGUATION_TIMEOUT)
+        self.force_resolve_after(pointer, None)
     }
 
     /// Check all arenas and force resolve any that have timed out.
@@ -1265,13 +1365,17 @@ pub fn force_resolve_if_default_timeout(&self, pointer: PointerId) -> bool {
     /// This should be called periodically (e.g., on each frame) to handle
     /// disambiguation timeouts.
     pub fn resolve_timed_out_arenas(&self, timeout: Duration) -> usize {
+        self.resolve_timed_out_arenas_after(Some(timeout))
+    }
+
+    fn resolve_timed_out_arenas_after(&self, timeout: Option<Duration>) -> usize {
         let mut resolved_count = 0;
 
         // Collect pointers to check (avoid holding iteration lock during resolve)
         let pointers: Vec<PointerId> = self.entries.iter().map(|e| *e.key()).collect();
 
         for pointer in pointers {
-            if self.force_resolve_if_timed_out(pointer, timeout) {
+            if self.force_resolve_after(pointer, timeout) {
                 resolved_count += 1;
             }
         }
@@ -1283,12 +1387,14 @@ pub fn resolve_timed_out_arenas(&self, timeout: Duration) -> usize {
         resolved_count
     }
 
-    /// Check all arenas with default timeout.
+    /// Check all arenas against their effective timeouts.
     ///
-    /// Uses [`DEFAULT_DISAMBIGUATION_TIMEOUT`] (100ms).
+    /// Uses each pointer's [`timeout_for_pointer`](Self::timeout_for_pointer),
+    /// which is [`DEFAULT_DISAMBIGUATION_TIMEOUT`] (100ms) unless overridden or
+    /// extended by a member.
     #[inline]
     pub fn resolve_default_timed_out_arenas(&self) -> usize {
-        self.resolve_timed_out_arenas(DEFAULT_DISAMBIGUATION_TIMEOUT)
+        self.resolve_timed_out_arenas_after(None)
     }
 }
 
@@ -1309,6 +1415,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::timer::GestureTimerService;
 
     // Mock arena member for testing - implement sealed trait
     struct MockMember {
@@ -2199,4 +2306,184 @@ fn a_held_entry_defers_the_binding_sweep_until_release() {
             "release drains the deferred sweep"
         );
     }
+
+    // ========================================================================
+    // Per-pointer timeouts
+    // ========================================================================
+
+    /// A member that asks the arena for a longer disambiguation window.
+    struct HintedMember {
+        inner: MockMember,
+        timeout: Duration,
+    }
+
+    impl crate::sealed::arena_member::Sealed for HintedMember {}
+
+    impl HintedMember {
+        fn new(timeout: Duration) -> Self {
+            Self {
+                inner: MockMember::new(),
+                timeout,
+            }
+        }
+    }
+
+    impl GestureArenaMember for HintedMember {
+        fn accept_gesture(&self, pointer: PointerId) {
+            self.inner.accept_gesture(pointer);
+        }
+
+        fn reject_gesture(&self, pointer: PointerId) {
+            self.inner.reject_gesture(pointer);
+        }
+
+        fn arena_timeout(&self) -> Option<Duration> {
+            Some(self.timeout)
+        }
+    }
+
+    fn manual_arena() -> (GestureArena, Arc<flui_foundation::ManualClock>) {
+        let clock = Arc::new(flui_foundation::ManualClock::new());
+        (GestureArena::with_clock(clock.clone()), clock)
+    }
+
+    #[test]
+    fn set_timeout_for_pointer_replaces_the_default_window() {
+        let (arena, clock) = manual_arena();
+        let pointer = PointerId::PRIMARY;
+        let first = Arc::new(MockMember::new());
+        arena.add(pointer, first.clone());
+        arena.add(pointer, Arc::new(MockMember::new()));
+        arena.close(pointer);
+
+        arena.set_timeout_for_pointer(pointer, Duration::from_millis(300));
+        assert_eq!(
+            arena.timeout_for_pointer(pointer),
+            Some(Duration::from_millis(300))
+        );
+
+        clock.advance(Duration::from_millis(150));
+        assert!(!arena.force_resolve_if_default_timeout(pointer));
+
+        clock.advance(Duration::from_millis(150));
+        assert!(arena.force_resolve_if_default_timeout(pointer));
+        assert!(first.was_accepted());
+    }
+
+    #[test]
+    fn member_hints_extend_the_window_to_their_maximum() {
+        let (arena, _clock) = manual_arena();
+        let pointer = PointerId::PRIMARY;
+        assert_eq!(arena.timeout_for_pointer(pointer), None);
+
+        arena.add(pointer, Arc::new(MockMember::new()));
+        assert_eq!(
+            arena.timeout_for_pointer(pointer),
+            Some(DEFAULT_DISAMBIGUATION_TIMEOUT)
+        );
+
+        arena.add(
+            pointer,
+            Arc::new(HintedMember::new(Duration::from_millis(250))),
+        );
+        arena.add(
+            pointer,
+            Arc::new(HintedMember::new(Duration::from_millis(400))),
+        );
+        assert_eq!(
+            arena.timeout_for_pointer(pointer),
+            Some(Duration::from_millis(400))
+        );
+
+        // An override only replaces the base; it cannot cut a member's window.
+        arena.set_timeout_for_pointer(pointer, Duration::from_millis(50));
+        assert_eq!(
+            arena.timeout_for_pointer(pointer),
+            Some(Duration::from_millis(400))
+        );
+    }
+
+    /// Drive `arena`'s timeout for `pointer` the way a sleeping event loop
+    /// would: schedule a wake-up at the current deadline on `timers`, fire due
+    /// timers at `now`, and force-resolve if the wake-up fired.
+    fn fire_timeout_at(
+        arena: &GestureArena,
+        timers: &GestureTimerService,
+        pointer: PointerId,
+        now: Instant,
+    ) -> bool {
+        let Some(deadline) = arena.timeout_deadline(pointer) else {
+            return false;
+        };
+        let woke = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let flag = woke.clone();
+        let timer = timers.schedule_at(deadline, move || {
+            flag.store(true, std::sync::atomic::Ordering::SeqCst);
+        });
+        timers.check_timers_at(now);
+        timer.cancel();
+        woke.load(std::sync::atomic::Ordering::SeqCst)
+            && arena.force_resolve_if_default_timeout(pointer)
+    }
+
+    #[test]
+    fn timeout_resolves_after_the_longest_member_window() {
+        let (arena, clock) = manual_arena();
+        let timers = GestureTimerService::new();
+        let pointer = PointerId::PRIMARY;
+        let tap = Arc::new(MockMember::new());
+        let long_press = Arc::new(HintedMember::new(Duration::from_millis(500)));
+        arena.add(pointer, tap.clone());
+        arena.add(pointer, long_press.clone());
+        arena.close(pointer);
+        assert_eq!(
+            arena.timeout_deadline(pointer),
+            Some(clock.now() + Duration::from_millis(500))
+        );
+
+        // Past the default window, but the long press still needs time.
+        clock.advance(Duration::from_millis(200));
+        assert!(!fire_timeout_at(&arena, &timers, pointer, clock.now()));
+        assert_eq!(arena.resolve_default_timed_out_arenas(), 0);
+        assert!(!arena.is_resolved(pointer));
+
+        clock.advance(Duration::from_millis(300));
+        assert!(fire_timeout_at(&arena, &timers, pointer, clock.now()));
+        assert!(tap.was_accepted());
+        assert!(long_press.inner.was_rejected());
+        assert_eq!(arena.timeout_deadline(pointer), None);
+    }
+
+    #[test]
+    fn resigning_member_shrinks_the_window() {
+        let (arena, clock) = manual_arena();
+        let timers = GestureTimerService::new();
+        let pointer = PointerId::PRIMARY;
+        let start = clock.now();
+        let tap = Arc::new(MockMember::new());
+        let drag = Arc::new(MockMember::new());
+        let long_press: Arc<dyn GestureArenaMember> =
+            Arc::new(HintedMember::new(Duration::from_millis(500)));
+        arena.add(pointer, tap.clone());
+        arena.add(pointer, drag.clone());
+        let long_press_entry = arena.add(pointer, long_press);
+        arena.close(pointer);
+
+        clock.advance(Duration::from_millis(150));
+        assert!(!fire_timeout_at(&arena, &timers, pointer, clock.now()));
+
+        // The long press resigns before its window elapses: the effective
+        // timeout falls back to the default, which has already passed.
+        long_press_entry.resolve(GestureDisposition::Rejected);
+        assert!(!arena.is_resolved(pointer), "tap and drag still compete");
+        assert_eq!(
+            arena.timeout_deadline(pointer),
+            Some(start + DEFAULT_DISAMBIGUATION_TIMEOUT)
+        );
+
+        assert!(fire_timeout_at(&arena, &timers, pointer, clock.now()));
+        assert!(tap.was_accepted());
+        assert!(drag.was_rejected());
+        assert!(!timers.has_pending());
+    }
 }
@@ -37,17 +37,97 @@
 //! // Resolve conflict
 //! resolver.resolve(pointer_id, signal_event);
 //! ```
+//!
+//! # Scroll signals
+//!
+//! Scroll handlers registered with
+//! [`register_scroll`](PointerSignalResolver::register_scroll) declare the
+//! axes they consume, and [`resolve_scroll`](PointerSignalResolver::resolve_scroll)
+//! resolves each axis separately: a nested horizontal list can claim the
+//! horizontal part of a diagonal trackpad swipe while its vertical parent
+//! takes the rest.
+//!
+//! ```rust,ignore
+//! // Child list: horizontal only, and it outranks the parent.
+//! resolver.register_scroll(pointer_id, SignalPriority::High, DragAxis::Horizontal, |signal| {
+//!     carousel.scroll_by(signal.delta.dx);
+//! });
+//! resolver.register_scroll(pointer_id, SignalPriority::Normal, DragAxis::Free, |signal| {
+//!     let multiplier = if signal.precise { 1.0 } else { 3.0 };
+//!     page.scroll_by(signal.delta.dy * multiplier);
+//! });
+//! resolver.resolve_scroll(pointer_id, &scroll_event);
+//! ```
 
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
+use flui_types::{
+    Axis,
+    geometry::{Offset, PixelDelta, Pixels},
+};
+
 use crate::{
-    events::PointerEvent,
+    events::{PointerEvent, ScrollDelta, ScrollEventData},
     ids::{HandlerId, PointerId},
+    traits::DragAxis,
 };
 
 /// Callback for handling pointer signals
 pub type SignalCallback = Rc<dyn Fn(PointerEvent)>;
 
+/// Callback for handling a resolved scroll signal
+pub type ScrollSignalCallback = Rc<dyn Fn(ScrollSignal)>;
+
+/// A scroll signal as delivered to the handler that won it.
+///
+/// Carries both axes. When the axes are won by different handlers, each one
+/// receives a copy with the other axis zeroed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollSignal {
+    /// Position where the scroll occurred.
+    pub position: Offset<Pixels>,
+    /// Scroll delta in pixels, converted from lines or pages when the device
+    /// reported those.
+    pub delta: Offset<PixelDelta>,
+    /// `true` for pixel-precise deltas (trackpads, touch scrolling), `false`
+    /// for line- or page-based deltas (notched mouse wheels). Scrollables
+    /// typically apply a larger multiplier to the latter.
+    pub precise: bool,
+}
+
+impl ScrollSignal {
+    /// Extracts the scroll signal from a [`PointerEvent::Scroll`]; `None` for
+    /// any other event.
+    pub fn from_event(event: &PointerEvent) -> Option<Self> {
+        let PointerEvent::Scroll(scroll) = event else {
+            return None;
+        };
+        let data = ScrollEventData::from(scroll);
+        Some(Self {
+            position: data.position,
+            delta: data.delta,
+            precise: matches!(scroll.delta, ScrollDelta::PixelDelta(_)),
+        })
+    }
+
+    /// The component of the delta along `axis`.
+    pub fn delta_along(&self, axis: Axis) -> PixelDelta {
+        match axis {
+            Axis::Horizontal => self.delta.dx,
+            Axis::Vertical => self.delta.dy,
+        }
+    }
+
+    /// This signal with the delta outside `axis` zeroed.
+    fn restricted_to(self, axis: Axis) -> Self {
+        let delta = match axis {
+            Axis::Horizontal => Offset::new(self.delta.dx, PixelDelta(0.0)),
+            Axis::Vertical => Offset::new(PixelDelta(0.0), self.delta.dy),
+        };
+        Self { delta, ..self }
+    }
+}
+
 /// Priority level for signal handlers
 ///
 /// Higher priority handlers win conflicts.
@@ -74,6 +154,42 @@ struct SignalHandler {
     callback: SignalCallback,
 }
 
+/// A registered scroll handler and the axes it consumes
+struct ScrollHandler {
+    /// Unique ID for this handler (shared sequence with [`SignalHandler`])
+    id: HandlerId,
+    /// Priority level
+    priority: SignalPriority,
+    /// The axes this handler claims; [`DragAxis::Free`] claims both
+    axes: DragAxis,
+    /// Callback to invoke
+    callback: ScrollSignalCallback,
+}
+
+impl ScrollHandler {
+    fn claims(&self, axis: Axis) -> bool {
+        matches!(
+            (self.axes, axis),
+            (DragAxis::Free, _)
+                | (DragAxis::Horizontal, Axis::Horizontal)
+                | (DragAxis::Vertical, Axis::Vertical)
+        )
+    }
+}
+
+/// Find the highest priority scroll handler claiming `axis`.
+///
+/// Ties are broken the same way as [`find_winner`]: last registered wins.
+fn find_scroll_winner(handlers: &[ScrollHandler], axis: Axis) -> Option<&ScrollHandler> {
+    handlers
+        .iter()
+        .filter(|handler| handler.claims(axis))
+        .max_by(|a, b| match a.priority.cmp(&b.priority) {
+            std::cmp::Ordering::Equal => a.id.cmp(&b.id),
+            other => other,
+        })
+}
+
 /// Find the highest priority handler from a list.
 ///
 /// If multiple handlers have the same priority, the last registered wins.
@@ -106,6 +222,8 @@ struct ResolverInner {
     next_handler_id: u64,
     /// Handlers registered for each pointer
     handlers: HashMap<PointerId, Vec<SignalHandler>>,
+    /// Axis-aware scroll handlers registered for each pointer
+    scroll_handlers: HashMap<PointerId, Vec<ScrollHandler>>,
 }
 
 // Manual impl: the registered handlers hold `dyn Fn` callbacks, which have no
@@ -124,6 +242,7 @@ pub fn new() -> Self {
             inner: Rc::new(RefCell::new(ResolverInner {
                 next_handler_id: 1,
                 handlers: HashMap::new(),
+                scroll_handlers: HashMap::new(),
             })),
         }
     }
@@ -162,6 +281,49 @@ pub fn register<F>(
         handler_id
     }
 
+    /// Registers a scroll handler that consumes the given axes
+    ///
+    /// Returns a handler ID that can be used to unregister later. Scroll
+    /// handlers only take part in
+    /// [`resolve_scroll`](Self::resolve_scroll).
+    ///
+    /// # Arguments
+    ///
+    /// * `pointer_id` - The pointer device to listen to
+    /// * `priority` - Priority level (higher wins conflicts, per axis)
+    /// * `axes` - The axes this handler claims ([`DragAxis::Free`] for both)
+    /// * `callback` - Function to call with the part of the signal it won
+    pub fn register_scroll<F>(
+        &self,
+        pointer_id: PointerId,
+        priority: SignalPriority,
+        axes: DragAxis,
+        callback: F,
+    ) -> HandlerId
+    where
+        F: Fn(ScrollSignal) + 'static,
+    {
+        let mut inner = self.inner.borrow_mut();
+
+        let handler_id = HandlerId::new(inner.next_handler_id);
+        inner.next_handler_id += 1;
+
+        let handler = ScrollHandler {
+            id: handler_id,
+            priority,
+            axes,
+            callback: Rc::new(callback),
+        };
+
+        inner
+            .scroll_handlers
+            .entry(pointer_id)
+            .or_default()
+            .push(handler);
+
+        handler_id
+    }
+
     /// Unregisters a signal handler
     ///
     /// # Arguments
@@ -179,6 +341,14 @@ pub fn unregister(&self, pointer_id: PointerId, handler_id: HandlerId) {
                 inner.handlers.remove(&pointer_id);
             }
         }
+
+        if let Some(handlers) = inner.scroll_handlers.get_mut(&pointer_id) {
+            handlers.retain(|h| h.id != handler_id);
+
+            if handlers.is_empty() {
+                inner.scroll_handlers.remove(&pointer_id);
+            }
+        }
     }
 
     /// Resolves a signal event
@@ -228,25 +398,79 @@ pub fn resolve_and_accept(&self, pointer_id: PointerId, event: PointerEvent) ->
         }
     }
 
+    /// Resolves a scroll event axis by axis
+    ///
+    /// For each axis with a non-zero delta, finds the highest priority scroll
+    /// handler claiming that axis. A handler that wins both axes is invoked
+    /// once with the whole signal; otherwise each winner receives the signal
+    /// restricted to its axis.
+    ///
+    /// Returns true if any handler was invoked. Non-scroll events are ignored.
+    pub fn resolve_scroll(&self, pointer_id: PointerId, event: &PointerEvent) -> bool {
+        let Some(signal) = ScrollSignal::from_event(event) else {
+            return false;
+        };
+
+        let inner = self.inner.borrow();
+
+        let Some(handlers) = inner.scroll_handlers.get(&pointer_id) else {
+            return false;
+        };
+
+        let winner = |axis| {
+            (signal.delta_along(axis) != PixelDelta(0.0))
+                .then(|| find_scroll_winner(handlers, axis))
+                .flatten()
+        };
+        let deliveries: Vec<(ScrollSignalCallback, ScrollSignal)> =
+            match (winner(Axis::Horizontal), winner(Axis::Vertical)) {
+                (Some(horizontal), Some(vertical)) if horizontal.id == vertical.id => {
+                    vec![(horizontal.callback.clone(), signal)]
+                }
+                (horizontal, vertical) => horizontal
+                    .map(|h| (h.callback.clone(), signal.restricted_to(Axis::Horizontal)))
+                    .into_iter()
+                    .chain(
+                        vertical
+                            .map(|v| (v.callback.clone(), signal.restricted_to(Axis::Vertical))),
+                    )
+                    .collect(),
+            };
+
+        // Release the borrow before calling callbacks.
+        drop(inner);
+        for (callback, signal) in &deliveries {
+            callback(*signal);
+        }
+        !deliveries.is_empty()
+    }
+
     /// Clears all handlers for a pointer
     pub fn clear(&self, pointer_id: PointerId) {
         let mut inner = self.inner.borrow_mut();
         inner.handlers.remove(&pointer_id);
+        inner.scroll_handlers.remove(&pointer_id);
     }
 
     /// Clears all handlers for all pointers
     pub fn clear_all(&self) {
         let mut inner = self.inner.borrow_mut();
         inner.handlers.clear();
+        inner.scroll_handlers.clear();
     }
 
-    /// Returns the number of handlers registered for a pointer
+    /// Returns the number of handlers (including scroll handlers) registered
+    /// for a pointer
     pub fn handler_count(&self, pointer_id: PointerId) -> usize {
-        self.inner
-            .borrow()
+        let inner = self.inner.borrow();
+        inner
             .handlers
             .get(&pointer_id)
             .map_or(0, std::vec::Vec::len)
+            + inner
+                .scroll_handlers
+                .get(&pointer_id)
+                .map_or(0, std::vec::Vec::len)
     }
 }
 
@@ -258,7 +482,10 @@ fn default() -> Self {
 
 #[cfg(test)]
 mod tests {
-    use std::{cell::Cell, rc::Rc};
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
 
     use flui_types::geometry::{Offset, px};
 
@@ -412,6 +639,104 @@ fn test_resolve_and_accept() {
         assert!(called.get());
     }
 
+    #[test]
+    fn test_scroll_signal_flags_precise_and_line_deltas() {
+        let trackpad =
+            crate::events::make_scroll_event(Offset::ZERO, Offset::new(px(3.0), px(4.0)));
+        let signal = ScrollSignal::from_event(&trackpad).expect("scroll event");
+        assert!(signal.precise);
+        assert_eq!(signal.delta, Offset::new(PixelDelta(3.0), PixelDelta(4.0)));
+
+        let PointerEvent::Scroll(mut wheel) = trackpad else {
+            unreachable!()
+        };
+        wheel.delta = ScrollDelta::LineDelta(0.0, -1.0);
+        let signal = ScrollSignal::from_event(&PointerEvent::Scroll(wheel)).expect("scroll event");
+        assert!(!signal.precise);
+        assert_eq!(signal.delta_along(Axis::Vertical), PixelDelta(-20.0));
+
+        let down = crate::events::make_down_event(Offset::ZERO, crate::events::PointerType::Mouse);
+        assert!(ScrollSignal::from_event(&down).is_none());
+    }
+
+    #[test]
+    fn test_diagonal_scroll_splits_between_child_and_parent() {
+        let resolver = PointerSignalResolver::new();
+        let parent = Rc::new(RefCell::new(Vec::new()));
+        let child = Rc::new(RefCell::new(Vec::new()));
+
+        // The child registers first, so only its higher priority (not the
+        // last-registered tie-break) lets it take the horizontal axis.
+        let child_log = Rc::clone(&child);
+        resolver.register_scroll(
+            PointerId::PRIMARY,
+            SignalPriority::High,
+            DragAxis::Horizontal,
+            move |signal| child_log.borrow_mut().push(signal.delta),
+        );
+        let parent_log = Rc::clone(&parent);
+        resolver.register_scroll(
+            PointerId::PRIMARY,
+            SignalPriority::Normal,
+            DragAxis::Free,
+            move |signal| parent_log.borrow_mut().push(signal.delta),
+        );
+
+        let event = crate::events::make_scroll_event(Offset::ZERO, Offset::new(px(6.0), px(8.0)));
+        assert!(resolver.resolve_scroll(PointerId::PRIMARY, &event));
+
+        assert_eq!(
+            *child.borrow(),
+            vec![Offset::new(PixelDelta(6.0), PixelDelta(0.0))]
+        );
+        assert_eq!(
+            *parent.borrow(),
+            vec![Offset::new(PixelDelta(0.0), PixelDelta(8.0))]
+        );
+
+        // A purely vertical scroll never reaches the horizontal child.
+        let event = crate::events::make_scroll_event(Offset::ZERO, Offset::new(px(0.0), px(5.0)));
+        assert!(resolver.resolve_scroll(PointerId::PRIMARY, &event));
+        assert_eq!(child.borrow().len(), 1);
+        assert_eq!(parent.borrow().len(), 2);
+    }
+
+    #[test]
+    fn test_scroll_winner_of_both_axes_is_called_once() {
+        let resolver = PointerSignalResolver::new();
+        let low_calls = Rc::new(Cell::new(0));
+        let high_calls = Rc::new(Cell::new(0));
+
+        let low_clone = Rc::clone(&low_calls);
+        resolver.register_scroll(
+            PointerId::PRIMARY,
+            SignalPriority::Low,
+            DragAxis::Vertical,
+            move |_| low_clone.set(low_clone.get() + 1),
+        );
+        let high_clone = Rc::clone(&high_calls);
+        let high_id = resolver.register_scroll(
+            PointerId::PRIMARY,
+            SignalPriority::High,
+            DragAxis::Free,
+            move |signal| {
+                assert_eq!(signal.delta, Offset::new(PixelDelta(1.0), PixelDelta(2.0)));
+                high_clone.set(high_clone.get() + 1);
+            },
+        );
+        assert_eq!(resolver.handler_count(PointerId::PRIMARY), 2);
+
+        let event = crate::events::make_scroll_event(Offset::ZERO, Offset::new(px(1.0), px(2.0)));
+        assert!(resolver.resolve_scroll(PointerId::PRIMARY, &event));
+        assert_eq!(high_calls.get(), 1);
+        assert_eq!(low_calls.get(), 0);
+
+        // With the free handler gone, the horizontal part finds no claimant.
+        resolver.unregister(PointerId::PRIMARY, high_id);
+        assert!(resolver.resolve_scroll(PointerId::PRIMARY, &event));
+        assert_eq!(low_calls.get(), 1);
+    }
+
     #[test]
     fn signal_callback_accepts_owner_local_rc_state() {
         let resolver = PointerSignalResolver::new();
@@ -43,6 +43,20 @@
 //! // to forward to native view
 //! ```
 //!
+//! ## Cooperative
+//!
+//! A cooperative team (no captain) lets every member win together: when the
+//! team wins, each member receives the gesture rather than only the first.
+//! This lets recognizers that read the same pointers in different ways (scale
+//! and rotation) run simultaneously while still competing as one unit against
+//! outside recognizers.
+//!
+//! ```rust,ignore
+//! let team = GestureArenaTeam::cooperative();
+//! let scale = ScaleGestureRecognizer::new(arena.clone()).with_team(team.clone());
+//! let rotation = RotationGestureRecognizer::new(arena.clone()).with_team(team.clone());
+//! ```
+//!
 //! Flutter reference: <https://api.flutter.dev/flutter/gestures/GestureArenaTeam-class.html>
 
 use std::sync::Arc;
@@ -203,6 +217,16 @@ fn accept_gesture(&mut self) -> PendingTeamNotifications {
         }
         self.resolved = true;
 
+        let captain = self.team.captain();
+
+        // A cooperative team without a captain hands the gesture to everyone.
+        if self.team.is_cooperative() && captain.is_none() {
+            self.winner = None;
+            pending.accepts.extend(self.members.iter().cloned());
+            self.team.remove_combiner(self.pointer);
+            return pending;
+        }
+
         // Determine winner: pre-set winner, captain, or first member
         let winner = self.winner.take().or_else(|| {
             self.team
@@ -210,26 +234,23 @@ fn accept_gesture(&mut self) -> PendingTeamNotifications {
                 .or_else(|| self.members.first().cloned())
         });
 
-        // Check if winner is the captain (not in members list)
-        let captain = self.team.captain();
-        let winner_is_captain = winner
-            .as_ref()
-            .zip(captain.as_ref())
-            .is_some_and(|(w, c)| Arc::ptr_eq(w, c));
-
         // Queue all member notifications - they all lose except the winner
+        let mut winner_is_member = false;
         for member in &self.members {
             let is_winner = winner.as_ref().is_some_and(|w| Arc::ptr_eq(w, member));
             if is_winner {
+                winner_is_member = true;
                 pending.accepts.push(member.clone());
             } else {
                 pending.rejects.push(member.clone());
             }
         }
 
-        // If winner is the captain (not in members), notify captain separately
-        if winner_is_captain && let Some(captain) = captain {
-            pending.accepts.push(captain);
+        // A captain outside the members list (the AndroidView forwarder) is
+        // notified separately; a captain drawn from the members was accepted
+        // above and must not hear `accept_gesture` twice.
+        if !winner_is_member && let Some(winner) = winner {
+            pending.accepts.push(winner);
         }
 
         // Remove from team's combiners
@@ -266,7 +287,8 @@ fn reject_gesture(&mut self) -> PendingTeamNotifications {
 /// on parking_lot's non-reentrant mutex.
 struct PendingTeamNotifications {
     pointer: PointerId,
-    /// At most the winner and (separately) the captain.
+    /// At most the winner and (separately) the captain, or every member of a
+    /// cooperative team.
     accepts: SmallVec<[Arc<dyn GestureArenaMember>; 2]>,
     rejects: SmallVec<[Arc<dyn GestureArenaMember>; 4]>,
 }
@@ -344,6 +366,8 @@ pub struct GestureArenaTeam {
     combiners: DashMap<PointerId, Arc<Mutex<CombiningMember>>>,
     /// Captain that wins on behalf of the team.
     captain: Mutex<Option<Arc<dyn GestureArenaMember>>>,
+    /// Whether every member wins when the team wins (ignored with a captain).
+    cooperative: bool,
 }
 
 impl GestureArenaTeam {
@@ -354,6 +378,20 @@ pub fn new() -> Arc<Self> {
         Arc::new(Self {
             combiners: DashMap::new(),
             captain: Mutex::new(None),
+            cooperative: false,
+        })
+    }
+
+    /// Create a new cooperative gesture arena team.
+    ///
+    /// When the team wins, every member receives the gesture instead of only
+    /// the first. Setting a captain later overrides this: the captain alone
+    /// wins.
+    pub fn cooperative() -> Arc<Self> {
+        Arc::new(Self {
+            combiners: DashMap::new(),
+            captain: Mutex::new(None),
+            cooperative: true,
         })
     }
 
@@ -365,9 +403,16 @@ pub fn with_captain(captain: Arc<dyn GestureArenaMember>) -> Arc<Self> {
         Arc::new(Self {
             combiners: DashMap::new(),
             captain: Mutex::new(Some(captain)),
+            cooperative: false,
         })
     }
 
+    /// Whether every member wins together when the team wins.
+    #[inline]
+    pub fn is_cooperative(&self) -> bool {
+        self.cooperative
+    }
+
     /// Get the team's captain (if any).
     pub fn captain(&self) -> Option<Arc<dyn GestureArenaMember>> {
         self.captain.lock().clone()
@@ -376,7 +421,10 @@ pub fn captain(&self) -> Option<Arc<dyn GestureArenaMember>> {
     /// Set the team's captain.
     ///
     /// The captain wins on behalf of the entire team when any member claims
-    /// victory.
+    /// victory. It may be an outside recognizer that only forwards events, or
+    /// one of the members itself — e.g. the scale recognizer of a
+    /// scale+pan+rotate team, which then drives the combined gesture while
+    /// the other members are rejected. Either way it is accepted exactly once.
     pub fn set_captain(&self, captain: Option<Arc<dyn GestureArenaMember>>) {
         *self.captain.lock() = captain;
     }
@@ -450,6 +498,7 @@ fn default() -> Self {
         Self {
             combiners: DashMap::new(),
             captain: Mutex::new(None),
+            cooperative: false,
         }
     }
 }
@@ -459,13 +508,14 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("GestureArenaTeam")
             .field("active_combiners", &self.combiners.len())
             .field("has_captain", &self.captain.lock().is_some())
+            .field("cooperative", &self.cooperative)
             .finish()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
     use super::*;
 
@@ -473,7 +523,7 @@ mod tests {
     #[allow(dead_code)]
     struct MockMember {
         id: usize,
-        accepted: AtomicBool,
+        accepts: AtomicUsize,
         rejected: AtomicBool,
     }
 
@@ -483,13 +533,17 @@ impl MockMember {
         fn new(id: usize) -> Arc<Self> {
             Arc::new(Self {
                 id,
-                accepted: AtomicBool::new(false),
+                accepts: AtomicUsize::new(0),
                 rejected: AtomicBool::new(false),
             })
         }
 
         fn was_accepted(&self) -> bool {
-            self.accepted.load(Ordering::SeqCst)
+            self.accept_count() > 0
+        }
+
+        fn accept_count(&self) -> usize {
+            self.accepts.load(Ordering::SeqCst)
         }
 
         fn was_rejected(&self) -> bool {
@@ -499,7 +553,7 @@ fn was_rejected(&self) -> bool {
 
     impl GestureArenaMember for MockMember {
         fn accept_gesture(&self, _pointer: PointerId) {
-            self.accepted.store(true, Ordering::SeqCst);
+            self.accepts.fetch_add(1, Ordering::SeqCst);
         }
 
         fn reject_gesture(&self, _pointer: PointerId) {
@@ -631,6 +685,28 @@ fn test_team_first_member_wins() {
         assert!(member1.was_accepted() || member2.was_accepted());
     }
 
+    #[test]
+    fn cooperative_team_accepts_every_member() {
+        let team = GestureArenaTeam::cooperative();
+        let arena = GestureArena::new();
+        let pointer = PointerId::PRIMARY;
+
+        let member1 = MockMember::new(1);
+        let member2 = MockMember::new(2);
+
+        let _entry1 = team.add(pointer, member1.clone(), &arena);
+        let entry2 = team.add(pointer, member2.clone(), &arena);
+        arena.close(pointer);
+
+        // The second member claims the win on behalf of the team.
+        entry2.resolve(GestureDisposition::Accepted);
+
+        assert!(team.is_cooperative());
+        assert!(member1.was_accepted() && member2.was_accepted());
+        assert!(!member1.was_rejected() && !member2.was_rejected());
+        assert!(!team.contains(pointer));
+    }
+
     #[test]
     fn test_team_captain_wins() {
         let captain = MockMember::new(0);
@@ -652,6 +728,33 @@ fn test_team_captain_wins() {
         assert!(captain.was_accepted());
     }
 
+    #[test]
+    fn member_captain_handles_the_combined_gesture_once() {
+        // Scale + pan in one team with scale as captain: pan recognizing first
+        // still hands the pointer to scale, which is accepted exactly once,
+        // while pan and an outside competitor lose.
+        let scale = MockMember::new(1);
+        let pan = MockMember::new(2);
+        let outside = MockMember::new(3);
+        let team = GestureArenaTeam::new();
+        team.set_captain(Some(scale.clone()));
+        let arena = GestureArena::new();
+        let pointer = PointerId::PRIMARY;
+
+        let _scale_entry = team.add(pointer, scale.clone(), &arena);
+        let pan_entry = team.add(pointer, pan.clone(), &arena);
+        let _outside_entry = arena.add(pointer, outside.clone());
+        arena.close(pointer);
+
+        pan_entry.resolve(GestureDisposition::Accepted);
+
+        assert_eq!(scale.accept_count(), 1, "captain is accepted exactly once");
+        assert!(!scale.was_rejected());
+        assert!(pan.was_rejected() && !pan.was_accepted());
+        assert!(outside.was_rejected() && !outside.was_accepted());
+        assert!(!team.contains(pointer));
+    }
+
     #[test]
     fn test_team_member_reject_removes_from_team() {
         let team = GestureArenaTeam::new();
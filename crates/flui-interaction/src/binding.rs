@@ -76,6 +76,8 @@
 //! }
 //! ```
 
+use std::time::{Duration, Instant};
+
 use dashmap::DashMap;
 use flui_types::geometry::{Offset, Pixels};
 use smallvec::SmallVec;
@@ -84,7 +86,7 @@
 use crate::{
     arena::GestureArena,
     ids::PointerId,
-    processing::{PointerEventResampler, SamplingClock},
+    processing::{PointerEventResampler, ResampleMode, ResamplerMetrics, SamplingClock},
     routing::{
         HitTestResult, PointerRouter, ResolvedRouteToken, RoutePanic, active_dispatch_handle,
     },
@@ -178,10 +180,22 @@ pub struct GestureBinding {
     /// [`Self::flush_pending_moves`]. Off by default.
     resampling_enabled: std::sync::atomic::AtomicBool,
 
+    /// Mode applied to every resampler, current and future.
+    resample_mode: parking_lot::RwLock<ResampleMode>,
+
+    /// Counters of resamplers already dropped (Up/Cancel, or resampling
+    /// disabled), so [`Self::resampler_metrics`] covers finished gestures.
+    retired_resampler_metrics: parking_lot::Mutex<ResamplerMetrics>,
+
     /// Frame-paced clock that produces `(now, next)` pairs for the
     /// resamplers. Only consulted when `resampling_enabled` is true.
     sampling_clock: parking_lot::RwLock<SamplingClock>,
 
+    /// First input-device timestamp seen (nanoseconds) and when it was
+    /// received, anchoring platform event times to `Instant`s for the
+    /// resamplers. See [`Self::input_instant`].
+    input_clock_anchor: parking_lot::Mutex<Option<(u64, Instant)>>,
+
     /// Routes pointer events to registered handlers.
     pointer_router: PointerRouter,
 
@@ -209,7 +223,10 @@ pub fn new() -> Self {
             pending_moves: DashMap::new(),
             resamplers: DashMap::new(),
             resampling_enabled: std::sync::atomic::AtomicBool::new(false),
+            resample_mode: parking_lot::RwLock::new(ResampleMode::default()),
+            retired_resampler_metrics: parking_lot::Mutex::new(ResamplerMetrics::default()),
             sampling_clock: parking_lot::RwLock::new(SamplingClock::default()),
+            input_clock_anchor: parking_lot::Mutex::new(None),
             pointer_router: PointerRouter::new(),
             arena: GestureArena::new(),
             default_settings: GestureSettings::default(),
@@ -223,7 +240,10 @@ pub fn with_settings(settings: GestureSettings) -> Self {
             pending_moves: DashMap::new(),
             resamplers: DashMap::new(),
             resampling_enabled: std::sync::atomic::AtomicBool::new(false),
+            resample_mode: parking_lot::RwLock::new(ResampleMode::default()),
+            retired_resampler_metrics: parking_lot::Mutex::new(ResamplerMetrics::default()),
             sampling_clock: parking_lot::RwLock::new(SamplingClock::default()),
+            input_clock_anchor: parking_lot::Mutex::new(None),
             pointer_router: PointerRouter::new(),
             arena: GestureArena::new(),
             default_settings: settings,
@@ -250,7 +270,60 @@ pub fn set_resampling_enabled(&self, enabled: bool) {
         use std::sync::atomic::Ordering;
         self.resampling_enabled.store(enabled, Ordering::Release);
         if !enabled {
-            self.resamplers.clear();
+            self.retire_all_resamplers();
+        }
+    }
+
+    /// Switch every per-pointer resampler between resampling and raw
+    /// passthrough.
+    ///
+    /// Applies to the resamplers of pointers already down, from their next
+    /// sample, and to every resampler created afterwards.
+    pub fn set_resample_mode(&self, mode: ResampleMode) {
+        *self.resample_mode.write() = mode;
+        for resampler in &self.resamplers {
+            resampler.set_mode(mode);
+        }
+    }
+
+    /// The mode set by [`Self::set_resample_mode`].
+    #[inline]
+    #[must_use]
+    pub fn resample_mode(&self) -> ResampleMode {
+        *self.resample_mode.read()
+    }
+
+    /// Sample counts and average latency over every resampler this binding
+    /// has run: the active ones and those of finished gestures.
+    #[must_use]
+    pub fn resampler_metrics(&self) -> ResamplerMetrics {
+        self.resamplers.iter().fold(
+            *self.retired_resampler_metrics.lock(),
+            |metrics, resampler| metrics.merge(resampler.metrics()),
+        )
+    }
+
+    /// Zeroes the counters reported by [`Self::resampler_metrics`].
+    pub fn reset_resampler_metrics(&self) {
+        *self.retired_resampler_metrics.lock() = ResamplerMetrics::default();
+        for resampler in &self.resamplers {
+            resampler.reset_metrics();
+        }
+    }
+
+    /// Drops `pointer_id`'s resampler, keeping its counters.
+    fn retire_resampler(&self, pointer_id: PointerId) {
+        if let Some((_, resampler)) = self.resamplers.remove(&pointer_id) {
+            let mut retired = self.retired_resampler_metrics.lock();
+            *retired = retired.merge(resampler.metrics());
+        }
+    }
+
+    /// Drops every resampler, keeping their counters.
+    fn retire_all_resamplers(&self) {
+        let pointers: Vec<PointerId> = self.resamplers.iter().map(|entry| *entry.key()).collect();
+        for pointer_id in pointers {
+            self.retire_resampler(pointer_id);
         }
     }
 
@@ -393,7 +466,15 @@ pub fn handle_pointer_event<F>(&self, event: &PointerEvent, hit_test_fn: F)
                     let resampler = self
                         .resamplers
                         .entry(pointer_id)
-                        .or_insert_with(|| PointerEventResampler::new(pointer_id));
+                        // Zero offset: the binding samples on frame ticks
+                        // that stop when the app idles, so a lagging sample
+                        // point would strand the last move until the next
+                        // input.
+                        .or_insert_with(|| {
+                            PointerEventResampler::new(pointer_id)
+                                .with_offset(std::time::Duration::ZERO)
+                                .with_mode(self.resample_mode())
+                        });
                     if self.is_resampling_enabled() {
                         resampler.start_tracking();
                     }
@@ -416,11 +497,16 @@ pub fn handle_pointer_event<F>(&self, event: &PointerEvent, hit_test_fn: F)
                 self.pending_moves.insert(pointer_id, event.clone());
 
                 // Mirror to the resampler's per-pointer queue so the
-                // resampler can pace the event on the next sample().
+                // resampler can pace the event on the next sample(). The
+                // device timestamp keeps the real spacing between moves
+                // that arrived in one batch.
                 if self.is_resampling_enabled()
                     && let Some(r) = self.resamplers.get(&pointer_id)
                 {
-                    r.add_event(event.clone());
+                    match self.input_instant(event) {
+                        Some(input_time) => r.add_event_at(event.clone(), input_time),
+                        None => r.add_event(event.clone()),
+                    }
                 }
             }
 
@@ -440,10 +526,9 @@ pub fn handle_pointer_event<F>(&self, event: &PointerEvent, hit_test_fn: F)
                 // Sweep the arena
                 self.arena.sweep(pointer_id);
 
-                // Drop the resampler for this pointer. `remove` returns
-                // the owned value, so the resampler's Arc drops with
-                // last reference.
-                self.resamplers.remove(&pointer_id);
+                // Drop the resampler for this pointer, folding its
+                // counters into the binding's metrics.
+                self.retire_resampler(pointer_id);
 
                 if let Some(cached) = cached {
                     Self::release_route(cached.token);
@@ -683,6 +768,25 @@ pub fn has_pending_moves(&self) -> bool {
         !self.pending_moves.is_empty()
     }
 
+    /// Map `event`'s input-device timestamp onto an `Instant`.
+    ///
+    /// Platform timestamps are nanoseconds from an unspecified base, so the
+    /// first timestamped event anchors that base to its receive time; later
+    /// events keep their real spacing from it, and each resampler's drift
+    /// estimate absorbs any skew between the two clocks. `None` when the
+    /// platform left the timestamp unset.
+    fn input_instant(&self, event: &PointerEvent) -> Option<Instant> {
+        let time = crate::events::pointer_time_ns(event)?;
+        let mut anchor = self.input_clock_anchor.lock();
+        let (base_time, base) = *anchor.get_or_insert_with(|| (time, Instant::now()));
+        let since_base = Duration::from_nanos(time.abs_diff(base_time));
+        if time >= base_time {
+            base.checked_add(since_base)
+        } else {
+            base.checked_sub(since_base)
+        }
+    }
+
     /// Get the number of pending move events.
     #[inline]
     pub fn pending_move_count(&self) -> usize {
@@ -755,7 +859,7 @@ pub fn handle_lifecycle_pause(&self) {
         // Move on the same id post-resume.
         if !self.resamplers.is_empty() {
             let cleared = self.resamplers.len();
-            self.resamplers.clear();
+            self.retire_all_resamplers();
             tracing::debug!(
                 cleared,
                 "GestureBinding draining resamplers on lifecycle pause"
@@ -1085,6 +1189,32 @@ fn up_removes_per_pointer_resampler() {
         assert_eq!(binding.active_resampler_count(), 0);
     }
 
+    #[test]
+    fn input_instant_keeps_device_spacing_and_skips_unset_times() {
+        let binding = GestureBinding::new();
+        let move_at = |time: u64| {
+            let mut event =
+                make_move_event(Offset::new(Pixels(5.0), Pixels(5.0)), PointerType::Touch);
+            if let PointerEvent::Move(update) = &mut event {
+                update.current.time = time;
+            }
+            event
+        };
+
+        assert_eq!(binding.input_instant(&move_at(0)), None);
+        let first = binding
+            .input_instant(&move_at(1_000_000))
+            .expect("timestamped");
+        let later = binding
+            .input_instant(&move_at(9_000_000))
+            .expect("timestamped");
+        let earlier = binding
+            .input_instant(&move_at(500_000))
+            .expect("timestamped");
+        assert_eq!(later - first, Duration::from_millis(8));
+        assert_eq!(first - earlier, Duration::from_micros(500));
+    }
+
     #[test]
     fn move_with_resampling_off_uses_coalescing_path() {
         // Resampling off (default): move events go into pending_moves
@@ -1235,6 +1365,61 @@ fn flush_with_resampling_on_dispatches_move_not_drops_it() {
         );
     }
 
+    #[test]
+    fn resample_mode_applies_to_current_and_future_resamplers() {
+        let binding = GestureBinding::new();
+        binding.set_resampling_enabled(true);
+        let at = Offset::new(Pixels(5.0), Pixels(5.0));
+        binding.handle_pointer_event(&make_down_event(at, PointerType::Mouse), |_| {
+            HitTestResult::new()
+        });
+
+        binding.set_resample_mode(ResampleMode::Raw);
+        assert_eq!(binding.resample_mode(), ResampleMode::Raw);
+        let mode_of_primary = || {
+            binding
+                .resamplers
+                .get(&PointerId::PRIMARY)
+                .map(|r| r.mode())
+        };
+        assert_eq!(mode_of_primary(), Some(ResampleMode::Raw), "current");
+
+        binding.handle_pointer_event(&make_up_event(at, PointerType::Mouse), |_| {
+            HitTestResult::new()
+        });
+        binding.handle_pointer_event(&make_down_event(at, PointerType::Mouse), |_| {
+            HitTestResult::new()
+        });
+        assert_eq!(mode_of_primary(), Some(ResampleMode::Raw), "future");
+    }
+
+    #[test]
+    fn resampler_metrics_outlive_the_gesture() {
+        let binding = GestureBinding::new();
+        binding.set_resampling_enabled(true);
+        binding.set_resample_mode(ResampleMode::Raw);
+        binding.set_sampling_clock(SamplingClock::Fixed {
+            period: Duration::from_millis(8),
+        });
+        let down = make_down_event(Offset::new(Pixels(5.0), Pixels(5.0)), PointerType::Mouse);
+        binding.handle_pointer_event(&down, |_| HitTestResult::new());
+        let mv = make_move_event(Offset::new(Pixels(10.0), Pixels(20.0)), PointerType::Mouse);
+        binding.handle_pointer_event(&mv, |_| HitTestResult::new());
+        let _ = binding.flush_pending_moves();
+
+        let during = binding.resampler_metrics();
+        assert_eq!(during.real_samples, 1, "raw mode emits the move as-is");
+        assert_eq!(during.synthetic_samples, 0);
+
+        let up = make_up_event(Offset::new(Pixels(10.0), Pixels(20.0)), PointerType::Mouse);
+        binding.handle_pointer_event(&up, |_| HitTestResult::new());
+        assert_eq!(binding.active_resampler_count(), 0);
+        assert_eq!(binding.resampler_metrics(), during);
+
+        binding.reset_resampler_metrics();
+        assert_eq!(binding.resampler_metrics(), ResamplerMetrics::default());
+    }
+
     // ========================================================================
     // Owner-routed route lifecycle (ADR-0027 Task 3)
     // ========================================================================
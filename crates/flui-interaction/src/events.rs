@@ -439,6 +439,15 @@ fn get_pointer_state(event: &PointerEvent) -> Option<&PointerState> {
     }
 }
 
+/// Input-device timestamp of `event` in nanoseconds, or `None` if the event
+/// carries no pointer state or the platform left the time unset (`0`).
+#[inline]
+pub(crate) fn pointer_time_ns(event: &PointerEvent) -> Option<u64> {
+    get_pointer_state(event)
+        .map(|state| state.time)
+        .filter(|&time| time != 0)
+}
+
 // ============================================================================
 // Helper trait for extracting position from pointer events
 // ============================================================================
@@ -198,8 +198,8 @@
 // ============================================================================
 pub use arena::{
     DEFAULT_DISAMBIGUATION_TIMEOUT, GestureArena, GestureArenaEntry, GestureArenaMember,
-    GestureArenaTeam, GestureDisposition, PointerSignalResolver, SignalPriority, SweepModel,
-    TeamEntry, run_pointer_lifecycle,
+    GestureArenaTeam, GestureDisposition, PointerSignalResolver, ScrollSignal, SignalPriority,
+    SweepModel, TeamEntry, run_pointer_lifecycle,
 };
 // ============================================================================
 // Re-exports: Other
@@ -240,7 +240,8 @@
 // ============================================================================
 pub use processing::{
     InputMode, InputPredictor, PointerEventResampler, PredictedPosition, PredictionConfig,
-    RawInputHandler, RawPointerEvent, Velocity, VelocityEstimate, VelocityTracker,
+    RawInputHandler, RawPointerEvent, ResampleMode, ResamplerMetrics, Velocity, VelocityEstimate,
+    VelocityEstimationStrategy, VelocityTracker,
 };
 pub use recognizers::{
     DoubleTapGestureRecognizer, DragCancelCallback, DragDownCallback, DragDownDetails,
@@ -248,15 +249,15 @@
     DragUpdateCallback, DragUpdateDetails, EagerGestureRecognizer, ForcePressGestureRecognizer,
     GestureRecognizer, LongPressGestureRecognizer, MultiDragAxis, MultiDragEndDetails,
     MultiDragGestureRecognizer, MultiDragHandle, MultiDragStartCallback, MultiDragUpdateDetails,
-    MultiTapGestureRecognizer, ScaleGestureRecognizer, TapAndDragGestureRecognizer,
-    TapDragDownCallback, TapDragDownDetails, TapDragEndCallback, TapDragEndDetails,
-    TapDragStartCallback, TapDragStartDetails, TapDragUpCallback, TapDragUpDetails,
-    TapDragUpdateCallback, TapDragUpdateDetails, TapGestureRecognizer,
+    MultiTapGestureRecognizer, RotationGestureRecognizer, ScaleGestureRecognizer,
+    TapAndDragGestureRecognizer, TapDragDownCallback, TapDragDownDetails, TapDragEndCallback,
+    TapDragEndDetails, TapDragStartCallback, TapDragStartDetails, TapDragUpCallback,
+    TapDragUpDetails, TapDragUpdateCallback, TapDragUpdateDetails, TapGestureRecognizer,
 };
 // Re-exports for drag axis sub-recognisers (Flutter parity for
 // `VerticalDragGestureRecognizer` / `HorizontalDragGestureRecognizer` /
-// `PanGestureRecognizer`). Aliased to `DragGestureRecognizer` so a
-// recogniser's axis is fixed at the type level.
+// `PanGestureRecognizer`). The vertical/horizontal names alias
+// `DragGestureRecognizer`; `PanGestureRecognizer` is its own type.
 pub use recognizers::drag_variants::{
     HorizontalDragGestureRecognizer, PanGestureRecognizer, VerticalDragGestureRecognizer,
 };
@@ -264,21 +265,22 @@
 // Re-exports: Event Routing
 // ============================================================================
 pub use routing::{
-    EventPropagation, EventRouter, FocusManager, FocusNode, FocusScopeNode, FocusTraversalPolicy,
-    GlobalPointerHandler, HitTestBehavior, HitTestEntry, HitTestResult, HitTestable,
-    InteractionDispatchError, InteractionDispatchHandle, InteractionLane, KeyEventCallback,
-    KeyEventHandler, KeyEventResult, MouseRegionCallbacks, MouseRegionTarget, PathClipTarget,
-    PointerRouteHandler, PointerRouter, PointerTarget, ReadingOrderPolicy, RectProvider, RenderId,
-    ResolvedRouteToken, ResolvedStep, RoutePanic, RouteResolution, RouteResolutionMiss,
-    ScrollTarget, ShaderMaskTarget, TransformGuard, TraversalEdgeBehavior,
+    ActionCallback, ActionMap, EventPropagation, EventRouter, FocusManager, FocusNode,
+    FocusScopeNode, FocusTraversalPolicy, GlobalPointerHandler, HitTestBehavior, HitTestEntry,
+    HitTestResult, HitTestable, Intent, InteractionDispatchError, InteractionDispatchHandle,
+    InteractionLane, KeyCombination, KeyEventCallback, KeyEventHandler, KeyEventResult,
+    MouseRegionCallbacks, MouseRegionTarget, PathClipTarget, PointerRouteHandler, PointerRouter,
+    PointerTarget, ReadingOrderPolicy, RectProvider, RenderId, ResolvedRouteToken, ResolvedStep,
+    RoutePanic, RouteResolution, RouteResolutionMiss, ScrollTarget, ShaderMaskTarget,
+    ShortcutRegistry, TransformGuard, TraversalDirection, TraversalEdgeBehavior,
     resolve_path_clip_target, resolve_shader_mask_target,
 };
 pub use sealed::{CustomGestureRecognizer, CustomHitTestable};
 pub use settings::{
     DEFAULT_DOUBLE_TAP_SLOP, DEFAULT_DOUBLE_TAP_TIMEOUT, DEFAULT_LONG_PRESS_TIMEOUT,
     DEFAULT_MAX_FLING_VELOCITY, DEFAULT_MIN_FLING_VELOCITY, DEFAULT_MOUSE_SLOP, DEFAULT_PAN_SLOP,
-    DEFAULT_PAN_SLOP_HORIZONTAL, DEFAULT_PAN_SLOP_VERTICAL, DEFAULT_PEN_SLOP, DEFAULT_SCALE_SLOP,
-    DEFAULT_TOUCH_SLOP, GestureSettings,
+    DEFAULT_PAN_SLOP_HORIZONTAL, DEFAULT_PAN_SLOP_VERTICAL, DEFAULT_PEN_SLOP,
+    DEFAULT_ROTATION_SLOP, DEFAULT_SCALE_SLOP, DEFAULT_TOUCH_SLOP, GestureSettings,
 };
 pub use text_input::{
     ClientToken, ImeEventCallback, OpaqueWindowHandle, TextInputHandle, TextInputRegistry,
@@ -289,7 +291,7 @@
 #[cfg(any(test, feature = "testing"))]
 pub use testing::{
     GestureBuilder, GesturePlayer, GestureRecorder, GestureRecording, ModifiersBuilder,
-    RecordedEvent, RecordedEventType,
+    PlaybackMode, RecordedEvent, RecordedEventType,
 };
 pub use timer::{GestureTimer, GestureTimerService, TimerId, global_timer_service};
 // ============================================================================
@@ -345,9 +347,9 @@ pub mod prelude {
         ids::{DeviceId, FocusNodeId, HandlerId, PointerId, RegionId},
         recognizers::{
             DoubleTapGestureRecognizer, DragGestureRecognizer, ForcePressGestureRecognizer,
-            LongPressGestureRecognizer, MultiTapGestureRecognizer, ScaleGestureRecognizer,
-            TapGestureRecognizer, double_tap::*, drag::*, force_press::*, long_press::*,
-            multi_tap::*, scale::*, tap::*,
+            LongPressGestureRecognizer, MultiTapGestureRecognizer, RotationGestureRecognizer,
+            ScaleGestureRecognizer, TapGestureRecognizer, double_tap::*, drag::*, force_press::*,
+            long_press::*, multi_tap::*, rotation::*, scale::*, tap::*,
         },
     };
 }
@@ -35,9 +35,12 @@
 pub use one_euro::{OneEuroFilter, OneEuroFilter2D};
 pub use prediction::{InputPredictor, PredictedPosition, PredictionConfig};
 pub use raw_input::{InputMode, RawInputHandler, RawPointerEvent};
-pub use resampler::{DEFAULT_RESAMPLE_LOOKBACK, PointerEventResampler};
+pub use resampler::{
+    DEFAULT_RESAMPLE_LOOKBACK, DEFAULT_RESAMPLE_OFFSET, PointerEventResampler, ResampleMode,
+    ResamplerMetrics, ResamplerStats,
+};
 pub use sampling_clock::{DEFAULT_SAMPLE_PERIOD, SamplingClock};
 pub use velocity::{
     ImpulseVelocityTracker, IosFlingVelocityTracker, MacosFlingVelocityTracker, Velocity,
-    VelocityEstimate, VelocityTracker,
+    VelocityEstimate, VelocityEstimationStrategy, VelocityTracker,
 };
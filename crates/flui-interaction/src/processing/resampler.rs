@@ -21,6 +21,27 @@
 //!              Interpolation Logic
 //! ```
 //!
+//! # Timing
+//!
+//! Each frame samples at `sample_time - resample_offset` (see
+//! [`PointerEventResampler::with_offset`]), so events that arrive just before
+//! the frame are interpolated toward instead of being emitted late. Events
+//! added with [`PointerEventResampler::add_event_at`] carry the input
+//! device's own timestamp; the resampler tracks the drift between that clock
+//! and the frame clock and corrects for it gradually (see
+//! [`PointerEventResampler::stats`]).
+//!
+//! # Modes
+//!
+//! [`ResampleMode::Raw`] turns the resampler into a passthrough: every
+//! buffered event is emitted on the next sample, unshifted and with no
+//! synthesized moves. Latency-sensitive apps (drawing, inking) trade the
+//! smoothing away for the shortest input-to-frame path; the mode can be
+//! switched at runtime with [`PointerEventResampler::set_mode`].
+//! [`PointerEventResampler::metrics`] reports how many real and synthetic
+//! samples were emitted and the average input-to-frame latency, so the
+//! trade-off can be measured.
+//!
 //! # Type System Features
 //!
 //! - **Newtype pattern**: Uses `PointerId` for type-safe pointer identification
@@ -68,6 +89,18 @@
 /// appropriate instead — pass your own offset to [`PointerEventResampler::sample`].
 pub const DEFAULT_RESAMPLE_LOOKBACK: Duration = Duration::from_millis(38);
 
+/// Default for [`PointerEventResampler::with_offset`]: how far behind the
+/// frame's sample time the resampler samples. Roughly one input-report
+/// interval on a 240 Hz digitizer, so an event delivered just before the
+/// frame is still ahead of the sample point and gets interpolated toward.
+pub const DEFAULT_RESAMPLE_OFFSET: Duration = Duration::from_millis(5);
+
+/// Fraction of the gap between the measured and the estimated clock drift
+/// closed per timestamped event. Small enough that delivery jitter and clock
+/// steps are absorbed over tens of events instead of shifting the timeline at
+/// once.
+const DRIFT_CORRECTION_RATE: f64 = 0.1;
+
 /// Maximum number of events to buffer (prevents unbounded memory growth)
 const MAX_BUFFERED_EVENTS: usize = 100;
 
@@ -83,8 +116,73 @@
 struct BufferedEvent {
     /// The pointer event
     event: PointerEvent,
-    /// Time when the event was received
+    /// Event time: on the input clock when `input_clock`, otherwise the
+    /// receive time (already on the frame clock)
     timestamp: Instant,
+    /// Whether `timestamp` came from [`PointerEventResampler::add_event_at`]
+    input_clock: bool,
+}
+
+/// Snapshot of a [`PointerEventResampler`]'s timing state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResamplerStats {
+    /// Smoothed offset from the input clock to the frame clock, in seconds:
+    /// adding it to an input timestamp places the event on the frame clock.
+    /// Positive when input timestamps run behind the frame clock. Zero until
+    /// the first [`PointerEventResampler::add_event_at`].
+    pub drift_secs: f64,
+    /// The resample offset in effect.
+    pub resample_offset: Duration,
+    /// Events currently buffered.
+    pub pending_events: usize,
+}
+
+/// How a [`PointerEventResampler`] turns buffered events into samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResampleMode {
+    /// Sample behind the frame time and interpolate toward pending moves
+    /// (default).
+    #[default]
+    Resample,
+    /// Passthrough: emit every buffered event as-is on the next sample, with
+    /// no resample offset and no interpolation.
+    Raw,
+}
+
+/// Counters accumulated by a [`PointerEventResampler`] since creation or the
+/// last [`PointerEventResampler::reset_metrics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResamplerMetrics {
+    /// Buffered input events emitted unchanged.
+    pub real_samples: u64,
+    /// Interpolated moves synthesized between real events.
+    pub synthetic_samples: u64,
+    /// Mean time from an event's (drift-corrected) input time to the frame
+    /// that emitted it, over the real samples. Zero before the first one.
+    pub average_latency: Duration,
+}
+
+impl ResamplerMetrics {
+    /// Combines the counters of two resamplers, weighting each average
+    /// latency by its real sample count.
+    pub(crate) fn merge(self, other: Self) -> Self {
+        let real_samples = self.real_samples + other.real_samples;
+        let total_latency = |metrics: Self| {
+            let count = u32::try_from(metrics.real_samples).unwrap_or(u32::MAX);
+            metrics.average_latency.saturating_mul(count)
+        };
+        let average_latency = u32::try_from(real_samples)
+            .ok()
+            .filter(|&count| count > 0)
+            .map_or(Duration::ZERO, |count| {
+                total_latency(self).saturating_add(total_latency(other)) / count
+            });
+        Self {
+            real_samples,
+            synthetic_samples: self.synthetic_samples + other.synthetic_samples,
+            average_latency,
+        }
+    }
 }
 
 /// Pointer event resampler for smooth motion
@@ -114,6 +212,19 @@ struct ResamplerInner {
     last_position: Option<Offset<Pixels>>,
     /// Last sample time
     last_sample_time: Option<Instant>,
+    /// How far behind the caller's sample time to sample
+    resample_offset: Duration,
+    /// Smoothed input-clock → frame-clock offset in seconds (`None` until
+    /// the first timestamped event)
+    drift_secs: Option<f64>,
+    /// Resample or pass through
+    mode: ResampleMode,
+    /// Real samples emitted
+    real_samples: u64,
+    /// Interpolated samples emitted
+    synthetic_samples: u64,
+    /// Summed input-to-frame latency of the real samples
+    total_latency: Duration,
 }
 
 impl PointerEventResampler {
@@ -127,10 +238,83 @@ pub fn new(pointer_id: PointerId) -> Self {
                 is_tracked: false,
                 last_position: None,
                 last_sample_time: None,
+                resample_offset: DEFAULT_RESAMPLE_OFFSET,
+                drift_secs: None,
+                mode: ResampleMode::Resample,
+                real_samples: 0,
+                synthetic_samples: 0,
+                total_latency: Duration::ZERO,
             })),
         }
     }
 
+    /// Sets how far behind the sample time passed to [`Self::sample`] the
+    /// resampler actually samples. Defaults to [`DEFAULT_RESAMPLE_OFFSET`];
+    /// `Duration::ZERO` samples exactly at the given time.
+    ///
+    /// A larger offset adds latency but leaves more real events ahead of the
+    /// sample point to interpolate toward, which smooths uneven input.
+    #[must_use]
+    pub fn with_offset(self, offset: Duration) -> Self {
+        self.inner.lock().resample_offset = offset;
+        self
+    }
+
+    /// Starts the resampler in `mode` (builder form of [`Self::set_mode`]).
+    #[must_use]
+    pub fn with_mode(self, mode: ResampleMode) -> Self {
+        self.set_mode(mode);
+        self
+    }
+
+    /// Switches between resampling and raw passthrough. Takes effect on the
+    /// next [`Self::sample`]; buffered events are kept.
+    pub fn set_mode(&self, mode: ResampleMode) {
+        self.inner.lock().mode = mode;
+    }
+
+    /// The mode in effect — see [`Self::set_mode`].
+    pub fn mode(&self) -> ResampleMode {
+        self.inner.lock().mode
+    }
+
+    /// Sample counts and average latency accumulated so far.
+    pub fn metrics(&self) -> ResamplerMetrics {
+        let inner = self.inner.lock();
+        let average_latency = u32::try_from(inner.real_samples)
+            .ok()
+            .filter(|&count| count > 0)
+            .map_or(Duration::ZERO, |count| inner.total_latency / count);
+        ResamplerMetrics {
+            real_samples: inner.real_samples,
+            synthetic_samples: inner.synthetic_samples,
+            average_latency,
+        }
+    }
+
+    /// Zeroes the counters reported by [`Self::metrics`].
+    pub fn reset_metrics(&self) {
+        let mut inner = self.inner.lock();
+        inner.real_samples = 0;
+        inner.synthetic_samples = 0;
+        inner.total_latency = Duration::ZERO;
+    }
+
+    /// The resample offset in effect — see [`Self::with_offset`].
+    pub fn resample_offset(&self) -> Duration {
+        self.inner.lock().resample_offset
+    }
+
+    /// Current timing state: clock drift, offset and queue depth.
+    pub fn stats(&self) -> ResamplerStats {
+        let inner = self.inner.lock();
+        ResamplerStats {
+            drift_secs: inner.drift_secs.unwrap_or(0.0),
+            resample_offset: inner.resample_offset,
+            pending_events: inner.event_queue.len(),
+        }
+    }
+
     /// Marks the pointer as down and tracked WITHOUT queueing an event.
     ///
     /// The binding dispatches the `Down` event directly (synchronously, to
@@ -147,37 +331,34 @@ pub fn start_tracking(&self) {
     /// Adds a pointer event to the resampling queue
     ///
     /// Events are buffered and will be processed during the next `sample()`
-    /// call.
+    /// call. The event is timed by when it is added; use
+    /// [`Self::add_event_at`] when the input device's timestamp is known.
     pub fn add_event(&self, event: PointerEvent) {
-        let mut inner = self.inner.lock();
+        self.inner.lock().push(event, None);
+    }
 
-        // Update tracking state
-        match &event {
-            PointerEvent::Down(..) => {
-                inner.is_down = true;
-                inner.is_tracked = true;
-            }
-            PointerEvent::Up(..) | PointerEvent::Cancel(..) => {
-                inner.is_down = false;
-            }
-            PointerEvent::Leave(..) => {
-                inner.is_tracked = false;
-            }
-            _ => {}
-        }
+    /// Adds a pointer event stamped by the input device's clock.
+    ///
+    /// Input timestamps preserve the real spacing between samples, which
+    /// delivery batching destroys, but their clock need not match the frame
+    /// clock. Each call measures the offset between `input_time` and now and
+    /// folds it into a smoothed drift estimate, so a clock step or slow drift
+    /// realigns the timeline gradually instead of making it jump.
+    pub fn add_event_at(&self, event: PointerEvent, input_time: Instant) {
+        self.add_event_received(event, input_time, Instant::now());
+    }
 
-        // Add to queue (with size limit)
-        if inner.event_queue.len() < MAX_BUFFERED_EVENTS {
-            inner.event_queue.push_back(BufferedEvent {
-                event,
-                timestamp: Instant::now(),
-            });
-        } else {
-            tracing::warn!(
-                pointer_id = ?inner.pointer_id,
-                "Event queue full, dropping event"
-            );
-        }
+    /// [`Self::add_event_at`] with an explicit receive time on the frame
+    /// clock.
+    fn add_event_received(&self, event: PointerEvent, input_time: Instant, received: Instant) {
+        let mut inner = self.inner.lock();
+        let measured = signed_secs(received, input_time);
+        let drift = match inner.drift_secs {
+            Some(drift) => drift + (measured - drift) * DRIFT_CORRECTION_RATE,
+            None => measured,
+        };
+        inner.drift_secs = Some(drift);
+        inner.push(event, Some(input_time));
     }
 
     /// Samples events at the specified time and invokes callback with resampled
@@ -185,7 +366,8 @@ pub fn add_event(&self, event: PointerEvent) {
     ///
     /// # Arguments
     ///
-    /// * `sample_time` - Current sample time (typically current frame time)
+    /// * `sample_time` - Current sample time (typically current frame time);
+    ///   the resampler samples [`Self::resample_offset`] behind it
     /// * `next_sample_time` - Next expected sample time (for interpolation)
     /// * `callback` - Function to call with each resampled event
     ///
@@ -195,6 +377,9 @@ pub fn add_event(&self, event: PointerEvent) {
     /// - Duplicate positions are removed
     /// - Positions are interpolated for smooth motion
     /// - Move events are only generated if position changed
+    ///
+    /// In [`ResampleMode::Raw`] every buffered event is emitted in order,
+    /// regardless of its timestamp, and nothing is synthesized.
     pub fn sample<F>(&self, sample_time: Instant, next_sample_time: Instant, mut callback: F)
     where
         F: FnMut(PointerEvent),
@@ -215,9 +400,27 @@ pub fn sample<F>(&self, sample_time: Instant, next_sample_time: Instant, mut cal
 
         inner.last_sample_time = Some(sample_time);
 
+        if inner.mode == ResampleMode::Raw {
+            while let Some(buffered) = inner.event_queue.pop_front() {
+                inner.last_position = Some(buffered.event.position());
+                inner.record_real(&buffered, sample_time);
+                callback(buffered.event);
+            }
+            return;
+        }
+
+        let frame_time = sample_time;
+
+        // Sample `resample_offset` behind the caller's clock.
+        let offset = inner.resample_offset;
+        let sample_time = sample_time.checked_sub(offset).unwrap_or(sample_time);
+        let next_sample_time = next_sample_time
+            .checked_sub(offset)
+            .unwrap_or(next_sample_time);
+
         // Process all events up to sample_time
         while let Some(front) = inner.event_queue.front() {
-            if front.timestamp > sample_time {
+            if inner.aligned(front) > sample_time {
                 break; // Future event, wait for next sample
             }
 
@@ -229,6 +432,7 @@ pub fn sample<F>(&self, sample_time: Instant, next_sample_time: Instant, mut cal
                 .event_queue
                 .pop_front()
                 .expect("event_queue front returned Some in the loop guard");
+            inner.record_real(&buffered, frame_time);
             let event = buffered.event;
 
             // Update last position for interpolation
@@ -250,7 +454,7 @@ pub fn sample<F>(&self, sample_time: Instant, next_sample_time: Instant, mut cal
             && let Some(last_pos) = inner.last_position
         {
             let next_pos = next_event.event.position();
-            let total_duration = next_event.timestamp.duration_since(sample_time);
+            let total_duration = inner.aligned(next_event).duration_since(sample_time);
             let sample_duration = next_sample_time.duration_since(sample_time);
 
             if total_duration > Duration::ZERO {
@@ -274,6 +478,7 @@ pub fn sample<F>(&self, sample_time: Instant, next_sample_time: Instant, mut cal
                         );
                     }
                     inner.last_position = Some(interpolated_pos);
+                    inner.synthetic_samples += 1;
                     callback(interpolated);
                 }
             }
@@ -290,7 +495,9 @@ pub fn stop<F>(&self, mut callback: F)
         let mut inner = self.inner.lock();
 
         // Flush all remaining events
+        let now = Instant::now();
         while let Some(buffered) = inner.event_queue.pop_front() {
+            inner.record_real(&buffered, now);
             callback(buffered.event);
         }
 
@@ -329,6 +536,78 @@ pub fn clear(&self) {
     }
 }
 
+impl ResamplerInner {
+    /// Update tracking state and buffer `event`, stamped `input_time` on the
+    /// input clock (now when `None`).
+    fn push(&mut self, event: PointerEvent, input_time: Option<Instant>) {
+        // Update tracking state
+        match &event {
+            PointerEvent::Down(..) => {
+                self.is_down = true;
+                self.is_tracked = true;
+            }
+            PointerEvent::Up(..) | PointerEvent::Cancel(..) => {
+                self.is_down = false;
+            }
+            PointerEvent::Leave(..) => {
+                self.is_tracked = false;
+            }
+            _ => {}
+        }
+
+        // Add to queue (with size limit)
+        if self.event_queue.len() < MAX_BUFFERED_EVENTS {
+            self.event_queue.push_back(BufferedEvent {
+                event,
+                timestamp: input_time.unwrap_or_else(Instant::now),
+                input_clock: input_time.is_some(),
+            });
+        } else {
+            tracing::warn!(
+                pointer_id = ?self.pointer_id,
+                "Event queue full, dropping event"
+            );
+        }
+    }
+
+    /// Count `buffered` as a real sample emitted for the frame at
+    /// `frame_time`.
+    fn record_real(&mut self, buffered: &BufferedEvent, frame_time: Instant) {
+        self.real_samples += 1;
+        self.total_latency += frame_time.saturating_duration_since(self.aligned(buffered));
+    }
+
+    /// `buffered`'s time on the frame clock, drift-corrected when it was
+    /// stamped by the input clock.
+    fn aligned(&self, buffered: &BufferedEvent) -> Instant {
+        if buffered.input_clock {
+            shift(buffered.timestamp, self.drift_secs.unwrap_or(0.0))
+        } else {
+            buffered.timestamp
+        }
+    }
+}
+
+/// `a - b` in seconds, negative when `a` is earlier.
+fn signed_secs(a: Instant, b: Instant) -> f64 {
+    match a.checked_duration_since(b) {
+        Some(ahead) => ahead.as_secs_f64(),
+        None => -b.duration_since(a).as_secs_f64(),
+    }
+}
+
+/// `instant` moved by `secs` (either sign), saturating at the representable
+/// range.
+fn shift(instant: Instant, secs: f64) -> Instant {
+    let magnitude = Duration::from_secs_f64(secs.abs());
+    let shifted = if secs >= 0.0 {
+        instant.checked_add(magnitude)
+    } else {
+        instant.checked_sub(magnitude)
+    };
+    shifted.unwrap_or(instant)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -351,7 +630,7 @@ fn sample_emits_interpolated_move_between_pending_events() {
         // Regression: the interpolated position used to be computed (and
         // last_position advanced) but the synthesized Move was never emitted,
         // so recognisers stalled until the next real sensor event.
-        let resampler = PointerEventResampler::new(PointerId::PRIMARY);
+        let resampler = PointerEventResampler::new(PointerId::PRIMARY).with_offset(Duration::ZERO);
         resampler.start_tracking();
 
         // First move is in the past relative to the sample time.
@@ -416,7 +695,7 @@ fn test_add_event() {
 
     #[test]
     fn test_sample_events() {
-        let resampler = PointerEventResampler::new(PointerId::PRIMARY);
+        let resampler = PointerEventResampler::new(PointerId::PRIMARY).with_offset(Duration::ZERO);
 
         // Add down event
         let event = make_down_event(Offset::new(Pixels(10.0), Pixels(20.0)), PointerType::Mouse);
@@ -466,4 +745,202 @@ fn test_clear() {
 
         assert!(!resampler.has_pending_events());
     }
+
+    fn move_to(x: f32) -> PointerEvent {
+        crate::events::make_move_event_for_id(
+            PointerId::PRIMARY,
+            Offset::new(Pixels(x), Pixels(0.0)),
+            PointerType::Touch,
+        )
+    }
+
+    #[test]
+    fn offset_defaults_and_is_configurable() {
+        let resampler = PointerEventResampler::new(PointerId::PRIMARY);
+        assert_eq!(resampler.resample_offset(), DEFAULT_RESAMPLE_OFFSET);
+        let stats = resampler.stats();
+        assert_eq!(stats.drift_secs, 0.0);
+        assert_eq!(stats.pending_events, 0);
+
+        let resampler = resampler.with_offset(Duration::from_millis(12));
+        assert_eq!(resampler.stats().resample_offset, Duration::from_millis(12));
+    }
+
+    #[test]
+    fn offset_holds_back_events_newer_than_the_sample_point() {
+        let resampler =
+            PointerEventResampler::new(PointerId::PRIMARY).with_offset(Duration::from_millis(10));
+        resampler.start_tracking();
+        let base = Instant::now();
+        // Input clock == frame clock (zero drift).
+        resampler.add_event_received(move_to(1.0), base, base);
+        resampler.add_event_received(
+            move_to(2.0),
+            base + Duration::from_millis(8),
+            base + Duration::from_millis(8),
+        );
+
+        let mut emitted = Vec::new();
+        // Samples at base + 5 ms: only the first move is behind it.
+        resampler.sample(
+            base + Duration::from_millis(15),
+            base + Duration::from_millis(16),
+            |event| emitted.push(event.position().dx.get()),
+        );
+        assert_eq!(emitted[0], 1.0);
+        assert_eq!(resampler.stats().pending_events, 1);
+    }
+
+    #[test]
+    fn drift_realigns_gradually_after_a_clock_step() {
+        let resampler = PointerEventResampler::new(PointerId::PRIMARY);
+        let base = Instant::now();
+        let ms = Duration::from_millis;
+
+        // Input timestamps lag the frame clock by a steady 20 ms.
+        for i in 0..50 {
+            let input = base + ms(i * 8);
+            resampler.add_event_received(move_to(0.0), input, input + ms(20));
+        }
+        resampler.clear();
+        assert!((resampler.stats().drift_secs - 0.020).abs() < 1e-6);
+
+        // The input clock steps back by 50 ms: the measured lag jumps to
+        // 70 ms, but the estimate moves only part of the way per event.
+        let mut drifts = Vec::new();
+        for i in 50..100 {
+            resampler.add_event_received(
+                move_to(0.0),
+                base + ms(i * 8 - 50),
+                base + ms(i * 8 + 20),
+            );
+            drifts.push(resampler.stats().drift_secs);
+        }
+        assert!(
+            drifts[0] < 0.030,
+            "first event after the step must not jump, got {}",
+            drifts[0]
+        );
+        assert!(
+            drifts.windows(2).all(|w| w[1] >= w[0]),
+            "realigns monotonically"
+        );
+        assert!(
+            (drifts[drifts.len() - 1] - 0.070).abs() < 0.001,
+            "converges to the new offset, got {}",
+            drifts[drifts.len() - 1]
+        );
+    }
+
+    #[test]
+    fn raw_mode_emits_exactly_the_input_samples() {
+        let resampler = PointerEventResampler::new(PointerId::PRIMARY);
+        assert_eq!(resampler.mode(), ResampleMode::Resample);
+        resampler.start_tracking();
+        let base = Instant::now();
+        let ms = Duration::from_millis;
+
+        // Sparse input, one event still ahead of the frame: resampling would
+        // hold it back and interpolate toward it.
+        for (at, x) in [(0, 0.0), (8, 10.0), (16, 20.0), (40, 30.0)] {
+            resampler.add_event_received(move_to(x), base + ms(at), base + ms(at));
+        }
+
+        // Switched at runtime, before the first sample.
+        resampler.set_mode(ResampleMode::Raw);
+        let mut emitted = Vec::new();
+        resampler.sample(base + ms(20), base + ms(36), |event| {
+            emitted.push(event.position().dx.get());
+        });
+
+        assert_eq!(emitted, vec![0.0, 10.0, 20.0, 30.0]);
+        assert!(!resampler.has_pending_events());
+        let metrics = resampler.metrics();
+        assert_eq!(metrics.real_samples, 4);
+        assert_eq!(metrics.synthetic_samples, 0);
+        // Latencies 20, 12, 4 and 0 (the early event saturates) ms.
+        assert_eq!(metrics.average_latency, ms(9));
+    }
+
+    #[test]
+    fn resampling_sparse_input_reports_synthetic_samples() {
+        let resampler = PointerEventResampler::new(PointerId::PRIMARY);
+        resampler.start_tracking();
+        let base = Instant::now();
+        let ms = Duration::from_millis;
+
+        // Two moves 50 ms apart: the frame between them gets an interpolated
+        // move toward the second.
+        resampler.add_event_received(move_to(0.0), base, base);
+        resampler.add_event_received(move_to(100.0), base + ms(50), base + ms(50));
+
+        let mut emitted = Vec::new();
+        resampler.sample(base + ms(20), base + ms(36), |event| {
+            emitted.push(event.position().dx.get());
+        });
+
+        assert_eq!(emitted.len(), 2, "one real and one synthetic move");
+        assert!(emitted[1] > 0.0 && emitted[1] < 100.0);
+        let metrics = resampler.metrics();
+        assert_eq!(metrics.real_samples, 1);
+        assert_eq!(metrics.synthetic_samples, 1);
+        assert_eq!(metrics.average_latency, ms(20));
+
+        resampler.reset_metrics();
+        assert_eq!(resampler.metrics(), ResamplerMetrics::default());
+    }
+
+    #[test]
+    fn non_vsync_input_resamples_smoothly_and_monotonically() {
+        // 1000 px/s swipe reported every 7 ms on the input clock, delivered
+        // with 0–4 ms of jitter and a 30 ms clock offset, sampled at 60 Hz.
+        let resampler = PointerEventResampler::new(PointerId::PRIMARY);
+        resampler.start_tracking();
+        let base = Instant::now();
+        let ms = Duration::from_millis;
+        let frame = Duration::from_micros(16_667);
+
+        let events: Vec<(Instant, Instant, f32)> = (0..40_u32)
+            .map(|i| {
+                let input = base + ms(u64::from(i) * 7);
+                let received = input + ms(30) + ms(u64::from(i % 3) * 2);
+                #[expect(clippy::cast_precision_loss, reason = "small test indices")]
+                let x = i as f32 * 7.0;
+                (input, received, x)
+            })
+            .collect();
+
+        let mut emitted: Vec<f32> = Vec::new();
+        let mut next = 0;
+        let mut frame_time = base + ms(30);
+        while next < events.len() || resampler.has_pending_events() {
+            while next < events.len() && events[next].1 <= frame_time {
+                let (input, received, x) = events[next];
+                resampler.add_event_received(move_to(x), input, received);
+                next += 1;
+            }
+            resampler.sample(frame_time, frame_time + frame, |event| {
+                emitted.push(event.position().dx.get());
+            });
+            frame_time += frame;
+            if frame_time > base + ms(600) {
+                break;
+            }
+        }
+
+        assert!(
+            emitted.len() >= 30,
+            "most samples delivered, got {}",
+            emitted.len()
+        );
+        assert!(
+            emitted.windows(2).all(|w| w[1] >= w[0]),
+            "positions must be monotonic: {emitted:?}"
+        );
+        // No step larger than one frame of motion plus one input interval.
+        assert!(
+            emitted.windows(2).all(|w| w[1] - w[0] <= 16.7 + 7.0 + 0.01),
+            "positions must not jump: {emitted:?}"
+        );
+    }
 }
@@ -26,7 +26,13 @@
 //!
 //! Confidence is the product of the R² fit quality of the x and y
 //! polynomials; a perfect linear swipe gives 1.0, a noisy curve gives
-//! something close to 0.0.
+//! something close to 0.0. Scroll physics can use
+//! [`VelocityTracker::get_confident_velocity`] to drop flings whose fit is
+//! too poor to trust.
+//!
+//! [`VelocityTracker`] can also be switched to the cheaper
+//! [`VelocityEstimationStrategy::LastTwoPoints`] via
+//! [`VelocityTracker::with_strategy`]; it uses the same sample window.
 //!
 //! # Example
 //!
@@ -90,6 +96,27 @@
 /// Polynomial degree for the least-squares fit. Quadratic — same as Flutter.
 const POLYNOMIAL_DEGREE: usize = 2;
 
+// ============================================================================
+// VelocityEstimationStrategy
+// ============================================================================
+
+/// How a [`VelocityTracker`] turns its sample window into a velocity.
+///
+/// Both strategies see the same window: the newest samples, walking back at
+/// most 100 ms and stopping at the first gap longer than 40 ms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum VelocityEstimationStrategy {
+    /// Quadratic least-squares fit over the whole window (Flutter's
+    /// `PolynomialFitLeastSquaresVelocityTracker`). Confidence is the product
+    /// of the x and y R².
+    #[default]
+    LeastSquares,
+    /// Displacement between the two newest samples divided by their time
+    /// gap. Reacts instantly but amplifies jitter; there is no fit to judge,
+    /// so confidence is 1.0 (0.0 when both samples share a timestamp).
+    LastTwoPoints,
+}
+
 // ============================================================================
 // PointAtTime
 // ============================================================================
@@ -111,16 +138,27 @@ struct PointAtTime {
 /// Computes a pointer's velocity from a stream of `(time, position)` samples.
 ///
 /// Mirrors Flutter's `VelocityTracker` (the
-/// `PolynomialFitLeastSquaresVelocityTracker` strategy). Adding samples is
-/// O(1); computing a velocity is O(N) where N ≤ 20, with the inner loop
-/// running through fixed-size stack-allocated scratch buffers in
-/// `LeastSquaresSolver`.
+/// `PolynomialFitLeastSquaresVelocityTracker` strategy by default; see
+/// [`Self::with_strategy`]). Adding samples is O(1); computing a velocity is
+/// O(N) where N ≤ 20, with the inner loop running through fixed-size
+/// stack-allocated scratch buffers in `LeastSquaresSolver`.
+///
+/// # Sample window
+///
+/// The last 20 samples are kept. An estimate uses the newest sample and walks
+/// back through older ones, stopping at the first sample older than 100 ms
+/// or separated from its successor by more than 40 ms (the pointer paused).
+/// If no sample has been added for 40 ms of wall-clock time the velocity is
+/// zero. Call [`Self::reset`] between gestures.
 #[derive(Debug, Clone)]
 pub struct VelocityTracker {
     /// Pointer device kind. Recorded for parity with Flutter even though the
     /// algorithm is currently device-independent.
     kind: PointerDeviceKind,
 
+    /// How the sample window is turned into a velocity.
+    strategy: VelocityEstimationStrategy,
+
     /// Circular buffer of samples. Empty slots are `None` so we can
     /// distinguish "slot not yet written" from a sample at `Instant::EPOCH`.
     samples: [Option<PointAtTime>; HISTORY_SIZE],
@@ -162,8 +200,15 @@ impl VelocityTracker {
     /// place for future device-specific tuning (mouse vs touch vs stylus).
     #[must_use]
     pub fn with_kind(kind: PointerDeviceKind) -> Self {
+        Self::with_strategy(kind, VelocityEstimationStrategy::default())
+    }
+
+    /// Construct a tracker that estimates with the given `strategy`.
+    #[must_use]
+    pub fn with_strategy(kind: PointerDeviceKind, strategy: VelocityEstimationStrategy) -> Self {
         Self {
             kind,
+            strategy,
             samples: [None; HISTORY_SIZE],
             index: 0,
             since_last_sample: None,
@@ -177,6 +222,12 @@ pub fn kind(&self) -> PointerDeviceKind {
         self.kind
     }
 
+    /// The estimation strategy this tracker uses.
+    #[inline]
+    pub fn strategy(&self) -> VelocityEstimationStrategy {
+        self.strategy
+    }
+
     /// Record a position at the given time.
     ///
     /// O(1). The samples are stored in a 20-slot circular buffer; older
@@ -211,7 +262,8 @@ pub fn add_position(&mut self, time: Instant, position: Offset<Pixels>) {
         self.samples[self.index] = Some(PointAtTime { time, position });
     }
 
-    /// Reset the tracker, discarding all samples.
+    /// Reset the tracker, discarding all samples. The kind and strategy are
+    /// kept, so one tracker can be reused across gestures.
     pub fn reset(&mut self) {
         self.samples = [None; HISTORY_SIZE];
         self.index = 0;
@@ -293,6 +345,8 @@ fn compute_estimate(&self) -> Option<VelocityEstimate> {
         let mut ws = [0.0f64; HISTORY_SIZE];
         let mut n: usize = 0;
         let mut cursor = self.index;
+        // The sample right before `newest` in the window, for `LastTwoPoints`.
+        let mut second_newest = None;
 
         // Bound the walk at one full lap — anything beyond that is stale.
         for _ in 0..HISTORY_SIZE {
@@ -322,6 +376,9 @@ fn compute_estimate(&self) -> Option<VelocityEstimate> {
             }
 
             oldest = sample;
+            if n == 1 {
+                second_newest = Some(sample);
+            }
             ts[n] = -age_ms; // Negative: we go back from the newest sample.
             xs[n] = sample.position.dx.get() as f64;
             ys[n] = sample.position.dy.get() as f64;
@@ -336,6 +393,10 @@ fn compute_estimate(&self) -> Option<VelocityEstimate> {
             };
         }
 
+        if self.strategy == VelocityEstimationStrategy::LastTwoPoints {
+            return Some(Self::two_point_estimate(newest, second_newest));
+        }
+
         // We were unable to gather enough samples to fit. Report zero
         // velocity with confidence 1.0 and the span we did see.
         if n < MIN_SAMPLE_SIZE {
@@ -395,6 +456,28 @@ fn compute_estimate(&self) -> Option<VelocityEstimate> {
         }
     }
 
+    /// [`VelocityEstimationStrategy::LastTwoPoints`] estimate from the newest
+    /// sample and the one before it in the window.
+    fn two_point_estimate(newest: PointAtTime, previous: Option<PointAtTime>) -> VelocityEstimate {
+        let Some(previous) = previous else {
+            return VelocityEstimate::new(Offset::ZERO, Offset::ZERO, Duration::ZERO, 1.0);
+        };
+        let offset = newest.position - previous.position;
+        let duration = newest.time.saturating_duration_since(previous.time);
+        let secs = duration.as_secs_f32();
+        if secs <= 0.0 {
+            // Same timestamp: no rate can be derived (see the singular-fit
+            // guard in `compute_estimate`).
+            return VelocityEstimate::new(offset, Offset::ZERO, duration, 0.0);
+        }
+        VelocityEstimate::new(
+            offset,
+            Offset::new(Pixels(offset.dx.0 / secs), Pixels(offset.dy.0 / secs)),
+            duration,
+            1.0,
+        )
+    }
+
     /// The most recent velocity as a [`Velocity`].
     ///
     /// Cheap wrapper over [`Self::get_velocity_estimate`] that returns
@@ -440,6 +523,19 @@ pub fn get_fling_velocity(&mut self, allow_slow: bool) -> Velocity {
         velocity
     }
 
+    /// The most recent velocity, or [`Velocity::ZERO`] when the estimate's
+    /// confidence is below `min_confidence`.
+    ///
+    /// Lets fling physics ignore velocities from erratic input (a poor
+    /// least-squares fit) instead of launching at a noisy speed. Confidence
+    /// lies in `[0, 1]`; a clean swipe scores close to 1.0.
+    pub fn get_confident_velocity(&mut self, min_confidence: f32) -> Velocity {
+        match self.get_velocity_estimate() {
+            Some(est) if est.confidence >= min_confidence => self.get_velocity(),
+            _ => Velocity::ZERO,
+        }
+    }
+
     /// Flutter-port alias for [`Self::get_velocity_estimate`].
     ///
     /// `&mut self` for the same memoization reason as
@@ -1270,6 +1366,138 @@ fn estimate_reflects_new_samples_not_stale_cache() {
         assert!(left < 0.0, "leftward swipe should be -dx, got {left}");
     }
 
+    /// Feed `(ms, x)` samples to a tracker using `strategy`.
+    fn tracker_from(
+        strategy: VelocityEstimationStrategy,
+        samples: &[(u64, f32)],
+    ) -> VelocityTracker {
+        let mut tracker = VelocityTracker::with_strategy(PointerDeviceKind::Touch, strategy);
+        let start = Instant::now();
+        for &(ms, x) in samples {
+            tracker.add_position(
+                start + Duration::from_millis(ms),
+                Offset::new(Pixels(x), Pixels(0.0)),
+            );
+        }
+        tracker
+    }
+
+    #[test]
+    fn strategies_agree_on_linear_motion() {
+        let samples: Vec<_> = (0..10).map(|i| (i * 10, i as f32 * 10.0)).collect();
+        for strategy in [
+            VelocityEstimationStrategy::LeastSquares,
+            VelocityEstimationStrategy::LastTwoPoints,
+        ] {
+            let mut tracker = tracker_from(strategy, &samples);
+            assert_eq!(tracker.strategy(), strategy);
+            let est = tracker.get_velocity_estimate().expect("non-empty");
+            let dx = est.pixels_per_second.dx.get();
+            assert!(
+                (dx - 1000.0).abs() < 1.0,
+                "{strategy:?}: expected ~1000 px/s, got {dx}"
+            );
+            assert!(
+                est.confidence > 0.99,
+                "{strategy:?}: confidence {}",
+                est.confidence
+            );
+        }
+    }
+
+    #[test]
+    fn last_two_points_follows_final_glitch_least_squares_smooths_it() {
+        // Steady 1000 px/s, then the final sample jumps an extra 30 px.
+        let mut samples: Vec<_> = (0..9).map(|i| (i * 10, i as f32 * 10.0)).collect();
+        samples.push((90, 120.0));
+
+        let two_point = tracker_from(VelocityEstimationStrategy::LastTwoPoints, &samples)
+            .get_velocity_estimate()
+            .expect("non-empty");
+        // (120 - 80) px / 10 ms.
+        assert!((two_point.pixels_per_second.dx.get() - 4000.0).abs() < 1.0);
+        assert_eq!(two_point.offset, Offset::new(Pixels(40.0), Pixels(0.0)));
+        assert_eq!(two_point.duration, Duration::from_millis(10));
+
+        let lsq = tracker_from(VelocityEstimationStrategy::LeastSquares, &samples)
+            .get_velocity_estimate()
+            .expect("non-empty");
+        let lsq_dx = lsq.pixels_per_second.dx.get();
+        assert!(
+            lsq_dx > 1000.0 && lsq_dx < 4000.0,
+            "fit should land between the steady and glitched speeds, got {lsq_dx}"
+        );
+        assert!(lsq.confidence < 1.0);
+    }
+
+    #[test]
+    fn last_two_points_needs_only_two_samples() {
+        let mut lsq = tracker_from(
+            VelocityEstimationStrategy::LeastSquares,
+            &[(0, 0.0), (10, 10.0)],
+        );
+        assert_eq!(lsq.get_velocity(), Velocity::ZERO);
+
+        let mut two_point = tracker_from(
+            VelocityEstimationStrategy::LastTwoPoints,
+            &[(0, 0.0), (10, 10.0)],
+        );
+        let dx = two_point.get_velocity().pixels_per_second.dx.get();
+        assert!((dx - 1000.0).abs() < 1.0, "got {dx}");
+
+        // Two samples at the same instant carry no rate.
+        let est = tracker_from(
+            VelocityEstimationStrategy::LastTwoPoints,
+            &[(0, 0.0), (0, 10.0)],
+        )
+        .get_velocity_estimate()
+        .expect("non-empty");
+        assert_eq!(est.pixels_per_second, Offset::ZERO);
+        assert_eq!(est.confidence, 0.0);
+    }
+
+    #[test]
+    fn last_two_points_respects_pause_gap() {
+        // 50 ms gap before the newest sample: the pointer paused, so the
+        // window holds only the newest sample.
+        let mut tracker = tracker_from(
+            VelocityEstimationStrategy::LastTwoPoints,
+            &[(0, 0.0), (50, 100.0)],
+        );
+        assert_eq!(tracker.get_velocity(), Velocity::ZERO);
+    }
+
+    #[test]
+    fn confident_velocity_drops_erratic_input() {
+        let linear: Vec<_> = (0..10).map(|i| (i * 10, i as f32 * 10.0)).collect();
+        let mut tracker = tracker_from(VelocityEstimationStrategy::LeastSquares, &linear);
+        assert_ne!(tracker.get_confident_velocity(0.9), Velocity::ZERO);
+
+        // Zig-zag jitter on top of a slow drift: a poor fit.
+        let erratic: Vec<_> = (0..10)
+            .map(|i| (i * 10, i as f32 + if i % 2 == 0 { 40.0 } else { -40.0 }))
+            .collect();
+        let mut tracker = tracker_from(VelocityEstimationStrategy::LeastSquares, &erratic);
+        let est = tracker.get_velocity_estimate().expect("non-empty");
+        assert!(est.confidence < 0.9, "confidence {}", est.confidence);
+        assert_eq!(tracker.get_confident_velocity(0.9), Velocity::ZERO);
+        assert_eq!(tracker.get_confident_velocity(0.0), tracker.get_velocity());
+    }
+
+    #[test]
+    fn reset_keeps_strategy() {
+        let mut tracker = tracker_from(
+            VelocityEstimationStrategy::LastTwoPoints,
+            &[(0, 0.0), (10, 10.0)],
+        );
+        tracker.reset();
+        assert_eq!(tracker.sample_count(), 0);
+        assert_eq!(
+            tracker.strategy(),
+            VelocityEstimationStrategy::LastTwoPoints
+        );
+    }
+
     proptest::proptest! {
         /// Finite positions at monotonic times never produce a non-finite
         /// velocity, and the estimate's confidence stays in [0, 1].
@@ -7,6 +7,10 @@
 //! - **Horizontal**: Movement constrained to horizontal axis
 //! - **Pan**: Free movement in any direction
 //!
+//! A pan can additionally lock to a single axis once it crosses the slop
+//! (see [`DragGestureRecognizer::with_axis_lock`]), e.g. a horizontal carousel
+//! inside a vertical scroll view.
+//!
 //! Flutter reference: <https://api.flutter.dev/flutter/gestures/DragGestureRecognizer-class.html>
 
 use std::{cell::RefCell, rc::Rc, sync::Arc, time::Instant};
@@ -160,6 +164,10 @@ pub struct DragGestureRecognizer {
     ///   position (Flutter default).
     start_behavior: DragStartBehavior,
 
+    /// Axis-lock mode for [`DragAxis::Free`] recognizers: `None` disables
+    /// locking; otherwise the axes the drag may lock to (`Free` = either).
+    axis_lock: Option<DragAxis>,
+
     /// Callbacks
     callbacks: Rc<RefCell<DragCallbacks>>,
 
@@ -176,6 +184,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             .field("state", &self.state)
             .field("axis", &self.axis)
             .field("start_behavior", &self.start_behavior)
+            .field("axis_lock", &self.axis_lock)
             .field("drag_state", &*self.drag_state.lock())
             .field("settings", &self.settings.lock())
             .finish_non_exhaustive()
@@ -208,6 +217,8 @@ struct DragState {
     last_time: Option<Instant>,
     /// Velocity tracker
     velocity_tracker: VelocityTracker,
+    /// Axis the drag committed to when it crossed the slop (axis-lock mode).
+    locked_axis: Option<DragAxis>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -227,6 +238,7 @@ fn default() -> Self {
             last_position: None,
             last_time: None,
             velocity_tracker: VelocityTracker::new(),
+            locked_axis: None,
         }
     }
 }
@@ -238,6 +250,7 @@ pub fn new(arena: crate::arena::GestureArena, axis: DragAxis) -> Arc<Self> {
             state: RecognizerBase::new(arena),
             axis,
             start_behavior: DragStartBehavior::default(),
+            axis_lock: None,
             callbacks: Rc::new(RefCell::new(DragCallbacks::default())),
             drag_state: Arc::new(Mutex::new(DragState::default())),
             settings: Arc::new(Mutex::new(GestureSettings::default())),
@@ -254,6 +267,7 @@ pub fn with_settings(
             state: RecognizerBase::new(arena),
             axis,
             start_behavior: DragStartBehavior::default(),
+            axis_lock: None,
             callbacks: Rc::new(RefCell::new(DragCallbacks::default())),
             drag_state: Arc::new(Mutex::new(DragState::default())),
             settings: Arc::new(Mutex::new(settings)),
@@ -264,13 +278,52 @@ pub fn with_settings(
     ///
     /// See [`DragStartBehavior`] for the semantics. Default is
     /// [`DragStartBehavior::Start`].
-    pub fn with_drag_start_behavior(self: Arc<Self>, behavior: DragStartBehavior) -> Arc<Self> {
-        // Re-construct with the new behavior — fields are all `Copy`/Arc so
-        // this is a cheap move, and it keeps the constructor pattern uniform.
-        Arc::new(Self {
-            start_behavior: behavior,
-            ..(*self).clone()
-        })
+    pub fn with_drag_start_behavior(mut self: Arc<Self>, behavior: DragStartBehavior) -> Arc<Self> {
+        // In place while the builder chain holds the only handle; a shared
+        // recognizer is cloned (its callbacks and state stay shared).
+        Arc::make_mut(&mut self).set_drag_start_behavior(behavior);
+        self
+    }
+
+    /// Set the start behavior of an unshared recognizer; see
+    /// [`with_drag_start_behavior`](Self::with_drag_start_behavior).
+    pub(crate) fn set_drag_start_behavior(&mut self, behavior: DragStartBehavior) {
+        self.start_behavior = behavior;
+    }
+
+    /// Lock a free (pan) drag to one axis once it crosses the slop.
+    ///
+    /// When the pointer first moves farther than
+    /// [`GestureSettings::pan_slop`], the drag commits to the axis the motion
+    /// is predominantly along — horizontal if `|dx| > |dy|`, otherwise
+    /// vertical (exact diagonals lock vertically, the usual scroll axis) — and
+    /// from then until pointer-up every update's `delta`, `primary_delta` and
+    /// the end velocity are projected onto that axis; cross-axis motion is
+    /// dropped.
+    ///
+    /// `allowed` restricts which axes the drag may lock to. Locking onto an
+    /// allowed axis claims the arena, so a competing ancestor scroll is
+    /// rejected; if the motion commits to a disallowed axis the recognizer
+    /// resigns instead, leaving the gesture to the competitor. A horizontal
+    /// carousel inside a vertical scroll view uses
+    /// `with_axis_lock(DragAxis::Horizontal)`; [`DragAxis::Free`] locks to
+    /// whichever axis wins. Only meaningful for [`DragAxis::Free`]
+    /// recognizers; fixed-axis drags ignore it.
+    pub fn with_axis_lock(mut self: Arc<Self>, allowed: DragAxis) -> Arc<Self> {
+        Arc::make_mut(&mut self).set_axis_lock(allowed);
+        self
+    }
+
+    /// Set the axis lock of an unshared recognizer; see
+    /// [`with_axis_lock`](Self::with_axis_lock).
+    pub(crate) fn set_axis_lock(&mut self, allowed: DragAxis) {
+        self.axis_lock = Some(allowed);
+    }
+
+    /// The axis the current drag locked to, if axis locking is enabled and
+    /// the slop has been crossed. Cleared on the next pointer down.
+    pub fn locked_axis(&self) -> Option<DragAxis> {
+        self.drag_state.lock().locked_axis
     }
 
     /// Get the current gesture settings
@@ -359,6 +412,7 @@ pub fn with_on_cancel(self: Arc<Self>, callback: impl Fn() + 'static) -> Arc<Sel
     fn handle_down(&self, position: Offset<Pixels>, kind: PointerType) {
         let mut state = self.drag_state.lock();
         state.state = DragPhase::Possible;
+        state.locked_axis = None;
         state.start_time = Some(Instant::now());
         state.last_position = Some(position);
         state.last_time = Some(Instant::now());
@@ -391,6 +445,18 @@ fn handle_move(&self, position: Offset<Pixels>, kind: PointerType) {
                     let distance = self.calculate_primary_delta(delta);
 
                     if distance.abs() > self.min_drag_distance() {
+                        if let Some(allowed) = self.lock_mode() {
+                            let axis = Self::dominant_axis(delta);
+                            state.locked_axis = Some(axis);
+                            if allowed != DragAxis::Free && allowed != axis {
+                                // Committed to an axis we may not handle:
+                                // resign so a competitor (e.g. an ancestor
+                                // scroll on that axis) gets the gesture.
+                                drop(state);
+                                self.handle_cancel();
+                                return;
+                            }
+                        }
                         // Start drag!
                         //
                         // The drag's initial position depends on
@@ -412,8 +478,15 @@ fn handle_move(&self, position: Offset<Pixels>, kind: PointerType) {
                         state
                             .velocity_tracker
                             .add_position(Instant::now(), position);
+                        let locked = state.locked_axis.is_some();
                         drop(state); // Release lock before calling callback
 
+                        if locked {
+                            // Committing to an axis claims the pointer, so a
+                            // competing recognizer on the other axis loses.
+                            self.state.accept_tracked();
+                        }
+
                         if let Some(callback) = self.callbacks.borrow().on_start.clone() {
                             let details = DragStartDetails {
                                 global_position: start_position,
@@ -429,7 +502,8 @@ fn handle_move(&self, position: Offset<Pixels>, kind: PointerType) {
             DragPhase::Started => {
                 // Update drag
                 if let Some(last_pos) = state.last_position {
-                    let delta = (position - last_pos).to_delta();
+                    let locked_axis = state.locked_axis;
+                    let delta = Self::project_onto(position - last_pos, locked_axis).to_delta();
                     state.last_position = Some(position);
                     state.last_time = Some(Instant::now());
                     state
@@ -443,7 +517,10 @@ fn handle_move(&self, position: Offset<Pixels>, kind: PointerType) {
                     // every update after the first report the wrong
                     // magnitude (and, once the drag reverses direction, the
                     // wrong sign) for any drag with 3+ move events.
-                    let primary_delta = self.calculate_primary_delta(delta.to_pixels());
+                    let primary_delta = match locked_axis {
+                        Some(axis) => Self::axis_component(delta.to_pixels(), axis),
+                        None => self.calculate_primary_delta(delta.to_pixels()),
+                    };
 
                     drop(state); // Release lock before calling callback
 
@@ -470,7 +547,19 @@ fn handle_up(&self, position: Offset<Pixels>, _kind: PointerType) {
         if state.state == DragPhase::Started {
             // Calculate final velocity
             let velocity = state.velocity_tracker.get_velocity();
-            let primary_velocity = self.calculate_primary_velocity(velocity.pixels_per_second);
+            let (velocity, primary_velocity) = match state.locked_axis {
+                Some(axis) => {
+                    let projected = Self::project_onto(velocity.pixels_per_second, Some(axis));
+                    (
+                        Velocity::new(projected),
+                        Self::axis_component(projected, axis),
+                    )
+                }
+                None => (
+                    velocity,
+                    self.calculate_primary_velocity(velocity.pixels_per_second),
+                ),
+            };
 
             state.state = DragPhase::Ready;
             drop(state); // Release lock before calling callback
@@ -508,6 +597,39 @@ fn handle_cancel(&self) {
         }
     }
 
+    /// The axes this recognizer may lock to, if axis locking applies.
+    fn lock_mode(&self) -> Option<DragAxis> {
+        self.axis_lock.filter(|_| self.axis == DragAxis::Free)
+    }
+
+    /// The axis `delta` predominantly moves along. Exact diagonals resolve
+    /// vertically.
+    fn dominant_axis(delta: Offset<Pixels>) -> DragAxis {
+        if delta.dx.0.abs() > delta.dy.0.abs() {
+            DragAxis::Horizontal
+        } else {
+            DragAxis::Vertical
+        }
+    }
+
+    /// Drop the component of `offset` across `axis` (no-op when unlocked).
+    fn project_onto(offset: Offset<Pixels>, axis: Option<DragAxis>) -> Offset<Pixels> {
+        match axis {
+            Some(DragAxis::Horizontal) => Offset::new(offset.dx, Pixels(0.0)),
+            Some(DragAxis::Vertical) => Offset::new(Pixels(0.0), offset.dy),
+            Some(DragAxis::Free) | None => offset,
+        }
+    }
+
+    /// Signed component of `offset` along a locked `axis`.
+    fn axis_component(offset: Offset<Pixels>, axis: DragAxis) -> f32 {
+        match axis {
+            DragAxis::Horizontal => offset.dx.0,
+            DragAxis::Vertical => offset.dy.0,
+            DragAxis::Free => offset.distance().0,
+        }
+    }
+
     /// Calculate primary delta based on axis
     fn calculate_primary_delta(&self, delta: Offset<Pixels>) -> f32 {
         match self.axis {
@@ -925,4 +1047,174 @@ fn drag_axis_getter_reflects_constructor() {
             DragAxis::Free,
         );
     }
+
+    // ========================================================================
+    // Axis-lock (pan) tests
+    // ========================================================================
+
+    /// Down at the origin, one move to `(dx, dy)`; returns the locked axis.
+    fn lock_after_move(dx: f32, dy: f32) -> Option<DragAxis> {
+        let recognizer = DragGestureRecognizer::new(GestureArena::new(), DragAxis::Free)
+            .with_axis_lock(DragAxis::Free);
+        recognizer.add_pointer(PointerId::PRIMARY, Offset::new(Pixels(0.0), Pixels(0.0)));
+        recognizer.handle_event(&make_move_event(
+            Offset::new(Pixels(dx), Pixels(dy)),
+            PointerType::Touch,
+        ));
+        recognizer.locked_axis()
+    }
+
+    #[test]
+    fn axis_lock_decision_boundary() {
+        // |(14, 13)| ≈ 19.1 > 18px slop, dx dominant.
+        assert_eq!(lock_after_move(14.0, 13.0), Some(DragAxis::Horizontal));
+        assert_eq!(lock_after_move(-14.0, 13.0), Some(DragAxis::Horizontal));
+        assert_eq!(lock_after_move(13.0, 14.0), Some(DragAxis::Vertical));
+        assert_eq!(lock_after_move(13.0, -14.0), Some(DragAxis::Vertical));
+        // Exact diagonal past the slop resolves vertically.
+        assert_eq!(lock_after_move(13.0, 13.0), Some(DragAxis::Vertical));
+        // |(12.7, 12.7)| ≈ 17.96 — still inside the slop, no decision yet.
+        assert_eq!(lock_after_move(12.7, 12.7), None);
+    }
+
+    #[test]
+    fn axis_lock_ignored_for_fixed_axis_drags() {
+        let recognizer = DragGestureRecognizer::new(GestureArena::new(), DragAxis::Vertical)
+            .with_axis_lock(DragAxis::Free);
+        recognizer.add_pointer(PointerId::PRIMARY, Offset::new(Pixels(0.0), Pixels(0.0)));
+        recognizer.handle_event(&make_move_event(
+            Offset::new(Pixels(5.0), Pixels(30.0)),
+            PointerType::Touch,
+        ));
+        assert_eq!(recognizer.locked_axis(), None);
+    }
+
+    #[test]
+    fn axis_lock_drops_cross_axis_motion_until_pointer_up() {
+        let updates = Arc::new(Mutex::new(Vec::<(Offset<PixelDelta>, f32)>::new()));
+        let ended = Arc::new(Mutex::new(None::<(Velocity, f32)>));
+        let updates_clone = updates.clone();
+        let ended_clone = ended.clone();
+
+        let recognizer = DragGestureRecognizer::new(GestureArena::new(), DragAxis::Free)
+            .with_axis_lock(DragAxis::Free)
+            .with_on_update(move |d| updates_clone.lock().push((d.delta, d.primary_delta)))
+            .with_on_end(move |d| *ended_clone.lock() = Some((d.velocity, d.primary_velocity)));
+
+        recognizer.add_pointer(PointerId::PRIMARY, Offset::new(Pixels(0.0), Pixels(0.0)));
+        // Lock horizontally.
+        recognizer.handle_event(&make_move_event(
+            Offset::new(Pixels(30.0), Pixels(5.0)),
+            PointerType::Touch,
+        ));
+        assert_eq!(recognizer.locked_axis(), Some(DragAxis::Horizontal));
+
+        // Mostly-vertical follow-up motion stays on the locked axis.
+        recognizer.handle_event(&make_move_event(
+            Offset::new(Pixels(20.0), Pixels(60.0)),
+            PointerType::Touch,
+        ));
+        recognizer.handle_event(&make_move_event(
+            Offset::new(Pixels(20.0), Pixels(120.0)),
+            PointerType::Touch,
+        ));
+        assert_eq!(
+            *updates.lock(),
+            vec![
+                (Offset::new(PixelDelta(-10.0), PixelDelta(0.0)), -10.0),
+                (Offset::new(PixelDelta(0.0), PixelDelta(0.0)), 0.0),
+            ]
+        );
+
+        recognizer.handle_event(&crate::events::make_up_event(
+            Offset::new(Pixels(20.0), Pixels(120.0)),
+            PointerType::Touch,
+        ));
+        let (velocity, primary_velocity) = ended.lock().expect("drag should end");
+        assert_eq!(velocity.pixels_per_second.dy, Pixels(0.0));
+        assert_eq!(primary_velocity, velocity.pixels_per_second.dx.0);
+        assert_eq!(recognizer.locked_axis(), Some(DragAxis::Horizontal));
+    }
+
+    /// A horizontal-only pan competing with a vertical scroll for one pointer.
+    struct PanVsScroll {
+        pan: Arc<DragGestureRecognizer>,
+        scroll: Arc<DragGestureRecognizer>,
+        pan_cancelled: Arc<Mutex<bool>>,
+        scroll_cancelled: Arc<Mutex<bool>>,
+        arena: GestureArena,
+    }
+
+    fn pan_vs_scroll() -> PanVsScroll {
+        let arena = GestureArena::new();
+        let pan_cancelled = Arc::new(Mutex::new(false));
+        let scroll_cancelled = Arc::new(Mutex::new(false));
+        let pan_flag = pan_cancelled.clone();
+        let scroll_flag = scroll_cancelled.clone();
+
+        let pan = DragGestureRecognizer::new(arena.clone(), DragAxis::Free)
+            .with_axis_lock(DragAxis::Horizontal)
+            .with_on_cancel(move || *pan_flag.lock() = true);
+        let scroll = DragGestureRecognizer::new(arena.clone(), DragAxis::Vertical)
+            .with_on_cancel(move || *scroll_flag.lock() = true);
+
+        let origin = Offset::new(Pixels(0.0), Pixels(0.0));
+        pan.add_pointer(PointerId::PRIMARY, origin);
+        scroll.add_pointer(PointerId::PRIMARY, origin);
+        arena.close(PointerId::PRIMARY);
+
+        PanVsScroll {
+            pan,
+            scroll,
+            pan_cancelled,
+            scroll_cancelled,
+            arena,
+        }
+    }
+
+    #[test]
+    fn axis_locked_pan_beats_vertical_scroll_on_horizontal_motion() {
+        let PanVsScroll {
+            pan,
+            pan_cancelled,
+            scroll_cancelled,
+            arena,
+            ..
+        } = pan_vs_scroll();
+
+        pan.handle_event(&make_move_event(
+            Offset::new(Pixels(30.0), Pixels(4.0)),
+            PointerType::Touch,
+        ));
+
+        assert!(*scroll_cancelled.lock(), "scroll should lose the arena");
+        assert!(!*pan_cancelled.lock());
+        // Settled and torn down by the losing member.
+        assert!(!arena.contains(PointerId::PRIMARY));
+    }
+
+    #[test]
+    fn axis_locked_pan_yields_to_vertical_scroll_on_vertical_motion() {
+        let PanVsScroll {
+            pan,
+            scroll,
+            pan_cancelled,
+            scroll_cancelled,
+            arena,
+        } = pan_vs_scroll();
+        let scroll_started = Arc::new(Mutex::new(false));
+        let started_flag = scroll_started.clone();
+        let scroll = scroll.with_on_start(move |_| *started_flag.lock() = true);
+
+        let vertical = make_move_event(Offset::new(Pixels(4.0), Pixels(30.0)), PointerType::Touch);
+        pan.handle_event(&vertical);
+
+        assert!(*pan_cancelled.lock(), "pan should resign");
+        // The scroll was the last member standing and won by default.
+        assert!(!arena.contains(PointerId::PRIMARY));
+
+        scroll.handle_event(&vertical);
+        assert!(*scroll_started.lock());
+        assert!(!*scroll_cancelled.lock());
+    }
 }
@@ -3,40 +3,45 @@
 //! Flutter parity: `gestures/monodrag.dart` exposes
 //! `VerticalDragGestureRecognizer`, `HorizontalDragGestureRecognizer`, and
 //! `PanGestureRecognizer` as thin subclasses of `DragGestureRecognizer` that
-//! hard-code the axis. We expose the same three names as type aliases over
-//! [`DragGestureRecognizer`] for call-site readability, paired with the
-//! [`vertical_drag`] / [`horizontal_drag`] / [`pan`] constructors that set the
-//! axis. Note: aliases are the *same* type — they do NOT enforce the axis at
+//! hard-code the axis. The vertical and horizontal names are type aliases
+//! over [`DragGestureRecognizer`] for call-site readability, paired with the
+//! [`vertical_drag`] / [`horizontal_drag`] constructors that set the axis.
+//! Note: those aliases are the *same* type — they do NOT enforce the axis at
 //! compile time; the axis is a runtime field set by the constructor.
 //!
-//! Because a type alias shares the underlying type's methods, the
-//! constructors and per-axis builders all live on
-//! [`DragGestureRecognizer`] itself; this module only adds the alias-flavoured
-//! *fluent* builders ([`PanGestureRecognizer::on_start`] /
-//! [`PanGestureRecognizer::on_update`] / [`PanGestureRecognizer::on_end`])
-//! and free constructor helpers ([`vertical_drag`], [`horizontal_drag`],
-//! [`pan`]) that hide the axis argument at the call site.
+//! [`PanGestureRecognizer`] is a distinct type: it always drags along
+//! [`DragAxis::Free`], carries the pan-only [axis lock], and names its
+//! callbacks after Flutter's `onPanStart` / `onPanUpdate` / `onPanEnd`.
+//!
+//! [axis lock]: PanGestureRecognizer::with_axis_lock
 //!
 //! # Example
 //!
 //! ```rust,ignore
-//! use flui_interaction::recognizers::drag_variants::{pan, PanGestureRecognizer};
-//! use flui_interaction::recognizers::drag::DragGestureRecognizer;
+//! use flui_interaction::recognizers::drag_variants::PanGestureRecognizer;
 //!
 //! let arena = GestureArena::new();
-//! // Free fn — sets the Free axis at construction.
-//! let recognizer: PanGestureRecognizer = pan(arena);
-//! // Standard builders on the underlying recogniser remain reachable.
-//! let _ = recognizer.clone()
-//!     .with_on_start(|d| { let _ = d; });
+//! let recognizer = PanGestureRecognizer::new(arena)
+//!     .on_pan_start(|d| println!("pan started at {:?}", d.global_position))
+//!     .on_pan_update(|d| println!("panned by {:?}", d.delta))
+//!     .on_pan_end(|d| println!("pan ended at {:?}", d.velocity));
 //! ```
 
 use std::sync::Arc;
 
-use crate::arena::GestureArena;
+use flui_types::geometry::{Offset, Pixels};
+
+use crate::arena::{GestureArena, GestureArenaMember, GestureDisposition};
+use crate::events::PointerEvent;
+use crate::ids::PointerId;
+use crate::settings::GestureSettings;
 use crate::traits::DragAxis;
 
-use super::drag::{DragEndCallback, DragGestureRecognizer, DragStartCallback, DragUpdateCallback};
+use super::drag::{
+    DragDownDetails, DragEndCallback, DragEndDetails, DragGestureRecognizer, DragStartBehavior,
+    DragStartCallback, DragStartDetails, DragUpdateCallback, DragUpdateDetails,
+};
+use super::recognizer::GestureRecognizer;
 
 /// A drag recogniser constrained to the vertical axis.
 ///
@@ -51,12 +56,6 @@
 /// Mirrors Flutter's `HorizontalDragGestureRecognizer`.
 pub type HorizontalDragGestureRecognizer = DragGestureRecognizer;
 
-/// A free-direction pan recogniser.
-///
-/// Mirrors Flutter's `PanGestureRecognizer`. A pan is a drag that can
-/// move in any direction — the default axis is [`DragAxis::Free`].
-pub type PanGestureRecognizer = DragGestureRecognizer;
-
 // ============================================================================
 // Free fn constructors (Flutter parity for per-axis subclass constructors)
 // ============================================================================
@@ -79,7 +78,7 @@ pub fn vertical_drag(arena: GestureArena) -> Arc<VerticalDragGestureRecognizer>
 #[must_use]
 pub fn vertical_drag_with_settings(
     arena: GestureArena,
-    settings: crate::settings::GestureSettings,
+    settings: GestureSettings,
 ) -> Arc<VerticalDragGestureRecognizer> {
     DragGestureRecognizer::with_settings(arena, DragAxis::Vertical, settings)
 }
@@ -94,62 +93,204 @@ pub fn horizontal_drag(arena: GestureArena) -> Arc<HorizontalDragGestureRecogniz
 #[must_use]
 pub fn horizontal_drag_with_settings(
     arena: GestureArena,
-    settings: crate::settings::GestureSettings,
+    settings: GestureSettings,
 ) -> Arc<HorizontalDragGestureRecognizer> {
     DragGestureRecognizer::with_settings(arena, DragAxis::Horizontal, settings)
 }
 
 /// Construct a free-direction pan recogniser.
+///
+/// Shorthand for [`PanGestureRecognizer::new`].
 #[must_use]
 pub fn pan(arena: GestureArena) -> Arc<PanGestureRecognizer> {
-    DragGestureRecognizer::new(arena, DragAxis::Free)
+    PanGestureRecognizer::new(arena)
 }
 
 /// Construct a free-direction pan recogniser with custom settings.
+///
+/// Shorthand for [`PanGestureRecognizer::with_settings`].
 #[must_use]
 pub fn pan_with_settings(
     arena: GestureArena,
-    settings: crate::settings::GestureSettings,
+    settings: GestureSettings,
 ) -> Arc<PanGestureRecognizer> {
-    DragGestureRecognizer::with_settings(arena, DragAxis::Free, settings)
+    PanGestureRecognizer::with_settings(arena, settings)
 }
 
 // ============================================================================
-// Pan-only fluent builders
+// PanGestureRecognizer
 // ============================================================================
-//
-// `on_start` / `on_update` / `on_end` are inherently tied to a recogniser
-// type, so pan-flavoured fluent builders live here. Vertical / Horizontal
-// recognisers can still use the standard `with_on_*` chain — the type alias
-// exposes those methods unchanged.
+
+/// A free-direction pan recogniser.
+///
+/// Mirrors Flutter's `PanGestureRecognizer`. A pan is a drag that can move
+/// in any direction, so the axis is always [`DragAxis::Free`]; call
+/// [`with_axis_lock`](Self::with_axis_lock) to commit the pan to one axis
+/// once it crosses the slop. Slop, velocity tracking and arena behaviour are
+/// those of the wrapped [`DragGestureRecognizer`].
+#[derive(Debug, Clone)]
+pub struct PanGestureRecognizer {
+    drag: Arc<DragGestureRecognizer>,
+}
 
 impl PanGestureRecognizer {
-    /// Convenience builder equivalent to
-    /// [`DragGestureRecognizer::with_on_start`] but returning the alias
-    /// type for fluent chaining.
+    /// Create a new pan recognizer with the gesture arena.
+    pub fn new(arena: GestureArena) -> Arc<Self> {
+        Arc::new(Self {
+            drag: DragGestureRecognizer::new(arena, DragAxis::Free),
+        })
+    }
+
+    /// Create a new pan recognizer with custom settings.
+    pub fn with_settings(arena: GestureArena, settings: GestureSettings) -> Arc<Self> {
+        Arc::new(Self {
+            drag: DragGestureRecognizer::with_settings(arena, DragAxis::Free, settings),
+        })
+    }
+
+    /// Lock the pan to one axis once it crosses the slop.
+    ///
+    /// See [`DragGestureRecognizer::with_axis_lock`] for the semantics of
+    /// `allowed`.
+    pub fn with_axis_lock(mut self: Arc<Self>, allowed: DragAxis) -> Arc<Self> {
+        Arc::make_mut(&mut Arc::make_mut(&mut self).drag).set_axis_lock(allowed);
+        self
+    }
+
+    /// Configure when the pan's initial position is reported.
+    pub fn with_drag_start_behavior(mut self: Arc<Self>, behavior: DragStartBehavior) -> Arc<Self> {
+        Arc::make_mut(&mut Arc::make_mut(&mut self).drag).set_drag_start_behavior(behavior);
+        self
+    }
+
+    /// Set the pan down callback (`onPanDown`): a pointer contacted the
+    /// screen and may begin to pan.
+    pub fn on_pan_down(self: Arc<Self>, callback: impl Fn(DragDownDetails) + 'static) -> Arc<Self> {
+        let _ = self.drag.clone().with_on_down(callback);
+        self
+    }
+
+    /// Set the pan start callback (`onPanStart`): the pointer moved past the
+    /// slop and the pan began.
+    pub fn on_pan_start(
+        self: Arc<Self>,
+        callback: impl Fn(DragStartDetails) + 'static,
+    ) -> Arc<Self> {
+        let _ = self.drag.clone().with_on_start(callback);
+        self
+    }
+
+    /// Set the pan update callback (`onPanUpdate`).
+    pub fn on_pan_update(
+        self: Arc<Self>,
+        callback: impl Fn(DragUpdateDetails) + 'static,
+    ) -> Arc<Self> {
+        let _ = self.drag.clone().with_on_update(callback);
+        self
+    }
+
+    /// Set the pan end callback (`onPanEnd`): the pointer lifted, with the
+    /// release velocity.
+    pub fn on_pan_end(self: Arc<Self>, callback: impl Fn(DragEndDetails) + 'static) -> Arc<Self> {
+        let _ = self.drag.clone().with_on_end(callback);
+        self
+    }
+
+    /// Set the pan cancel callback (`onPanCancel`).
+    pub fn on_pan_cancel(self: Arc<Self>, callback: impl Fn() + 'static) -> Arc<Self> {
+        let _ = self.drag.clone().with_on_cancel(callback);
+        self
+    }
+
+    /// Set the pan start callback.
+    #[deprecated(since = "0.2.0", note = "use `on_pan_start`")]
     pub fn on_start(self: Arc<Self>, cb: DragStartCallback) -> Arc<Self> {
-        // The aliased method already returns Arc<Self>; the closure is
-        // forwarded as-is.
-        self.with_on_start(move |d| cb(d))
+        self.on_pan_start(move |d| cb(d))
     }
 
-    /// Convenience builder equivalent to
-    /// [`DragGestureRecognizer::with_on_update`].
+    /// Set the pan update callback.
+    #[deprecated(since = "0.2.0", note = "use `on_pan_update`")]
     pub fn on_update(self: Arc<Self>, cb: DragUpdateCallback) -> Arc<Self> {
-        self.with_on_update(move |d| cb(d))
+        self.on_pan_update(move |d| cb(d))
     }
 
-    /// Convenience builder equivalent to
-    /// [`DragGestureRecognizer::with_on_end`].
+    /// Set the pan end callback.
+    #[deprecated(since = "0.2.0", note = "use `on_pan_end`")]
     pub fn on_end(self: Arc<Self>, cb: DragEndCallback) -> Arc<Self> {
-        self.with_on_end(move |d| cb(d))
+        self.on_pan_end(move |d| cb(d))
+    }
+
+    /// The axis the current pan locked to, if axis locking is enabled and
+    /// the slop has been crossed.
+    pub fn locked_axis(&self) -> Option<DragAxis> {
+        self.drag.locked_axis()
+    }
+
+    /// Get the current gesture settings
+    pub fn settings(&self) -> GestureSettings {
+        self.drag.settings()
+    }
+
+    /// Update gesture settings
+    pub fn set_settings(&self, settings: GestureSettings) {
+        self.drag.set_settings(settings);
+    }
+
+    /// Currently-configured [`DragStartBehavior`].
+    pub fn drag_start_behavior(&self) -> DragStartBehavior {
+        self.drag.drag_start_behavior()
+    }
+}
+
+impl GestureRecognizer for PanGestureRecognizer {
+    fn add_pointer(&self, pointer: PointerId, position: Offset<Pixels>) {
+        self.drag.add_pointer(pointer, position);
+    }
+
+    fn handle_event(&self, event: &PointerEvent) {
+        self.drag.handle_event(event);
+    }
+
+    fn dispose(&self) {
+        self.drag.dispose();
+    }
+
+    fn primary_pointer(&self) -> Option<PointerId> {
+        self.drag.primary_pointer()
+    }
+}
+
+impl crate::recognizers::OneSequenceGestureRecognizer for PanGestureRecognizer {
+    fn tracked_pointers(&self) -> Vec<PointerId> {
+        self.drag.tracked_pointers()
+    }
+
+    fn resolve_pointer(&self, pointer: PointerId, disposition: GestureDisposition) {
+        self.drag.resolve_pointer(pointer, disposition);
+    }
+
+    fn stop_tracking_pointer(&self, pointer: PointerId) {
+        self.drag.stop_tracking_pointer(pointer);
+    }
+}
+
+impl GestureArenaMember for PanGestureRecognizer {
+    fn accept_gesture(&self, pointer: PointerId) {
+        self.drag.accept_gesture(pointer);
+    }
+
+    fn reject_gesture(&self, pointer: PointerId) {
+        self.drag.reject_gesture(pointer);
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
     use super::*;
-    use crate::settings::GestureSettings;
+    use crate::events::{PointerType, make_move_event, make_up_event};
 
     #[test]
     fn vertical_constructor_pins_axis() {
@@ -166,10 +307,93 @@ fn horizontal_constructor_pins_axis() {
     }
 
     #[test]
-    fn pan_constructor_pins_axis() {
+    fn pan_constructor_has_no_locked_axis() {
         let arena = crate::arena::GestureArena::new();
         let rec = pan(arena);
-        assert_eq!(rec.axis(), DragAxis::Free);
+        assert_eq!(rec.locked_axis(), None);
+        assert_eq!(rec.drag_start_behavior(), DragStartBehavior::Start);
+    }
+
+    #[test]
+    fn pan_callbacks_fire_in_order() {
+        let arena = crate::arena::GestureArena::new();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (start, update, end) = (log.clone(), log.clone(), log.clone());
+        let rec = PanGestureRecognizer::new(arena)
+            .on_pan_start(move |_| start.borrow_mut().push("start"))
+            .on_pan_update(move |_| update.borrow_mut().push("update"))
+            .on_pan_end(move |_| end.borrow_mut().push("end"));
+
+        rec.add_pointer(PointerId::PRIMARY, Offset::new(Pixels(0.0), Pixels(0.0)));
+        rec.handle_event(&make_move_event(
+            Offset::new(Pixels(30.0), Pixels(30.0)),
+            PointerType::Touch,
+        ));
+        rec.handle_event(&make_move_event(
+            Offset::new(Pixels(40.0), Pixels(30.0)),
+            PointerType::Touch,
+        ));
+        rec.handle_event(&make_up_event(
+            Offset::new(Pixels(40.0), Pixels(30.0)),
+            PointerType::Touch,
+        ));
+
+        assert_eq!(log.borrow().first(), Some(&"start"));
+        assert!(log.borrow().contains(&"update"));
+        assert_eq!(log.borrow().last(), Some(&"end"));
+    }
+
+    #[test]
+    fn pan_axis_lock_commits_to_dominant_axis() {
+        let arena = crate::arena::GestureArena::new();
+        let rec = PanGestureRecognizer::new(arena).with_axis_lock(DragAxis::Free);
+
+        rec.add_pointer(PointerId::PRIMARY, Offset::new(Pixels(0.0), Pixels(0.0)));
+        rec.handle_event(&make_move_event(
+            Offset::new(Pixels(40.0), Pixels(5.0)),
+            PointerType::Touch,
+        ));
+
+        assert_eq!(rec.locked_axis(), Some(DragAxis::Horizontal));
+    }
+
+    #[test]
+    fn pan_builders_configure_in_place() {
+        let arena = crate::arena::GestureArena::new();
+        let rec = PanGestureRecognizer::new(arena);
+        let before = Arc::as_ptr(&rec);
+
+        let rec = rec
+            .with_axis_lock(DragAxis::Horizontal)
+            .with_drag_start_behavior(DragStartBehavior::Down);
+
+        assert_eq!(Arc::as_ptr(&rec), before);
+        assert_eq!(rec.drag_start_behavior(), DragStartBehavior::Down);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_callback_builders_forward_to_pan_callbacks() {
+        let arena = crate::arena::GestureArena::new();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (start, update, end) = (log.clone(), log.clone(), log.clone());
+        let rec = PanGestureRecognizer::new(arena)
+            .on_start(Rc::new(move |_| start.borrow_mut().push("start")))
+            .on_update(Rc::new(move |_| update.borrow_mut().push("update")))
+            .on_end(Rc::new(move |_| end.borrow_mut().push("end")));
+
+        rec.add_pointer(PointerId::PRIMARY, Offset::new(Pixels(0.0), Pixels(0.0)));
+        rec.handle_event(&make_move_event(
+            Offset::new(Pixels(30.0), Pixels(30.0)),
+            PointerType::Touch,
+        ));
+        rec.handle_event(&make_up_event(
+            Offset::new(Pixels(30.0), Pixels(30.0)),
+            PointerType::Touch,
+        ));
+
+        assert_eq!(log.borrow().first(), Some(&"start"));
+        assert_eq!(log.borrow().last(), Some(&"end"));
     }
 
     #[test]
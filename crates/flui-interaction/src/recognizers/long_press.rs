@@ -620,6 +620,12 @@ fn poll_deadline(&self) {
         }
     }
 
+    fn arena_timeout(&self) -> Option<Duration> {
+        // Keep the arena from force-resolving (first member wins, typically a
+        // competing tap) before the hold deadline can fire.
+        Some(self.long_press_duration())
+    }
+
     fn reject_gesture(&self, _pointer: PointerId) {
         // We lost the arena - cancel the gesture
         if let Some(pos) = self.state.initial_position() {
@@ -15,6 +15,7 @@
 //!                 ├── DoubleTapGestureRecognizer
 //!                 ├── DragGestureRecognizer
 //!                 ├── ScaleGestureRecognizer
+//!                 ├── RotationGestureRecognizer
 //!                 └── ...
 //! ```
 //!
@@ -30,6 +31,7 @@
 //! - [`LongPressGestureRecognizer`] - Long press detection
 //! - [`DragGestureRecognizer`] - Drag/pan gesture detection
 //! - [`ScaleGestureRecognizer`] - Pinch-to-zoom detection
+//! - [`RotationGestureRecognizer`] - Two-finger rotation detection
 //! - [`MultiTapGestureRecognizer`] - Multi-finger tap detection
 //! - [`ForcePressGestureRecognizer`] - Force/pressure touch detection
 //!
@@ -57,6 +59,7 @@
 pub mod one_sequence;
 pub mod primary_pointer;
 pub mod recognizer;
+pub mod rotation;
 pub mod scale;
 pub mod tap;
 pub mod tap_and_drag;
@@ -79,6 +82,7 @@
 pub use one_sequence::OneSequenceGestureRecognizer;
 pub use primary_pointer::PrimaryPointerGestureRecognizer;
 pub use recognizer::{GestureRecognizer, GestureRecognizerState, RecognizerBase, constants};
+pub use rotation::RotationGestureRecognizer;
 pub use scale::ScaleGestureRecognizer;
 pub use tap::TapGestureRecognizer;
 pub use tap_and_drag::{
@@ -13,7 +13,7 @@
 use tracing::instrument;
 
 use crate::{
-    arena::{GestureArena, GestureArenaMember},
+    arena::{GestureArena, GestureArenaMember, GestureArenaTeam, GestureDisposition, TeamEntry},
     events::PointerEvent,
     ids::PointerId,
 };
@@ -89,6 +89,14 @@ pub struct RecognizerBase {
     /// fresh `Arc::new(self.clone())`. A `Weak` (not `Arc`) avoids a
     /// self-referential cycle that would leak the recognizer.
     tracked_member: Arc<Mutex<Option<Weak<dyn GestureArenaMember>>>>,
+
+    /// Team this recognizer competes in, if any (Flutter parity:
+    /// `OneSequenceGestureRecognizer.team`). When set, pointers are entered
+    /// into the arena through the team's combiner instead of directly.
+    team: Arc<Mutex<Option<Arc<GestureArenaTeam>>>>,
+
+    /// Team entry for the tracked pointer while competing through a team.
+    team_entry: Arc<Mutex<Option<TeamEntry>>>,
 }
 
 impl RecognizerBase {
@@ -100,9 +108,26 @@ pub fn new(arena: GestureArena) -> Self {
             initial_position: Arc::new(Mutex::new(None)),
             disposed: Arc::new(AtomicBool::new(false)),
             tracked_member: Arc::new(Mutex::new(None)),
+            team: Arc::new(Mutex::new(None)),
+            team_entry: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// The team this recognizer competes in, if any.
+    #[inline]
+    pub fn team(&self) -> Option<Arc<GestureArenaTeam>> {
+        self.team.lock().clone()
+    }
+
+    /// Set the team this recognizer competes in.
+    ///
+    /// Takes effect for the next pointer passed to
+    /// [`start_tracking`](Self::start_tracking); a pointer already entered
+    /// into the arena keeps its existing membership.
+    pub fn set_team(&self, team: Option<Arc<GestureArenaTeam>>) {
+        *self.team.lock() = team;
+    }
+
     /// Get the gesture arena
     #[inline]
     pub fn arena(&self) -> &GestureArena {
@@ -213,7 +238,14 @@ pub fn start_tracking<T: GestureArenaMember + Clone + 'static>(
         // `Arc` identity the arena matches on via `Arc::ptr_eq`.
         let member: Arc<dyn GestureArenaMember> = recognizer.clone();
         *self.tracked_member.lock() = Some(Arc::downgrade(&member));
-        self.arena.add(pointer, member);
+        match self.team() {
+            Some(team) => {
+                *self.team_entry.lock() = Some(team.add(pointer, member, &self.arena));
+            }
+            None => {
+                self.arena.add(pointer, member);
+            }
+        }
     }
 
     /// Claim the arena win for the currently-tracked pointer.
@@ -226,6 +258,13 @@ pub fn accept_tracked(&self) {
         let Some(pointer) = self.primary_pointer() else {
             return;
         };
+        // Take the entry out before resolving: the team's combiner notifies
+        // members synchronously and they may re-enter this recognizer.
+        let team_entry = self.team_entry.lock().take();
+        if let Some(entry) = team_entry {
+            entry.resolve(GestureDisposition::Accepted);
+            return;
+        }
         let Some(member) = self.tracked_member() else {
             return;
         };
@@ -266,6 +305,7 @@ pub fn stop_tracking(&self) {
         {
             self.arena.sweep(pointer);
         }
+        self.team_entry.lock().take();
         self.set_primary_pointer(None);
         self.set_initial_position(None);
     }
@@ -305,9 +345,16 @@ pub fn reject(&self) {
         // no winner (the previous behavior) rejected every *competing* member
         // too, so a recognizer that bowed out of a shared arena (e.g. a tap
         // exceeding its slop) silently killed the drag it was competing with.
-        let member = self.tracked_member.lock().as_ref().and_then(Weak::upgrade);
-        if let Some(member) = member {
-            self.arena.reject_member(pointer, &member);
+        // Team members withdraw through the team, which rejects the combined
+        // entry only once its last member has resigned.
+        let team_entry = self.team_entry.lock().take();
+        if let Some(entry) = team_entry {
+            entry.resolve(GestureDisposition::Rejected);
+        } else if self.team().is_none() {
+            let member = self.tracked_member.lock().as_ref().and_then(Weak::upgrade);
+            if let Some(member) = member {
+                self.arena.reject_member(pointer, &member);
+            }
         }
         // Clear ONLY this recognizer's local tracking — do NOT `stop_tracking`
         // (it would `sweep`, force-resolving any still-open competition in
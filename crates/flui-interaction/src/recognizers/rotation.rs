@@ -0,0 +1,736 @@
+//! Rotation gesture recognizer
+//!
+//! Recognizes two-finger rotation (twist) gestures.
+//!
+//! A rotation gesture requires:
+//! - Two or more pointers down
+//! - The line through the first two pointers turning by more than
+//!   [`DEFAULT_ROTATION_SLOP`] radians
+//!
+//! Rotation is accumulated from per-move angle deltas, so a twist that
+//! crosses the ±π boundary keeps counting instead of jumping by a full turn.
+//!
+//! Unlike [`ScaleGestureRecognizer`](super::ScaleGestureRecognizer), which
+//! reports rotation as a by-product of pinching, this recognizer competes for
+//! rotation alone. Put both in a [`GestureArenaTeam::cooperative`] team to let
+//! them win together.
+
+use std::{cell::RefCell, f32::consts::PI, rc::Rc, sync::Arc};
+
+use flui_types::{Offset, geometry::Pixels};
+use parking_lot::Mutex;
+
+use super::recognizer::{GestureRecognizer, RecognizerBase};
+use crate::{
+    arena::{GestureArenaMember, GestureArenaTeam},
+    events::PointerEvent,
+    ids::PointerId,
+    processing::VelocityTracker,
+    settings::DEFAULT_ROTATION_SLOP,
+};
+
+/// Callback for rotation start events
+pub type RotationStartCallback = Rc<dyn Fn(RotationStartDetails)>;
+
+/// Callback for rotation update events
+pub type RotationUpdateCallback = Rc<dyn Fn(RotationUpdateDetails)>;
+
+/// Callback for rotation end events
+pub type RotationEndCallback = Rc<dyn Fn(RotationEndDetails)>;
+
+/// Callback for rotation cancel events
+pub type RotationCancelCallback = Rc<dyn Fn()>;
+
+/// Details about rotation gesture start
+#[derive(Debug, Clone, PartialEq)]
+pub struct RotationStartDetails {
+    /// Focal point (center between pointers) in global coordinates
+    pub focal_point: Offset<Pixels>,
+    /// Focal point in local coordinates
+    pub local_focal_point: Offset<Pixels>,
+    /// Rotation accumulated while crossing the slop, in radians
+    pub rotation: f32,
+    /// Number of pointers involved
+    pub pointer_count: usize,
+}
+
+/// Details about rotation gesture update
+#[derive(Debug, Clone, PartialEq)]
+pub struct RotationUpdateDetails {
+    /// Focal point (center between pointers) in global coordinates
+    pub focal_point: Offset<Pixels>,
+    /// Focal point in local coordinates
+    pub local_focal_point: Offset<Pixels>,
+    /// Cumulative rotation since the pointers went down, in radians
+    /// (positive = clockwise)
+    pub rotation: f32,
+    /// Rotation since the previous update, in radians
+    pub delta: f32,
+    /// Number of pointers involved
+    pub pointer_count: usize,
+}
+
+/// Details about rotation gesture end
+#[derive(Debug, Clone, PartialEq)]
+pub struct RotationEndDetails {
+    /// Final cumulative rotation, in radians
+    pub rotation: f32,
+    /// Angular velocity at release, in radians per second
+    pub angular_velocity: f32,
+    /// Number of pointers still down
+    pub pointer_count: usize,
+}
+
+/// Recognizes two-finger rotation gestures
+///
+/// Tracks the angle of the line through the first two pointers (in the order
+/// they went down) and reports cumulative rotation in radians. Starts once
+/// the accumulated rotation exceeds [`DEFAULT_ROTATION_SLOP`], claiming the
+/// arena at that point.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use flui_interaction::prelude::*;
+///
+/// let team = GestureArenaTeam::cooperative();
+/// let rotation = RotationGestureRecognizer::new(arena.clone())
+///     .with_team(team.clone())
+///     .with_on_rotation_update(|details| {
+///         tracing::debug!(radians = details.rotation, "rotating");
+///     });
+/// let scale = ScaleGestureRecognizer::new(arena).with_team(team);
+/// ```
+#[derive(Clone)]
+pub struct RotationGestureRecognizer {
+    /// Base state (arena, tracking, etc.)
+    state: RecognizerBase,
+
+    /// Callbacks
+    callbacks: Rc<RefCell<RotationCallbacks>>,
+
+    /// Current gesture state
+    gesture_state: Arc<Mutex<RotationState>>,
+
+    /// Minimum accumulated rotation to start the gesture (radians)
+    min_rotation: f32,
+}
+
+impl std::fmt::Debug for RotationGestureRecognizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RotationGestureRecognizer")
+            .field("state", &self.state)
+            .field("gesture_state", &*self.gesture_state.lock())
+            .field("min_rotation", &self.min_rotation)
+            .finish_non_exhaustive()
+    }
+}
+
+// Field names mirror the `on_rotation_start`-style builder names.
+#[allow(clippy::struct_field_names)]
+#[derive(Default)]
+struct RotationCallbacks {
+    on_start: Option<RotationStartCallback>,
+    on_update: Option<RotationUpdateCallback>,
+    on_end: Option<RotationEndCallback>,
+    on_cancel: Option<RotationCancelCallback>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RotationPhase {
+    /// Ready to start
+    Ready,
+    /// Waiting for a second pointer or sufficient rotation
+    Possible,
+    /// Rotation gesture started
+    Started,
+    /// Cancelled
+    Cancelled,
+}
+
+#[derive(Debug, Clone)]
+struct RotationState {
+    /// Current phase
+    phase: RotationPhase,
+    /// Active pointers in the order they went down
+    pointers: Vec<(PointerId, Offset<Pixels>)>,
+    /// Angle of the line through the first two pointers at the last event
+    last_angle: Option<f32>,
+    /// Rotation accumulated since the pointers went down
+    rotation: f32,
+    /// Velocity tracker fed with the cumulative rotation
+    velocity_tracker: VelocityTracker,
+}
+
+impl Default for RotationState {
+    fn default() -> Self {
+        Self {
+            phase: RotationPhase::Ready,
+            pointers: Vec::new(),
+            last_angle: None,
+            rotation: 0.0,
+            velocity_tracker: VelocityTracker::new(),
+        }
+    }
+}
+
+impl RotationState {
+    fn reset_rotation(&mut self) {
+        self.last_angle = Self::angle(&self.pointers);
+        self.rotation = 0.0;
+        self.velocity_tracker.reset();
+    }
+
+    /// Re-baseline the angle after the pointer set changed, keeping the
+    /// rotation accumulated so far.
+    fn rebase(&mut self) {
+        self.last_angle = Self::angle(&self.pointers);
+    }
+
+    /// Angle of the line from the first to the second pointer (radians).
+    fn angle(pointers: &[(PointerId, Offset<Pixels>)]) -> Option<f32> {
+        let [(_, first), (_, second), ..] = pointers else {
+            return None;
+        };
+        let delta = *second - *first;
+        Some(delta.dy.0.atan2(delta.dx.0))
+    }
+
+    /// Focal point (center of all pointers).
+    fn focal_point(&self) -> Offset<Pixels> {
+        if self.pointers.is_empty() {
+            return Offset::ZERO;
+        }
+        let (sum_x, sum_y) = self
+            .pointers
+            .iter()
+            .fold((0.0, 0.0), |(x, y), (_, pos)| (x + pos.dx.0, y + pos.dy.0));
+        let count = self.pointers.len() as f32;
+        Offset::new(Pixels(sum_x / count), Pixels(sum_y / count))
+    }
+}
+
+/// Wrap an angle difference into `(-π, π]` so a step across the ±π boundary
+/// counts as the short way round.
+fn wrap_angle(delta: f32) -> f32 {
+    let wrapped = (delta + PI).rem_euclid(2.0 * PI) - PI;
+    if wrapped <= -PI {
+        wrapped + 2.0 * PI
+    } else {
+        wrapped
+    }
+}
+
+impl RotationGestureRecognizer {
+    /// Create a new rotation recognizer with gesture arena
+    pub fn new(arena: crate::arena::GestureArena) -> Arc<Self> {
+        Arc::new(Self {
+            state: RecognizerBase::new(arena),
+            callbacks: Rc::new(RefCell::new(RotationCallbacks::default())),
+            gesture_state: Arc::new(Mutex::new(RotationState::default())),
+            min_rotation: DEFAULT_ROTATION_SLOP,
+        })
+    }
+
+    /// Set the rotation start callback
+    pub fn with_on_rotation_start(
+        self: Arc<Self>,
+        callback: impl Fn(RotationStartDetails) + 'static,
+    ) -> Arc<Self> {
+        self.callbacks.borrow_mut().on_start = Some(Rc::new(callback));
+        self
+    }
+
+    /// Set the rotation update callback
+    pub fn with_on_rotation_update(
+        self: Arc<Self>,
+        callback: impl Fn(RotationUpdateDetails) + 'static,
+    ) -> Arc<Self> {
+        self.callbacks.borrow_mut().on_update = Some(Rc::new(callback));
+        self
+    }
+
+    /// Set the rotation end callback
+    pub fn with_on_rotation_end(
+        self: Arc<Self>,
+        callback: impl Fn(RotationEndDetails) + 'static,
+    ) -> Arc<Self> {
+        self.callbacks.borrow_mut().on_end = Some(Rc::new(callback));
+        self
+    }
+
+    /// Set the rotation cancel callback
+    pub fn with_on_rotation_cancel(self: Arc<Self>, callback: impl Fn() + 'static) -> Arc<Self> {
+        self.callbacks.borrow_mut().on_cancel = Some(Rc::new(callback));
+        self
+    }
+
+    /// Compete in the arena as part of `team`.
+    ///
+    /// Use a [`GestureArenaTeam::cooperative`] team shared with a
+    /// [`ScaleGestureRecognizer`](super::ScaleGestureRecognizer) so rotation
+    /// and scale win together.
+    pub fn with_team(self: Arc<Self>, team: Arc<GestureArenaTeam>) -> Arc<Self> {
+        self.state.set_team(Some(team));
+        self
+    }
+
+    /// Handle pointer down - add to tracking
+    fn handle_pointer_down(&self, pointer: PointerId, position: Offset<Pixels>) {
+        let mut state = self.gesture_state.lock();
+
+        if state.pointers.is_empty() {
+            state.phase = RotationPhase::Ready;
+        }
+        state.pointers.push((pointer, position));
+
+        // The first two pointers define the angle; later ones only move the
+        // focal point.
+        if state.pointers.len() == 2 {
+            state.phase = RotationPhase::Possible;
+            state.reset_rotation();
+        }
+    }
+
+    /// Handle pointer move - accumulate rotation
+    fn handle_pointer_move(&self, pointer: PointerId, position: Offset<Pixels>) {
+        let mut state = self.gesture_state.lock();
+
+        if let Some((_, pos)) = state.pointers.iter_mut().find(|(id, _)| *id == pointer) {
+            *pos = position;
+        }
+
+        let (Some(angle), Some(last_angle)) =
+            (RotationState::angle(&state.pointers), state.last_angle)
+        else {
+            return;
+        };
+        let delta = wrap_angle(angle - last_angle);
+        state.last_angle = Some(angle);
+        state.rotation += delta;
+
+        match state.phase {
+            RotationPhase::Possible => {
+                if state.rotation.abs() > self.min_rotation {
+                    state.phase = RotationPhase::Started;
+                    let rotation = state.rotation;
+                    let now = self.state.now();
+                    state
+                        .velocity_tracker
+                        .add_position(now, Offset::new(Pixels(rotation), Pixels(0.0)));
+
+                    let details = RotationStartDetails {
+                        focal_point: state.focal_point(),
+                        local_focal_point: state.focal_point(),
+                        rotation,
+                        pointer_count: state.pointers.len(),
+                    };
+                    drop(state); // Release lock before arena + callback
+
+                    self.state.accept_tracked();
+
+                    if let Some(callback) = self.callbacks.borrow().on_start.clone() {
+                        callback(details);
+                    }
+                }
+            }
+            RotationPhase::Started => {
+                let rotation = state.rotation;
+                let now = self.state.now();
+                state
+                    .velocity_tracker
+                    .add_position(now, Offset::new(Pixels(rotation), Pixels(0.0)));
+
+                let focal_point = state.focal_point();
+                let details = RotationUpdateDetails {
+                    focal_point,
+                    local_focal_point: focal_point,
+                    rotation,
+                    delta,
+                    pointer_count: state.pointers.len(),
+                };
+                drop(state); // Release lock before callback
+
+                if let Some(callback) = self.callbacks.borrow().on_update.clone() {
+                    callback(details);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle pointer up - remove from tracking
+    fn handle_pointer_up(&self, pointer: PointerId) {
+        let mut state = self.gesture_state.lock();
+
+        state.pointers.retain(|(id, _)| *id != pointer);
+
+        if state.pointers.len() >= 2 {
+            // A different pair may now define the angle.
+            state.rebase();
+            return;
+        }
+
+        match state.phase {
+            RotationPhase::Started => {
+                let details = RotationEndDetails {
+                    rotation: state.rotation,
+                    angular_velocity: state.velocity_tracker.get_velocity().pixels_per_second.dx.0,
+                    pointer_count: state.pointers.len(),
+                };
+                state.phase = RotationPhase::Ready;
+                state.reset_rotation();
+                drop(state); // Release lock before callback
+
+                if let Some(callback) = self.callbacks.borrow().on_end.clone() {
+                    callback(details);
+                }
+
+                self.state.stop_tracking();
+            }
+            RotationPhase::Possible | RotationPhase::Ready => {
+                state.phase = RotationPhase::Ready;
+                state.reset_rotation();
+                let lifted_all = state.pointers.is_empty();
+                drop(state);
+
+                // Never rotated: resign rather than hold the arena open.
+                if lifted_all {
+                    self.state.reject();
+                }
+            }
+            RotationPhase::Cancelled => {}
+        }
+    }
+
+    /// Handle cancel
+    fn handle_cancel(&self) {
+        let mut state = self.gesture_state.lock();
+
+        if state.phase == RotationPhase::Started || state.phase == RotationPhase::Possible {
+            state.phase = RotationPhase::Cancelled;
+            state.pointers.clear();
+            state.reset_rotation();
+            drop(state);
+
+            if let Some(callback) = self.callbacks.borrow().on_cancel.clone() {
+                callback();
+            }
+
+            self.state.reject();
+        }
+    }
+}
+
+impl GestureRecognizer for RotationGestureRecognizer {
+    fn add_pointer(&self, pointer: PointerId, position: Offset<Pixels>) {
+        if !self.state.assert_not_disposed("add_pointer") {
+            return;
+        }
+        // For the first pointer, track with arena
+        if self.gesture_state.lock().pointers.is_empty() {
+            let recognizer = Arc::new(self.clone());
+            self.state.start_tracking(pointer, position, &recognizer);
+        }
+
+        self.handle_pointer_down(pointer, position);
+    }
+
+    fn handle_event(&self, event: &PointerEvent) {
+        if !self.state.assert_not_disposed("handle_event") {
+            return;
+        }
+        // Route by the event's own pointer id: every finger owns its slot.
+        match event {
+            PointerEvent::Move(data) => {
+                let pointer = crate::events::extract_pointer_id(event);
+                let pos = data.current.position;
+                let position = Offset::new(Pixels(pos.x as f32), Pixels(pos.y as f32));
+                self.handle_pointer_move(pointer, position);
+            }
+            PointerEvent::Up(_) => {
+                let pointer = crate::events::extract_pointer_id(event);
+                self.handle_pointer_up(pointer);
+            }
+            PointerEvent::Cancel(_) => {
+                self.handle_cancel();
+            }
+            _ => {}
+        }
+    }
+
+    fn dispose(&self) {
+        self.state.mark_disposed();
+        self.state.reject();
+        self.callbacks.borrow_mut().on_start = None;
+        self.callbacks.borrow_mut().on_update = None;
+        self.callbacks.borrow_mut().on_end = None;
+        self.callbacks.borrow_mut().on_cancel = None;
+    }
+
+    fn primary_pointer(&self) -> Option<PointerId> {
+        self.state.primary_pointer()
+    }
+}
+
+impl crate::recognizers::OneSequenceGestureRecognizer for RotationGestureRecognizer {
+    fn tracked_pointers(&self) -> Vec<PointerId> {
+        // Only the first pointer is entered into the arena; the rest live in
+        // the recognizer's own state.
+        self.state
+            .primary_pointer()
+            .map(|p| vec![p])
+            .unwrap_or_default()
+    }
+
+    fn resolve_pointer(&self, _pointer: PointerId, disposition: crate::arena::GestureDisposition) {
+        match disposition {
+            crate::arena::GestureDisposition::Accepted => {
+                self.state.accept_tracked();
+            }
+            crate::arena::GestureDisposition::Rejected => {
+                self.state.reject();
+            }
+        }
+    }
+
+    fn stop_tracking_pointer(&self, _pointer: PointerId) {
+        self.state.stop_tracking();
+    }
+}
+
+impl GestureArenaMember for RotationGestureRecognizer {
+    fn accept_gesture(&self, _pointer: PointerId) {
+        // We won the arena - callbacks fire from event handlers
+    }
+
+    fn reject_gesture(&self, _pointer: PointerId) {
+        // We lost the arena - cancel the gesture
+        self.handle_cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+    use crate::{
+        arena::GestureArena,
+        events::{PointerType, make_move_event_for_id, make_up_event_for_id},
+        recognizers::ScaleGestureRecognizer,
+    };
+
+    /// Competing arena member that never resolves on its own, so the arena
+    /// only settles when a recognizer claims it.
+    #[derive(Default)]
+    struct Bystander {
+        rejected: std::sync::atomic::AtomicBool,
+    }
+
+    impl crate::sealed::arena_member::Sealed for Bystander {}
+
+    impl GestureArenaMember for Bystander {
+        fn accept_gesture(&self, _pointer: PointerId) {}
+
+        fn reject_gesture(&self, _pointer: PointerId) {
+            self.rejected
+                .store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    impl Bystander {
+        fn join(arena: &GestureArena, pointer: PointerId) -> Arc<Self> {
+            let bystander = Arc::new(Self::default());
+            arena.add(pointer, bystander.clone());
+            bystander
+        }
+
+        fn was_rejected(&self) -> bool {
+            self.rejected.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    fn finger(n: u64) -> PointerId {
+        PointerId::new(n).expect("nonzero pointer id")
+    }
+
+    fn at(x: f32, y: f32) -> Offset<Pixels> {
+        Offset::new(Pixels(x), Pixels(y))
+    }
+
+    /// Place two fingers on opposite ends of a circle of `radius` around
+    /// `center`, at `angle` radians.
+    fn orbit(center: Offset<Pixels>, radius: f32, angle: f32) -> [Offset<Pixels>; 2] {
+        let arm = at(radius * angle.cos(), radius * angle.sin());
+        [center - arm, center + arm]
+    }
+
+    /// Drive both fingers around the circle in `steps` equal increments up to
+    /// `to` radians.
+    fn rotate(
+        recognizer: &RotationGestureRecognizer,
+        center: Offset<Pixels>,
+        from: f32,
+        to: f32,
+        steps: u16,
+    ) {
+        for i in 1..=steps {
+            let angle = from + (to - from) * f32::from(i) / f32::from(steps);
+            let [a, b] = orbit(center, 50.0, angle);
+            recognizer.handle_event(&make_move_event_for_id(finger(2), a, PointerType::Touch));
+            recognizer.handle_event(&make_move_event_for_id(finger(3), b, PointerType::Touch));
+        }
+    }
+
+    #[test]
+    fn orbiting_pointers_report_rotation_delta() {
+        let arena = GestureArena::new();
+        let started = Rc::new(RefCell::new(None));
+        let last_update = Rc::new(RefCell::new(None));
+        let ended = Rc::new(RefCell::new(None));
+        let (started2, last_update2, ended2) =
+            (started.clone(), last_update.clone(), ended.clone());
+        let recognizer = RotationGestureRecognizer::new(arena.clone())
+            .with_on_rotation_start(move |d| *started2.borrow_mut() = Some(d))
+            .with_on_rotation_update(move |d| *last_update2.borrow_mut() = Some(d))
+            .with_on_rotation_end(move |d| *ended2.borrow_mut() = Some(d));
+
+        let center = at(200.0, 200.0);
+        let [a, b] = orbit(center, 50.0, 0.0);
+        recognizer.add_pointer(finger(2), a);
+        recognizer.add_pointer(finger(3), b);
+        let bystander = Bystander::join(&arena, finger(2));
+        arena.close(finger(2));
+
+        rotate(&recognizer, center, 0.0, FRAC_PI_2, 18);
+
+        assert!(
+            started.borrow().is_some(),
+            "quarter turn must start rotation"
+        );
+        let update = last_update.borrow().clone().expect("rotation updates");
+        assert!(
+            (update.rotation - FRAC_PI_2).abs() < 1e-3,
+            "expected a quarter turn, got {}",
+            update.rotation
+        );
+        assert!((update.focal_point.dx.0 - 200.0).abs() < 1e-3);
+        assert!(bystander.was_rejected(), "start must claim the arena");
+
+        recognizer.handle_event(&make_up_event_for_id(finger(3), b, PointerType::Touch));
+        let end = ended
+            .borrow()
+            .clone()
+            .expect("lifting a finger ends rotation");
+        assert!((end.rotation - FRAC_PI_2).abs() < 1e-3);
+        assert!(end.angular_velocity.is_finite());
+        assert_eq!(end.pointer_count, 1);
+    }
+
+    #[test]
+    fn rotation_below_slop_does_not_start() {
+        let arena = GestureArena::new();
+        let started = Rc::new(RefCell::new(false));
+        let started2 = started.clone();
+        let recognizer = RotationGestureRecognizer::new(arena)
+            .with_on_rotation_start(move |_| *started2.borrow_mut() = true);
+
+        let center = at(100.0, 100.0);
+        let [a, b] = orbit(center, 50.0, 0.0);
+        recognizer.add_pointer(finger(2), a);
+        recognizer.add_pointer(finger(3), b);
+
+        rotate(&recognizer, center, 0.0, DEFAULT_ROTATION_SLOP * 0.5, 4);
+
+        assert!(!*started.borrow());
+        assert_eq!(
+            recognizer.gesture_state.lock().phase,
+            RotationPhase::Possible
+        );
+    }
+
+    #[test]
+    fn rotation_accumulates_across_half_turn_boundary() {
+        let arena = GestureArena::new();
+        let last = Rc::new(RefCell::new(0.0_f32));
+        let last2 = last.clone();
+        let recognizer = RotationGestureRecognizer::new(arena)
+            .with_on_rotation_update(move |d| *last2.borrow_mut() = d.rotation);
+
+        // Start just short of π so the raw atan2 angle flips sign mid-twist.
+        let center = at(0.0, 0.0);
+        let start = PI - 0.2;
+        let [a, b] = orbit(center, 50.0, start);
+        recognizer.add_pointer(finger(2), a);
+        recognizer.add_pointer(finger(3), b);
+
+        rotate(&recognizer, center, start, start + 0.6, 12);
+
+        assert!(
+            (*last.borrow() - 0.6).abs() < 1e-3,
+            "rotation must not jump by a full turn, got {}",
+            *last.borrow()
+        );
+    }
+
+    #[test]
+    fn wrap_angle_takes_short_way_round() {
+        assert!((wrap_angle(2.0 * PI - 0.1) + 0.1).abs() < 1e-5);
+        assert!((wrap_angle(-2.0 * PI + 0.1) - 0.1).abs() < 1e-5);
+        assert!((wrap_angle(PI) - PI).abs() < 1e-5);
+    }
+
+    #[test]
+    fn rotation_and_scale_win_together_in_cooperative_team() {
+        let arena = GestureArena::new();
+        let team = GestureArenaTeam::cooperative();
+
+        let scale_updates = Rc::new(RefCell::new(0_usize));
+        let scale_cancelled = Rc::new(RefCell::new(false));
+        let rotation_cancelled = Rc::new(RefCell::new(false));
+        let (scale_updates2, scale_cancelled2, rotation_cancelled2) = (
+            scale_updates.clone(),
+            scale_cancelled.clone(),
+            rotation_cancelled.clone(),
+        );
+        let scale = ScaleGestureRecognizer::new(arena.clone())
+            .with_team(team.clone())
+            .with_on_scale_update(move |_| *scale_updates2.borrow_mut() += 1)
+            .with_on_scale_cancel(move || *scale_cancelled2.borrow_mut() = true);
+        let rotation = RotationGestureRecognizer::new(arena.clone())
+            .with_team(team.clone())
+            .with_on_rotation_cancel(move || *rotation_cancelled2.borrow_mut() = true);
+
+        let center = at(100.0, 100.0);
+        let [a, b] = orbit(center, 50.0, 0.0);
+        for recognizer in [&*scale as &dyn GestureRecognizer, &*rotation] {
+            recognizer.add_pointer(finger(2), a);
+            recognizer.add_pointer(finger(3), b);
+        }
+        let bystander = Bystander::join(&arena, finger(2));
+        arena.close(finger(2));
+
+        // Twist while spreading the fingers: both gestures are present.
+        for i in 1..=10_u16 {
+            let t = f32::from(i) / 10.0;
+            let arm_angle = FRAC_PI_2 * t;
+            let radius = 50.0 + 30.0 * t;
+            let arm = at(radius * arm_angle.cos(), radius * arm_angle.sin());
+            for (id, pos) in [(finger(2), center - arm), (finger(3), center + arm)] {
+                let event = make_move_event_for_id(id, pos, PointerType::Touch);
+                scale.handle_event(&event);
+                rotation.handle_event(&event);
+            }
+        }
+
+        assert!(bystander.was_rejected(), "the team must win the arena");
+        assert!(!*rotation_cancelled.borrow());
+        assert!(
+            !*scale_cancelled.borrow(),
+            "scale must not be rejected when rotation claims the team win"
+        );
+        assert!(*scale_updates.borrow() > 0);
+        assert_eq!(rotation.gesture_state.lock().phase, RotationPhase::Started);
+    }
+}
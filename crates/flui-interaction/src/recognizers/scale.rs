@@ -16,7 +16,10 @@
 
 use super::recognizer::{GestureRecognizer, RecognizerBase};
 use crate::{
-    arena::GestureArenaMember, events::PointerEvent, ids::PointerId, processing::VelocityTracker,
+    arena::{GestureArenaMember, GestureArenaTeam},
+    events::PointerEvent,
+    ids::PointerId,
+    processing::VelocityTracker,
 };
 
 /// Callback for scale start events
@@ -234,6 +237,15 @@ pub fn with_on_scale_cancel(self: Arc<Self>, callback: impl Fn() + 'static) -> A
         self
     }
 
+    /// Compete in the arena as part of `team`.
+    ///
+    /// Pair with a [`RotationGestureRecognizer`](super::RotationGestureRecognizer)
+    /// in a [`GestureArenaTeam::cooperative`] team to pinch and rotate at once.
+    pub fn with_team(self: Arc<Self>, team: Arc<GestureArenaTeam>) -> Arc<Self> {
+        self.state.set_team(Some(team));
+        self
+    }
+
     /// Handle pointer down - add to tracking
     fn handle_pointer_down(&self, pointer: PointerId, position: Offset<Pixels>) {
         let mut state = self.gesture_state.lock();
@@ -175,15 +175,18 @@ fn route_pointer_event(&mut self, root: &mut dyn HitTestable, event: &PointerEve
     /// Route keyboard event to focused element.
     ///
     /// Events are dispatched via FocusManager:
-    /// 1. Global key handlers (shortcuts)
-    /// 2. Focused node's handler
+    /// 1. Shortcut bindings, innermost focus scope first
+    ///    ([`FocusManager::dispatch_shortcut`])
+    /// 2. Global key handlers
+    /// 3. Focused node's handler, then its ancestors'
     ///
     /// Returns `true` if the event was handled.
     fn route_key_event(_root: &mut dyn HitTestable, event: &KeyEvent) -> bool {
-        let handled = FocusManager::global().dispatch_key_event(event);
+        let focus = FocusManager::global();
+        let handled = focus.dispatch_shortcut(event) || focus.dispatch_key_event(event);
 
         if !handled {
-            if FocusManager::global().focused().is_some() {
+            if focus.focused().is_some() {
                 tracing::trace!("Key event not handled by focused element");
             } else {
                 tracing::trace!("No focused element for key event");
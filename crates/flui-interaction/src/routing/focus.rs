@@ -61,7 +61,12 @@
 use crate::{
     events::KeyEvent,
     ids::FocusNodeId,
-    routing::focus_scope::{FocusNode, FocusScopeNode, KeyEventResult, ResolvedStep},
+    routing::{
+        focus_scope::{
+            FocusNode, FocusScopeNode, KeyEventResult, ResolvedStep, TraversalDirection,
+        },
+        shortcuts::{ActionMap, Intent, KeyCombination},
+    },
 };
 
 // ============================================================================
@@ -154,6 +159,9 @@ pub struct FocusManager {
     /// [`FocusManager::root_scope`]. App code can set this to a sub-scope to scope
     /// traversal (matches Flutter modal-route scope semantics).
     active_scope: RwLock<Option<Arc<FocusScopeNode>>>,
+
+    /// Actions performing shortcut intents — Flutter's root `Actions`.
+    actions: RwLock<ActionMap>,
 }
 
 impl std::fmt::Debug for FocusManager {
@@ -182,6 +190,7 @@ fn default() -> Self {
             key_handlers: RwLock::new(HashMap::new()),
             global_key_handlers: RwLock::new(Vec::new()),
             active_scope: RwLock::new(None),
+            actions: RwLock::new(ActionMap::new()),
         }
     }
 }
@@ -425,7 +434,28 @@ pub fn focus_previous(&self) -> bool {
     fn traverse(&self, forward: bool) -> bool {
         let scope = self.active_scope();
         let current = *self.primary_focus.read();
-        match scope.step(current, forward) {
+        self.perform_step(scope.step(current, forward))
+    }
+
+    /// Move focus to the nearest focusable node in `direction` from the
+    /// primary focus (arrow keys), within the active scope.
+    ///
+    /// Geometry-based — see [`FocusScopeNode::resolve_directional`] for the
+    /// selection rule. With nothing focused, focuses the node at the edge the
+    /// motion starts from. At the edge of the scope the scope's
+    /// [`directional_traversal_edge_behavior`](FocusScopeNode::directional_traversal_edge_behavior)
+    /// decides (stop by default; `ClosedLoop` wraps).
+    ///
+    /// Returns `true` if focus moved.
+    pub fn focus_in_direction(&self, direction: TraversalDirection) -> bool {
+        let scope = self.active_scope();
+        let current = *self.primary_focus.read();
+        self.perform_step(scope.step_in_direction(current, direction))
+    }
+
+    /// Perform a resolved traversal step against this manager.
+    fn perform_step(&self, step: ResolvedStep) -> bool {
+        match step {
             ResolvedStep::Focus(id) => {
                 self.set_primary_focus(Some(id));
                 true
@@ -585,6 +615,83 @@ pub fn dispatch_key_event(&self, event: &KeyEvent) -> bool {
     pub fn has_key_handler(&self, node_id: FocusNodeId) -> bool {
         self.key_handlers.read().contains_key(&node_id)
     }
+
+    /// Bind `combination` to `intent` globally.
+    ///
+    /// Global shortcuts live on the [root scope](Self::root_scope); a
+    /// binding for the same combination on a scope enclosing the primary
+    /// focus takes precedence.
+    pub fn register_shortcut(&self, combination: KeyCombination, intent: impl Intent) {
+        self.root_scope.set_shortcut(combination, intent);
+    }
+
+    /// Remove the global binding for `combination`.
+    pub fn unregister_shortcut(&self, combination: &KeyCombination) {
+        self.root_scope.remove_shortcut(combination);
+    }
+
+    /// Register the action performing intents of type `I`, replacing any
+    /// previous one.
+    ///
+    /// The action returns `true` if it handled the intent; `false` lets the
+    /// key event fall through to outer scopes' bindings and then to the
+    /// focus handlers.
+    pub fn register_action<I: Intent>(&self, action: impl Fn(&I) -> bool + 'static) {
+        self.actions.write().register(action);
+    }
+
+    /// Remove the action for intents of type `I`.
+    pub fn unregister_action<I: Intent>(&self) {
+        self.actions.write().unregister::<I>();
+    }
+
+    /// Perform `intent` with its registered action.
+    ///
+    /// Returns `true` if an action handled it — Flutter's `Actions.invoke`.
+    pub fn invoke_action(&self, intent: &dyn Intent) -> bool {
+        // Clone the action out so it can register or remove actions itself.
+        let action = self.actions.read().action_for(intent);
+        action.is_some_and(|action| action(intent))
+    }
+
+    /// Resolve a key event against the shortcut registries and perform the
+    /// bound intent.
+    ///
+    /// Scopes are consulted innermost first: the scopes enclosing the
+    /// primary focus (including the focused node itself if it is a scope),
+    /// then the root scope. A binding whose intent has no action, or whose
+    /// action returns `false`, does not stop the search — the next scope out
+    /// gets its chance, as with nested Flutter `Shortcuts` widgets.
+    ///
+    /// Returns `true` if an action handled the event.
+    pub fn dispatch_shortcut(&self, event: &KeyEvent) -> bool {
+        let mut scopes: Vec<Arc<FocusScopeNode>> = self
+            .primary_focus()
+            .and_then(|id| self.find_node(id))
+            .map(|focused| {
+                std::iter::once(Arc::clone(&focused))
+                    .chain(focused.ancestors())
+                    .filter_map(|node| node.as_scope())
+                    .collect()
+            })
+            .unwrap_or_default();
+        if !scopes
+            .last()
+            .is_some_and(|scope| Arc::ptr_eq(scope, &self.root_scope))
+        {
+            scopes.push(Arc::clone(&self.root_scope));
+        }
+
+        for scope in &scopes {
+            if let Some(intent) = scope.shortcut_intent(event)
+                && self.invoke_action(&*intent)
+            {
+                tracing::trace!(scope = scope.id().get(), ?intent, "Shortcut handled");
+                return true;
+            }
+        }
+        false
+    }
 }
 
 #[cfg(test)]
@@ -1626,4 +1733,232 @@ fn a_non_finite_rect_does_not_panic_the_sort() {
         let order = root.sorted_traversal_order(None);
         assert_eq!(order.len(), NODES + 5);
     }
+
+    /// A 3×3 grid of 10×10 cells at a 20 px pitch, attached to `manager`'s
+    /// root scope; `grid[row][col]`.
+    fn focus_grid(manager: &FocusManager) -> Vec<Vec<Arc<FocusNode>>> {
+        let root = manager.root_scope().clone();
+        (0..3_u8)
+            .map(|row| {
+                (0..3_u8)
+                    .map(|col| {
+                        let node = FocusNode::with_debug_label(format!("{row},{col}"));
+                        node.set_rect(flui_types::geometry::Rect::from_xywh(
+                            flui_types::geometry::Pixels(f32::from(col) * 20.0),
+                            flui_types::geometry::Pixels(f32::from(row) * 20.0),
+                            flui_types::geometry::Pixels(10.0),
+                            flui_types::geometry::Pixels(10.0),
+                        ));
+                        root.attach_node(&node);
+                        node
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn arrow_keys_move_to_the_grid_neighbour() {
+        let manager = FocusManager::new_for_test();
+        let grid = focus_grid(&manager);
+        let center = grid[1][1].id();
+
+        for (direction, expected) in [
+            (TraversalDirection::Right, &grid[1][2]),
+            (TraversalDirection::Down, &grid[2][1]),
+            (TraversalDirection::Left, &grid[1][0]),
+            (TraversalDirection::Up, &grid[0][1]),
+        ] {
+            manager.request_focus(center);
+            assert!(manager.focus_in_direction(direction), "{direction:?} moves");
+            assert_eq!(
+                manager.focused(),
+                Some(expected.id()),
+                "{direction:?} from the center"
+            );
+        }
+
+        // Chained: right twice from the left column crosses the row.
+        manager.request_focus(grid[2][0].id());
+        assert!(manager.focus_in_direction(TraversalDirection::Right));
+        assert!(manager.focus_in_direction(TraversalDirection::Right));
+        assert_eq!(manager.focused(), Some(grid[2][2].id()));
+    }
+
+    #[test]
+    fn arrow_keys_from_nothing_focused_start_at_the_near_edge() {
+        let manager = FocusManager::new_for_test();
+        let grid = focus_grid(&manager);
+
+        assert!(manager.focus_in_direction(TraversalDirection::Down));
+        assert_eq!(
+            manager.focused(),
+            Some(grid[0][0].id()),
+            "topmost, then leftmost"
+        );
+
+        manager.unfocus();
+        assert!(manager.focus_in_direction(TraversalDirection::Left));
+        assert_eq!(
+            manager.focused(),
+            Some(grid[0][2].id()),
+            "rightmost, then topmost"
+        );
+    }
+
+    #[test]
+    fn directional_edge_stops_by_default_and_wraps_when_configured() {
+        use crate::routing::focus_scope::TraversalEdgeBehavior;
+
+        let manager = FocusManager::new_for_test();
+        let grid = focus_grid(&manager);
+        let root = manager.root_scope().clone();
+
+        assert_eq!(
+            root.directional_traversal_edge_behavior(),
+            TraversalEdgeBehavior::Stop
+        );
+        manager.request_focus(grid[1][2].id());
+        assert!(!manager.focus_in_direction(TraversalDirection::Right));
+        assert_eq!(manager.focused(), Some(grid[1][2].id()), "Stop stays put");
+
+        root.set_directional_traversal_edge_behavior(TraversalEdgeBehavior::ClosedLoop);
+        assert!(manager.focus_in_direction(TraversalDirection::Right));
+        assert_eq!(
+            manager.focused(),
+            Some(grid[1][0].id()),
+            "wraps to the start of the same row"
+        );
+        manager.request_focus(grid[0][1].id());
+        assert!(manager.focus_in_direction(TraversalDirection::Up));
+        assert_eq!(
+            manager.focused(),
+            Some(grid[2][1].id()),
+            "wraps to the bottom of the same column"
+        );
+
+        // Tab's edge behavior is independent.
+        assert_eq!(
+            root.traversal_edge_behavior(),
+            TraversalEdgeBehavior::ClosedLoop
+        );
+    }
+
+    #[test]
+    fn lined_up_candidates_beat_nearer_ones_off_to_the_side() {
+        let manager = FocusManager::new_for_test();
+        let root = manager.root_scope().clone();
+        let rect = |x: f32, y: f32| {
+            flui_types::geometry::Rect::from_xywh(
+                flui_types::geometry::Pixels(x),
+                flui_types::geometry::Pixels(y),
+                flui_types::geometry::Pixels(10.0),
+                flui_types::geometry::Pixels(10.0),
+            )
+        };
+        let source = FocusNode::with_debug_label("source");
+        source.set_rect(rect(0.0, 0.0));
+        // Just to the right but in another row: no vertical overlap.
+        let near_off_row = FocusNode::with_debug_label("near, off row");
+        near_off_row.set_rect(rect(15.0, 12.0));
+        // Far to the right, partially overlapping the source's row.
+        let far_in_row = FocusNode::with_debug_label("far, in row");
+        far_in_row.set_rect(rect(200.0, 6.0));
+        for node in [&source, &near_off_row, &far_in_row] {
+            root.attach_node(node);
+        }
+
+        manager.request_focus(source.id());
+        assert!(manager.focus_in_direction(TraversalDirection::Right));
+        assert_eq!(manager.focused(), Some(far_in_row.id()));
+
+        // Without an in-band candidate, the nearest edge wins.
+        root.detach_node(far_in_row.id());
+        manager.request_focus(source.id());
+        assert!(manager.focus_in_direction(TraversalDirection::Right));
+        assert_eq!(manager.focused(), Some(near_off_row.id()));
+
+        // Nothing lies to the left at all.
+        manager.request_focus(source.id());
+        assert!(!manager.focus_in_direction(TraversalDirection::Left));
+    }
+
+    /// A binding on the scope enclosing the focus shadows the global binding
+    /// for the same combination; a combination nobody binds reaches the
+    /// focused node's key handler unchanged.
+    #[test]
+    fn scope_shortcuts_override_global_ones_and_unbound_keys_propagate() {
+        use std::cell::RefCell;
+
+        use crate::{
+            events::{Key, Modifiers, keyboard::Code},
+            testing::input::KeyEventBuilder,
+        };
+
+        #[derive(Debug)]
+        struct SaveDocument;
+        impl Intent for SaveDocument {}
+
+        #[derive(Debug)]
+        struct SaveSelection;
+        impl Intent for SaveSelection {}
+
+        let manager = FocusManager::new_for_test();
+        let editor = FocusScopeNode::with_debug_label("editor");
+        manager.root_scope().attach_node(editor.as_focus_node());
+        let field = FocusNode::with_debug_label("field");
+        editor.attach_node(&field);
+
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2, l3) = (Rc::clone(&log), Rc::clone(&log), Rc::clone(&log));
+        manager.register_action(move |_: &SaveDocument| {
+            l1.borrow_mut().push("document");
+            true
+        });
+        manager.register_action(move |_: &SaveSelection| {
+            l2.borrow_mut().push("selection");
+            true
+        });
+        manager.register_key_handler(
+            field.id(),
+            Rc::new(move |_event| {
+                l3.borrow_mut().push("field");
+                true
+            }),
+        );
+
+        let ctrl_s = KeyCombination::new(Key::Character("s".into()), Modifiers::CONTROL);
+        manager.register_shortcut(ctrl_s.clone(), SaveDocument);
+        let key = |text: &str, code| {
+            KeyEventBuilder::new(code)
+                .with_key(Key::Character(text.into()))
+                .with_modifiers(Modifiers::CONTROL)
+                .build()
+        };
+
+        // Nothing focused: only the global binding applies.
+        assert!(manager.dispatch_shortcut(&key("s", Code::KeyS)));
+        assert_eq!(log.take(), ["document"]);
+
+        // Focus inside the editor: its binding wins.
+        editor.set_shortcut(ctrl_s.clone(), SaveSelection);
+        manager.request_focus(field.id());
+        assert!(manager.dispatch_shortcut(&key("s", Code::KeyS)));
+        assert_eq!(log.take(), ["selection"]);
+
+        // A disabled scope action falls through to the global binding.
+        manager.register_action(|_: &SaveSelection| false);
+        assert!(manager.dispatch_shortcut(&key("s", Code::KeyS)));
+        assert_eq!(log.take(), ["document"]);
+
+        // Ctrl+D is unbound: no shortcut fires, the focused node handles it.
+        let ctrl_d = key("d", Code::KeyD);
+        assert!(!manager.dispatch_shortcut(&ctrl_d));
+        assert!(manager.dispatch_key_event(&ctrl_d));
+        assert_eq!(log.take(), ["field"]);
+
+        manager
+            .root_scope()
+            .detach_node(editor.as_focus_node().id());
+    }
 }
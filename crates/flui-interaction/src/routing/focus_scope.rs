@@ -7,6 +7,7 @@
 //! - [`FocusScopeNode`] - A special FocusNode that groups descendants and
 //!   tracks focus history
 //! - [`FocusTraversalPolicy`] - Determines Tab/Shift+Tab navigation order
+//! - [`TraversalDirection`] - Arrow-key (directional) traversal by geometry
 //!
 //! # Flutter Architecture
 //!
@@ -69,6 +70,7 @@
 use flui_types::geometry::{Pixels, Rect};
 use parking_lot::{Mutex, RwLock};
 
+use super::shortcuts::{Intent, KeyCombination, ShortcutRegistry};
 use crate::events::KeyEvent;
 // Re-export FocusNodeId from ids module
 pub use crate::ids::FocusNodeId;
@@ -525,6 +527,15 @@ pub fn previous_focus(&self) -> bool {
         false
     }
 
+    /// Moves focus to the nearest focusable node in `direction` (arrow keys).
+    /// Flutter's `FocusNode.focusInDirection`.
+    pub fn focus_in_direction(&self, direction: TraversalDirection) -> bool {
+        if let Some(scope) = self.enclosing_scope() {
+            return scope.focus_in_direction_in_scope(self.id, direction);
+        }
+        false
+    }
+
     /// Handles a key event through this node's [`on_key_event`] handler.
     ///
     /// The handler's [`KeyEventResult`] passes through verbatim —
@@ -852,6 +863,14 @@ pub struct FocusScopeNode {
     /// What happens when traversal runs off the end of this scope's order —
     /// Flutter's `TraversalEdgeBehavior` (`focus_traversal.dart:113-156`).
     traversal_edge_behavior: RwLock<TraversalEdgeBehavior>,
+
+    /// Edge behavior for arrow-key traversal — Flutter's
+    /// `directionalTraversalEdgeBehavior`, which defaults to `stop`.
+    directional_traversal_edge_behavior: RwLock<TraversalEdgeBehavior>,
+
+    /// Shortcuts bound within this scope; they shadow bindings of enclosing
+    /// scopes while focus is inside it.
+    shortcuts: RwLock<ShortcutRegistry>,
 }
 
 impl FocusScopeNode {
@@ -864,6 +883,8 @@ pub fn new() -> Arc<Self> {
             traps_focus: AtomicBool::new(false),
             traversal_policy: RwLock::new(Arc::new(ReadingOrderPolicy)),
             traversal_edge_behavior: RwLock::new(TraversalEdgeBehavior::default()),
+            directional_traversal_edge_behavior: RwLock::new(TraversalEdgeBehavior::Stop),
+            shortcuts: RwLock::new(ShortcutRegistry::new()),
         })
     }
 
@@ -877,6 +898,8 @@ pub fn with_debug_label(label: impl Into<String>) -> Arc<Self> {
             traps_focus: AtomicBool::new(false),
             traversal_policy: RwLock::new(Arc::new(ReadingOrderPolicy)),
             traversal_edge_behavior: RwLock::new(TraversalEdgeBehavior::default()),
+            directional_traversal_edge_behavior: RwLock::new(TraversalEdgeBehavior::Stop),
+            shortcuts: RwLock::new(ShortcutRegistry::new()),
         })
     }
 
@@ -963,6 +986,37 @@ pub fn set_traversal_edge_behavior(&self, behavior: TraversalEdgeBehavior) {
         *self.traversal_edge_behavior.write() = behavior;
     }
 
+    /// This scope's edge behavior for arrow-key traversal. Defaults to
+    /// [`TraversalEdgeBehavior::Stop`], like Flutter.
+    pub fn directional_traversal_edge_behavior(&self) -> TraversalEdgeBehavior {
+        *self.directional_traversal_edge_behavior.read()
+    }
+
+    /// Set what happens when arrow-key traversal finds no node in the
+    /// requested direction. [`TraversalEdgeBehavior::ClosedLoop`] wraps to
+    /// the far side of the scope (staying in the same row or column when one
+    /// lines up).
+    pub fn set_directional_traversal_edge_behavior(&self, behavior: TraversalEdgeBehavior) {
+        *self.directional_traversal_edge_behavior.write() = behavior;
+    }
+
+    /// Binds `combination` to `intent` within this scope, replacing any
+    /// binding this scope already had for it.
+    pub fn set_shortcut(&self, combination: KeyCombination, intent: impl Intent) {
+        self.shortcuts.write().register(combination, intent);
+    }
+
+    /// Removes this scope's binding for `combination`, letting enclosing
+    /// scopes' bindings apply again.
+    pub fn remove_shortcut(&self, combination: &KeyCombination) {
+        self.shortcuts.write().unregister(combination);
+    }
+
+    /// The intent this scope binds to the combination `event` triggers.
+    pub fn shortcut_intent(&self, event: &KeyEvent) -> Option<Rc<dyn Intent>> {
+        self.shortcuts.read().intent_for(event)
+    }
+
     /// Sets focus to the first focusable child through the current owner
     /// thread's TLS manager, in **policy order**, not attach order.
     pub fn set_first_focus(self: &Arc<Self>) {
@@ -1075,6 +1129,89 @@ pub fn resolve_traversal(&self, current: Option<FocusNodeId>, forward: bool) ->
         }
     }
 
+    /// One arrow-key step from `current` — the directional counterpart of
+    /// [`resolve_traversal`](Self::resolve_traversal), deciding by geometry
+    /// ([`FocusNode::rect`]) instead of policy order.
+    ///
+    /// Flutter's `DirectionalFocusTraversalPolicyMixin.inDirection`: only
+    /// candidates whose center lies past `current`'s leading edge are
+    /// eligible. Those overlapping `current` on the perpendicular axis (the
+    /// row for left/right, the column for up/down) win, nearest center first;
+    /// otherwise the candidate with the nearest edge wins, so one far off to
+    /// the side loses to one that is slightly farther ahead but lined up.
+    ///
+    /// With nothing focused, the node at the edge the motion starts from is
+    /// chosen (Down from nothing focuses the topmost node). When no candidate
+    /// lies ahead, the
+    /// [`directional_traversal_edge_behavior`](Self::directional_traversal_edge_behavior)
+    /// decides; a cursor outside this scope resolves to [`ResolvedStep::None`].
+    pub fn resolve_directional(
+        &self,
+        current: Option<FocusNodeId>,
+        direction: TraversalDirection,
+    ) -> ResolvedStep {
+        let candidates = self.collect_focusable_nodes();
+
+        let Some(current) = current else {
+            return match first_in_direction(&candidates, direction) {
+                Some(node) => ResolvedStep::Focus(node.id()),
+                None => ResolvedStep::None,
+            };
+        };
+
+        let Some(source) = self
+            .inner
+            .descendants()
+            .find(|node| node.id() == current)
+            .map(|node| node.rect())
+        else {
+            return ResolvedStep::None;
+        };
+
+        let others = || candidates.iter().filter(|node| node.id() != current);
+        if let Some(node) = nearest_in_direction(source, others(), direction) {
+            return ResolvedStep::Focus(node.id());
+        }
+
+        match self.directional_traversal_edge_behavior() {
+            TraversalEdgeBehavior::ParentScope if self.inner.enclosing_scope().is_some() => {
+                ResolvedStep::RetryInParent
+            }
+            TraversalEdgeBehavior::ClosedLoop | TraversalEdgeBehavior::ParentScope => {
+                // Re-run the search from just beyond the opposite side of the
+                // scope, so the row or column is kept when one lines up.
+                let wrapped = wrapped_source(source, others(), direction);
+                match nearest_in_direction(wrapped, others(), direction) {
+                    Some(node) => ResolvedStep::Focus(node.id()),
+                    None => ResolvedStep::None,
+                }
+            }
+            TraversalEdgeBehavior::Stop => ResolvedStep::None,
+            TraversalEdgeBehavior::LeaveFlutterView => ResolvedStep::Unfocus,
+        }
+    }
+
+    /// [`resolve_directional`](Self::resolve_directional), following
+    /// [`ResolvedStep::RetryInParent`] up the scope chain like
+    /// [`step`](Self::step).
+    pub fn step_in_direction(
+        &self,
+        current: Option<FocusNodeId>,
+        direction: TraversalDirection,
+    ) -> ResolvedStep {
+        self.step_with(|scope| scope.resolve_directional(current, direction))
+    }
+
+    /// Focuses the nearest node in `direction` from `current` in this scope.
+    /// Returns `true` when focus moved.
+    pub fn focus_in_direction_in_scope(
+        &self,
+        current: FocusNodeId,
+        direction: TraversalDirection,
+    ) -> bool {
+        Self::perform(self.step_in_direction(Some(current), direction))
+    }
+
     /// Focuses the next node in this scope. Returns `true` when focus
     /// advanced.
     pub fn focus_next_in_scope(&self, current: FocusNodeId) -> bool {
@@ -1094,11 +1231,17 @@ pub fn focus_previous_in_scope(&self, current: FocusNodeId) -> bool {
     /// The walk is bounded by the scope chain, which is finite and acyclic
     /// (a scope's parent is a strict ancestor), so it terminates.
     pub fn step(&self, current: Option<FocusNodeId>, forward: bool) -> ResolvedStep {
+        self.step_with(|scope| scope.resolve_traversal(current, forward))
+    }
+
+    /// Run `resolve` against this scope, then against each enclosing scope for
+    /// as long as it answers [`ResolvedStep::RetryInParent`].
+    fn step_with(&self, resolve: impl Fn(&FocusScopeNode) -> ResolvedStep) -> ResolvedStep {
         let mut scope: Option<Arc<FocusScopeNode>> = None;
         loop {
             let step = match &scope {
-                Some(scope) => scope.resolve_traversal(current, forward),
-                None => self.resolve_traversal(current, forward),
+                Some(scope) => resolve(scope),
+                None => resolve(self),
             };
             if step != ResolvedStep::RetryInParent {
                 return step;
@@ -1282,6 +1425,153 @@ fn sorted_indices(nodes: &[Arc<FocusNode>]) -> Vec<usize> {
     }
 }
 
+// ============================================================================
+// Directional traversal
+// ============================================================================
+
+/// A direction for arrow-key focus traversal — Flutter's `TraversalDirection`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TraversalDirection {
+    /// Toward the top of the screen.
+    Up,
+    /// Toward the right of the screen.
+    Right,
+    /// Toward the bottom of the screen.
+    Down,
+    /// Toward the left of the screen.
+    Left,
+}
+
+impl TraversalDirection {
+    /// Whether motion runs along the y axis.
+    #[inline]
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::Up | Self::Down)
+    }
+
+    /// `+1.0` when motion increases the coordinate (down/right), else `-1.0`.
+    #[inline]
+    fn sign(self) -> f32 {
+        match self {
+            Self::Down | Self::Right => 1.0,
+            Self::Up | Self::Left => -1.0,
+        }
+    }
+}
+
+/// `rect`'s `(min, max)` extent along `direction`'s axis, then across it.
+fn spans(rect: Rect<Pixels>, direction: TraversalDirection) -> ((f32, f32), (f32, f32)) {
+    let horizontal = (rect.left().0, rect.right().0);
+    let vertical = (rect.top().0, rect.bottom().0);
+    if direction.is_vertical() {
+        (vertical, horizontal)
+    } else {
+        (horizontal, vertical)
+    }
+}
+
+fn mid(span: (f32, f32)) -> f32 {
+    f32::midpoint(span.0, span.1)
+}
+
+/// The candidate `direction` reaches first from `source`, if any lies ahead.
+///
+/// Scores compare lexicographically: in-band (overlapping `source`
+/// across the axis) before out-of-band; then, in band, the distance between
+/// centers along the axis, and out of band the distance from `source`'s
+/// center to the candidate's nearest edge, which penalizes sideways offset;
+/// finally the sideways distance between centers. Ties keep tree order.
+fn nearest_in_direction<'a>(
+    source: Rect<Pixels>,
+    candidates: impl Iterator<Item = &'a Arc<FocusNode>>,
+    direction: TraversalDirection,
+) -> Option<&'a Arc<FocusNode>> {
+    let sign = direction.sign();
+    let (source_main, source_cross) = spans(source, direction);
+    let leading_edge = if sign > 0.0 {
+        source_main.1
+    } else {
+        source_main.0
+    };
+    let source_center = mid(source_main);
+
+    candidates
+        .filter_map(|node| {
+            let (main, cross) = spans(node.rect(), direction);
+            let past_leading_edge = (mid(main) - leading_edge) * sign;
+            if past_leading_edge.is_nan() || past_leading_edge < 0.0 {
+                return None;
+            }
+            let sideways = (mid(cross) - mid(source_cross)).abs();
+            let overlap = cross.1.min(source_cross.1) - cross.0.max(source_cross.0);
+            let score = if overlap > 0.0 {
+                (0u8, (mid(main) - source_center) * sign, sideways)
+            } else {
+                let near_edge = if sign > 0.0 { main.0 } else { main.1 };
+                let ahead = ((near_edge - source_center) * sign).max(0.0);
+                (1u8, ahead.hypot(-overlap), sideways)
+            };
+            Some((score, node))
+        })
+        .min_by(|(a, _), (b, _)| {
+            a.0.cmp(&b.0)
+                .then(a.1.total_cmp(&b.1))
+                .then(a.2.total_cmp(&b.2))
+        })
+        .map(|(_, node)| node)
+}
+
+/// `source` moved just beyond the side of `candidates` that `direction`
+/// starts from, keeping its cross-axis extent: the origin of a wrapped
+/// search.
+fn wrapped_source<'a>(
+    source: Rect<Pixels>,
+    candidates: impl Iterator<Item = &'a Arc<FocusNode>>,
+    direction: TraversalDirection,
+) -> Rect<Pixels> {
+    let (source_main, source_cross) = spans(source, direction);
+    let length = source_main.1 - source_main.0;
+    let main = if direction.sign() > 0.0 {
+        let start = candidates
+            .map(|node| spans(node.rect(), direction).0.0)
+            .fold(source_main.0, f32::min);
+        (start - length, start)
+    } else {
+        let end = candidates
+            .map(|node| spans(node.rect(), direction).0.1)
+            .fold(source_main.1, f32::max);
+        (end, end + length)
+    };
+    let ((left, right), (top, bottom)) = if direction.is_vertical() {
+        (source_cross, main)
+    } else {
+        (main, source_cross)
+    };
+    Rect::from_ltrb(Pixels(left), Pixels(top), Pixels(right), Pixels(bottom))
+}
+
+/// With nothing focused: the node at the edge `direction` starts from
+/// (topmost for `Down`, rightmost for `Left`), ties broken by the smaller
+/// cross-axis coordinate — Flutter's `findFirstFocusInDirection`.
+fn first_in_direction(
+    candidates: &[Arc<FocusNode>],
+    direction: TraversalDirection,
+) -> Option<&Arc<FocusNode>> {
+    candidates.iter().min_by(|a, b| {
+        let key = |node: &Arc<FocusNode>| {
+            let (main, cross) = spans(node.rect(), direction);
+            let start = if direction.sign() > 0.0 {
+                main.0
+            } else {
+                -main.1
+            };
+            (start, cross.0)
+        };
+        let (a, b) = (key(a), key(b));
+        a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1))
+    })
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -8,6 +8,7 @@
 //! - [`FocusScopeNode`] - Groups focusable elements for keyboard navigation
 //! - [`FocusTraversalPolicy`] - Determines Tab/Shift+Tab navigation order
 //! - [`PointerRouter`] - Centralized pointer event routing
+//! - [`ShortcutRegistry`] / [`ActionMap`] - Key combinations → intents → actions
 //!
 //! # Architecture
 //!
@@ -27,12 +28,13 @@
 mod interaction_lane;
 pub(crate) mod mouse_tracker;
 mod pointer_router;
+mod shortcuts;
 
 pub use event_router::EventRouter;
 pub use focus::{FocusManager, KeyEventCallback};
 pub use focus_scope::{
     FocusNode, FocusNodeId, FocusScopeNode, FocusTraversalPolicy, KeyEventHandler, KeyEventResult,
-    ReadingOrderPolicy, RectProvider, ResolvedStep, TraversalEdgeBehavior,
+    ReadingOrderPolicy, RectProvider, ResolvedStep, TraversalDirection, TraversalEdgeBehavior,
 };
 pub use hit_test::{
     EventPropagation, HitTestBehavior, HitTestEntry, HitTestResult, HitTestable, RenderId,
@@ -47,3 +49,4 @@
 };
 pub use mouse_tracker::{CursorChangeCallback, DeviceId, MouseTracker, MouseTrackerAnnotation};
 pub use pointer_router::{GlobalPointerHandler, PointerRouteHandler, PointerRouter};
+pub use shortcuts::{ActionCallback, ActionMap, Intent, KeyCombination, ShortcutRegistry};
@@ -72,6 +72,11 @@ struct DeviceState {
     active_order: Vec<RegionId>,
     /// Current mouse cursor for this device.
     current_cursor: CursorIcon,
+    /// Non-default cursors along the last hit-test path, front first, each
+    /// with the region that contributed it (if any). Lets
+    /// [`MouseTracker::annotations_changed`] recompute the cursor without a
+    /// fresh hit test.
+    cursor_path: SmallVec<[(Option<RegionId>, CursorIcon); 4]>,
 }
 
 impl DeviceState {
@@ -81,8 +86,17 @@ fn new(position: Offset<Pixels>) -> Self {
             active_regions: HashSet::new(),
             active_order: Vec::new(),
             current_cursor: CursorIcon::Default,
+            cursor_path: SmallVec::new(),
         }
     }
+
+    /// The cursor the remaining `cursor_path` resolves to — the same
+    /// first-non-default rule as [`HitTestResult::resolve_cursor`].
+    fn resolve_cursor_path(&self) -> CursorIcon {
+        self.cursor_path
+            .first()
+            .map_or(CursorIcon::Default, |&(_, cursor)| cursor)
+    }
 }
 
 /// Owner-local mouse tracker.
@@ -238,6 +252,7 @@ pub fn update_with_event(&self, event: &InputEvent, hit_test_result: &HitTestRes
             state.active_regions = new_regions;
             state.active_order = resolved.order;
             state.current_cursor = new_cursor;
+            state.cursor_path = cursor_path(hit_test_result);
 
             let enter_callbacks: SmallVec<[MouseEnterCallback; 4]> = entered
                 .iter()
@@ -332,6 +347,7 @@ pub fn update_all_devices<F>(&self, hit_test_fn: F)
                 state.active_regions = new_regions;
                 state.active_order = resolved.order;
                 state.current_cursor = new_cursor;
+                state.cursor_path = cursor_path(&result);
 
                 let enter_callbacks: SmallVec<[MouseEnterCallback; 4]> = entered
                     .iter()
@@ -375,6 +391,76 @@ pub fn update_all_devices<F>(&self, hit_test_fn: F)
         }
     }
 
+    /// Synthesizes exit callbacks for hovered regions whose annotations are no
+    /// longer mounted.
+    ///
+    /// Call after the tree changes with every annotation that is still
+    /// attached. A region removed from under a stationary cursor would
+    /// otherwise never see another pointer event and stay hovered forever.
+    /// Exits are reported at each device's last-known position. A cursor a
+    /// removed region contributed is dropped too, and the device's cursor is
+    /// recomputed from what remains of its last hit-test path, emitting a
+    /// cursor change when it differs. Regions that are still mounted keep
+    /// their hover state; entering newly mounted regions needs a hit test and
+    /// is left to [`update_all_devices`](Self::update_all_devices).
+    pub fn annotations_changed(&self, new_annotations: &[MouseTrackerAnnotation]) {
+        let mounted: HashSet<RegionId> = new_annotations
+            .iter()
+            .map(|annotation| annotation.region_id)
+            .collect();
+
+        let pending: Vec<DeviceWork> = {
+            let mut guard = self.inner.borrow_mut();
+            let inner = &mut *guard;
+            let mut pending = Vec::new();
+            for (device_id, state) in &mut inner.devices {
+                let exited: SmallVec<[RegionId; 4]> = state
+                    .active_order
+                    .iter()
+                    .filter(|id| !mounted.contains(id))
+                    .copied()
+                    .collect();
+                state.active_order.retain(|id| mounted.contains(id));
+                state.active_regions.retain(|id| mounted.contains(id));
+                state
+                    .cursor_path
+                    .retain(|(region, _)| region.is_none_or(|id| mounted.contains(&id)));
+                let new_cursor = state.resolve_cursor_path();
+                let cursor_changed = state.current_cursor != new_cursor;
+                state.current_cursor = new_cursor;
+                if exited.is_empty() && !cursor_changed {
+                    continue;
+                }
+
+                let exit_callbacks: SmallVec<[MouseExitCallback; 4]> = exited
+                    .iter()
+                    .filter_map(|id| {
+                        inner
+                            .annotations
+                            .get(id)
+                            .and_then(|ann| ann.cell.snapshot().on_exit)
+                    })
+                    .collect();
+                pending.push(DeviceWork {
+                    device_id: *device_id,
+                    position: state.last_position,
+                    enter_callbacks: SmallVec::new(),
+                    exit_callbacks,
+                    cursor_callback: cursor_changed
+                        .then(|| inner.cursor_change_callback.clone())
+                        .flatten(),
+                    new_cursor,
+                });
+            }
+            inner.annotations.retain(|id, _| mounted.contains(id));
+            pending
+        };
+
+        for work in pending {
+            work.invoke();
+        }
+    }
+
     /// Checks if any mouse is currently connected.
     #[inline]
     #[must_use]
@@ -464,6 +550,23 @@ fn resolve_hit_test_annotations(result: &HitTestResult) -> ResolvedHitAnnotation
     ResolvedHitAnnotations { order, annotations }
 }
 
+/// The non-default cursors along `result`'s path, front first, paired with
+/// the region (if any) of the entry that set each.
+fn cursor_path(result: &HitTestResult) -> SmallVec<[(Option<RegionId>, CursorIcon); 4]> {
+    result
+        .iter()
+        .filter(|entry| entry.cursor != CursorIcon::Default)
+        .map(|entry| {
+            (
+                entry
+                    .mouse_annotation
+                    .map(|annotation| annotation.region_id),
+                entry.cursor,
+            )
+        })
+        .collect()
+}
+
 fn resolve_annotation(
     annotation: MouseTrackerAnnotation,
 ) -> Option<ResolvedMouseTrackerAnnotation> {
@@ -664,6 +767,115 @@ fn enter_exit_callbacks_are_owner_local_and_exit_uses_previous_annotation() {
         );
     }
 
+    #[test]
+    fn removing_region_under_stationary_cursor_synthesizes_exit() {
+        let lane = InteractionLane::try_new().expect("lane");
+        let handle = lane.dispatch_handle();
+        let tracker = MouseTracker::new();
+        let exits = Rc::new(RefCell::new(Vec::new()));
+
+        let (removed_target, kept_target) = lane.enter(|| {
+            let exit_log = Rc::clone(&exits);
+            let removed = handle
+                .register_mouse_region(MouseRegionCallbacks {
+                    on_exit: Some(Rc::new(move |device, position| {
+                        exit_log.borrow_mut().push((device, position));
+                    })),
+                    ..MouseRegionCallbacks::default()
+                })
+                .expect("register removed region");
+            let kept = handle
+                .register_mouse_region(MouseRegionCallbacks::default())
+                .expect("register kept region");
+            (removed, kept)
+        });
+
+        let removed_id = RenderId::new(1);
+        let kept_id = RenderId::new(2);
+        let removed = MouseTrackerAnnotation::new(removed_id, removed_target);
+        let kept = MouseTrackerAnnotation::new(kept_id, kept_target);
+        let position = Offset::new(Pixels(10.0), Pixels(10.0));
+        let event = InputEvent::Pointer(make_move_event(position, PointerType::Mouse));
+        let mut inside = HitTestResult::new();
+        inside.add(HitTestEntry::new(removed_id).mouse_annotation(removed));
+        inside.add(HitTestEntry::new(kept_id).mouse_annotation(kept));
+
+        tracker.update_with_event(&added_event(), &HitTestResult::new());
+        lane.enter(|| tracker.update_with_event(&event, &inside));
+        assert!(exits.borrow().is_empty());
+
+        // The region is unmounted; the cursor never moves.
+        lane.enter(|| tracker.annotations_changed(&[kept]));
+
+        assert_eq!(*exits.borrow(), vec![(0, position)]);
+        let active = tracker.device_active_regions(0);
+        assert!(!active.contains(&removed_id));
+        assert!(active.contains(&kept_id), "mounted regions stay hovered");
+
+        // A repeated notification must not exit twice.
+        lane.enter(|| tracker.annotations_changed(&[kept]));
+        assert_eq!(exits.borrow().len(), 1);
+    }
+
+    #[test]
+    fn removing_region_under_stationary_cursor_recomputes_the_cursor() {
+        let lane = InteractionLane::try_new().expect("lane");
+        let handle = lane.dispatch_handle();
+        let tracker = MouseTracker::new();
+        let cursors = Rc::new(RefCell::new(Vec::new()));
+        let cursor_log = Rc::clone(&cursors);
+        tracker.set_cursor_change_callback(Rc::new(move |device, cursor| {
+            cursor_log.borrow_mut().push((device, cursor));
+        }));
+
+        let (button_target, panel_target) = lane.enter(|| {
+            let register = || {
+                handle
+                    .register_mouse_region(MouseRegionCallbacks::default())
+                    .expect("register mouse region")
+            };
+            (register(), register())
+        });
+        let button = MouseTrackerAnnotation::new(RenderId::new(1), button_target);
+        let panel = MouseTrackerAnnotation::new(RenderId::new(2), panel_target);
+        let position = Offset::new(Pixels(10.0), Pixels(10.0));
+        let event = InputEvent::Pointer(make_move_event(position, PointerType::Mouse));
+        let mut inside = HitTestResult::new();
+        inside.add(
+            HitTestEntry::new(RenderId::new(1))
+                .mouse_annotation(button)
+                .cursor(CursorIcon::Pointer),
+        );
+        inside.add(
+            HitTestEntry::new(RenderId::new(2))
+                .mouse_annotation(panel)
+                .cursor(CursorIcon::Text),
+        );
+
+        tracker.update_with_event(&added_event(), &HitTestResult::new());
+        lane.enter(|| tracker.update_with_event(&event, &inside));
+        assert_eq!(tracker.device_cursor(0), CursorIcon::Pointer);
+
+        // The button unmounts: the panel beneath it now sets the cursor.
+        lane.enter(|| tracker.annotations_changed(&[panel]));
+        assert_eq!(tracker.device_cursor(0), CursorIcon::Text);
+
+        // The panel unmounts too: back to the default cursor.
+        lane.enter(|| tracker.annotations_changed(&[]));
+        assert_eq!(tracker.device_cursor(0), CursorIcon::Default);
+
+        // Unchanged annotations emit nothing further.
+        lane.enter(|| tracker.annotations_changed(&[]));
+        assert_eq!(
+            *cursors.borrow(),
+            vec![
+                (0, CursorIcon::Pointer),
+                (0, CursorIcon::Text),
+                (0, CursorIcon::Default)
+            ]
+        );
+    }
+
     #[test]
     fn active_regions_are_derived_only_from_mouse_annotations() {
         let lane = InteractionLane::try_new().expect("lane");
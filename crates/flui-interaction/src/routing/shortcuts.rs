@@ -0,0 +1,292 @@
+//! Declarative keyboard shortcuts — Flutter's `Shortcuts` / `Actions`.
+//!
+//! A [`ShortcutRegistry`] maps a [`KeyCombination`] (Ctrl+S) to an
+//! [`Intent`] (`SaveIntent`), and an [`ActionMap`] maps each intent type to
+//! the callback that performs it. Keeping the two apart lets a focus scope
+//! rebind keys without touching handler code, and lets the same intent be
+//! raised from a menu or a button.
+//!
+//! Registries hang off [`FocusScopeNode`](super::FocusScopeNode)s; the root
+//! scope's registry acts as the global one. [`FocusManager`](super::FocusManager)
+//! resolves a key event against the scopes enclosing the primary focus,
+//! innermost first, so a scope-local binding overrides a global one.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! #[derive(Debug)]
+//! struct SaveIntent;
+//! impl Intent for SaveIntent {}
+//!
+//! let manager = FocusManager::global();
+//! manager.root_scope().set_shortcut(
+//!     KeyCombination::new(Key::Character("s".into()), Modifiers::CONTROL),
+//!     SaveIntent,
+//! );
+//! manager.register_action(|_: &SaveIntent| {
+//!     document.save();
+//!     true
+//! });
+//! ```
+//!
+//! Flutter reference: <https://api.flutter.dev/flutter/widgets/Shortcuts-class.html>
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt,
+    rc::Rc,
+};
+
+use crate::events::{Key, KeyEvent, KeyState, Modifiers};
+
+/// Modifiers that take part in shortcut matching.
+///
+/// Lock states (Caps Lock, Num Lock, ...) never block a shortcut.
+const SHORTCUT_MODIFIERS: Modifiers = Modifiers::CONTROL
+    .union(Modifiers::SHIFT)
+    .union(Modifiers::ALT)
+    .union(Modifiers::META);
+
+/// A key plus the exact set of modifiers that must be held — Flutter's
+/// `SingleActivator`.
+///
+/// Character keys match case-insensitively, so Ctrl+Shift+S matches whether
+/// the platform reports `"s"` or `"S"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyCombination {
+    key: Key,
+    modifiers: Modifiers,
+}
+
+impl KeyCombination {
+    /// Creates a combination of `key` and exactly `modifiers`.
+    #[must_use]
+    pub fn new(key: Key, modifiers: Modifiers) -> Self {
+        Self {
+            key: normalize_key(key),
+            modifiers: modifiers & SHORTCUT_MODIFIERS,
+        }
+    }
+
+    /// Creates a combination for `key` with no modifiers.
+    #[must_use]
+    pub fn key(key: Key) -> Self {
+        Self::new(key, Modifiers::empty())
+    }
+
+    /// The combination a key event would trigger, or `None` for key releases.
+    #[must_use]
+    pub fn from_event(event: &KeyEvent) -> Option<Self> {
+        (event.state == KeyState::Down).then(|| Self::new(event.key.clone(), event.modifiers))
+    }
+
+    /// Returns the logical key.
+    #[must_use]
+    pub fn logical_key(&self) -> &Key {
+        &self.key
+    }
+
+    /// Returns the required modifiers.
+    #[must_use]
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// Whether `event` triggers this combination.
+    #[must_use]
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        Self::from_event(event).is_some_and(|combination| combination == *self)
+    }
+}
+
+fn normalize_key(key: Key) -> Key {
+    if let Key::Character(text) = key {
+        Key::Character(text.to_lowercase())
+    } else {
+        key
+    }
+}
+
+/// A type-erased description of something the user wants to do — Flutter's
+/// `Intent`.
+///
+/// Intents are plain data; what they *do* is decided by the [`ActionMap`]
+/// entry registered for their concrete type.
+pub trait Intent: Any + fmt::Debug {}
+
+/// Maps key combinations to intents for one focus scope.
+#[derive(Default, Clone)]
+pub struct ShortcutRegistry {
+    bindings: HashMap<KeyCombination, Rc<dyn Intent>>,
+}
+
+impl fmt::Debug for ShortcutRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.bindings.iter()).finish()
+    }
+}
+
+impl ShortcutRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds `combination` to `intent`, returning the intent it replaced.
+    pub fn register(
+        &mut self,
+        combination: KeyCombination,
+        intent: impl Intent,
+    ) -> Option<Rc<dyn Intent>> {
+        self.bindings.insert(combination, Rc::new(intent))
+    }
+
+    /// Removes the binding for `combination`.
+    pub fn unregister(&mut self, combination: &KeyCombination) -> Option<Rc<dyn Intent>> {
+        self.bindings.remove(combination)
+    }
+
+    /// The intent bound to the combination `event` triggers, if any.
+    #[must_use]
+    pub fn intent_for(&self, event: &KeyEvent) -> Option<Rc<dyn Intent>> {
+        let combination = KeyCombination::from_event(event)?;
+        self.bindings.get(&combination).cloned()
+    }
+
+    /// Returns the number of bindings.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// Returns `true` if there are no bindings.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+}
+
+/// Owner-local callback performing an intent.
+///
+/// Returns `true` if the intent was handled; `false` lets the key event keep
+/// propagating (Flutter's disabled action).
+pub type ActionCallback = Rc<dyn Fn(&dyn Intent) -> bool>;
+
+/// Maps intent types to the callbacks that perform them — Flutter's
+/// `Actions`.
+#[derive(Default, Clone)]
+pub struct ActionMap {
+    actions: HashMap<TypeId, ActionCallback>,
+}
+
+impl fmt::Debug for ActionMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ActionMap")
+            .field("len", &self.actions.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ActionMap {
+    /// Creates an empty action map.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the action for intents of type `I`, replacing any previous
+    /// one.
+    pub fn register<I: Intent>(&mut self, action: impl Fn(&I) -> bool + 'static) {
+        let erased: ActionCallback = Rc::new(move |intent: &dyn Intent| {
+            (intent as &dyn Any)
+                .downcast_ref::<I>()
+                .is_some_and(&action)
+        });
+        self.actions.insert(TypeId::of::<I>(), erased);
+    }
+
+    /// Removes the action for intents of type `I`.
+    pub fn unregister<I: Intent>(&mut self) {
+        self.actions.remove(&TypeId::of::<I>());
+    }
+
+    /// The action registered for `intent`'s concrete type.
+    #[must_use]
+    pub fn action_for(&self, intent: &dyn Intent) -> Option<ActionCallback> {
+        self.actions.get(&(intent as &dyn Any).type_id()).cloned()
+    }
+
+    /// Performs `intent`.
+    ///
+    /// Returns `true` only if an action was registered and handled it.
+    pub fn invoke(&self, intent: &dyn Intent) -> bool {
+        self.action_for(intent).is_some_and(|action| action(intent))
+    }
+
+    /// Returns the number of registered actions.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    /// Returns `true` if no actions are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{events::keyboard::Code, testing::input::KeyEventBuilder};
+
+    #[derive(Debug)]
+    struct SaveIntent;
+    impl Intent for SaveIntent {}
+
+    fn ctrl_s(key: &str, modifiers: Modifiers) -> KeyEvent {
+        KeyEventBuilder::new(Code::KeyS)
+            .with_key(Key::Character(key.into()))
+            .with_modifiers(modifiers)
+            .build()
+    }
+
+    #[test]
+    fn combination_requires_exact_modifiers_and_ignores_locks() {
+        let combination = KeyCombination::new(Key::Character("s".into()), Modifiers::CONTROL);
+
+        assert!(combination.matches(&ctrl_s("s", Modifiers::CONTROL)));
+        assert!(combination.matches(&ctrl_s("S", Modifiers::CONTROL | Modifiers::CAPS_LOCK)));
+        assert!(!combination.matches(&ctrl_s("s", Modifiers::CONTROL | Modifiers::SHIFT)));
+        assert!(!combination.matches(&ctrl_s("s", Modifiers::empty())));
+
+        let released = KeyEventBuilder::new(Code::KeyS)
+            .with_key(Key::Character("s".into()))
+            .with_modifiers(Modifiers::CONTROL)
+            .with_state(KeyState::Up)
+            .build();
+        assert!(!combination.matches(&released));
+    }
+
+    #[test]
+    fn action_map_dispatches_by_intent_type() {
+        #[derive(Debug)]
+        struct OtherIntent;
+        impl Intent for OtherIntent {}
+
+        let mut actions = ActionMap::new();
+        let saves = Rc::new(std::cell::Cell::new(0));
+        let counter = Rc::clone(&saves);
+        actions.register(move |_: &SaveIntent| {
+            counter.set(counter.get() + 1);
+            true
+        });
+
+        assert!(actions.invoke(&SaveIntent));
+        assert!(!actions.invoke(&OtherIntent), "no action for this intent");
+        assert_eq!(saves.get(), 1);
+    }
+}
@@ -90,6 +90,15 @@ pub trait CustomGestureRecognizer {
     /// Another recognizer won, or this recognizer explicitly rejected.
     /// Clean up any state and prepare for the next gesture.
     fn on_arena_reject(&self, pointer: PointerId);
+
+    /// Minimum disambiguation window this recognizer needs before the arena
+    /// may force-resolve on timeout.
+    ///
+    /// See [`GestureArenaMember::arena_timeout`](crate::arena::GestureArenaMember::arena_timeout).
+    /// Defaults to `None` (no requirement).
+    fn arena_timeout(&self) -> Option<std::time::Duration> {
+        None
+    }
 }
 
 /// Extension trait for custom hit-testable types.
@@ -195,6 +204,8 @@ impl Sealed for crate::recognizers::TapGestureRecognizer {}
     impl Sealed for crate::recognizers::DoubleTapGestureRecognizer {}
     impl Sealed for crate::recognizers::LongPressGestureRecognizer {}
     impl Sealed for crate::recognizers::DragGestureRecognizer {}
+    impl Sealed for crate::recognizers::drag_variants::PanGestureRecognizer {}
+    impl Sealed for crate::recognizers::RotationGestureRecognizer {}
     impl Sealed for crate::recognizers::ScaleGestureRecognizer {}
     impl Sealed for crate::recognizers::MultiTapGestureRecognizer {}
     impl Sealed for crate::recognizers::ForcePressGestureRecognizer {}
@@ -221,6 +232,8 @@ impl Sealed for crate::recognizers::TapGestureRecognizer {}
     impl Sealed for crate::recognizers::DoubleTapGestureRecognizer {}
     impl Sealed for crate::recognizers::LongPressGestureRecognizer {}
     impl Sealed for crate::recognizers::DragGestureRecognizer {}
+    impl Sealed for crate::recognizers::drag_variants::PanGestureRecognizer {}
+    impl Sealed for crate::recognizers::RotationGestureRecognizer {}
     impl Sealed for crate::recognizers::ScaleGestureRecognizer {}
     impl Sealed for crate::recognizers::MultiTapGestureRecognizer {}
     impl Sealed for crate::recognizers::ForcePressGestureRecognizer {}
@@ -59,6 +59,10 @@
 /// Default scale slop (minimum scale factor change to start scaling).
 pub const DEFAULT_SCALE_SLOP: f32 = 0.05;
 
+/// Default rotation slop (minimum accumulated rotation, in radians, to start
+/// a rotation gesture; about 3 degrees).
+pub const DEFAULT_ROTATION_SLOP: f32 = 0.05;
+
 /// Default double-tap distance tolerance (100 logical pixels).
 pub const DEFAULT_DOUBLE_TAP_SLOP: f32 = 100.0;
 
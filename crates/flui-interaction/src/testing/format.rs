@@ -0,0 +1,380 @@
+//! File format for gesture recordings
+//!
+//! [`GestureRecording::to_bytes`] / [`GestureRecording::from_bytes`] encode a
+//! recording as JSON so it can be saved next to an integration test or
+//! attached to a bug report and replayed later with a
+//! [`GesturePlayer`](super::GesturePlayer).
+//!
+//! The file starts with a magic tag and a format version; the payload mirrors
+//! [`RecordedEvent`] field by field with plain integers. Floats are stored as
+//! their `f32` bit patterns, so positions, pressure and tilt round-trip
+//! exactly along with timestamps (nanosecond [`Duration`]s), event types,
+//! device kinds and modifiers.
+
+use std::time::Duration;
+
+use flui_types::{Offset, geometry::Pixels};
+use serde::{Deserialize, Serialize};
+
+use super::recording::{GestureRecording, RecordedEvent, RecordedEventType};
+use crate::{
+    events::{Modifiers, PointerType},
+    ids::PointerId,
+};
+
+/// Magic tag at the start of every recording file.
+const MAGIC: [u8; 4] = *b"FLGR";
+
+/// Current format version.
+const FORMAT_VERSION: u16 = 1;
+
+/// Why a recording could not be encoded or decoded.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum RecordingFormatError {
+    /// The bytes do not start with the recording magic tag.
+    #[error("not a gesture recording")]
+    BadMagic,
+    /// The recording was written by a newer, unknown format version.
+    #[error("unsupported gesture recording version {0}")]
+    UnsupportedVersion(u16),
+    /// A recorded event carries pointer id 0.
+    #[error("recorded pointer id must be nonzero")]
+    InvalidPointer,
+    /// A recorded event carries an unknown event type tag.
+    #[error("unknown recorded event type {0}")]
+    UnknownEventType(u8),
+    /// The payload could not be encoded or decoded.
+    #[error("gesture recording codec error: {0}")]
+    Codec(#[from] serde_json::Error),
+}
+
+#[derive(Serialize, Deserialize)]
+struct Header {
+    magic: [u8; 4],
+    version: u16,
+}
+
+/// Just the header of a [`RecordingFile`]; the rest of the payload is
+/// ignored.
+#[derive(Deserialize)]
+struct HeaderProbe {
+    header: Header,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RecordingFile {
+    header: Header,
+    name: String,
+    duration: Duration,
+    events: Vec<EventRecord>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EventRecord {
+    time_offset: Duration,
+    pointer: u64,
+    event_type: u8,
+    /// `f32` bit patterns, see [`f32::to_bits`].
+    x: u32,
+    y: u32,
+    device_kind: u8,
+    pressure: Option<u32>,
+    tilt_x: Option<u32>,
+    tilt_y: Option<u32>,
+    rotation: Option<u32>,
+    modifiers: u32,
+}
+
+impl RecordedEventType {
+    fn tag(self) -> u8 {
+        match self {
+            Self::Down => 0,
+            Self::Move => 1,
+            Self::Up => 2,
+            Self::Cancel => 3,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Result<Self, RecordingFormatError> {
+        match tag {
+            0 => Ok(Self::Down),
+            1 => Ok(Self::Move),
+            2 => Ok(Self::Up),
+            3 => Ok(Self::Cancel),
+            other => Err(RecordingFormatError::UnknownEventType(other)),
+        }
+    }
+}
+
+fn device_tag(kind: PointerType) -> u8 {
+    match kind {
+        PointerType::Mouse => 1,
+        PointerType::Pen => 2,
+        PointerType::Touch => 3,
+        _ => 0,
+    }
+}
+
+fn device_from_tag(tag: u8) -> PointerType {
+    // Unknown tags (a device kind added after this file was written) decode
+    // as `Unknown` rather than failing the whole recording.
+    match tag {
+        1 => PointerType::Mouse,
+        2 => PointerType::Pen,
+        3 => PointerType::Touch,
+        _ => PointerType::Unknown,
+    }
+}
+
+impl From<&RecordedEvent> for EventRecord {
+    fn from(event: &RecordedEvent) -> Self {
+        Self {
+            time_offset: event.time_offset,
+            pointer: event.pointer.get_inner().get(),
+            event_type: event.event_type.tag(),
+            x: event.position.dx.0.to_bits(),
+            y: event.position.dy.0.to_bits(),
+            device_kind: device_tag(event.device_kind),
+            pressure: event.pressure.map(f32::to_bits),
+            tilt_x: event.tilt_x.map(f32::to_bits),
+            tilt_y: event.tilt_y.map(f32::to_bits),
+            rotation: event.rotation.map(f32::to_bits),
+            modifiers: event.modifiers.bits(),
+        }
+    }
+}
+
+impl TryFrom<EventRecord> for RecordedEvent {
+    type Error = RecordingFormatError;
+
+    fn try_from(record: EventRecord) -> Result<Self, Self::Error> {
+        let pointer = PointerId::new(record.pointer).ok_or(RecordingFormatError::InvalidPointer)?;
+        let mut event = RecordedEvent::new(
+            record.time_offset,
+            pointer,
+            RecordedEventType::from_tag(record.event_type)?,
+            Offset::new(
+                Pixels(f32::from_bits(record.x)),
+                Pixels(f32::from_bits(record.y)),
+            ),
+        )
+        .with_device_kind(device_from_tag(record.device_kind))
+        .with_modifiers(Modifiers::from_bits_retain(record.modifiers));
+        event.pressure = record.pressure.map(f32::from_bits);
+        event.tilt_x = record.tilt_x.map(f32::from_bits);
+        event.tilt_y = record.tilt_y.map(f32::from_bits);
+        event.rotation = record.rotation.map(f32::from_bits);
+        Ok(event)
+    }
+}
+
+impl GestureRecording {
+    /// Encode the recording into the versioned file format.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, RecordingFormatError> {
+        let file = RecordingFile {
+            header: Header {
+                magic: MAGIC,
+                version: FORMAT_VERSION,
+            },
+            name: self.name.clone(),
+            duration: self.duration,
+            events: self.events.iter().map(EventRecord::from).collect(),
+        };
+        Ok(serde_json::to_vec(&file)?)
+    }
+
+    /// Decode a recording written by [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, RecordingFormatError> {
+        // The header is decoded on its own first so a future layout change
+        // reports a version mismatch instead of a codec error.
+        let HeaderProbe { header } =
+            serde_json::from_slice(bytes).map_err(|_| RecordingFormatError::BadMagic)?;
+        if header.magic != MAGIC {
+            return Err(RecordingFormatError::BadMagic);
+        }
+        if header.version != FORMAT_VERSION {
+            return Err(RecordingFormatError::UnsupportedVersion(header.version));
+        }
+
+        let file: RecordingFile = serde_json::from_slice(bytes)?;
+        Ok(Self {
+            name: file.name,
+            duration: file.duration,
+            events: file
+                .events
+                .into_iter()
+                .map(RecordedEvent::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+    use crate::{
+        arena::GestureArena,
+        events::PointerEvent,
+        recognizers::{DragGestureRecognizer, GestureRecognizer, TapGestureRecognizer},
+        testing::GesturePlayer,
+        traits::DragAxis,
+    };
+
+    fn at(x: f32, y: f32) -> Offset<Pixels> {
+        Offset::new(Pixels(x), Pixels(y))
+    }
+
+    /// A drag followed by a shift-held pen tap, with frame-rate timestamps
+    /// that are not whole milliseconds.
+    fn drag_and_tap() -> GestureRecording {
+        let frame = Duration::from_nanos(16_666_667);
+        let pointer = PointerId::PRIMARY;
+        let mut recording = GestureRecording::with_name("drag_and_tap");
+
+        recording.push(RecordedEvent::new(
+            Duration::ZERO,
+            pointer,
+            RecordedEventType::Down,
+            at(10.0, 10.0),
+        ));
+        for i in 1..=6_u16 {
+            recording.push(RecordedEvent::new(
+                frame * u32::from(i),
+                pointer,
+                RecordedEventType::Move,
+                at(10.0 + 15.0 * f32::from(i), 10.0),
+            ));
+        }
+        recording.push(RecordedEvent::new(
+            frame * 7,
+            pointer,
+            RecordedEventType::Up,
+            at(100.0, 10.0),
+        ));
+
+        for (offset, event_type) in [(20, RecordedEventType::Down), (23, RecordedEventType::Up)] {
+            recording.push(
+                RecordedEvent::new(frame * offset, pointer, event_type, at(200.0, 50.0))
+                    .with_device_kind(PointerType::Pen)
+                    .with_pressure(0.4)
+                    .with_tilt(0.1, -0.2)
+                    .with_modifiers(Modifiers::SHIFT),
+            );
+        }
+        recording
+    }
+
+    /// Replay `player` into a fresh drag + tap pair and log every callback.
+    fn replay(player: GesturePlayer) -> Vec<String> {
+        let arena = GestureArena::new();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2, l3, l4) = (log.clone(), log.clone(), log.clone(), log.clone());
+        let drag = DragGestureRecognizer::new(arena.clone(), DragAxis::Free)
+            .with_on_start(move |d| {
+                l1.borrow_mut()
+                    .push(format!("drag start {:?}", d.global_position));
+            })
+            .with_on_update(move |d| {
+                l2.borrow_mut()
+                    .push(format!("drag update {:?}", d.global_position));
+            })
+            .with_on_end(move |d| {
+                l3.borrow_mut()
+                    .push(format!("drag end {:?}", d.global_position));
+            });
+        let tap = TapGestureRecognizer::new(arena.clone())
+            .with_on_tap(move |d| l4.borrow_mut().push(format!("tap {:?}", d.global_position)));
+
+        for event in player {
+            let pointer = crate::events::extract_pointer_id(&event);
+            if let PointerEvent::Down(data) = &event {
+                let pos = data.state.position;
+                let position = at(pos.x as f32, pos.y as f32);
+                tap.add_pointer(pointer, position);
+                drag.add_pointer(pointer, position);
+            }
+            drag.handle_event(&event);
+            tap.handle_event(&event);
+            // The binding closes the arena after dispatching the down and
+            // sweeps it after the up.
+            match event {
+                PointerEvent::Down(_) => arena.close(pointer),
+                PointerEvent::Up(_) => arena.sweep(pointer),
+                _ => {}
+            }
+        }
+        log.take()
+    }
+
+    #[test]
+    fn recording_round_trips_exactly() {
+        let recording = drag_and_tap();
+        let bytes = recording.to_bytes().expect("encode");
+        let decoded = GestureRecording::from_bytes(&bytes).expect("decode");
+
+        assert_eq!(decoded.name, recording.name);
+        assert_eq!(decoded.duration, recording.duration);
+        assert_eq!(decoded.len(), recording.len());
+        for (a, b) in decoded.iter().zip(recording.iter()) {
+            assert_eq!(a.time_offset, b.time_offset);
+            assert_eq!(a.pointer, b.pointer);
+            assert_eq!(a.event_type, b.event_type);
+            assert_eq!(a.position, b.position);
+            assert_eq!(a.device_kind, b.device_kind);
+            assert_eq!(a.pressure, b.pressure);
+            assert_eq!((a.tilt_x, a.tilt_y), (b.tilt_x, b.tilt_y));
+            assert_eq!(a.modifiers, b.modifiers);
+        }
+    }
+
+    #[test]
+    fn decoded_recording_replays_identically() {
+        let recording = drag_and_tap();
+        let decoded =
+            GestureRecording::from_bytes(&recording.to_bytes().expect("encode")).expect("decode");
+
+        let original = replay(GesturePlayer::new(recording));
+        let replayed = replay(GesturePlayer::new(decoded));
+
+        assert!(original.iter().any(|line| line.starts_with("drag end")));
+        assert!(original.iter().any(|line| line.starts_with("tap")));
+        assert_eq!(original, replayed);
+    }
+
+    #[test]
+    fn replayed_events_carry_modifiers() {
+        let recording = drag_and_tap();
+        let tap_down = GesturePlayer::new(recording)
+            .nth(8)
+            .expect("tap down event");
+        match tap_down {
+            PointerEvent::Down(data) => assert_eq!(data.state.modifiers, Modifiers::SHIFT),
+            other => panic!("expected tap down, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn rejects_foreign_bytes_and_future_versions() {
+        assert!(matches!(
+            GestureRecording::from_bytes(b"not a recording"),
+            Err(RecordingFormatError::BadMagic)
+        ));
+
+        let encoded = String::from_utf8(drag_and_tap().to_bytes().expect("encode"))
+            .expect("recordings are JSON");
+        let future = encoded.replacen(
+            &format!("\"version\":{FORMAT_VERSION}"),
+            "\"version\":99",
+            1,
+        );
+        assert_ne!(future, encoded);
+        let bytes = future.into_bytes();
+        assert!(matches!(
+            GestureRecording::from_bytes(&bytes),
+            Err(RecordingFormatError::UnsupportedVersion(99))
+        ));
+    }
+}
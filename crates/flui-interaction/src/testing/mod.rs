@@ -4,10 +4,14 @@
 //! handling:
 //!
 //! - [`GestureRecorder`] - Record pointer event sequences
-//! - [`GesturePlayer`] - Replay recorded gestures
+//! - [`GesturePlayer`] - Replay recorded gestures, immediately or in real time
 //! - [`GestureBuilder`] - Pre-built gesture patterns (tap, drag, pinch, etc.)
 //! - [`input`] - Builders for creating test events
 //!
+//! With the `serde` feature, [`GestureRecording::to_bytes`] and
+//! [`GestureRecording::from_bytes`] save recordings to disk for replay in
+//! integration tests or bug reports.
+//!
 //! # Example
 //!
 //! ```rust,ignore
@@ -23,12 +27,16 @@
 //! }
 //! ```
 
+#[cfg(feature = "serde")]
+mod format;
 pub mod input;
 mod recording;
 
 // Re-export input builders
+#[cfg(feature = "serde")]
+pub use format::RecordingFormatError;
 pub use input::ModifiersBuilder;
 pub use recording::{
-    GestureBuilder, GesturePlayer, GestureRecorder, GestureRecording, RecordedEvent,
+    GestureBuilder, GesturePlayer, GestureRecorder, GestureRecording, PlaybackMode, RecordedEvent,
     RecordedEventType,
 };
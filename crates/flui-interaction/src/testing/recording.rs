@@ -15,8 +15,9 @@
 //! recorder.record_move(PointerId::PRIMARY, Offset::new(Pixels(150.0), Pixels(100.0)));
 //! recorder.record_up(PointerId::PRIMARY, Offset::new(Pixels(200.0), Pixels(100.0)));
 //!
-//! // Save/export the recording
+//! // Save/export the recording (`serde` feature)
 //! let recording = recorder.finish();
+//! std::fs::write("drag.gesture", recording.to_bytes()?)?;
 //!
 //! // Replay the gesture
 //! let player = GesturePlayer::new(recording);
@@ -31,7 +32,7 @@
 use ui_events::pointer::{PointerButton, PointerButtons};
 
 use crate::{
-    events::{PointerEvent, PointerEventData, PointerType},
+    events::{Modifiers, PointerEvent, PointerEventData, PointerType},
     ids::PointerId,
 };
 
@@ -56,6 +57,8 @@ pub struct RecordedEvent {
     pub tilt_y: Option<f32>,
     /// Rotation (if available)
     pub rotation: Option<f32>,
+    /// Keyboard modifiers held during the event
+    pub modifiers: Modifiers,
 }
 
 /// Type of recorded event
@@ -89,6 +92,7 @@ pub fn new(
             tilt_x: None,
             tilt_y: None,
             rotation: None,
+            modifiers: Modifiers::empty(),
         }
     }
 
@@ -117,6 +121,12 @@ pub fn with_rotation(mut self, rotation: f32) -> Self {
         self
     }
 
+    /// Set keyboard modifiers
+    pub fn with_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
     /// Convert to a PointerEventData (for internal use)
     fn to_pointer_event_data(&self) -> PointerEventData {
         let pressure = self.pressure.unwrap_or(1.0);
@@ -146,7 +156,13 @@ fn to_pointer_event_data(&self) -> PointerEventData {
     /// Convert to a PointerEvent
     pub fn to_pointer_event(&self) -> PointerEvent {
         let data = self.to_pointer_event_data();
-        crate::events::make_pointer_event(self.event_type.into(), data)
+        let mut event = crate::events::make_pointer_event(self.event_type.into(), data);
+        match &mut event {
+            PointerEvent::Down(e) | PointerEvent::Up(e) => e.state.modifiers = self.modifiers,
+            PointerEvent::Move(e) => e.current.modifiers = self.modifiers,
+            _ => {}
+        }
+        event
     }
 }
 
@@ -294,7 +310,7 @@ pub fn record_event(&mut self, event: &PointerEvent) {
         let time_offset = self.time_offset();
 
         // Extract event info using pattern matching
-        let (event_type, position, device_kind, pressure) = match event {
+        let (event_type, position, device_kind, pressure, modifiers) = match event {
             PointerEvent::Down(data) => {
                 let pos = data.state.position;
                 (
@@ -302,6 +318,7 @@ pub fn record_event(&mut self, event: &PointerEvent) {
                     Offset::new(Pixels(pos.x as f32), Pixels(pos.y as f32)),
                     data.pointer.pointer_type,
                     Some(data.state.pressure),
+                    data.state.modifiers,
                 )
             }
             PointerEvent::Up(data) => {
@@ -311,6 +328,7 @@ pub fn record_event(&mut self, event: &PointerEvent) {
                     Offset::new(Pixels(pos.x as f32), Pixels(pos.y as f32)),
                     data.pointer.pointer_type,
                     Some(data.state.pressure),
+                    data.state.modifiers,
                 )
             }
             PointerEvent::Move(data) => {
@@ -320,6 +338,7 @@ pub fn record_event(&mut self, event: &PointerEvent) {
                     Offset::new(Pixels(pos.x as f32), Pixels(pos.y as f32)),
                     data.pointer.pointer_type,
                     Some(data.current.pressure),
+                    data.current.modifiers,
                 )
             }
             PointerEvent::Cancel(info) => (
@@ -327,6 +346,7 @@ pub fn record_event(&mut self, event: &PointerEvent) {
                 Offset::ZERO,
                 info.pointer_type,
                 None,
+                Modifiers::empty(),
             ),
             _ => return, // Skip Enter, Leave, Scroll, Gesture events
         };
@@ -337,7 +357,8 @@ pub fn record_event(&mut self, event: &PointerEvent) {
             event_type,
             position,
         )
-        .with_device_kind(device_kind);
+        .with_device_kind(device_kind)
+        .with_modifiers(modifiers);
 
         if let Some(p) = pressure {
             recorded = recorded.with_pressure(p);
@@ -366,6 +387,17 @@ fn default() -> Self {
     }
 }
 
+/// How a [`GesturePlayer`] paces the events it yields
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlaybackMode {
+    /// Yield every event immediately (deterministic tests)
+    #[default]
+    AsFastAsPossible,
+    /// Block before each event until its recorded time offset has elapsed
+    /// since the first event was yielded (reproducing timing-sensitive bugs)
+    RealTime,
+}
+
 /// Plays back a recorded gesture
 #[derive(Debug, Clone)]
 pub struct GesturePlayer {
@@ -373,6 +405,11 @@ pub struct GesturePlayer {
     recording: GestureRecording,
     /// Current index in the recording
     current_index: usize,
+    /// Pacing of yielded events
+    mode: PlaybackMode,
+    /// Wall-clock time and recorded offset of the first event yielded
+    /// (real-time playback)
+    origin: Option<(Instant, Duration)>,
 }
 
 impl GesturePlayer {
@@ -381,12 +418,27 @@ pub fn new(recording: GestureRecording) -> Self {
         Self {
             recording,
             current_index: 0,
+            mode: PlaybackMode::default(),
+            origin: None,
         }
     }
 
+    /// Set how events are paced
+    #[must_use]
+    pub fn with_playback_mode(mut self, mode: PlaybackMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Get the playback mode
+    pub fn playback_mode(&self) -> PlaybackMode {
+        self.mode
+    }
+
     /// Reset the player to the beginning
     pub fn reset(&mut self) {
         self.current_index = 0;
+        self.origin = None;
     }
 
     /// Get the next event without advancing
@@ -404,7 +456,20 @@ pub fn next_event(&mut self) -> Option<&RecordedEvent> {
     }
 
     /// Get the next PointerEvent and advance
+    ///
+    /// In [`PlaybackMode::RealTime`] this sleeps until the event is due.
     pub fn next_pointer_event(&mut self) -> Option<PointerEvent> {
+        if self.mode == PlaybackMode::RealTime
+            && let Some(offset) = self.peek().map(|event| event.time_offset)
+        {
+            let (started_at, first_offset) =
+                *self.origin.get_or_insert_with(|| (Instant::now(), offset));
+            let due = started_at + offset.saturating_sub(first_offset);
+            let remaining = due.saturating_duration_since(Instant::now());
+            if !remaining.is_zero() {
+                std::thread::sleep(remaining);
+            }
+        }
         self.next_event().map(RecordedEvent::to_pointer_event)
     }
 
@@ -839,4 +904,29 @@ fn test_swipe_builder() {
         assert_eq!(recording.len(), 7); // 1 down + 5 moves + 1 up
         assert_eq!(recording.name, "swipe");
     }
+
+    #[test]
+    fn real_time_playback_waits_for_recorded_offsets() {
+        let mut recording = GestureRecording::new();
+        recording.push(RecordedEvent::new(
+            Duration::from_millis(5),
+            PointerId::PRIMARY,
+            RecordedEventType::Down,
+            Offset::new(Pixels(0.0), Pixels(0.0)),
+        ));
+        recording.push(RecordedEvent::new(
+            Duration::from_millis(25),
+            PointerId::PRIMARY,
+            RecordedEventType::Up,
+            Offset::new(Pixels(0.0), Pixels(0.0)),
+        ));
+
+        let started = Instant::now();
+        let events: Vec<_> = GesturePlayer::new(recording)
+            .with_playback_mode(PlaybackMode::RealTime)
+            .collect();
+
+        assert_eq!(events.len(), 2);
+        assert!(started.elapsed() >= Duration::from_millis(20));
+    }
 }
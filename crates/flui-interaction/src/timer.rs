@@ -269,7 +269,16 @@ pub fn schedule_at<F>(&self, deadline: Instant, callback: F) -> GestureTimer
     ///
     /// Returns the number of timers that fired.
     pub fn check_timers(&self) -> usize {
-        let now = Instant::now();
+        self.check_timers_at(Instant::now())
+    }
+
+    /// Check and fire any timers whose deadline is at or before `now`.
+    ///
+    /// Lets a driver with its own time source (e.g. a headless binding's
+    /// `ManualClock`) fire timers deterministically, without sleeping.
+    ///
+    /// Returns the number of timers that fired.
+    pub fn check_timers_at(&self, now: Instant) -> usize {
         let mut fired_count = 0;
 
         // Collect ready timers (must release lock before calling callbacks)
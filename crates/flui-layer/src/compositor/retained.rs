@@ -6,6 +6,7 @@
 
 use flui_foundation::LayerId;
 
+use crate::texture_registry::TextureFrameRegistry;
 use crate::tree::LayerTree;
 
 // ============================================================================
@@ -23,6 +24,9 @@ pub struct SceneCompositor {
 
     /// Statistics for debugging
     stats: CompositorStats,
+
+    /// Frame-available signals from external texture producers
+    texture_frames: TextureFrameRegistry,
 }
 
 /// Statistics about compositor operations.
@@ -76,6 +80,25 @@ pub fn is_retained(&self, layer_id: LayerId) -> bool {
         self.retained.contains(&layer_id)
     }
 
+    /// Returns the registry external texture producers signal new frames
+    /// through.
+    pub fn texture_frames(&self) -> &TextureFrameRegistry {
+        &self.texture_frames
+    }
+
+    /// Mutable access to the texture frame registry, for registering
+    /// `on_frame_available` callbacks.
+    pub fn texture_frames_mut(&mut self) -> &mut TextureFrameRegistry {
+        &mut self.texture_frames
+    }
+
+    /// Marks the texture layers whose producers signaled a new frame since
+    /// the last call; only those layers (and their ancestors) are re-pushed
+    /// instead of recomposing the scene. Call before building the frame.
+    pub fn flush_texture_frames(&mut self, tree: &LayerTree) -> usize {
+        self.texture_frames.flush(tree)
+    }
+
     /// Updates statistics after frame composition.
     pub fn update_stats(&mut self, tree: &LayerTree) {
         self.stats.total_layers = tree.len();
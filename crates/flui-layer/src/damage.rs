@@ -11,8 +11,16 @@
 //! keeping damage tight — a single full-screen union wastes GPU work on
 //! unchanged regions.
 
+use std::collections::HashMap;
+use std::mem::{Discriminant, discriminant};
+
+use flui_foundation::LayerId;
+use flui_types::Matrix4;
 use flui_types::geometry::{Pixels, Rect};
 
+use crate::layer::Layer;
+use crate::tree::LayerTree;
+
 /// Maximum number of damage rects before merging. Slint uses 3 — enough
 /// for typical UI patterns (toolbar + content + sidebar) without blowing
 /// up scissor passes.
@@ -173,6 +181,14 @@ pub fn region_count(&self) -> usize {
         self.count
     }
 
+    /// Marks every rect of [`LayerTree::damage_region`] between `current`
+    /// and `previous` as dirty.
+    pub fn mark_layer_tree_damage(&mut self, current: &LayerTree, previous: &LayerTree) {
+        for rect in current.damage_region(previous) {
+            self.mark_dirty(rect);
+        }
+    }
+
     /// Computes the area of a rect.
     #[inline]
     fn area(rect: &Rect<Pixels>) -> f32 {
@@ -187,6 +203,230 @@ fn default() -> Self {
     }
 }
 
+// ============================================================================
+// LAYER TREE DIFF
+// ============================================================================
+
+impl LayerTree {
+    /// Returns the screen regions that differ between `previous` and this
+    /// tree, merged so no two returned rects overlap.
+    ///
+    /// Layers are matched by [`LayerId`] across the two trees; only layers
+    /// reachable from each tree's root take part. A layer contributes damage
+    /// when it was removed (its old bounds), inserted (its new bounds), or
+    /// kept but changed kind or global bounds (both). A kept layer that moved
+    /// to another parent or child index, or whose parameters changed (offset,
+    /// transform, alpha, clip shape, filter…, or a `Picture` / `Canvas`
+    /// re-recorded with a new display list), damages the old and new bounds
+    /// of its whole subtree — so a picture lifted out of an `OpacityLayer`,
+    /// or two siblings swapping z-order over the same pixels, repaint.
+    ///
+    /// Bounds are taken in root coordinates, through every ancestor
+    /// `Offset` / `Transform` / `Opacity` / `ImageFilter` translation, and
+    /// are cached per tree until its next mutation, so each frame's bounds
+    /// are walked once even though the frame is diffed twice (as current,
+    /// then as previous). Layers without comparable parameters
+    /// (`ShaderMask`, `BackdropFilter`, `PerformanceOverlay`) are compared by
+    /// bounds only — a change that keeps their bounds still has to be
+    /// reported through [`DamageTracker::mark_dirty`].
+    #[must_use]
+    pub fn damage_region(&self, previous: &LayerTree) -> Vec<Rect<Pixels>> {
+        let old = previous.layer_bounds();
+        let new = self.layer_bounds();
+        let mut damage = Vec::new();
+
+        for entry in &old.entries {
+            let Some(current) = new.get(entry.id) else {
+                damage.extend(entry.own);
+                continue;
+            };
+            if entry.kind != current.kind || entry.own != current.own {
+                damage.extend(entry.own);
+                damage.extend(current.own);
+            }
+            let moved = entry.parent != current.parent || entry.index != current.index;
+            let changed = match (previous.get_layer(entry.id), self.get_layer(entry.id)) {
+                (Some(before), Some(after)) => !same_parameters(before, after),
+                _ => false,
+            };
+            if moved || changed {
+                damage.extend(entry.subtree);
+                damage.extend(current.subtree);
+            }
+        }
+        for entry in &new.entries {
+            if old.get(entry.id).is_none() {
+                damage.extend(entry.own);
+            }
+        }
+
+        merge_overlapping(damage)
+    }
+
+    /// Returns the root-space bounds of the reachable texture layers marked
+    /// for re-push, merged like [`Self::damage_region`].
+    ///
+    /// After [`TextureFrameRegistry::flush`](crate::TextureFrameRegistry::flush)
+    /// on an otherwise clean tree this is exactly the part of the screen a
+    /// new external texture frame changes.
+    #[must_use]
+    pub fn texture_frame_damage(&self) -> Vec<Rect<Pixels>> {
+        let damage = self
+            .layer_bounds()
+            .entries
+            .iter()
+            .filter(|entry| {
+                self.get(entry.id).is_some_and(|node| {
+                    matches!(node.layer(), Layer::Texture(_)) && node.needs_add_to_scene()
+                })
+            })
+            .filter_map(|entry| entry.own)
+            .collect();
+        merge_overlapping(damage)
+    }
+}
+
+/// One reachable layer with its place in the tree and its bounds in root
+/// coordinates.
+#[derive(Debug)]
+struct BoundsEntry {
+    id: LayerId,
+    /// Parent id; `None` for the root.
+    parent: Option<LayerId>,
+    /// Position among the parent's children (paint order).
+    index: usize,
+    kind: Discriminant<Layer>,
+    /// The layer's own bounds, if it defines any.
+    own: Option<Rect<Pixels>>,
+    /// Union of `own` over the layer and all its descendants.
+    subtree: Option<Rect<Pixels>>,
+}
+
+/// Pre-order list of a tree's reachable layers, indexed by id.
+///
+/// Cached on the [`LayerTree`] it was collected from; see
+/// [`LayerTree::damage_region`].
+#[derive(Debug)]
+pub(crate) struct LayerBounds {
+    entries: Vec<BoundsEntry>,
+    index: HashMap<LayerId, usize>,
+}
+
+impl LayerBounds {
+    pub(crate) fn collect(tree: &LayerTree) -> Self {
+        let mut bounds = Self {
+            entries: Vec::new(),
+            index: HashMap::new(),
+        };
+        if let Some(root) = tree.root() {
+            bounds.visit(tree, root, None, 0, Matrix4::IDENTITY);
+        }
+        bounds
+    }
+
+    fn get(&self, id: LayerId) -> Option<&BoundsEntry> {
+        self.index.get(&id).map(|&index| &self.entries[index])
+    }
+
+    /// Records `id` and its subtree; returns the subtree's global bounds.
+    fn visit(
+        &mut self,
+        tree: &LayerTree,
+        id: LayerId,
+        parent: Option<LayerId>,
+        index: usize,
+        transform: Matrix4,
+    ) -> Option<Rect<Pixels>> {
+        let node = tree.get(id)?;
+        let layer = node.layer();
+        let own = layer.bounds().map(|rect| transform.transform_rect(&rect));
+
+        let slot = self.entries.len();
+        self.index.insert(id, slot);
+        self.entries.push(BoundsEntry {
+            id,
+            parent,
+            index,
+            kind: discriminant(layer),
+            own,
+            subtree: own,
+        });
+
+        let child_transform = transform * child_space(layer);
+        let mut subtree = own;
+        for (child_index, &child) in node.children().iter().enumerate() {
+            if let Some(child_bounds) =
+                self.visit(tree, child, Some(id), child_index, child_transform)
+            {
+                subtree = Some(subtree.map_or(child_bounds, |acc| acc.union(&child_bounds)));
+            }
+        }
+        self.entries[slot].subtree = subtree;
+        subtree
+    }
+}
+
+/// The transform a layer applies to its children's coordinate space.
+fn child_space(layer: &Layer) -> Matrix4 {
+    let translate = |offset: flui_types::Offset<Pixels>| {
+        Matrix4::translation(offset.dx.get(), offset.dy.get(), 0.0)
+    };
+    match layer {
+        Layer::Offset(layer) => translate(layer.offset()),
+        Layer::Opacity(layer) => translate(layer.offset()),
+        Layer::ImageFilter(layer) => translate(layer.offset()),
+        Layer::Transform(layer) => *layer.transform(),
+        _ => Matrix4::IDENTITY,
+    }
+}
+
+/// Whether two same-id layers carry the same parameters. Picture and canvas
+/// layers compare their display lists' generations, so a re-recorded picture
+/// counts as changed even when its bounds are not. Other variants without
+/// `PartialEq` compare equal here and fall back to the bounds check.
+fn same_parameters(a: &Layer, b: &Layer) -> bool {
+    match (a, b) {
+        (Layer::Picture(a), Layer::Picture(b)) => {
+            a.picture().generation() == b.picture().generation()
+        }
+        (Layer::Canvas(a), Layer::Canvas(b)) => {
+            a.display_list().generation() == b.display_list().generation()
+        }
+        (Layer::Offset(a), Layer::Offset(b)) => a == b,
+        (Layer::Transform(a), Layer::Transform(b)) => a == b,
+        (Layer::Opacity(a), Layer::Opacity(b)) => a == b,
+        (Layer::ColorFilter(a), Layer::ColorFilter(b)) => a == b,
+        (Layer::ImageFilter(a), Layer::ImageFilter(b)) => a == b,
+        (Layer::ClipRect(a), Layer::ClipRect(b)) => a == b,
+        (Layer::ClipRRect(a), Layer::ClipRRect(b)) => a == b,
+        (Layer::ClipPath(a), Layer::ClipPath(b)) => a == b,
+        (Layer::ClipSuperellipse(a), Layer::ClipSuperellipse(b)) => a == b,
+        (Layer::Texture(a), Layer::Texture(b)) => a == b,
+        (Layer::PlatformView(a), Layer::PlatformView(b)) => a == b,
+        (Layer::Leader(a), Layer::Leader(b)) => a == b,
+        (Layer::Follower(a), Layer::Follower(b)) => a == b,
+        _ => true,
+    }
+}
+
+/// Unions overlapping rects until no two overlap; empty rects are dropped.
+fn merge_overlapping(rects: Vec<Rect<Pixels>>) -> Vec<Rect<Pixels>> {
+    let mut merged: Vec<Rect<Pixels>> = Vec::with_capacity(rects.len());
+    for rect in rects {
+        if rect.width().0 <= 0.0 || rect.height().0 <= 0.0 || merged.contains(&rect) {
+            continue;
+        }
+        let mut rect = rect;
+        // Absorbing one rect can make the grown rect overlap another
+        // already-merged one, so keep sweeping until it stops growing.
+        while let Some(hit) = merged.iter().position(|other| other.overlaps(&rect)) {
+            rect = rect.union(&merged.swap_remove(hit));
+        }
+        merged.push(rect);
+    }
+    merged
+}
+
 #[cfg(test)]
 mod tests {
     use flui_types::geometry::{Rect, px};
@@ -268,4 +508,18 @@ fn mark_full_repaint_overrides() {
         assert!(tracker.damage_rect().is_none());
         assert!(tracker.damage_rects().is_none());
     }
+
+    #[test]
+    fn merge_overlapping_follows_chains_through_grown_rects() {
+        // a and c are disjoint, but once b joins a the union reaches c.
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+        let c = rect(15.0, 0.0, 25.0, 10.0);
+        let b = rect(5.0, 0.0, 18.0, 10.0);
+        let far = rect(100.0, 100.0, 110.0, 110.0);
+
+        let merged = merge_overlapping(vec![a, c, far, b, Rect::ZERO]);
+        assert_eq!(merged.len(), 2);
+        assert!(merged.contains(&rect(0.0, 0.0, 25.0, 10.0)));
+        assert!(merged.contains(&far));
+    }
 }
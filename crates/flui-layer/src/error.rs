@@ -12,7 +12,7 @@
 use flui_foundation::LayerId;
 use thiserror::Error;
 
-use crate::layer::LayerLink;
+use crate::layer::{LayerLink, TextureFormat};
 
 /// Errors surfaced by the layer crate.
 #[derive(Debug, Error)]
@@ -54,6 +54,22 @@ pub enum LayerError {
         link: LayerLink,
     },
 
+    /// A color matrix was built from a slice that is not exactly 20 values
+    /// (5 columns × 4 rows).
+    #[error("color matrix needs 20 values (5x4), got {len}")]
+    InvalidColorMatrixLength {
+        /// The rejected slice length.
+        len: usize,
+    },
+
+    /// A texture layer was created for a pixel format the engine cannot
+    /// sample.
+    #[error("texture format {format:?} is not supported by the engine")]
+    UnsupportedTextureFormat {
+        /// The rejected format.
+        format: TextureFormat,
+    },
+
     /// A composition callback panicked inside `catch_unwind`.
     ///
     /// The panic payload is unwound; the carrier message is best-effort
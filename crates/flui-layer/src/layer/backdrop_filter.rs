@@ -131,6 +131,7 @@ fn test_backdrop_filter_layer_blur() {
         let filter = ImageFilter::Blur {
             sigma_x: 5.0,
             sigma_y: 5.0,
+            tile_mode: None,
         };
         let bounds = Rect::from_xywh(px(0.0), px(0.0), px(50.0), px(50.0));
 
@@ -138,7 +139,9 @@ fn test_backdrop_filter_layer_blur() {
 
         assert_eq!(layer.blend_mode(), BlendMode::Multiply);
         match layer.filter() {
-            ImageFilter::Blur { sigma_x, sigma_y } => {
+            ImageFilter::Blur {
+                sigma_x, sigma_y, ..
+            } => {
                 assert_eq!(*sigma_x, 5.0);
                 assert_eq!(*sigma_y, 5.0);
             }
@@ -76,9 +76,10 @@ pub fn display_list(&self) -> &DisplayList {
         self.canvas.display_list()
     }
 
-    /// Returns the bounds of all drawing commands in this layer.
+    /// Returns the bounds of all drawing commands in this layer
+    /// (`Rect::ZERO` when nothing is drawn).
     pub fn bounds(&self) -> Rect<Pixels> {
-        self.canvas.display_list().bounds()
+        self.canvas.display_list().bounds().unwrap_or(Rect::ZERO)
     }
 
     /// Returns true if the canvas has no drawing commands.
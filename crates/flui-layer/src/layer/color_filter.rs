@@ -4,6 +4,8 @@
 
 use flui_types::painting::{ColorFilter, effects::ColorMatrix};
 
+use crate::error::{LayerError, LayerResult};
+
 /// Layer that applies a [`ColorFilter`] to its children.
 ///
 /// Color filters transform the color of every pixel rendered by children.
@@ -16,7 +18,10 @@
 /// | [`ColorFilter::LinearToSrgbGamma`] | linear → sRGB transfer per RGB channel |
 /// | [`ColorFilter::SrgbToLinearGamma`] | sRGB → linear transfer per RGB channel |
 ///
-/// Use the constructors on [`ColorFilter`] directly to build the filter value:
+/// Common matrix effects have presets on the layer itself (`grayscale`,
+/// `sepia`, `invert`, `saturation`, `brightness`, `contrast`,
+/// `hue_rotate`), and `from_matrix` takes any custom 5×4 matrix. For other
+/// filters, use the constructors on [`ColorFilter`] directly:
 ///
 /// ```rust
 /// use flui_layer::ColorFilterLayer;
@@ -64,6 +69,84 @@ pub fn identity() -> Self {
         Self::new(ColorFilter::Matrix(ColorMatrix::identity()))
     }
 
+    /// Creates a matrix filter layer from a row-major 5×4 color matrix:
+    /// `[r0..r3, off_r, g0..g3, off_g, b0..b3, off_b, a0..a3, off_a]`,
+    /// applied to un-premultiplied RGBA.
+    #[inline]
+    #[must_use]
+    pub const fn from_matrix(values: [f32; 20]) -> Self {
+        Self::new(ColorFilter::Matrix(ColorMatrix::new(values)))
+    }
+
+    /// Like [`from_matrix`](Self::from_matrix), for a matrix whose length is
+    /// only known at runtime (deserialized themes, user-supplied filters).
+    ///
+    /// # Errors
+    ///
+    /// [`LayerError::InvalidColorMatrixLength`] unless `values` holds exactly
+    /// 20 entries.
+    pub fn try_from_matrix_slice(values: &[f32]) -> LayerResult<Self> {
+        let values = <[f32; 20]>::try_from(values)
+            .map_err(|_| LayerError::InvalidColorMatrixLength { len: values.len() })?;
+        Ok(Self::from_matrix(values))
+    }
+
+    /// Luminance-weighted grayscale (Rec. 709: 0.2126 R + 0.7152 G +
+    /// 0.0722 B).
+    #[inline]
+    #[must_use]
+    pub fn grayscale() -> Self {
+        Self::from_color_matrix(ColorMatrix::grayscale())
+    }
+
+    /// Warm brown "old photo" tint.
+    #[inline]
+    #[must_use]
+    pub fn sepia() -> Self {
+        Self::from_color_matrix(ColorMatrix::sepia())
+    }
+
+    /// Inverts RGB (`1 - c`), leaving alpha untouched.
+    #[inline]
+    #[must_use]
+    pub fn invert() -> Self {
+        Self::from_color_matrix(ColorMatrix::invert())
+    }
+
+    /// Scales saturation: `0.0` is grayscale, `1.0` is unchanged, values
+    /// above `1.0` oversaturate.
+    #[inline]
+    #[must_use]
+    pub fn saturation(amount: f32) -> Self {
+        Self::from_color_matrix(ColorMatrix::saturation(amount))
+    }
+
+    /// Adds `amount` to each RGB channel (`-1.0..=1.0`, `0.0` = unchanged).
+    #[inline]
+    #[must_use]
+    pub fn brightness(amount: f32) -> Self {
+        Self::from_color_matrix(ColorMatrix::brightness(amount))
+    }
+
+    /// Scales contrast around mid-gray (`1.0` = unchanged).
+    #[inline]
+    #[must_use]
+    pub fn contrast(amount: f32) -> Self {
+        Self::from_color_matrix(ColorMatrix::contrast(amount))
+    }
+
+    /// Rotates hue by `radians`, preserving luminance.
+    #[inline]
+    #[must_use]
+    pub fn hue_rotate(radians: f32) -> Self {
+        Self::from_color_matrix(ColorMatrix::hue_rotate(radians.to_degrees()))
+    }
+
+    #[inline]
+    const fn from_color_matrix(matrix: ColorMatrix) -> Self {
+        Self::new(ColorFilter::Matrix(matrix))
+    }
+
     /// Returns the [`ColorFilter`] this layer applies.
     ///
     /// `ColorFilter` is `Copy`, so the value is returned by value at no cost.
@@ -209,6 +292,85 @@ fn set_mode_filter() {
         assert!(!layer.is_identity());
     }
 
+    // ── Matrix presets ────────────────────────────────────────────────────────
+
+    fn matrix_of(layer: ColorFilterLayer) -> ColorMatrix {
+        match layer.color_filter() {
+            ColorFilter::Matrix(m) => m,
+            other => panic!("expected a matrix filter, got {other:?}"),
+        }
+    }
+
+    fn assert_rgba_eq(actual: [f32; 4], expected: [f32; 4]) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-5, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn invert_turns_white_black() {
+        let invert = matrix_of(ColorFilterLayer::invert());
+        assert_rgba_eq(invert.apply([1.0, 1.0, 1.0, 1.0]), [0.0, 0.0, 0.0, 1.0]);
+        assert_rgba_eq(invert.apply([0.0, 0.0, 0.0, 0.5]), [1.0, 1.0, 1.0, 0.5]);
+    }
+
+    #[test]
+    fn grayscale_is_luminance_weighted() {
+        let luma = [0.2126, 0.7152, 0.0722, 0.0, 0.0];
+        #[rustfmt::skip]
+        let expected = [
+            luma[0], luma[1], luma[2], 0.0, 0.0,
+            luma[0], luma[1], luma[2], 0.0, 0.0,
+            luma[0], luma[1], luma[2], 0.0, 0.0,
+            0.0, 0.0, 0.0, 1.0, 0.0,
+        ];
+        assert_eq!(matrix_of(ColorFilterLayer::grayscale()).values, expected);
+    }
+
+    #[test]
+    fn neutral_adjustments_are_identity() {
+        assert!(ColorFilterLayer::brightness(0.0).is_identity());
+        assert!(ColorFilterLayer::contrast(1.0).is_identity());
+        assert!(ColorFilterLayer::saturation(1.0).is_identity());
+        let hue = matrix_of(ColorFilterLayer::hue_rotate(0.0));
+        assert_rgba_eq(hue.apply([0.2, 0.4, 0.6, 1.0]), [0.2, 0.4, 0.6, 1.0]);
+    }
+
+    #[test]
+    fn hue_rotate_takes_radians() {
+        let half_turn = matrix_of(ColorFilterLayer::hue_rotate(std::f32::consts::PI));
+        let degrees = ColorMatrix::hue_rotate(180.0);
+        for (a, e) in half_turn.values.iter().zip(degrees.values) {
+            assert!((a - e).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn from_matrix_round_trips_values() {
+        let mut values = [0.0; 20];
+        values[3] = 1.0;
+        values[18] = 1.0;
+        let layer = ColorFilterLayer::from_matrix(values);
+        assert_eq!(matrix_of(layer).values, values);
+    }
+
+    #[test]
+    fn matrix_slice_length_is_validated() {
+        let identity = ColorMatrix::identity().values;
+        assert_eq!(
+            ColorFilterLayer::try_from_matrix_slice(&identity).expect("20 values"),
+            ColorFilterLayer::identity()
+        );
+
+        let err = ColorFilterLayer::try_from_matrix_slice(&identity[..16])
+            .expect_err("a 4x4 matrix is rejected");
+        assert!(matches!(
+            err,
+            LayerError::InvalidColorMatrixLength { len: 16 }
+        ));
+        assert!(ColorFilterLayer::try_from_matrix_slice(&[0.0; 21]).is_err());
+    }
+
     // ── Send + Sync ───────────────────────────────────────────────────────────
 
     #[test]
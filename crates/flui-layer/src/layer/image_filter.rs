@@ -3,7 +3,11 @@
 //! This layer applies image filters (blur, dilate, erode, etc.) to its
 //! children. Corresponds to Flutter's `ImageFilterLayer`.
 
-use flui_types::{Offset, geometry::Pixels, painting::effects::ImageFilter};
+use flui_types::{
+    Offset,
+    geometry::Pixels,
+    painting::{TileMode, effects::ImageFilter},
+};
 
 /// Layer that applies an image filter to its children.
 ///
@@ -80,7 +84,7 @@ pub fn with_offset(filter: ImageFilter, offset: Offset<Pixels>) -> Self {
     /// * `sigma` - Blur radius (standard deviation) for both axes
     #[inline]
     pub fn blur(sigma: f32) -> Self {
-        Self::new(ImageFilter::blur(sigma))
+        Self::blur_xy(sigma, sigma)
     }
 
     /// Creates a directional Gaussian blur filter.
@@ -94,6 +98,26 @@ pub fn blur_xy(sigma_x: f32, sigma_y: f32) -> Self {
         Self::new(ImageFilter::blur_directional(sigma_x, sigma_y))
     }
 
+    /// Creates a directional Gaussian blur filter with an explicit edge mode.
+    ///
+    /// Corresponds to Flutter's `ImageFilter.blur(tileMode:)`. The default
+    /// for layers ([`blur_xy`](Self::blur_xy)) is [`TileMode::Decal`]: the
+    /// blur fades out past the children's bounds. The other modes sample
+    /// edge, repeated or mirrored pixels instead and keep the result within
+    /// the children's bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `sigma_x` - Horizontal blur radius
+    /// * `sigma_y` - Vertical blur radius
+    /// * `tile_mode` - How pixels beyond the children's bounds are sampled
+    #[inline]
+    pub fn blur_with_tile_mode(sigma_x: f32, sigma_y: f32, tile_mode: TileMode) -> Self {
+        Self::new(ImageFilter::blur_with_tile_mode(
+            sigma_x, sigma_y, tile_mode,
+        ))
+    }
+
     /// Creates a dilate filter.
     ///
     /// Dilation expands bright regions and shrinks dark regions.
@@ -162,7 +186,20 @@ pub fn has_offset(&self) -> bool {
     /// Returns `None` for non-blur filters.
     pub fn blur_sigma(&self) -> Option<(f32, f32)> {
         match &self.filter {
-            ImageFilter::Blur { sigma_x, sigma_y } => Some((*sigma_x, *sigma_y)),
+            ImageFilter::Blur {
+                sigma_x, sigma_y, ..
+            } => Some((*sigma_x, *sigma_y)),
+            _ => None,
+        }
+    }
+
+    /// Returns the explicit blur tile mode, if any.
+    ///
+    /// Returns `None` for non-blur filters and for blurs using the default
+    /// edge handling.
+    pub fn blur_tile_mode(&self) -> Option<TileMode> {
+        match &self.filter {
+            ImageFilter::Blur { tile_mode, .. } => *tile_mode,
             _ => None,
         }
     }
@@ -234,6 +271,15 @@ fn test_image_filter_layer_blur_xy() {
         assert_eq!(sigma, (5.0, 15.0));
     }
 
+    #[test]
+    fn test_image_filter_layer_blur_with_tile_mode() {
+        let layer = ImageFilterLayer::blur_with_tile_mode(4.0, 2.0, TileMode::Clamp);
+
+        assert_eq!(layer.blur_sigma(), Some((4.0, 2.0)));
+        assert_eq!(layer.blur_tile_mode(), Some(TileMode::Clamp));
+        assert_eq!(ImageFilterLayer::blur_xy(4.0, 2.0).blur_tile_mode(), None);
+    }
+
     #[test]
     fn test_image_filter_layer_dilate() {
         let layer = ImageFilterLayer::dilate(3.0);
@@ -123,7 +123,7 @@
 pub use picture::PictureLayer;
 pub use platform_view::{PlatformViewHitTestBehavior, PlatformViewId, PlatformViewLayer};
 pub use shader_mask::ShaderMaskLayer;
-pub use texture::TextureLayer;
+pub use texture::{TextureFormat, TextureLayer};
 pub use transform::TransformLayer;
 
 /// Compositor layer - polymorphic layer types for advanced rendering
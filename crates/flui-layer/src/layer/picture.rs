@@ -95,7 +95,7 @@ impl PictureLayer {
     /// let layer = PictureLayer::new(picture);
     /// ```
     pub fn new(picture: DisplayList) -> Self {
-        let bounds = picture.bounds();
+        let bounds = picture.bounds().unwrap_or(Rect::ZERO);
         Self { picture, bounds }
     }
 
@@ -145,7 +145,7 @@ pub fn set_bounds(&mut self, bounds: Rect<Pixels>) {
     ///
     /// * `picture` - New recorded `DisplayList` to store
     pub fn set_picture(&mut self, picture: DisplayList) {
-        self.bounds = picture.bounds();
+        self.bounds = picture.bounds().unwrap_or(Rect::ZERO);
         self.picture = picture;
     }
 
@@ -8,6 +8,41 @@
     painting::{FilterQuality, TextureId},
 };
 
+use crate::error::{LayerError, LayerResult};
+
+/// Pixel layout an external texture producer delivers frames in.
+///
+/// Producers negotiate by checking [`is_supported`](Self::is_supported)
+/// before creating the layer; [`TextureLayer::try_new`] rejects a format
+/// the engine cannot sample, so a decoder can fall back to converting
+/// frames to RGBA itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TextureFormat {
+    /// 8-bit RGBA, the engine's native sampling layout.
+    #[default]
+    Rgba8,
+    /// 8-bit BGRA (common camera / swapchain layout). The GPU swizzles on
+    /// sample, so it shares the RGBA sampling path.
+    Bgra8,
+    /// Bi-planar YUV 4:2:0 (Y plane + interleaved UV), the usual hardware
+    /// video decoder output.
+    Nv12,
+    /// Tri-planar YUV 4:2:0 (Y, U, V planes).
+    I420,
+}
+
+impl TextureFormat {
+    /// Returns `true` if the engine has a sampling path for this format.
+    ///
+    /// The YUV layouts need a colour-space conversion stage the external
+    /// texture pipeline does not have yet.
+    #[inline]
+    #[must_use]
+    pub const fn is_supported(self) -> bool {
+        matches!(self, Self::Rgba8 | Self::Bgra8)
+    }
+}
+
 /// Layer that displays an external GPU texture.
 ///
 /// Used for rendering content that comes from external sources:
@@ -48,6 +83,10 @@
 /// // With custom filter quality
 /// let hq_layer = TextureLayer::new(texture_id, rect).with_filter_quality(FilterQuality::High);
 /// ```
+///
+/// Frame updates from the producer reach the compositor through a
+/// [`TextureFrameRegistry`](crate::TextureFrameRegistry), which marks only
+/// the layers showing that texture for re-push.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct TextureLayer {
     /// The texture ID referencing an external GPU texture
@@ -64,6 +103,9 @@ pub struct TextureLayer {
 
     /// Opacity (0.0 = transparent, 1.0 = opaque)
     opacity: f32,
+
+    /// Pixel layout the producer delivers frames in
+    format: TextureFormat,
 }
 
 impl TextureLayer {
@@ -81,7 +123,28 @@ pub fn new(texture_id: TextureId, rect: Rect<Pixels>) -> Self {
             freeze: false,
             filter_quality: FilterQuality::Low,
             opacity: 1.0,
+            format: TextureFormat::Rgba8,
+        }
+    }
+
+    /// Creates a texture layer whose producer delivers frames in `format`.
+    ///
+    /// # Errors
+    ///
+    /// [`LayerError::UnsupportedTextureFormat`] if the engine cannot sample
+    /// `format` (see [`TextureFormat::is_supported`]).
+    pub fn try_new(
+        texture_id: TextureId,
+        rect: Rect<Pixels>,
+        format: TextureFormat,
+    ) -> LayerResult<Self> {
+        if !format.is_supported() {
+            return Err(LayerError::UnsupportedTextureFormat { format });
         }
+        Ok(Self {
+            format,
+            ..Self::new(texture_id, rect)
+        })
     }
 
     /// Creates a frozen texture layer (texture won't update).
@@ -93,6 +156,7 @@ pub fn frozen(texture_id: TextureId, rect: Rect<Pixels>) -> Self {
             freeze: true,
             filter_quality: FilterQuality::Low,
             opacity: 1.0,
+            format: TextureFormat::Rgba8,
         }
     }
 
@@ -123,6 +187,12 @@ pub fn texture_id(&self) -> TextureId {
         self.texture_id
     }
 
+    /// Returns the pixel format frames arrive in.
+    #[inline]
+    pub fn format(&self) -> TextureFormat {
+        self.format
+    }
+
     /// Returns the destination rectangle.
     #[inline]
     pub fn rect(&self) -> Rect<Pixels> {
@@ -204,6 +274,7 @@ fn default() -> Self {
             freeze: false,
             filter_quality: FilterQuality::Low,
             opacity: 1.0,
+            format: TextureFormat::Rgba8,
         }
     }
 }
@@ -314,6 +385,37 @@ fn test_texture_layer_visibility() {
         assert!(!semi.is_opaque());
     }
 
+    #[test]
+    fn test_texture_layer_format_defaults_to_rgba() {
+        let layer = TextureLayer::new(TextureId::new(1), Rect::ZERO);
+        assert_eq!(layer.format(), TextureFormat::Rgba8);
+        assert_eq!(TextureLayer::default().format(), TextureFormat::Rgba8);
+    }
+
+    #[test]
+    fn test_texture_layer_try_new_accepts_supported_formats() {
+        let rect = Rect::from_xywh(px(0.0), px(0.0), px(64.0), px(64.0));
+        let layer = TextureLayer::try_new(TextureId::new(7), rect, TextureFormat::Bgra8)
+            .expect("BGRA is sampled natively");
+
+        assert_eq!(layer.format(), TextureFormat::Bgra8);
+        assert_eq!(layer.texture_id(), TextureId::new(7));
+        assert_eq!(layer.rect(), rect);
+    }
+
+    #[test]
+    fn test_texture_layer_try_new_rejects_unsupported_format() {
+        for format in [TextureFormat::Nv12, TextureFormat::I420] {
+            assert!(!format.is_supported());
+            let err = TextureLayer::try_new(TextureId::new(1), Rect::ZERO, format)
+                .expect_err("YUV formats have no sampling path");
+            assert!(matches!(
+                err,
+                LayerError::UnsupportedTextureFormat { format: rejected } if rejected == format
+            ));
+        }
+    }
+
     #[test]
     fn test_texture_layer_clone_copy() {
         // Compile-time bound checks — fail to compile if `Clone` or `Copy`
@@ -91,6 +91,7 @@
 mod scene;
 // The owned per-window per-frame raster package.
 mod scene_snapshot;
+mod texture_registry;
 
 pub mod layer;
 // Layer-tree test harness. Compiled only for this crate's own tests
@@ -155,6 +156,7 @@
     SemanticLabel,
     ShaderMaskLayer,
     SystemUiOverlayStyle,
+    TextureFormat,
     TextureLayer,
     TransformLayer,
 };
@@ -164,6 +166,9 @@
 pub use link_registry::{LeaderInfo, LinkRegistry, resolve_follower_offset};
 pub use scene::{CompositionCallback, Scene};
 pub use scene_snapshot::{DamageRegion, SceneSnapshot};
+pub use texture_registry::{
+    FrameAvailableCallback, FrameScheduler, TextureFrameRegistry, TextureFrameSignal,
+};
 // ============================================================================
 // RE-EXPORTS - Tree
 // ============================================================================
@@ -5,7 +5,6 @@
 //! reimplementing the walk.
 
 use flui_foundation::{Diagnosticable, DiagnosticsNode, LayerId};
-use flui_painting::DisplayListCore;
 use flui_types::Rect;
 
 use crate::{Layer, LayerTree};
@@ -54,7 +53,7 @@ pub fn first_picture_bounds(tree: &LayerTree) -> Option<Rect> {
     fn find(tree: &LayerTree, id: LayerId) -> Option<Rect> {
         let node = tree.get(id)?;
         if let Layer::Picture(picture) = node.layer() {
-            return Some(picture.picture().bounds());
+            return Some(picture.picture().bounds().unwrap_or(Rect::ZERO));
         }
         node.children().iter().find_map(|&child| find(tree, child))
     }
@@ -0,0 +1,329 @@
+//! External-texture frame notifications.
+//!
+//! A video decoder or camera owns its GPU texture and produces frames on its
+//! own thread. Instead of recomposing the whole scene per frame, it signals
+//! "frame available" for its [`TextureId`], which schedules a frame through
+//! the registry's frame scheduler; at the next composite the
+//! compositor flushes the registry, which runs the registered callbacks and
+//! marks only the [`TextureLayer`](crate::TextureLayer)s showing that texture
+//! for re-push.
+//!
+//! # Usage
+//!
+//! ```rust
+//! use std::sync::{
+//!     Arc,
+//!     atomic::{AtomicUsize, Ordering},
+//! };
+//!
+//! use flui_layer::{Layer, LayerTree, TextureFrameRegistry, TextureLayer};
+//! use flui_types::{geometry::Rect, painting::TextureId};
+//!
+//! let mut tree = LayerTree::new();
+//! let video = TextureId::new(7);
+//! let layer = tree.insert(Layer::Texture(TextureLayer::new(video, Rect::ZERO)));
+//! tree.clear_needs_add_to_scene_subtree(layer);
+//!
+//! let frames_seen = Arc::new(AtomicUsize::new(0));
+//! let wakes = Arc::new(AtomicUsize::new(0));
+//!
+//! let mut registry = TextureFrameRegistry::new();
+//! let seen = Arc::clone(&frames_seen);
+//! registry.on_frame_available(video, move |_| {
+//!     seen.fetch_add(1, Ordering::Relaxed);
+//! });
+//! // The binding wakes its frame loop whenever a producer signals.
+//! let woken = Arc::clone(&wakes);
+//! registry.set_frame_scheduler(move || {
+//!     woken.fetch_add(1, Ordering::Relaxed);
+//! });
+//!
+//! // Decoder thread: hand it a signal and call it per decoded frame.
+//! let signal = registry.frame_signal(video);
+//! signal.signal();
+//! assert_eq!(wakes.load(Ordering::Relaxed), 1);
+//!
+//! // Compositor, before building the scene:
+//! assert_eq!(registry.flush(&tree), 1);
+//! assert_eq!(frames_seen.load(Ordering::Relaxed), 1);
+//! assert!(tree.get(layer).unwrap().needs_add_to_scene());
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use flui_types::painting::TextureId;
+
+use crate::tree::LayerTree;
+
+/// Callback run on the compositor thread when a texture has a new frame.
+pub type FrameAvailableCallback = Box<dyn FnMut(TextureId) + Send>;
+
+/// Hook a signal calls to get a frame scheduled (e.g. waking the event loop).
+pub type FrameScheduler = Arc<dyn Fn() + Send + Sync>;
+
+/// State shared between the registry and its producer-side signals.
+#[derive(Default)]
+struct Shared {
+    /// Texture ids signaled since the last flush, in signal order.
+    pending: Mutex<Vec<TextureId>>,
+    /// Installed after signals may already have been handed out, so it lives
+    /// here rather than being copied into each [`TextureFrameSignal`].
+    scheduler: Mutex<Option<FrameScheduler>>,
+}
+
+impl Shared {
+    fn pending(&self) -> MutexGuard<'_, Vec<TextureId>> {
+        lock(&self.pending)
+    }
+
+    /// Queues `texture_id`, then runs the scheduler outside both locks so it
+    /// may call back into the registry.
+    fn signal(&self, texture_id: TextureId) {
+        self.pending().push(texture_id);
+        let scheduler = lock(&self.scheduler).clone();
+        if let Some(schedule_frame) = scheduler {
+            schedule_frame();
+        }
+    }
+}
+
+// ============================================================================
+// TEXTURE FRAME REGISTRY
+// ============================================================================
+
+/// Collects frame-available signals for external textures and turns them
+/// into dirty marks on the layers that show those textures.
+#[derive(Default)]
+pub struct TextureFrameRegistry {
+    callbacks: HashMap<TextureId, Vec<FrameAvailableCallback>>,
+    shared: Arc<Shared>,
+}
+
+impl fmt::Debug for TextureFrameRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextureFrameRegistry")
+            .field("textures_with_callbacks", &self.callbacks.len())
+            .field("pending", &self.shared.pending().len())
+            .field(
+                "has_frame_scheduler",
+                &lock(&self.shared.scheduler).is_some(),
+            )
+            .finish()
+    }
+}
+
+impl TextureFrameRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `callback` to run (at flush time) whenever `texture_id`
+    /// signals a new frame. Several callbacks per texture run in
+    /// registration order.
+    pub fn on_frame_available(
+        &mut self,
+        texture_id: TextureId,
+        callback: impl FnMut(TextureId) + Send + 'static,
+    ) {
+        self.callbacks
+            .entry(texture_id)
+            .or_default()
+            .push(Box::new(callback));
+    }
+
+    /// Installs the hook every signal calls to get a frame scheduled, so a
+    /// new texture frame is composited even when nothing else is dirty.
+    /// Applies to signals already handed out; replaces any previous hook.
+    pub fn set_frame_scheduler(&self, schedule_frame: impl Fn() + Send + Sync + 'static) {
+        *lock(&self.shared.scheduler) = Some(Arc::new(schedule_frame));
+    }
+
+    /// Drops every callback registered for `texture_id` (when its producer
+    /// shuts down). Returns `true` if any were registered.
+    pub fn remove_callbacks(&mut self, texture_id: TextureId) -> bool {
+        self.callbacks.remove(&texture_id).is_some()
+    }
+
+    /// Records that `texture_id` has a new frame and schedules a frame.
+    /// Takes `&self`, so the compositor thread can signal directly; producer
+    /// threads use a [`frame_signal`](Self::frame_signal) handle instead.
+    pub fn signal_frame_available(&self, texture_id: TextureId) {
+        self.shared.signal(texture_id);
+    }
+
+    /// Returns a `Send + Clone` handle a producer thread can use to signal
+    /// frames for `texture_id`.
+    pub fn frame_signal(&self, texture_id: TextureId) -> TextureFrameSignal {
+        TextureFrameSignal {
+            texture_id,
+            shared: Arc::clone(&self.shared),
+        }
+    }
+
+    /// Returns `true` if any frame was signaled since the last flush.
+    pub fn has_pending(&self) -> bool {
+        !self.shared.pending().is_empty()
+    }
+
+    /// Drains the pending signals: runs each signaled texture's callbacks
+    /// once (however many frames arrived) and marks its non-frozen texture
+    /// layers in `tree` for re-push. Returns how many layers were marked.
+    pub fn flush(&mut self, tree: &LayerTree) -> usize {
+        let mut signaled = std::mem::take(&mut *self.shared.pending());
+        let mut seen = Vec::with_capacity(signaled.len());
+        signaled.retain(|id| {
+            let first = !seen.contains(id);
+            seen.push(*id);
+            first
+        });
+
+        let mut marked = 0;
+        for texture_id in signaled {
+            if let Some(callbacks) = self.callbacks.get_mut(&texture_id) {
+                for callback in callbacks {
+                    callback(texture_id);
+                }
+            }
+            marked += tree.mark_texture_frame_available(texture_id);
+        }
+        marked
+    }
+}
+
+// ============================================================================
+// TEXTURE FRAME SIGNAL
+// ============================================================================
+
+/// Producer-side handle that signals new frames for one texture.
+///
+/// Obtained from [`TextureFrameRegistry::frame_signal`]; cheap to clone and
+/// safe to move to a decoder thread. Signals are queued until the next
+/// [`TextureFrameRegistry::flush`], and each one schedules a frame through
+/// the registry's [frame scheduler](TextureFrameRegistry::set_frame_scheduler).
+#[derive(Clone)]
+pub struct TextureFrameSignal {
+    texture_id: TextureId,
+    shared: Arc<Shared>,
+}
+
+impl fmt::Debug for TextureFrameSignal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextureFrameSignal")
+            .field("texture_id", &self.texture_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TextureFrameSignal {
+    /// The texture this handle signals for.
+    pub fn texture_id(&self) -> TextureId {
+        self.texture_id
+    }
+
+    /// Signals that a new frame is available and schedules a frame.
+    pub fn signal(&self) {
+        self.shared.signal(self.texture_id);
+    }
+}
+
+/// A producer panicking mid-push leaves the queue itself valid, so a
+/// poisoned lock is recovered rather than propagated.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use flui_types::geometry::Rect;
+
+    use super::*;
+    use crate::layer::{Layer, OffsetLayer, TextureLayer};
+
+    #[test]
+    fn callbacks_run_once_per_flush_for_repeated_signals() {
+        let tree = LayerTree::new();
+        let video = TextureId::new(3);
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let mut registry = TextureFrameRegistry::new();
+        let counter = Arc::clone(&calls);
+        registry.on_frame_available(video, move |id| {
+            assert_eq!(id, video);
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+
+        let signal = registry.frame_signal(video);
+        std::thread::spawn(move || {
+            signal.signal();
+            signal.signal();
+        })
+        .join()
+        .unwrap();
+        assert!(registry.has_pending());
+
+        registry.flush(&tree);
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+        assert!(!registry.has_pending());
+
+        registry.flush(&tree);
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn removed_callbacks_do_not_run() {
+        let tree = LayerTree::new();
+        let video = TextureId::new(3);
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let mut registry = TextureFrameRegistry::new();
+        let counter = Arc::clone(&calls);
+        registry.on_frame_available(video, move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+        assert!(registry.remove_callbacks(video));
+        assert!(!registry.remove_callbacks(video));
+
+        registry.signal_frame_available(video);
+        registry.flush(&tree);
+        assert_eq!(calls.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn signals_schedule_a_frame_through_the_installed_hook() {
+        let video = TextureId::new(5);
+        let wakes = Arc::new(AtomicUsize::new(0));
+        let registry = TextureFrameRegistry::new();
+
+        // Handed out before the hook exists; must still reach it.
+        let signal = registry.frame_signal(video);
+        let counter = Arc::clone(&wakes);
+        registry.set_frame_scheduler(move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+
+        std::thread::spawn(move || signal.signal()).join().unwrap();
+        registry.signal_frame_available(video);
+        assert_eq!(wakes.load(Ordering::Relaxed), 2);
+        assert!(registry.has_pending());
+    }
+
+    #[test]
+    fn frozen_texture_layers_are_not_marked() {
+        let mut tree = LayerTree::new();
+        let video = TextureId::new(9);
+        let root = tree.insert(Layer::Offset(OffsetLayer::zero()));
+        let frozen = tree.insert(Layer::Texture(TextureLayer::frozen(video, Rect::ZERO)));
+        tree.add_child(root, frozen);
+        tree.clear_needs_add_to_scene_subtree(root);
+
+        let mut registry = TextureFrameRegistry::new();
+        registry.signal_frame_available(video);
+        assert_eq!(registry.flush(&tree), 0);
+        assert!(tree.get(frozen).unwrap().is_clean());
+    }
+}
@@ -3,12 +3,14 @@
 //! This module provides the LayerTree struct and LayerNode
 //! for managing the compositor layer hierarchy.
 
+use std::sync::OnceLock;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use flui_foundation::{Diagnosticable, ElementId, LayerId};
-use flui_types::{Offset, geometry::Pixels};
+use flui_types::{Offset, geometry::Pixels, painting::TextureId};
 use slab::Slab;
 
+use crate::damage::LayerBounds;
 use crate::layer::Layer;
 
 // ============================================================================
@@ -328,7 +330,8 @@ fn drop(&mut self) {
 /// ```text
 /// LayerTree
 ///   ├─ nodes: Slab<LayerNode>  (direct storage)
-///   └─ root: Option<LayerId>
+///   ├─ root: Option<LayerId>
+///   └─ layer_bounds: OnceLock<LayerBounds>  (damage cache)
 /// ```
 ///
 /// # Thread Safety
@@ -359,6 +362,10 @@ pub struct LayerTree {
 
     /// Root LayerNode ID (None if tree is empty)
     root: Option<LayerId>,
+
+    /// Per-layer global bounds for [`Self::damage_region`], filled on first
+    /// use and dropped by every structural or `&mut` layer access.
+    layer_bounds: OnceLock<LayerBounds>,
 }
 
 impl LayerTree {
@@ -367,6 +374,7 @@ pub fn new() -> Self {
         Self {
             nodes: Slab::new(),
             root: None,
+            layer_bounds: OnceLock::new(),
         }
     }
 
@@ -375,6 +383,7 @@ pub fn with_capacity(capacity: usize) -> Self {
         Self {
             nodes: Slab::with_capacity(capacity),
             root: None,
+            layer_bounds: OnceLock::new(),
         }
     }
 
@@ -389,6 +398,7 @@ pub fn root(&self) -> Option<LayerId> {
     /// Set the root LayerNode ID.
     #[inline]
     pub fn set_root(&mut self, root: Option<LayerId>) {
+        self.invalidate_layer_bounds();
         self.root = root;
     }
 
@@ -441,6 +451,7 @@ pub fn insert(&mut self, layer: Layer) -> LayerId {
     /// node assembled elsewhere (e.g. element-cloning workflows in
     /// flui-view).
     pub fn insert_node(&mut self, node: LayerNode) -> LayerId {
+        self.invalidate_layer_bounds();
         let slab_index = self.nodes.insert(node);
         LayerId::new(slab_index + 1) // +1 offset
     }
@@ -448,6 +459,7 @@ pub fn insert_node(&mut self, node: LayerNode) -> LayerId {
     /// Inserts a Layer with an associated ElementId.
     pub fn insert_with_element(&mut self, layer: Layer, element_id: ElementId) -> LayerId {
         let node = LayerNode::new(layer).with_element_id(element_id);
+        self.invalidate_layer_bounds();
         let slab_index = self.nodes.insert(node);
         LayerId::new(slab_index + 1)
     }
@@ -465,6 +477,7 @@ pub fn get(&self, id: LayerId) -> Option<&LayerNode> {
     /// Returns a mutable reference to a LayerNode.
     #[inline]
     pub fn get_mut(&mut self, id: LayerId) -> Option<&mut LayerNode> {
+        self.invalidate_layer_bounds();
         self.nodes.get_mut(id.get() - 1)
     }
 
@@ -527,11 +540,13 @@ pub fn remove_shallow(&mut self, id: LayerId) -> Option<LayerNode> {
         if self.root == Some(id) {
             self.root = None;
         }
+        self.invalidate_layer_bounds();
         self.nodes.try_remove(id.get() - 1)
     }
 
     /// Clears all nodes from the tree.
     pub fn clear(&mut self) {
+        self.invalidate_layer_bounds();
         self.nodes.clear();
         self.root = None;
     }
@@ -862,11 +877,28 @@ pub fn iter(&self) -> impl Iterator<Item = (LayerId, &LayerNode)> + '_ {
 
     /// Returns a mutable iterator over all (LayerId, &mut LayerNode) pairs.
     pub fn iter_mut(&mut self) -> impl Iterator<Item = (LayerId, &mut LayerNode)> + '_ {
+        self.invalidate_layer_bounds();
         self.nodes
             .iter_mut()
             .map(|(index, node)| (LayerId::new(index + 1), node))
     }
 
+    // ========== Damage bounds cache ==========
+
+    /// Returns the per-layer global bounds of the reachable tree, computing
+    /// them on first use. Diffing frame N against N-1 then reuses the
+    /// bounds N-1 cached when it was the current frame.
+    pub(crate) fn layer_bounds(&self) -> &LayerBounds {
+        self.layer_bounds.get_or_init(|| LayerBounds::collect(self))
+    }
+
+    /// Drops the cached bounds; called by every mutator that reaches the
+    /// slab or the root.
+    #[inline]
+    fn invalidate_layer_bounds(&mut self) {
+        self.layer_bounds.take();
+    }
+
     // ========== Compositor dirty-bit propagation ==========
     //
     // Mirrors Flutter `layer.dart`:
@@ -925,6 +957,30 @@ pub fn mark_needs_add_to_scene(&self, id: LayerId) {
         }
     }
 
+    /// Marks every non-frozen [`Layer::Texture`] showing `texture_id` (and
+    /// its ancestors) for re-push, leaving unrelated layers clean. Returns
+    /// how many texture layers were marked.
+    ///
+    /// Called by [`TextureFrameRegistry::flush`](crate::TextureFrameRegistry::flush)
+    /// when the texture's producer signals a new frame.
+    pub fn mark_texture_frame_available(&self, texture_id: TextureId) -> usize {
+        let showing: Vec<LayerId> = self
+            .iter()
+            .filter_map(|(id, node)| match node.layer() {
+                Layer::Texture(texture)
+                    if texture.texture_id() == texture_id && !texture.is_frozen() =>
+                {
+                    Some(id)
+                }
+                _ => None,
+            })
+            .collect();
+        for &id in &showing {
+            self.mark_needs_add_to_scene(id);
+        }
+        showing.len()
+    }
+
     /// Post-order walks the subtree rooted at `root`, folding each child's
     /// dirty bit into the parent so the parent reports `true` whenever any
     /// descendant is dirty. Returns the resulting per-subtree dirty bit.
@@ -1,7 +1,11 @@
-//! Damage-tracker integration tests: dirty-region accumulation and reset.
+//! Damage-tracker integration tests: dirty-region accumulation and reset,
+//! plus `LayerTree::damage_region` frame-to-frame diffs.
 
 use flui_layer::damage::DamageTracker;
-use flui_types::geometry::{Rect, px};
+use flui_layer::{Layer, LayerTree, OffsetLayer, OpacityLayer, PictureLayer};
+use flui_painting::{Canvas, DisplayList, Paint};
+use flui_types::geometry::{Pixels, Rect, px};
+use flui_types::styling::Color;
 
 #[test]
 fn test_new_tracker_needs_full_repaint() {
@@ -105,3 +109,229 @@ fn test_region_count() {
     tracker.reset();
     assert_eq!(tracker.region_count(), 0);
 }
+
+// ============================================================================
+// LayerTree::damage_region
+// ============================================================================
+
+fn rect(l: f32, t: f32, r: f32, b: f32) -> Rect<Pixels> {
+    Rect::from_ltrb(px(l), px(t), px(r), px(b))
+}
+
+fn picture(bounds: Rect<Pixels>) -> Layer {
+    Layer::Picture(Box::new(PictureLayer::with_bounds(
+        DisplayList::new(),
+        bounds,
+    )))
+}
+
+/// Root offset layer with a static 50×50 picture at (200, 0) and a 20×20
+/// picture placed by its own offset layer at `moving`. Built in the same
+/// insertion order every time, so ids line up across frames.
+fn frame(moving: (f32, f32), alpha: f32, with_extra: bool) -> LayerTree {
+    let mut tree = LayerTree::new();
+    let root = tree.insert(Layer::Offset(OffsetLayer::zero()));
+    tree.set_root(Some(root));
+
+    let fixed = tree.insert(picture(rect(200.0, 0.0, 250.0, 50.0)));
+    tree.add_child(root, fixed);
+
+    let opacity = tree.insert(Layer::Opacity(OpacityLayer::new(alpha)));
+    tree.add_child(root, opacity);
+    let offset = tree.insert(Layer::Offset(OffsetLayer::from_xy(moving.0, moving.1)));
+    tree.add_child(opacity, offset);
+    let moved = tree.insert(picture(rect(0.0, 0.0, 20.0, 20.0)));
+    tree.add_child(offset, moved);
+
+    if with_extra {
+        let extra = tree.insert(picture(rect(300.0, 300.0, 310.0, 310.0)));
+        tree.add_child(root, extra);
+    }
+    tree
+}
+
+fn sorted(mut rects: Vec<Rect<Pixels>>) -> Vec<Rect<Pixels>> {
+    rects.sort_by(|a, b| {
+        (a.left().0, a.top().0)
+            .partial_cmp(&(b.left().0, b.top().0))
+            .expect("finite rect coordinates")
+    });
+    rects
+}
+
+#[test]
+fn test_unchanged_tree_has_no_damage() {
+    let previous = frame((0.0, 100.0), 1.0, false);
+    let current = frame((0.0, 100.0), 1.0, false);
+    assert!(current.damage_region(&previous).is_empty());
+}
+
+#[test]
+fn test_moving_one_layer_damages_its_old_and_new_bounds_only() {
+    let previous = frame((0.0, 100.0), 1.0, false);
+    let current = frame((100.0, 100.0), 1.0, false);
+
+    assert_eq!(
+        sorted(current.damage_region(&previous)),
+        vec![
+            rect(0.0, 100.0, 20.0, 120.0),
+            rect(100.0, 100.0, 120.0, 120.0)
+        ]
+    );
+}
+
+#[test]
+fn test_overlapping_damage_is_merged() {
+    let previous = frame((0.0, 100.0), 1.0, false);
+    let current = frame((10.0, 105.0), 1.0, false);
+
+    assert_eq!(
+        current.damage_region(&previous),
+        vec![rect(0.0, 100.0, 30.0, 125.0)]
+    );
+}
+
+#[test]
+fn test_inserted_and_removed_layers_damage_their_bounds() {
+    let without = frame((0.0, 100.0), 1.0, false);
+    let with = frame((0.0, 100.0), 1.0, true);
+    let extra = vec![rect(300.0, 300.0, 310.0, 310.0)];
+
+    assert_eq!(with.damage_region(&without), extra);
+    assert_eq!(without.damage_region(&with), extra);
+}
+
+#[test]
+fn test_container_parameter_change_damages_its_subtree() {
+    // Same geometry, different alpha: the faded picture has to repaint.
+    let previous = frame((0.0, 100.0), 1.0, false);
+    let current = frame((0.0, 100.0), 0.5, false);
+
+    assert_eq!(
+        current.damage_region(&previous),
+        vec![rect(0.0, 100.0, 20.0, 120.0)]
+    );
+}
+
+#[test]
+fn test_tracker_accumulates_layer_tree_damage() {
+    let previous = frame((0.0, 100.0), 1.0, false);
+    let current = frame((100.0, 100.0), 1.0, false);
+
+    let mut tracker = DamageTracker::new();
+    tracker.reset();
+    tracker.mark_layer_tree_damage(&current, &previous);
+
+    assert_eq!(tracker.region_count(), 2);
+    assert_eq!(tracker.damage_rect(), Some(rect(0.0, 100.0, 120.0, 120.0)));
+}
+
+/// Root offset layer holding a half-transparent opacity layer and two
+/// 20×20 pictures over the same pixels. With `in_opacity` the first picture
+/// sits inside the opacity layer instead of the root. The root's children
+/// are appended in `root_order` (`0` = opacity, `1` / `2` = the pictures).
+fn stacked_frame(in_opacity: bool, root_order: [usize; 3]) -> LayerTree {
+    let mut tree = LayerTree::new();
+    let root = tree.insert(Layer::Offset(OffsetLayer::zero()));
+    tree.set_root(Some(root));
+
+    let opacity = tree.insert(Layer::Opacity(OpacityLayer::new(0.5)));
+    let first = tree.insert(picture(rect(0.0, 0.0, 20.0, 20.0)));
+    let second = tree.insert(picture(rect(0.0, 0.0, 20.0, 20.0)));
+    let children = [opacity, first, second];
+    for index in root_order {
+        if !(in_opacity && index == 1) {
+            tree.add_child(root, children[index]);
+        }
+    }
+    if in_opacity {
+        tree.add_child(opacity, first);
+    }
+    tree
+}
+
+#[test]
+fn test_reparented_layer_with_same_bounds_is_damaged() {
+    // The picture leaves the opacity layer but keeps its global bounds.
+    let previous = stacked_frame(true, [0, 1, 2]);
+    let current = stacked_frame(false, [0, 1, 2]);
+
+    assert_eq!(
+        current.damage_region(&previous),
+        vec![rect(0.0, 0.0, 20.0, 20.0)]
+    );
+}
+
+#[test]
+fn test_sibling_reorder_with_same_bounds_is_damaged() {
+    let previous = stacked_frame(false, [0, 1, 2]);
+    let current = stacked_frame(false, [0, 2, 1]);
+
+    assert!(
+        previous
+            .damage_region(&stacked_frame(false, [0, 1, 2]))
+            .is_empty()
+    );
+    assert_eq!(
+        current.damage_region(&previous),
+        vec![rect(0.0, 0.0, 20.0, 20.0)]
+    );
+}
+
+#[test]
+fn test_cached_bounds_follow_tree_mutation() {
+    let previous = frame((0.0, 100.0), 1.0, false);
+    let mut current = frame((0.0, 100.0), 1.0, false);
+    assert!(current.damage_region(&previous).is_empty());
+
+    // Moving the inner offset layer must not be masked by the bounds both
+    // trees cached for the diff above.
+    let offset = current
+        .iter()
+        .find(|(_, node)| matches!(node.layer(), Layer::Offset(layer) if layer.offset().dy.0 > 0.0))
+        .map(|(id, _)| id)
+        .expect("inner offset layer");
+    *current.get_layer_mut(offset).expect("offset layer") =
+        Layer::Offset(OffsetLayer::from_xy(100.0, 100.0));
+
+    assert_eq!(
+        sorted(current.damage_region(&previous)),
+        vec![
+            rect(0.0, 100.0, 20.0, 120.0),
+            rect(100.0, 100.0, 120.0, 120.0)
+        ]
+    );
+}
+
+/// A single picture layer holding `list`, at fixed 0..40 bounds.
+fn picture_frame(list: DisplayList) -> LayerTree {
+    let mut tree = LayerTree::new();
+    let root = tree.insert(Layer::Picture(Box::new(PictureLayer::with_bounds(
+        list,
+        rect(0.0, 0.0, 40.0, 40.0),
+    ))));
+    tree.set_root(Some(root));
+    tree
+}
+
+fn recorded(color: Color) -> DisplayList {
+    let mut canvas = Canvas::new();
+    canvas.draw_rect(rect(0.0, 0.0, 40.0, 40.0), &Paint::fill(color));
+    canvas.finish()
+}
+
+#[test]
+fn test_rerecorded_picture_with_same_bounds_is_damaged() {
+    let red = recorded(Color::RED);
+    let previous = picture_frame(red.clone());
+
+    // A retained picture (same display list) is not damage…
+    assert!(picture_frame(red).damage_region(&previous).is_empty());
+
+    // …but new content with unchanged bounds is.
+    let current = picture_frame(recorded(Color::BLUE));
+    assert_eq!(
+        current.damage_region(&previous),
+        vec![rect(0.0, 0.0, 40.0, 40.0)]
+    );
+}
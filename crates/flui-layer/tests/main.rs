@@ -17,3 +17,5 @@
 mod layer_tree;
 #[path = "scene_builder.rs"]
 mod scene_builder;
+#[path = "texture_frames.rs"]
+mod texture_frames;
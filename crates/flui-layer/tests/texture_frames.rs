@@ -0,0 +1,106 @@
+//! Texture frame-available signals: a new video frame re-pushes only the
+//! texture layers showing it.
+
+use flui_layer::{
+    Layer, LayerTree, OffsetLayer, PictureLayer, SceneCompositor, TextureFormat, TextureLayer,
+};
+use flui_painting::DisplayList;
+use flui_types::{
+    geometry::{Rect, px},
+    painting::TextureId,
+};
+
+fn rect() -> Rect<flui_types::geometry::Pixels> {
+    Rect::from_xywh(px(0.0), px(0.0), px(320.0), px(180.0))
+}
+
+#[test]
+fn test_frame_available_marks_only_that_texture_layer() {
+    let video = TextureId::new(1);
+    let camera = TextureId::new(2);
+
+    let mut tree = LayerTree::new();
+    let root = tree.insert(Layer::Offset(OffsetLayer::zero()));
+    tree.set_root(Some(root));
+    let video_parent = tree.insert(Layer::Offset(OffsetLayer::from_xy(0.0, 200.0)));
+    tree.add_child(root, video_parent);
+    let video_layer = tree.insert(Layer::Texture(
+        TextureLayer::try_new(video, rect(), TextureFormat::Bgra8).expect("supported format"),
+    ));
+    tree.add_child(video_parent, video_layer);
+    let camera_layer = tree.insert(Layer::Texture(TextureLayer::new(camera, rect())));
+    tree.add_child(root, camera_layer);
+    let picture = tree.insert(Layer::Picture(Box::new(PictureLayer::with_bounds(
+        DisplayList::new(),
+        rect(),
+    ))));
+    tree.add_child(root, picture);
+    tree.clear_needs_add_to_scene_subtree(root);
+
+    let mut compositor = SceneCompositor::new();
+    let signal = compositor.texture_frames().frame_signal(video);
+    std::thread::spawn(move || signal.signal())
+        .join()
+        .expect("producer thread");
+
+    assert_eq!(compositor.flush_texture_frames(&tree), 1);
+
+    let dirty = |id| tree.get(id).expect("live layer").needs_add_to_scene();
+    assert!(dirty(video_layer));
+    // The path to the root is re-pushed so the compositor reaches the layer…
+    assert!(dirty(video_parent));
+    assert!(dirty(root));
+    // …but siblings stay clean.
+    assert!(!dirty(camera_layer));
+    assert!(!dirty(picture));
+
+    // Nothing pending on the next frame.
+    tree.clear_needs_add_to_scene_subtree(root);
+    assert_eq!(compositor.flush_texture_frames(&tree), 0);
+    assert!(!dirty(video_layer));
+}
+
+#[test]
+fn test_frame_available_damages_only_that_texture_rect() {
+    let video = TextureId::new(3);
+    let mut tree = LayerTree::new();
+    let root = tree.insert(Layer::Offset(OffsetLayer::zero()));
+    tree.set_root(Some(root));
+    let offset = tree.insert(Layer::Offset(OffsetLayer::from_xy(40.0, 200.0)));
+    tree.add_child(root, offset);
+    let video_layer = tree.insert(Layer::Texture(TextureLayer::new(video, rect())));
+    tree.add_child(offset, video_layer);
+    let picture = tree.insert(Layer::Picture(Box::new(PictureLayer::with_bounds(
+        DisplayList::new(),
+        Rect::from_xywh(px(0.0), px(0.0), px(800.0), px(600.0)),
+    ))));
+    tree.add_child(root, picture);
+    tree.clear_needs_add_to_scene_subtree(root);
+    assert!(tree.texture_frame_damage().is_empty());
+
+    let mut compositor = SceneCompositor::new();
+    compositor.texture_frames().signal_frame_available(video);
+    compositor.flush_texture_frames(&tree);
+
+    // The full-screen picture and the containers are not damage.
+    assert_eq!(
+        tree.texture_frame_damage(),
+        vec![Rect::from_xywh(px(40.0), px(200.0), px(320.0), px(180.0))]
+    );
+}
+
+#[test]
+fn test_frame_available_runs_registered_callback() {
+    let video = TextureId::new(5);
+    let tree = LayerTree::new();
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    let mut compositor = SceneCompositor::new();
+    compositor
+        .texture_frames_mut()
+        .on_frame_available(video, move |id| tx.send(id).expect("receiver alive"));
+    compositor.texture_frames().signal_frame_available(video);
+    compositor.flush_texture_frames(&tree);
+
+    assert_eq!(rx.try_recv(), Ok(video));
+}
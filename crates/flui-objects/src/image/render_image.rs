@@ -2,7 +2,7 @@
 //!
 //! Implements the RenderImage protocol object following Flutter's image.dart (22-404).
 //! Supports aspect-ratio preservation, fit modes (Fill/Contain/Cover/ScaleDown/None),
-//! and alignment.
+//! alignment, and nine-slice (`centerSlice`) scaling for resizable chrome.
 
 use flui_foundation::Diagnosticable;
 use flui_tree::Leaf;
@@ -116,6 +116,15 @@ pub struct RenderImage {
     fit: ImageFit,
     /// How to align the image within the box. Paint-only, like `fit`.
     alignment: ImageAlignment,
+    /// Nine-slice center, in image pixels (Flutter `RenderImage.centerSlice`).
+    /// When set, corners keep their size, edges stretch along one axis and
+    /// the center stretches both ways. Paint-only.
+    center_slice: Option<Rect>,
+    /// `center_slice` once validated against the current image's size:
+    /// `None` when unset, when there is no image, or when the slice is empty
+    /// or outside the image. Recomputed whenever the slice or the image
+    /// changes, so paint never re-validates.
+    valid_center_slice: Option<Rect>,
 }
 
 impl RenderImage {
@@ -130,6 +139,8 @@ pub fn new(intrinsic_size: Size, fit: ImageFit, alignment: ImageAlignment) -> Se
             scale: 1.0,
             fit,
             alignment,
+            center_slice: None,
+            valid_center_slice: None,
         }
     }
 
@@ -146,6 +157,8 @@ pub fn from_image(image: Image, fit: ImageFit, alignment: ImageAlignment) -> Sel
             scale: 1.0,
             fit,
             alignment,
+            center_slice: None,
+            valid_center_slice: None,
         }
     }
 
@@ -169,6 +182,19 @@ pub fn scale(&self) -> f32 {
         self.scale
     }
 
+    /// Returns the nine-slice center, if set.
+    pub fn center_slice(&self) -> Option<Rect> {
+        self.center_slice
+    }
+
+    /// Sets the nine-slice center (builder form). See
+    /// [`set_center_slice`](Self::set_center_slice).
+    #[must_use]
+    pub fn with_center_slice(mut self, center_slice: Option<Rect>) -> Self {
+        self.set_center_slice(center_slice);
+        self
+    }
+
     /// Sets the image source and updates the intrinsic size from its
     /// dimensions.
     ///
@@ -178,6 +204,7 @@ pub fn set_image(&mut self, image: Option<Image>) {
             self.intrinsic_size = img.size();
         }
         self.image = image;
+        self.validate_center_slice();
     }
 
     /// Sets the intrinsic (natural) size of the image.
@@ -220,6 +247,56 @@ pub fn set_scale(&mut self, scale: f32) {
         // Caller responsible for marking the node layout-dirty.
     }
 
+    /// Sets the nine-slice center, in image pixels (`None` draws the image
+    /// as a single stretched region).
+    ///
+    /// A slice that is empty or does not lie within the image bounds is kept
+    /// (it applies again once a large enough image is set) but ignored at
+    /// paint time — see [`nine_slice_regions`](Self::nine_slice_regions).
+    pub fn set_center_slice(&mut self, center_slice: Option<Rect>) {
+        self.center_slice = center_slice;
+        self.validate_center_slice();
+        // Caller responsible for marking repaint dirty
+    }
+
+    /// The nine `(source, destination)` region pairs the image is drawn as
+    /// in a box of `box_size`, row-major from the top-left corner — the
+    /// geometry the backend applies to the recorded nine-slice draw.
+    ///
+    /// `None` when no center slice is set, there is no image, nothing would
+    /// paint, or the slice is empty or not within the image bounds
+    /// (`0,0`–`image.size()`), in which case the image paints as one region.
+    pub fn nine_slice_regions(&self, box_size: Size) -> Option<[(Rect, Rect); 9]> {
+        let slice = self.valid_center_slice?;
+        let image_size = self.image.as_ref()?.size();
+        let dst = self.paint_rect_in(box_size)?;
+        Some(flui_painting::nine_slice_regions(image_size, slice, dst))
+    }
+
+    /// Recompute [`valid_center_slice`](Self::valid_center_slice) against the
+    /// current image, warning once if a set slice does not fit it.
+    fn validate_center_slice(&mut self) {
+        self.valid_center_slice = match (self.center_slice, &self.image) {
+            (Some(slice), Some(image)) => {
+                let image_size = image.size();
+                let bounds = Rect::from_origin_size(Point::ZERO, image_size);
+                let valid = slice.width().get() > 0.0
+                    && slice.height().get() > 0.0
+                    && bounds.contains_rect(&slice);
+                if !valid {
+                    tracing::warn!(
+                        ?slice,
+                        ?image_size,
+                        "RenderImage center_slice must be non-empty and lie within the image; \
+                         painting without nine-slice scaling",
+                    );
+                }
+                valid.then_some(slice)
+            }
+            _ => None,
+        };
+    }
+
     /// Computes the destination rectangle for the image content within a box
     /// of the given size, applying the fit mode (scaling) and alignment
     /// (positioning).
@@ -331,6 +408,9 @@ fn debug_fill_properties(&self, properties: &mut flui_foundation::DiagnosticsBui
         properties.add_default_double("scale", self.scale, 1.0, None);
         properties.add_enum("fit", self.fit);
         properties.add_enum("alignment", self.alignment);
+        if let Some(slice) = self.center_slice {
+            properties.add("center_slice", format!("{slice:?}"));
+        }
     }
 }
 
@@ -350,8 +430,14 @@ fn paint(&self, ctx: &mut PaintCx<'_, Leaf>) {
         // Apply fit + alignment to obtain the destination rect in local
         // coordinates (the recorder pre-translates to this node's origin).
         // The laid-out box size comes from RenderState via `ctx.size()`.
-        if let Some(dst) = self.paint_rect_in(ctx.size()) {
-            ctx.canvas().draw_image(image.clone(), dst, None);
+        let Some(dst) = self.paint_rect_in(ctx.size()) else {
+            return;
+        };
+        match self.valid_center_slice {
+            Some(slice) => ctx
+                .canvas()
+                .draw_image_nine_slice(image.clone(), slice, dst, None),
+            None => ctx.canvas().draw_image(image.clone(), dst, None),
         }
     }
 
@@ -723,6 +809,138 @@ fn test_paint_fill_covers_whole_box() {
         assert_eq!(dst.size().height, px(80.0));
     }
 
+    // ===== center slice (nine-patch) =====
+
+    /// 30x30 button image with a 10px border on every side.
+    fn button_image_with_slice() -> RenderImage {
+        RenderImage::from_image(
+            Image::from_rgba8(30, 30, vec![255; 30 * 30 * 4]),
+            ImageFit::Fill,
+            ImageAlignment::TopLeft,
+        )
+        .with_center_slice(Some(Rect::from_ltrb(
+            px(10.0),
+            px(10.0),
+            px(20.0),
+            px(20.0),
+        )))
+    }
+
+    /// Paints `image` into `box_size` and returns the recorded
+    /// DrawImageNineSlice commands as (center_slice, dst).
+    fn capture_nine_slices(image: &RenderImage, box_size: Size) -> Vec<(Rect, Rect)> {
+        let mut rec = FragmentRecorder::new(Offset::ZERO, 1.0);
+        {
+            let mut cx = PaintCx::<Leaf>::new(&mut rec, 0, box_size);
+            image.paint(&mut cx);
+        }
+        let frag = rec.finish();
+        let mut out = Vec::new();
+        for op in frag.ops() {
+            if let flui_rendering::context::FragmentOp::Run(list) = op {
+                for cmd in list.commands() {
+                    if let DrawCommand::DrawImageNineSlice {
+                        center_slice, dst, ..
+                    } = cmd
+                    {
+                        out.push((*center_slice, *dst));
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn center_slice_records_a_nine_slice_draw_over_the_fit_rect() {
+        let image = button_image_with_slice();
+        let box_size = Size::new(px(200.0), px(60.0));
+
+        let slices = capture_nine_slices(&image, box_size);
+        assert_eq!(slices.len(), 1, "expected exactly one DrawImageNineSlice");
+        assert!(
+            capture_draw_images(&image, box_size).is_empty(),
+            "a valid slice replaces the plain DrawImage"
+        );
+        let (slice, dst) = slices[0];
+        assert_eq!(
+            slice,
+            Rect::from_ltrb(px(10.0), px(10.0), px(20.0), px(20.0))
+        );
+        assert_eq!(dst, Rect::from_ltrb(px(0.0), px(0.0), px(200.0), px(60.0)));
+    }
+
+    #[test]
+    fn center_slice_preserves_corners_when_scaled_up() {
+        let image = button_image_with_slice();
+        let regions = image
+            .nine_slice_regions(Size::new(px(200.0), px(60.0)))
+            .expect("valid slice");
+
+        for corner in [0, 2, 6, 8] {
+            let (src, dst) = regions[corner];
+            assert_eq!(dst.size(), src.size(), "corner {corner} must keep 10x10");
+        }
+        // Edges stretch along one axis, the center along both.
+        assert_eq!(regions[1].1.size(), Size::new(px(180.0), px(10.0)));
+        assert_eq!(regions[3].1.size(), Size::new(px(10.0), px(40.0)));
+        assert_eq!(regions[4].1.size(), Size::new(px(180.0), px(40.0)));
+        assert_eq!(
+            regions[8].1,
+            Rect::from_ltrb(px(190.0), px(50.0), px(200.0), px(60.0))
+        );
+    }
+
+    #[test]
+    fn center_slice_outside_the_image_falls_back_to_a_plain_draw() {
+        let image = button_image_with_slice().with_center_slice(Some(Rect::from_ltrb(
+            px(10.0),
+            px(10.0),
+            px(40.0),
+            px(20.0),
+        )));
+        let box_size = Size::new(px(200.0), px(60.0));
+
+        assert!(image.nine_slice_regions(box_size).is_none());
+        assert!(capture_nine_slices(&image, box_size).is_empty());
+        assert_eq!(capture_draw_images(&image, box_size).len(), 1);
+    }
+
+    #[test]
+    fn empty_center_slice_is_ignored() {
+        let image = button_image_with_slice().with_center_slice(Some(Rect::from_ltrb(
+            px(10.0),
+            px(10.0),
+            px(10.0),
+            px(20.0),
+        )));
+        let box_size = Size::new(px(100.0), px(100.0));
+
+        assert!(capture_nine_slices(&image, box_size).is_empty());
+        assert_eq!(capture_draw_images(&image, box_size).len(), 1);
+    }
+
+    #[test]
+    fn center_slice_is_revalidated_when_the_image_changes() {
+        let slice = Rect::from_ltrb(px(10.0), px(10.0), px(40.0), px(40.0));
+        let mut image = button_image_with_slice().with_center_slice(Some(slice));
+        let box_size = Size::new(px(100.0), px(100.0));
+        assert!(
+            image.nine_slice_regions(box_size).is_none(),
+            "30x30 is too small"
+        );
+
+        image.set_image(Some(Image::from_rgba8(50, 50, vec![255; 50 * 50 * 4])));
+        assert_eq!(capture_nine_slices(&image, box_size).len(), 1);
+
+        // Validation follows the image, not a placeholder intrinsic size.
+        image.set_intrinsic_size(Size::new(px(20.0), px(20.0)));
+        assert_eq!(capture_nine_slices(&image, box_size).len(), 1);
+
+        image.set_image(None);
+        assert!(image.nine_slice_regions(box_size).is_none());
+    }
+
     // ===== width / height / scale folding + intrinsics + dry layout =====
 
     use flui_rendering::context::intrinsics_test_support::{leaf_dry_layout, leaf_intrinsics};
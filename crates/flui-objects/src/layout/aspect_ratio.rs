@@ -19,10 +19,10 @@
 //!   `isFinite` checks on raw doubles.
 
 use flui_tree::Single;
-use flui_types::{Offset, Pixels, Size, geometry::px};
+use flui_types::{Offset, Size};
 
 use flui_rendering::{
-    constraints::{BoxConstraints, Constraints},
+    constraints::BoxConstraints,
     context::{BoxHitTestContext, BoxLayoutContext},
     parent_data::BoxParentData,
     traits::RenderBox,
@@ -190,43 +190,7 @@ fn apply_aspect_ratio(&self, constraints: BoxConstraints) -> Size {
             return Size::ZERO;
         }
 
-        // Tight constraints — the size is fully determined; the ratio is
-        // honoured by the parent before we get here.
-        if constraints.is_tight() {
-            return constraints.smallest();
-        }
-
-        let ratio = self.aspect_ratio.value();
-
-        let mut width = constraints.max_width;
-        let mut height: Pixels;
-
-        if width.get().is_finite() {
-            height = px(width.get() / ratio);
-        } else {
-            height = constraints.max_height;
-            width = px(height.get() * ratio);
-        }
-
-        // Bias toward inflexibility: check tighter bounds first.
-        if width > constraints.max_width {
-            width = constraints.max_width;
-            height = px(width.get() / ratio);
-        }
-        if height > constraints.max_height {
-            height = constraints.max_height;
-            width = px(height.get() * ratio);
-        }
-        if width < constraints.min_width {
-            width = constraints.min_width;
-            height = px(width.get() / ratio);
-        }
-        if height < constraints.min_height {
-            height = constraints.min_height;
-            width = px(height.get() * ratio);
-        }
-
-        constraints.constrain(Size::new(width, height))
+        constraints.constrain_dimensions_respecting_aspect_ratio(self.aspect_ratio.value())
     }
 }
 
@@ -366,6 +330,8 @@ fn compute_dry_baseline(
 
 #[cfg(test)]
 mod tests {
+    use flui_types::geometry::px;
+
     use super::*;
 
     fn bc(min_w: f32, max_w: f32, min_h: f32, max_h: f32) -> BoxConstraints {
@@ -0,0 +1,73 @@
+//! Debug-build overflow marker — the yellow/black warning tape a layout
+//! object paints over the edge its children ran past.
+//!
+//! Flutter parity: `rendering/debug_overflow_indicator.dart`
+//! `DebugOverflowIndicatorMixin.paintOverflowIndicator`, reduced to a single
+//! striped band along the overflowing edge (no label text). Compiled only
+//! under `debug_assertions`; release builds carry no marker code at all.
+
+use flui_painting::{Canvas, Paint};
+use flui_types::{Color, Point, Rect, Size, geometry::px};
+
+/// Thickness of the striped band, in logical pixels.
+const MARKER_EXTENT: f32 = 8.0;
+
+/// Spacing between consecutive black stripes.
+const STRIPE_PERIOD: f32 = 12.0;
+
+/// Flutter's `_black` / `_yellow` at the same 0xBF alpha.
+const TAPE_BLACK: Color = Color::rgba(0, 0, 0, 0xBF);
+const TAPE_YELLOW: Color = Color::rgba(0xFF, 0xFF, 0x00, 0xBF);
+
+/// The edge of a box an overflow ran past.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OverflowEdge {
+    /// Children extend past the right edge.
+    Right,
+    /// Children extend past the bottom edge.
+    Bottom,
+}
+
+/// Paints the warning tape along `edge` of a box of `size`, in the box's
+/// local coordinates.
+pub(crate) fn paint_overflow_indicator(canvas: &mut Canvas, size: Size, edge: OverflowEdge) {
+    let width = size.width.get();
+    let height = size.height.get();
+    let marker = match edge {
+        OverflowEdge::Right => Rect::from_ltrb(
+            px((width - MARKER_EXTENT).max(0.0)),
+            px(0.0),
+            px(width),
+            px(height),
+        ),
+        OverflowEdge::Bottom => Rect::from_ltrb(
+            px(0.0),
+            px((height - MARKER_EXTENT).max(0.0)),
+            px(width),
+            px(height),
+        ),
+    };
+    if marker.width() <= px(0.0) || marker.height() <= px(0.0) {
+        return;
+    }
+
+    canvas.draw_rect(marker, &Paint::fill(TAPE_YELLOW));
+
+    // 45° stripes across the band, clipped so they never leave it.
+    let stripe = Paint::stroke(TAPE_BLACK, STRIPE_PERIOD / 3.0);
+    let left = marker.left().get();
+    let top = marker.top().get();
+    let band_w = marker.width().get();
+    let band_h = marker.height().get();
+    canvas.with_clip_rect(marker, |canvas| {
+        let mut start = -band_h;
+        while start < band_w {
+            canvas.draw_line(
+                Point::new(px(left + start), px(top + band_h)),
+                Point::new(px(left + start + band_h), px(top)),
+                &stripe,
+            );
+            start += STRIPE_PERIOD;
+        }
+    });
+}
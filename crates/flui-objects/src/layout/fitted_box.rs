@@ -24,13 +24,16 @@
 //! * `has_visual_overflow()` is a public post-layout query method
 //!   (Flutter keeps the equivalent flag private and only consults it
 //!   internally for clip-decision branching).
-//! * `clip_behavior` is stored and surfaced via the diagnosticable
-//!   dump; **active clipping awaits the layer-level clip integration
-//!   that lands in Wave 3b** (alongside `RenderRepaintBoundary`).
-//!   The default `Clip::None` matches the no-clip behaviour exactly,
-//!   so configurations that need clipping must wait or opt into the
-//!   in-progress wiring. This is documented intentionally — same
-//!   defer pattern as Wave 3a's `RenderClipPath::contains`.
+//! * `clip_behavior` clips the child to the box whenever the fit crops
+//!   it (`has_visual_overflow()`), through a `ClipRect` scope recorded in
+//!   `paint`. The pipeline wraps the whole node fragment in the
+//!   `RenderFittedBox::effective_transform` layer, so the clip rect is the
+//!   box's own bounds mapped back into child space through the inverse.
+//! * `filter_quality` is stored and surfaced via the diagnosticable dump.
+//!   Flutter's `Transform.filterQuality` swaps the transform layer for an
+//!   `ImageFilter.matrix`; FLUI's `TransformLayer` does not carry a
+//!   sampling mode yet, so `None` (a plain transform) is the only rendered
+//!   behaviour until the layer grows one.
 //!
 //! # Divergence found and fixed (widget-parity port, `parity/fitted_box_test.rs`)
 //!
@@ -68,10 +71,10 @@
 
 use flui_tree::Single;
 use flui_types::{
-    Alignment, Matrix4, Offset, Size,
+    Alignment, Matrix4, Offset, Rect, Size,
     geometry::px,
     layout::{BoxFit, FittedSizes},
-    painting::Clip,
+    painting::{Clip, FilterQuality},
 };
 
 use flui_rendering::{
@@ -91,6 +94,9 @@ pub struct RenderFittedBox {
     fit: BoxFit,
     alignment: Alignment,
     clip_behavior: Clip,
+    /// Sampling quality for the scaled child; `None` paints through a plain
+    /// transform (Flutter's default).
+    filter_quality: Option<FilterQuality>,
     has_child: bool,
     /// Cached scale factors derived in layout, consumed by
     /// [`RenderBox::paint_transform`].
@@ -114,6 +120,7 @@ pub const fn new(fit: BoxFit, alignment: Alignment, clip_behavior: Clip) -> Self
             fit,
             alignment,
             clip_behavior,
+            filter_quality: None,
             has_child: false,
             scale_x: 1.0,
             scale_y: 1.0,
@@ -141,6 +148,12 @@ pub fn clip_behavior(&self) -> Clip {
         self.clip_behavior
     }
 
+    /// Returns the sampling quality requested for the scaled child.
+    #[inline]
+    pub fn filter_quality(&self) -> Option<FilterQuality> {
+        self.filter_quality
+    }
+
     /// Returns whether the scaled child overflowed the box at the last
     /// layout. Reset on every layout.
     #[inline]
@@ -221,6 +234,13 @@ pub const fn with_clip_behavior(mut self, clip_behavior: Clip) -> Self {
         self
     }
 
+    /// Builder: set the sampling quality for the scaled child.
+    #[must_use]
+    pub const fn with_filter_quality(mut self, filter_quality: Option<FilterQuality>) -> Self {
+        self.filter_quality = filter_quality;
+        self
+    }
+
     /// Updates the fit; returns true if the value changed.
     pub fn set_fit(&mut self, fit: BoxFit) -> bool {
         if self.fit == fit {
@@ -248,6 +268,25 @@ pub fn set_clip_behavior(&mut self, clip_behavior: Clip) -> bool {
         true
     }
 
+    /// Updates the sampling quality; returns true if the value changed.
+    pub fn set_filter_quality(&mut self, filter_quality: Option<FilterQuality>) -> bool {
+        if self.filter_quality == filter_quality {
+            return false;
+        }
+        self.filter_quality = filter_quality;
+        true
+    }
+
+    /// The box's own bounds expressed in child space — the clip rect `paint`
+    /// records, since the pipeline applies [`Self::effective_transform`] to
+    /// the whole node fragment. `None` when the transform is degenerate.
+    fn clip_rect_in_child_space(&self, size: Size) -> Option<Rect> {
+        let inverse = self.effective_transform().try_inverse()?;
+        let (left, top) = inverse.transform_point(px(0.0), px(0.0));
+        let (right, bottom) = inverse.transform_point(size.width, size.height);
+        Some(Rect::from_ltrb(left, top, right, bottom))
+    }
+
     /// Maps an alignment scalar in [-1, 1] to a position in [0, free].
     #[inline]
     fn align_axis(component: f32, free: f32) -> f32 {
@@ -305,6 +344,9 @@ fn debug_fill_properties(&self, builder: &mut flui_foundation::DiagnosticsBuilde
             format!("({}, {})", self.alignment.x, self.alignment.y),
         );
         builder.add_enum("clip_behavior", self.clip_behavior);
+        if let Some(filter_quality) = self.filter_quality {
+            builder.add_enum("filter_quality", filter_quality);
+        }
     }
 }
 
@@ -431,6 +473,22 @@ fn compute_dry_baseline(
         }
     }
 
+    // Closure is load-bearing: `PaintCx::paint_child` is ambiguous as a method
+    // path (Single's zero-arg overload vs the indexed variant on other
+    // arities).
+    #[allow(clippy::redundant_closure_for_method_calls)]
+    fn paint(&self, ctx: &mut flui_rendering::context::PaintCx<'_, Single>) {
+        // Flutter `RenderFittedBox.paint`: clip only when the fit actually
+        // cropped the child and a clip was asked for.
+        let clip_rect = (self.has_visual_overflow && self.clip_behavior != Clip::None)
+            .then(|| self.clip_rect_in_child_space(ctx.size()))
+            .flatten();
+        match clip_rect {
+            Some(rect) => ctx.with_clip_rect(rect, self.clip_behavior, |ctx| ctx.paint_child()),
+            None => ctx.paint_child(),
+        }
+    }
+
     fn hit_test(&self, ctx: &mut BoxHitTestContext<'_, Single, BoxParentData>) -> bool {
         if !ctx.is_within_own_size() {
             return false;
@@ -521,6 +579,9 @@ fn setters_return_change_flag() {
         assert!(!node.set_fit(BoxFit::Fill));
         assert!(node.set_alignment(Alignment::TOP_LEFT));
         assert!(node.set_clip_behavior(Clip::AntiAlias));
+        assert_eq!(node.filter_quality(), None);
+        assert!(node.set_filter_quality(Some(FilterQuality::High)));
+        assert!(!node.set_filter_quality(Some(FilterQuality::High)));
     }
 
     #[test]
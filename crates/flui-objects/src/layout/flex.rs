@@ -3,10 +3,13 @@
 use flui_tree::Variable;
 use flui_types::{Offset, Pixels, Size, geometry::px};
 
+#[cfg(debug_assertions)]
+use super::debug_overflow::{OverflowEdge, paint_overflow_indicator};
 use flui_rendering::{
     constraints::BoxConstraints,
     context::{
         BoxDryBaselineCtx, BoxDryLayoutCtx, BoxHitTestContext, BoxIntrinsicsCtx, BoxLayoutContext,
+        PaintCx,
     },
     parent_data::{FlexFit, FlexParentData},
     traits::{RenderBox, TextBaseline},
@@ -132,6 +135,13 @@ pub struct RenderFlex {
     /// (`shifted_box.rs:138-141`).  Reset to `[None; 2]` on layout when no
     /// children are present or none report a baseline.
     reported_baselines: [Option<f32>; 2],
+    /// How far the children's combined main-axis extent (spacing included)
+    /// ran past the container's main extent in the last `perform_layout`;
+    /// `0.0` when they fit.
+    ///
+    /// Flutter parity: `RenderFlex._overflow` (`flex.dart`), recorded as
+    /// `max(0, -freeSpace)`.
+    overflow: f32,
 }
 
 impl Default for RenderFlex {
@@ -145,6 +155,7 @@ fn default() -> Self {
             spacing: 0.0,
             child_count: 0,
             reported_baselines: [None; 2],
+            overflow: 0.0,
         }
     }
 }
@@ -216,6 +227,21 @@ pub fn is_vertical(&self) -> bool {
         self.direction == FlexDirection::Vertical
     }
 
+    /// How far the children overflowed the main axis in the last layout, in
+    /// logical pixels — `0.0` when they fit.
+    ///
+    /// An over-stuffed row (inflexible children wider than the row's max
+    /// width) reports the excess here; debug builds also paint a warning tape
+    /// along the overflowing edge.
+    pub fn overflow(&self) -> f32 {
+        self.overflow
+    }
+
+    /// Returns true if the last layout overflowed the main axis.
+    pub fn has_overflow(&self) -> bool {
+        self.overflow > 0.0
+    }
+
     /// Extracts main axis extent from a size.
     fn main_size(&self, size: Size) -> Pixels {
         match self.direction {
@@ -632,6 +658,10 @@ fn perform_layout(&mut self, ctx: &mut BoxLayoutContext<'_, Variable, FlexParent
             ctx.layout_child(i, c)
         });
 
+        self.overflow = (flex_sizes.total_main - self.main_size(flex_sizes.size))
+            .max(Pixels::ZERO)
+            .get();
+
         // Zero-child case: no positioning loop needed.
         if child_count == 0 {
             self.reported_baselines = [None; 2];
@@ -887,7 +917,24 @@ fn compute_max_intrinsic_height(&self, width: f32, ctx: &mut BoxIntrinsicsCtx<'_
         }
     }
 
-    // paint() uses default no-op - Flex just positions children
+    /// Paints children in order; debug builds then mark an overflowing flex
+    /// with warning tape along its trailing main-axis edge.
+    ///
+    /// Flutter parity: `RenderFlex.paint` → `paintOverflowIndicator` under
+    /// `assert`. Children are not clipped (`Clip.none` default).
+    fn paint(&self, ctx: &mut PaintCx<'_, Variable>) {
+        ctx.paint_children_in_order();
+
+        #[cfg(debug_assertions)]
+        if self.has_overflow() {
+            let edge = match self.direction {
+                FlexDirection::Horizontal => OverflowEdge::Right,
+                FlexDirection::Vertical => OverflowEdge::Bottom,
+            };
+            let size = ctx.size();
+            paint_overflow_indicator(ctx.canvas(), size, edge);
+        }
+    }
 
     fn hit_test(&self, ctx: &mut BoxHitTestContext<'_, Variable, FlexParentData>) -> bool {
         if !ctx.is_within_own_size() {
@@ -6,6 +6,8 @@
 mod constrained_box;
 mod custom_multi_child_layout;
 mod custom_single_child_layout;
+#[cfg(debug_assertions)]
+mod debug_overflow;
 mod fitted_box;
 mod flex;
 mod flow;
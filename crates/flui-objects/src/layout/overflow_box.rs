@@ -79,6 +79,9 @@ pub struct RenderConstrainedOverflowBox {
     fit: OverflowBoxFit,
     /// Handles child alignment and hit-testing.
     inner: AligningShiftedBox,
+    /// Per-axis amount the child's size exceeded this box's own size in the
+    /// last `perform_layout`; zero on an axis where the child fit.
+    overflow: Size,
 }
 
 impl RenderConstrainedOverflowBox {
@@ -98,6 +101,7 @@ pub fn new(
             max_height,
             fit,
             inner: AligningShiftedBox::new(alignment),
+            overflow: Size::ZERO,
         }
     }
 
@@ -113,6 +117,16 @@ pub fn centered() -> Self {
         )
     }
 
+    /// How far the child's laid-out size exceeded this box's own size, per
+    /// axis, in the last layout — the amount the parent's constraints were
+    /// clamped away from the child.
+    ///
+    /// Reported for inspection only: overflowing is this box's purpose, so no
+    /// debug overflow indicator is painted (Flutter parity).
+    pub fn overflow(&self) -> Size {
+        self.overflow
+    }
+
     // --- setters that return a change flag -----------------------------------
 
     /// Replaces the child alignment.
@@ -220,6 +234,7 @@ fn perform_layout(&mut self, ctx: &mut BoxLayoutContext<'_, Single, BoxParentDat
 
         if ctx.child_count() == 0 {
             self.inner.clear_child_baselines();
+            self.overflow = Size::ZERO;
             return match self.fit {
                 OverflowBoxFit::Max => constraints.biggest(),
                 OverflowBoxFit::DeferToChild => constraints.smallest(),
@@ -229,6 +244,10 @@ fn perform_layout(&mut self, ctx: &mut BoxLayoutContext<'_, Single, BoxParentDat
         let inner_constraints = self.inner_constraints(constraints);
         let child_size = ctx.layout_child(0, inner_constraints);
         let our_size = self.parent_size(constraints, child_size);
+        self.overflow = Size::new(
+            (child_size.width - our_size.width).max(Pixels::ZERO),
+            (child_size.height - our_size.height).max(Pixels::ZERO),
+        );
 
         self.inner.align_child(ctx, our_size, child_size);
         self.inner.record_child_baselines(ctx);
@@ -1,7 +1,9 @@
 //! RenderPadding - adds padding around a single child.
 
 use flui_tree::Single;
-use flui_types::{EdgeInsets, Offset, Pixels, Size, geometry::px};
+use flui_types::{
+    EdgeInsets, EdgeInsetsGeometry, Offset, Pixels, Size, geometry::px, typography::TextDirection,
+};
 
 use flui_rendering::{
     constraints::BoxConstraints,
@@ -12,17 +14,26 @@
 
 /// A render object that adds padding around its child.
 ///
+/// The padding is an [`EdgeInsetsGeometry`]: absolute [`EdgeInsets`] are used
+/// as-is, directional insets are resolved against
+/// [`text_direction`](Self::text_direction) (default LTR) at layout time.
+///
 /// # Example
 ///
 /// ```ignore
 /// let padding = RenderPadding::new(EdgeInsets::all(16.0));
 /// let mut wrapper = BoxWrapper::new(padding);
 /// // Add child, then layout...
+///
+/// let mut rtl = RenderPadding::new(EdgeInsetsDirectional::only_start(px(8.0)));
+/// rtl.set_text_direction(TextDirection::Rtl); // 8px on the right
 /// ```
 #[derive(Debug, Clone)]
 pub struct RenderPadding {
     /// The padding to apply.
-    padding: EdgeInsets,
+    padding: EdgeInsetsGeometry,
+    /// The direction directional padding is resolved against.
+    text_direction: TextDirection,
     /// Whether we have a child (tracked for hit testing).
     has_child: bool,
     /// Child offset for hit testing.
@@ -31,9 +42,10 @@ pub struct RenderPadding {
 
 impl RenderPadding {
     /// Creates a new padding render object.
-    pub fn new(padding: EdgeInsets) -> Self {
+    pub fn new(padding: impl Into<EdgeInsetsGeometry>) -> Self {
         Self {
-            padding,
+            padding: padding.into(),
+            text_direction: TextDirection::Ltr,
             has_child: false,
             child_offset: Offset::ZERO,
         }
@@ -49,20 +61,37 @@ pub fn symmetric(horizontal: f32, vertical: f32) -> Self {
         Self::new(EdgeInsets::symmetric(px(vertical), px(horizontal)))
     }
 
-    /// Returns the padding.
-    pub fn padding(&self) -> EdgeInsets {
+    /// Returns the padding, as configured (possibly directional).
+    pub fn padding(&self) -> EdgeInsetsGeometry {
         self.padding
     }
 
     /// Sets the padding.
-    pub fn set_padding(&mut self, padding: EdgeInsets) {
-        self.padding = padding;
+    pub fn set_padding(&mut self, padding: impl Into<EdgeInsetsGeometry>) {
+        self.padding = padding.into();
+    }
+
+    /// Returns the direction directional padding is resolved against.
+    pub fn text_direction(&self) -> TextDirection {
+        self.text_direction
+    }
+
+    /// Sets the direction directional padding is resolved against. Has no
+    /// effect on absolute padding.
+    pub fn set_text_direction(&mut self, text_direction: TextDirection) {
+        self.text_direction = text_direction;
+    }
+
+    /// Returns the padding resolved to absolute insets.
+    pub fn resolved_padding(&self) -> EdgeInsets {
+        self.padding.resolve(self.text_direction)
     }
 
     /// Deflates constraints by padding amount.
     fn deflate_constraints(&self, constraints: &BoxConstraints) -> BoxConstraints {
-        let horizontal = self.padding.horizontal_total();
-        let vertical = self.padding.vertical_total();
+        let padding = self.resolved_padding();
+        let horizontal = padding.horizontal_total();
+        let vertical = padding.vertical_total();
 
         BoxConstraints::new(
             (constraints.min_width - horizontal).max(Pixels::ZERO),
@@ -76,6 +105,9 @@ fn deflate_constraints(&self, constraints: &BoxConstraints) -> BoxConstraints {
 impl flui_foundation::Diagnosticable for RenderPadding {
     fn debug_fill_properties(&self, properties: &mut flui_foundation::DiagnosticsBuilder) {
         properties.add_enum("padding", self.padding);
+        if self.padding.is_directional() {
+            properties.add_enum("text_direction", self.text_direction);
+        }
     }
 }
 impl RenderBox for RenderPadding {
@@ -83,6 +115,7 @@ impl RenderBox for RenderPadding {
     type ParentData = BoxParentData;
 
     fn perform_layout(&mut self, ctx: &mut BoxLayoutContext<'_, Single, BoxParentData>) -> Size {
+        let padding = self.resolved_padding();
         let constraints = *ctx.constraints();
 
         let size = if ctx.child_count() > 0 {
@@ -93,21 +126,18 @@ fn perform_layout(&mut self, ctx: &mut BoxLayoutContext<'_, Single, BoxParentDat
             let child_size = ctx.layout_child(0, child_constraints);
 
             // Position child with top-left padding offset
-            self.child_offset = Offset::new(self.padding.left, self.padding.top);
+            self.child_offset = Offset::new(padding.left, padding.top);
             ctx.position_child(0, self.child_offset);
 
             // Our size is child size + padding
             Size::new(
-                child_size.width + self.padding.horizontal_total(),
-                child_size.height + self.padding.vertical_total(),
+                child_size.width + padding.horizontal_total(),
+                child_size.height + padding.vertical_total(),
             )
         } else {
             self.has_child = false;
             // No child - just the padding itself
-            Size::new(
-                self.padding.horizontal_total(),
-                self.padding.vertical_total(),
-            )
+            Size::new(padding.horizontal_total(), padding.vertical_total())
         };
 
         // Constrain to parent's constraints
@@ -119,11 +149,12 @@ fn compute_min_intrinsic_width(
         height: f32,
         ctx: &mut flui_rendering::context::BoxIntrinsicsCtx<'_>,
     ) -> f32 {
-        let deflated_height = (height - self.padding.vertical_total().get()).max(0.0);
+        let padding = self.resolved_padding();
+        let deflated_height = (height - padding.vertical_total().get()).max(0.0);
         if ctx.child_count() == 0 {
-            return self.padding.horizontal_total().get();
+            return padding.horizontal_total().get();
         }
-        ctx.child_min_intrinsic_width(0, deflated_height) + self.padding.horizontal_total().get()
+        ctx.child_min_intrinsic_width(0, deflated_height) + padding.horizontal_total().get()
     }
 
     fn compute_max_intrinsic_width(
@@ -131,11 +162,12 @@ fn compute_max_intrinsic_width(
         height: f32,
         ctx: &mut flui_rendering::context::BoxIntrinsicsCtx<'_>,
     ) -> f32 {
-        let deflated_height = (height - self.padding.vertical_total().get()).max(0.0);
+        let padding = self.resolved_padding();
+        let deflated_height = (height - padding.vertical_total().get()).max(0.0);
         if ctx.child_count() == 0 {
-            return self.padding.horizontal_total().get();
+            return padding.horizontal_total().get();
         }
-        ctx.child_max_intrinsic_width(0, deflated_height) + self.padding.horizontal_total().get()
+        ctx.child_max_intrinsic_width(0, deflated_height) + padding.horizontal_total().get()
     }
 
     fn compute_min_intrinsic_height(
@@ -143,11 +175,12 @@ fn compute_min_intrinsic_height(
         width: f32,
         ctx: &mut flui_rendering::context::BoxIntrinsicsCtx<'_>,
     ) -> f32 {
-        let deflated_width = (width - self.padding.horizontal_total().get()).max(0.0);
+        let padding = self.resolved_padding();
+        let deflated_width = (width - padding.horizontal_total().get()).max(0.0);
         if ctx.child_count() == 0 {
-            return self.padding.vertical_total().get();
+            return padding.vertical_total().get();
         }
-        ctx.child_min_intrinsic_height(0, deflated_width) + self.padding.vertical_total().get()
+        ctx.child_min_intrinsic_height(0, deflated_width) + padding.vertical_total().get()
     }
 
     fn compute_max_intrinsic_height(
@@ -155,11 +188,12 @@ fn compute_max_intrinsic_height(
         width: f32,
         ctx: &mut flui_rendering::context::BoxIntrinsicsCtx<'_>,
     ) -> f32 {
-        let deflated_width = (width - self.padding.horizontal_total().get()).max(0.0);
+        let padding = self.resolved_padding();
+        let deflated_width = (width - padding.horizontal_total().get()).max(0.0);
         if ctx.child_count() == 0 {
-            return self.padding.vertical_total().get();
+            return padding.vertical_total().get();
         }
-        ctx.child_max_intrinsic_height(0, deflated_width) + self.padding.vertical_total().get()
+        ctx.child_max_intrinsic_height(0, deflated_width) + padding.vertical_total().get()
     }
 
     fn compute_dry_layout(
@@ -167,17 +201,18 @@ fn compute_dry_layout(
         constraints: BoxConstraints,
         ctx: &mut flui_rendering::context::BoxDryLayoutCtx<'_>,
     ) -> Size {
+        let padding = self.resolved_padding();
         if ctx.child_count() == 0 {
             return constraints.constrain(Size::new(
-                self.padding.horizontal_total(),
-                self.padding.vertical_total(),
+                padding.horizontal_total(),
+                padding.vertical_total(),
             ));
         }
         let child_constraints = self.deflate_constraints(&constraints);
         let child_size = ctx.child_dry_layout(0, child_constraints);
         constraints.constrain(Size::new(
-            child_size.width + self.padding.horizontal_total(),
-            child_size.height + self.padding.vertical_total(),
+            child_size.width + padding.horizontal_total(),
+            child_size.height + padding.vertical_total(),
         ))
     }
 
@@ -187,12 +222,13 @@ fn compute_dry_baseline(
         baseline: flui_rendering::traits::TextBaseline,
         ctx: &mut flui_rendering::context::BoxDryBaselineCtx<'_>,
     ) -> Option<f32> {
+        let padding = self.resolved_padding();
         if ctx.child_count() == 0 {
             return None;
         }
         let child_constraints = self.deflate_constraints(&constraints);
         let child_baseline = ctx.child_dry_baseline(0, child_constraints, baseline)?;
-        Some(child_baseline + self.padding.top.get())
+        Some(child_baseline + padding.top.get())
     }
 
     // paint() uses default no-op - Padding just positions children
@@ -214,6 +250,7 @@ fn hit_test(&self, ctx: &mut BoxHitTestContext<'_, Single, BoxParentData>) -> bo
 mod tests {
     use super::*;
     use flui_rendering::constraints::BoxConstraints;
+    use flui_types::EdgeInsetsDirectional;
 
     #[test]
     fn test_edge_insets() {
@@ -228,7 +265,25 @@ fn test_edge_insets() {
     #[test]
     fn test_padding_creation() {
         let padding = RenderPadding::all(16.0);
-        assert_eq!(padding.padding(), EdgeInsets::all(px(16.0)));
+        assert_eq!(padding.padding(), EdgeInsets::all(px(16.0)).into());
+        assert_eq!(padding.resolved_padding(), EdgeInsets::all(px(16.0)));
+    }
+
+    #[test]
+    fn test_directional_padding_resolves_with_text_direction() {
+        let mut padding = RenderPadding::new(EdgeInsetsDirectional::from_steb(
+            px(8.0),
+            px(0.0),
+            px(4.0),
+            px(0.0),
+        ));
+        assert_eq!(padding.text_direction(), TextDirection::Ltr);
+        let ltr = padding.resolved_padding();
+        assert_eq!((ltr.left, ltr.right), (px(8.0), px(4.0)));
+
+        padding.set_text_direction(TextDirection::Rtl);
+        let rtl = padding.resolved_padding();
+        assert_eq!((rtl.left, rtl.right), (px(4.0), px(8.0)));
     }
 
     #[test]
@@ -26,10 +26,9 @@
 //! parity. [`RenderEditable::rect_for_composing_range`] exposes the same
 //! geometry as a bounding rect for the IME cursor-area loop (ADR-0032), with
 //! Flutter's own caret-rect fallback order (`_updateComposingRectIfNeeded`,
-//! `editable_text.dart`, tag `3.44.0`). Single-line only: box-to-byte-range
-//! mapping (`get_boxes_for_range`) compares a global byte range against
-//! per-run glyph indices, which is only correct while this object is
-//! constrained to one line (`max_lines(1)`) — revisit when multiline lands.
+//! `editable_text.dart`, tag `3.44.0`). The byte range is global to the
+//! text: `get_boxes_for_range` rebases each line's glyph indices, so the
+//! geometry stays correct once this object grows past one line.
 
 use std::ops::Range;
 
@@ -8,15 +8,20 @@
 //! dry layout, the four intrinsics, baseline, and paint — with `softWrap`,
 //! `maxLines`, and ellipsis truncation.
 //!
+//! Selection geometry is exposed as queries
+//! ([`RenderParagraph::get_boxes_for_selection`],
+//! [`RenderParagraph::get_position_for_offset`]); painting a highlight is
+//! left to the caller.
+//!
 //! Out of scope for this object (separable per Flutter's own structure):
-//! inline `WidgetSpan` children, text selection, semantics, and the
+//! inline `WidgetSpan` children, selection painting, semantics, and the
 //! clip/fade `TextOverflow` policies (only `ellipsis` is wired here).
 
 use flui_foundation::Diagnosticable;
 use flui_painting::{Invalidation, TextBaseline as PainterBaseline, TextPainter};
 use flui_tree::Leaf;
 use flui_types::{
-    Offset, Size,
+    Offset, Rect, Size,
     typography::{InlineSpan, TextAlign, TextDirection},
 };
 
@@ -37,6 +42,12 @@ pub struct RenderParagraph {
     /// lays out at unbounded width (single logical line per hard break) and
     /// can overflow — Flutter `RenderParagraph.softWrap`.
     soft_wrap: bool,
+    /// The paragraph's own text scale factor. The painter lays out at this
+    /// times `view_text_scale_factor`.
+    text_scale_factor: f32,
+    /// The view's accessibility text scale, pushed in by the pipeline owner
+    /// (`ViewConfiguration::text_scale_factor`).
+    view_text_scale_factor: f32,
 }
 
 impl RenderParagraph {
@@ -47,6 +58,8 @@ pub fn new(text: impl Into<InlineSpan>, direction: TextDirection) -> Self {
                 .with_text(text)
                 .with_text_direction(direction),
             soft_wrap: true,
+            text_scale_factor: 1.0,
+            view_text_scale_factor: 1.0,
         }
     }
 
@@ -72,9 +85,13 @@ pub fn with_ellipsis(mut self, ellipsis: Option<String>) -> Self {
     }
 
     /// Sets the accessibility text scale factor (builder form).
+    ///
+    /// Composes with the view's text scale factor: the text lays out at the
+    /// product of the two.
     #[must_use]
     pub fn with_text_scale_factor(mut self, factor: f32) -> Self {
-        self.painter.set_text_scale_factor(factor);
+        self.text_scale_factor = factor;
+        self.sync_text_scale_factor();
         self
     }
 
@@ -108,6 +125,45 @@ pub fn painter(&self) -> &TextPainter {
         &self.painter
     }
 
+    /// The local rectangles covering the text between byte offsets `start`
+    /// and `end` — one per visual line the range touches, for painting a
+    /// selection highlight (Flutter `RenderParagraph.getBoxesForSelection`).
+    ///
+    /// Offsets index the paragraph's plain text. Empty before layout and
+    /// for an empty range; mixed-direction lines get one conservative rect
+    /// spanning every selected glyph.
+    #[must_use]
+    pub fn get_boxes_for_selection(&self, start: usize, end: usize) -> Vec<Rect> {
+        if start >= end || !self.painter.has_layout() {
+            return Vec::new();
+        }
+        self.painter
+            .get_boxes_for_selection(start, end)
+            .into_iter()
+            .map(|text_box| text_box.rect)
+            .collect()
+    }
+
+    /// The byte offset into the plain text nearest to `local`, a point in
+    /// this object's coordinate space — maps a tap to a caret position
+    /// (Flutter `RenderParagraph.getPositionForOffset`). Points above the
+    /// text resolve to `0`, points below it to the last line. `0` before
+    /// layout.
+    #[must_use]
+    pub fn get_position_for_offset(&self, local: Offset) -> usize {
+        if !self.painter.has_layout() {
+            return 0;
+        }
+        self.painter.get_position_for_offset(local).offset
+    }
+
+    /// Pushes the effective (own × view) scale into the painter, which
+    /// drops its cached layout when the value changes.
+    fn sync_text_scale_factor(&mut self) {
+        self.painter
+            .set_text_scale_factor(self.text_scale_factor * self.view_text_scale_factor);
+    }
+
     /// The width to lay out at for the given constraints. The box width
     /// matters — and the finite max is used — when the text wraps OR an
     /// ellipsis is configured (Flutter `_layoutText`:
@@ -237,6 +293,15 @@ fn compute_distance_to_actual_baseline(&self, baseline: TextBaseline) -> Option<
         })
     }
 
+    fn update_text_scale_factor(&mut self, factor: f32) -> bool {
+        if self.view_text_scale_factor == factor {
+            return false;
+        }
+        self.view_text_scale_factor = factor;
+        self.sync_text_scale_factor();
+        true
+    }
+
     fn paint(&self, ctx: &mut PaintCx<'_, Leaf>) {
         // The recorder pre-translates the canvas to this node's origin, so the
         // text paints in local coordinates. Skipped before layout (no cache).
@@ -269,6 +334,22 @@ fn baseline_is_none_before_layout() {
         );
     }
 
+    #[test]
+    fn view_text_scale_composes_with_the_paragraph_scale() {
+        let mut p = para("hello").with_text_scale_factor(1.5);
+        let base = leaf_intrinsics(|c| p.compute_max_intrinsic_width(f32::INFINITY, c));
+
+        assert!(p.update_text_scale_factor(2.0));
+        assert!(!p.update_text_scale_factor(2.0), "same factor is no change");
+        assert!((p.painter().text_scale_factor() - 3.0).abs() < f32::EPSILON);
+
+        let scaled = leaf_intrinsics(|c| p.compute_max_intrinsic_width(f32::INFINITY, c));
+        assert!(
+            scaled > base * 1.5,
+            "doubling the view scale must widen the text ({base} -> {scaled})",
+        );
+    }
+
     #[test]
     fn max_intrinsic_width_bounds_min_intrinsic_width() {
         let p = para("hello world wrapping example");
@@ -363,4 +444,100 @@ fn no_wrap_ellipsis_truncates_under_finite_constraints() {
             dry.width.get(),
         );
     }
+
+    /// Lays `p` out at `max_width` the way `perform_layout` does.
+    fn laid_out(mut p: RenderParagraph, max_width: f32) -> RenderParagraph {
+        p.painter.layout(0.0, max_width);
+        p
+    }
+
+    /// "hello world" laid out at 70% of its single-line width: "hello "
+    /// fits, "world" wraps to a second line.
+    fn two_line_hello_world() -> RenderParagraph {
+        let p = para("hello world");
+        let single_line = leaf_intrinsics(|c| p.compute_max_intrinsic_width(f32::INFINITY, c));
+        laid_out(p, single_line * 0.7)
+    }
+
+    #[test]
+    fn selection_queries_are_empty_before_layout_and_for_empty_ranges() {
+        let p = para("hello world");
+        assert!(p.get_boxes_for_selection(0, 5).is_empty());
+        assert_eq!(p.get_position_for_offset(Offset::new(px(10.0), px(5.0))), 0);
+
+        let p = laid_out(p, f32::INFINITY);
+        assert!(p.get_boxes_for_selection(3, 3).is_empty());
+        assert!(p.get_boxes_for_selection(5, 2).is_empty());
+    }
+
+    #[test]
+    fn selection_across_a_wrap_yields_one_rect_per_line() {
+        let p = two_line_hello_world();
+        assert_eq!(p.painter().get_line_metrics().len(), 2, "text must wrap");
+
+        // "lo wo": the tail of line one and the head of line two.
+        let rects = p.get_boxes_for_selection(3, 8);
+        assert_eq!(rects.len(), 2, "one rect per touched line, got {rects:?}");
+        let (first, second) = (rects[0], rects[1]);
+        assert!(
+            first.bottom() <= second.top(),
+            "rects must sit on successive lines: {first:?} then {second:?}",
+        );
+        assert!(first.left() > px(0.0), "line one starts mid-line at 'l'");
+        assert!(
+            second.left() <= px(0.5),
+            "line two starts at the line start"
+        );
+        assert!(
+            second.right() < first.right(),
+            "'wo' is narrower than 'lo' plus the trailing space",
+        );
+
+        // A range inside line two only touches line two.
+        let tail = p.get_boxes_for_selection(6, 11);
+        assert_eq!(tail.len(), 1);
+        assert_eq!(tail[0].top(), second.top());
+    }
+
+    #[test]
+    fn selection_after_a_hard_break_uses_whole_text_offsets() {
+        let p = laid_out(para("ab\ncd"), f32::INFINITY);
+        let line_two = p.get_boxes_for_selection(3, 5);
+        assert_eq!(line_two.len(), 1, "'cd' lives on line two only");
+        assert!(
+            line_two[0].top() > px(0.0),
+            "box must be on the second line"
+        );
+        assert!(line_two[0].width() > px(0.0));
+    }
+
+    #[test]
+    fn position_for_offset_maps_taps_to_the_tapped_line() {
+        let p = two_line_hello_world();
+        let second_line = p.get_boxes_for_selection(6, 11)[0];
+
+        let mid_second = px(f32::midpoint(
+            second_line.top().get(),
+            second_line.bottom().get(),
+        ));
+        assert_eq!(
+            p.get_position_for_offset(Offset::new(px(0.0), mid_second)),
+            6,
+            "a tap at the start of line two lands on 'w'",
+        );
+        assert_eq!(
+            p.get_position_for_offset(Offset::new(px(10_000.0), mid_second)),
+            11,
+            "a tap past the end of line two lands after 'd'",
+        );
+        assert_eq!(
+            p.get_position_for_offset(Offset::new(px(0.0), px(-20.0))),
+            0
+        );
+        assert_eq!(
+            p.get_position_for_offset(Offset::new(px(10_000.0), px(10_000.0))),
+            11,
+            "a tap below the text clamps to the last line",
+        );
+    }
 }
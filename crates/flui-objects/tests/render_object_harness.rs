@@ -45,7 +45,7 @@
 //! | `RenderMergeSemantics` | `harness_merge_semantics_*` | yes | — | — | yes | semantics |
 //! | `RenderExcludeSemantics` | `harness_exclude_semantics_*` | yes | — | — | yes | semantics |
 //! | `RenderMetaData` | `harness_metadata_*` | yes | — | — | yes | — |
-//! | `RenderFlex` | `harness_flex_*` | yes | — | — | yes | queries, baseline |
+//! | `RenderFlex` | `harness_flex_*` | yes | — | yes | yes | queries, baseline, overflow |
 //! | `RenderStack` | `harness_stack_*` | yes | yes | — | yes | queries |
 //! | `RenderIndexedStack` | `harness_indexed_stack_*` | yes | yes | yes | yes | baseline |
 //! | `RenderTheater` | `harness_theater_*` | yes | yes | yes | yes | skip_count |
@@ -126,7 +126,7 @@
     view::{ScrollDirection, ScrollableViewportOffset},
 };
 use flui_types::{
-    Alignment, EdgeInsets, Matrix4, Offset, Point, Rect, Size,
+    Alignment, EdgeInsets, EdgeInsetsDirectional, Matrix4, Offset, Point, Rect, Size,
     geometry::px,
     layout::{
         Axis, AxisDirection, BoxFit, BoxShape, StackFit, TableCellVerticalAlignment,
@@ -226,6 +226,19 @@ fn loose(max: f32) -> BoxConstraints {
     BoxConstraints::new(px(0.0), px(max), px(0.0), px(max))
 }
 
+/// The root box render object of `run`, downcast to `T`.
+fn root_render_object<T: RenderBox + 'static>(run: &flui_rendering::testing::LayoutRun) -> &T {
+    run.owner()
+        .render_tree()
+        .get(run.root())
+        .expect("root render id must be live")
+        .as_box()
+        .expect("root is a box node")
+        .render_object()
+        .downcast_ref::<T>()
+        .unwrap_or_else(|| panic!("root is a {}", std::any::type_name::<T>()))
+}
+
 // ============================================================================
 // Intrinsics test doubles (RenderIntrinsicWidth / RenderIntrinsicHeight oracle port)
 // ============================================================================
@@ -1574,6 +1587,21 @@ fn harness_padding_deflates_child_offset() {
     );
 }
 
+#[test]
+fn harness_padding_resolves_directional_insets_for_rtl() {
+    let mut padding = RenderPadding::new(EdgeInsetsDirectional::only_start(px(12.0)));
+    padding.set_text_direction(TextDirection::Rtl);
+    let run = RenderTester::mount(
+        box_node(padding).child(box_node(RenderColoredBox::red(30.0, 30.0)).label("child")),
+    )
+    .with_constraints(loose(200.0))
+    .run_layout();
+
+    // The start inset lands on the right in RTL, so the child stays at x = 0.
+    assert_eq!(run.offset(run.id("child")), Offset::new(px(0.0), px(0.0)));
+    assert_eq!(run.box_geometry(run.root()), Size::new(px(42.0), px(30.0)));
+}
+
 #[test]
 fn harness_padding_forwards_intrinsics_with_insets() {
     let mut run = RenderTester::mount(
@@ -3169,6 +3197,42 @@ fn harness_fitted_box_cover_crops_the_source_and_offsets_the_transform() {
     );
 }
 
+/// `clip_behavior` only emits a clip when the fit actually crops the child:
+/// a cropping `Cover` under `HardEdge` records a `ClipRect`, while the same
+/// clip on a non-cropping `Contain` (and `Clip::None` on the crop) does not.
+#[test]
+fn harness_fitted_box_clips_only_a_cropped_child() {
+    let frame = |fit: BoxFit, clip: Clip| {
+        RenderTester::mount(
+            box_node(RenderFittedBox::new(fit, Alignment::CENTER, clip))
+                .child(box_node(RenderColoredBox::red(100.0, 50.0)).label("child")),
+        )
+        .with_size(Size::new(px(200.0), px(200.0)))
+        .run_frame()
+    };
+
+    let cropped = frame(BoxFit::Cover, Clip::HardEdge);
+    assert!(
+        cropped.structure().contains(&"ClipRect"),
+        "a cropped Cover with Clip::HardEdge must emit a ClipRect; structure: {:?}",
+        cropped.structure(),
+    );
+
+    let unclipped = frame(BoxFit::Cover, Clip::None);
+    assert!(
+        !unclipped.structure().contains(&"ClipRect"),
+        "Clip::None never clips; structure: {:?}",
+        unclipped.structure(),
+    );
+
+    let contained = frame(BoxFit::Contain, Clip::HardEdge);
+    assert!(
+        !contained.structure().contains(&"ClipRect"),
+        "Contain never crops, so there is nothing to clip; structure: {:?}",
+        contained.structure(),
+    );
+}
+
 #[test]
 fn harness_fractionally_sized_box_applies_width_factor() {
     let run = RenderTester::mount(
@@ -3438,6 +3502,62 @@ fn harness_clip_rrect_wraps_child() {
     assert_eq!(run.box_geometry(run.root()), Size::new(px(40.0), px(40.0)));
 }
 
+/// Taps just past each rounded corner's arc miss the clip (and its child),
+/// so they fall through to whatever is behind; taps just inside the arc
+/// still land. The arc crosses each corner's diagonal at centre ± r/√2.
+#[test]
+fn harness_clip_rrect_corner_taps_fall_through_outside_the_arc() {
+    let run = RenderTester::mount(
+        box_node(RenderClipRRect::anti_alias().with_border_radius(
+            BorderRadius::circular(px(20.0)).with_bottom_left(flui_types::geometry::Radius::zero()),
+        ))
+        .child(box_node(RenderColoredBox::red(100.0, 100.0)).label("child")),
+    )
+    .with_constraints(BoxConstraints::tight(Size::new(px(100.0), px(100.0))))
+    .run_frame();
+    let child = run.id("child");
+
+    // (corner centre, diagonal direction) for the three rounded corners.
+    for (cx, cy, sx, sy) in [
+        (20.0, 20.0, -1.0, -1.0),
+        (80.0, 20.0, 1.0, -1.0),
+        (80.0, 80.0, 1.0, 1.0),
+    ] {
+        let (out_x, out_y) = (cx + sx * 14.5, cy + sy * 14.5);
+        let (in_x, in_y) = (cx + sx * 13.8, cy + sy * 13.8);
+        assert!(
+            run.hit(out_x, out_y).is_empty(),
+            "({out_x}, {out_y}) is outside the rounded corner and must fall through",
+        );
+        assert!(
+            run.hit(in_x, in_y).contains(&child),
+            "({in_x}, {in_y}) is inside the rounded corner and must reach the child",
+        );
+    }
+
+    // The zero-radius bottom-left corner stays square.
+    assert!(run.hit(0.5, 99.5).contains(&child));
+}
+
+#[test]
+fn harness_clip_rrect_elliptical_corner_hit_follows_each_axis_radius() {
+    let run = RenderTester::mount(
+        box_node(
+            RenderClipRRect::anti_alias()
+                .with_border_radius(BorderRadius::elliptical(px(40.0), px(10.0))),
+        )
+        .child(box_node(RenderColoredBox::red(100.0, 100.0)).label("child")),
+    )
+    .with_constraints(BoxConstraints::tight(Size::new(px(100.0), px(100.0))))
+    .run_frame();
+    let child = run.id("child");
+
+    // Wide, shallow corner centred at (40, 10).
+    assert!(run.hit(5.0, 5.0).is_empty(), "outside the ellipse");
+    assert!(run.hit(30.0, 2.0).contains(&child), "inside the ellipse");
+    assert!(run.hit(2.0, 30.0).contains(&child), "below the corner band");
+}
+
 #[test]
 fn harness_clip_oval_wraps_child() {
     let run = RenderTester::mount(
@@ -4589,6 +4709,71 @@ fn harness_flex_row_weights_flexible_child_min_intrinsic_width() {
     );
 }
 
+#[test]
+fn harness_flex_overstuffed_row_reports_overflow() {
+    // 60 + 70 = 130 of inflexible content in a 100-wide row: 30 px overflow.
+    let run = RenderTester::mount(
+        box_node(RenderFlex::row())
+            .child(box_node(RenderColoredBox::red(60.0, 20.0)).label("a"))
+            .child(box_node(RenderColoredBox::green(70.0, 20.0)).label("b")),
+    )
+    .with_size(Size::new(px(100.0), px(50.0)))
+    .run_layout();
+
+    let flex = root_render_object::<RenderFlex>(&run);
+    assert_eq!(flex.overflow(), 30.0);
+    assert!(flex.has_overflow());
+}
+
+#[test]
+fn harness_flex_fitting_row_reports_zero_overflow() {
+    let run = RenderTester::mount(
+        box_node(RenderFlex::row().with_spacing(10.0))
+            .child(box_node(RenderColoredBox::red(30.0, 20.0)).label("a"))
+            .child(box_node(RenderColoredBox::green(50.0, 20.0)).label("b")),
+    )
+    .with_size(Size::new(px(200.0), px(50.0)))
+    .run_layout();
+
+    let flex = root_render_object::<RenderFlex>(&run);
+    assert_eq!(flex.overflow(), 0.0);
+    assert!(!flex.has_overflow());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn harness_flex_overflow_paints_debug_indicator_only_when_overflowing() {
+    // Column: 40 + 40 in a 50-tall column overflows its bottom edge.
+    let overflowing = RenderTester::mount(
+        box_node(RenderFlex::column())
+            .child(box_node(RenderColoredBox::red(30.0, 40.0)))
+            .child(box_node(RenderColoredBox::green(30.0, 40.0))),
+    )
+    .with_size(Size::new(px(100.0), px(50.0)))
+    .run_frame();
+    assert!(
+        overflowing
+            .display_commands()
+            .iter()
+            .any(|cmd| cmd.line == "DrawRect rect=(0.00,42.00 100.00x8.00) fill #FFFF00BF"),
+        "warning tape along the column's bottom edge; commands: {:#?}",
+        overflowing.display_commands(),
+    );
+
+    let fitting = RenderTester::mount(
+        box_node(RenderFlex::column()).child(box_node(RenderColoredBox::red(30.0, 40.0))),
+    )
+    .with_size(Size::new(px(100.0), px(50.0)))
+    .run_frame();
+    assert!(
+        !fitting
+            .display_commands()
+            .iter()
+            .any(|cmd| cmd.line.contains("#FFFF00BF")),
+        "a fitting flex paints no indicator",
+    );
+}
+
 #[test]
 fn harness_flex_column_stacks_children_vertically() {
     let run = RenderTester::mount(
@@ -7871,6 +8056,28 @@ fn harness_constrained_overflow_box_defer_to_child_shrink_wraps() {
     );
 }
 
+#[test]
+fn harness_constrained_overflow_box_reports_clamped_overflow() {
+    // maxWidth override 300 lets a 250-wide child through; Max fit claims the
+    // 200×200 parent, so 50 px of width spill past the box.
+    let run = RenderTester::mount(
+        box_node(RenderConstrainedOverflowBox::new(
+            Alignment::CENTER,
+            None,
+            Some(px(300.0)),
+            None,
+            None,
+            OverflowBoxFit::Max,
+        ))
+        .child(box_node(RenderColoredBox::red(250.0, 40.0)).label("child")),
+    )
+    .with_constraints(loose(200.0))
+    .run_layout();
+
+    let overflow_box = root_render_object::<RenderConstrainedOverflowBox>(&run);
+    assert_eq!(overflow_box.overflow(), Size::new(px(50.0), px(0.0)));
+}
+
 #[test]
 fn harness_constrained_overflow_box_self_describes_fit() {
     let run = RenderTester::mount(box_node(RenderConstrainedOverflowBox::new(
@@ -8505,6 +8712,88 @@ fn harness_table_grid_lays_out_each_cell_at_its_exact_offset_and_size() {
     );
 }
 
+#[test]
+fn harness_table_mixed_column_width_specs_sum_to_the_available_width() {
+    // One row, four columns under a tight 400px width:
+    //   Fixed(40)                   -> 40
+    //   Fraction(0.25)              -> 0.25 * 400 = 100
+    //   Intrinsic { flex: None }    -> widest cell = 30
+    //   Flex(1.0) (the default)     -> remainder 400 - 170 = 230
+    let run = RenderTester::mount(
+        box_node(RenderTable::new(4).with_column_widths(HashMap::from([
+            (0, TableColumnWidth::Fixed(40.0)),
+            (1, TableColumnWidth::Fraction(0.25)),
+            (2, TableColumnWidth::Intrinsic { flex: None }),
+        ])))
+        .child(box_node(RenderColoredBox::red(10.0, 10.0)).label("fixed"))
+        .child(box_node(RenderColoredBox::green(10.0, 20.0)).label("fraction"))
+        .child(box_node(RenderColoredBox::blue(30.0, 10.0)).label("intrinsic"))
+        .child(box_node(RenderColoredBox::red(10.0, 10.0)).label("flex")),
+    )
+    .with_constraints(table_tight_width_loose_height(400.0, 800.0))
+    .run_frame();
+
+    assert_eq!(
+        run.box_geometry(run.root()),
+        Size::new(px(400.0), px(20.0)),
+        "column widths must sum to the tight width; the row is its tallest cell",
+    );
+
+    let columns: Vec<(f32, f32)> = ["fixed", "fraction", "intrinsic", "flex"]
+        .iter()
+        .map(|label| {
+            let id = run.id(label);
+            (run.offset(id).dx.get(), run.box_geometry(id).width.get())
+        })
+        .collect();
+    assert_eq!(
+        columns,
+        vec![(0.0, 40.0), (40.0, 100.0), (140.0, 30.0), (170.0, 230.0)],
+        "each cell is laid out at its column's x and tight to its column width",
+    );
+}
+
+#[test]
+fn harness_table_intrinsic_columns_shrink_to_their_content_under_loose_constraints() {
+    // Two all-Intrinsic columns under a loose 400px width: each column is
+    // its widest cell (max(30, 50) = 50, max(20, 10) = 20), and with no flex
+    // column nothing grows into the leftover 330px.
+    let mut run = RenderTester::mount(
+        box_node(
+            RenderTable::new(2)
+                .with_default_column_width(TableColumnWidth::Intrinsic { flex: None }),
+        )
+        .child(box_node(RenderColoredBox::red(30.0, 10.0)).label("a"))
+        .child(box_node(RenderColoredBox::green(20.0, 10.0)).label("b"))
+        .child(box_node(RenderColoredBox::blue(50.0, 10.0)).label("c"))
+        .child(box_node(RenderColoredBox::red(10.0, 10.0)).label("d")),
+    )
+    .with_constraints(loose(400.0))
+    .run_frame();
+
+    assert_eq!(
+        run.box_geometry(run.root()),
+        Size::new(px(70.0), px(20.0)),
+        "an intrinsic-only table must shrink-wrap its content, not fill 400px",
+    );
+    assert_eq!(run.box_geometry(run.id("a")).width, px(50.0));
+    assert_eq!(run.offset(run.id("b")).dx, px(50.0));
+    assert_eq!(run.box_geometry(run.id("d")).width, px(20.0));
+
+    // Narrowing the widest cell shrinks its column on the next frame.
+    run.update::<RenderColoredBox>(run.id("c"), |cell| {
+        cell.set_preferred_size(Size::new(px(25.0), px(10.0)));
+    });
+    run.pump();
+
+    assert_eq!(
+        run.box_geometry(run.root()).width,
+        px(50.0),
+        "column 0 shrinks to max(30, 25) = 30 once its widest cell narrows",
+    );
+    assert_eq!(run.offset(run.id("b")).dx, px(30.0));
+}
+
 #[test]
 fn harness_table_paints_row_decoration_then_children_then_border_in_order() {
     // 1 row x 2 columns, uniform border (so the outer edge is one DrawDRRect)
@@ -8909,6 +9198,44 @@ fn harness_render_animated_size_interpolates_over_several_frames_not_snap() {
     );
 }
 
+#[test]
+fn harness_render_animated_size_midpoint_follows_the_curve_not_linear_time() {
+    use flui_animation::Curve;
+
+    let (controller, driver) = animated_size_controller(100);
+    let ro = RenderAnimatedSize::new(
+        controller,
+        ArcCurve::new(Curves::EaseIn),
+        Alignment::CENTER,
+        Clip::HardEdge,
+    );
+
+    let mut run = RenderTester::mount(
+        box_node(ro)
+            .label("root")
+            .child(box_node(RenderColoredBox::red(10.0, 10.0)).label("child")),
+    )
+    .run_frame();
+
+    run.update::<RenderColoredBox>(run.id("child"), |b| {
+        b.set_preferred_size(Size::new(px(50.0), px(90.0)));
+    });
+    run.pump();
+
+    // Halfway through the run the size sits at the curve's eased fraction,
+    // per axis: 10 + EaseIn(0.5) * (50-10) wide, 10 + EaseIn(0.5) * (90-10) tall.
+    driver.tick_at(0.05);
+    run.pump();
+    let eased = Curves::EaseIn.transform(0.5);
+    assert!(eased < 0.5, "EaseIn must lag linear time at the midpoint");
+    assert_size_approx(
+        run.box_geometry(run.root()),
+        Size::new(px(10.0 + eased * 40.0), px(10.0 + eased * 80.0)),
+        0.5,
+        "EaseIn t=0.5",
+    );
+}
+
 #[test]
 fn harness_render_animated_size_clip_appears_mid_animation_and_disappears_once_settled() {
     let (controller, driver) = animated_size_controller(100);
@@ -9378,6 +9705,63 @@ fn harness_sliver_persistent_header_pinned_stays_at_zero_and_reports_max_scroll_
     );
 }
 
+#[test]
+fn harness_sliver_persistent_header_pinned_overlaps_following_sliver_once_collapsed() {
+    // Once a pinned header has shrunk to min_extent it paints where it no
+    // longer takes layout space: the following sliver must see that strip as
+    // `constraints.overlap` (oracle: viewport.dart `overlap: maxPaintOffset -
+    // layoutOffset`) so it can avoid drawing under the toolbar.
+    let header = RenderSliverPinnedPersistentHeader::new(40.0, 120.0);
+    let mut run = RenderTester::mount(viewport_multi_with_scroll(
+        0.0,
+        [
+            sliver_node(header)
+                .label("header")
+                .child(box_node(RenderColoredBox::red(300.0, 1000.0)).label("child")),
+            filler_sliver(),
+        ],
+    ))
+    .with_size(Size::new(px(300.0), px(400.0)))
+    .run_layout();
+
+    let filler_overlap = |run: &flui_rendering::testing::LayoutRun| {
+        run.owner()
+            .render_tree()
+            .get(run.id("filler"))
+            .and_then(|node| node.as_sliver())
+            .and_then(|sliver| sliver.state().constraints().copied())
+            .expect("filler is laid out")
+            .overlap
+    };
+
+    let header_id = run.id("header");
+    assert_eq!(run.sliver_geometry(header_id).layout_extent, 120.0);
+    assert_eq!(
+        filler_overlap(&run),
+        0.0,
+        "expanded header overlaps nothing"
+    );
+
+    let vp_id = run.id("viewport");
+    run.update::<RenderViewport<ScrollableViewportOffset>>(vp_id, |vp| {
+        vp.offset_mut().set_pixels(300.0);
+    });
+    run.relayout();
+
+    let geometry = run.sliver_geometry(header_id);
+    assert_eq!(geometry.paint_extent, 40.0);
+    assert_eq!(
+        geometry.layout_extent, 0.0,
+        "scrolled past: no layout space"
+    );
+    assert_eq!(geometry.max_paint_extent, 120.0);
+    assert_eq!(
+        filler_overlap(&run),
+        40.0,
+        "the collapsed header's min_extent strip overlaps the next sliver",
+    );
+}
+
 #[test]
 fn harness_sliver_persistent_header_floating_reveals_on_reverse_scroll_and_pointer_scroll_start_direction_permits_reveal()
  {
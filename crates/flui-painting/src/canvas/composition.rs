@@ -6,8 +6,8 @@
 //! `Canvas`es, and merges them in via `extend_from`/`merge`/`extend`.
 //!
 //! The first child append is O(1) (`Vec::mem::swap` underneath via
-//! `DisplayList::append`); subsequent appends are O(N) where N is the
-//! child's command count.
+//! `DisplayList::append_owned`); subsequent appends are O(N) where N is
+//! the child's command count.
 
 use flui_types::geometry::{Matrix4, Offset, Pixels, px};
 
@@ -31,7 +31,7 @@ impl Canvas {
     pub fn extend_from(&mut self, other: Canvas) {
         let child_count = other.display_list.len();
 
-        self.display_list.append(other.display_list);
+        self.display_list.append_owned(other.display_list);
 
         tracing::debug!(
             total_commands = self.display_list.len(),
@@ -85,9 +85,9 @@ pub fn merge(mut self, other: Canvas) -> Self {
     /// the throw-away intermediate, then move the vec into self).
     /// The current shape walks the source once, clones each command
     /// while applying the translation, and pushes directly into self;
-    /// `DisplayList::push` maintains the running bounds union
-    /// incrementally, so the throw-away `recalculate_bounds` pass is
-    /// gone and the temporary `DisplayList` allocation is gone.
+    /// `DisplayList::bounds` is computed lazily on first query, so the
+    /// throw-away bounds pass is gone and the temporary `DisplayList`
+    /// allocation is gone.
     /// Net: O(N) with one clone per command (down from clone, rewrite,
     /// move, bounds-recompute on the intermediate) and one fewer heap
     /// allocation (the intermediate `DisplayList`).
@@ -97,17 +97,16 @@ pub fn merge(mut self, other: Canvas) -> Self {
     /// `RestoreLayer`, which allocate a real offscreen buffer and
     /// would be far heavier than per-command transform baking).
     ///
-    /// For the zero-offset shortcut we fall through to the
-    /// `DisplayList::append` move path (no per-command rewrite
-    /// needed); `DisplayList::clone` is still required because the
-    /// input is `&DisplayList`.
+    /// For the zero-offset shortcut we fall through to
+    /// `DisplayList::append` (a straight command clone, no per-command
+    /// rewrite needed).
     pub fn append_display_list_at_offset(
         &mut self,
         display_list: &DisplayList,
         offset: Offset<Pixels>,
     ) {
         if offset.dx == px(0.0) && offset.dy == px(0.0) {
-            self.display_list.append(display_list.clone());
+            self.display_list.append(display_list);
             return;
         }
 
@@ -121,6 +120,6 @@ pub fn append_display_list_at_offset(
 
     /// Appends a cached `DisplayList` directly (no offset).
     pub fn append_display_list(&mut self, display_list: DisplayList) {
-        self.display_list.append(display_list);
+        self.display_list.append_owned(display_list);
     }
 }
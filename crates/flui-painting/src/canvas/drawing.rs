@@ -34,9 +34,12 @@
 };
 
 use super::Canvas;
-use crate::display_list::{
-    BlendMode, ColorFilter, DisplayList, DrawCommand, FilterQuality, ImageFilter, ImageRepeat,
-    Paint, PointMode, Shader, TextureId,
+use crate::{
+    display_list::{
+        BlendMode, ColorFilter, DrawCommand, FilterQuality, ImageFilter, ImageRepeat, Paint,
+        Picture, PointMode, Shader, TextureId, VertexMode,
+    },
+    error::{PaintingError, Result},
 };
 
 impl Canvas {
@@ -334,26 +337,65 @@ pub fn draw_points_with_mode(
         });
     }
 
-    /// Draws custom vertices with optional colors and texture
-    /// coordinates.
+    /// Draws a triangle mesh (Flutter's `Canvas.drawVertices`).
+    ///
+    /// `indices` selects vertices (all of them, in order, when `None`);
+    /// `mode` says how the selected sequence forms triangles. Strips and fans
+    /// are resolved into a triangle list at record time, so the recorded
+    /// `DrawVertices` is always a plain list the engine can upload as-is.
+    /// Per-vertex `colors` override `paint.color`.
+    ///
+    /// Records nothing when the mesh has no complete triangle.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PaintingError::InvalidVertices`] if `colors` and `vertices`
+    /// differ in length or an index is out of range for `vertices`; nothing
+    /// is recorded in that case.
     pub fn draw_vertices(
         &mut self,
-        vertices: Vec<Point<Pixels>>,
-        colors: Option<Vec<Color>>,
-        tex_coords: Option<Vec<Point<Pixels>>>,
-        indices: Vec<u16>,
+        vertices: &[Offset<Pixels>],
+        colors: Option<&[Color]>,
+        indices: Option<&[u32]>,
+        mode: VertexMode,
         paint: &Paint,
-    ) {
+    ) -> Result<()> {
+        if let Some(colors) = colors
+            && colors.len() != vertices.len()
+        {
+            return Err(PaintingError::invalid_vertices(format!(
+                "color count ({}) doesn't match vertex count ({})",
+                colors.len(),
+                vertices.len()
+            )));
+        }
+        if let Some(&index) = indices
+            .into_iter()
+            .flatten()
+            .find(|&&index| index as usize >= vertices.len())
+        {
+            return Err(PaintingError::invalid_vertices(format!(
+                "index {index} out of range for {} vertices",
+                vertices.len()
+            )));
+        }
+
+        let indices = triangle_list(vertices.len(), indices, mode);
+        if indices.is_empty() {
+            return Ok(());
+        }
+
         let paint = self.intern_paint(paint);
         let transform = self.transform;
         self.display_list.push(DrawCommand::DrawVertices {
-            vertices,
-            colors,
-            tex_coords,
+            vertices: vertices.iter().copied().map(Offset::to_point).collect(),
+            colors: colors.map(<[Color]>::to_vec),
+            tex_coords: None,
             indices,
             paint,
             transform,
         });
+        Ok(())
     }
 
     /// Fills entire canvas with a color (respects clipping).
@@ -373,22 +415,61 @@ pub fn draw_paint(&mut self, paint: &Paint) {
             .push(DrawCommand::DrawPaint { paint, transform });
     }
 
-    /// Draws a previously recorded `DisplayList` into this canvas.
+    /// Replays a previously recorded [`Picture`] into this canvas at
+    /// `offset`, under the current transform.
+    ///
+    /// Each replayed command keeps its recorded transform, pre-multiplied
+    /// by the current transform and the offset translation, so the same
+    /// cached picture can be drawn at several positions. The canvas's own
+    /// state (transform, clip stack, save count) is left untouched.
+    ///
+    /// A picture that clips outside of any layer of its own is replayed
+    /// inside a no-op `SaveLayer` / `RestoreLayer` pair: the engine keeps a
+    /// clip active until the enclosing layer is restored, so without it the
+    /// picture's clips would also cut whatever the host draws next.
     ///
-    /// Replays all commands from the `DisplayList`. Useful for caching
-    /// and reusing drawing commands.
+    /// # Errors
+    ///
+    /// Returns [`PaintingError::InvalidPicture`] if `picture`'s
+    /// `SaveLayer` / `RestoreLayer` commands do not balance; replaying it
+    /// would leave a layer open (or close one of this canvas's). Nothing is
+    /// recorded in that case.
     ///
     /// # Performance
     ///
-    /// This always clones `picture`'s command vector (`O(N)`), even
-    /// when `self` is empty. The zero-copy path is
-    /// [`Self::extend_from`], which takes the source `Canvas` by
-    /// value and swaps the vectors when `self` is empty (`O(1)`).
-    /// Prefer `extend_from` when you control the source canvas;
-    /// `draw_picture` is the right choice when the same `DisplayList`
-    /// is replayed multiple times.
-    pub fn draw_picture(&mut self, picture: &DisplayList) {
-        self.display_list.append(picture.clone());
+    /// This always clones `picture`'s commands (`O(N)`), even when
+    /// `self` is empty. The zero-copy path is [`Self::extend_from`],
+    /// which takes the source `Canvas` by value and swaps the vectors
+    /// when `self` is empty (`O(1)`). Prefer `extend_from` when you
+    /// control the source canvas; `draw_picture` is the right choice
+    /// when the same picture is replayed multiple times.
+    pub fn draw_picture(&mut self, picture: &Picture, offset: Offset<Pixels>) -> Result<()> {
+        let clips_unscoped = check_layer_balance(picture)?;
+
+        let transform = self.transform * Matrix4::translation(offset.dx.0, offset.dy.0, 0.0);
+        if clips_unscoped {
+            let paint = self.intern_paint(&Paint::fill(Color::WHITE));
+            self.display_list.push(DrawCommand::SaveLayer {
+                bounds: None,
+                paint,
+                transform: self.transform,
+            });
+        }
+        if transform == Matrix4::IDENTITY {
+            self.display_list.append(picture);
+        } else {
+            for cmd in picture {
+                let mut replayed = cmd.clone();
+                replayed.apply_transform(transform);
+                self.display_list.push(replayed);
+            }
+        }
+        if clips_unscoped {
+            self.display_list.push(DrawCommand::RestoreLayer {
+                transform: self.transform,
+            });
+        }
+        Ok(())
     }
 
     /// Draws multiple sprites from a texture atlas.
@@ -519,3 +600,57 @@ pub fn draw_polyline(&mut self, points: &[Point<Pixels>], paint: &Paint) {
         }
     }
 }
+
+/// Verifies that every `RestoreLayer` in `picture` closes a `SaveLayer`
+/// recorded earlier in it, and that none is left open.
+///
+/// Returns whether `picture` clips outside of any of its own layers.
+fn check_layer_balance(picture: &Picture) -> Result<bool> {
+    let mut depth = 0usize;
+    let mut clips_unscoped = false;
+    for (index, cmd) in picture.iter().enumerate() {
+        match cmd {
+            DrawCommand::SaveLayer { .. } => depth += 1,
+            cmd if depth == 0 && cmd.is_clip() => clips_unscoped = true,
+            DrawCommand::RestoreLayer { .. } => {
+                depth = depth.checked_sub(1).ok_or_else(|| {
+                    PaintingError::invalid_picture(format!(
+                        "RestoreLayer at command {index} has no matching SaveLayer"
+                    ))
+                })?;
+            }
+            _ => {}
+        }
+    }
+    if depth == 0 {
+        Ok(clips_unscoped)
+    } else {
+        Err(PaintingError::invalid_picture(format!(
+            "{depth} SaveLayer(s) never restored"
+        )))
+    }
+}
+
+/// Resolves `mode` over the selected vertex sequence into triangle-list
+/// indices. Incomplete trailing triangles are dropped.
+fn triangle_list(vertex_count: usize, indices: Option<&[u32]>, mode: VertexMode) -> Vec<u32> {
+    let mut sequence: Vec<u32> = match indices {
+        Some(indices) => indices.to_vec(),
+        None => (0..u32::try_from(vertex_count).unwrap_or(u32::MAX)).collect(),
+    };
+
+    match mode {
+        VertexMode::Triangles => {
+            sequence.truncate(sequence.len() - sequence.len() % 3);
+            sequence
+        }
+        VertexMode::TriangleStrip => sequence
+            .windows(3)
+            .flat_map(|w| [w[0], w[1], w[2]])
+            .collect(),
+        VertexMode::TriangleFan => match sequence.split_first() {
+            Some((&hub, rest)) => rest.windows(2).flat_map(|w| [hub, w[0], w[1]]).collect(),
+            None => Vec::new(),
+        },
+    }
+}
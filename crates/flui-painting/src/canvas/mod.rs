@@ -271,10 +271,11 @@ pub fn len(&self) -> usize {
         self.display_list.len()
     }
 
-    /// Returns the bounds of all recorded drawing commands.
+    /// Returns the bounds of all recorded drawing commands, or `None` if
+    /// nothing has been drawn (see [`DisplayList::bounds`]).
     #[inline]
     #[must_use]
-    pub fn bounds(&self) -> Rect<Pixels> {
+    pub fn bounds(&self) -> Option<Rect<Pixels>> {
         self.display_list.bounds()
     }
 }
@@ -128,45 +128,11 @@ fn paint_shadow(
 /// Resolves an alignment-relative [`Gradient`] into a pixel-space
 /// [`Shader`] for the given rect. Alignment is the (-1,-1)..(1,1)
 /// space over the rect; the radial radius is a fraction of the
-/// shortest side (Flutter parity).
+/// shortest side (Flutter parity). Delegates to
+/// [`Gradient::create_shader`].
 #[must_use]
 pub fn resolve_gradient(gradient: &Gradient, rect: Rect<Pixels>) -> Shader {
-    let center = rect.center();
-    let half_w = rect.width().get() / 2.0;
-    let half_h = rect.height().get() / 2.0;
-    let at = |alignment: flui_types::Alignment| {
-        Offset::new(
-            Pixels(center.x.get() + alignment.x * half_w),
-            Pixels(center.y.get() + alignment.y * half_h),
-        )
-    };
-
-    match gradient {
-        Gradient::Linear(linear) => Shader::LinearGradient {
-            from: at(linear.begin),
-            to: at(linear.end),
-            colors: linear.colors.clone(),
-            stops: linear.stops.clone(),
-            tile_mode: linear.tile_mode,
-        },
-        Gradient::Radial(radial) => Shader::RadialGradient {
-            center: at(radial.center),
-            radius: radial.radius * half_w.min(half_h) * 2.0,
-            colors: radial.colors.clone(),
-            stops: radial.stops.clone(),
-            tile_mode: radial.tile_mode,
-            focal: None,
-            focal_radius: None,
-        },
-        Gradient::Sweep(sweep) => Shader::SweepGradient {
-            center: at(sweep.center),
-            colors: sweep.colors.clone(),
-            stops: sweep.stops.clone(),
-            tile_mode: sweep.tile_mode,
-            start_angle: sweep.start_angle,
-            end_angle: sweep.end_angle,
-        },
-    }
+    gradient.create_shader(rect)
 }
 
 /// The decoration image, fitted into the rect per its `BoxFit` (the
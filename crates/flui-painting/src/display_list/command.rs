@@ -386,8 +386,9 @@ pub enum DrawCommand {
         colors: Option<Vec<Color>>,
         /// Optional texture coordinates (must match vertices length).
         tex_coords: Option<Vec<Point<Pixels>>>,
-        /// Triangle indices (groups of 3).
-        indices: Vec<u16>,
+        /// Triangle-list indices (groups of 3). `Canvas::draw_vertices`
+        /// resolves strip/fan modes into this form at record time.
+        indices: Vec<u32>,
         /// Paint style.
         paint: Arc<Paint>,
         /// Transform at recording time.
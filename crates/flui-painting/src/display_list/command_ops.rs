@@ -48,7 +48,7 @@
 use flui_types::geometry::{Matrix4, Pixels, Rect, Size};
 
 use super::command::{CommandKind, DrawCommand};
-use crate::display_list::Paint;
+use crate::display_list::{ClipOp, Paint};
 
 /// Maximum recursion depth for [`DrawCommand::with_opacity`] and
 /// [`DrawCommand::apply_transform`] into the inner [`DisplayList`](super::DisplayList) of
@@ -617,6 +617,43 @@ pub(crate) fn bounds(&self) -> Option<Rect<Pixels>> {
         }
     }
 
+    /// Returns the transformed bounds of this command's clip region if it
+    /// is an intersect clip, `None` otherwise.
+    ///
+    /// Used by `DisplayList::bounds` to tighten the commands that follow.
+    /// Difference clips only remove area, so they never shrink the outer
+    /// bound and are treated as non-clips.
+    pub(crate) fn clip_bounds(&self) -> Option<Rect<Pixels>> {
+        let (local, transform) = match self {
+            DrawCommand::ClipRect {
+                rect,
+                clip_op: ClipOp::Intersect,
+                transform,
+                ..
+            } => (*rect, transform),
+            DrawCommand::ClipRRect {
+                rrect,
+                clip_op: ClipOp::Intersect,
+                transform,
+                ..
+            } => (rrect.bounding_rect(), transform),
+            DrawCommand::ClipRSuperellipse {
+                rsuperellipse,
+                clip_op: ClipOp::Intersect,
+                transform,
+                ..
+            } => (rsuperellipse.outer_rect(), transform),
+            DrawCommand::ClipPath {
+                path,
+                clip_op: ClipOp::Intersect,
+                transform,
+                ..
+            } => (path.compute_bounds(), transform),
+            _ => return None,
+        };
+        Some(transform.transform_rect(&local))
+    }
+
     // ===== Type Discrimination =====
 
     /// Returns the kind/category of this command.
@@ -24,6 +24,7 @@
 //! - [`command_ops`]  -- `DrawCommand` impl block (with_opacity, bounds, transform, paint, kind, is_*, apply_transform).
 //! - [`sealed`]       -- sealed extension-trait pair (`DisplayListCore` + `DisplayListExt`) + 4 blanket impls.
 //! - [`stats`]        -- `DisplayListStats` struct + Display impl.
+//! - [`nine_slice`]   -- region geometry behind `DrawImageNineSlice`.
 //!
 //! This module (`mod.rs`) carries the `DisplayList` struct itself,
 //! mutation methods (`apply_transform`/`filter`/`map`/`to_opacity`/
@@ -32,18 +33,26 @@
 //! `pub(crate) push` + `pub(crate) append` entry points used by
 //! `Canvas`.
 
-use std::ops::{Index, IndexMut};
+use std::{
+    ops::{Index, IndexMut},
+    sync::{
+        OnceLock,
+        atomic::{AtomicU64, Ordering},
+    },
+};
 
 use flui_foundation::{Diagnosticable, DiagnosticsBuilder};
 use flui_types::geometry::{Matrix4, Pixels, Rect};
 
 pub mod command;
 pub mod command_ops;
+pub mod nine_slice;
 pub mod sealed;
 pub mod stats;
 
 // Re-export the public surface.
 pub use command::{CommandKind, DrawCommand};
+pub use nine_slice::nine_slice_regions;
 pub use sealed::{DisplayListCore, DisplayListExt};
 pub use stats::DisplayListStats;
 
@@ -55,11 +64,16 @@
 // types is `flui_types::painting`; this re-export is a convenience
 // facade.
 pub use flui_types::painting::{
-    BlendMode, Clip, ClipOp, FilterQuality, Paint, PointMode, Shader, TextureId,
+    BlendMode, Clip, ClipOp, FilterQuality, Paint, PointMode, Shader, TextureId, VertexMode,
     effects::ImageFilter,
     image::{ColorFilter, ImageRepeat},
 };
 
+/// Flutter's name for a recorded display list (`ui.Picture`).
+///
+/// Replay one into a canvas with [`Canvas::draw_picture`](crate::Canvas::draw_picture).
+pub type Picture = DisplayList;
+
 /// A recorded sequence of drawing commands.
 ///
 /// `DisplayList` is immutable after recording from the public API and
@@ -71,14 +85,32 @@ pub struct DisplayList {
     /// Drawing commands in order.
     pub(crate) commands: Vec<DrawCommand>,
 
-    /// Cached bounds of all drawing.
-    pub(crate) bounds: Rect<Pixels>,
+    /// Lazily computed bounds of all drawing (see [`Self::bounds`]);
+    /// reset by every mutation.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    bounds: OnceLock<Option<Rect<Pixels>>>,
+
+    /// Content identity (see [`Self::generation`]); renewed by every
+    /// mutation.
+    #[cfg_attr(feature = "serde", serde(skip, default = "next_generation"))]
+    generation: u64,
+}
+
+/// Generation shared by every empty list from [`DisplayList::new`] and
+/// [`DisplayList::clear`]: they all hold the same (no) commands.
+const EMPTY_GENERATION: u64 = 0;
+
+/// Source of [`DisplayList::generation`] values.
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(EMPTY_GENERATION + 1);
+
+fn next_generation() -> u64 {
+    NEXT_GENERATION.fetch_add(1, Ordering::Relaxed)
 }
 
 impl Diagnosticable for DisplayList {
     fn debug_fill_properties(&self, properties: &mut DiagnosticsBuilder) {
         properties.add("commands", self.commands.len());
-        properties.add("bounds", format!("{:?}", self.bounds));
+        properties.add("bounds", format!("{:?}", self.bounds()));
     }
 }
 
@@ -87,10 +119,77 @@ impl DisplayList {
     pub fn new() -> Self {
         Self {
             commands: Vec::new(),
-            bounds: Rect::ZERO,
+            bounds: OnceLock::new(),
+            generation: EMPTY_GENERATION,
         }
     }
 
+    /// Returns the union of every command's transformed bounds, or `None`
+    /// if nothing is drawn.
+    ///
+    /// Stroke width expands shape bounds, and intersect clips in effect at
+    /// a command tighten its contribution (a `SaveLayer` / `RestoreLayer`
+    /// pair scopes the clips recorded inside it). `DrawColor` / `DrawPaint`
+    /// contribute the current clip, and nothing when unclipped; difference
+    /// clips never tighten.
+    ///
+    /// Computed on first query and cached until the list is next mutated.
+    pub fn bounds(&self) -> Option<Rect<Pixels>> {
+        *self.bounds.get_or_init(|| self.compute_bounds())
+    }
+
+    /// Identifies this list's content.
+    ///
+    /// Every new recording and every mutation gets a fresh value, while a
+    /// clone keeps its source's, so two lists with the same generation hold
+    /// identical commands. Damage tracking uses it to tell a re-recorded
+    /// picture from a retained one without comparing commands.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Drops the cached bounds and renews the generation; called by every
+    /// mutation.
+    fn invalidate(&mut self) {
+        self.bounds.take();
+        self.generation = next_generation();
+    }
+
+    fn compute_bounds(&self) -> Option<Rect<Pixels>> {
+        // `Some(Rect::ZERO)` models an empty clip: it intersects nothing.
+        let mut clip: Option<Rect<Pixels>> = None;
+        let mut saved_clips = Vec::new();
+        let mut bounds: Option<Rect<Pixels>> = None;
+
+        for cmd in &self.commands {
+            match cmd {
+                DrawCommand::SaveLayer { .. } => saved_clips.push(clip),
+                DrawCommand::RestoreLayer { .. } => clip = saved_clips.pop().flatten(),
+                _ => {}
+            }
+            if let Some(clip_rect) = cmd.clip_bounds() {
+                clip = Some(match clip {
+                    Some(current) => current.intersect(&clip_rect).unwrap_or(Rect::ZERO),
+                    None => clip_rect,
+                });
+                continue;
+            }
+
+            let visible = match (cmd, cmd.bounds(), clip) {
+                (DrawCommand::DrawColor { .. } | DrawCommand::DrawPaint { .. }, _, clip) => {
+                    clip.filter(|c| !c.is_empty())
+                }
+                (_, Some(cmd_bounds), Some(clip)) => cmd_bounds.intersect(&clip),
+                (_, cmd_bounds, None) => cmd_bounds,
+                (_, None, Some(_)) => None,
+            };
+            if let Some(visible) = visible {
+                bounds = Some(bounds.map_or(visible, |b| b.union(&visible)));
+            }
+        }
+        bounds
+    }
+
     /// Returns an iterator over command references.
     pub fn iter(&self) -> std::slice::Iter<'_, DrawCommand> {
         self.commands.iter()
@@ -98,18 +197,13 @@ pub fn iter(&self) -> std::slice::Iter<'_, DrawCommand> {
 
     /// Returns an iterator over mutable command references.
     pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, DrawCommand> {
+        self.invalidate();
         self.commands.iter_mut()
     }
 
     /// Adds a command to the display list (internal).
     pub(crate) fn push(&mut self, command: DrawCommand) {
-        if let Some(cmd_bounds) = command.bounds() {
-            if self.commands.is_empty() {
-                self.bounds = cmd_bounds;
-            } else {
-                self.bounds = self.bounds.union(&cmd_bounds);
-            }
-        }
+        self.invalidate();
         self.commands.push(command);
     }
 
@@ -165,21 +259,7 @@ pub(crate) fn apply_transform_depth(&mut self, transform: Matrix4, depth: usize)
         for cmd in &mut self.commands {
             cmd.apply_transform_depth(transform, depth);
         }
-        self.recalculate_bounds();
-    }
-
-    /// Recalculates the bounds from all commands.
-    fn recalculate_bounds(&mut self) {
-        self.bounds = Rect::ZERO;
-        for cmd in &self.commands {
-            if let Some(cmd_bounds) = cmd.bounds() {
-                if self.bounds == Rect::ZERO {
-                    self.bounds = cmd_bounds;
-                } else {
-                    self.bounds = self.bounds.union(&cmd_bounds);
-                }
-            }
-        }
+        self.invalidate();
     }
 
     /// Filters commands, keeping only those that satisfy the
@@ -196,12 +276,11 @@ pub fn filter<F>(&self, predicate: F) -> Self
             .cloned()
             .collect();
 
-        let mut result = Self {
+        Self {
             commands,
-            bounds: Rect::ZERO,
-        };
-        result.recalculate_bounds();
-        result
+            bounds: OnceLock::new(),
+            generation: next_generation(),
+        }
     }
 
     /// Maps each command through a function.
@@ -212,22 +291,40 @@ pub fn map<F>(&self, f: F) -> Self
     {
         let commands: Vec<_> = self.commands.iter().map(f).collect();
 
-        let mut result = Self {
+        Self {
             commands,
-            bounds: Rect::ZERO,
-        };
-        result.recalculate_bounds();
-        result
+            bounds: OnceLock::new(),
+            generation: next_generation(),
+        }
     }
 
     /// Clears all commands (for pooling/reuse).
     pub fn clear(&mut self) {
         self.commands.clear();
-        self.bounds = Rect::ZERO;
+        self.bounds.take();
+        self.generation = EMPTY_GENERATION;
+    }
+
+    /// Appends clones of all commands from `other`.
+    ///
+    /// Commands are self-contained (each carries its own transform), so
+    /// concatenation preserves replay semantics. Use
+    /// [`Self::append_owned`] when `other` is not needed afterwards.
+    pub fn append(&mut self, other: &DisplayList) {
+        if other.commands.is_empty() {
+            return;
+        }
+        if self.commands.is_empty() {
+            self.bounds = other.bounds.clone();
+            self.generation = other.generation;
+        } else {
+            self.invalidate();
+        }
+        self.commands.extend_from_slice(&other.commands);
     }
 
     /// Appends all commands from another DisplayList (zero-copy
-    /// move), unioning the cached bounds.
+    /// move).
     ///
     /// Commands are self-contained (each carries its own transform),
     /// so concatenation preserves replay semantics. The
@@ -244,21 +341,21 @@ pub fn clear(&mut self) {
         parent_len = self.commands.len(),
         child_len = other.commands.len(),
     ))]
-    pub fn append(&mut self, mut other: DisplayList) {
+    pub fn append_owned(&mut self, mut other: DisplayList) {
         if self.commands.is_empty() {
             tracing::trace!("Using fast path: vector swap (O(1))");
             std::mem::swap(&mut self.commands, &mut other.commands);
             self.bounds = other.bounds;
+            self.generation = other.generation;
         } else if !other.commands.is_empty() {
             tracing::trace!(
                 commands_to_append = other.commands.len(),
                 "Using append path (O(N))"
             );
             self.commands.append(&mut other.commands);
-
-            if !other.bounds.is_empty() {
-                self.bounds = self.bounds.union(&other.bounds);
-            }
+            // A clip recorded in `self` may tighten `other`'s commands, so
+            // the two cached bounds cannot simply be unioned.
+            self.invalidate();
         }
 
         tracing::debug!(
@@ -306,7 +403,8 @@ pub(crate) fn to_opacity_depth(&self, opacity: f32, depth: usize) -> Self {
 
         Self {
             commands,
-            bounds: self.bounds,
+            bounds: self.bounds.clone(),
+            generation: next_generation(),
         }
     }
 }
@@ -330,6 +428,7 @@ fn as_ref(&self) -> &[DrawCommand] {
 /// commands.
 impl AsMut<[DrawCommand]> for DisplayList {
     fn as_mut(&mut self) -> &mut [DrawCommand] {
+        self.invalidate();
         &mut self.commands
     }
 }
@@ -350,6 +449,7 @@ impl<'a> IntoIterator for &'a mut DisplayList {
     type IntoIter = std::slice::IterMut<'a, DrawCommand>;
 
     fn into_iter(self) -> Self::IntoIter {
+        self.invalidate();
         self.commands.iter_mut()
     }
 }
@@ -366,6 +466,7 @@ fn index(&self, index: usize) -> &Self::Output {
 
 impl IndexMut<usize> for DisplayList {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.invalidate();
         &mut self.commands[index]
     }
 }
@@ -0,0 +1,97 @@
+//! Nine-slice geometry for [`DrawCommand::DrawImageNineSlice`].
+//!
+//! One source of truth for how a center slice splits an image into nine
+//! regions and where each lands in the destination, shared by the GPU
+//! backend (which draws the regions) and render objects (which reason about
+//! the result without a GPU).
+//!
+//! [`DrawCommand::DrawImageNineSlice`]: super::DrawCommand::DrawImageNineSlice
+
+use flui_types::geometry::{Pixels, Rect, Size};
+
+/// Splits an image of `image_size` around `center_slice` (image
+/// coordinates) and maps each piece into `dst`, returning nine
+/// `(source, destination)` pairs in row-major order: top-left, top,
+/// top-right, left, center, right, bottom-left, bottom, bottom-right.
+///
+/// Corners keep their source size, the top and bottom edges stretch
+/// horizontally, the left and right edges vertically, and the center both
+/// ways. When `dst` is too small for the corners, the inner edges collapse
+/// onto the far edge and the affected regions get zero or negative extent —
+/// callers skip any region without positive area.
+#[must_use]
+pub fn nine_slice_regions(
+    image_size: Size<Pixels>,
+    center_slice: Rect<Pixels>,
+    dst: Rect<Pixels>,
+) -> [(Rect<Pixels>, Rect<Pixels>); 9] {
+    let (img_w, img_h) = (image_size.width, image_size.height);
+    let (sl, st) = (center_slice.left(), center_slice.top());
+    let (sr, sb) = (center_slice.right(), center_slice.bottom());
+    let (dl, dt, dr, db) = (dst.left(), dst.top(), dst.right(), dst.bottom());
+
+    // Inner destination edges: corners keep their source size, clamped so a
+    // too-small destination collapses the middle instead of inverting it.
+    let d_inner_left = (dl + sl).min(dr);
+    let d_inner_top = (dt + st).min(db);
+    let d_inner_right = (dr - (img_w - sr)).max(d_inner_left);
+    let d_inner_bottom = (db - (img_h - sb)).max(d_inner_top);
+
+    let src_x = [Pixels::ZERO, sl, sr, img_w];
+    let src_y = [Pixels::ZERO, st, sb, img_h];
+    let dst_x = [dl, d_inner_left, d_inner_right, dr];
+    let dst_y = [dt, d_inner_top, d_inner_bottom, db];
+
+    std::array::from_fn(|i| {
+        let (col, row) = (i % 3, i / 3);
+        (
+            Rect::from_ltrb(src_x[col], src_y[row], src_x[col + 1], src_y[row + 1]),
+            Rect::from_ltrb(dst_x[col], dst_y[row], dst_x[col + 1], dst_y[row + 1]),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use flui_types::geometry::px;
+
+    use super::*;
+
+    #[test]
+    fn corners_keep_their_size_while_edges_and_center_stretch() {
+        // 30×30 image with a 10px border, drawn into 100×60.
+        let regions = nine_slice_regions(
+            Size::new(px(30.0), px(30.0)),
+            Rect::from_ltrb(px(10.0), px(10.0), px(20.0), px(20.0)),
+            Rect::from_ltrb(px(0.0), px(0.0), px(100.0), px(60.0)),
+        );
+
+        for corner in [0, 2, 6, 8] {
+            let (src, dst) = regions[corner];
+            assert_eq!(src.size(), Size::new(px(10.0), px(10.0)));
+            assert_eq!(dst.size(), src.size(), "corner {corner} must not scale");
+        }
+        assert_eq!(regions[1].1.size(), Size::new(px(80.0), px(10.0)), "top");
+        assert_eq!(regions[3].1.size(), Size::new(px(10.0), px(40.0)), "left");
+        assert_eq!(regions[4].1.size(), Size::new(px(80.0), px(40.0)), "center");
+        assert_eq!(
+            regions[8].1,
+            Rect::from_ltrb(px(90.0), px(50.0), px(100.0), px(60.0))
+        );
+    }
+
+    #[test]
+    fn destination_smaller_than_the_corners_collapses_the_middle() {
+        let regions = nine_slice_regions(
+            Size::new(px(30.0), px(30.0)),
+            Rect::from_ltrb(px(10.0), px(10.0), px(20.0), px(20.0)),
+            Rect::from_ltrb(px(0.0), px(0.0), px(15.0), px(15.0)),
+        );
+        let (_, center) = regions[4];
+        assert_eq!(center.width(), px(0.0));
+        assert_eq!(center.height(), px(0.0));
+        // The near corner keeps its 10px; the far corner gets the rest.
+        assert_eq!(regions[0].1.width(), px(10.0));
+        assert_eq!(regions[8].1.width(), px(5.0));
+    }
+}
@@ -67,8 +67,9 @@ pub trait DisplayListCore: private::Sealed {
     fn commands(&self) -> impl Iterator<Item = &DrawCommand>;
 
     /// Returns the bounding rectangle containing all drawing
-    /// operations.
-    fn bounds(&self) -> Rect<Pixels>;
+    /// operations, or `None` if nothing is drawn (see
+    /// [`DisplayList::bounds`]).
+    fn bounds(&self) -> Option<Rect<Pixels>>;
 
     /// Returns the total number of commands in this display list.
     fn len(&self) -> usize;
@@ -172,8 +173,8 @@ fn commands(&self) -> impl Iterator<Item = &DrawCommand> {
         self.commands.iter()
     }
 
-    fn bounds(&self) -> Rect<Pixels> {
-        self.bounds
+    fn bounds(&self) -> Option<Rect<Pixels>> {
+        DisplayList::bounds(self)
     }
 
     fn len(&self) -> usize {
@@ -188,7 +189,7 @@ fn commands(&self) -> impl Iterator<Item = &DrawCommand> {
         (**self).commands()
     }
 
-    fn bounds(&self) -> Rect<Pixels> {
+    fn bounds(&self) -> Option<Rect<Pixels>> {
         (**self).bounds()
     }
 
@@ -204,7 +205,7 @@ fn commands(&self) -> impl Iterator<Item = &DrawCommand> {
         (**self).commands()
     }
 
-    fn bounds(&self) -> Rect<Pixels> {
+    fn bounds(&self) -> Option<Rect<Pixels>> {
         (**self).bounds()
     }
 
@@ -220,7 +221,7 @@ fn commands(&self) -> impl Iterator<Item = &DrawCommand> {
         (*self).commands()
     }
 
-    fn bounds(&self) -> Rect<Pixels> {
+    fn bounds(&self) -> Option<Rect<Pixels>> {
         (*self).bounds()
     }
 
@@ -52,6 +52,24 @@ pub enum PaintingError {
         reason: Cow<'static, str>,
     },
 
+    /// Vertex mesh data is inconsistent (see [`Canvas::draw_vertices`]).
+    ///
+    /// [`Canvas::draw_vertices`]: crate::Canvas::draw_vertices
+    #[error("Invalid vertices: {reason}")]
+    InvalidVertices {
+        /// Failure reason
+        reason: Cow<'static, str>,
+    },
+
+    /// A picture cannot be replayed (see [`Canvas::draw_picture`]).
+    ///
+    /// [`Canvas::draw_picture`]: crate::Canvas::draw_picture
+    #[error("Invalid picture: {reason}")]
+    InvalidPicture {
+        /// Failure reason
+        reason: Cow<'static, str>,
+    },
+
     /// Font registration failed (see [`PaintingBinding::register_font`]).
     ///
     /// [`PaintingBinding::register_font`]: crate::PaintingBinding::register_font
@@ -116,6 +134,26 @@ pub fn paint_image_failed(reason: impl Into<Cow<'static, str>>) -> Self {
         }
     }
 
+    /// Create an invalid vertices error
+    ///
+    /// Accepts both static strings (zero-cost) and dynamic strings (allocated).
+    #[must_use]
+    pub fn invalid_vertices(reason: impl Into<Cow<'static, str>>) -> Self {
+        Self::InvalidVertices {
+            reason: reason.into(),
+        }
+    }
+
+    /// Create an invalid picture error
+    ///
+    /// Accepts both static strings (zero-cost) and dynamic strings (allocated).
+    #[must_use]
+    pub fn invalid_picture(reason: impl Into<Cow<'static, str>>) -> Self {
+        Self::InvalidPicture {
+            reason: reason.into(),
+        }
+    }
+
     /// Create a font registration failed error
     ///
     /// Accepts both static strings (zero-cost) and dynamic strings (allocated).
@@ -197,7 +197,8 @@
 pub use clip_context::ClipContext;
 pub use decoration::{box_decoration_hit_test, paint_box_decoration, resolve_gradient};
 pub use display_list::{
-    DisplayList, DisplayListCore, DisplayListExt, DisplayListStats, DrawCommand,
+    DisplayList, DisplayListCore, DisplayListExt, DisplayListStats, DrawCommand, Picture,
+    nine_slice_regions,
 };
 pub use error::{PaintingError, Result};
 // Re-exported so consumers can name the font system type that appears in
@@ -208,7 +209,7 @@
 pub use table_border::paint_table_border;
 pub use text_layout::{
     LineInfo, SharedFontSystem, TextLayout, TextLayoutResult, detect_text_direction,
-    measure_inline_span, measure_text,
+    expand_word_spacing, measure_inline_span, measure_text,
 };
 pub use text_painter::{DEFAULT_FONT_SIZE, Invalidation, TextBaseline, TextPainter};
 
@@ -230,6 +231,7 @@
 // CONTRIBUTING.md cross-reference once that doc lands.
 pub use flui_types::painting::{
     BlendMode, Paint, PaintBuilder, PaintStyle, PointMode, Shader, StrokeCap, StrokeJoin,
+    VertexMode,
 };
 
 pub mod prelude {
@@ -271,7 +273,7 @@ pub mod prelude {
 
     pub use crate::{
         canvas::Canvas,
-        display_list::{DisplayList, DisplayListCore, DisplayListExt, DrawCommand},
+        display_list::{DisplayList, DisplayListCore, DisplayListExt, DrawCommand, Picture},
         text_layout::{TextLayoutResult, detect_text_direction, measure_inline_span, measure_text},
         text_painter::{TextBaseline, TextPainter},
     };
@@ -44,9 +44,9 @@ pub fn command_count(list: &DisplayList) -> usize {
     list.len()
 }
 
-/// The record-time bounds of the display list.
+/// The bounds of the display list, `None` when nothing is drawn.
 #[must_use]
-pub fn bounds(list: &DisplayList) -> Rect {
+pub fn bounds(list: &DisplayList) -> Option<Rect> {
     list.bounds()
 }
 
@@ -81,7 +81,7 @@ fn record_captures_commands_and_bounds() {
         assert_eq!(command_count(&list), 1);
         assert_eq!(
             bounds(&list),
-            Rect::from_ltrb(px(0.0), px(0.0), px(40.0), px(40.0))
+            Some(Rect::from_ltrb(px(0.0), px(0.0), px(40.0), px(40.0)))
         );
     }
 
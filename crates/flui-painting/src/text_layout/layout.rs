@@ -19,7 +19,10 @@
 };
 use parking_lot::Mutex;
 
-use super::{LineInfo, TextLayoutResult, measure::style_to_attrs};
+use super::{
+    LineInfo, TextLayoutResult,
+    measure::{expand_word_spacing, style_to_attrs},
+};
 
 /// Global font system instance.
 ///
@@ -210,8 +213,8 @@ pub fn with_overflow(
     /// Creates a RICH text layout from styled spans.
     ///
     /// Each span carries its own (already inheritance-merged) style:
-    /// per-span font selection, weight, style, font size, and letter
-    /// spacing all reach the shaper — a bold or larger child span
+    /// per-span font selection, weight, style, font size, line height,
+    /// and letter/word spacing all reach the shaper — a bold or larger child span
     /// measures as bold or larger instead of being flattened to the
     /// root style.
     /// `default_style` and `font_size` describe the buffer-level
@@ -239,7 +242,7 @@ pub fn from_spans(
         let line_height = line_height.unwrap_or(font_size * 1.2);
         let default_attrs = cosmic_text::AttrsOwned::new(&style_to_attrs(default_style));
 
-        let runs: Vec<OwnedRun> = spans
+        let runs: Vec<OwnedRun> = expand_word_spacing(spans)
             .into_iter()
             .map(|(text, style)| {
                 let attrs = match &style {
@@ -514,54 +517,51 @@ pub fn get_offset_for_caret(&mut self, position: TextPosition) -> Offset<Pixels>
     }
 
     /// Returns the text position for a screen offset.
+    ///
+    /// The offset picks a *visual* line by its vertical band — a wrapped
+    /// paragraph contributes one band per wrapped line — clamping above
+    /// the first line and below the last. Within the line the nearest
+    /// glyph edge wins. The returned offset is a byte index into the whole
+    /// text, hard line breaks included.
     pub fn get_position_for_offset(&self, offset: Offset<Pixels>) -> TextPosition {
         let x = offset.dx.0;
         let y = offset.dy.0;
+        let line_starts = self.line_starts();
 
-        let mut target_line: Option<usize> = None;
-        let mut line_top = 0.0f32;
-
-        for run in self.buffer.layout_runs() {
-            if y >= run.line_top && y < run.line_top + run.line_height {
-                target_line = Some(run.line_i);
-                line_top = run.line_top;
-                break;
-            }
-        }
-
-        let target_line = if let Some(l) = target_line {
-            l
-        } else {
-            let mut last_line = 0;
-            for run in self.buffer.layout_runs() {
-                last_line = run.line_i;
-            }
-            if y >= line_top {
-                last_line
+        // The first line whose band ends below `y`, else the last line.
+        let Some(run) = self.buffer.layout_runs().reduce(|found, run| {
+            if y < found.line_top + found.line_height {
+                found
             } else {
-                return TextPosition::upstream(0);
+                run
             }
+        }) else {
+            return TextPosition::upstream(0);
         };
+        if y < 0.0 {
+            return TextPosition::upstream(0);
+        }
 
-        for run in self.buffer.layout_runs() {
-            if run.line_i == target_line {
-                let mut last_offset = run.glyphs.first().map_or(0, |g| g.start);
-
-                for glyph in run.glyphs {
-                    let glyph_center = glyph.x + glyph.w / 2.0;
-
-                    if x < glyph_center {
-                        return TextPosition::new(glyph.start, TextAffinity::Downstream);
-                    }
-
-                    last_offset = glyph.end;
-                }
-
-                return TextPosition::new(last_offset, TextAffinity::Upstream);
+        let line_start = line_starts.get(run.line_i).copied().unwrap_or(0);
+        let mut last_offset = run.glyphs.first().map_or(0, |g| g.start);
+        for glyph in run.glyphs {
+            let glyph_center = glyph.x + glyph.w / 2.0;
+            if x < glyph_center {
+                let local = if glyph.level.is_rtl() {
+                    glyph.end
+                } else {
+                    glyph.start
+                };
+                return TextPosition::new(line_start + local, TextAffinity::Downstream);
             }
+            last_offset = if glyph.level.is_rtl() {
+                glyph.start
+            } else {
+                glyph.end
+            };
         }
 
-        TextPosition::upstream(0)
+        TextPosition::new(line_start + last_offset, TextAffinity::Upstream)
     }
 
     /// Returns line metrics for all lines in the layout.
@@ -675,51 +675,89 @@ pub fn is_bidirectional(&self) -> bool {
     }
 
     /// Returns bounding boxes for the given text range.
+    ///
+    /// `range` holds byte offsets into the whole text. One box is returned
+    /// per visual line the range touches, spanning the line's full height,
+    /// so a selection across a wrap or a hard break yields one box per
+    /// line. Mixed-direction lines are handled conservatively: the box
+    /// covers every selected glyph from the leftmost to the rightmost edge,
+    /// and a partly selected right-to-left cluster is covered whole. An
+    /// empty range yields no boxes.
     #[allow(clippy::needless_pass_by_value)]
     pub fn get_boxes_for_range(&self, range: TextRange) -> Vec<TextBox> {
+        self.line_boxes_for_range(range)
+            .into_iter()
+            .map(|(_, text_box)| text_box)
+            .collect()
+    }
+
+    /// [`get_boxes_for_range`](Self::get_boxes_for_range), with each box
+    /// paired with the index of its visual line in
+    /// [`get_line_metrics`](Self::get_line_metrics).
+    pub(crate) fn line_boxes_for_range(&self, range: TextRange) -> Vec<(usize, TextBox)> {
         let mut boxes = Vec::new();
+        if range.start >= range.end {
+            return boxes;
+        }
+        let line_starts = self.line_starts();
 
-        for run in self.buffer.layout_runs() {
-            let mut line_start_x: Option<f32> = None;
-            let mut line_end_x = 0.0f32;
+        for (line_number, run) in self.buffer.layout_runs().enumerate() {
+            let line_start = line_starts.get(run.line_i).copied().unwrap_or(0);
+            let mut extent: Option<(f32, f32)> = None;
 
             for glyph in run.glyphs {
-                if glyph.end > range.start && glyph.start < range.end {
-                    if line_start_x.is_none() {
-                        let start_offset = if glyph.start < range.start {
-                            let progress = (range.start - glyph.start) as f32
-                                / (glyph.end - glyph.start) as f32;
-                            glyph.x + glyph.w * progress
-                        } else {
-                            glyph.x
-                        };
-                        line_start_x = Some(start_offset);
-                    }
-
-                    line_end_x = if glyph.end > range.end {
-                        let progress =
-                            (range.end - glyph.start) as f32 / (glyph.end - glyph.start) as f32;
-                        glyph.x + glyph.w * progress
-                    } else {
-                        glyph.x + glyph.w
-                    };
+                let (start, end) = (line_start + glyph.start, line_start + glyph.end);
+                if end <= range.start || start >= range.end {
+                    continue;
                 }
+
+                let (left, right) = if glyph.level.is_rtl() || end == start {
+                    (glyph.x, glyph.x + glyph.w)
+                } else {
+                    let span = (end - start) as f32;
+                    let from = range.start.saturating_sub(start) as f32 / span;
+                    let to = (range.end.min(end) - start) as f32 / span;
+                    (glyph.x + glyph.w * from, glyph.x + glyph.w * to)
+                };
+                extent = Some(extent.map_or((left, right), |(l, r)| (l.min(left), r.max(right))));
             }
 
-            if let Some(start_x) = line_start_x {
+            if let Some((left, right)) = extent {
                 let rect = Rect::from_ltrb(
-                    Pixels(start_x),
+                    Pixels(left),
                     Pixels(run.line_top),
-                    Pixels(line_end_x),
+                    Pixels(right),
                     Pixels(run.line_top + run.line_height),
                 );
-                boxes.push(TextBox::new(rect, self.direction));
+                let direction = if run.rtl {
+                    TextDirection::Rtl
+                } else {
+                    TextDirection::Ltr
+                };
+                boxes.push((line_number, TextBox::new(rect, direction)));
             }
         }
 
         boxes
     }
 
+    /// Byte offset of each buffer line's first character in the whole
+    /// text. cosmic-text splits the text at hard breaks and reports glyph
+    /// ranges relative to their own line; adding the line's start makes
+    /// them global.
+    fn line_starts(&self) -> Vec<usize> {
+        let mut next = 0;
+        self.buffer
+            .lines
+            .iter()
+            .map(|line| {
+                let start = next;
+                next += line.text().len() + line.ending().as_str().len();
+                start
+            })
+            .collect()
+    }
+
     /// Returns the word boundary at the given text position.
     ///
     /// The implementation expands left and right from `position.offset`
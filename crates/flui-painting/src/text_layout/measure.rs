@@ -1,5 +1,5 @@
 //! Text measurement helpers: `measure_text`, `measure_inline_span`,
-//! `style_to_attrs`.
+//! `style_to_attrs`, `expand_word_spacing`.
 //!
 //! Extracted from the 1,243-LOC
 //! `text_layout.rs` god module. Measurement is shape-then-compute on
@@ -141,3 +141,48 @@ pub fn measure_inline_span(
 
     measure_text(&plain_text, style, scaled_font_size, max_width, None)
 }
+
+/// Lowers `word_spacing` onto the shaper by splitting each affected span
+/// into word and whitespace pieces.
+///
+/// cosmic-text has no word-spacing attribute, but it applies letter
+/// spacing to every glyph, spaces included. Each run of Unicode whitespace
+/// (so no-break and ideographic spaces as well as ASCII ones) therefore
+/// becomes its own span whose `letter_spacing` is increased by the word
+/// spacing, so the extra advance takes part in line breaking exactly like
+/// the glyph widths do. The measuring (`TextLayout`) and rendering paths
+/// must both run spans through here so they agree on line breaks.
+///
+/// Spans without a non-zero word spacing pass through unchanged.
+#[must_use]
+pub fn expand_word_spacing(
+    spans: Vec<(String, Option<TextStyle>)>,
+) -> Vec<(String, Option<TextStyle>)> {
+    let mut out = Vec::with_capacity(spans.len());
+    for (text, style) in spans {
+        let Some(word_spacing) = style
+            .as_ref()
+            .and_then(|s| s.word_spacing)
+            .filter(|&ws| ws != 0.0)
+        else {
+            out.push((text, style));
+            continue;
+        };
+        let mut word_style = style.unwrap_or_default();
+        word_style.word_spacing = None;
+        let mut space_style = word_style.clone();
+        space_style.letter_spacing = Some(word_style.letter_spacing.unwrap_or(0.0) + word_spacing);
+
+        let mut rest = text.as_str();
+        while !rest.is_empty() {
+            let is_space = rest.starts_with(char::is_whitespace);
+            let end = rest
+                .find(|c: char| c.is_whitespace() != is_space)
+                .unwrap_or(rest.len());
+            let piece_style = if is_space { &space_style } else { &word_style };
+            out.push((rest[..end].to_string(), Some(piece_style.clone())));
+            rest = &rest[end..];
+        }
+    }
+    out
+}
@@ -15,7 +15,8 @@
 //!
 //! - `detect`   -- RTL/LTR detection helpers.
 //! - `layout`   -- `FONT_SYSTEM` static + `TextLayout` struct + cursor/hit-test methods.
-//! - `measure`  -- `measure_text` + `measure_inline_span` + `style_to_attrs` helpers.
+//! - `measure`  -- `measure_text` + `measure_inline_span` + `style_to_attrs` +
+//!   `expand_word_spacing` helpers.
 
 use flui_types::{
     geometry::{Pixels, Size, px},
@@ -29,7 +30,7 @@
 pub use detect::detect_text_direction;
 pub(crate) use layout::shared_font_system;
 pub use layout::{SharedFontSystem, TextLayout};
-pub use measure::{measure_inline_span, measure_text};
+pub use measure::{expand_word_spacing, measure_inline_span, measure_text};
 
 // ===== Shared types (identical between cosmic-text impl and fallback) =====
 
@@ -373,6 +373,9 @@ fn walk(
                 if let Some(spacing) = style.letter_spacing {
                     style.letter_spacing = Some(spacing * f64::from(scale));
                 }
+                if let Some(spacing) = style.word_spacing {
+                    style.word_spacing = Some(spacing * f64::from(scale));
+                }
             }
             out.push((text.clone(), effective));
         }
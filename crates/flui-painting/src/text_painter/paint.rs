@@ -1,17 +1,20 @@
 //! `TextPainter` painting + cursor methods: `paint`,
 //! `get_offset_for_caret`, `get_position_for_offset`,
-//! `get_line_metrics`, `get_boxes_for_selection`, `get_word_boundary`.
+//! `get_line_metrics`, `get_boxes_for_selection`, `get_word_boundary`,
+//! plus the decoration pass that `paint` runs after the glyphs.
 //!
 //! Extracted from the 990-LOC `text_painter.rs`
 //! god module. All methods here depend on the cached layout
 //! (`TextLayoutCache`) populated by [`super::measure`]'s `layout()`.
 
 use flui_types::{
-    geometry::{Offset, Pixels},
-    typography::{LineMetrics, TextBox, TextPosition, TextRange},
+    Color,
+    geometry::{Offset, Pixels, Point, Rect, px},
+    painting::Paint,
+    typography::{InlineSpan, LineMetrics, TextBox, TextDecorationStyle, TextPosition, TextRange},
 };
 
-use super::TextPainter;
+use super::{DEFAULT_FONT_SIZE, TextLayoutCache, TextPainter, measure::collect_styled_spans};
 use crate::Canvas;
 
 impl TextPainter {
@@ -153,5 +156,152 @@ pub fn paint(&self, canvas: &mut Canvas, offset: Offset<Pixels>) {
             self.text_scale_factor as f64,
             wrap_width,
         );
+
+        self.paint_decorations(canvas, text, cache, paint_offset);
+    }
+
+    /// Draws underline / overline / line-through for every styled run that
+    /// carries a [`TextDecoration`](flui_types::typography::TextDecoration),
+    /// once per visual line the run spans, in the style's
+    /// `decoration_color`, `decoration_style` and `decoration_thickness`.
+    ///
+    /// Positions come from the baseline of the line each box was laid out
+    /// on; the offsets from the baseline use the same 0.8 em ascent
+    /// approximation as the empty-layout line metrics, since cosmic-text does
+    /// not expose per-font decoration metrics.
+    fn paint_decorations(
+        &self,
+        canvas: &mut Canvas,
+        text: &InlineSpan,
+        cache: &TextLayoutCache,
+        paint_offset: Offset<Pixels>,
+    ) {
+        let runs = collect_styled_spans(text, self.text_scale_factor);
+        if !runs
+            .iter()
+            .any(|(_, style)| style.as_ref().is_some_and(|s| s.decoration.is_some()))
+        {
+            return;
+        }
+
+        let lines = cache.layout.get_line_metrics();
+        let mut start = 0;
+        for (run_text, style) in &runs {
+            let range = TextRange::new(start, start + run_text.len());
+            start = range.end;
+            let Some(style) = style else { continue };
+            let Some(decoration) = style.decoration.filter(|d| !d.is_none()) else {
+                continue;
+            };
+
+            #[allow(clippy::cast_possible_truncation)] // UI font sizes fit f32
+            let font_size = style
+                .font_size
+                .map_or(DEFAULT_FONT_SIZE * self.text_scale_factor, |s| s as f32);
+            #[allow(clippy::cast_possible_truncation)] // thickness multipliers fit f32
+            let thickness =
+                (font_size / 14.0).max(1.0) * style.decoration_thickness.map_or(1.0, |t| t as f32);
+            // A zero, negative or NaN thickness would never advance the dash
+            // and wave steps below; such a decoration draws nothing.
+            if !(thickness.is_finite() && thickness > 0.0) {
+                continue;
+            }
+            let line = DecorationLine {
+                color: style
+                    .decoration_color
+                    .or(style.foreground)
+                    .or(style.color)
+                    .unwrap_or(Color::BLACK),
+                style: style.decoration_style.unwrap_or_default(),
+                thickness,
+                offset: paint_offset,
+            };
+
+            for (line_number, text_box) in cache.layout.line_boxes_for_range(range) {
+                let Some(metrics) = lines.get(line_number) else {
+                    continue;
+                };
+                #[allow(clippy::cast_possible_truncation)] // line metrics are f32 upstream
+                let baseline = metrics.baseline as f32;
+                let (left, right) = (text_box.rect.left().get(), text_box.rect.right().get());
+
+                if decoration.has_underline() {
+                    line.paint(canvas, left, right, baseline + thickness);
+                }
+                if decoration.has_overline() {
+                    line.paint(canvas, left, right, baseline - font_size * 0.8 - thickness);
+                }
+                if decoration.has_line_through() {
+                    line.paint(
+                        canvas,
+                        left,
+                        right,
+                        baseline - font_size * 0.3 - thickness / 2.0,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// How one run's decoration lines are drawn.
+struct DecorationLine {
+    color: Color,
+    style: TextDecorationStyle,
+    thickness: f32,
+    /// Added to every recorded coordinate (the painter's paint offset).
+    offset: Offset<Pixels>,
+}
+
+impl DecorationLine {
+    /// Draws one decoration line spanning `left..right`, its top edge at `y`.
+    ///
+    /// Dots are `thickness` squares one `thickness` apart, dashes three
+    /// `thickness` long with a two-`thickness` gap, a double line is two
+    /// lines one `thickness` apart, and a wave has an amplitude and a
+    /// half-period of `thickness`.
+    fn paint(&self, canvas: &mut Canvas, left: f32, right: f32, y: f32) {
+        let t = self.thickness;
+        match self.style {
+            TextDecorationStyle::Solid => self.segment(canvas, left, right, y),
+            TextDecorationStyle::Double => {
+                self.segment(canvas, left, right, y);
+                self.segment(canvas, left, right, y + 2.0 * t);
+            }
+            TextDecorationStyle::Dotted => self.dashes(canvas, left, right, y, t, t),
+            TextDecorationStyle::Dashed => self.dashes(canvas, left, right, y, 3.0 * t, 2.0 * t),
+            TextDecorationStyle::Wavy => {
+                let mid = y + t / 2.0;
+                let mut points = vec![self.point(left, mid)];
+                let mut x = left;
+                let mut up = true;
+                while x < right {
+                    let next = (x + t).min(right);
+                    let peak = if up { mid - t / 2.0 } else { mid + t / 2.0 };
+                    points.push(self.point(next, peak));
+                    x = next;
+                    up = !up;
+                }
+                canvas.draw_polyline(&points, &Paint::stroke(self.color, t / 2.0));
+            }
+        }
+    }
+
+    /// Repeating `on`-long segments separated by `off`, clipped to `right`.
+    fn dashes(&self, canvas: &mut Canvas, left: f32, right: f32, y: f32, on: f32, off: f32) {
+        let mut x = left;
+        while x < right {
+            self.segment(canvas, x, (x + on).min(right), y);
+            x += on + off;
+        }
+    }
+
+    fn segment(&self, canvas: &mut Canvas, left: f32, right: f32, y: f32) {
+        let rect = Rect::from_ltrb(px(left), px(y), px(right), px(y + self.thickness));
+        canvas.draw_rect(rect.translate_offset(self.offset), &Paint::fill(self.color));
+    }
+
+    fn point(&self, x: f32, y: f32) -> Point<Pixels> {
+        Point::new(px(x) + self.offset.dx, px(y) + self.offset.dy)
     }
 }
@@ -3,7 +3,7 @@
 //! Tests for the zero-copy `Canvas::extend_from()` optimization and
 //! `DisplayList::append()` method.
 
-use flui_painting::prelude::*;
+use flui_painting::{PaintingError, prelude::*};
 use flui_types::{
     geometry::{Rect, px},
     styling::Color,
@@ -170,7 +170,7 @@ fn test_bounds_after_append() {
     parent.extend_from(child);
 
     let parent_list = parent.finish();
-    let bounds = parent_list.bounds();
+    let bounds = parent_list.bounds().unwrap();
 
     // Bounds should encompass both rectangles
     assert!(bounds.left().get() <= 0.0);
@@ -301,7 +301,7 @@ fn append_display_list_at_offset_actually_shifts_commands() {
     );
 
     // Bounds should reflect the offset translation.
-    let bounds = dl.bounds();
+    let bounds = dl.bounds().unwrap();
     assert!(
         bounds.left().0 >= 50.0 - 0.01,
         "bounds.left should be >= 50.0 after offset, got {}",
@@ -397,8 +397,8 @@ fn append_display_list_at_offset_matches_apply_transform_per_command() {
     // (the line carries a stroke outset, so we compare to the pre-shift
     // bounds shifted by the offset rather than to a raw geometric
     // edge).
-    let pre_bounds = child_list.bounds();
-    let bounds = dl.bounds();
+    let pre_bounds = child_list.bounds().unwrap();
+    let bounds = dl.bounds().unwrap();
     assert!(
         (bounds.left().0 - (pre_bounds.left().0 + offset.dx.0)).abs() <= 0.01,
         "bounds.left after offset should equal pre.left + dx; got {} vs {}",
@@ -412,3 +412,134 @@ fn append_display_list_at_offset_matches_apply_transform_per_command() {
         pre_bounds.top().0 + offset.dy.0,
     );
 }
+
+fn three_command_picture() -> Picture {
+    let mut canvas = Canvas::new();
+    let paint = Paint::fill(Color::RED);
+    canvas.draw_rect(
+        Rect::from_ltrb(px(0.0), px(0.0), px(10.0), px(10.0)),
+        &paint,
+    );
+    canvas.draw_rect(
+        Rect::from_ltrb(px(10.0), px(0.0), px(20.0), px(10.0)),
+        &paint,
+    );
+    canvas.draw_line(
+        flui_types::geometry::Point::new(px(0.0), px(5.0)),
+        flui_types::geometry::Point::new(px(20.0), px(5.0)),
+        &Paint::stroke(Color::BLACK, 0.0),
+    );
+    canvas.finish()
+}
+
+/// A cached picture replayed twice lands at both offsets, and replay does
+/// not disturb the canvas's own transform / save state.
+#[test]
+fn draw_picture_twice_at_different_offsets() {
+    use flui_types::geometry::{Matrix4, Offset};
+
+    let picture = three_command_picture();
+    let mut canvas = Canvas::new();
+    canvas.translate(5.0, 5.0);
+    let transform_before = canvas.transform_matrix();
+
+    canvas
+        .draw_picture(&picture, Offset::new(px(100.0), px(0.0)))
+        .unwrap();
+    canvas
+        .draw_picture(&picture, Offset::new(px(0.0), px(200.0)))
+        .unwrap();
+
+    assert_eq!(canvas.transform_matrix(), transform_before);
+    assert_eq!(canvas.save_count(), 1);
+
+    let list = canvas.finish();
+    assert_eq!(list.len(), 6);
+    let transforms: Vec<Matrix4> = list.commands().map(DrawCommand::transform).collect();
+    for (i, expected) in [
+        Matrix4::translation(105.0, 5.0, 0.0),
+        Matrix4::translation(5.0, 205.0, 0.0),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        for transform in &transforms[i * 3..i * 3 + 3] {
+            assert_eq!(*transform, expected, "replay {i}");
+        }
+    }
+    assert_eq!(
+        list.bounds(),
+        Some(Rect::from_ltrb(px(5.0), px(5.0), px(125.0), px(215.0)))
+    );
+}
+
+#[test]
+fn draw_picture_rejects_unbalanced_layers() {
+    use flui_types::geometry::Offset;
+
+    let mut recorder = Canvas::new();
+    recorder.save_layer_alpha(None, 128);
+    recorder.draw_rect(
+        Rect::from_ltrb(px(0.0), px(0.0), px(10.0), px(10.0)),
+        &Paint::fill(Color::RED),
+    );
+    recorder.restore();
+    let balanced = recorder.finish();
+    let unrestored = balanced.filter(|cmd| !matches!(cmd, DrawCommand::RestoreLayer { .. }));
+    let unopened = balanced.filter(|cmd| !matches!(cmd, DrawCommand::SaveLayer { .. }));
+
+    let mut canvas = Canvas::new();
+    for picture in [&unrestored, &unopened] {
+        let err = canvas.draw_picture(picture, Offset::ZERO).unwrap_err();
+        assert!(matches!(err, PaintingError::InvalidPicture { .. }), "{err}");
+    }
+    assert!(canvas.is_empty(), "rejected pictures record nothing");
+
+    canvas.draw_picture(&balanced, Offset::ZERO).unwrap();
+    assert_eq!(canvas.len(), 3);
+}
+
+/// A picture's clips stay inside the picture: replay scopes them in a
+/// layer, so a host draw recorded afterwards is not clipped.
+#[test]
+fn draw_picture_scopes_the_pictures_clips() {
+    use flui_types::geometry::Offset;
+
+    let mut recorder = Canvas::new();
+    recorder.clip_rect(Rect::from_ltrb(px(0.0), px(0.0), px(10.0), px(10.0)));
+    recorder.draw_rect(
+        Rect::from_ltrb(px(0.0), px(0.0), px(50.0), px(50.0)),
+        &Paint::fill(Color::RED),
+    );
+    let picture = recorder.finish();
+
+    let mut canvas = Canvas::new();
+    canvas.draw_picture(&picture, Offset::ZERO).unwrap();
+    canvas.draw_rect(
+        Rect::from_ltrb(px(100.0), px(100.0), px(120.0), px(120.0)),
+        &Paint::fill(Color::BLUE),
+    );
+
+    let list = canvas.finish();
+    let kinds: Vec<bool> = list
+        .commands()
+        .map(|cmd| matches!(cmd, DrawCommand::SaveLayer { .. }))
+        .collect();
+    assert_eq!(kinds, [true, false, false, false, false]);
+    assert!(matches!(
+        list.commands().nth(3),
+        Some(DrawCommand::RestoreLayer { .. })
+    ));
+    assert_eq!(
+        list.bounds(),
+        Some(Rect::from_ltrb(px(0.0), px(0.0), px(120.0), px(120.0))),
+        "the host rect is not cut by the picture's clip"
+    );
+
+    // A picture without clips is replayed as-is.
+    let mut canvas = Canvas::new();
+    canvas
+        .draw_picture(&three_command_picture(), Offset::ZERO)
+        .unwrap();
+    assert_eq!(canvas.len(), 3);
+}
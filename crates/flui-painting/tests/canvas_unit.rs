@@ -6,9 +6,9 @@
 //! does not carry inline `#[cfg(test)] mod tests` blocks for surface
 //! that is already exercised through the public API.
 
-use flui_painting::{Canvas, DisplayListCore, Paint};
+use flui_painting::{Canvas, DisplayListCore, DrawCommand, Paint, PaintingError, VertexMode};
 use flui_types::{
-    geometry::{Point, Rect, px},
+    geometry::{Offset, Point, Rect, px},
     styling::Color,
 };
 
@@ -222,3 +222,94 @@ fn test_draw_polyline_five_points_records_four_segments() {
     canvas.draw_polyline(&points, &paint);
     assert_eq!(canvas.finish().len(), 4);
 }
+
+fn unit_quad() -> [Offset<flui_types::geometry::Pixels>; 4] {
+    [
+        Offset::new(px(0.0), px(0.0)),
+        Offset::new(px(10.0), px(0.0)),
+        Offset::new(px(10.0), px(10.0)),
+        Offset::new(px(0.0), px(10.0)),
+    ]
+}
+
+#[test]
+fn test_draw_vertices_quad_records_vertices_and_indices() {
+    let mut canvas = Canvas::new();
+    let quad = unit_quad();
+    let colors = [Color::RED, Color::GREEN, Color::BLUE, Color::BLACK];
+    canvas
+        .draw_vertices(
+            &quad,
+            Some(&colors),
+            Some(&[0, 1, 2, 0, 2, 3]),
+            VertexMode::Triangles,
+            &Paint::fill(Color::WHITE),
+        )
+        .unwrap();
+
+    let list = canvas.finish();
+    let mut commands = list.commands();
+    let Some(DrawCommand::DrawVertices {
+        vertices,
+        colors: recorded_colors,
+        indices,
+        ..
+    }) = commands.next()
+    else {
+        panic!("expected a DrawVertices command");
+    };
+    assert!(commands.next().is_none());
+    assert_eq!(
+        *vertices,
+        quad.iter().map(|o| o.to_point()).collect::<Vec<Point<_>>>()
+    );
+    assert_eq!(recorded_colors.as_deref(), Some(&colors[..]));
+    assert_eq!(*indices, vec![0, 1, 2, 0, 2, 3]);
+}
+
+#[test]
+fn test_draw_vertices_resolves_strip_and_fan_into_triangle_lists() {
+    let quad = unit_quad();
+    let paint = Paint::fill(Color::WHITE);
+    for (mode, expected) in [
+        (VertexMode::TriangleStrip, vec![0, 1, 2, 1, 2, 3]),
+        (VertexMode::TriangleFan, vec![0, 1, 2, 0, 2, 3]),
+        (VertexMode::Triangles, vec![0, 1, 2]),
+    ] {
+        let mut canvas = Canvas::new();
+        canvas
+            .draw_vertices(&quad, None, None, mode, &paint)
+            .unwrap();
+        let list = canvas.finish();
+        let Some(DrawCommand::DrawVertices { indices, .. }) = list.commands().next() else {
+            panic!("expected a DrawVertices command for {mode:?}");
+        };
+        assert_eq!(*indices, expected, "{mode:?}");
+    }
+}
+
+#[test]
+fn test_draw_vertices_rejects_out_of_range_index_and_color_mismatch() {
+    let mut canvas = Canvas::new();
+    let quad = unit_quad();
+    let paint = Paint::fill(Color::WHITE);
+
+    let err = canvas
+        .draw_vertices(&quad, None, Some(&[0, 1, 4]), VertexMode::Triangles, &paint)
+        .unwrap_err();
+    assert!(matches!(err, PaintingError::InvalidVertices { .. }));
+    assert!(err.to_string().contains("index 4"));
+
+    let err = canvas
+        .draw_vertices(
+            &quad,
+            Some(&[Color::RED]),
+            None,
+            VertexMode::Triangles,
+            &paint,
+        )
+        .unwrap_err();
+    assert!(matches!(err, PaintingError::InvalidVertices { .. }));
+
+    assert_eq!(canvas.finish().len(), 0, "rejected meshes record nothing");
+}
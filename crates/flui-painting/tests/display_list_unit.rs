@@ -15,7 +15,7 @@ fn test_display_list_creation() {
     let display_list = DisplayList::new();
     assert!(display_list.is_empty());
     assert_eq!(display_list.len(), 0);
-    assert_eq!(display_list.bounds(), Rect::ZERO);
+    assert_eq!(display_list.bounds(), None);
 }
 
 #[test]
@@ -34,7 +34,7 @@ fn test_display_list_clear() {
 
     display_list.clear();
     assert!(display_list.is_empty());
-    assert_eq!(display_list.bounds(), Rect::ZERO);
+    assert_eq!(display_list.bounds(), None);
 }
 
 #[test]
@@ -52,7 +52,7 @@ fn test_display_list_apply_transform_via_canvas() {
     dl.apply_transform(translation);
 
     // Bounds should have shifted (left + 50.0)
-    assert!(dl.bounds().left() > px(0.0));
+    assert!(dl.bounds().unwrap().left() > px(0.0));
 }
 
 #[test]
@@ -279,3 +279,99 @@ fn interning_distinguishes_paints_with_different_shaders() {
     assert!(p0.shader.is_none());
     assert!(p1.shader.is_some());
 }
+
+fn ltrb(l: f32, t: f32, r: f32, b: f32) -> Rect<flui_types::geometry::Pixels> {
+    Rect::from_ltrb(px(l), px(t), px(r), px(b))
+}
+
+#[test]
+fn test_display_list_bounds_union_of_transformed_rects() {
+    let mut canvas = Canvas::new();
+    let paint = Paint::fill(Color::RED);
+    canvas.draw_rect(ltrb(0.0, 0.0, 10.0, 10.0), &paint);
+
+    canvas.save();
+    canvas.translate(100.0, 50.0);
+    canvas.draw_rect(ltrb(0.0, 0.0, 10.0, 10.0), &paint);
+    canvas.restore();
+
+    canvas.save();
+    canvas.translate(-20.0, 0.0);
+    canvas.scale_uniform(2.0);
+    canvas.draw_rect(ltrb(0.0, 10.0, 5.0, 20.0), &paint);
+    canvas.restore();
+
+    let list = canvas.finish();
+    assert_eq!(list.bounds(), Some(ltrb(-20.0, 0.0, 110.0, 60.0)));
+}
+
+#[test]
+fn test_display_list_bounds_include_stroke_outset() {
+    let mut canvas = Canvas::new();
+    canvas.draw_rect(
+        ltrb(10.0, 10.0, 20.0, 20.0),
+        &Paint::stroke(Color::BLACK, 4.0),
+    );
+    assert_eq!(canvas.finish().bounds(), Some(ltrb(8.0, 8.0, 22.0, 22.0)));
+}
+
+#[test]
+fn test_display_list_bounds_tightened_by_clips() {
+    let mut canvas = Canvas::new();
+    let paint = Paint::fill(Color::RED);
+
+    canvas.save_layer(None, &paint);
+    canvas.clip_rect(ltrb(0.0, 0.0, 50.0, 50.0));
+    canvas.draw_rect(ltrb(25.0, 25.0, 200.0, 200.0), &paint);
+    canvas.draw_color(Color::BLUE, BlendMode::SrcOver);
+    canvas.restore();
+    assert_eq!(canvas.bounds(), Some(ltrb(0.0, 0.0, 50.0, 50.0)));
+
+    // The clip ended with its layer.
+    canvas.draw_rect(ltrb(90.0, 90.0, 100.0, 100.0), &paint);
+    assert_eq!(canvas.bounds(), Some(ltrb(0.0, 0.0, 100.0, 100.0)));
+
+    // An unclipped DrawColor has no finite bounds to contribute.
+    canvas.draw_color(Color::BLUE, BlendMode::SrcOver);
+    assert_eq!(canvas.finish().bounds(), Some(ltrb(0.0, 0.0, 100.0, 100.0)));
+}
+
+#[test]
+fn test_display_list_bounds_cache_invalidated_on_append() {
+    let paint = Paint::fill(Color::RED);
+    let mut first = Canvas::new();
+    first.draw_rect(ltrb(0.0, 0.0, 10.0, 10.0), &paint);
+    let mut list = first.finish();
+    assert_eq!(list.bounds(), Some(ltrb(0.0, 0.0, 10.0, 10.0)));
+
+    let mut second = Canvas::new();
+    second.draw_rect(ltrb(30.0, 30.0, 40.0, 40.0), &paint);
+    list.append(&second.finish());
+    assert_eq!(list.bounds(), Some(ltrb(0.0, 0.0, 40.0, 40.0)));
+}
+
+#[test]
+fn test_display_list_generation_tracks_content() {
+    let paint = Paint::fill(Color::RED);
+    let record = |color| {
+        let mut canvas = Canvas::new();
+        canvas.draw_rect(ltrb(0.0, 0.0, 10.0, 10.0), &Paint::fill(color));
+        canvas.finish()
+    };
+
+    assert_eq!(
+        DisplayList::new().generation(),
+        DisplayList::new().generation()
+    );
+
+    let list = record(Color::RED);
+    let retained = list.clone();
+    assert_eq!(retained.generation(), list.generation());
+    assert_ne!(record(Color::BLUE).generation(), list.generation());
+
+    let mut mutated = list.clone();
+    let mut extra = Canvas::new();
+    extra.draw_rect(ltrb(0.0, 0.0, 5.0, 5.0), &paint);
+    mutated.append(&extra.finish());
+    assert_ne!(mutated.generation(), list.generation());
+}
@@ -2,12 +2,24 @@
 //! `crates/flui-painting/src/text_painter/mod.rs` during the text-painter
 //! module split.
 
-use flui_painting::{DEFAULT_FONT_SIZE, TextBaseline, TextPainter};
+use flui_painting::{Canvas, DEFAULT_FONT_SIZE, DrawCommand, TextBaseline, TextPainter};
 use flui_types::{
+    Color,
     geometry::{Offset, px},
-    typography::{TextAlign, TextDirection, TextPosition, TextSpan},
+    typography::{
+        TextAlign, TextDecoration, TextDecorationStyle, TextDirection, TextPosition, TextSpan,
+        TextStyle,
+    },
 };
 
+fn laid_out(text: &str, style: TextStyle, max_width: f32) -> TextPainter {
+    let mut painter = TextPainter::new()
+        .with_text(TextSpan::new(text).with_style(style.with_font_size(16.0)))
+        .with_text_direction(TextDirection::Ltr);
+    painter.layout(0.0, max_width);
+    painter
+}
+
 #[test]
 fn test_text_painter_new() {
     let painter = TextPainter::new();
@@ -151,3 +163,204 @@ fn test_get_word_boundary() {
     assert!(boundary.start <= 2);
     assert!(boundary.end >= 2);
 }
+
+#[test]
+fn letter_spacing_widens_measured_text() {
+    let plain = laid_out("spacing", TextStyle::new(), f32::INFINITY);
+    let spaced = laid_out(
+        "spacing",
+        TextStyle::new().with_letter_spacing(4.0),
+        f32::INFINITY,
+    );
+    // Seven glyphs, 4px each.
+    assert!(
+        spaced.width() >= plain.width() + 7.0 * 4.0 - 1.0,
+        "{} vs {}",
+        spaced.width(),
+        plain.width()
+    );
+}
+
+#[test]
+fn word_spacing_widens_only_the_spaces() {
+    let plain = laid_out("a b c", TextStyle::new(), f32::INFINITY);
+    let spaced = laid_out(
+        "a b c",
+        TextStyle::new().with_word_spacing(10.0),
+        f32::INFINITY,
+    );
+    assert!(
+        (spaced.width() - plain.width() - 20.0).abs() < 1.0,
+        "two spaces × 10px: {} vs {}",
+        spaced.width(),
+        plain.width()
+    );
+
+    // Spaceless text is unaffected.
+    let word = laid_out(
+        "abc",
+        TextStyle::new().with_word_spacing(10.0),
+        f32::INFINITY,
+    );
+    let word_plain = laid_out("abc", TextStyle::new(), f32::INFINITY);
+    assert_eq!(word.width(), word_plain.width());
+}
+
+#[test]
+fn line_height_scales_paragraph_height() {
+    let text = "one two three four five six";
+    let normal = laid_out(text, TextStyle::new().with_height(1.0), 60.0);
+    let tall = laid_out(text, TextStyle::new().with_height(2.0), 60.0);
+    let lines = normal.get_line_metrics().len();
+    assert!(lines > 1);
+    assert_eq!(tall.get_line_metrics().len(), lines);
+    assert!(
+        (tall.height() - 2.0 * normal.height()).abs() < 1.0,
+        "{} vs {}",
+        tall.height(),
+        normal.height()
+    );
+}
+
+#[test]
+fn decoration_paints_a_rect_per_line() {
+    let draw_rects = |style: TextStyle| {
+        let painter = laid_out("underlined text here", style, 80.0);
+        let mut canvas = Canvas::new();
+        painter.paint(&mut canvas, Offset::ZERO);
+        let rects: Vec<_> = canvas
+            .finish()
+            .iter()
+            .filter_map(|command| match command {
+                DrawCommand::DrawRect { rect, .. } => Some(*rect),
+                _ => None,
+            })
+            .collect();
+        (painter, rects)
+    };
+
+    let (_, none) = draw_rects(TextStyle::new());
+    assert!(none.is_empty());
+
+    let (painter, underlines) =
+        draw_rects(TextStyle::new().with_decoration(TextDecoration::UNDERLINE));
+    let lines = painter.get_line_metrics();
+    assert_eq!(underlines.len(), lines.len());
+    for (rect, line) in underlines.iter().zip(&lines) {
+        // Just below the baseline of its own line.
+        assert!(f64::from(rect.top().get()) > line.baseline);
+        assert!(f64::from(rect.top().get()) < line.baseline + line.descent);
+    }
+
+    let (_, both) = draw_rects(TextStyle::new().with_decoration(TextDecoration::combine(&[
+        TextDecoration::UNDERLINE,
+        TextDecoration::LINE_THROUGH,
+    ])));
+    assert_eq!(both.len(), 2 * lines.len());
+}
+
+#[test]
+fn word_spacing_applies_to_unicode_whitespace() {
+    // U+00A0 NO-BREAK SPACE counts as inter-word whitespace too.
+    let plain = laid_out("a\u{a0}b", TextStyle::new(), f32::INFINITY);
+    let spaced = laid_out(
+        "a\u{a0}b",
+        TextStyle::new().with_word_spacing(10.0),
+        f32::INFINITY,
+    );
+    assert!(
+        (spaced.width() - plain.width() - 10.0).abs() < 1.0,
+        "{} vs {}",
+        spaced.width(),
+        plain.width()
+    );
+}
+
+/// The draw commands a single-line underlined `text` records.
+fn underline_commands(style: TextStyle) -> (TextPainter, Vec<DrawCommand>) {
+    let painter = laid_out(
+        "underlined",
+        style.with_decoration(TextDecoration::UNDERLINE),
+        f32::INFINITY,
+    );
+    let mut canvas = Canvas::new();
+    painter.paint(&mut canvas, Offset::ZERO);
+    let commands = canvas
+        .finish()
+        .iter()
+        .filter(|command| {
+            matches!(
+                command,
+                DrawCommand::DrawRect { .. } | DrawCommand::DrawLine { .. }
+            )
+        })
+        .cloned()
+        .collect();
+    (painter, commands)
+}
+
+#[test]
+fn decoration_honors_color_and_thickness() {
+    let (_, plain) = underline_commands(TextStyle::new().with_color(Color::RED));
+    let (_, styled) = underline_commands(
+        TextStyle::new()
+            .with_color(Color::RED)
+            .with_decoration_color(Color::BLUE)
+            .with_decoration_thickness(3.0),
+    );
+    let rect_of = |commands: &[DrawCommand]| match &commands[0] {
+        DrawCommand::DrawRect { rect, paint, .. } => (*rect, paint.color),
+        other => panic!("expected a rect, got {other:?}"),
+    };
+
+    let (plain_rect, plain_color) = rect_of(&plain);
+    assert_eq!(plain_color, Color::RED, "defaults to the text color");
+    let (rect, color) = rect_of(&styled);
+    assert_eq!(color, Color::BLUE);
+    assert!(
+        (rect.height().get() - 3.0 * plain_rect.height().get()).abs() < 1e-3,
+        "{} vs {}",
+        rect.height().get(),
+        plain_rect.height().get()
+    );
+}
+
+#[test]
+fn decoration_style_changes_the_drawn_line() {
+    let count = |style: TextDecorationStyle| {
+        underline_commands(TextStyle::new().with_decoration_style(style))
+            .1
+            .len()
+    };
+    assert_eq!(count(TextDecorationStyle::Solid), 1);
+    assert_eq!(count(TextDecorationStyle::Double), 2);
+    assert!(count(TextDecorationStyle::Dashed) > 2);
+    assert!(count(TextDecorationStyle::Dotted) > count(TextDecorationStyle::Dashed));
+
+    let (_, wavy) =
+        underline_commands(TextStyle::new().with_decoration_style(TextDecorationStyle::Wavy));
+    assert!(
+        wavy.iter()
+            .all(|command| matches!(command, DrawCommand::DrawLine { .. })),
+        "a wave is stroked, not filled"
+    );
+}
+
+#[test]
+fn decoration_without_a_positive_thickness_draws_nothing() {
+    for thickness in [0.0, -1.0, f64::NAN] {
+        for style in [
+            TextDecorationStyle::Solid,
+            TextDecorationStyle::Dashed,
+            TextDecorationStyle::Dotted,
+            TextDecorationStyle::Wavy,
+        ] {
+            let (_, commands) = underline_commands(
+                TextStyle::new()
+                    .with_decoration_style(style)
+                    .with_decoration_thickness(thickness),
+            );
+            assert!(commands.is_empty(), "{thickness} {style:?}: {commands:?}");
+        }
+    }
+}
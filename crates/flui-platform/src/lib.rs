@@ -200,11 +200,11 @@
 pub use task::{Priority, Task, TaskLabel};
 // Re-export core traits
 pub use traits::{
-    Clipboard, ClipboardItem, DesktopCapabilities, DispatchEventResult, DisplayId,
-    MobileCapabilities, PathPromptOptions, Platform, PlatformCapabilities, PlatformDisplay,
-    PlatformEmbedder, PlatformExecutor, PlatformHaptics, PlatformReadyCallback, PlatformTextInput,
-    PlatformWindow, WebCapabilities, WindowAppearance, WindowBackgroundAppearance, WindowBounds,
-    WindowEvent, WindowId, WindowMode, WindowOptions,
+    Clipboard, ClipboardImage, ClipboardItem, DesktopCapabilities, DispatchEventResult, DisplayId,
+    MobileCapabilities, NoopClipboard, PathPromptOptions, Platform, PlatformCapabilities,
+    PlatformDisplay, PlatformEmbedder, PlatformExecutor, PlatformHaptics, PlatformReadyCallback,
+    PlatformTextInput, PlatformWindow, QuitRequester, WebCapabilities, WindowAppearance,
+    WindowBackgroundAppearance, WindowBounds, WindowEvent, WindowId, WindowMode, WindowOptions,
 };
 
 /// Get the current platform implementation
@@ -18,7 +18,7 @@
     traits::{
         Clipboard, ClipboardItem, DesktopCapabilities, DispatchEventResult, Platform,
         PlatformCapabilities, PlatformDisplay, PlatformExecutor, PlatformHaptics, PlatformInput,
-        PlatformReadyCallback, PlatformTextInput, PlatformWindow, WindowAppearance,
+        PlatformReadyCallback, PlatformTextInput, PlatformWindow, QuitRequester, WindowAppearance,
         WindowBackgroundAppearance, WindowBounds, WindowEvent, WindowId, WindowOptions,
     },
 };
@@ -68,7 +68,7 @@ pub fn new() -> Self {
         };
 
         Self {
-            capabilities: DesktopCapabilities,
+            capabilities: DesktopCapabilities::new(),
             state: Arc::new(Mutex::new(state)),
         }
     }
@@ -125,6 +125,17 @@ fn quit(&self) {
         });
     }
 
+    fn quit_requester(&self) -> Option<QuitRequester> {
+        // No event loop to wake: quit synchronously, like `quit()`.
+        let state = Arc::clone(&self.state);
+        Some(Arc::new(move || {
+            tracing::info!("Quitting headless platform");
+            let mut state = state.lock();
+            state.is_running = false;
+            state.handlers.invoke_quit();
+        }))
+    }
+
     fn open_window(&self, options: WindowOptions) -> Result<Box<dyn PlatformWindow>> {
         tracing::info!(?options, "Creating mock window");
 
@@ -287,14 +298,14 @@ fn new(id: WindowId, options: WindowOptions) -> Self {
             state: Arc::new(Mutex::new(MockWindowState {
                 title: options.title.clone(),
                 bounds: Bounds {
-                    origin: Point::default(),
+                    origin: options.position.unwrap_or_default(),
                     size: options.size,
                 },
                 scale_factor: 1.0,
                 focused: true,
                 visible: options.visible,
                 maximized: false,
-                fullscreen: false,
+                fullscreen: options.fullscreen,
                 hovered: false,
                 modifiers: keyboard_types::Modifiers::empty(),
                 appearance: WindowAppearance::default(),
@@ -754,6 +765,23 @@ fn test_headless_platform_creation() {
         assert!(platform.active_window().is_none());
     }
 
+    #[test]
+    fn test_quit_requester_fires_on_quit_from_another_thread() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let platform = HeadlessPlatform::new();
+        let quits = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&quits);
+        platform.on_quit(Box::new(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        let requester = platform.quit_requester().expect("headless can quit");
+        std::thread::spawn(move || requester()).join().unwrap();
+
+        assert_eq!(quits.load(Ordering::SeqCst), 1);
+    }
+
     #[test]
     fn test_mock_clipboard() {
         let clipboard = MockClipboard::new();
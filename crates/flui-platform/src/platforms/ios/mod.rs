@@ -127,8 +127,8 @@
 
 /// iOS platform implementation (stub)
 ///
-/// This is a placeholder for future native iOS support. All methods
-/// currently return `unimplemented!()`.
+/// This is a placeholder for future native iOS support. Most methods
+/// currently return `unimplemented!()`; the clipboard is a [`NoopClipboard`].
 ///
 /// # Future Implementation
 ///
@@ -205,7 +205,8 @@ fn open_window(&self, _options: WindowOptions) -> Result<Box<dyn PlatformWindow>
     }
 
     fn clipboard(&self) -> Arc<dyn Clipboard> {
-        unimplemented!("iOS UIPasteboard not implemented")
+        // UIPasteboard not implemented yet
+        Arc::new(NoopClipboard)
     }
 
     fn capabilities(&self) -> &dyn PlatformCapabilities {
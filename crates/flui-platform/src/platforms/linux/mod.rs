@@ -80,14 +80,15 @@
 pub use window_ext::*;
 
 use crate::traits::{
-    Clipboard, Platform, PlatformCapabilities, PlatformDisplay, PlatformExecutor,
+    Clipboard, NoopClipboard, Platform, PlatformCapabilities, PlatformDisplay, PlatformExecutor,
     PlatformReadyCallback, PlatformWindow, WindowEvent, WindowId, WindowOptions,
 };
 
 /// Linux platform implementation (stub)
 ///
 /// This is a placeholder for future native Linux support (Wayland + X11).
-/// All methods currently return `unimplemented!()`.
+/// Most methods currently return `unimplemented!()`; the clipboard is a
+/// [`NoopClipboard`].
 ///
 /// # Future Implementation
 ///
@@ -167,7 +168,8 @@ fn open_window(&self, _options: WindowOptions) -> Result<Box<dyn PlatformWindow>
     }
 
     fn clipboard(&self) -> Arc<dyn Clipboard> {
-        unimplemented!("Linux clipboard (wayland-data-device/X11 CLIPBOARD) not implemented")
+        // wayland-data-device/X11 CLIPBOARD not implemented yet
+        Arc::new(NoopClipboard)
     }
 
     fn capabilities(&self) -> &dyn PlatformCapabilities {
@@ -46,10 +46,11 @@
 /// Process-start epoch for monotonic event timestamps.
 static PROCESS_START: LazyLock<Instant> = LazyLock::new(Instant::now);
 
-/// Get monotonic timestamp in milliseconds since process start.
+/// Get monotonic timestamp in nanoseconds since process start, the unit
+/// `ui_events::pointer::PointerState::time` expects.
 #[inline]
-fn event_timestamp_ms() -> u64 {
-    PROCESS_START.elapsed().as_millis() as u64
+fn event_timestamp_ns() -> u64 {
+    u64::try_from(PROCESS_START.elapsed().as_nanos()).unwrap_or(u64::MAX)
 }
 
 /// Create a `PointerInfo` for the primary mouse pointer.
@@ -336,7 +337,7 @@ unsafe fn pointer_state(ns_event: id, scale_factor: f64, view_height: f64) -> Po
         let buttons = extract_mouse_buttons();
 
         PointerState {
-            time: event_timestamp_ms(),
+            time: event_timestamp_ns(),
             position: PhysicalPosition::new(location.x, view_height - location.y),
             buttons,
             modifiers,
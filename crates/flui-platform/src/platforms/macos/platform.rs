@@ -6,6 +6,7 @@
 use cocoa::{
     appkit::{NSApp, NSApplication, NSApplicationActivationPolicyRegular},
     base::{YES, id, nil},
+    foundation::NSPoint,
 };
 use objc::{class, msg_send, sel, sel_impl};
 use parking_lot::Mutex;
@@ -17,13 +18,16 @@
     shared::PlatformHandlers,
     traits::{
         Clipboard, DesktopCapabilities, Platform, PlatformCapabilities, PlatformDisplay,
-        PlatformExecutor, PlatformReadyCallback, PlatformWindow, WindowEvent, WindowId,
-        WindowOptions,
+        PlatformExecutor, PlatformReadyCallback, PlatformWindow, QuitRequester, WindowEvent,
+        WindowId, WindowOptions,
     },
 };
 
+/// `NSEventTypeApplicationDefined`.
+const NS_APPLICATION_DEFINED: u64 = 15;
+
 /// Capabilities descriptor shared by all macOS platform instances.
-static MACOS_CAPABILITIES: DesktopCapabilities = DesktopCapabilities;
+static MACOS_CAPABILITIES: DesktopCapabilities = DesktopCapabilities::new();
 
 /// macOS platform state
 pub struct MacOSPlatform {
@@ -126,6 +130,10 @@ fn run(self: Box<Self>, on_finish_launching: PlatformReadyCallback) {
             tracing::info!("Starting NSApplication event loop");
             self.app.run();
         }
+
+        // `run` only returns after a `quit_requester` stop; `terminate:`
+        // exits the process from inside it.
+        self.handlers.lock().invoke_quit();
     }
 
     fn quit(&self) {
@@ -136,8 +144,51 @@ fn quit(&self) {
         }
     }
 
+    fn quit_requester(&self) -> Option<QuitRequester> {
+        // The raw pointer is not `Send`; carry it as an integer, like
+        // `windows` keys.
+        let app = self.app as usize;
+        Some(Arc::new(move || {
+            tracing::info!("Quit requested");
+            let app = app as id;
+            // SAFETY: `app` is the NSApplication singleton, which lives for
+            // the whole process. `performSelectorOnMainThread:` and
+            // `postEvent:atStart:` are documented as callable from any
+            // thread. `stop:` (unlike `terminate:`) makes `run` return, so
+            // the runner's exit code survives; it only takes effect after
+            // the next event, hence the application-defined wake-up event,
+            // posted once `stop:` has run on the main thread.
+            unsafe {
+                let _: () = msg_send![
+                    app,
+                    performSelectorOnMainThread: sel!(stop:)
+                    withObject: nil
+                    waitUntilDone: YES
+                ];
+                let wake: id = msg_send![
+                    class!(NSEvent),
+                    otherEventWithType: NS_APPLICATION_DEFINED
+                    location: NSPoint::new(0.0, 0.0)
+                    modifierFlags: 0_u64
+                    timestamp: 0.0_f64
+                    windowNumber: 0_i64
+                    context: nil
+                    subtype: 0_i16
+                    data1: 0_i64
+                    data2: 0_i64
+                ];
+                let _: () = msg_send![app, postEvent: wake atStart: YES];
+            }
+        }))
+    }
+
     fn open_window(&self, options: WindowOptions) -> Result<Box<dyn PlatformWindow>> {
-        let window = MacOSWindow::new(options, Arc::clone(&self.windows), self.config.clone())?;
+        let window = MacOSWindow::new(
+            options,
+            Arc::clone(&self.windows),
+            Arc::clone(&self.handlers),
+            self.config.clone(),
+        )?;
 
         Ok(Box::new(window))
     }
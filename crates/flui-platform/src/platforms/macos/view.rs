@@ -20,6 +20,9 @@
 //!     ↓
 //! WindowCallbacks::dispatch_input
 //! ```
+//!
+//! `viewDidChangeEffectiveAppearance` reports light/dark changes through the
+//! window's [`AppearanceObserver`].
 
 use std::sync::Weak;
 
@@ -35,7 +38,10 @@
     sel, sel_impl,
 };
 
-use super::events::convert_ns_event;
+use super::{
+    events::convert_ns_event,
+    window::{AppearanceObserver, window_appearance_of},
+};
 use crate::shared::WindowCallbacks;
 
 // ============================================================================
@@ -50,6 +56,7 @@ pub fn create_content_view(
     frame: NSRect,
     scale_factor: f64,
     callbacks: Weak<WindowCallbacks>,
+    appearance_observer: Weak<AppearanceObserver>,
 ) -> id {
     // SAFETY: FLUIContentView is registered before alloc/init; the boxed
     // ViewContext pointer is stored in the view's ivar and released in
@@ -63,6 +70,7 @@ pub fn create_content_view(
         let context = Box::into_raw(Box::new(ViewContext {
             scale_factor,
             callbacks,
+            appearance_observer,
         })) as *mut std::ffi::c_void;
         (*view).set_ivar("context_ptr", context);
 
@@ -74,6 +82,7 @@ pub fn create_content_view(
 struct ViewContext {
     scale_factor: f64,
     callbacks: Weak<WindowCallbacks>,
+    appearance_observer: Weak<AppearanceObserver>,
 }
 
 // ============================================================================
@@ -144,6 +153,22 @@ extern "C" fn draw_rect(this: &Object, _sel: Sel, dirty_rect: NSRect) {
     }
 }
 
+/// viewDidChangeEffectiveAppearance — the system light/dark appearance (or
+/// the window's override) changed.
+extern "C" fn view_did_change_effective_appearance(this: &Object, _sel: Sel) {
+    // SAFETY: `this` is a live FLUIContentView; `effectiveAppearance`
+    // returns nil or a live NSAppearance.
+    unsafe {
+        if let Some(ctx) = get_context(this)
+            && let Some(callbacks) = ctx.callbacks.upgrade()
+            && let Some(observer) = ctx.appearance_observer.upgrade()
+        {
+            let appearance: id = msg_send![this, effectiveAppearance];
+            observer.appearance_changed(&callbacks, window_appearance_of(appearance));
+        }
+    }
+}
+
 /// Get or create the FLUIContentView class
 fn get_or_create_view_class() -> &'static Class {
     use std::sync::Once;
@@ -321,6 +346,12 @@ extern "C" fn accepts_touch_events(_this: &Object, _sel: Sel) -> BOOL {
                 draw_rect as extern "C" fn(&Object, Sel, NSRect),
             );
 
+            // Appearance (light/dark)
+            decl.add_method(
+                sel!(viewDidChangeEffectiveAppearance),
+                view_did_change_effective_appearance as extern "C" fn(&Object, Sel),
+            );
+
             // Lifecycle
             decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
 
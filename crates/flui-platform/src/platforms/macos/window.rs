@@ -8,7 +8,10 @@
     base::{BOOL, NO, YES, id, nil},
     foundation::NSRect,
 };
-use flui_types::geometry::{Bounds, DevicePixels, Pixels, Point, Size};
+use flui_types::{
+    geometry::{Bounds, DevicePixels, Pixels, Point, Size},
+    platform::Brightness,
+};
 use objc::{
     class,
     declare::ClassDecl,
@@ -25,8 +28,11 @@
 use super::view;
 use crate::{
     config::WindowConfiguration,
-    shared::WindowCallbacks,
-    traits::{DispatchEventResult, PlatformInput, PlatformWindow, WindowOptions},
+    shared::{PlatformHandlers, WindowCallbacks},
+    traits::{
+        DispatchEventResult, PlatformInput, PlatformWindow, WindowAppearance, WindowEvent,
+        WindowId, WindowOptions,
+    },
 };
 
 /// macOS window wrapper around NSWindow
@@ -43,6 +49,9 @@ pub struct MacOSWindow {
     /// Per-window callbacks (input, resize, close, ...)
     callbacks: Arc<WindowCallbacks>,
 
+    /// Reports light/dark changes seen by the content view
+    appearance_observer: Arc<AppearanceObserver>,
+
     /// Window configuration
     _config: WindowConfiguration,
 }
@@ -65,11 +74,87 @@ struct MacOSWindowState {
     scale_factor: f64,
 }
 
+/// Turns the content view's `viewDidChangeEffectiveAppearance` into the
+/// per-window `on_appearance_changed` callback and, when the light/dark
+/// brightness actually flipped, a platform
+/// [`WindowEvent::PlatformBrightnessChanged`].
+pub(super) struct AppearanceObserver {
+    window_id: WindowId,
+    handlers: Arc<Mutex<PlatformHandlers>>,
+    /// Last brightness reported, so accent-only changes stay silent.
+    brightness: Mutex<Brightness>,
+}
+
+impl AppearanceObserver {
+    /// Handle the window's effective appearance changing to `appearance`.
+    pub(super) fn appearance_changed(
+        &self,
+        callbacks: &WindowCallbacks,
+        appearance: WindowAppearance,
+    ) {
+        callbacks.dispatch_appearance_changed();
+
+        let brightness = appearance.brightness();
+        if std::mem::replace(&mut *self.brightness.lock(), brightness) == brightness {
+            return;
+        }
+        tracing::debug!(?brightness, "Platform brightness changed");
+
+        // Take the handler out so it can re-enter the platform without
+        // deadlocking, then put it back.
+        let handler = self.handlers.lock().window_event.take();
+        if let Some(mut handler) = handler {
+            handler(WindowEvent::PlatformBrightnessChanged {
+                window_id: self.window_id,
+                brightness,
+            });
+            self.handlers.lock().window_event = Some(handler);
+        }
+    }
+}
+
+/// The [`WindowAppearance`] of an `NSAppearance*`, judged by its name.
+///
+/// # Safety
+///
+/// `appearance` must be nil or a live `NSAppearance`.
+pub(super) unsafe fn window_appearance_of(appearance: id) -> WindowAppearance {
+    // SAFETY: per the function contract `appearance` is nil or live; `name`
+    // and its UTF8String buffer are nil-checked and copied before returning.
+    unsafe {
+        if appearance == nil {
+            return WindowAppearance::default();
+        }
+        let name: id = msg_send![appearance, name];
+        if name == nil {
+            return WindowAppearance::default();
+        }
+        let c_str: *const i8 = msg_send![name, UTF8String];
+        if c_str.is_null() {
+            return WindowAppearance::default();
+        }
+        appearance_from_name(&std::ffi::CStr::from_ptr(c_str).to_string_lossy())
+    }
+}
+
+/// Map an AppKit appearance name (`NSAppearanceNameDarkAqua`,
+/// `NSAppearanceNameVibrantLight`, the high-contrast variants, ...) to a
+/// [`WindowAppearance`].
+fn appearance_from_name(name: &str) -> WindowAppearance {
+    match (name.contains("Vibrant"), name.contains("Dark")) {
+        (false, false) => WindowAppearance::Light,
+        (false, true) => WindowAppearance::Dark,
+        (true, false) => WindowAppearance::VibrantLight,
+        (true, true) => WindowAppearance::VibrantDark,
+    }
+}
+
 impl MacOSWindow {
     /// Create a new macOS window
     pub fn new(
         options: WindowOptions,
         windows_map: Arc<Mutex<HashMap<u64, Arc<MacOSWindow>>>>,
+        handlers: Arc<Mutex<PlatformHandlers>>,
         config: WindowConfiguration,
     ) -> Result<Arc<Self>> {
         // SAFETY: must run on the main thread (enforced by the platform's
@@ -135,31 +220,61 @@ pub fn new(
                 let _: () = msg_send![ns_window, makeKeyAndOrderFront: nil];
             }
 
-            // Center window on screen
-            let _: () = msg_send![ns_window, center];
+            // Place the window's top-left at the requested position, else
+            // center it. `position` is top-left-origin logical pixels;
+            // AppKit's screen space is bottom-left-origin, anchored at the
+            // primary (first) screen.
+            if let Some(position) = options.position {
+                let screens: id = msg_send![class!(NSScreen), screens];
+                let primary: id = msg_send![screens, firstObject];
+                let primary_height = if primary == nil {
+                    0.0
+                } else {
+                    let primary_frame: NSRect = msg_send![primary, frame];
+                    primary_frame.size.height
+                };
+                let top_left = cocoa::foundation::NSPoint::new(
+                    f64::from(position.x.0),
+                    primary_height - f64::from(position.y.0),
+                );
+                let _: () = msg_send![ns_window, setFrameTopLeftPoint: top_left];
+            } else {
+                let _: () = msg_send![ns_window, center];
+            }
 
             let callbacks = Arc::new(WindowCallbacks::new());
+            let appearance: id = msg_send![ns_window, effectiveAppearance];
+            let appearance_observer = Arc::new(AppearanceObserver {
+                window_id: WindowId(ns_window as u64),
+                handlers,
+                brightness: Mutex::new(window_appearance_of(appearance).brightness()),
+            });
 
             let window = Arc::new(Self {
                 ns_window,
                 state: Arc::new(Mutex::new(MacOSWindowState {
                     bounds: Bounds {
-                        origin: Point::new(
+                        origin: options.position.unwrap_or(Point::new(
                             flui_types::geometry::px(frame.origin.x as f32),
                             flui_types::geometry::px(frame.origin.y as f32),
-                        ),
+                        )),
                         size: options.size,
                     },
                     scale_factor: scale,
                 })),
                 windows_map: Arc::clone(&windows_map),
                 callbacks,
+                appearance_observer,
                 _config: config,
             });
 
-            // Create content view for input events
-            let content_view =
-                view::create_content_view(frame, scale, Arc::downgrade(&window.callbacks));
+            // Create content view for input and appearance events
+            let content_view = view::create_content_view(
+                frame,
+                scale,
+                Arc::downgrade(&window.callbacks),
+                Arc::downgrade(&window.appearance_observer),
+            );
             let _: () = msg_send![ns_window, setContentView: content_view];
 
             // Enable mouse tracking for mouse moved events
@@ -176,6 +291,13 @@ pub fn new(
             let window_id = ns_window as u64;
             windows_map.lock().insert(window_id, Arc::clone(&window));
 
+            // Fullscreen on the window's screen, the same transition
+            // `set_state(WindowState::Fullscreen)` takes; after the delegate
+            // is set so it observes the transition.
+            if options.fullscreen {
+                let _: () = msg_send![ns_window, toggleFullScreen: nil];
+            }
+
             tracing::info!(
                 "Created NSWindow {:p} with size {}x{} (scale: {})",
                 ns_window,
@@ -220,6 +342,15 @@ fn scale_factor(&self) -> f64 {
         state.scale_factor
     }
 
+    fn appearance(&self) -> WindowAppearance {
+        // SAFETY: `ns_window` is alive for the lifetime of `self`;
+        // `effectiveAppearance` returns nil or a live NSAppearance.
+        unsafe {
+            let appearance: id = msg_send![self.ns_window, effectiveAppearance];
+            window_appearance_of(appearance)
+        }
+    }
+
     fn request_redraw(&self) {
         // SAFETY: `ns_window` is alive for the lifetime of `self`; the
         // content view is nil-checked before messaging.
@@ -423,6 +554,10 @@ fn scale_factor(&self) -> f64 {
         PlatformWindow::scale_factor(self.as_ref())
     }
 
+    fn appearance(&self) -> WindowAppearance {
+        PlatformWindow::appearance(self.as_ref())
+    }
+
     fn request_redraw(&self) {
         PlatformWindow::request_redraw(self.as_ref())
     }
@@ -573,6 +708,7 @@ fn clone(&self) -> Self {
             state: Arc::clone(&self.state),
             windows_map: Arc::clone(&self.windows_map),
             callbacks: Arc::clone(&self.callbacks),
+            appearance_observer: Arc::clone(&self.appearance_observer),
             _config: self._config.clone(),
         }
     }
@@ -947,6 +1083,7 @@ fn clear_liquid_glass(&mut self) {
                 NSRect::new(cocoa::foundation::NSPoint::new(0.0, 0.0), frame.size),
                 PlatformWindow::scale_factor(self),
                 Arc::downgrade(&self.callbacks),
+                Arc::downgrade(&self.appearance_observer),
             );
 
             let _: () = msg_send![self.ns_window, setContentView: content_view];
@@ -1421,3 +1558,27 @@ fn handle_screen_changed(&self) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appearance_names_map_to_light_dark_and_vibrancy() {
+        for (name, expected) in [
+            ("NSAppearanceNameAqua", WindowAppearance::Light),
+            ("NSAppearanceNameDarkAqua", WindowAppearance::Dark),
+            (
+                "NSAppearanceNameAccessibilityHighContrastDarkAqua",
+                WindowAppearance::Dark,
+            ),
+            (
+                "NSAppearanceNameVibrantLight",
+                WindowAppearance::VibrantLight,
+            ),
+            ("NSAppearanceNameVibrantDark", WindowAppearance::VibrantDark),
+        ] {
+            assert_eq!(appearance_from_name(name), expected, "{name}");
+        }
+    }
+}
@@ -27,10 +27,11 @@
 /// Process-start epoch for monotonic event timestamps.
 static PROCESS_START: LazyLock<Instant> = LazyLock::new(Instant::now);
 
-/// Get monotonic timestamp in milliseconds since process start.
+/// Get monotonic timestamp in nanoseconds since process start, the unit
+/// `ui_events::pointer::PointerState::time` expects.
 #[inline]
-fn event_timestamp_ms() -> u64 {
-    PROCESS_START.elapsed().as_millis() as u64
+fn event_timestamp_ns() -> u64 {
+    u64::try_from(PROCESS_START.elapsed().as_nanos()).unwrap_or(u64::MAX)
 }
 
 /// Create a `PointerInfo` for the primary mouse pointer.
@@ -174,7 +175,7 @@ fn pointer_state(
     let logical_y = device_to_logical(y as f32, scale_factor);
 
     let state = PointerState {
-        time: event_timestamp_ms(),
+        time: event_timestamp_ns(),
         position: PhysicalPosition::new(logical_x as f64, logical_y as f64),
         buttons: Default::default(),
         modifiers,
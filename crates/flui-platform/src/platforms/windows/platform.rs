@@ -3,7 +3,10 @@
 use std::{collections::HashMap, sync::Arc};
 
 use anyhow::{Context, Result};
-use flui_types::geometry::{Bounds, DevicePixels, Point, Size};
+use flui_types::{
+    geometry::{Bounds, DevicePixels, Point, Size},
+    platform::Brightness,
+};
 use parking_lot::Mutex;
 use windows::{
     Win32::{
@@ -27,6 +30,10 @@
     traits::*,
 };
 
+/// Posted to the message-only window by [`Platform::quit_requester`]; the
+/// message loop answers it with the `on_quit` handler and `PostQuitMessage`.
+const WM_FLUI_QUIT: u32 = WM_APP + 1;
+
 /// Ensures window class is registered exactly once (sound replacement for
 /// `static mut bool`).
 static REGISTER_WINDOW_CLASS: std::sync::Once = std::sync::Once::new();
@@ -56,6 +63,9 @@ pub(super) struct WindowContext {
     /// Stored here instead of in `WindowMode` to keep the cross-platform enum
     /// free of platform-specific fields.
     pub restore_style: std::cell::Cell<u32>,
+    /// Last platform brightness reported for this window, so repeated
+    /// `WM_SETTINGCHANGE` broadcasts only dispatch real changes
+    pub brightness: std::cell::Cell<Brightness>,
 }
 
 impl WindowContext {
@@ -778,6 +788,19 @@ unsafe fn register_window_class() -> Result<()> {
                 WM_SETTINGCHANGE => {
                     if let Some(ctx) = ctx {
                         ctx.callbacks.dispatch_appearance_changed();
+
+                        // Windows names the "ImmersiveColorSet" area when the
+                        // light/dark app preference (or accent color) changes.
+                        if is_immersive_color_set(lparam) {
+                            let brightness = system_brightness();
+                            if ctx.brightness.replace(brightness) != brightness {
+                                tracing::debug!(?brightness, "Platform brightness changed");
+                                ctx.dispatch_event(WindowEvent::PlatformBrightnessChanged {
+                                    window_id: ctx.window_id,
+                                    brightness,
+                                });
+                            }
+                        }
                     }
                     DefWindowProcW(hwnd, msg, wparam, lparam)
                 }
@@ -811,6 +834,15 @@ fn run_message_loop(&self) -> Result<()> {
                 // Drain foreground executor tasks before processing Windows messages
                 self.foreground_executor.drain_tasks();
 
+                // A cross-thread quit request: `PostQuitMessage` only ends the
+                // loop of the thread that calls it, so the requester posts
+                // here and the loop thread quits itself.
+                if msg.hwnd == self.message_window && msg.message == WM_FLUI_QUIT {
+                    self.handlers.lock().invoke_quit();
+                    PostQuitMessage(0);
+                    continue;
+                }
+
                 let _ = TranslateMessage(&msg);
                 DispatchMessageW(&msg);
             }
@@ -854,6 +886,30 @@ fn quit(&self) {
         }
     }
 
+    fn quit_requester(&self) -> Option<QuitRequester> {
+        // `HWND` is not `Send`; carry the raw handle value, as `windows`
+        // keys do.
+        let message_window = self.message_window.0 as isize;
+        Some(Arc::new(move || {
+            tracing::info!("Quit requested");
+            // SAFETY: `PostMessageW` is callable from any thread. The
+            // message-only window lives as long as the platform; once it is
+            // destroyed the post fails, which is logged and otherwise
+            // harmless.
+            let posted = unsafe {
+                PostMessageW(
+                    Some(HWND(message_window as *mut std::ffi::c_void)),
+                    WM_FLUI_QUIT,
+                    WPARAM(0),
+                    LPARAM(0),
+                )
+            };
+            if let Err(error) = posted {
+                tracing::warn!(?error, "Failed to post the quit request");
+            }
+        }))
+    }
+
     // ==================== Window Management ====================
 
     fn active_window(&self) -> Option<WindowId> {
@@ -940,49 +996,7 @@ fn activate(&self, _ignoring_other_apps: bool) {
     // ==================== Appearance (US3 T040) ====================
 
     fn window_appearance(&self) -> WindowAppearance {
-        // Read system theme from registry: AppsUseLightTheme
-        use windows::Win32::System::Registry::*;
-        unsafe {
-            let mut hkey = HKEY::default();
-            let subkey: Vec<u16> =
-                "Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize\0"
-                    .encode_utf16()
-                    .collect();
-            let value_name: Vec<u16> = "AppsUseLightTheme\0".encode_utf16().collect();
-
-            let status = RegOpenKeyExW(
-                HKEY_CURRENT_USER,
-                PCWSTR(subkey.as_ptr()),
-                Some(0),
-                KEY_READ,
-                &mut hkey,
-            );
-            if status.is_err() {
-                return WindowAppearance::Light;
-            }
-
-            let mut data: u32 = 1;
-            let mut data_size = std::mem::size_of::<u32>() as u32;
-            let status = RegQueryValueExW(
-                hkey,
-                PCWSTR(value_name.as_ptr()),
-                None,
-                None,
-                Some(&mut data as *mut u32 as *mut u8),
-                Some(&mut data_size),
-            );
-            let _ = RegCloseKey(hkey);
-
-            if status.is_err() {
-                return WindowAppearance::Light;
-            }
-
-            if data == 0 {
-                WindowAppearance::Dark
-            } else {
-                WindowAppearance::Light
-            }
-        }
+        system_appearance()
     }
 
     // ==================== Cursor (US3 T039) ====================
@@ -1283,7 +1297,59 @@ fn current_modifiers() -> keyboard_types::Modifiers {
 }
 
 // Windows platform capabilities
-static WINDOWS_CAPABILITIES: DesktopCapabilities = DesktopCapabilities;
+static WINDOWS_CAPABILITIES: DesktopCapabilities = DesktopCapabilities::new();
+
+/// The light/dark app preference, read from the `AppsUseLightTheme`
+/// registry value (light when it cannot be read).
+pub(super) fn system_appearance() -> WindowAppearance {
+    use windows::Win32::System::Registry::*;
+    unsafe {
+        let mut hkey = HKEY::default();
+        let subkey: Vec<u16> =
+            "Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize\0"
+                .encode_utf16()
+                .collect();
+        let value_name: Vec<u16> = "AppsUseLightTheme\0".encode_utf16().collect();
+
+        let status = RegOpenKeyExW(
+            HKEY_CURRENT_USER,
+            PCWSTR(subkey.as_ptr()),
+            Some(0),
+            KEY_READ,
+            &mut hkey,
+        );
+        if status.is_err() {
+            return WindowAppearance::Light;
+        }
+
+        let mut data: u32 = 1;
+        let mut data_size = std::mem::size_of::<u32>() as u32;
+        let status = RegQueryValueExW(
+            hkey,
+            PCWSTR(value_name.as_ptr()),
+            None,
+            None,
+            Some(&mut data as *mut u32 as *mut u8),
+            Some(&mut data_size),
+        );
+        let _ = RegCloseKey(hkey);
+
+        if status.is_err() {
+            return WindowAppearance::Light;
+        }
+
+        if data == 0 {
+            WindowAppearance::Dark
+        } else {
+            WindowAppearance::Light
+        }
+    }
+}
+
+/// [`system_appearance`] as the platform [`Brightness`].
+pub(super) fn system_brightness() -> Brightness {
+    system_appearance().brightness()
+}
 
 #[cfg(test)]
 mod tests {
@@ -89,6 +89,19 @@ pub unsafe fn is_key_pressed(vkey: i32) -> bool {
     unsafe { (GetAsyncKeyState(vkey) as i32 & 0x8000) != 0 }
 }
 
+/// Whether a `WM_SETTINGCHANGE` `lparam` names the `ImmersiveColorSet`
+/// area, which Windows broadcasts when the light/dark app preference changes
+///
+/// # Safety
+///
+/// `lparam` must come from a `WM_SETTINGCHANGE` message: null or a pointer
+/// to a NUL-terminated UTF-16 string.
+pub unsafe fn is_immersive_color_set(lparam: LPARAM) -> bool {
+    let area = lparam.0 as *const u16;
+    !area.is_null()
+        && unsafe { PCWSTR(area).to_string() }.is_ok_and(|area| area == "ImmersiveColorSet")
+}
+
 /// DPI constants
 pub const USER_DEFAULT_SCREEN_DPI: u32 = 96;
 
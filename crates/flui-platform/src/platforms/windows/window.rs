@@ -82,9 +82,15 @@ pub fn new(
             let width = logical_to_device(options.size.width.0, scale_factor);
             let height = logical_to_device(options.size.height.0, scale_factor);
 
-            // Default position (center on screen)
-            let x = CW_USEDEFAULT;
-            let y = CW_USEDEFAULT;
+            // Requested position in device pixels, else let Windows cascade
+            let (x, y) = options
+                .position
+                .map_or((CW_USEDEFAULT, CW_USEDEFAULT), |origin| {
+                    (
+                        logical_to_device(origin.x.0, scale_factor),
+                        logical_to_device(origin.y.0, scale_factor),
+                    )
+                });
 
             // Determine window style
             let style = if options.decorated {
@@ -139,7 +145,7 @@ pub fn new(
 
             let state = Arc::new(Mutex::new(WindowState {
                 bounds: Bounds {
-                    origin: Point::new(px(0.0), px(0.0)),
+                    origin: options.position.unwrap_or(Point::new(px(0.0), px(0.0))),
                     size: options.size,
                 },
                 scale_factor,
@@ -175,6 +181,7 @@ pub fn new(
                 is_hovered: std::cell::Cell::new(false),
                 modifiers: std::cell::Cell::new(keyboard_types::Modifiers::empty()),
                 restore_style: std::cell::Cell::new(0),
+                brightness: std::cell::Cell::new(super::platform::system_brightness()),
             });
             let context_ptr = Box::into_raw(context);
             SetWindowLongPtrW(hwnd, GWLP_USERDATA, context_ptr as isize);
@@ -186,6 +193,12 @@ pub fn new(
                 window.state.lock().visible = true;
             }
 
+            // Borderless fullscreen on the window's monitor, the same path
+            // the F11 hotkey takes; needs the WindowContext set above.
+            if options.fullscreen {
+                Self::toggle_fullscreen_for_hwnd(hwnd);
+            }
+
             Ok(window)
         }
     }
@@ -745,27 +758,10 @@ fn modifiers(&self) -> keyboard_types::Modifiers {
     }
 
     fn appearance(&self) -> WindowAppearance {
-        unsafe {
-            let ctx_ptr =
-                GetWindowLongPtrW(self.hwnd, GWLP_USERDATA) as *mut super::platform::WindowContext;
-            if ctx_ptr.is_null() {
-                return WindowAppearance::default();
-            }
-            // Check DWM dark mode attribute
-            use windows::Win32::Graphics::Dwm::{DWMWINDOWATTRIBUTE, DwmGetWindowAttribute};
-            let mut dark_mode: i32 = 0;
-            let result = DwmGetWindowAttribute(
-                self.hwnd,
-                DWMWINDOWATTRIBUTE(20), // DWMWA_USE_IMMERSIVE_DARK_MODE
-                &mut dark_mode as *mut i32 as *mut std::ffi::c_void,
-                std::mem::size_of::<i32>() as u32,
-            );
-            if result.is_ok() && dark_mode != 0 {
-                WindowAppearance::Dark
-            } else {
-                WindowAppearance::Light
-            }
-        }
+        // The OS light/dark app preference. The DWM dark-mode attribute only
+        // styles the title bar (it is forced on at creation), so it says
+        // nothing about the theme the app should use.
+        super::platform::system_appearance()
     }
 
     fn display(&self) -> Option<Arc<dyn PlatformDisplay>> {
@@ -1572,6 +1568,8 @@ fn test_window_creation() {
             decorated: true,
             min_size: None,
             max_size: None,
+            position: None,
+            fullscreen: false,
         };
 
         let windows_map = Arc::new(Mutex::new(HashMap::new()));
@@ -5,6 +5,7 @@
 use std::{sync::LazyLock, time::Instant};
 
 use dpi::{PhysicalPosition, PhysicalSize};
+use flui_types::platform::Brightness;
 use keyboard_types::Modifiers as KeyboardModifiers;
 use ui_events::{
     ScrollDelta,
@@ -14,17 +15,21 @@
         PointerOrientation, PointerState, PointerType, PointerUpdate,
     },
 };
-use winit::event::{ElementState, MouseButton, MouseScrollDelta};
+use winit::{
+    event::{ElementState, MouseButton, MouseScrollDelta},
+    window::Theme,
+};
 
-use crate::traits::PlatformInput;
+use crate::traits::{PlatformInput, WindowAppearance, WindowEvent, WindowId};
 
 /// Process-start epoch for monotonic event timestamps.
 static PROCESS_START: LazyLock<Instant> = LazyLock::new(Instant::now);
 
-/// Get monotonic timestamp in milliseconds since process start.
+/// Get monotonic timestamp in nanoseconds since process start, the unit
+/// `ui_events::pointer::PointerState::time` expects.
 #[inline]
-fn event_timestamp_ms() -> u64 {
-    PROCESS_START.elapsed().as_millis() as u64
+fn event_timestamp_ns() -> u64 {
+    u64::try_from(PROCESS_START.elapsed().as_nanos()).unwrap_or(u64::MAX)
 }
 
 /// Create a `PointerInfo` for the primary mouse pointer.
@@ -48,7 +53,7 @@ fn pointer_state(
     let logical_y = position.y / scale_factor;
 
     PointerState {
-        time: event_timestamp_ms(),
+        time: event_timestamp_ns(),
         position: PhysicalPosition::new(logical_x, logical_y),
         buttons: PointerButtons::default(),
         modifiers,
@@ -389,6 +394,43 @@ pub fn keyboard_event(
     PlatformInput::Keyboard(keyboard_event)
 }
 
+/// Convert winit's `Theme` to the platform [`Brightness`].
+pub fn brightness_from_theme(theme: Theme) -> Brightness {
+    match theme {
+        Theme::Light => Brightness::Light,
+        Theme::Dark => Brightness::Dark,
+    }
+}
+
+/// Convert winit's `Theme` to a [`WindowAppearance`].
+///
+/// winit has no notion of vibrancy, so only the plain variants are produced.
+pub fn appearance_from_theme(theme: Theme) -> WindowAppearance {
+    match theme {
+        Theme::Light => WindowAppearance::Light,
+        Theme::Dark => WindowAppearance::Dark,
+    }
+}
+
+/// Translate winit's `ScaleFactorChanged` into a platform [`WindowEvent`].
+///
+/// Takes the bare factor rather than the winit event: its `InnerSizeWriter`
+/// cannot be constructed outside winit, which would make this untestable.
+pub fn scale_factor_changed_event(window_id: WindowId, scale_factor: f64) -> WindowEvent {
+    WindowEvent::ScaleFactorChanged {
+        window_id,
+        scale_factor,
+    }
+}
+
+/// Translate winit's `ThemeChanged` into a platform [`WindowEvent`].
+pub fn theme_changed_event(window_id: WindowId, theme: Theme) -> WindowEvent {
+    WindowEvent::PlatformBrightnessChanged {
+        window_id,
+        brightness: brightness_from_theme(theme),
+    }
+}
+
 #[cfg(test)]
 mod ime_tests {
     use flui_types::ImeEvent;
@@ -448,3 +490,48 @@ fn preedit_with_no_cursor_hides_the_caret() {
         );
     }
 }
+
+#[cfg(test)]
+mod window_event_tests {
+    use flui_types::platform::Brightness;
+    use winit::window::Theme;
+
+    use super::{appearance_from_theme, scale_factor_changed_event, theme_changed_event};
+    use crate::traits::{WindowAppearance, WindowEvent, WindowId};
+
+    #[test]
+    fn scale_factor_change_carries_the_new_factor() {
+        match scale_factor_changed_event(WindowId(3), 1.75) {
+            WindowEvent::ScaleFactorChanged {
+                window_id,
+                scale_factor,
+            } => {
+                assert_eq!(window_id, WindowId(3));
+                assert!((scale_factor - 1.75).abs() < f64::EPSILON);
+            }
+            other => panic!("expected ScaleFactorChanged, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn theme_change_maps_to_platform_brightness() {
+        for (theme, expected) in [
+            (Theme::Dark, Brightness::Dark),
+            (Theme::Light, Brightness::Light),
+        ] {
+            match theme_changed_event(WindowId(1), theme) {
+                WindowEvent::PlatformBrightnessChanged {
+                    window_id,
+                    brightness,
+                } => {
+                    assert_eq!(window_id, WindowId(1));
+                    assert_eq!(brightness, expected);
+                }
+                other => panic!("expected PlatformBrightnessChanged, got {other:?}"),
+            }
+        }
+
+        assert_eq!(appearance_from_theme(Theme::Dark), WindowAppearance::Dark);
+        assert_eq!(appearance_from_theme(Theme::Light), WindowAppearance::Light);
+    }
+}
@@ -2,7 +2,7 @@
 
 mod clipboard;
 mod display;
-mod events;
+pub(crate) mod events;
 mod platform;
 mod window_requests;
 
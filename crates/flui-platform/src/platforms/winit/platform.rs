@@ -71,9 +71,9 @@
 use crate::{
     shared::PlatformHandlers,
     traits::{
-        Clipboard, DesktopCapabilities, Platform, PlatformCapabilities, PlatformDisplay,
-        PlatformExecutor, PlatformReadyCallback, PlatformWindow, WindowEvent, WindowId,
-        WindowOptions, WinitWindow,
+        Clipboard, DesktopCapabilities, NoopClipboard, Platform, PlatformCapabilities,
+        PlatformDisplay, PlatformExecutor, PlatformReadyCallback, PlatformWindow, QuitRequester,
+        WindowEvent, WindowId, WindowOptions, WinitWindow,
     },
 };
 
@@ -93,9 +93,9 @@
 /// }));
 /// ```
 pub struct WinitPlatform {
-    /// Platform capabilities descriptor. `DesktopCapabilities` is a
-    /// zero-sized, immutable-after-construction marker, so it lives directly
-    /// on `WinitPlatform` (not inside the `Mutex`-guarded state) — that lets
+    /// Platform capabilities descriptor. `DesktopCapabilities` is
+    /// immutable after construction, so it lives directly on
+    /// `WinitPlatform` (not inside the `Mutex`-guarded state) — that lets
     /// `capabilities()` return `&dyn PlatformCapabilities` borrowed straight
     /// from `&self` instead of from a `MutexGuard` temporary.
     capabilities: DesktopCapabilities,
@@ -124,8 +124,8 @@ struct WinitPlatformState {
     /// Foreground executor
     foreground_executor: Arc<SimpleExecutor>,
 
-    /// Clipboard
-    clipboard: Arc<ArboardClipboard>,
+    /// Clipboard (a `NoopClipboard` if arboard failed to initialize)
+    clipboard: Arc<dyn Clipboard>,
 
     /// Window request queue
     window_requests: Arc<WindowRequestQueue>,
@@ -151,6 +151,10 @@ struct WinitPlatformState {
     /// Whether quit was requested
     should_quit: bool,
 
+    /// Quit requested through a [`QuitRequester`], possibly off-thread;
+    /// promoted to `should_quit` (and `on_quit`) in `about_to_wait`
+    quit_requested: bool,
+
     /// Current cursor position per window (physical)
     cursor_positions: HashMap<WindowId, winit::dpi::PhysicalPosition<f64>>,
 
@@ -161,13 +165,13 @@ struct WinitPlatformState {
 impl WinitPlatformState {
     fn new() -> Self {
         // Initialize clipboard (may fail in headless environments)
-        let clipboard = ArboardClipboard::new().map_or_else(
-            |err| {
-                tracing::warn!(?err, "Failed to initialize clipboard, using fallback");
-                Arc::new(ArboardClipboard::default())
-            },
-            Arc::new,
-        );
+        let clipboard: Arc<dyn Clipboard> = match ArboardClipboard::new() {
+            Ok(clipboard) => Arc::new(clipboard),
+            Err(err) => {
+                tracing::warn!(?err, "Failed to initialize clipboard, using no-op fallback");
+                Arc::new(NoopClipboard)
+            }
+        };
 
         Self {
             handlers: PlatformHandlers::new(),
@@ -182,6 +186,7 @@ fn new() -> Self {
             next_window_id: 1,
             is_running: false,
             should_quit: false,
+            quit_requested: false,
             cursor_positions: HashMap::new(),
             current_modifiers: KeyboardModifiers::empty(),
         }
@@ -240,9 +245,16 @@ fn default() -> Self {
 impl WinitPlatform {
     /// Create a new winit platform
     pub fn new() -> Self {
+        let state = WinitPlatformState::new();
+        // Report no clipboard when arboard fell back to `NoopClipboard`.
+        let capabilities = if state.clipboard.is_available() {
+            DesktopCapabilities::new()
+        } else {
+            DesktopCapabilities::without_clipboard()
+        };
         Self {
-            capabilities: DesktopCapabilities,
-            state: Arc::new(Mutex::new(WinitPlatformState::new())),
+            capabilities,
+            state: Arc::new(Mutex::new(state)),
         }
     }
 
@@ -283,26 +295,7 @@ fn create_window_now(
         event_loop: &ActiveEventLoop,
         options: WindowOptions,
     ) -> Result<WindowId> {
-        let mut attributes = WindowAttributes::default()
-            .with_title(options.title)
-            .with_inner_size(winit::dpi::LogicalSize::new(
-                options.size.width.0,
-                options.size.height.0,
-            ))
-            .with_resizable(options.resizable)
-            .with_decorations(options.decorated)
-            .with_visible(options.visible);
-
-        if let Some(min) = options.min_size {
-            attributes = attributes
-                .with_min_inner_size(winit::dpi::LogicalSize::new(min.width.0, min.height.0));
-        }
-        if let Some(max) = options.max_size {
-            attributes = attributes
-                .with_max_inner_size(winit::dpi::LogicalSize::new(max.width.0, max.height.0));
-        }
-
-        let raw_window = Arc::new(event_loop.create_window(attributes)?);
+        let raw_window = Arc::new(event_loop.create_window(window_attributes(options))?);
         let winit_id = raw_window.id();
         let winit_window = Arc::new(WinitWindow::new(raw_window));
 
@@ -532,10 +525,10 @@ fn window_event(
                 self.platform.with_state(|state| {
                     state
                         .handlers
-                        .invoke_window_event(WindowEvent::ScaleFactorChanged {
-                            window_id: platform_id,
+                        .invoke_window_event(winit_events::scale_factor_changed_event(
+                            platform_id,
                             scale_factor,
-                        });
+                        ));
                 });
             }
             WinitWindowEvent::CursorMoved { position, .. } => {
@@ -621,10 +614,18 @@ fn window_event(
                     win.callbacks().dispatch_moved();
                 }
             }
-            WinitWindowEvent::ThemeChanged(_) => {
+            WinitWindowEvent::ThemeChanged(theme) => {
+                tracing::debug!(?platform_id, ?theme, "Window theme changed");
+
                 if let Some(ref win) = window {
                     win.callbacks().dispatch_appearance_changed();
                 }
+
+                self.platform.with_state(|state| {
+                    state
+                        .handlers
+                        .invoke_window_event(winit_events::theme_changed_event(platform_id, theme));
+                });
             }
             WinitWindowEvent::Occluded(occluded) => {
                 tracing::debug!(?platform_id, ?occluded, "Window occlusion changed");
@@ -655,8 +656,16 @@ fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
         // Process pending window creation requests
         self.process_window_requests(event_loop);
 
-        // Check if we should quit
-        let should_quit = self.platform.with_state(|state| state.should_quit);
+        // Check if we should quit. A `QuitRequester` call only flags the
+        // request, since it may come from another thread; `on_quit` fires
+        // here, on the event-loop thread, like it does for `quit()`.
+        let should_quit = self.platform.with_state(|state| {
+            if std::mem::take(&mut state.quit_requested) && !state.should_quit {
+                state.should_quit = true;
+                state.handlers.invoke_quit();
+            }
+            state.should_quit
+        });
         if should_quit {
             tracing::info!("Quitting event loop");
             event_loop.exit();
@@ -725,6 +734,23 @@ fn quit(&self) {
         });
     }
 
+    fn quit_requester(&self) -> Option<QuitRequester> {
+        let state = Arc::clone(&self.state);
+        Some(Arc::new(move || {
+            tracing::info!("Quit requested");
+            let windows: Vec<_> = {
+                let mut state = state.lock();
+                state.quit_requested = true;
+                state.windows.values().cloned().collect()
+            };
+            // The loop waits on events (`ControlFlow::Wait`); a redraw is the
+            // wake-up that gets `about_to_wait` to see the request.
+            for window in windows {
+                window.request_redraw();
+            }
+        }))
+    }
+
     fn open_window(&self, options: WindowOptions) -> Result<Box<dyn PlatformWindow>> {
         tracing::info!(?options, "Requesting window creation");
 
@@ -960,6 +986,10 @@ fn scale_factor(&self) -> f64 {
         self.inner.scale_factor()
     }
 
+    fn appearance(&self) -> crate::traits::WindowAppearance {
+        self.inner.appearance()
+    }
+
     fn request_redraw(&self) {
         self.inner.request_redraw();
     }
@@ -1071,3 +1101,107 @@ fn as_any(&self) -> &dyn std::any::Any {
         self
     }
 }
+
+/// Map [`WindowOptions`] onto winit's [`WindowAttributes`].
+///
+/// All geometry is logical; winit scales it by the target monitor's factor.
+/// `fullscreen` selects borderless fullscreen on the current monitor —
+/// exclusive video modes are not exposed through `WindowOptions`.
+fn window_attributes(options: WindowOptions) -> WindowAttributes {
+    let mut attributes = WindowAttributes::default()
+        .with_title(options.title)
+        .with_inner_size(winit::dpi::LogicalSize::new(
+            options.size.width.0,
+            options.size.height.0,
+        ))
+        .with_resizable(options.resizable)
+        .with_decorations(options.decorated)
+        .with_visible(options.visible);
+
+    if let Some(min) = options.min_size {
+        attributes =
+            attributes.with_min_inner_size(winit::dpi::LogicalSize::new(min.width.0, min.height.0));
+    }
+    if let Some(max) = options.max_size {
+        attributes =
+            attributes.with_max_inner_size(winit::dpi::LogicalSize::new(max.width.0, max.height.0));
+    }
+    if let Some(position) = options.position {
+        attributes =
+            attributes.with_position(winit::dpi::LogicalPosition::new(position.x.0, position.y.0));
+    }
+    if options.fullscreen {
+        attributes = attributes.with_fullscreen(Some(winit::window::Fullscreen::Borderless(None)));
+    }
+
+    attributes
+}
+
+#[cfg(test)]
+mod tests {
+    use flui_types::geometry::{Point, Size, px};
+    use winit::dpi::{LogicalPosition, LogicalSize, Position, Size as WinitSize};
+    use winit::window::Fullscreen;
+
+    use super::*;
+
+    /// Whichever clipboard arboard gave us (a real one, or the no-op
+    /// fallback on a headless host), the capabilities must agree with it.
+    #[test]
+    fn clipboard_capability_matches_the_clipboard_in_use() {
+        let platform = WinitPlatform::new();
+        assert_eq!(
+            platform.capabilities().supports_clipboard(),
+            platform.clipboard().is_available()
+        );
+    }
+
+    #[test]
+    fn window_attributes_map_every_option() {
+        let attributes = window_attributes(WindowOptions {
+            title: "Editor".to_string(),
+            size: Size::new(px(1024.0), px(768.0)),
+            resizable: false,
+            visible: false,
+            decorated: false,
+            min_size: Some(Size::new(px(640.0), px(480.0))),
+            max_size: Some(Size::new(px(1920.0), px(1080.0))),
+            position: Some(Point::new(px(40.0), px(-20.0))),
+            fullscreen: true,
+        });
+
+        assert_eq!(attributes.title, "Editor");
+        assert_eq!(
+            attributes.inner_size,
+            Some(WinitSize::Logical(LogicalSize::new(1024.0, 768.0)))
+        );
+        assert_eq!(
+            attributes.min_inner_size,
+            Some(WinitSize::Logical(LogicalSize::new(640.0, 480.0)))
+        );
+        assert_eq!(
+            attributes.max_inner_size,
+            Some(WinitSize::Logical(LogicalSize::new(1920.0, 1080.0)))
+        );
+        assert_eq!(
+            attributes.position,
+            Some(Position::Logical(LogicalPosition::new(40.0, -20.0)))
+        );
+        assert_eq!(attributes.fullscreen, Some(Fullscreen::Borderless(None)));
+        assert!(!attributes.resizable);
+        assert!(!attributes.decorations);
+        assert!(!attributes.visible);
+    }
+
+    #[test]
+    fn window_attributes_leave_unset_options_to_the_window_manager() {
+        let attributes = window_attributes(WindowOptions::default());
+
+        assert_eq!(attributes.min_inner_size, None);
+        assert_eq!(attributes.max_inner_size, None);
+        assert_eq!(attributes.position, None);
+        assert_eq!(attributes.fullscreen, None);
+        assert!(attributes.resizable);
+        assert!(attributes.decorations);
+    }
+}
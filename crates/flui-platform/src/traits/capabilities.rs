@@ -57,11 +57,46 @@ pub trait PlatformCapabilities: Send + Sync {
     fn supports_stylus(&self) -> bool {
         false
     }
+
+    /// Does this platform provide a clipboard?
+    ///
+    /// When `false`, `Platform::clipboard()` returns a
+    /// [`NoopClipboard`](crate::traits::NoopClipboard).
+    fn supports_clipboard(&self) -> bool {
+        true
+    }
 }
 
 /// Desktop platform capabilities (Windows, macOS, Linux)
-#[derive(Debug, Clone, Copy, Default)]
-pub struct DesktopCapabilities;
+#[derive(Debug, Clone, Copy)]
+pub struct DesktopCapabilities {
+    /// Whether the system clipboard is available (`false` when the
+    /// clipboard backend failed to initialize)
+    pub has_clipboard: bool,
+}
+
+impl DesktopCapabilities {
+    /// Create desktop capabilities with a working clipboard
+    pub const fn new() -> Self {
+        Self {
+            has_clipboard: true,
+        }
+    }
+
+    /// Create desktop capabilities for a platform that fell back to a
+    /// [`NoopClipboard`](crate::traits::NoopClipboard)
+    pub const fn without_clipboard() -> Self {
+        Self {
+            has_clipboard: false,
+        }
+    }
+}
+
+impl Default for DesktopCapabilities {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl PlatformCapabilities for DesktopCapabilities {
     fn platform_name(&self) -> &'static str {
@@ -110,6 +145,10 @@ fn supports_keyboard(&self) -> bool {
     fn supports_stylus(&self) -> bool {
         true // Surface, Wacom, etc.
     }
+
+    fn supports_clipboard(&self) -> bool {
+        self.has_clipboard
+    }
 }
 
 /// Mobile platform capabilities (Android, iOS)
@@ -171,6 +210,10 @@ fn supports_keyboard(&self) -> bool {
     fn supports_stylus(&self) -> bool {
         true // Apple Pencil, S Pen
     }
+
+    fn supports_clipboard(&self) -> bool {
+        !self.is_ios // UIPasteboard not implemented yet
+    }
 }
 
 /// Web platform capabilities
@@ -221,10 +264,12 @@ mod tests {
 
     #[test]
     fn test_desktop_capabilities() {
-        let caps = DesktopCapabilities;
+        let caps = DesktopCapabilities::new();
         assert!(caps.supports_mouse());
         assert!(caps.supports_multiple_windows());
         assert!(!caps.suspend_rendering_in_background());
+        assert!(caps.supports_clipboard());
+        assert!(!DesktopCapabilities::without_clipboard().supports_clipboard());
     }
 
     #[test]
@@ -236,6 +281,9 @@ fn test_mobile_capabilities() {
 
         let ios = MobileCapabilities::ios();
         assert_eq!(ios.platform_name(), "iOS");
+
+        assert!(android.supports_clipboard());
+        assert!(!ios.supports_clipboard());
     }
 
     #[test]
@@ -47,8 +47,9 @@
 // Re-export keyboard-types for convenience
 pub use keyboard_types::NamedKey;
 pub use platform::{
-    Clipboard, ClipboardItem, PathPromptOptions, Platform, PlatformExecutor, PlatformReadyCallback,
-    WindowEvent, WindowId, WindowMode, WindowOptions,
+    Clipboard, ClipboardImage, ClipboardItem, NoopClipboard, PathPromptOptions, Platform,
+    PlatformExecutor, PlatformReadyCallback, QuitRequester, WindowEvent, WindowId, WindowMode,
+    WindowOptions,
 };
 pub use text_input::PlatformTextInput;
 #[cfg(feature = "winit-backend")]
@@ -10,7 +10,10 @@
 };
 
 use anyhow::Result;
-use flui_types::geometry::{Bounds, DevicePixels, Pixels, Point, Size};
+use flui_types::{
+    geometry::{Bounds, DevicePixels, Pixels, Point, Size},
+    platform::Brightness,
+};
 
 use super::{PlatformCapabilities, PlatformDisplay, PlatformWindow, window::WindowAppearance};
 use crate::{cursor::CursorStyle, task::Task};
@@ -33,6 +36,11 @@ pub struct WindowOptions {
     pub min_size: Option<Size<Pixels>>,
     /// Maximum window size
     pub max_size: Option<Size<Pixels>>,
+    /// Initial outer position (logical pixels); `None` lets the window
+    /// manager place the window
+    pub position: Option<Point<Pixels>>,
+    /// Whether the window starts in borderless fullscreen on its display
+    pub fullscreen: bool,
 }
 
 impl Default for WindowOptions {
@@ -47,6 +55,8 @@ fn default() -> Self {
             decorated: true,
             min_size: None,
             max_size: None,
+            position: None,
+            fullscreen: false,
         }
     }
 }
@@ -141,6 +151,13 @@ pub fn can_transition_to(&self, new_mode: &WindowMode) -> bool {
 /// every call site's signature.
 pub type PlatformReadyCallback = Box<dyn FnOnce(&dyn Platform)>;
 
+/// Thread-safe request to quit the platform event loop
+///
+/// Returned by [`Platform::quit_requester`]. Unlike [`Platform::quit`], it
+/// outlives `on_ready` and may be called from any thread; the event loop
+/// wakes, fires the `on_quit` handler on its own thread, and exits.
+pub type QuitRequester = Arc<dyn Fn() + Send + Sync>;
+
 /// Core platform abstraction trait
 ///
 /// This trait provides the complete interface for platform-specific operations.
@@ -204,6 +221,15 @@ pub trait Platform: Send + Sync + 'static {
     /// exit.
     fn quit(&self);
 
+    /// A [`QuitRequester`] usable after `on_ready` returns and from any
+    /// thread
+    ///
+    /// `None` when the backend cannot request a quit from outside its own
+    /// event-loop callbacks (the default).
+    fn quit_requester(&self) -> Option<QuitRequester> {
+        None
+    }
+
     // ==================== Window Management ====================
 
     /// Create and open a new window
@@ -413,6 +439,16 @@ pub enum WindowEvent {
         scale_factor: f64,
     },
 
+    /// The OS light/dark preference changed, as seen by this window
+    ///
+    /// Apps use this to switch themes when the user toggles dark mode.
+    PlatformBrightnessChanged {
+        /// The window that observed the change
+        window_id: WindowId,
+        /// New platform brightness
+        brightness: Brightness,
+    },
+
     /// Window needs to be redrawn
     RedrawRequested {
         /// The window that must be repainted
@@ -492,6 +528,65 @@ pub trait Clipboard: Send + Sync {
     fn has_text(&self) -> bool {
         self.read_text().is_some()
     }
+
+    /// Whether this clipboard is backed by a working implementation
+    ///
+    /// `false` for [`NoopClipboard`], which platforms use when no clipboard
+    /// is available. Agrees with [`PlatformCapabilities::supports_clipboard`]
+    /// unless a backend failed to initialize at runtime.
+    ///
+    /// [`PlatformCapabilities::supports_clipboard`]: crate::traits::PlatformCapabilities::supports_clipboard
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    /// Read an image from clipboard
+    ///
+    /// No backend supports images yet; the default returns `None`.
+    fn read_image(&self) -> Option<ClipboardImage> {
+        None
+    }
+
+    /// Write an image to clipboard
+    ///
+    /// No backend supports images yet; the default ignores the image.
+    fn write_image(&self, image: ClipboardImage) {
+        tracing::debug!(
+            width = image.width,
+            height = image.height,
+            "Clipboard images are not supported, ignoring write"
+        );
+    }
+}
+
+/// Image stored on the clipboard
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardImage {
+    /// Width in pixels
+    pub width: usize,
+    /// Height in pixels
+    pub height: usize,
+    /// Pixel data, RGBA8, row-major
+    pub bytes: Vec<u8>,
+}
+
+/// Clipboard for platforms without one
+///
+/// Reads return nothing and writes are dropped. Reports
+/// [`Clipboard::is_available`] as `false`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopClipboard;
+
+impl Clipboard for NoopClipboard {
+    fn read_text(&self) -> Option<String> {
+        None
+    }
+
+    fn write_text(&self, _text: String) {}
+
+    fn is_available(&self) -> bool {
+        false
+    }
 }
 
 /// Rich clipboard item with text content and optional metadata
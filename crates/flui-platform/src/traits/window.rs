@@ -31,6 +31,19 @@ pub enum WindowAppearance {
     VibrantDark,
 }
 
+impl WindowAppearance {
+    /// The platform [`Brightness`](flui_types::platform::Brightness) this
+    /// appearance corresponds to.
+    #[must_use]
+    pub const fn brightness(self) -> flui_types::platform::Brightness {
+        use flui_types::platform::Brightness;
+        match self {
+            Self::Dark | Self::VibrantDark => Brightness::Dark,
+            Self::Light | Self::VibrantLight => Brightness::Light,
+        }
+    }
+}
+
 /// Window background appearance (backdrop material)
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub enum WindowBackgroundAppearance {
@@ -459,6 +472,13 @@ fn scale_factor(&self) -> f64 {
         self.window.scale_factor()
     }
 
+    fn appearance(&self) -> WindowAppearance {
+        self.window
+            .theme()
+            .map(crate::platforms::winit::events::appearance_from_theme)
+            .unwrap_or_default()
+    }
+
     fn request_redraw(&self) {
         self.window.request_redraw();
     }
@@ -25,7 +25,10 @@
 //! RUST_LOG=debug cargo test -p flui-platform --test contract -- --nocapture
 //! ```
 
-use flui_platform::{Platform, WindowOptions, current_platform, headless_platform};
+use flui_platform::{
+    Clipboard, ClipboardImage, NoopClipboard, Platform, WindowOptions, current_platform,
+    headless_platform,
+};
 use flui_types::geometry::{Size, device_px, px};
 
 // ==================== Helper: Get Test Platform ====================
@@ -188,6 +191,45 @@ fn contract_clipboard_empty() {
     }
 }
 
+#[test]
+fn contract_clipboard_image_stubs() {
+    // GIVEN: The in-memory headless clipboard
+    let platform = get_test_platform();
+    let clipboard = platform.clipboard();
+
+    // WHEN: We write an image (no backend supports images yet)
+    clipboard.write_image(ClipboardImage {
+        width: 1,
+        height: 1,
+        bytes: vec![255, 0, 0, 255],
+    });
+
+    // THEN: The write is ignored and text is untouched
+    clipboard.write_text("still text".to_string());
+    assert_eq!(clipboard.read_image(), None);
+    assert_eq!(clipboard.read_text().as_deref(), Some("still text"));
+}
+
+#[test]
+fn contract_clipboard_capability_matches_implementation() {
+    // GIVEN: A platform implementation
+    let platform = get_test_platform();
+
+    // THEN: The capability flag reflects the clipboard it hands out
+    assert_eq!(
+        platform.capabilities().supports_clipboard(),
+        platform.clipboard().is_available(),
+        "supports_clipboard should match the active clipboard"
+    );
+
+    // AND: The no-op fallback reports itself unavailable and stores nothing
+    let noop = NoopClipboard;
+    assert!(!noop.is_available());
+    noop.write_text("dropped".to_string());
+    assert!(!noop.has_text());
+    assert_eq!(noop.read_image(), None);
+}
+
 // ==================== Contract Tests: Executors ====================
 
 #[test]
@@ -328,6 +370,8 @@ fn test_window_lifecycle_contract() {
         decorated: true,
         min_size: Some(Size::new(px(320.0), px(240.0))),
         max_size: None,
+        position: None,
+        fullscreen: false,
     };
 
     match platform.open_window(options.clone()) {
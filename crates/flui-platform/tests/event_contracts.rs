@@ -44,6 +44,8 @@ fn test_platform_event_contract() {
         decorated: true,
         min_size: None,
         max_size: None,
+        position: None,
+        fullscreen: false,
     };
 
     let window = platform
@@ -198,6 +200,8 @@ fn test_cross_platform_event_consistency() {
         decorated: true,
         min_size: Some(Size::new(px(320.0), px(240.0))),
         max_size: Some(Size::new(px(1920.0), px(1080.0))),
+        position: None,
+        fullscreen: false,
     };
 
     let window = platform
@@ -276,6 +280,8 @@ fn test_event_dispatch_latency_benchmark() {
         decorated: true,
         min_size: None,
         max_size: None,
+        position: None,
+        fullscreen: false,
     };
 
     // Measure window creation time
@@ -348,6 +354,8 @@ fn test_event_handling_performance_baseline() {
             decorated: true,
             min_size: None,
             max_size: None,
+            position: None,
+            fullscreen: false,
         };
 
         let window = platform
@@ -54,6 +54,8 @@ fn test_mouse_click_pointer_event() {
         decorated: true,
         min_size: None,
         max_size: None,
+        position: None,
+        fullscreen: false,
     };
 
     let window = platform
@@ -101,6 +103,8 @@ fn test_keyboard_with_modifiers() {
         decorated: true,
         min_size: None,
         max_size: None,
+        position: None,
+        fullscreen: false,
     };
 
     let _window = platform
@@ -166,6 +170,8 @@ fn test_window_resize_event() {
         decorated: true,
         min_size: Some(Size::new(px(320.0), px(240.0))),
         max_size: Some(Size::new(px(1920.0), px(1080.0))),
+        position: None,
+        fullscreen: false,
     };
 
     let window = platform
@@ -237,6 +243,8 @@ fn test_mouse_movement_pointer_event() {
         decorated: true,
         min_size: None,
         max_size: None,
+        position: None,
+        fullscreen: false,
     };
 
     let _window = platform
@@ -299,6 +307,8 @@ fn test_multi_touch_pointer_events() {
         decorated: true,
         min_size: None,
         max_size: None,
+        position: None,
+        fullscreen: false,
     };
 
     let _window = platform
@@ -381,6 +391,8 @@ fn test_event_coordinate_system() {
         decorated: true,
         min_size: None,
         max_size: None,
+        position: None,
+        fullscreen: false,
     };
 
     let window = platform
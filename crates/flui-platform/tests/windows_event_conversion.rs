@@ -42,6 +42,8 @@ fn test_wm_lbuttondown_pointer_event_conversion() {
             decorated: true,
             min_size: None,
             max_size: None,
+            position: None,
+            fullscreen: false,
         };
 
         let _window = platform
@@ -79,6 +81,8 @@ fn test_wm_keydown_keyboard_event_conversion() {
             decorated: true,
             min_size: None,
             max_size: None,
+            position: None,
+            fullscreen: false,
         };
 
         let _window = platform
@@ -118,6 +122,8 @@ fn test_wm_size_window_resized_conversion() {
             decorated: true,
             min_size: Some(Size::new(px(320.0), px(240.0))),
             max_size: Some(Size::new(px(1920.0), px(1080.0))),
+            position: None,
+            fullscreen: false,
         };
 
         let window = platform
@@ -172,6 +178,8 @@ fn test_event_dispatch_latency_tracing() {
             decorated: true,
             min_size: None,
             max_size: None,
+            position: None,
+            fullscreen: false,
         };
 
         let _window = platform
@@ -214,6 +222,8 @@ fn test_modifier_key_handling() {
             decorated: true,
             min_size: None,
             max_size: None,
+            position: None,
+            fullscreen: false,
         };
 
         let _window = platform
@@ -258,6 +268,8 @@ fn test_windows_event_pipeline_integration() {
             decorated: true,
             min_size: None,
             max_size: None,
+            position: None,
+            fullscreen: false,
         };
 
         let window = platform
@@ -100,7 +100,8 @@ pub fn receiver_mut(&mut self) -> &mut T {
     /// use-after-free, etc.), not for intentional resource leaks.
     #[must_use = "Subscription is leaked - ensure this is intentional"]
     pub fn into_receiver(self) -> T {
-        std::mem::forget(self._subscription); // Intentionally leak subscription
+        // The guard never unsubscribes on its own, so discarding the id
+        // leaves the subscription registered for good.
         self.receiver
     }
 }
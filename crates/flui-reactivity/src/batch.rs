@@ -1,12 +1,15 @@
 //! Batch updates for multiple signals.
 //!
 //! This module provides utilities for updating multiple signals atomically,
-//! deferring notifications until all updates are complete.
+//! deferring notifications until all updates are complete. Effects scheduled
+//! on an [`EffectScheduler`] during a batch are deduplicated and run once,
+//! after the batch's signal notifications.
 
 use parking_lot::Mutex;
 use std::collections::HashMap;
 use tracing::{debug, trace, warn};
 
+use crate::scheduler::EffectScheduler;
 use crate::signal::SignalId;
 
 /// Maximum number of pending notifications before forcing a flush.
@@ -22,6 +25,13 @@
 const MAX_BATCH_DEPTH: usize = 20;
 const WARN_BATCH_DEPTH: usize = 10;
 
+/// Maximum number of notify/effect rounds when closing a batch.
+///
+/// Effects may set signals, whose subscribers may schedule more effects; each
+/// such cascade is one more round. Hitting the limit almost certainly means
+/// two effects keep re-triggering each other.
+const MAX_FLUSH_ROUNDS: usize = 100;
+
 thread_local! {
     /// Thread-local batch update state.
     ///
@@ -37,6 +47,17 @@
     /// Each signal only notifies once per batch, even if set() is called multiple times.
     static PENDING_NOTIFICATIONS: Mutex<HashMap<SignalId, Box<dyn FnOnce() + Send>>> =
         Mutex::new(HashMap::new());
+
+    /// Whether the outermost batch is currently draining its queues.
+    ///
+    /// Notifications run after `BATCHING` is cleared, but effects they
+    /// schedule must still be collected so each runs once per batch.
+    static FLUSHING: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+
+    /// Schedulers that had effects scheduled during the batch, deduped by
+    /// identity and flushed in first-scheduled order when the batch closes.
+    static PENDING_SCHEDULERS: std::cell::RefCell<Vec<EffectScheduler>> =
+        const { std::cell::RefCell::new(Vec::new()) };
 }
 
 /// Check if currently in a batch update context.
@@ -47,6 +68,76 @@ pub fn is_batching() -> bool {
     BATCHING.with(|b| b.get())
 }
 
+/// Whether effects scheduled right now should wait for the batch to close:
+/// inside a batch, or while the closing batch drains its notifications.
+pub(crate) fn defers_effects() -> bool {
+    is_batching() || FLUSHING.with(|f| f.get())
+}
+
+/// Remember `scheduler` so it is flushed once when the current batch closes.
+pub(crate) fn defer_scheduler_flush(scheduler: &EffectScheduler) {
+    PENDING_SCHEDULERS.with(|pending| {
+        let mut pending = pending.borrow_mut();
+        if !pending.iter().any(|queued| queued.ptr_eq(scheduler)) {
+            pending.push(scheduler.clone());
+            trace!(count = pending.len(), "Scheduler queued for batch flush");
+        }
+    });
+}
+
+/// Drain everything a closing batch deferred: signal notifications first, then
+/// every scheduler that had effects scheduled, repeating while either step
+/// queues more work.
+fn flush_batch() {
+    let was_flushing = FLUSHING.with(|f| f.replace(true));
+
+    for round in 0..MAX_FLUSH_ROUNDS {
+        let notifications =
+            PENDING_NOTIFICATIONS.with(|pending| std::mem::take(&mut *pending.lock()));
+        let count = notifications.len();
+        if count > 0 {
+            debug!(count, round, "Executing pending notifications");
+        }
+
+        // Execute notifications with panic safety
+        for (signal_id, notification) in notifications {
+            if let Err(e) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                notification();
+            })) {
+                warn!(
+                    signal_id = ?signal_id,
+                    error = ?e,
+                    "Notification panicked during batch flush - continuing with remaining notifications"
+                );
+            }
+        }
+
+        let schedulers =
+            PENDING_SCHEDULERS.with(|pending| std::mem::take(&mut *pending.borrow_mut()));
+        if count == 0 && schedulers.is_empty() {
+            break;
+        }
+        if !schedulers.is_empty() {
+            debug!(
+                count = schedulers.len(),
+                round, "Flushing batched effect schedulers"
+            );
+        }
+        for scheduler in schedulers {
+            scheduler.flush();
+        }
+
+        if round + 1 == MAX_FLUSH_ROUNDS {
+            warn!(
+                "Batch flush did not settle after {} rounds; effects may be re-triggering each other",
+                MAX_FLUSH_ROUNDS
+            );
+        }
+    }
+
+    FLUSHING.with(|f| f.set(was_flushing));
+}
+
 /// Queue a notification to be executed after the batch completes.
 ///
 /// Notifications are deduped by SignalId, so each signal only notifies once per batch
@@ -213,24 +304,8 @@ pub fn batch<F, R>(f: F) -> R
     // End batching - thread-local state
     BATCHING.with(|b| b.set(false));
 
-    // Execute all pending notifications
-    let notifications = PENDING_NOTIFICATIONS.with(|pending| std::mem::take(&mut *pending.lock()));
-
-    let count = notifications.len();
-    debug!(count, "Executing pending notifications");
-
-    // Execute notifications with panic safety
-    for (signal_id, notification) in notifications {
-        if let Err(e) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-            notification();
-        })) {
-            warn!(
-                signal_id = ?signal_id,
-                error = ?e,
-                "Notification panicked during batch flush - continuing with remaining notifications"
-            );
-        }
-    }
+    // Execute all pending notifications, then the effects they scheduled
+    flush_batch();
 
     trace!("Batch update complete");
 
@@ -291,18 +366,8 @@ fn drop(&mut self) {
             // End batching - thread-local state
             BATCHING.with(|b| b.set(false));
 
-            // Execute all pending notifications
-            let notifications =
-                PENDING_NOTIFICATIONS.with(|pending| std::mem::take(&mut *pending.lock()));
-
-            let count = notifications.len();
-            if count > 0 {
-                debug!(count, "BatchGuard executing pending notifications");
-
-                for (_, notification) in notifications {
-                    notification();
-                }
-            }
+            // Execute all pending notifications, then the effects they scheduled
+            flush_batch();
 
             trace!("BatchGuard dropped");
         }
@@ -406,4 +471,94 @@ fn test_no_batch() {
 
         assert_eq!(notification_count.load(Ordering::SeqCst), 3);
     }
+
+    /// An effect registered on `scheduler` that re-runs whenever any of
+    /// `signals` changes, counting its runs.
+    fn counted_effect(
+        scheduler: &EffectScheduler,
+        signals: &[Signal<i32>],
+    ) -> (Arc<AtomicU32>, Vec<crate::signal::SubscriptionId>) {
+        let runs = Arc::new(AtomicU32::new(0));
+        let r = runs.clone();
+        let effect = scheduler.register(
+            move || {
+                r.fetch_add(1, Ordering::SeqCst);
+            },
+            crate::EffectPriority::Normal,
+        );
+        let subs = signals
+            .iter()
+            .map(|signal| {
+                let scheduler = scheduler.clone();
+                signal
+                    .subscribe(move || scheduler.schedule(effect))
+                    .expect("Failed to subscribe")
+            })
+            .collect();
+        (runs, subs)
+    }
+
+    #[test]
+    fn test_batch_runs_effect_once_for_several_dependencies() {
+        let (a, b, c) = (Signal::new(0), Signal::new(0), Signal::new(0));
+        let scheduler = EffectScheduler::new();
+        let (runs, _subs) = counted_effect(&scheduler, &[a, b, c]);
+
+        batch(|| {
+            a.update(|v| v + 1);
+            b.update(|v| v + 1);
+            c.update(|v| v + 1);
+            assert_eq!(runs.load(Ordering::SeqCst), 0);
+        });
+
+        // Flushed by the batch itself, exactly once.
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert!(!scheduler.has_pending());
+    }
+
+    #[test]
+    fn test_batch_guard_runs_effect_once_for_several_dependencies() {
+        let (a, b) = (Signal::new(0), Signal::new(0));
+        let scheduler = EffectScheduler::new();
+        let (runs, _subs) = counted_effect(&scheduler, &[a, b]);
+
+        {
+            let _batch = BatchGuard::new();
+            a.set(1);
+            b.set(1);
+            a.set(2);
+        }
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_batched_effects_run_by_priority_then_insertion() {
+        use crate::EffectPriority;
+
+        let signal = Signal::new(0);
+        let scheduler = EffectScheduler::new();
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let ids: Vec<_> = [
+            ("low", EffectPriority::Low),
+            ("normal-1", EffectPriority::Normal),
+            ("critical", EffectPriority::Critical),
+            ("normal-2", EffectPriority::Normal),
+        ]
+        .into_iter()
+        .map(|(name, priority)| {
+            let order = order.clone();
+            scheduler.register(move || order.lock().push(name), priority)
+        })
+        .collect();
+
+        let sched = scheduler.clone();
+        let _sub = signal
+            .subscribe(move || ids.iter().for_each(|&id| sched.schedule(id)))
+            .expect("Failed to subscribe");
+
+        batch(|| signal.set(1));
+
+        assert_eq!(*order.lock(), ["critical", "normal-1", "normal-2", "low"]);
+    }
 }
@@ -10,7 +10,7 @@
 use std::cell::RefCell;
 use std::collections::HashSet;
 use std::fmt;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 use tracing::{debug, trace};
 
 // Thread-local cycle detection for computed signals
@@ -82,6 +82,9 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 /// Computation function that tracks dependencies.
 type ComputeFn<T> = Box<dyn FnMut() -> T + Send + 'static>;
 
+/// Equality check deciding whether a recomputed value counts as a change.
+type EqFn<T> = Box<dyn Fn(&T, &T) -> bool + Send + Sync + 'static>;
+
 /// Stored subscription with signal ID
 ///
 /// Automatically unsubscribes when dropped (RAII cleanup).
@@ -110,6 +113,9 @@ struct ComputedInner<T> {
     dependencies: Mutex<HashSet<SignalId>>,
     subscriptions: Mutex<Vec<StoredSubscription>>,
     is_dirty: std::sync::atomic::AtomicBool, // Lock-free dirty flag for read-heavy workloads
+    /// When set, the computed recomputes eagerly on dependency changes and
+    /// only notifies downstream if `eq(old, new)` is false.
+    eq: Option<EqFn<T>>,
 }
 
 impl<T> fmt::Debug for ComputedInner<T> {
@@ -121,6 +127,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 "is_dirty",
                 &self.is_dirty.load(std::sync::atomic::Ordering::Acquire),
             )
+            .field("has_eq", &self.eq.is_some())
             .finish()
     }
 }
@@ -200,6 +207,53 @@ impl<T> Computed<T>
     /// The computation function will be called immediately to compute the initial value,
     /// and any signals accessed during computation will be tracked as dependencies.
     pub fn new<F>(compute_fn: F) -> Self
+    where
+        F: FnMut() -> T + Send + 'static,
+    {
+        Self::new_inner(compute_fn, None)
+    }
+
+    /// Create a computed signal that suppresses unchanged results.
+    ///
+    /// Whenever a dependency changes, the value is recomputed right away and
+    /// compared with the previous one: if `eq(old, new)` is true, no
+    /// notification reaches downstream computeds, effects or subscribers, so
+    /// a derived value that did not actually change does not cascade into
+    /// re-renders. Dependencies read by the recomputation are still tracked.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use flui_reactivity::{Computed, Signal};
+    ///
+    /// let offset = Signal::new(0.0_f32);
+    /// // Only notify when the rounded pixel position moves.
+    /// let pixel = Computed::new_with_eq(
+    ///     move || offset.get().round(),
+    ///     |a, b| a == b,
+    /// );
+    /// ```
+    pub fn new_with_eq<F, Eq>(compute_fn: F, eq: Eq) -> Self
+    where
+        F: FnMut() -> T + Send + 'static,
+        Eq: Fn(&T, &T) -> bool + Send + Sync + 'static,
+    {
+        Self::new_inner(compute_fn, Some(Box::new(eq)))
+    }
+
+    /// Create a computed signal that suppresses results equal to the previous
+    /// value according to [`PartialEq`].
+    ///
+    /// Shorthand for [`new_with_eq`](Self::new_with_eq) with `==`.
+    pub fn new_with_partial_eq<F>(compute_fn: F) -> Self
+    where
+        F: FnMut() -> T + Send + 'static,
+        T: PartialEq,
+    {
+        Self::new_with_eq(compute_fn, |old: &T, new: &T| old == new)
+    }
+
+    fn new_inner<F>(compute_fn: F, eq: Option<EqFn<T>>) -> Self
     where
         F: FnMut() -> T + Send + 'static,
     {
@@ -224,6 +278,7 @@ pub fn new<F>(compute_fn: F) -> Self
             dependencies: Mutex::new(dependencies.clone()),
             subscriptions: Mutex::new(Vec::new()),
             is_dirty: std::sync::atomic::AtomicBool::new(false),
+            eq,
         });
 
         // Subscribe to all dependencies with rollback on failure
@@ -231,20 +286,7 @@ pub fn new<F>(compute_fn: F) -> Self
         let mut subscriptions = Vec::new();
 
         for &dep_id in &dependencies {
-            let weak = weak_inner.clone();
-
-            match SignalRuntime::global().subscribe(dep_id, move || {
-                if let Some(inner) = weak.upgrade() {
-                    inner
-                        .is_dirty
-                        .store(true, std::sync::atomic::Ordering::Release);
-                    trace!(computed_id = ?inner.id, "Marked dirty");
-
-                    // Trigger notification on cached_value to propagate dirty flag
-                    // to downstream computed signals
-                    inner.cached_value.notify_subscribers();
-                }
-            }) {
+            match Self::subscribe_dependency(weak_inner.clone(), dep_id) {
                 Ok(sub_id) => {
                     subscriptions.push(StoredSubscription {
                         signal_id: dep_id,
@@ -349,10 +391,50 @@ pub fn get(&self) -> T {
     /// - This likely indicates circular Computed dependencies across threads
     /// - The computation function panics
     fn recompute(&self) {
+        Self::recompute_inner(&self.inner);
+    }
+
+    /// Subscribe `weak` to changes of the dependency `dep_id`.
+    fn subscribe_dependency(
+        weak: Weak<ComputedInner<T>>,
+        dep_id: SignalId,
+    ) -> Result<SubscriptionId, crate::error::SignalError> {
+        SignalRuntime::global().subscribe(dep_id, move || {
+            if let Some(inner) = weak.upgrade() {
+                Self::on_dependency_changed(&inner);
+            }
+        })
+    }
+
+    /// React to a dependency change.
+    ///
+    /// A plain computed only marks itself dirty (recomputing lazily on the
+    /// next `get`) and propagates the dirty flag downstream. A computed with
+    /// an equality check must know the new value to decide whether anything
+    /// changed, so it recomputes eagerly and notifies only on a real change.
+    fn on_dependency_changed(inner: &Arc<ComputedInner<T>>) {
+        if inner.eq.is_some() {
+            inner
+                .is_dirty
+                .store(false, std::sync::atomic::Ordering::Release);
+            Self::recompute_inner(inner);
+            return;
+        }
+
+        inner
+            .is_dirty
+            .store(true, std::sync::atomic::Ordering::Release);
+        trace!(computed_id = ?inner.id, "Marked dirty");
+
+        // Trigger notification on cached_value to propagate dirty flag
+        // to downstream computed signals
+        inner.cached_value.notify_subscribers();
+    }
+
+    fn recompute_inner(inner: &Arc<ComputedInner<T>>) {
         let (new_value, new_dependencies) = {
             // Try to acquire lock with timeout to detect potential deadlocks
-            let mut compute_fn = self
-                .inner
+            let mut compute_fn = inner
                 .compute_fn
                 .try_lock_for(std::time::Duration::from_secs(5))
                 .unwrap_or_else(|| {
@@ -381,12 +463,19 @@ fn recompute(&self) {
             (value, dependencies)
         };
 
-        // Update cached value
-        self.inner.cached_value.set(new_value);
+        // Update cached value, unless the equality check says nothing changed
+        // (then downstream is not notified at all).
+        let unchanged = inner.eq.as_ref().is_some_and(|eq| {
+            SignalRuntime::global().with(inner.cached_value.id(), |old: &T| eq(old, &new_value))
+        });
+        if unchanged {
+            trace!(computed_id = ?inner.id, "Recomputed value unchanged; notification suppressed");
+        } else {
+            inner.cached_value.set(new_value);
+        }
 
         // Update dependencies if changed
-        let mut deps = self
-            .inner
+        let mut deps = inner
             .dependencies
             .try_lock_for(std::time::Duration::from_secs(5))
             .unwrap_or_else(|| {
@@ -398,7 +487,7 @@ fn recompute(&self) {
             });
         if *deps != new_dependencies {
             debug!(
-                computed_id = ?self.inner.id,
+                computed_id = ?inner.id,
                 old_count = deps.len(),
                 new_count = new_dependencies.len(),
                 "Dependencies changed"
@@ -406,8 +495,7 @@ fn recompute(&self) {
 
             // Unsubscribe from old dependencies
             let old_subs = std::mem::take(
-                &mut *self
-                    .inner
+                &mut *inner
                     .subscriptions
                     .try_lock_for(std::time::Duration::from_secs(5))
                     .unwrap_or_else(|| {
@@ -423,19 +511,11 @@ fn recompute(&self) {
             }
 
             // Subscribe to new dependencies
-            let weak_inner = Arc::downgrade(&self.inner);
+            let weak_inner = Arc::downgrade(inner);
             let mut subscriptions = Vec::new();
 
             for &dep_id in &new_dependencies {
-                let weak = weak_inner.clone();
-                let sub_id = SignalRuntime::global()
-                    .subscribe(dep_id, move || {
-                        if let Some(inner) = weak.upgrade() {
-                            inner
-                                .is_dirty
-                                .store(true, std::sync::atomic::Ordering::Release);
-                        }
-                    })
+                let sub_id = Self::subscribe_dependency(weak_inner.clone(), dep_id)
                     .expect("Failed to subscribe to dependency: too many subscribers");
 
                 subscriptions.push(StoredSubscription {
@@ -444,14 +524,14 @@ fn recompute(&self) {
                 });
             }
 
-            *self.inner.subscriptions.lock() = subscriptions;
+            *inner.subscriptions.lock() = subscriptions;
             *deps = new_dependencies;
         }
 
         // Note: is_dirty flag already reset to false in get() using swap()
         // No need to set it again here
 
-        trace!(computed_id = ?self.inner.id, "Recomputed");
+        trace!(computed_id = ?inner.id, "Recomputed");
     }
 
     /// Track dependencies during computation.
@@ -668,4 +748,83 @@ fn test_computed_lazy_evaluation() {
         let _ = computed.get();
         assert_eq!(compute_count.load(Ordering::SeqCst), 2);
     }
+
+    #[test]
+    fn test_computed_with_eq_suppresses_unchanged_values() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let input = Signal::new(5);
+        let clamped = Computed::new_with_partial_eq(move || input.get().clamp(0, 10));
+
+        let notified = Arc::new(AtomicU32::new(0));
+        let n = notified.clone();
+        let _sub = clamped
+            .subscribe_scoped(move || {
+                n.fetch_add(1, Ordering::SeqCst);
+            })
+            .expect("Failed to subscribe");
+
+        // Past the upper bound: the clamped value changes once...
+        input.set(20);
+        assert_eq!(clamped.get(), 10);
+        assert_eq!(notified.load(Ordering::SeqCst), 1);
+
+        // ...then stays pinned at 10, so nothing propagates.
+        input.set(30);
+        input.set(40);
+        assert_eq!(clamped.get(), 10);
+        assert_eq!(notified.load(Ordering::SeqCst), 1);
+
+        // Back inside the range it changes again.
+        input.set(7);
+        assert_eq!(clamped.get(), 7);
+        assert_eq!(notified.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_computed_with_eq_stops_downstream_recompute() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let input = Signal::new(1_i32);
+        let sign = Computed::new_with_eq(move || input.get().signum(), |a, b| a == b);
+        let downstream_runs = Arc::new(AtomicU32::new(0));
+        let runs = downstream_runs.clone();
+        let sign_for_label = sign.clone();
+        let label = Computed::new(move || {
+            runs.fetch_add(1, Ordering::SeqCst);
+            if sign_for_label.get() < 0 {
+                "negative"
+            } else {
+                "non-negative"
+            }
+        });
+        assert_eq!(downstream_runs.load(Ordering::SeqCst), 1);
+
+        input.set(2);
+        input.set(3);
+        assert_eq!(label.get(), "non-negative");
+        assert!(!label.is_dirty());
+        assert_eq!(downstream_runs.load(Ordering::SeqCst), 1);
+
+        input.set(-3);
+        assert_eq!(label.get(), "negative");
+        assert_eq!(downstream_runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_computed_with_eq_tracks_new_dependencies() {
+        let use_b = Signal::new(false);
+        let a = Signal::new(1);
+        let b = Signal::new(1);
+        let picked =
+            Computed::new_with_partial_eq(move || if use_b.get() { b.get() } else { a.get() });
+
+        // Switching sources yields an equal value (suppressed), but `b` must
+        // still become a tracked dependency.
+        use_b.set(true);
+        assert_eq!(picked.get(), 1);
+
+        b.set(9);
+        assert_eq!(picked.get(), 9);
+    }
 }
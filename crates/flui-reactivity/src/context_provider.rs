@@ -309,7 +309,7 @@ fn test_multiple_contexts() {
         let config = use_context::<Config>().unwrap();
 
         assert_eq!(theme.color, "green");
-        assert_eq!(config.debug, true);
+        assert!(config.debug);
 
         remove_context::<Theme>();
         remove_context::<Config>();
@@ -328,7 +328,7 @@ fn test_context_scope() {
             let config = use_context::<Config>().unwrap();
 
             assert_eq!(theme.color, "purple");
-            assert_eq!(config.debug, false);
+            assert!(!config.debug);
         });
 
         // Both should be cleaned up
@@ -150,7 +150,8 @@ pub enum RuntimeError {
 }
 
 /// Extension trait for Result types to provide additional context.
-pub trait ResultExt<T> { // PORT-CHECK-OK-SP3: pre-existing parallel definition; consolidation tracked
+pub trait ResultExt<T> {
+    // PORT-CHECK-OK-SP3: pre-existing parallel definition; consolidation tracked
     /// Add context to an error.
     fn context(self, msg: impl Into<String>) -> Result<T>;
 
@@ -258,7 +259,7 @@ fn test_result_with_context() {
         let result: std::result::Result<(), SignalError> =
             Err(SignalError::NotFound(SignalId::new()));
 
-        let with_context = result.with_context(|| format!("Context: signal lookup failed"));
+        let with_context = result.with_context(|| "Context: signal lookup failed".to_string());
         assert!(with_context.is_err());
         let err_msg = with_context.unwrap_err().to_string();
         assert!(err_msg.contains("Context: signal lookup failed"));
@@ -0,0 +1,339 @@
+//! Async effect hook with abort-on-cleanup.
+//!
+//! `use_async_effect` is the async counterpart of
+//! [`use_effect`](super::effect::use_effect): the effect is a future spawned on
+//! the configured executor. Because a future can outlive the render that
+//! started it, every run receives an [`AbortSignal`] that is tripped when the
+//! dependencies change or the component unmounts, and the spawned task itself
+//! is aborted at that point.
+
+use crate::context::HookContext;
+use crate::traits::{DependencyId, Hook};
+use futures_util::future::{AbortHandle, Abortable};
+use parking_lot::Mutex;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Poll, Waker};
+
+/// Future returned by an async effect.
+pub type AsyncEffectFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// Async effect function type.
+pub type AsyncEffectFn = Arc<dyn Fn(AbortSignal) -> AsyncEffectFuture + Send + Sync + 'static>;
+
+#[derive(Default)]
+struct AbortSignalInner {
+    aborted: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+/// Cancellation signal handed to an async effect.
+///
+/// The signal is tripped when the effect is cleaned up (dependencies changed
+/// or the component unmounted). Long synchronous sections can poll
+/// [`is_aborted`](Self::is_aborted); async code can await
+/// [`aborted`](Self::aborted), e.g. to race it against other work or to stop
+/// helper tasks the effect spawned itself.
+///
+/// Cloning is cheap; all clones observe the same state.
+#[derive(Clone, Default)]
+pub struct AbortSignal {
+    inner: Arc<AbortSignalInner>,
+}
+
+impl AbortSignal {
+    /// Create a signal that has not been aborted yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true once the signal has been tripped.
+    pub fn is_aborted(&self) -> bool {
+        self.inner.aborted.load(Ordering::Acquire)
+    }
+
+    /// Resolve once the signal has been tripped.
+    ///
+    /// Resolves immediately if it already was.
+    pub async fn aborted(&self) {
+        futures_util::future::poll_fn(|cx| {
+            if self.is_aborted() {
+                return Poll::Ready(());
+            }
+            let mut wakers = self.inner.wakers.lock();
+            // Re-check under the lock: `abort` drains the wakers while holding
+            // it, so a trip between the first check and here is not missed.
+            if self.is_aborted() {
+                return Poll::Ready(());
+            }
+            if !wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                wakers.push(cx.waker().clone());
+            }
+            Poll::Pending
+        })
+        .await;
+    }
+
+    /// Trip the signal and wake everything awaiting [`aborted`](Self::aborted).
+    pub(crate) fn abort(&self) {
+        let wakers = {
+            let mut wakers = self.inner.wakers.lock();
+            self.inner.aborted.store(true, Ordering::Release);
+            std::mem::take(&mut *wakers)
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl std::fmt::Debug for AbortSignal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AbortSignal")
+            .field("aborted", &self.is_aborted())
+            .finish()
+    }
+}
+
+/// A spawned effect run: its signal and the handle aborting its task.
+struct RunningEffect {
+    signal: AbortSignal,
+    task: AbortHandle,
+}
+
+impl RunningEffect {
+    fn spawn(effect: &AsyncEffectFn) -> Self {
+        let signal = AbortSignal::new();
+        let (task, registration) = AbortHandle::new_pair();
+        let future = Abortable::new(effect(signal.clone()), registration);
+
+        any_spawner::Executor::spawn(async move {
+            if future.await.is_err() {
+                tracing::trace!("Async effect aborted");
+            }
+        });
+
+        Self { signal, task }
+    }
+
+    /// Trip the signal, then abort the task.
+    fn stop(self) {
+        self.signal.abort();
+        self.task.abort();
+    }
+}
+
+/// Hook state for AsyncEffectHook.
+pub struct AsyncEffectState {
+    dependencies: Vec<DependencyId>,
+    running: Option<RunningEffect>,
+}
+
+impl std::fmt::Debug for AsyncEffectState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncEffectState")
+            .field("dependencies", &self.dependencies)
+            .field("running", &self.running.is_some())
+            .finish()
+    }
+}
+
+/// Async effect hook implementation.
+pub struct AsyncEffectHook;
+
+impl Hook for AsyncEffectHook {
+    type State = AsyncEffectState;
+    type Input = (AsyncEffectFn, Vec<DependencyId>);
+    type Output = ();
+
+    fn create(input: Self::Input) -> Self::State {
+        let (effect, dependencies) = input;
+
+        AsyncEffectState {
+            dependencies,
+            running: Some(RunningEffect::spawn(&effect)),
+        }
+    }
+
+    fn update(state: &mut Self::State, input: Self::Input) -> Self::Output {
+        let (effect, new_deps) = input;
+
+        if state.dependencies != new_deps {
+            // Stop the previous run before the new one is spawned.
+            if let Some(running) = state.running.take() {
+                running.stop();
+            }
+
+            state.running = Some(RunningEffect::spawn(&effect));
+            state.dependencies = new_deps;
+        }
+    }
+
+    fn cleanup(mut state: Self::State) {
+        if let Some(running) = state.running.take() {
+            running.stop();
+        }
+    }
+}
+
+/// Run an async side effect that is aborted on cleanup.
+///
+/// The effect is spawned on mount and again whenever `dependencies` change.
+/// Before a new run is spawned (and when the component unmounts) the previous
+/// run's [`AbortSignal`] is tripped and its task is aborted, so the future is
+/// dropped at its next suspension point even if it never checks the signal.
+/// Put cleanup that must always happen in `Drop` guards owned by the future.
+///
+/// **Note:** Requires the `async` feature to be enabled.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use flui_reactivity::{use_async_effect, AbortSignal};
+///
+/// let room_dep = DependencyId::new(room.id().0);
+/// use_async_effect(ctx, vec![room_dep], move |signal: AbortSignal| async move {
+///     let mut connection = connect(room.get()).await;
+///     while !signal.is_aborted() {
+///         let message = connection.next().await;
+///         messages.update(|list| list.push(message));
+///     }
+/// });
+/// ```
+#[cfg(feature = "async")]
+pub fn use_async_effect<F, Fut>(ctx: &mut HookContext, dependencies: Vec<DependencyId>, effect: F)
+where
+    F: Fn(AbortSignal) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let effect: AsyncEffectFn = Arc::new(move |signal| Box::pin(effect(signal)));
+    ctx.use_hook::<AsyncEffectHook>((effect, dependencies))
+}
+
+#[cfg(test)]
+#[cfg(feature = "async")]
+mod tests {
+    use super::*;
+    use crate::context::ComponentId;
+    use std::sync::atomic::AtomicUsize;
+    use tokio::time::Duration;
+
+    fn init_executor() {
+        // Idempotent across tests: later calls report "already set".
+        let _ = any_spawner::Executor::init_tokio();
+    }
+
+    async fn settle() {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    #[tokio::test]
+    async fn test_abort_signal_wakes_waiters() {
+        let signal = AbortSignal::new();
+        let waiter = tokio::spawn({
+            let signal = signal.clone();
+            async move { signal.aborted().await }
+        });
+        settle().await;
+        assert!(!signal.is_aborted());
+        assert!(!waiter.is_finished());
+
+        signal.abort();
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("waiter not woken")
+            .expect("waiter panicked");
+        assert!(signal.is_aborted());
+        // Already tripped: resolves immediately.
+        signal.aborted().await;
+    }
+
+    #[tokio::test]
+    async fn test_long_running_effect_stops_when_deps_change() {
+        init_executor();
+        let mut ctx = HookContext::new();
+        let ticks = Arc::new(AtomicUsize::new(0));
+        let first_signal = Arc::new(Mutex::new(None::<AbortSignal>));
+
+        ctx.begin_component(ComponentId(1));
+        {
+            let ticks = Arc::clone(&ticks);
+            let first_signal = Arc::clone(&first_signal);
+            use_async_effect(&mut ctx, vec![DependencyId::new(1)], move |signal| {
+                *first_signal.lock() = Some(signal);
+                let ticks = Arc::clone(&ticks);
+                async move {
+                    loop {
+                        ticks.fetch_add(1, Ordering::SeqCst);
+                        tokio::time::sleep(Duration::from_millis(1)).await;
+                    }
+                }
+            });
+        }
+        ctx.end_component();
+
+        settle().await;
+        assert!(ticks.load(Ordering::SeqCst) > 0, "effect never ran");
+
+        ctx.begin_component(ComponentId(1));
+        use_async_effect(&mut ctx, vec![DependencyId::new(2)], |_| async {});
+        ctx.end_component();
+
+        let signal = first_signal
+            .lock()
+            .clone()
+            .expect("first run never started");
+        assert!(signal.is_aborted());
+
+        settle().await;
+        let stopped_at = ticks.load(Ordering::SeqCst);
+        settle().await;
+        assert_eq!(ticks.load(Ordering::SeqCst), stopped_at);
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_runs_before_next_effect_starts() {
+        init_executor();
+        let mut ctx = HookContext::new();
+        let previous = Arc::new(Mutex::new(None::<AbortSignal>));
+        // Whether the previous run was already aborted when each run started.
+        let previous_aborted_at_start = Arc::new(Mutex::new(Vec::new()));
+
+        for dep in 1..=3 {
+            let previous = Arc::clone(&previous);
+            let log = Arc::clone(&previous_aborted_at_start);
+            ctx.begin_component(ComponentId(1));
+            use_async_effect(&mut ctx, vec![DependencyId::new(dep)], move |signal| {
+                if let Some(prev) = previous.lock().replace(signal.clone()) {
+                    log.lock().push(prev.is_aborted());
+                }
+                async move { signal.aborted().await }
+            });
+            ctx.end_component();
+            settle().await;
+        }
+
+        assert_eq!(*previous_aborted_at_start.lock(), vec![true, true]);
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_deps_keep_effect_running() {
+        init_executor();
+        let mut ctx = HookContext::new();
+        let runs = Arc::new(AtomicUsize::new(0));
+
+        for _ in 0..3 {
+            let runs = Arc::clone(&runs);
+            ctx.begin_component(ComponentId(1));
+            use_async_effect(&mut ctx, vec![DependencyId::new(1)], move |_| {
+                runs.fetch_add(1, Ordering::SeqCst);
+                async {}
+            });
+            ctx.end_component();
+        }
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+}
@@ -4,6 +4,8 @@
 //! component state and side effects in FLUI applications.
 
 // Re-export all hook implementations
+#[cfg(feature = "async")]
+pub mod async_effect;
 pub mod callback;
 pub mod effect;
 pub mod memo;
@@ -19,5 +21,7 @@
 pub use r#ref::{use_ref, Ref};
 pub use reducer::{use_reducer, Dispatch, Reducer};
 
+#[cfg(feature = "async")]
+pub use async_effect::{use_async_effect, AbortSignal};
 #[cfg(feature = "async")]
 pub use resource::{use_resource, Resource, ResourceState};
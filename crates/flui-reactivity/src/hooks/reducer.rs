@@ -184,6 +184,8 @@ mod tests {
     use super::*;
     use crate::context::ComponentId;
 
+    // Not every action is dispatched by every test.
+    #[allow(dead_code)]
     #[derive(Debug, Clone, PartialEq)]
     enum CounterAction {
         Increment,
@@ -246,6 +248,7 @@ struct State {
             name: String,
         }
 
+        #[allow(dead_code)]
         #[derive(Debug, Clone)]
         enum Action {
             SetCount(i32),
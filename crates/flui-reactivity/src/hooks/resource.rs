@@ -6,6 +6,8 @@
 use crate::context::HookContext;
 use crate::signal::Signal;
 use crate::traits::{DependencyId, Hook};
+use futures_util::future::AbortHandle;
+use parking_lot::Mutex;
 use std::future::Future;
 use std::marker::PhantomData;
 use std::pin::Pin;
@@ -82,6 +84,8 @@ pub fn map_err<F>(self, f: impl FnOnce(E) -> F) -> ResourceState<T, F> {
     }
 }
 
+// Manual impl: `#[derive(Default)]` would demand `T: Default, E: Default`.
+#[allow(clippy::derivable_impls)]
 impl<T: Clone, E: Clone> Default for ResourceState<T, E> {
     fn default() -> Self {
         ResourceState::Idle
@@ -94,11 +98,66 @@ fn default() -> Self {
 /// Fetcher function type.
 pub type Fetcher<T, E> = Arc<dyn Fn() -> ResourceFuture<T, E> + Send + Sync>;
 
+/// Bookkeeping for the fetch currently in flight, shared by every clone of a
+/// [`Resource`].
+///
+/// Every fetch is tagged with a generation, bumped on each fetch start and
+/// cancellation. The generation and the abort handle live under one lock, so
+/// a fetch that resolves after being superseded can neither publish its
+/// result nor clear the abort handle of the fetch that replaced it.
+#[derive(Default)]
+struct InFlight {
+    slot: Mutex<InFlightSlot>,
+}
+
+#[derive(Default)]
+struct InFlightSlot {
+    generation: u64,
+    /// Abort handle of the fetch tagged `generation`, if it is still running.
+    abort: Option<AbortHandle>,
+}
+
+impl InFlight {
+    /// Abort the in-flight fetch (if any) and register `abort` as the new
+    /// one. Returns the new fetch's generation.
+    fn start(&self, abort: AbortHandle) -> u64 {
+        let mut slot = self.slot.lock();
+        if let Some(previous) = slot.abort.replace(abort) {
+            previous.abort();
+        }
+        slot.generation += 1;
+        slot.generation
+    }
+
+    /// Abort the in-flight fetch (if any) without starting a new one.
+    fn cancel(&self) {
+        let mut slot = self.slot.lock();
+        if let Some(previous) = slot.abort.take() {
+            previous.abort();
+        }
+        slot.generation += 1;
+    }
+
+    /// Mark the fetch tagged `generation` as finished.
+    ///
+    /// Returns `false`, leaving the slot untouched, if a newer fetch or a
+    /// cancellation superseded it; its result must then be discarded.
+    fn finish(&self, generation: u64) -> bool {
+        let mut slot = self.slot.lock();
+        if slot.generation != generation {
+            return false;
+        }
+        slot.abort = None;
+        true
+    }
+}
+
 /// Resource handle for managing async data.
 #[derive(Clone)]
 pub struct Resource<T, E> {
     state_signal: Signal<ResourceState<T, E>>,
     fetcher: Fetcher<T, E>,
+    in_flight: Arc<InFlight>,
 }
 
 impl<T, E> Resource<T, E>
@@ -113,19 +172,32 @@ pub fn state(&self) -> ResourceState<T, E> {
 
     /// Refetch the resource.
     ///
-    /// This sets the state to Loading and starts a new fetch.
+    /// This cancels any fetch still in flight, sets the state to Loading and
+    /// starts a new fetch. Only the newest fetch can publish a result: a
+    /// superseded fetch that resolves anyway is discarded.
     pub fn refetch(&self) {
+        let (abort, registration) = AbortHandle::new_pair();
+        let generation = self.in_flight.start(abort);
         self.state_signal.set(ResourceState::Loading);
 
         let state_signal = self.state_signal.clone();
         let fetcher = Arc::clone(&self.fetcher);
+        let in_flight = Arc::clone(&self.in_flight);
 
         // Spawn the async task
         #[cfg(feature = "async")]
         {
             use any_spawner::Executor;
+            let fetch = futures_util::future::Abortable::new((fetcher)(), registration);
             Executor::spawn(async move {
-                let result = (fetcher)().await;
+                let Ok(result) = fetch.await else {
+                    tracing::trace!(generation, "Resource fetch cancelled");
+                    return;
+                };
+                if !in_flight.finish(generation) {
+                    tracing::trace!(generation, "Discarding superseded resource fetch");
+                    return;
+                }
                 match result {
                     Ok(data) => state_signal.set(ResourceState::Ready(data)),
                     Err(err) => state_signal.set(ResourceState::Error(err)),
@@ -144,6 +216,17 @@ pub fn refetch(&self) {
         }
     }
 
+    /// Cancel the in-flight fetch, if any.
+    ///
+    /// A resource that was `Loading` returns to `Idle`; a settled resource
+    /// keeps its data or error.
+    pub fn cancel(&self) {
+        self.in_flight.cancel();
+        if self.state().is_loading() {
+            self.state_signal.set(ResourceState::Idle);
+        }
+    }
+
     /// Check if the resource is loading.
     pub fn is_loading(&self) -> bool {
         self.state().is_loading()
@@ -183,6 +266,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 }
 
 /// Hook state for ResourceHook.
+#[allow(non_camel_case_types)]
 pub struct ResourceState_Internal<T, E> {
     resource: Resource<T, E>,
     dependencies: Vec<DependencyId>,
@@ -221,6 +305,7 @@ fn create(input: Self::Input) -> Self::State {
         let resource = Resource {
             state_signal,
             fetcher,
+            in_flight: Arc::new(InFlight::default()),
         };
 
         // Start initial fetch
@@ -243,15 +328,18 @@ fn update(state: &mut Self::State, input: Self::Input) -> Self::Output {
             state.resource.fetcher = fetcher;
             state.dependencies = new_deps;
 
-            // Refetch with new dependencies
+            // Refetch with new dependencies; this cancels a fetch for the old
+            // dependencies that has not resolved yet.
             state.resource.refetch();
         }
 
         state.resource.clone()
     }
 
-    fn cleanup(_state: Self::State) {
-        // Signal cleanup is automatic
+    fn cleanup(state: Self::State) {
+        // Signal cleanup is automatic; a fetch still in flight has nowhere
+        // left to report to.
+        state.resource.in_flight.cancel();
     }
 }
 
@@ -318,9 +406,42 @@ pub fn use_resource<T, E, F>(
 mod tests {
     use super::*;
     use crate::context::ComponentId;
+    use std::collections::VecDeque;
+    use tokio::sync::oneshot;
+
+    fn init_executor() {
+        // Idempotent across tests: later calls report "already set".
+        let _ = any_spawner::Executor::init_tokio();
+    }
+
+    /// A fetcher whose n-th call resolves only when the n-th sender fires,
+    /// so tests can resolve fetches in any order.
+    fn controllable_fetcher(
+        count: usize,
+    ) -> (
+        Vec<oneshot::Sender<i32>>,
+        impl Fn() -> ResourceFuture<i32, String> + Send + Sync + 'static,
+    ) {
+        let (senders, receivers): (Vec<_>, VecDeque<_>) =
+            (0..count).map(|_| oneshot::channel::<i32>()).unzip();
+        let receivers = Arc::new(Mutex::new(receivers));
+        let fetcher = move || -> ResourceFuture<i32, String> {
+            let rx = receivers
+                .lock()
+                .pop_front()
+                .expect("fetcher called more often than the test planned");
+            Box::pin(async move { rx.await.map_err(|_| "sender dropped".to_string()) })
+        };
+        (senders, fetcher)
+    }
+
+    async fn settle() {
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+    }
 
     #[tokio::test]
     async fn test_resource_basic() {
+        init_executor();
         let mut ctx = HookContext::new();
         ctx.begin_component(ComponentId(1));
 
@@ -341,6 +462,7 @@ async fn test_resource_basic() {
 
     #[tokio::test]
     async fn test_resource_error() {
+        init_executor();
         let mut ctx = HookContext::new();
         ctx.begin_component(ComponentId(1));
 
@@ -361,6 +483,7 @@ async fn test_resource_error() {
 
     #[tokio::test]
     async fn test_resource_refetch() {
+        init_executor();
         let mut ctx = HookContext::new();
         ctx.begin_component(ComponentId(1));
 
@@ -390,6 +513,103 @@ async fn test_resource_refetch() {
         assert_eq!(resource.data(), Some(2));
     }
 
+    #[tokio::test]
+    async fn test_refetch_discards_superseded_fetch_resolving_late() {
+        init_executor();
+        let mut ctx = HookContext::new();
+        ctx.begin_component(ComponentId(1));
+
+        let (mut senders, fetcher) = controllable_fetcher(2);
+        let resource = use_resource(&mut ctx, vec![], fetcher);
+        resource.refetch();
+        assert!(resource.is_loading());
+
+        // Resolve out of order: the newer fetch first, then the stale one.
+        let stale = senders.remove(0);
+        senders.remove(0).send(2).unwrap();
+        settle().await;
+        assert_eq!(resource.data(), Some(2));
+
+        // The first fetch was cancelled, so its receiver is gone; even if it
+        // were not, its result must not clobber the newer one.
+        let _ = stale.send(1);
+        settle().await;
+        assert_eq!(resource.data(), Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_dependency_change_cancels_in_flight_fetch() {
+        init_executor();
+        let mut ctx = HookContext::new();
+
+        let (mut senders, fetcher) = controllable_fetcher(2);
+        let fetcher: Fetcher<i32, String> = Arc::new(fetcher);
+
+        ctx.begin_component(ComponentId(1));
+        let resource = ctx.use_hook::<ResourceHook<i32, String>>((
+            Arc::clone(&fetcher),
+            vec![DependencyId::new(1)],
+        ));
+        ctx.end_component();
+
+        // Dependencies change before the first fetch resolves.
+        ctx.begin_component(ComponentId(1));
+        let _ = ctx.use_hook::<ResourceHook<i32, String>>((
+            Arc::clone(&fetcher),
+            vec![DependencyId::new(2)],
+        ));
+        ctx.end_component();
+        assert!(resource.is_loading());
+
+        let _ = senders.remove(0).send(1);
+        settle().await;
+        assert!(resource.is_loading(), "the stale fetch must not publish");
+
+        senders.remove(0).send(2).unwrap();
+        settle().await;
+        assert_eq!(resource.data(), Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_returns_loading_resource_to_idle() {
+        init_executor();
+        let mut ctx = HookContext::new();
+        ctx.begin_component(ComponentId(1));
+
+        let (mut senders, fetcher) = controllable_fetcher(1);
+        let resource = use_resource(&mut ctx, vec![], fetcher);
+        resource.cancel();
+        assert!(resource.state().is_idle());
+
+        let _ = senders.remove(0).send(1);
+        settle().await;
+        assert!(resource.state().is_idle());
+    }
+
+    #[test]
+    fn test_stale_fetch_neither_publishes_nor_clears_newer_abort_handle() {
+        let in_flight = InFlight::default();
+        let (stale_abort, _stale_registration) = AbortHandle::new_pair();
+        let (current_abort, _current_registration) = AbortHandle::new_pair();
+
+        let stale = in_flight.start(stale_abort.clone());
+        let current = in_flight.start(current_abort.clone());
+        assert!(
+            stale_abort.is_aborted(),
+            "starting a fetch aborts the previous one"
+        );
+
+        // The superseded fetch resolving late is rejected and must leave the
+        // newer fetch cancellable.
+        assert!(!in_flight.finish(stale));
+        in_flight.cancel();
+        assert!(current_abort.is_aborted());
+        assert!(
+            !in_flight.finish(current),
+            "a cancelled fetch must not publish"
+        );
+    }
+
     #[test]
     fn test_resource_state_methods() {
         let idle: ResourceState<i32, String> = ResourceState::Idle;
@@ -101,7 +101,7 @@
 };
 
 #[cfg(feature = "async")]
-pub use hooks::{use_resource, Resource, ResourceState};
+pub use hooks::{use_async_effect, use_resource, AbortSignal, Resource, ResourceState};
 
 // Re-export Context API
 pub use context_provider::{
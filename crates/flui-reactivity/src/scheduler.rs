@@ -84,7 +84,12 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 struct ScheduledEffect {
     id: EffectId,
     callback: EffectCallback,
-    #[allow(dead_code)]
+    priority: EffectPriority,
+}
+
+/// A registered effect: its callback and the priority it was registered with.
+struct RegisteredEffect {
+    callback: EffectCallback,
     priority: EffectPriority,
 }
 
@@ -145,7 +150,7 @@ struct SchedulerState {
     /// Set of queued effect IDs (for deduplication)
     queued: HashSet<EffectId>,
     /// Registered effects by ID
-    effects: std::collections::HashMap<EffectId, EffectCallback>,
+    effects: std::collections::HashMap<EffectId, RegisteredEffect>,
     /// Whether the scheduler is currently flushing
     is_flushing: bool,
 }
@@ -153,7 +158,13 @@ struct SchedulerState {
 /// Async effect scheduler for batched updates.
 ///
 /// The scheduler batches effect executions to prevent redundant updates.
-/// Effects are queued and executed in batches when `flush()` is called.
+/// Effects are queued and executed in batches when `flush()` is called,
+/// highest [`EffectPriority`] first and in scheduling order within a priority.
+///
+/// Scheduling inside a [`batch`](crate::batch) (or while a
+/// [`BatchGuard`](crate::BatchGuard) is alive) also flushes the scheduler
+/// automatically when the batch closes, so an effect whose dependencies all
+/// change in one batch runs exactly once.
 ///
 /// # Example
 ///
@@ -206,7 +217,9 @@ pub fn register<F>(&self, callback: F, priority: EffectPriority) -> EffectId
         let callback = Arc::new(Mutex::new(
             Box::new(callback) as Box<dyn FnMut() + Send + 'static>
         ));
-        state.effects.insert(id, callback);
+        state
+            .effects
+            .insert(id, RegisteredEffect { callback, priority });
 
         debug!(effect_id = ?id, priority = ?priority, "Effect registered");
         id
@@ -215,7 +228,12 @@ pub fn register<F>(&self, callback: F, priority: EffectPriority) -> EffectId
     /// Schedule an effect for execution.
     ///
     /// If the effect is already queued, this is a no-op (deduplication).
+    /// Inside a batch, the scheduler is flushed when the batch closes.
     pub fn schedule(&self, effect_id: EffectId) {
+        if crate::batch::defers_effects() {
+            crate::batch::defer_scheduler_flush(self);
+        }
+
         let mut state = self.state.lock();
 
         // Skip if already queued (deduplication)
@@ -253,8 +271,11 @@ pub fn schedule(&self, effect_id: EffectId) {
 
         // Clone the effect callback instead of removing it
         // This prevents use-after-free if effect is unregistered during execution
-        if let Some(callback) = state.effects.get(&effect_id).cloned() {
-            let priority = EffectPriority::Normal; // Could be stored with effect
+        if let Some((callback, priority)) = state
+            .effects
+            .get(&effect_id)
+            .map(|effect| (Arc::clone(&effect.callback), effect.priority))
+        {
             state.queue.push_back(ScheduledEffect {
                 id: effect_id,
                 callback,
@@ -277,8 +298,9 @@ pub fn unregister(&self, effect_id: EffectId) {
 
     /// Flush all pending effects.
     ///
-    /// Executes all queued effects in order. Effects scheduled during
-    /// flush are queued for the next flush cycle.
+    /// Executes all queued effects, highest priority first and in scheduling
+    /// order within a priority. Effects scheduled during flush are queued for
+    /// the next flush cycle.
     pub fn flush(&self) {
         let mut state = self.state.lock();
 
@@ -297,8 +319,12 @@ pub fn flush(&self) {
 
         debug!(batch_size, "Flushing effect batch");
 
-        // Take all pending effects
+        // Take all pending effects; the stable sort keeps scheduling order
+        // within each priority level.
         let mut effects = std::mem::take(&mut state.queue);
+        effects
+            .make_contiguous()
+            .sort_by_key(|effect| std::cmp::Reverse(effect.priority));
         state.queued.clear();
 
         // Release lock while executing effects
@@ -337,6 +363,11 @@ pub fn pending_count(&self) -> usize {
         state.queue.len()
     }
 
+    /// Whether `self` and `other` share the same underlying scheduler state.
+    pub(crate) fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+
     /// Clear all pending effects without executing them.
     pub fn clear(&self) {
         let mut state = self.state.lock();
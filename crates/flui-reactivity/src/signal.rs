@@ -412,6 +412,44 @@ pub fn subscribe_scoped<F>(
         Ok(Subscription { signal: self, id })
     }
 
+    /// Subscribe to changes with a callback that receives the new value.
+    ///
+    /// Meant for imperative integrations outside the hook system (platform
+    /// glue, bridges to other event loops): no [`Owner`](crate::Owner) or
+    /// reactive scope is needed, and reading the value inside the callback is
+    /// not tracked as a dependency. Dropping the returned [`Subscription`]
+    /// unsubscribes.
+    ///
+    /// The subscription only holds the signal's ID, so it never keeps the
+    /// signal alive: once the signal is removed from the runtime the callback
+    /// simply stops firing, and dropping the guard afterwards is a no-op.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let title = Signal::new(String::from("Untitled"));
+    /// let _subscription = title.subscribe_value(move |title| {
+    ///     window.set_title(title);
+    /// })?;
+    /// ```
+    pub fn subscribe_value<F>(
+        &self,
+        callback: F,
+    ) -> Result<Subscription<T>, crate::error::SignalError>
+    where
+        T: Send + 'static,
+        F: Fn(&T) + Send + Sync + 'static,
+    {
+        let signal_id = self.id;
+        let id = self.subscribe(move || {
+            super::SignalRuntime::global().with(signal_id, |value: &T| callback(value));
+        })?;
+        Ok(Subscription {
+            signal: Self::from_id(signal_id),
+            id,
+        })
+    }
+
     /// Unsubscribe from changes using a subscription ID.
     pub fn unsubscribe(&self, id: SubscriptionId) {
         self.runtime().unsubscribe(self.id, id);
@@ -743,4 +781,61 @@ fn test_subscriber_notification_order() {
         assert!(log.lock().contains(&2));
         assert!(log.lock().contains(&3));
     }
+
+    #[test]
+    fn test_subscribe_value_passes_value_and_stops_on_drop() {
+        // No HookContext or Owner: plain imperative usage.
+        let signal = Signal::new(0);
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+
+        let subscription = signal
+            .subscribe_value(move |value: &i32| seen_clone.lock().push(*value))
+            .expect("subscribe failed");
+
+        signal.set(1);
+        signal.update(|n| n + 1);
+        assert_eq!(*seen.lock(), vec![1, 2]);
+
+        drop(subscription);
+        signal.set(3);
+        assert_eq!(*seen.lock(), vec![1, 2]);
+        assert_eq!(
+            crate::SignalRuntime::global().subscriber_count(signal.id()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_subscribe_value_from_another_thread() {
+        let signal = Signal::new(String::new());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+        let _subscription = signal
+            .subscribe_value(move |value: &String| seen_clone.lock().push(value.clone()))
+            .expect("subscribe failed");
+
+        std::thread::spawn(move || signal.set("from worker".to_string()))
+            .join()
+            .expect("worker panicked");
+
+        assert_eq!(*seen.lock(), vec!["from worker".to_string()]);
+    }
+
+    #[test]
+    fn test_subscribe_value_does_not_keep_signal_alive() {
+        let signal = Signal::new(0);
+        let subscription = signal
+            .subscribe_value(|_: &i32| {})
+            .expect("subscribe failed");
+
+        crate::SignalRuntime::global().remove_signal(signal.id());
+        assert_eq!(
+            crate::SignalRuntime::global().subscriber_count(signal.id()),
+            0
+        );
+
+        // Dropping the guard after the signal is gone is a no-op.
+        drop(subscription);
+    }
 }
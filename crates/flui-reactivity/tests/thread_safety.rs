@@ -38,12 +38,12 @@ fn test_concurrent_signal_updates() {
     const UPDATES_PER_THREAD: usize = 100;
 
     let signal = Signal::new(0);
-    let signal_clone = signal.clone();
+    let signal_clone = signal;
 
     // Spawn multiple writer threads
     let handles: Vec<_> = (0..NUM_THREADS)
         .map(|_| {
-            let sig = signal_clone.clone();
+            let sig = signal_clone;
             thread::spawn(move || {
                 for _ in 0..UPDATES_PER_THREAD {
                     sig.update(|v| v + 1);
@@ -72,7 +72,7 @@ fn test_concurrent_subscriptions() {
     // Spawn multiple threads that subscribe
     let handles: Vec<_> = (0..NUM_THREADS)
         .map(|_| {
-            let sig = signal.clone();
+            let sig = signal;
             let cnt = counter.clone();
             thread::spawn(move || {
                 for _ in 0..SUBSCRIPTIONS_PER_THREAD {
@@ -122,7 +122,7 @@ fn test_concurrent_batch_updates() {
     // Spawn multiple threads that perform batch updates
     let handles: Vec<_> = (0..NUM_THREADS)
         .map(|_| {
-            let sig = signal.clone();
+            let sig = signal;
             thread::spawn(move || {
                 batch(|| {
                     sig.update(|v| v + 1);
@@ -165,7 +165,7 @@ fn test_concurrent_nested_batches() {
 
     let handles: Vec<_> = (0..NUM_THREADS)
         .map(|_| {
-            let sig = signal.clone();
+            let sig = signal;
             thread::spawn(move || {
                 batch(|| {
                     sig.update(|v| v + 1);
@@ -201,7 +201,7 @@ fn test_concurrent_subscribe_unsubscribe() {
 
     let handles: Vec<_> = (0..NUM_THREADS)
         .map(|_| {
-            let sig = signal.clone();
+            let sig = signal;
             thread::spawn(move || {
                 for _ in 0..ITERATIONS {
                     // Subscribe
@@ -276,8 +276,8 @@ fn test_parallel_batch_consistency() {
     // Each thread updates both signals in a batch
     let handles: Vec<_> = (0..NUM_THREADS)
         .map(|_| {
-            let sa = sig_a.clone();
-            let sb = sig_b.clone();
+            let sa = sig_a;
+            let sb = sig_b;
             thread::spawn(move || {
                 batch(|| {
                     sa.update(|v| v + 1);
@@ -314,7 +314,7 @@ fn test_thread_local_batching_isolation() {
 
     let handles: Vec<_> = (0..NUM_THREADS)
         .map(|i| {
-            let sig = signal.clone();
+            let sig = signal;
             let bar = barrier.clone();
             thread::spawn(move || {
                 // Start batch on all threads simultaneously
@@ -357,13 +357,13 @@ fn stress_test_high_contention() {
     const OPERATIONS_PER_THREAD: usize = 10000;
 
     let signal = Signal::new(0_u64);
-    let signal_clone = signal.clone();
+    let signal_clone = signal;
 
     let start = std::time::Instant::now();
 
     let handles: Vec<_> = (0..NUM_THREADS)
         .map(|_| {
-            let sig = signal_clone.clone();
+            let sig = signal_clone;
             thread::spawn(move || {
                 for _ in 0..OPERATIONS_PER_THREAD {
                     sig.update(|v| v + 1);
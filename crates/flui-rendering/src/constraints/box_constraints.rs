@@ -417,6 +417,61 @@ pub fn constrain_size_and_attempt_to_preserve_aspect_ratio(&self, size: Size) ->
         )
     }
 
+    /// Returns the largest size with the given `width / height` ratio that
+    /// satisfies these constraints.
+    ///
+    /// Starts from the full max width (or the max height when width is
+    /// unbounded), then walks the same max-then-min adjustments as
+    /// [`constrain_size_and_attempt_to_preserve_aspect_ratio`], so tall and
+    /// wide available spaces both end up with the biggest fitting box. When
+    /// the min/max bounds cannot all hold at that ratio, the constraints win.
+    /// Tight constraints return their only size; with both axes unbounded
+    /// there is no largest size and [`smallest`](Self::smallest) is returned.
+    ///
+    /// Flutter equivalent: `RenderAspectRatio._applyAspectRatio`.
+    ///
+    /// [`constrain_size_and_attempt_to_preserve_aspect_ratio`]: Self::constrain_size_and_attempt_to_preserve_aspect_ratio
+    #[must_use]
+    pub fn constrain_dimensions_respecting_aspect_ratio(&self, aspect: f32) -> Size {
+        debug_assert!(
+            aspect.is_finite() && aspect > 0.0,
+            "aspect ratio must be positive and finite, got {aspect}"
+        );
+
+        if self.is_tight() || (!self.has_bounded_width() && !self.has_bounded_height()) {
+            return self.smallest();
+        }
+
+        let mut width = self.max_width.get();
+        let mut height;
+        if width.is_finite() {
+            height = width / aspect;
+        } else {
+            height = self.max_height.get();
+            width = height * aspect;
+        }
+
+        // Bias toward inflexibility: settle the max bounds before the mins.
+        if width > self.max_width.get() {
+            width = self.max_width.get();
+            height = width / aspect;
+        }
+        if height > self.max_height.get() {
+            height = self.max_height.get();
+            width = height * aspect;
+        }
+        if width < self.min_width.get() {
+            width = self.min_width.get();
+            height = width / aspect;
+        }
+        if height < self.min_height.get() {
+            height = self.min_height.get();
+            width = height * aspect;
+        }
+
+        self.constrain(Size::new(Pixels::new(width), Pixels::new(height)))
+    }
+
     // ============================================================================
     // TRANSFORMATION OPERATIONS
     // ============================================================================
@@ -550,6 +605,24 @@ pub fn enforce(&self, other: &Self) -> Self {
         }
     }
 
+    /// Returns constraints that satisfy
+    /// [`is_normalized`](Constraints::is_normalized): negative minimums are
+    /// raised to zero and a maximum below its minimum is raised to meet it.
+    ///
+    /// Flutter parity: `BoxConstraints.normalize`.
+    #[inline]
+    #[must_use]
+    pub fn normalize(&self) -> Self {
+        let min_width = self.min_width.max(Pixels::ZERO);
+        let min_height = self.min_height.max(Pixels::ZERO);
+        Self {
+            min_width,
+            max_width: self.max_width.max(min_width),
+            min_height,
+            max_height: self.max_height.max(min_height),
+        }
+    }
+
     // ============================================================================
     // BUILDER PATTERN
     // ============================================================================
@@ -1025,4 +1098,52 @@ fn enforce_clamps_self_into_other_bounds() {
         assert_eq!(c.min_width, px(10.0));
         assert_eq!(c.max_width, px(50.0));
     }
+
+    #[test]
+    fn normalize_raises_negative_mins_and_inverted_maxes() {
+        let denormalized = BoxConstraints::new(px(-10.0), px(50.0), px(80.0), px(40.0));
+        assert!(!denormalized.is_normalized());
+
+        let normalized = denormalized.normalize();
+        assert!(normalized.is_normalized());
+        assert_eq!(
+            normalized,
+            BoxConstraints::new(px(0.0), px(50.0), px(80.0), px(80.0))
+        );
+
+        // Already-valid constraints are untouched.
+        let valid = BoxConstraints::new(px(10.0), px(50.0), px(0.0), Pixels::INFINITY);
+        assert_eq!(valid.normalize(), valid);
+    }
+
+    #[test]
+    fn aspect_ratio_constrain_picks_largest_fitting_size() {
+        // Wide space: height is the binding axis for a square.
+        let wide = BoxConstraints::new(px(0.0), px(300.0), px(0.0), px(100.0));
+        assert_eq!(
+            wide.constrain_dimensions_respecting_aspect_ratio(1.0),
+            Size::new(px(100.0), px(100.0))
+        );
+
+        // Tall space: width is the binding axis for 2:1.
+        let tall = BoxConstraints::new(px(0.0), px(120.0), px(0.0), px(400.0));
+        assert_eq!(
+            tall.constrain_dimensions_respecting_aspect_ratio(2.0),
+            Size::new(px(120.0), px(60.0))
+        );
+
+        // Unbounded width falls back to the height.
+        let open_width = BoxConstraints::new(px(0.0), Pixels::INFINITY, px(0.0), px(50.0));
+        assert_eq!(
+            open_width.constrain_dimensions_respecting_aspect_ratio(2.0),
+            Size::new(px(100.0), px(50.0))
+        );
+
+        // Mins that cannot hold at the ratio lose to the constraints.
+        let pinched = BoxConstraints::new(px(50.0), px(200.0), px(0.0), px(5.0));
+        assert_eq!(
+            pinched.constrain_dimensions_respecting_aspect_ratio(10.0),
+            Size::new(px(50.0), px(5.0))
+        );
+    }
 }
@@ -731,7 +731,7 @@ fn origin_is_baked_into_run_transforms() {
         // local (0,0,10,10) lands at (7,3,17,13) in layer space.
         assert_eq!(
             list.bounds(),
-            Rect::from_ltrb(px(7.0), px(3.0), px(17.0), px(13.0)),
+            Some(Rect::from_ltrb(px(7.0), px(3.0), px(17.0), px(13.0))),
             "record-time bounds must include the node-origin translation",
         );
     }
@@ -351,6 +351,29 @@ pub fn global_to_local(
         Some(flui_types::Point::new(x, y))
     }
 
+    /// The bounds of box node `id` in the render root's coordinate space — its
+    /// laid-out `Rect::from_origin_size(ZERO, size)` pushed through every
+    /// ancestor's paint transform.
+    ///
+    /// Flutter's `MatrixUtils.transformRect(box.getTransformTo(null),
+    /// Offset.zero & box.size)`, the idiom tooltips, popovers and the inspector
+    /// use to anchor to a widget. Under a rotation or skew the result is the
+    /// axis-aligned box enclosing the transformed rectangle.
+    ///
+    /// `None` when `id` is not a laid-out box node, or when
+    /// [`transform_to`](Self::transform_to) cannot answer for it.
+    #[must_use]
+    pub fn global_paint_bounds(&self, id: RenderId) -> Option<flui_types::Rect> {
+        let size = self.box_size(id)?;
+        let transform = self.transform_to(id, self.root_id?)?;
+        Some(
+            transform.transform_rect(&flui_types::Rect::from_origin_size(
+                flui_types::Point::ZERO,
+                size,
+            )),
+        )
+    }
+
     /// Returns a mutable reference to the render tree.
     pub fn render_tree_mut(&mut self) -> &mut crate::storage::RenderTree {
         &mut self.render_tree
@@ -393,6 +416,11 @@ pub fn device_pixel_ratio(&self) -> f32 {
         self.device_pixel_ratio
     }
 
+    /// Accessibility text scale factor pushed into every render object.
+    pub fn text_scale_factor(&self) -> f32 {
+        self.text_scale_factor
+    }
+
     /// Removes the subtree rooted at `id` — THE dispose site.
     ///
     /// Removal is where owner-side state dies (the inversion of the
@@ -895,9 +923,17 @@ fn hit_test_sliver_subtree_impl(
     /// change. Non-finite or non-positive values are rejected (kept at
     /// the previous ratio) — a zero or NaN DPR poisons every shaped
     /// glyph and snapped hairline downstream.
+    ///
+    /// A changed ratio marks the root paint-dirty: the DPR scale lives on
+    /// the paint root's transform layer, while layout stays in logical
+    /// pixels and is unaffected.
     pub fn set_device_pixel_ratio(&mut self, dpr: f32) {
         if dpr.is_finite() && dpr > 0.0 {
+            let changed = dpr != self.device_pixel_ratio;
             self.device_pixel_ratio = dpr;
+            if changed && let Some(root_id) = self.root_id {
+                self.add_node_needing_paint(root_id, 0);
+            }
         } else {
             tracing::warn!(
                 dpr,
@@ -908,6 +944,56 @@ pub fn set_device_pixel_ratio(&mut self, dpr: f32) {
         }
     }
 
+    /// Sets the accessibility text scale factor and pushes it into the tree.
+    ///
+    /// Every render object receives the factor through
+    /// [`RenderObject::update_text_scale_factor`](crate::traits::RenderObject::update_text_scale_factor);
+    /// the nodes that report a change are marked layout- and paint-dirty.
+    /// Setting the current value is a no-op. Non-finite or non-positive
+    /// values are rejected like [`Self::set_device_pixel_ratio`].
+    pub fn set_text_scale_factor(&mut self, factor: f32) {
+        if !(factor.is_finite() && factor > 0.0) {
+            tracing::warn!(
+                factor,
+                "set_text_scale_factor: rejecting non-finite / non-positive \
+                 factor; keeping {}",
+                self.text_scale_factor,
+            );
+            return;
+        }
+        if factor == self.text_scale_factor {
+            return;
+        }
+        self.text_scale_factor = factor;
+        let Some(root_id) = self.root_id else {
+            return;
+        };
+        for id in self.render_tree.collect_subtree_ids(root_id) {
+            let changed = self
+                .render_tree
+                .get_mut(id)
+                .is_some_and(|node| node.update_text_scale_factor(factor));
+            if changed {
+                let depth = self
+                    .render_tree
+                    .get(id)
+                    .map_or(0, |node| node.depth() as usize);
+                self.mark_needs_layout(id);
+                self.add_node_needing_paint(id, depth);
+            }
+        }
+    }
+
+    /// Applies a view configuration's ambient metrics — device pixel ratio
+    /// and text scale factor — to this owner.
+    ///
+    /// Root constraints are left alone: the binding sets them from the
+    /// live window size via [`Self::set_root_constraints`].
+    pub fn apply_view_configuration(&mut self, configuration: &crate::view::ViewConfiguration) {
+        self.set_device_pixel_ratio(configuration.device_pixel_ratio());
+        self.set_text_scale_factor(configuration.text_scale_factor());
+    }
+
     // ========================================================================
     // RenderObject Insertion (with dirty tracking)
     // ========================================================================
@@ -1168,8 +1254,14 @@ pub(super) fn attach_inserted_node(&mut self, id: RenderId) {
         let Some(handle) = self.repaint_handle(id) else {
             return;
         };
+        let text_scale_factor = self.text_scale_factor;
         if let Some(node) = self.render_tree.get_mut(id) {
             node.attach(handle);
+            // Late arrivals must see the current ambient scale; the node is
+            // already queued for layout by every insert path.
+            if text_scale_factor != 1.0 {
+                node.update_text_scale_factor(text_scale_factor);
+            }
         }
     }
 
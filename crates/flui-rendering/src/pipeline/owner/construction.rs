@@ -52,6 +52,7 @@ pub fn new_with_capacity(dirty_channel_capacity: usize) -> Self {
             last_follower_offsets: FxHashMap::default(),
             last_hidden_follower_ids: FxHashSet::default(),
             device_pixel_ratio: 1.0,
+            text_scale_factor: 1.0,
             deferred_mutations: crate::pipeline::deferred::DeferredMutations::new(),
             handle,
             dirty_rx,
@@ -113,6 +114,7 @@ pub fn with_callbacks<F, G, H>(
             last_follower_offsets: FxHashMap::default(),
             last_hidden_follower_ids: FxHashSet::default(),
             device_pixel_ratio: 1.0,
+            text_scale_factor: 1.0,
             deferred_mutations: crate::pipeline::deferred::DeferredMutations::new(),
             handle,
             dirty_rx,
@@ -1,4 +1,5 @@
-//! Diagnostics dump — Diagnosticable-backed render-tree introspection.
+//! Diagnostics dump — Diagnosticable-backed render-tree introspection, plus
+//! the on-demand repaint-boundary advisor.
 
 use flui_foundation::{DiagnosticsNode, RenderId};
 
@@ -6,6 +7,14 @@
 
 use super::PipelineOwner;
 
+/// A subtree must have repainted at least this often before it is worth a
+/// boundary; below that, the extra layer costs more than it saves.
+const BOUNDARY_MIN_REPAINTS: u32 = 4;
+
+/// A subtree must repaint at least this many times as often as its parent
+/// to be singled out; otherwise the parent is the better boundary site.
+const BOUNDARY_REPAINT_RATIO: u32 = 2;
+
 // ============================================================================
 // Diagnostics dump (Diagnosticable-backed)
 // ============================================================================
@@ -62,4 +71,70 @@ fn debug_diagnostics_subtree(&self, id: RenderId) -> Option<DiagnosticsNode> {
         }
         Some(diagnostics)
     }
+
+    /// Recommends repaint boundaries from the paint walk's repaint counters.
+    ///
+    /// Every paint pass counts, per node, whether the node was itself marked
+    /// needs-paint. A non-boundary node whose count reaches a minimum and is
+    /// at least twice its parent's is repainting on its own schedule. Unless
+    /// its parent already is a boundary, each of those repaints also repaints
+    /// every ancestor up to the nearest boundary. Each returned node
+    /// describes one such subtree root, with `repaint_count`,
+    /// `parent_repaint_count` and a `suggestion` property.
+    ///
+    /// The counters are always on (one relaxed atomic add per dirty node);
+    /// this walk only runs when called. Pair with
+    /// [`reset_repaint_counts`](Self::reset_repaint_counts) to measure a
+    /// specific interaction.
+    pub fn suggest_repaint_boundaries(&self) -> Vec<DiagnosticsNode> {
+        let mut suggestions = Vec::new();
+        let Some(root_id) = self.root_id else {
+            return suggestions;
+        };
+        let Some(root) = self.render_tree.get(root_id) else {
+            return suggestions;
+        };
+
+        // The root owns the frame's root layer; only its descendants can
+        // gain anything from a boundary.
+        // Stack entries: (node, parent's repaint count, parent is a boundary).
+        let mut stack: Vec<(RenderId, u32, bool)> = Vec::new();
+        let push_children = |stack: &mut Vec<_>, node: &RenderNode| {
+            let (count, boundary) = (node.repaint_count(), node.is_repaint_boundary());
+            stack.extend(
+                node.children()
+                    .iter()
+                    .rev()
+                    .map(|&child| (child, count, boundary)),
+            );
+        };
+        push_children(&mut stack, root);
+        while let Some((id, parent_count, parent_is_boundary)) = stack.pop() {
+            let Some(node) = self.render_tree.get(id) else {
+                continue;
+            };
+            let count = node.repaint_count();
+            if !parent_is_boundary
+                && !node.is_repaint_boundary()
+                && count >= BOUNDARY_MIN_REPAINTS
+                && count >= parent_count.saturating_mul(BOUNDARY_REPAINT_RATIO)
+            {
+                suggestions.push(
+                    node_diagnostics(node)
+                        .property("repaint_count", count)
+                        .property("parent_repaint_count", parent_count)
+                        .property("suggestion", "wrap in a RepaintBoundary"),
+                );
+            }
+            push_children(&mut stack, node);
+        }
+        suggestions
+    }
+
+    /// Zeroes every node's repaint counter.
+    pub fn reset_repaint_counts(&self) {
+        for (_, node) in self.render_tree.iter() {
+            node.reset_repaint_count();
+        }
+    }
 }
@@ -41,6 +41,13 @@ pub fn into_idle(self) -> PipelineOwner<Idle> {
     /// Nodes are sorted by depth (shallow first) so parents are laid out
     /// before their children. This matches Flutter's `flushLayout` behavior.
     ///
+    /// Each queued node is a relayout boundary:
+    /// [`mark_needs_layout`](Self::mark_needs_layout) walks a dirty node up to
+    /// its nearest boundary (see [`RenderBox::is_relayout_boundary`]) and
+    /// queues that, so layout restarts there instead of at the root.
+    ///
+    /// [`RenderBox::is_relayout_boundary`]: crate::traits::RenderBox::is_relayout_boundary
+    ///
     /// # Synchronous Child Layout
     ///
     /// With interior mutability (RwLock on RenderNode), parent's
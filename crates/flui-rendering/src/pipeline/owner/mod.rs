@@ -195,6 +195,11 @@ pub struct PipelineOwner<Phase: PipelinePhase = Idle> {
     /// headless tests.
     device_pixel_ratio: f32,
 
+    /// Accessibility text scale factor of the view, pushed into every
+    /// render object through `RenderObject::update_text_scale_factor`.
+    /// Defaults to 1.0.
+    text_scale_factor: f32,
+
     /// Deferred mutation queue for re-entrant layout.
     ///
     /// During layout, render objects may enqueue child insertions,
@@ -301,6 +306,7 @@ fn rebind_phase<From, To>(from: PipelineOwner<From>) -> PipelineOwner<To>
         last_follower_offsets: from.last_follower_offsets,
         last_hidden_follower_ids: from.last_hidden_follower_ids,
         device_pixel_ratio: from.device_pixel_ratio,
+        text_scale_factor: from.text_scale_factor,
         deferred_mutations: from.deferred_mutations,
         handle: from.handle,
         dirty_rx: from.dirty_rx,
@@ -250,6 +250,13 @@ fn paint_subtree_impl(
             return Ok(());
         }
 
+        // Repaint statistics for `suggest_repaint_boundaries`. Only
+        // self-requested repaints count: the descent repaints every
+        // reachable node, so "painted" alone would say nothing.
+        if dirty_set.contains(&node_id) {
+            render_node.record_repaint();
+        }
+
         // Record the node's fragment. paint_raw sees ONLY the recorder
         // (sans-IO): no tree access, no layer access, no recursion.
         let debug_name = render_node.debug_name();
@@ -439,7 +446,7 @@ fn new(device_pixel_ratio: f32) -> Self {
 
     /// Merges a sealed fragment run into the open picture.
     fn append_run(&mut self, run: DisplayList) {
-        self.open.append(run);
+        self.open.append_owned(run);
     }
 
     /// Flushes the open picture into a `PictureLayer` under the
@@ -1047,15 +1047,16 @@ unsafe fn layout_subtree_borrowed_impl(
         <BoxProtocol as Protocol>::validate_layout_output(debug_name, &constraints, &geometry)?;
         <BoxProtocol as Protocol>::debug_assert_layout_output(&constraints, &geometry);
 
+        let declared_boundary = entry.render_object().is_relayout_boundary_for(&constraints);
+
         entry.state_mut().set_geometry(geometry);
         entry.state_mut().set_constraints(constraints);
 
         // Bootstrap the relayout boundary now that constraints are populated.
         let has_parent = entry.links().parent().is_some();
-        let sized_by_parent = entry.render_object().sized_by_parent();
         <BoxProtocol as Protocol>::bootstrap_relayout_boundary(
             entry.state(),
-            sized_by_parent,
+            declared_boundary,
             has_parent,
         );
 
@@ -1561,14 +1562,15 @@ unsafe fn layout_sliver_subtree_borrowed_impl(
         <SliverProtocol as Protocol>::validate_layout_output(debug_name, &constraints, &geometry)?;
         <SliverProtocol as Protocol>::debug_assert_layout_output(&constraints, &geometry);
 
+        let declared_boundary = entry.render_object().is_relayout_boundary_for(&constraints);
+
         entry.state_mut().set_geometry(geometry);
         entry.state_mut().set_constraints(constraints);
 
         let has_parent = entry.links().parent().is_some();
-        let sized_by_parent = entry.render_object().sized_by_parent();
         <SliverProtocol as Protocol>::bootstrap_relayout_boundary(
             entry.state(),
-            sized_by_parent,
+            declared_boundary,
             has_parent,
         );
 
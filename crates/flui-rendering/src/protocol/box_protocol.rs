@@ -117,16 +117,16 @@ fn name() -> &'static str {
     /// non-root non-sized-by-parent nodes correctly default to non-boundary,
     /// preserving propagation.
     ///
-    /// `sized_by_parent = false`: full Flutter parity for both parameters
-    /// requires per-render-object trait methods that report their layout
-    /// dependency shape; deferred to Core.2 alongside the intrinsic-
-    /// dimension protocol.
+    /// `declared_boundary` is the render object's own verdict from
+    /// [`RenderBox::is_relayout_boundary`](crate::traits::RenderBox::is_relayout_boundary)
+    /// (`sized_by_parent` or tight constraints by default) and fills the
+    /// formula's `sized_by_parent` slot.
     fn bootstrap_relayout_boundary(
         state: &crate::storage::RenderState<Self>,
-        sized_by_parent: bool,
+        declared_boundary: bool,
         has_parent: bool,
     ) {
-        state.compute_relayout_boundary(true, sized_by_parent, has_parent);
+        state.compute_relayout_boundary(true, declared_boundary, has_parent);
     }
 
     /// Flutter's `debugAssertDoesMeetConstraints` (`box.dart`): a node's own
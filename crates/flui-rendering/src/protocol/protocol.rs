@@ -111,9 +111,9 @@ fn validate_constraints(constraints: &<Self::Layout as LayoutCapability>::Constr
     /// Default implementation is a no-op (slivers don't use relayout-boundary
     /// semantics today; that's deferred to Core.2). The `BoxProtocol`
     /// override calls [`RenderState::<BoxProtocol>::compute_relayout_boundary`]
-    /// with `parent_uses_size = false` and `sized_by_parent = false` —
-    /// Flutter parity for those parameters lands later in Core.2 alongside
-    /// the intrinsic-dimension protocol.
+    /// with `parent_uses_size = true`, feeding `declared_boundary` (the render
+    /// object's [`RenderObject::is_relayout_boundary_for`] verdict) into the
+    /// `sized_by_parent` slot.
     ///
     /// This bootstrap exists so that
     /// [`PipelineOwner::mark_needs_layout`] has a meaningful
@@ -122,15 +122,16 @@ fn validate_constraints(constraints: &<Self::Layout as LayoutCapability>::Constr
     /// runs to root — the correct fallback (root is the implicit boundary).
     ///
     /// [`RenderState::<BoxProtocol>::compute_relayout_boundary`]: crate::storage::RenderState::compute_relayout_boundary
+    /// [`RenderObject::is_relayout_boundary_for`]: crate::traits::RenderObject::is_relayout_boundary_for
     /// [`PipelineOwner::mark_needs_layout`]: crate::pipeline::PipelineOwner::mark_needs_layout
     fn bootstrap_relayout_boundary(
         state: &crate::storage::RenderState<Self>,
-        sized_by_parent: bool,
+        declared_boundary: bool,
         has_parent: bool,
     ) where
         Self: Sized,
     {
-        let _ = (state, sized_by_parent, has_parent);
+        let _ = (state, declared_boundary, has_parent);
     }
 
     /// Debug-only check that a freshly computed layout result is well-formed:
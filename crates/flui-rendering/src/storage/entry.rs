@@ -389,21 +389,22 @@ pub fn layout_leaf_only(
             &geometry,
         )?;
         <P as crate::protocol::Protocol>::debug_assert_layout_output(&constraints, &geometry);
+        let declared_boundary = self.render_object.is_relayout_boundary_for(&constraints);
         self.state.set_geometry(geometry.clone());
         self.state.set_constraints(constraints);
 
         // Bootstrap the per-instance `IS_RELAYOUT_BOUNDARY` flag now
         // that constraints are populated. For `BoxProtocol`, dispatches
         // to `compute_relayout_boundary` (Flutter `!parent_uses_size ||
-        // sized_by_parent || constraints.is_tight() || !has_parent`);
+        // sized_by_parent || constraints.is_tight() || !has_parent`, plus
+        // the render object's own `is_relayout_boundary_for` verdict);
         // for `SliverProtocol`, no-op (relayout-boundary semantics not
         // used). Before this bootstrap runs, `PipelineOwner::mark_needs_layout`
         // treats every node as non-boundary and walks to root.
         let has_parent = self.links.parent().is_some();
-        let sized_by_parent = self.render_object.sized_by_parent();
         <P as crate::protocol::Protocol>::bootstrap_relayout_boundary(
             &self.state,
-            sized_by_parent,
+            declared_boundary,
             has_parent,
         );
 
@@ -128,6 +128,17 @@ pub fn reassemble(&mut self) {
         }
     }
 
+    /// Pushes the view's ambient text scale factor into the render object.
+    ///
+    /// Dispatches to [`RenderObject::update_text_scale_factor`]; returns
+    /// whether the node must relayout.
+    pub fn update_text_scale_factor(&mut self, factor: f32) -> bool {
+        match self {
+            Self::Box(entry) => entry.render_object_mut().update_text_scale_factor(factor),
+            Self::Sliver(entry) => entry.render_object_mut().update_text_scale_factor(factor),
+        }
+    }
+
     /// Tree-lifecycle hook (ADR-0013): hands the freshly-inserted render
     /// object a self-dirty handle bound to its own node.
     ///
@@ -843,6 +854,35 @@ pub fn clear_needs_paint(&self) {
         }
     }
 
+    /// Counts one self-requested repaint (see [`RenderState::record_repaint`]).
+    ///
+    /// [`RenderState::record_repaint`]: crate::storage::RenderState::record_repaint
+    #[inline]
+    pub fn record_repaint(&self) {
+        match self {
+            Self::Box(entry) => entry.state().record_repaint(),
+            Self::Sliver(entry) => entry.state().record_repaint(),
+        }
+    }
+
+    /// Paint passes that repainted this node because it was itself dirty.
+    #[inline]
+    pub fn repaint_count(&self) -> u32 {
+        match self {
+            Self::Box(entry) => entry.state().repaint_count(),
+            Self::Sliver(entry) => entry.state().repaint_count(),
+        }
+    }
+
+    /// Resets the repaint counter to zero.
+    #[inline]
+    pub fn reset_repaint_count(&self) {
+        match self {
+            Self::Box(entry) => entry.state().reset_repaint_count(),
+            Self::Sliver(entry) => entry.state().reset_repaint_count(),
+        }
+    }
+
     /// Clears the needs_layout flag.
     #[inline]
     pub fn clear_needs_layout(&self) {
@@ -88,6 +88,7 @@
 //! ```
 
 use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 // NOTE: `OnceCell` was previously imported here for `geometry`/`constraints`
 // write-once semantics; both fields moved to `Option<T>` so the import is
@@ -219,6 +220,11 @@ pub struct RenderState<P: Protocol> {
     /// The parent downcasts via `downcast_ref::<T>()` when reading.
     parent_data: Option<Box<dyn crate::parent_data::ParentData>>,
 
+    /// Paint passes that repainted this node because it was itself marked
+    /// needs-paint. Feeds
+    /// [`PipelineOwner::suggest_repaint_boundaries`](crate::pipeline::PipelineOwner::suggest_repaint_boundaries).
+    repaint_count: AtomicU32,
+
     /// Protocol marker (zero-sized).
     _phantom: PhantomData<P>,
 }
@@ -251,6 +257,7 @@ pub fn new() -> Self {
             offset: AtomicOffset::new(flui_types::Offset::ZERO),
             layout_cache: P::LayoutCache::default(),
             parent_data: None,
+            repaint_count: AtomicU32::new(0),
             _phantom: PhantomData,
         }
     }
@@ -275,6 +282,7 @@ pub fn with_flags(flags: RenderFlags) -> Self {
             offset: AtomicOffset::new(flui_types::Offset::ZERO),
             layout_cache: P::LayoutCache::default(),
             parent_data: None,
+            repaint_count: AtomicU32::new(0),
             _phantom: PhantomData,
         }
     }
@@ -360,6 +368,30 @@ pub fn clear_layout_cache(&mut self) -> bool {
     }
 }
 
+// ============================================================================
+// REPAINT STATISTICS
+// ============================================================================
+
+impl<P: Protocol> RenderState<P> {
+    /// Counts one self-requested repaint. Called by the paint walk.
+    #[inline]
+    pub fn record_repaint(&self) {
+        self.repaint_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Paint passes that repainted this node because it was itself dirty.
+    #[inline]
+    pub fn repaint_count(&self) -> u32 {
+        self.repaint_count.load(Ordering::Relaxed)
+    }
+
+    /// Resets [`repaint_count`](Self::repaint_count) to zero.
+    #[inline]
+    pub fn reset_repaint_count(&self) {
+        self.repaint_count.store(0, Ordering::Relaxed);
+    }
+}
+
 impl<P: Protocol> Default for RenderState<P> {
     fn default() -> Self {
         Self::new()
@@ -380,6 +412,7 @@ fn clone(&self) -> Self {
             // Memoized results are node-local; a cloned state starts cold.
             layout_cache: P::LayoutCache::default(),
             parent_data: self.parent_data.clone(),
+            repaint_count: AtomicU32::new(self.repaint_count()),
             _phantom: PhantomData,
         }
     }
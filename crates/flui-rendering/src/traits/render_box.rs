@@ -377,6 +377,24 @@ fn is_repaint_boundary(&self) -> bool {
         false
     }
 
+    /// Whether this node is a relayout boundary when laid out under
+    /// `constraints`.
+    ///
+    /// A relayout boundary stops [`PipelineOwner::mark_needs_layout`] from
+    /// walking further up: a dirty descendant re-lays out only the subtree
+    /// rooted here, and neither this node's siblings nor its ancestors run
+    /// layout. The pipeline evaluates this after every layout of the node.
+    ///
+    /// Default: `sized_by_parent() || constraints.is_tight()` — Flutter's
+    /// rule (`object.dart` `RenderObject.layout`). The pipeline always
+    /// treats tight, `sized_by_parent` and root nodes as boundaries, so an
+    /// override can promote a node to a boundary but never demote one.
+    ///
+    /// [`PipelineOwner::mark_needs_layout`]: crate::pipeline::PipelineOwner::mark_needs_layout
+    fn is_relayout_boundary(&self, constraints: &BoxConstraints) -> bool {
+        self.sized_by_parent() || crate::constraints::Constraints::is_tight(constraints)
+    }
+
     /// Whether this node always needs its own compositing layer.
     ///
     /// Override and return `true` for nodes that apply an effect (clip,
@@ -487,6 +505,16 @@ fn excludes_semantics_subtree(&self) -> bool {
     /// [`RenderObject::reassemble`].
     fn reassemble(&mut self) {}
 
+    /// Receives the view's ambient text scale factor; returns `true` when
+    /// layout must rerun.
+    ///
+    /// Default: `false`. See
+    /// [`RenderObject::update_text_scale_factor`].
+    fn update_text_scale_factor(&mut self, factor: f32) -> bool {
+        let _ = factor;
+        false
+    }
+
     // ========================================================================
     // Tree Lifecycle (ADR-0013)
     // ========================================================================
@@ -713,6 +741,10 @@ fn is_repaint_boundary(&self) -> bool {
         <T as RenderBox>::is_repaint_boundary(self)
     }
 
+    fn is_relayout_boundary_for(&self, constraints: &BoxConstraints) -> bool {
+        <T as RenderBox>::is_relayout_boundary(self, constraints)
+    }
+
     fn always_needs_compositing(&self) -> bool {
         <T as RenderBox>::always_needs_compositing(self)
     }
@@ -777,6 +809,10 @@ fn reassemble(&mut self) {
         <T as RenderBox>::reassemble(self);
     }
 
+    fn update_text_scale_factor(&mut self, factor: f32) -> bool {
+        <T as RenderBox>::update_text_scale_factor(self, factor)
+    }
+
     fn attach(&mut self, handle: crate::pipeline::RepaintHandle) {
         <T as RenderBox>::attach(self, handle);
     }
@@ -122,6 +122,8 @@ pub const fn new(add_self: bool, blocks_below: bool) -> Self {
 ///   `hit_test_transform` — paint-effect hooks (default `None`/`false`)
 /// - `describe_semantics_configuration` — accessibility hook (default no-op)
 /// - `reassemble` — hot-reload hook (default no-op; see note below)
+/// - `update_text_scale_factor` — ambient text-scale hook (default
+///   `false`; see *Ambient view metrics note* below)
 /// - `attach`/`detach` — tree-lifecycle hook (default no-op; see
 ///   *Tree-lifecycle note* below)
 ///
@@ -156,6 +158,16 @@ pub const fn new(add_self: bool, blocks_below: bool) -> Self {
 /// [`PipelineOwner::remove_render_object`](crate::pipeline::PipelineOwner::remove_render_object)),
 /// never mid-layout/paint/hit-test.
 ///
+/// # Ambient view metrics note
+///
+/// The view's accessibility text scale (`ViewConfiguration::text_scale_factor`)
+/// lives on the [`PipelineOwner`](crate::pipeline::PipelineOwner), not in
+/// the per-node layout contexts. The owner pushes it into each node through
+/// `update_text_scale_factor` — on insert, and again for the whole tree
+/// whenever it changes — and marks every node that reports a change
+/// layout- and paint-dirty. A text-bearing object caches the factor and
+/// reads it from `perform_layout`.
+///
 /// # Storage Integration
 ///
 /// Render objects are wrapped in `RenderEntry<P>` which adds:
@@ -401,6 +413,20 @@ fn is_relayout_boundary(&self) -> bool {
         false
     }
 
+    /// Returns whether this is a relayout boundary under `constraints`.
+    ///
+    /// Called by the pipeline after every layout of the node; the result is
+    /// combined with the protocol's own boundary rule. `RenderBox` types
+    /// answer through [`RenderBox::is_relayout_boundary`].
+    ///
+    /// Default: `is_relayout_boundary() || sized_by_parent()`
+    ///
+    /// [`RenderBox::is_relayout_boundary`]: crate::traits::RenderBox::is_relayout_boundary
+    fn is_relayout_boundary_for(&self, constraints: &ProtocolConstraints<P>) -> bool {
+        let _ = constraints;
+        self.is_relayout_boundary() || self.sized_by_parent()
+    }
+
     /// Returns whether size depends only on constraints (not on children).
     ///
     /// When true, `perform_resize()` is called instead of `perform_layout()`
@@ -618,6 +644,17 @@ fn excludes_semantics_subtree(&self) -> bool {
     /// reason this is a documented FLUI divergence from Flutter semantics.
     fn reassemble(&mut self) {}
 
+    /// Receives the view's ambient text scale factor.
+    ///
+    /// Returns `true` when the factor changed something layout reads, so
+    /// the owner must relayout and repaint this node. Called by
+    /// [`PipelineOwner::set_text_scale_factor`](crate::pipeline::PipelineOwner::set_text_scale_factor)
+    /// and on insert. Default: ignores the factor and returns `false`.
+    fn update_text_scale_factor(&mut self, factor: f32) -> bool {
+        let _ = factor;
+        false
+    }
+
     // ========================================================================
     // Tree Lifecycle (ADR-0013)
     // ========================================================================
@@ -830,6 +867,12 @@ fn default_reassemble_is_a_no_op() {
         leaf.reassemble();
     }
 
+    #[test]
+    fn default_text_scale_hook_reports_no_change() {
+        let mut leaf = MinimalLeaf;
+        assert!(!leaf.update_text_scale_factor(2.0));
+    }
+
     #[test]
     fn default_child_count_is_zero() {
         let leaf = MinimalLeaf;
@@ -464,6 +464,16 @@ fn excludes_semantics_subtree(&self) -> bool {
     /// [`RenderObject::reassemble`].
     fn reassemble(&mut self) {}
 
+    /// Receives the view's ambient text scale factor; returns `true` when
+    /// layout must rerun.
+    ///
+    /// Default: `false`. See
+    /// [`RenderObject::update_text_scale_factor`].
+    fn update_text_scale_factor(&mut self, factor: f32) -> bool {
+        let _ = factor;
+        false
+    }
+
     // ========================================================================
     // Tree Lifecycle (ADR-0013)
     // ========================================================================
@@ -619,6 +629,10 @@ fn reassemble(&mut self) {
         <T as RenderSliver>::reassemble(self);
     }
 
+    fn update_text_scale_factor(&mut self, factor: f32) -> bool {
+        <T as RenderSliver>::update_text_scale_factor(self, factor)
+    }
+
     fn attach(&mut self, handle: crate::pipeline::RepaintHandle) {
         <T as RenderSliver>::attach(self, handle);
     }
@@ -6,8 +6,8 @@
 
 /// The layout constraints for the root render object.
 ///
-/// This configuration defines the size constraints and device pixel ratio
-/// for the root of the render tree.
+/// This configuration defines the size constraints, device pixel ratio and
+/// accessibility text scale factor for the root of the render tree.
 ///
 /// # Flutter Equivalence
 ///
@@ -30,6 +30,12 @@ pub struct ViewConfiguration {
     ///
     /// This is the number of physical pixels per logical pixel.
     device_pixel_ratio: f32,
+
+    /// The accessibility text scale factor.
+    ///
+    /// Multiplies every font size laid out under this view; 1.0 leaves text
+    /// at its specified size.
+    text_scale_factor: f32,
 }
 
 impl Default for ViewConfiguration {
@@ -38,6 +44,7 @@ fn default() -> Self {
             physical_constraints: BoxConstraints::tight(Size::ZERO),
             logical_constraints: BoxConstraints::tight(Size::ZERO),
             device_pixel_ratio: 1.0,
+            text_scale_factor: 1.0,
         }
     }
 }
@@ -45,8 +52,8 @@ fn default() -> Self {
 impl ViewConfiguration {
     /// Creates a new view configuration.
     ///
-    /// By default, the view has constraints with all dimensions set to zero
-    /// and a device pixel ratio of 1.0.
+    /// The text scale factor starts at 1.0; see
+    /// [`with_text_scale_factor`](Self::with_text_scale_factor).
     pub fn new(
         physical_constraints: BoxConstraints,
         logical_constraints: BoxConstraints,
@@ -56,6 +63,7 @@ pub fn new(
             physical_constraints,
             logical_constraints,
             device_pixel_ratio,
+            text_scale_factor: 1.0,
         }
     }
 
@@ -76,6 +84,7 @@ pub fn from_size(physical_size: Size, device_pixel_ratio: f32) -> Self {
             physical_constraints,
             logical_constraints,
             device_pixel_ratio,
+            text_scale_factor: 1.0,
         }
     }
 
@@ -105,9 +114,17 @@ pub fn flexible(
             physical_constraints,
             logical_constraints,
             device_pixel_ratio,
+            text_scale_factor: 1.0,
         }
     }
 
+    /// Sets the accessibility text scale factor (builder form).
+    #[must_use]
+    pub fn with_text_scale_factor(mut self, text_scale_factor: f32) -> Self {
+        self.text_scale_factor = text_scale_factor;
+        self
+    }
+
     /// Returns the constraints in logical pixels.
     ///
     /// These constraints are passed to the child of the root render object.
@@ -133,6 +150,12 @@ pub fn device_pixel_ratio(&self) -> f32 {
         self.device_pixel_ratio
     }
 
+    /// Returns the accessibility text scale factor.
+    #[inline]
+    pub fn text_scale_factor(&self) -> f32 {
+        self.text_scale_factor
+    }
+
     /// Creates a transformation matrix that applies the device pixel ratio.
     ///
     /// The matrix translates points from the local coordinate system of the
@@ -148,6 +171,17 @@ pub fn should_update_matrix(&self, old_configuration: &ViewConfiguration) -> boo
         self.device_pixel_ratio != old_configuration.device_pixel_ratio
     }
 
+    /// Returns whether the tree must be laid out again when this
+    /// configuration replaces `old_configuration`.
+    ///
+    /// Layout runs in logical pixels, so a device pixel ratio change alone
+    /// only repaints; new logical constraints or a new text scale factor
+    /// relayout.
+    pub fn should_relayout(&self, old_configuration: &ViewConfiguration) -> bool {
+        self.logical_constraints != old_configuration.logical_constraints
+            || self.text_scale_factor != old_configuration.text_scale_factor
+    }
+
     /// Transforms the provided size in logical pixels to physical pixels.
     ///
     /// The result is constrained to the physical constraints.
@@ -236,6 +270,31 @@ fn test_view_configuration_should_update_matrix() {
         assert!(!config1.should_update_matrix(&config3)); // Same DPR
     }
 
+    #[test]
+    fn test_view_configuration_text_scale_factor() {
+        let config = ViewConfiguration::from_size(Size::new(px(800.0), px(600.0)), 1.0);
+        assert_eq!(config.text_scale_factor(), 1.0);
+
+        let scaled = config.clone().with_text_scale_factor(1.5);
+        assert_eq!(scaled.text_scale_factor(), 1.5);
+        assert!(scaled.should_relayout(&config));
+        assert!(!scaled.should_update_matrix(&config));
+    }
+
+    #[test]
+    fn test_view_configuration_should_relayout() {
+        let config1 = ViewConfiguration::flexible(Size::ZERO, Size::new(px(800.0), px(600.0)), 1.0);
+        let config2 = ViewConfiguration::new(
+            config1.physical_constraints(),
+            config1.logical_constraints(),
+            2.0,
+        );
+        let config3 = ViewConfiguration::from_size(Size::new(px(1600.0), px(1200.0)), 1.0);
+
+        assert!(!config2.should_relayout(&config1)); // DPR only: repaint
+        assert!(config3.should_relayout(&config1)); // New logical constraints
+    }
+
     #[test]
     fn test_view_configuration_to_physical_size() {
         let config = ViewConfiguration::from_size(Size::new(px(1920.0), px(1080.0)), 2.0);
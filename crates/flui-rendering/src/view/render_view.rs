@@ -148,16 +148,55 @@ pub fn has_configuration(&self) -> bool {
     /// the new configuration is installed *before* the root layer is
     /// rebuilt, since rebuilding it reads the new configuration to compute
     /// the updated matrix.
+    ///
+    /// When a pipeline owner is attached, the configuration's device pixel
+    /// ratio and text scale factor are pushed into it
+    /// ([`PipelineOwner::apply_view_configuration`]), which schedules the
+    /// repaint (DPR) or relayout (text scale) the change needs. That takes
+    /// the owner's write lock: a caller already holding it must use
+    /// [`set_configuration_with_owner`](Self::set_configuration_with_owner).
     pub fn set_configuration(&mut self, configuration: ViewConfiguration) {
+        match self.owner.as_ref().and_then(Weak::upgrade) {
+            Some(owner) => self.set_configuration_with_owner(configuration, &mut owner.write()),
+            None => {
+                self.update_configuration(configuration);
+            }
+        }
+    }
+
+    /// Sets the view configuration, pushing it into `owner` — the caller's
+    /// already-locked pipeline owner — instead of locking the attached one.
+    pub fn set_configuration_with_owner(
+        &mut self,
+        configuration: ViewConfiguration,
+        owner: &mut PipelineOwner,
+    ) {
+        if self.update_configuration(configuration) {
+            // Invalidation is the owner's job: the root's NEEDS_LAYOUT /
+            // NEEDS_PAINT bits live on `RenderState<P>::flags` in the owner's
+            // tree, not on this struct.
+            owner.apply_view_configuration(self.configuration());
+        }
+    }
+
+    /// Installs the view configuration and rebuilds the root layer, without
+    /// touching any pipeline owner. Returns whether the configuration
+    /// changed.
+    ///
+    /// For a view that lives inside the owner's own render tree, where the
+    /// owner cannot be borrowed alongside it: the caller pushes the new
+    /// configuration with [`PipelineOwner::apply_view_configuration`] once
+    /// it has released the view.
+    pub fn update_configuration(&mut self, configuration: ViewConfiguration) -> bool {
         if self.configuration.as_ref() == Some(&configuration) {
-            return;
+            return false;
         }
 
         let old_configuration = self.configuration.replace(configuration);
 
         if self.root_transform.is_none() {
             // prepare_initial_frame has not been called yet — nothing more to do.
-            return;
+            return true;
         }
 
         let should_replace_layer = match &old_configuration {
@@ -167,11 +206,7 @@ pub fn set_configuration(&mut self, configuration: ViewConfiguration) {
         if should_replace_layer {
             self.replace_root_layer_internal();
         }
-        // A `self.needs_layout = true` write used to happen here, but it had
-        // zero readers, so it was removed. When RenderView's full lifecycle
-        // plumbing lands, the equivalent invalidation should flip on
-        // `RenderState<P>::flags::NEEDS_LAYOUT` (the atomic version
-        // in `crates/flui-rendering/src/storage/flags.rs`).
+        true
     }
 
     // ========================================================================
@@ -580,6 +615,7 @@ fn debug_fill_properties(&self, properties: &mut DiagnosticsBuilder) {
         properties.add("size", format!("{:?}", self.size));
         if let Some(ref config) = self.configuration {
             properties.add("devicePixelRatio", config.device_pixel_ratio());
+            properties.add("textScaleFactor", config.text_scale_factor());
         }
     }
 }
@@ -803,6 +839,80 @@ fn perform_layout_sizes_to_the_smallest_logical_constraint() {
         assert_eq!(view.size(), config.logical_constraints().smallest());
     }
 
+    #[test]
+    fn doubling_device_pixel_ratio_doubles_paint_transform_but_not_logical_size() {
+        let logical = BoxConstraints::tight(Size::new(px(400.0), px(300.0)));
+        let config = ViewConfiguration::new(
+            BoxConstraints::tight(Size::new(px(400.0), px(300.0))),
+            logical,
+            1.0,
+        );
+        let mut view = RenderView::with_configuration(config);
+        view.prepare_initial_frame_internal();
+        view.perform_layout();
+        let logical_size = view.size();
+        let mut before = Matrix4::identity();
+        view.apply_paint_transform(&mut before);
+
+        view.set_configuration(ViewConfiguration::new(
+            BoxConstraints::tight(Size::new(px(800.0), px(600.0))),
+            logical,
+            2.0,
+        ));
+        view.perform_layout();
+        let mut after = Matrix4::identity();
+        view.apply_paint_transform(&mut after);
+
+        assert_eq!(view.size(), logical_size);
+        assert!((after[0] - 2.0 * before[0]).abs() < 1e-6);
+        assert!((after[5] - 2.0 * before[5]).abs() < 1e-6);
+        assert_eq!(view.physical_paint_bounds().width(), px(800.0));
+    }
+
+    #[test]
+    fn set_configuration_pushes_view_metrics_into_the_attached_owner() {
+        use std::sync::Arc;
+
+        use parking_lot::RwLock;
+
+        use crate::pipeline::PipelineOwner;
+
+        let owner = Arc::new(RwLock::new(PipelineOwner::new()));
+        let mut view = RenderView::new();
+        view.attach(&owner);
+
+        view.set_configuration(
+            ViewConfiguration::from_size(Size::new(px(800.0), px(600.0)), 2.0)
+                .with_text_scale_factor(1.5),
+        );
+
+        assert_eq!(owner.read().device_pixel_ratio(), 2.0);
+        assert_eq!(owner.read().text_scale_factor(), 1.5);
+    }
+
+    #[test]
+    fn set_configuration_with_owner_uses_the_held_guard() {
+        use std::sync::Arc;
+
+        use parking_lot::RwLock;
+
+        use crate::pipeline::PipelineOwner;
+
+        let owner = Arc::new(RwLock::new(PipelineOwner::new()));
+        let mut view = RenderView::new();
+        view.attach(&owner);
+
+        // Locking the attached owner again here would deadlock.
+        let mut guard = owner.write();
+        view.set_configuration_with_owner(
+            ViewConfiguration::from_size(Size::new(px(800.0), px(600.0)), 3.0),
+            &mut guard,
+        );
+        drop(guard);
+
+        assert_eq!(owner.read().device_pixel_ratio(), 3.0);
+    }
+
     #[test]
     fn physical_paint_bounds_scales_logical_size_by_device_pixel_ratio() {
         let config = ViewConfiguration::from_size(Size::new(px(800.0), px(600.0)), 2.0);
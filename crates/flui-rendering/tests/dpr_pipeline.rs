@@ -8,7 +8,6 @@
 
 use flui_layer::Layer;
 use flui_objects::RenderColoredBox;
-use flui_painting::DisplayListCore;
 use flui_rendering::{constraints::BoxConstraints, pipeline::PipelineOwner};
 use flui_types::{Point, Rect, Size, geometry::px};
 
@@ -62,7 +61,10 @@ fn paint_root_carries_the_dpr_scale_and_ops_stay_logical() {
     };
     assert_eq!(
         picture.picture().bounds(),
-        Rect::from_origin_size(Point::ZERO, Size::new(px(100.0), px(100.0))),
+        Some(Rect::from_origin_size(
+            Point::ZERO,
+            Size::new(px(100.0), px(100.0))
+        )),
         "draw commands must remain in logical pixels",
     );
 }
@@ -88,3 +90,96 @@ fn dpr_one_keeps_the_offset_root() {
         "DPR 1.0 must not pay for an identity transform layer",
     );
 }
+
+fn run_frame(
+    owner: PipelineOwner<flui_rendering::pipeline::phase::Idle>,
+) -> (
+    flui_layer::LayerTree,
+    PipelineOwner<flui_rendering::pipeline::phase::Idle>,
+) {
+    let mut owner = owner.into_layout();
+    owner.run_layout().expect("layout");
+    let mut owner = owner.into_compositing();
+    owner.run_compositing().expect("compositing");
+    let mut owner = owner.into_paint();
+    owner.run_paint().expect("paint");
+    let tree = owner.take_layer_tree().expect("layer tree");
+    (tree, owner.into_idle())
+}
+
+fn root_scale(tree: &flui_layer::LayerTree) -> f32 {
+    let root_node = tree.get(tree.root().expect("root")).expect("node");
+    let Layer::Transform(transform) = root_node.layer() else {
+        panic!("expected a DPR transform root, got {:?}", root_node.layer());
+    };
+    transform.transform()[0]
+}
+
+#[test]
+fn doubling_the_dpr_doubles_the_root_scale_and_keeps_logical_sizes() {
+    let mut owner = PipelineOwner::new();
+    owner.set_device_pixel_ratio(1.5);
+    let root = owner.insert(Box::new(RenderColoredBox::red(40.0, 40.0)) as BoxedRenderObject);
+    owner.set_root_id(Some(root));
+    owner.set_root_constraints(Some(BoxConstraints::tight(Size::new(px(100.0), px(100.0)))));
+
+    let (tree, mut owner) = run_frame(owner);
+    let scale_before = root_scale(&tree);
+    let size_before = owner.box_size(root).expect("laid out");
+
+    // A DPR change alone must schedule a frame (repaint) — no relayout.
+    owner.set_device_pixel_ratio(3.0);
+    let (tree, owner) = run_frame(owner);
+
+    assert!(
+        (root_scale(&tree) - 2.0 * scale_before).abs() < f32::EPSILON,
+        "doubling the DPR must double the physical paint scale",
+    );
+    assert_eq!(
+        owner.box_size(root),
+        Some(size_before),
+        "layout stays in logical pixels",
+    );
+}
+
+#[test]
+fn text_scale_change_relays_out_paragraphs_under_the_root() {
+    use flui_objects::{RenderPadding, RenderParagraph};
+    use flui_types::typography::{TextDirection, TextSpan};
+
+    let mut owner = PipelineOwner::new();
+    let root = owner.insert(Box::new(RenderPadding::all(4.0)) as BoxedRenderObject);
+    let text = owner
+        .insert_child_render_object(
+            root,
+            Box::new(RenderParagraph::new(
+                TextSpan::new("scaled"),
+                TextDirection::Ltr,
+            )) as BoxedRenderObject,
+        )
+        .expect("text child");
+    owner.set_root_id(Some(root));
+    owner.set_root_constraints(Some(BoxConstraints::new(
+        px(0.0),
+        px(1000.0),
+        px(0.0),
+        px(1000.0),
+    )));
+
+    let (_, mut owner) = run_frame(owner);
+    let before = owner.box_size(text).expect("text laid out");
+
+    owner.set_text_scale_factor(2.0);
+    let (_, owner) = run_frame(owner);
+    let after = owner.box_size(text).expect("text relaid out");
+
+    assert!(
+        after.width > before.width && after.height > before.height,
+        "text scale 2.0 must grow the paragraph ({before:?} -> {after:?})",
+    );
+    assert_eq!(
+        owner.box_size(root).expect("root laid out"),
+        Size::new(after.width + px(8.0), after.height + px(8.0)),
+        "the relayout must propagate to the paragraph's ancestors",
+    );
+}
@@ -46,8 +46,12 @@
 mod paint_fragment_snapshot;
 #[path = "pipeline_scenarios.rs"]
 mod pipeline_scenarios;
+#[path = "relayout_boundary.rs"]
+mod relayout_boundary;
 #[path = "render_viewport.rs"]
 mod render_viewport;
+#[path = "repaint_boundary_advisor.rs"]
+mod repaint_boundary_advisor;
 #[path = "repaint_handle.rs"]
 mod repaint_handle;
 #[path = "root_resize_repaint.rs"]
@@ -146,7 +146,7 @@ fn inline_siblings_merge_into_one_origin_baked_picture() {
     );
     assert_eq!(
         picture.bounds(),
-        Rect::from_ltrb(px(0.0), px(0.0), px(90.0), px(40.0)),
+        Some(Rect::from_ltrb(px(0.0), px(0.0), px(90.0), px(40.0))),
         "record-time bounds must reflect the committed child offsets: \
          child 0 at (0,0)-(40,40), child 1 at (50,0)-(90,40)",
     );
@@ -189,7 +189,10 @@ fn repaint_boundary_child_splits_into_rebased_offset_layer() {
     let picture = first_picture(&tree);
     assert_eq!(
         picture.bounds(),
-        Rect::from_origin_size(Point::ZERO, Size::new(px(40.0), px(40.0))),
+        Some(Rect::from_origin_size(
+            Point::ZERO,
+            Size::new(px(40.0), px(40.0))
+        )),
         "boundary-subtree coordinates must be rebased to Offset::ZERO",
     );
 }
@@ -365,7 +368,10 @@ fn box_host_splices_sliver_leaf_paint_into_picture() {
     );
     assert_eq!(
         first_picture(&tree).bounds(),
-        Rect::from_origin_size(Point::ZERO, Size::new(px(100.0), px(80.0))),
+        Some(Rect::from_origin_size(
+            Point::ZERO,
+            Size::new(px(100.0), px(80.0))
+        )),
     );
 }
 
@@ -403,7 +409,7 @@ fn box_host_splices_sliver_to_box_adapter_child_at_paint_offset() {
 
     assert_eq!(
         first_picture(&tree).bounds(),
-        Rect::from_ltrb(px(0.0), px(-40.0), px(100.0), px(40.0)),
+        Some(Rect::from_ltrb(px(0.0), px(-40.0), px(100.0), px(40.0))),
         "RenderSliverToBoxAdapter paint must compose its Box child at \
          the same -scroll_offset paint offset committed during layout",
     );
@@ -478,7 +484,7 @@ fn box_host_splices_sliver_padding_child_at_paint_offset() {
 
     assert_eq!(
         first_picture(&tree).bounds(),
-        Rect::from_ltrb(px(7.0), px(10.0), px(107.0), px(90.0)),
+        Some(Rect::from_ltrb(px(7.0), px(10.0), px(107.0), px(90.0))),
         "sliver child paint must be composed at the paint offset computed \
          by RenderSliverPadding",
     );
@@ -0,0 +1,254 @@
+//! Relayout boundaries confine a dirty leaf's relayout to its subtree.
+//!
+//! `PipelineOwner::mark_needs_layout` walks up from the dirty node to the
+//! nearest relayout boundary and queues only that boundary; `run_layout`
+//! then lays out from the queued boundary, never from the root. A node is
+//! a boundary when its `RenderBox::is_relayout_boundary(constraints)`
+//! says so — tight constraints or `sized_by_parent` by default, or an
+//! explicit override.
+//!
+//! Scenarios:
+//! 1. a leaf inside a tightly-constrained box relayouts only up to that
+//!    box — its sibling and the root keep their layout;
+//! 2. an override promotes a loosely-constrained box to a boundary;
+//! 3. without a boundary on the path, the dirty leaf relayouts the root.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use flui_foundation::RenderId;
+use flui_rendering::{
+    constraints::BoxConstraints,
+    context::{BoxHitTestContext, BoxLayoutContext},
+    pipeline::PipelineOwner,
+    traits::RenderBox,
+};
+use flui_tree::Variable;
+use flui_types::{Size, geometry::px};
+
+type BoxedRenderObject =
+    Box<dyn flui_rendering::traits::RenderObject<flui_rendering::protocol::BoxProtocol>>;
+
+// ============================================================================
+// Counting test object
+// ============================================================================
+
+/// How a [`CountingBox`] constrains each of its children.
+#[derive(Debug, Clone, Copy)]
+enum ChildConstraints {
+    /// Child 0 gets a tight 100×100; later children get loose constraints.
+    TightFirst,
+    /// Every child gets the parent's constraints loosened.
+    Loose,
+}
+
+/// Variable-arity box that counts its `perform_layout` calls and sizes
+/// itself to `preferred` (constrained). `declares_boundary` overrides
+/// `is_relayout_boundary` to opt in regardless of constraints.
+#[derive(Debug)]
+struct CountingBox {
+    layout_runs: Arc<AtomicUsize>,
+    preferred: Size,
+    children: ChildConstraints,
+    declares_boundary: bool,
+}
+
+impl CountingBox {
+    fn new(preferred: f32, children: ChildConstraints) -> (Self, Arc<AtomicUsize>) {
+        let layout_runs = Arc::new(AtomicUsize::new(0));
+        let node = Self {
+            layout_runs: Arc::clone(&layout_runs),
+            preferred: Size::new(px(preferred), px(preferred)),
+            children,
+            declares_boundary: false,
+        };
+        (node, layout_runs)
+    }
+
+    fn declaring_boundary(mut self) -> Self {
+        self.declares_boundary = true;
+        self
+    }
+}
+
+impl flui_foundation::Diagnosticable for CountingBox {}
+
+impl RenderBox for CountingBox {
+    type Arity = Variable;
+    type ParentData = flui_rendering::parent_data::BoxParentData;
+
+    fn perform_layout(
+        &mut self,
+        ctx: &mut BoxLayoutContext<'_, Variable, Self::ParentData>,
+    ) -> Size {
+        self.layout_runs.fetch_add(1, Ordering::Relaxed);
+        let constraints = *ctx.constraints();
+        for i in 0..ctx.child_count() {
+            let child_constraints = match self.children {
+                ChildConstraints::TightFirst if i == 0 => {
+                    BoxConstraints::tight(Size::new(px(100.0), px(100.0)))
+                }
+                ChildConstraints::TightFirst | ChildConstraints::Loose => constraints.loosen(),
+            };
+            ctx.layout_child(i, child_constraints);
+        }
+        constraints.constrain(self.preferred)
+    }
+
+    fn hit_test(&self, _ctx: &mut BoxHitTestContext<'_, Variable, Self::ParentData>) -> bool {
+        false
+    }
+
+    fn is_relayout_boundary(&self, constraints: &BoxConstraints) -> bool {
+        self.declares_boundary || flui_rendering::constraints::Constraints::is_tight(constraints)
+    }
+}
+
+// ============================================================================
+// Fixture
+// ============================================================================
+
+/// Layout-run counters for the root → [middle → leaf, sibling] tree.
+struct Counts {
+    root: Arc<AtomicUsize>,
+    middle: Arc<AtomicUsize>,
+    leaf: Arc<AtomicUsize>,
+    sibling: Arc<AtomicUsize>,
+}
+
+impl Counts {
+    fn snapshot(&self) -> [usize; 4] {
+        [&self.root, &self.middle, &self.leaf, &self.sibling].map(|c| c.load(Ordering::Relaxed))
+    }
+}
+
+/// Builds root → [middle → leaf, sibling], runs the first frame, and
+/// returns the owner with the leaf id. `root_children` decides whether
+/// `middle` (child 0) is laid out under tight constraints.
+fn laid_out_tree(
+    root_children: ChildConstraints,
+    middle: CountingBox,
+    middle_runs: Arc<AtomicUsize>,
+) -> (PipelineOwner, RenderId, Counts) {
+    let (root, root_runs) = CountingBox::new(300.0, root_children);
+    let (leaf, leaf_runs) = CountingBox::new(20.0, ChildConstraints::Loose);
+    let (sibling, sibling_runs) = CountingBox::new(40.0, ChildConstraints::Loose);
+
+    let mut owner = PipelineOwner::new();
+    let root_id = owner.insert(Box::new(root) as BoxedRenderObject);
+    let middle_id = owner
+        .insert_child_render_object(root_id, Box::new(middle))
+        .expect("middle insert");
+    let leaf_id = owner
+        .insert_child_render_object(middle_id, Box::new(leaf))
+        .expect("leaf insert");
+    owner
+        .insert_child_render_object(root_id, Box::new(sibling))
+        .expect("sibling insert");
+    owner.set_root_id(Some(root_id));
+    owner.set_root_constraints(Some(BoxConstraints::new(
+        px(0.0),
+        px(300.0),
+        px(0.0),
+        px(300.0),
+    )));
+
+    let (owner, result) = owner.run_frame();
+    result.expect("first frame must not error");
+
+    let counts = Counts {
+        root: root_runs,
+        middle: middle_runs,
+        leaf: leaf_runs,
+        sibling: sibling_runs,
+    };
+    assert_eq!(
+        counts.snapshot(),
+        [1, 1, 1, 1],
+        "first frame lays out every node once"
+    );
+    (owner, leaf_id, counts)
+}
+
+/// Resizes the leaf, marks it dirty and runs one frame.
+fn relayout_leaf(mut owner: PipelineOwner, leaf: RenderId) -> PipelineOwner {
+    owner
+        .render_tree_mut()
+        .get_mut(leaf)
+        .expect("leaf node")
+        .as_box_mut()
+        .expect("box entry")
+        .render_object_mut()
+        .downcast_mut::<CountingBox>()
+        .expect("CountingBox")
+        .preferred = Size::new(px(30.0), px(30.0));
+    owner.mark_needs_layout(leaf);
+
+    let (owner, result) = owner.run_frame();
+    result.expect("relayout frame must not error");
+    owner
+}
+
+// ============================================================================
+// 1. Tight constraints make a boundary
+// ============================================================================
+
+#[test]
+fn leaf_under_tightly_constrained_box_relayouts_only_up_to_that_box() {
+    let (middle, middle_runs) = CountingBox::new(100.0, ChildConstraints::Loose);
+    let (owner, leaf, counts) = laid_out_tree(ChildConstraints::TightFirst, middle, middle_runs);
+
+    let owner = relayout_leaf(owner, leaf);
+
+    assert_eq!(
+        counts.snapshot(),
+        [1, 2, 2, 1],
+        "only the tightly-constrained box and its dirty leaf relayout; \
+         the root and the sibling keep their layout",
+    );
+    assert_eq!(
+        owner
+            .render_tree()
+            .get(leaf)
+            .and_then(flui_rendering::storage::RenderNode::geometry_box),
+        Some(Size::new(px(30.0), px(30.0))),
+        "the leaf commits its new size",
+    );
+}
+
+// ============================================================================
+// 2. An override opts a loosely-constrained box in
+// ============================================================================
+
+#[test]
+fn overriding_is_relayout_boundary_stops_propagation_under_loose_constraints() {
+    let (middle, middle_runs) = CountingBox::new(100.0, ChildConstraints::Loose);
+    let (owner, leaf, counts) = laid_out_tree(
+        ChildConstraints::Loose,
+        middle.declaring_boundary(),
+        middle_runs,
+    );
+
+    relayout_leaf(owner, leaf);
+
+    assert_eq!(counts.snapshot(), [1, 2, 2, 1]);
+}
+
+// ============================================================================
+// 3. No boundary on the path: the root relayouts
+// ============================================================================
+
+#[test]
+fn without_a_boundary_the_dirty_leaf_relayouts_from_the_root() {
+    let (middle, middle_runs) = CountingBox::new(100.0, ChildConstraints::Loose);
+    let (owner, leaf, counts) = laid_out_tree(ChildConstraints::Loose, middle, middle_runs);
+
+    relayout_leaf(owner, leaf);
+
+    let [root, middle, leaf, _sibling] = counts.snapshot();
+    assert_eq!(
+        [root, middle, leaf],
+        [2, 2, 2],
+        "a loosely-constrained chain propagates the dirty mark to the root",
+    );
+}
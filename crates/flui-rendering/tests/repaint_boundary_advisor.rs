@@ -0,0 +1,63 @@
+//! `PipelineOwner::suggest_repaint_boundaries` against a synthetic tree in
+//! which one animated leaf forces whole-tree repaints.
+
+use flui_objects::{RenderColoredBox, RenderPadding, RenderRepaintBoundary};
+use flui_rendering::testing::{FrameRun, Probe, RenderTester, box_node};
+
+const ANIMATED_FRAMES: u32 = 10;
+
+/// Pumps `ANIMATED_FRAMES` frames, each repainting only the `animated` leaf.
+fn animate(run: &mut FrameRun) {
+    let animated = run.id("animated");
+    for _ in 0..ANIMATED_FRAMES {
+        run.mark_needs_paint(animated);
+        assert!(run.pump().painted);
+    }
+}
+
+#[test]
+fn frequently_repainting_leaf_is_suggested_as_a_boundary() {
+    let mut run = RenderTester::mount(
+        box_node(RenderPadding::all(4.0)).child(
+            box_node(RenderPadding::all(4.0))
+                .label("static")
+                .child(box_node(RenderColoredBox::red(20.0, 20.0)).label("animated")),
+        ),
+    )
+    .run_frame();
+    animate(&mut run);
+
+    let suggestions = run.owner().suggest_repaint_boundaries();
+    assert_eq!(
+        suggestions.len(),
+        1,
+        "only the animated leaf: {suggestions:?}"
+    );
+    let suggestion = &suggestions[0];
+    assert_eq!(suggestion.name(), Some("RenderColoredBox"));
+    assert!(
+        suggestion.get_property_f64("repaint_count") >= Some(f64::from(ANIMATED_FRAMES)),
+        "{suggestion:?}"
+    );
+    assert_eq!(
+        suggestion.get_property_f64("parent_repaint_count"),
+        Some(1.0)
+    );
+
+    run.owner().reset_repaint_counts();
+    assert!(run.owner().suggest_repaint_boundaries().is_empty());
+}
+
+#[test]
+fn leaf_already_under_a_boundary_is_not_suggested() {
+    let mut run = RenderTester::mount(
+        box_node(RenderPadding::all(4.0)).child(
+            box_node(RenderRepaintBoundary::new())
+                .child(box_node(RenderColoredBox::red(20.0, 20.0)).label("animated")),
+        ),
+    )
+    .run_frame();
+    animate(&mut run);
+
+    assert!(run.owner().suggest_repaint_boundaries().is_empty());
+}
@@ -30,7 +30,7 @@
 use web_time::Instant;
 
 use crate::{
-    duration::{FrameDuration, Milliseconds, Percentage},
+    duration::{DEADLINE_NEAR_RATIO, FrameDuration, Milliseconds, Percentage},
     frame::FramePhase,
 };
 
@@ -459,6 +459,18 @@ pub fn is_deadline_near(&self) -> bool {
         self.frame_duration.is_deadline_near(self.elapsed())
     }
 
+    /// Get the instant the deadline becomes near (80% of the budget after
+    /// [`reset`](Self::reset)), or `None` outside a frame
+    ///
+    /// Pass this to [`TaskQueue::run_until_deadline`](crate::TaskQueue::run_until_deadline)
+    /// to leave low-priority work for the next frame once the frame is
+    /// nearly spent.
+    pub fn deadline(&self) -> Option<Instant> {
+        let near = self.frame_duration.as_seconds().value() * DEADLINE_NEAR_RATIO;
+        self.frame_start
+            .map(|start| start + web_time::Duration::from_secs_f64(near))
+    }
+
     /// Finish current frame and record total time
     ///
     /// This is a convenience that calls
@@ -513,6 +525,145 @@ pub fn jank_percentage(&self) -> Percentage {
     }
 }
 
+/// Frames observed at one cadence before [`AdaptiveFrameRate`] reconsiders it.
+const PACING_WINDOW: usize = 30;
+
+/// Fraction of the window that must miss the budget before stepping down.
+const PACING_OVERRUN_RATIO: f64 = 0.5;
+
+/// Share of the faster cadence's budget every frame must stay under before
+/// stepping back up.
+const PACING_HEADROOM_RATIO: f64 = 0.8;
+
+/// Adaptive frame pacing - picks the fastest sustainable cadence
+///
+/// The candidate cadences are whole divisors of `max_fps` (60, 30, 20, ...)
+/// down to `min_fps`, so each one lines up with the display's vsync. The
+/// pacer watches a window of recent frame times and:
+///
+/// - steps **down** one cadence when more than half the window overran the
+///   current budget, and
+/// - steps **up** one cadence when every frame in a full window fit within
+///   80% of the faster cadence's budget.
+///
+/// The gap between the two thresholds, and clearing the window after every
+/// change, keep a device that can almost-but-not-quite hold 60fps settled at
+/// 30 instead of flipping between a janky 60 and 30 every few frames.
+///
+/// # Examples
+///
+/// ```
+/// use flui_scheduler::{budget::AdaptiveFrameRate, duration::Milliseconds};
+///
+/// let mut pacer = AdaptiveFrameRate::new(30, 60);
+/// assert_eq!(pacer.target_fps(), 60);
+///
+/// // A run of 25ms frames can't hold 60fps...
+/// let changed = (0..30).find_map(|_| pacer.record_frame(Milliseconds::new(25.0)));
+/// assert_eq!(changed, Some(30));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AdaptiveFrameRate {
+    /// Candidate cadences, fastest first
+    cadences: Vec<u32>,
+
+    /// Index of the current cadence in `cadences`
+    current: usize,
+
+    /// Frame times observed at the current cadence
+    recent: VecDeque<Milliseconds>,
+}
+
+impl AdaptiveFrameRate {
+    /// Create a pacer choosing between `min_fps` and `max_fps`, starting at
+    /// `max_fps`
+    ///
+    /// `min_fps` is clamped to `1..=max_fps`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_fps` is zero.
+    pub fn new(min_fps: u32, max_fps: u32) -> Self {
+        assert!(max_fps > 0, "fps > 0");
+        let min_fps = min_fps.clamp(1, max_fps);
+        let cadences = (1..=max_fps)
+            .map(|divisor| max_fps / divisor)
+            .take_while(|&fps| fps >= min_fps)
+            .filter(|&fps| max_fps.is_multiple_of(fps))
+            .fold(Vec::new(), |mut cadences, fps| {
+                if cadences.last() != Some(&fps) {
+                    cadences.push(fps);
+                }
+                cadences
+            });
+
+        Self {
+            cadences,
+            current: 0,
+            recent: VecDeque::with_capacity(PACING_WINDOW),
+        }
+    }
+
+    /// Get the currently chosen cadence
+    pub fn target_fps(&self) -> u32 {
+        self.cadences[self.current]
+    }
+
+    /// Get the fastest cadence the pacer may choose
+    pub fn max_fps(&self) -> u32 {
+        self.cadences[0]
+    }
+
+    /// Get the slowest cadence the pacer may choose
+    pub fn min_fps(&self) -> u32 {
+        self.cadences[self.cadences.len() - 1]
+    }
+
+    /// Record a finished frame's duration
+    ///
+    /// Returns the new target FPS when this frame changed the cadence.
+    pub fn record_frame(&mut self, frame_time: Milliseconds) -> Option<u32> {
+        self.recent.push_back(frame_time);
+        if self.recent.len() > PACING_WINDOW {
+            self.recent.pop_front();
+        }
+
+        let budget = budget_ms(self.target_fps());
+        let overruns = self.recent.iter().filter(|t| t.value() > budget).count();
+        if self.current + 1 < self.cadences.len()
+            && overruns as f64 > PACING_WINDOW as f64 * PACING_OVERRUN_RATIO
+        {
+            return Some(self.switch_to(self.current + 1));
+        }
+
+        if self.current > 0 && self.recent.len() == PACING_WINDOW {
+            let faster_budget = budget_ms(self.cadences[self.current - 1]);
+            if self
+                .recent
+                .iter()
+                .all(|t| t.value() <= faster_budget * PACING_HEADROOM_RATIO)
+            {
+                return Some(self.switch_to(self.current - 1));
+            }
+        }
+
+        None
+    }
+
+    fn switch_to(&mut self, index: usize) -> u32 {
+        let from = self.target_fps();
+        self.current = index;
+        self.recent.clear();
+        let to = self.target_fps();
+        tracing::debug!(from, to, "adaptive frame pacing changed target fps");
+        to
+    }
+}
+
+fn budget_ms(fps: u32) -> f64 {
+    1000.0 / f64::from(fps)
+}
+
 /// All phase statistics
 #[derive(Debug, Clone, Copy, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -764,4 +915,54 @@ fn test_jank_statistics() {
         assert_eq!(budget.jank_count(), 2);
         assert!((budget.jank_percentage().value() - 20.0).abs() < 0.1);
     }
+
+    #[test]
+    fn test_adaptive_cadences_divide_max_fps() {
+        let pacer = AdaptiveFrameRate::new(20, 60);
+        assert_eq!(pacer.cadences, [60, 30, 20]);
+        assert_eq!(pacer.target_fps(), 60);
+        assert_eq!(pacer.min_fps(), 20);
+
+        // A min_fps that isn't a divisor stops at the next cadence above it.
+        assert_eq!(AdaptiveFrameRate::new(25, 120).cadences, [120, 60, 40, 30]);
+        assert_eq!(AdaptiveFrameRate::new(90, 60).cadences, [60]);
+    }
+
+    #[test]
+    fn test_adaptive_steps_down_then_recovers() {
+        let mut pacer = AdaptiveFrameRate::new(20, 60);
+
+        // Occasional misses don't change the cadence.
+        for i in 0..60 {
+            let ms = if i % 4 == 0 { 20.0 } else { 12.0 };
+            assert_eq!(pacer.record_frame(Milliseconds::new(ms)), None);
+        }
+
+        // Sustained 25ms frames: 60 -> 30, where they fit.
+        let changes: Vec<_> = (0..90)
+            .filter_map(|_| pacer.record_frame(Milliseconds::new(25.0)))
+            .collect();
+        assert_eq!(changes, [30]);
+
+        // 12ms frames fit under 80% of 60fps's budget: back up to 60.
+        let changes: Vec<_> = (0..90)
+            .filter_map(|_| pacer.record_frame(Milliseconds::new(12.0)))
+            .collect();
+        assert_eq!(changes, [60]);
+    }
+
+    #[test]
+    fn test_adaptive_holds_cadence_without_headroom() {
+        let mut pacer = AdaptiveFrameRate::new(30, 60);
+        for _ in 0..PACING_WINDOW {
+            pacer.record_frame(Milliseconds::new(25.0));
+        }
+        assert_eq!(pacer.target_fps(), 30);
+
+        // 15ms fits 60fps, but not with headroom: stay at 30 rather than thrash.
+        for _ in 0..3 * PACING_WINDOW {
+            assert_eq!(pacer.record_frame(Milliseconds::new(15.0)), None);
+        }
+        assert_eq!(pacer.target_fps(), 30);
+    }
 }
@@ -465,6 +465,9 @@ fn from(us: Microseconds) -> Self {
 // FrameDuration - Frame budget calculations
 // =============================================================================
 
+/// Share of the frame budget after which the deadline counts as near.
+pub(crate) const DEADLINE_NEAR_RATIO: f64 = 0.8;
+
 /// Frame duration with budget-related calculations
 ///
 /// This type is specifically designed for frame timing calculations,
@@ -550,7 +553,7 @@ pub fn utilization(self, elapsed: Milliseconds) -> f64 {
     /// Check if deadline is near (>80% budget used)
     #[inline]
     pub fn is_deadline_near(self, elapsed: Milliseconds) -> bool {
-        self.utilization(elapsed) >= 0.8
+        self.utilization(elapsed) >= DEADLINE_NEAR_RATIO
     }
 
     /// Check if elapsed time indicates a janky frame.
@@ -557,6 +557,13 @@ pub struct FrameTiming {
     /// Current phase
     pub phase: FramePhase,
 
+    /// Cadence chosen by adaptive frame pacing for this frame, or `None` when
+    /// pacing is off
+    ///
+    /// When set, [`frame_duration`](Self::frame_duration) is this cadence's
+    /// budget. See [`Scheduler::enable_adaptive_fps`](crate::Scheduler::enable_adaptive_fps).
+    pub adaptive_fps: Option<u32>,
+
     /// Per-phase elapsed durations recorded by the pipeline driver.
     ///
     /// Indexed by [`FramePhase::as_index`]. Entries default to
@@ -577,6 +584,7 @@ pub fn new(target_fps: u32) -> Self {
             start_time: Instant::now(),
             frame_duration: FrameDuration::try_from_fps(target_fps).expect("fps > 0"),
             phase: FramePhase::Idle,
+            adaptive_fps: None,
             phase_durations: [Milliseconds::ZERO; FRAME_PHASE_COUNT],
         }
     }
@@ -588,6 +596,7 @@ pub fn with_duration(frame_duration: FrameDuration) -> Self {
             start_time: Instant::now(),
             frame_duration,
             phase: FramePhase::Idle,
+            adaptive_fps: None,
             phase_durations: [Milliseconds::ZERO; FRAME_PHASE_COUNT],
         }
     }
@@ -137,6 +137,8 @@
 pub mod task;
 pub mod ticker;
 pub mod vsync;
+#[cfg(feature = "winit")]
+pub mod winit_vsync;
 
 // Type-safe primitives
 pub mod async_driver;
@@ -146,7 +148,8 @@
 // Re-exports - Core types
 pub use async_driver::{AsyncDriver, BoxedTask, TaskToken};
 pub use budget::{
-    AllPhaseStats, BudgetPolicy, FrameBudget, FrameBudgetBuilder, PhaseStats, SharedBudget,
+    AdaptiveFrameRate, AllPhaseStats, BudgetPolicy, FrameBudget, FrameBudgetBuilder, PhaseStats,
+    SharedBudget,
 };
 pub use config::{
     PerformanceMode, PerformanceModeRequestHandle, SERVICE_EXT_TIME_DILATION, SchedulingStrategy,
@@ -171,12 +174,14 @@
 // Re-exports - ID types (unified with flui-foundation)
 pub use id::{CallbackId, Id, IdGenerator, Marker, markers};
 pub use scheduler::{FrameCompletionFuture, FrameSkipPolicy, Scheduler, SchedulerBuilder};
-pub use task::{Priority, PriorityCount, Task, TaskId, TaskQueue};
+pub use task::{DeadlineRun, Priority, PriorityCount, Task, TaskId, TaskQueue};
 pub use ticker::{
-    Ticker, TickerCallback, TickerCanceled, TickerFuture, TickerFutureOrCancel, TickerGroup,
-    TickerId, TickerProvider, TickerState,
+    InvalidTimeScale, Ticker, TickerCallback, TickerCanceled, TickerFuture, TickerFutureOrCancel,
+    TickerGroup, TickerId, TickerProvider, TickerState,
 };
 pub use vsync::{VsyncCallback, VsyncMode, VsyncScheduler, VsyncStats};
+#[cfg(feature = "winit")]
+pub use winit_vsync::{RedrawProxy, WinitVsyncScheduler};
 
 /// Prelude for common scheduler types
 pub mod prelude {
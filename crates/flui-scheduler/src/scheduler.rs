@@ -69,7 +69,7 @@
 use web_time::{Duration, Instant};
 
 use crate::{
-    budget::FrameBudget,
+    budget::{AdaptiveFrameRate, FrameBudget},
     config::{
         PerformanceMode, PerformanceModeRequestHandle, TimingsCallback, adjust_duration_for_epoch,
         time_dilation,
@@ -335,6 +335,8 @@ struct FrameState {
     frame_duration: Mutex<FrameDuration>,
     /// Frame budget management
     budget: Mutex<FrameBudget>,
+    /// Adaptive frame pacing (None = fixed target FPS)
+    pacer: Mutex<Option<AdaptiveFrameRate>>,
     /// Whether a frame is currently scheduled
     frame_scheduled: AtomicBool,
     /// Frame counter
@@ -502,6 +504,7 @@ pub fn with_frame_duration(frame_duration: FrameDuration) -> Self {
                 current_vsync_time: Mutex::new(None),
                 frame_duration: Mutex::new(frame_duration),
                 budget: Mutex::new(FrameBudget::new(target_fps)),
+                pacer: Mutex::new(None),
                 frame_scheduled: AtomicBool::new(false),
                 frame_count: AtomicU64::new(0),
                 janky_frame_count: AtomicU64::new(0),
@@ -637,6 +640,12 @@ pub fn handle_begin_frame(&self, vsync_time: Instant) -> FrameId {
         let mut timing = FrameTiming::with_duration(frame_duration);
         timing.start_time = vsync_time;
         timing.phase = FramePhase::Build;
+        timing.adaptive_fps = self
+            .frame
+            .pacer
+            .lock()
+            .as_ref()
+            .map(AdaptiveFrameRate::target_fps);
 
         let frame_id = timing.id;
         *self.frame.current_frame.lock() = Some(timing);
@@ -746,14 +755,18 @@ pub fn handle_draw_frame(&self) {
             }
         }
 
-        // Execute priority tasks with budget awareness
-        self.task_queue.execute_until(Priority::Animation);
-
-        if !self.is_over_budget() {
-            self.task_queue.execute_until(Priority::Build);
-        }
-        if !self.is_deadline_near() {
-            self.task_queue.execute_until(Priority::Idle);
+        // Execute priority tasks until the budget's deadline is near;
+        // Build and Idle work left over waits for the next frame. A
+        // persistent callback may have called `budget().finish_frame()`,
+        // which clears the deadline; the tasks then run without one.
+        let deadline = self.frame.budget.lock().deadline();
+        let run = self.task_queue.run_until(deadline);
+        if run.deferred > 0 {
+            tracing::debug!(
+                executed = run.executed,
+                deferred = run.deferred,
+                "deferred low-priority tasks to the next frame"
+            );
         }
     }
 
@@ -790,6 +803,7 @@ pub fn end_frame(&self) {
             // Record timing and check for jank
             let elapsed = timing.elapsed();
             self.frame.budget.lock().record_frame_duration(elapsed);
+            self.pace_frame(elapsed);
 
             if timing.is_janky() {
                 self.frame.janky_frame_count.fetch_add(1, Ordering::Relaxed);
@@ -1203,18 +1217,45 @@ pub fn add_persistent_frame_callback(&self, callback: RecurringFrameCallback) {
 
     /// Add a post-frame callback.
     ///
-    /// Fires once after the current/next frame completes.
+    /// Fires exactly once, after the current frame's paint completes (or after
+    /// the next frame if none is in progress). Callbacks run in registration
+    /// order. A callback registered *from inside* a post-frame callback is
+    /// deferred to the following frame, so a callback that re-registers itself
+    /// cannot spin the current frame forever.
     ///
     /// Flutter parity at [`binding.dart:802`](../../../.flutter/flutter-master/packages/flutter/lib/src/scheduler/binding.dart):
-    /// "Post-frame callbacks ... are called exactly once" and cannot be
-    /// cancelled before they fire. Returns `()` — no cancellation handle.
-    pub fn add_post_frame_callback(&self, callback: PostFrameCallback) {
+    /// "Post-frame callbacks ... are called exactly once". Unlike Flutter, the
+    /// returned [`CallbackId`] can be passed to
+    /// [`cancel_post_frame_callback`](Self::cancel_post_frame_callback) before
+    /// the callback fires.
+    pub fn add_post_frame_callback(&self, callback: PostFrameCallback) -> CallbackId {
         self.with_post_frame_registration(|id| {
             self.callbacks
                 .post_frame
                 .lock()
                 .push(CancellablePostFrameCallback { id, callback });
-        });
+            id
+        })
+    }
+
+    /// Cancel a post-frame callback by ID.
+    ///
+    /// Returns `true` if the callback was still queued and has been removed.
+    /// Returns `false` if it already ran or is unknown; a callback cancelled
+    /// by an earlier post-frame callback of the same frame is still skipped.
+    pub fn cancel_post_frame_callback(&self, id: CallbackId) -> bool {
+        let _registration = self.callbacks.post_frame_registration.lock();
+        let mut callbacks = self.callbacks.post_frame.lock();
+        let original_len = callbacks.len();
+        callbacks.retain(|c| c.id != id);
+
+        if callbacks.len() < original_len {
+            return true;
+        }
+
+        // Already drained into the running frame: skip it when reached.
+        self.callbacks.cancelled.insert(id, ());
+        false
     }
 
     // =========================================================================
@@ -1259,18 +1300,71 @@ pub fn task_queue(&self) -> &TaskQueue {
     // =========================================================================
 
     /// Set target FPS
+    ///
+    /// Turns adaptive pacing off: an explicit target is a fixed cadence.
     pub fn set_target_fps(&self, fps: u32) {
         let frame_duration = FrameDuration::try_from_fps(fps).expect("fps > 0");
+        *self.frame.pacer.lock() = None;
         *self.frame.frame_duration.lock() = frame_duration;
         *self.frame.budget.lock() = FrameBudget::new(fps);
     }
 
     /// Set frame duration directly
+    ///
+    /// Turns adaptive pacing off, like [`set_target_fps`](Self::set_target_fps).
     pub fn set_frame_duration(&self, frame_duration: FrameDuration) {
+        *self.frame.pacer.lock() = None;
         *self.frame.frame_duration.lock() = frame_duration;
         *self.frame.budget.lock() = FrameBudget::new(frame_duration.fps() as u32);
     }
 
+    /// Let the scheduler pick its target FPS between `min` and `max`
+    ///
+    /// The target starts at `max` and moves between whole divisors of it
+    /// (60, 30, 20, ...) as recent frame times show the budget being
+    /// consistently overrun or comfortably met; see [`AdaptiveFrameRate`].
+    /// Each frame's chosen cadence is reported in
+    /// [`FrameTiming::adaptive_fps`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is zero.
+    pub fn enable_adaptive_fps(&self, min: u32, max: u32) {
+        let pacer = AdaptiveFrameRate::new(min, max);
+        self.apply_paced_fps(pacer.target_fps());
+        *self.frame.pacer.lock() = Some(pacer);
+    }
+
+    /// Stop adaptive pacing, keeping the currently chosen target FPS
+    pub fn disable_adaptive_fps(&self) {
+        *self.frame.pacer.lock() = None;
+    }
+
+    /// Check if adaptive pacing is choosing the target FPS
+    pub fn is_adaptive_fps_enabled(&self) -> bool {
+        self.frame.pacer.lock().is_some()
+    }
+
+    /// Feed a finished frame to the pacer and apply any cadence change.
+    fn pace_frame(&self, frame_time: Milliseconds) {
+        let changed = self
+            .frame
+            .pacer
+            .lock()
+            .as_mut()
+            .and_then(|pacer| pacer.record_frame(frame_time));
+        if let Some(fps) = changed {
+            self.apply_paced_fps(fps);
+        }
+    }
+
+    /// Retarget frames without resetting the budget's frame-time history.
+    fn apply_paced_fps(&self, fps: u32) {
+        *self.frame.frame_duration.lock() =
+            FrameDuration::try_from_fps(fps).expect("BUG: paced fps > 0");
+        self.frame.budget.lock().set_target_fps(fps);
+    }
+
     /// Get target FPS
     pub fn target_fps(&self) -> u32 {
         self.frame.frame_duration.lock().fps() as u32
@@ -2398,6 +2492,45 @@ fn test_task_execution_priority() {
         assert_eq!(*counter.lock(), vec![1, 2, 3, 4]);
     }
 
+    #[test]
+    fn test_frame_defers_idle_tasks_past_the_deadline() {
+        let scheduler = Scheduler::new();
+        let ran = Arc::new(Mutex::new(Vec::new()));
+
+        // An animation task that eats the whole 16.67ms budget.
+        let r = Arc::clone(&ran);
+        scheduler.add_task(Priority::Animation, move || {
+            std::thread::sleep(Duration::from_millis(20));
+            r.lock().push(Priority::Animation);
+        });
+        let r = Arc::clone(&ran);
+        scheduler.add_task(Priority::Idle, move || r.lock().push(Priority::Idle));
+
+        scheduler.execute_frame();
+        assert_eq!(*ran.lock(), [Priority::Animation]);
+        assert_eq!(scheduler.task_queue().len(), 1, "idle task waits");
+
+        scheduler.execute_frame();
+        assert_eq!(*ran.lock(), [Priority::Animation, Priority::Idle]);
+    }
+
+    #[test]
+    fn test_persistent_callback_may_finish_the_budget_frame() {
+        let scheduler = Arc::new(Scheduler::new());
+        let ran = Arc::new(Mutex::new(false));
+
+        let s = Arc::clone(&scheduler);
+        scheduler.add_persistent_frame_callback(Arc::new(move |_| {
+            s.budget().finish_frame();
+        }));
+        let r = Arc::clone(&ran);
+        scheduler.add_task(Priority::Idle, move || *r.lock() = true);
+
+        // No deadline left: the frame still runs its tasks.
+        scheduler.execute_frame();
+        assert!(*ran.lock());
+    }
+
     #[test]
     fn test_post_frame_callback() {
         let scheduler = Scheduler::new();
@@ -2477,6 +2610,52 @@ fn test_frame_duration_setting() {
         assert!((scheduler.target_fps() as i32 - 30).abs() <= 1);
     }
 
+    #[test]
+    fn test_adaptive_fps_steps_down_then_recovers() {
+        let scheduler = Scheduler::new();
+        scheduler.enable_adaptive_fps(30, 60);
+
+        // Simulate a frame that took `ms`: its vsync was that long ago.
+        let frame = |ms: u64| {
+            let vsync = Instant::now()
+                .checked_sub(Duration::from_millis(ms))
+                .expect("clock is past the simulated vsync");
+            scheduler.handle_begin_frame(vsync);
+            let timing = scheduler.current_frame().expect("frame is open");
+            scheduler.handle_draw_frame();
+            scheduler.end_frame();
+            timing
+        };
+
+        let first = frame(25);
+        assert_eq!(first.adaptive_fps, Some(60));
+        assert_eq!(
+            first.frame_duration,
+            FrameDuration::try_from_fps(60).expect("fps > 0")
+        );
+
+        // Consistently over the 16.67ms budget: settle at 30.
+        let over: Vec<_> = (0..60).map(|_| frame(25).adaptive_fps).collect();
+        assert_eq!(over.last(), Some(&Some(30)));
+        assert!(
+            over.windows(2).all(|w| w[0] >= w[1]),
+            "no thrashing back up"
+        );
+        assert_eq!(
+            scheduler.frame_duration(),
+            FrameDuration::try_from_fps(30).expect("fps > 0")
+        );
+
+        // Plenty of headroom again: back to 60.
+        let recovered: Vec<_> = (0..60).map(|_| frame(2).adaptive_fps).collect();
+        assert_eq!(recovered.last(), Some(&Some(60)));
+
+        // An explicit target turns pacing off.
+        scheduler.set_target_fps(30);
+        assert!(!scheduler.is_adaptive_fps_enabled());
+        assert_eq!(frame(25).adaptive_fps, None);
+    }
+
     // Frame Skip Policy Tests
 
     #[test]
@@ -2604,7 +2783,7 @@ fn test_persistent_callback_fires_every_frame() {
     #[test]
     fn test_post_frame_callback_fires_exactly_once() {
         // Flutter parity: binding.dart:802 "Post-frame callbacks ... are
-        // called exactly once" and cannot be cancelled before they fire.
+        // called exactly once".
         let scheduler = Scheduler::new();
         let called = Arc::new(Mutex::new(0));
 
@@ -2626,9 +2805,11 @@ fn test_callback_id_uniqueness() {
 
         let id1 = scheduler.schedule_frame_callback(Box::new(|_| {}));
         let id2 = scheduler.schedule_frame_callback(Box::new(|_| {}));
-        // persistent + post-frame no longer return CallbackId; only the
-        // transient schedule_frame_callback does. Both transient IDs differ.
+        let id3 = scheduler.add_post_frame_callback(Box::new(|_| {}));
+        // Persistent callbacks return no CallbackId; transient and post-frame
+        // IDs share one generator.
         assert_ne!(id1, id2);
+        assert_ne!(id2, id3);
     }
 
     #[test]
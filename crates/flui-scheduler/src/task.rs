@@ -20,6 +20,7 @@
 use parking_lot::Mutex;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use web_time::Instant;
 
 use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
 
@@ -404,6 +405,65 @@ pub fn execute_priority(&self, priority: Priority) -> usize {
         count
     }
 
+    /// Execute tasks in priority order until `deadline`
+    ///
+    /// [`Priority::Animation`] and [`Priority::UserInput`] tasks always run,
+    /// even past the deadline. Once the deadline has passed, no further
+    /// [`Priority::Build`] or [`Priority::Idle`] task is started; they stay
+    /// queued for the next frame. A task already running is never
+    /// interrupted.
+    ///
+    /// Tasks are popped one at a time, so a task enqueued by a running task
+    /// is considered in priority order. At most as many tasks as were queued
+    /// on entry are run, so a task that re-enqueues itself cannot spin the
+    /// frame forever.
+    pub fn run_until_deadline(&self, deadline: Instant) -> DeadlineRun {
+        self.run_until(Some(deadline))
+    }
+
+    /// [`run_until_deadline`](Self::run_until_deadline), or with no
+    /// deadline at all when `deadline` is `None` (every task runs, up to
+    /// the same cap).
+    pub(crate) fn run_until(&self, deadline: Option<Instant>) -> DeadlineRun {
+        let mut remaining = self.len();
+        let mut executed = 0;
+
+        while remaining > 0 {
+            let task = {
+                let mut queue = self.queue.lock();
+                let Some(next) = queue.peek() else {
+                    break;
+                };
+                // The heap is priority-ordered: once the head may be
+                // deferred, everything behind it may be too.
+                if next.0.priority < Priority::Animation
+                    && deadline.is_some_and(|deadline| Instant::now() >= deadline)
+                {
+                    return DeadlineRun {
+                        executed,
+                        deferred: queue.len(),
+                    };
+                }
+                let task = queue
+                    .pop()
+                    .expect("BUG: peek returned Some under the same lock, so pop must succeed");
+                self.len.fetch_sub(1, AtomicOrdering::AcqRel);
+                task.0
+            };
+
+            task.execute();
+            executed += 1;
+            remaining -= 1;
+        }
+
+        // The cap may stop the loop with tasks enqueued during the run still
+        // queued; they wait for the next frame too.
+        DeadlineRun {
+            executed,
+            deferred: self.len(),
+        }
+    }
+
     /// Execute all pending tasks
     ///
     /// Returns number of tasks executed
@@ -462,6 +522,16 @@ fn default() -> Self {
     }
 }
 
+/// Outcome of [`TaskQueue::run_until_deadline`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeadlineRun {
+    /// Number of tasks executed
+    pub executed: usize,
+    /// Number of tasks left queued for the next frame, because the deadline
+    /// passed or the run reached its cap
+    pub deferred: usize,
+}
+
 /// Count of tasks at each priority level
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -561,6 +631,88 @@ fn test_execute_until() {
         assert_eq!(queue.len(), 2); // Build tasks remain
     }
 
+    #[test]
+    fn test_run_until_deadline_defers_low_priority_work() {
+        let queue = TaskQueue::new();
+        let ran = Arc::new(Mutex::new(Vec::new()));
+        for priority in [
+            Priority::Idle,
+            Priority::UserInput,
+            Priority::Build,
+            Priority::Animation,
+        ] {
+            let ran = Arc::clone(&ran);
+            queue.add(priority, move || ran.lock().push(priority));
+        }
+
+        // Deadline already passed: only high-priority work runs.
+        let run = queue.run_until_deadline(Instant::now());
+        assert_eq!(
+            run,
+            DeadlineRun {
+                executed: 2,
+                deferred: 2
+            }
+        );
+        assert_eq!(*ran.lock(), [Priority::UserInput, Priority::Animation]);
+
+        // Next frame, with time to spare, the deferred tasks run.
+        let later = Instant::now() + web_time::Duration::from_secs(10);
+        let run = queue.run_until_deadline(later);
+        assert_eq!(
+            run,
+            DeadlineRun {
+                executed: 2,
+                deferred: 0
+            }
+        );
+        assert_eq!(ran.lock()[2..], [Priority::Build, Priority::Idle]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_run_until_deadline_runs_high_priority_past_deadline() {
+        use std::time::Duration;
+
+        let queue = TaskQueue::new();
+        let deadline = Instant::now() + Duration::from_millis(5);
+        let count = Arc::new(Mutex::new(0));
+
+        // The first animation task overruns the deadline; the second still
+        // runs, the idle task doesn't.
+        queue.add(Priority::Animation, || {
+            std::thread::sleep(Duration::from_millis(10));
+        });
+        let c = Arc::clone(&count);
+        queue.add(Priority::Animation, move || *c.lock() += 1);
+        queue.add(Priority::Idle, || panic!("idle task ran past the deadline"));
+
+        let run = queue.run_until_deadline(deadline);
+        assert_eq!(run.executed, 2);
+        assert_eq!(run.deferred, 1);
+        assert_eq!(*count.lock(), 1);
+        assert_eq!(queue.peek_priority(), Some(Priority::Idle));
+    }
+
+    #[test]
+    fn test_run_until_deadline_counts_tasks_left_by_the_cap() {
+        let queue = TaskQueue::new();
+        let requeue = queue.clone();
+        queue.add(Priority::Build, move || requeue.add(Priority::Build, || {}));
+
+        // Only the task queued on entry runs; the one it enqueued is deferred.
+        let later = Instant::now() + web_time::Duration::from_secs(10);
+        let run = queue.run_until_deadline(later);
+        assert_eq!(
+            run,
+            DeadlineRun {
+                executed: 1,
+                deferred: 1
+            }
+        );
+        assert_eq!(queue.len(), 1);
+    }
+
     #[test]
     fn test_priority_count() {
         let queue = TaskQueue::new();
@@ -63,6 +63,7 @@
 
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
+use crate::config::{set_time_dilation, time_dilation};
 use crate::duration::Seconds;
 use crate::id::CallbackId;
 
@@ -79,6 +80,32 @@ fn next_ticker_id() -> TickerId {
 /// Ticker callback - receives elapsed time in seconds
 pub type TickerCallback = Box<dyn FnMut(f64) + Send>;
 
+/// Configuration error for [`Ticker::set_time_scale`] and
+/// [`TickerProvider::set_global_time_scale`].
+#[derive(Debug, Clone, Copy, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum InvalidTimeScale {
+    /// `value <= 0.0` rejected — use [`Ticker::mute`] to freeze a ticker.
+    #[error("ticker time scale must be positive (got {0})")]
+    NonPositive(f64),
+    /// `value` is NaN or infinite — undefined math.
+    #[error("ticker time scale must be finite (got {0})")]
+    NonFinite(f64),
+    /// `value` is so small its reciprocal time dilation overflows.
+    #[error("ticker time scale is too small (got {0})")]
+    TooSmall(f64),
+}
+
+fn validate_time_scale(value: f64) -> Result<f64, InvalidTimeScale> {
+    if !value.is_finite() {
+        return Err(InvalidTimeScale::NonFinite(value));
+    }
+    if value <= 0.0 {
+        return Err(InvalidTimeScale::NonPositive(value));
+    }
+    Ok(value)
+}
+
 /// Ticker provider trait — Flutter-faithful factory shape.
 ///
 /// Flutter parity: [`ticker.dart:248`](../../../.flutter/flutter-master/packages/flutter/lib/src/scheduler/ticker.dart)
@@ -106,6 +133,35 @@ fn create_ticker(&self, on_tick: TickerCallback) -> Ticker {
         ticker.set_pending_callback(on_tick);
         ticker
     }
+
+    /// Scale the time seen by **every** ticker, e.g. `0.25` to run all
+    /// animations at quarter speed for inspection.
+    ///
+    /// A thin wrapper over [`set_time_dilation`]: a scale of `0.25` is a
+    /// dilation of `4.0`. Multiplies with each ticker's own
+    /// [`Ticker::set_time_scale`], and tickers don't jump on a change.
+    ///
+    /// The setting is process-wide; implementors should not override this.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidTimeScale`] unless `scale` is positive, finite and
+    /// has a finite reciprocal.
+    fn set_global_time_scale(scale: f64) -> Result<(), InvalidTimeScale>
+    where
+        Self: Sized,
+    {
+        let scale = validate_time_scale(scale)?;
+        set_time_dilation(1.0 / scale).map_err(|_| InvalidTimeScale::TooSmall(scale))
+    }
+
+    /// The current global ticker time scale, `1.0 / time_dilation()`.
+    fn global_time_scale() -> f64
+    where
+        Self: Sized,
+    {
+        1.0 / time_dilation()
+    }
 }
 
 /// State of a ticker
@@ -150,9 +206,17 @@ pub const fn can_start(self) -> bool {
 /// Shared inner state for a Ticker (single allocation, single lock)
 struct TickerInner {
     state: TickerState,
-    start_time: Option<Instant>,
+    /// Wall-clock instant `anchor_elapsed` was taken at; `None` while time
+    /// is not advancing (idle, muted, stopped).
+    anchor: Option<Instant>,
+    /// Logical elapsed time at `anchor`, or the frozen elapsed time while
+    /// muted.
+    anchor_elapsed: Seconds,
+    /// [`time_dilation`] in effect since `anchor`.
+    anchor_dilation: f64,
+    /// This ticker's own time scale, on top of the global time dilation.
+    time_scale: f64,
     callback: Option<TickerCallback>,
-    muted_elapsed: Seconds,
     /// Future for the currently active ticker run.
     ///
     /// Created by `start` / `start_default`, completed by `stop`, and
@@ -171,6 +235,45 @@ struct TickerInner {
     scheduled_callback_id: Option<CallbackId>,
 }
 
+impl TickerInner {
+    /// Logical elapsed time: time since start at this ticker's scale,
+    /// slowed by the global [`time_dilation`], excluding muted spans.
+    ///
+    /// A dilation change re-anchors on the first reading that sees it, so
+    /// elapsed time stays continuous: the span before that reading keeps the
+    /// old rate, at most one frame late for a ticking ticker.
+    fn current_elapsed(&mut self) -> Seconds {
+        let Some(anchor) = self.anchor else {
+            return self.anchor_elapsed;
+        };
+        let now = Instant::now();
+        let elapsed = Seconds::new(
+            self.anchor_elapsed.value()
+                + now.duration_since(anchor).as_secs_f64() * self.time_scale / self.anchor_dilation,
+        );
+        let dilation = time_dilation();
+        if (dilation - self.anchor_dilation).abs() >= f64::EPSILON {
+            self.anchor = Some(now);
+            self.anchor_elapsed = elapsed;
+            self.anchor_dilation = dilation;
+        }
+        elapsed
+    }
+
+    /// Start advancing from `elapsed`.
+    fn resume_from(&mut self, elapsed: Seconds) {
+        self.anchor_elapsed = elapsed;
+        self.anchor = Some(Instant::now());
+        self.anchor_dilation = time_dilation();
+    }
+
+    /// Stop advancing, keeping the current elapsed time.
+    fn freeze(&mut self) {
+        self.anchor_elapsed = self.current_elapsed();
+        self.anchor = None;
+    }
+}
+
 /// Animation ticker with runtime state management
 ///
 /// A Ticker provides callbacks on every frame, allowing you to drive animations
@@ -246,9 +349,11 @@ pub fn new() -> Self {
             id: next_ticker_id(),
             inner: Arc::new(Mutex::new(TickerInner {
                 state: TickerState::Idle,
-                start_time: None,
+                anchor: None,
+                anchor_elapsed: Seconds::ZERO,
+                anchor_dilation: 1.0,
+                time_scale: 1.0,
                 callback: None,
-                muted_elapsed: Seconds::ZERO,
                 active_future: None,
                 scheduled_callback_id: None,
             })),
@@ -273,9 +378,11 @@ pub fn new_with_scheduler(scheduler: Arc<crate::scheduler::Scheduler>) -> Self {
             id: next_ticker_id(),
             inner: Arc::new(Mutex::new(TickerInner {
                 state: TickerState::Idle,
-                start_time: None,
+                anchor: None,
+                anchor_elapsed: Seconds::ZERO,
+                anchor_dilation: 1.0,
+                time_scale: 1.0,
                 callback: None,
-                muted_elapsed: Seconds::ZERO,
                 active_future: None,
                 scheduled_callback_id: None,
             })),
@@ -327,7 +434,7 @@ pub fn dispose(&mut self) {
             let mut inner = self.inner.lock();
             inner.state = TickerState::Stopped;
             inner.callback = None;
-            inner.start_time = None;
+            inner.anchor = None;
             (
                 inner.scheduled_callback_id.take(),
                 inner.active_future.take(),
@@ -424,8 +531,7 @@ fn start_inner(&mut self, callback: Option<TickerCallback>) -> TickerFuture {
                 return TickerFuture::complete();
             }
             inner.state = TickerState::Active;
-            inner.start_time = Some(Instant::now());
-            inner.muted_elapsed = Seconds::ZERO;
+            inner.resume_from(Seconds::ZERO);
             inner.active_future = Some(future.clone());
         }
         // Auto-scheduling tickers register a transient frame callback now.
@@ -483,9 +589,7 @@ pub fn mute(&mut self) {
         let pending_id = {
             let mut inner = self.inner.lock();
             if inner.state == TickerState::Active {
-                if let Some(start) = inner.start_time {
-                    inner.muted_elapsed = Seconds::new(start.elapsed().as_secs_f64());
-                }
+                inner.freeze();
                 inner.state = TickerState::Muted;
                 inner.scheduled_callback_id.take()
             } else {
@@ -512,23 +616,53 @@ pub fn unmute(&mut self) {
         {
             let mut inner = self.inner.lock();
             if inner.state == TickerState::Muted {
-                let now = Instant::now();
-                let adjusted_start = now
-                    .checked_sub(std::time::Duration::from_secs_f64(
-                        inner.muted_elapsed.value(),
-                    ))
-                    .expect(
-                        "BUG: muted_elapsed was measured as (mute instant - start_time), so \
-                         subtracting it from a later `now` cannot precede the ticker's start \
-                         instant, which is a valid Instant",
-                    );
-                inner.start_time = Some(adjusted_start);
+                let elapsed = inner.anchor_elapsed;
+                inner.resume_from(elapsed);
                 inner.state = TickerState::Active;
             }
         }
         self.schedule_tick_if_active();
     }
 
+    /// Mute or unmute the ticker — Flutter's `Ticker.muted` setter.
+    ///
+    /// Equivalent to [`mute`](Self::mute) / [`unmute`](Self::unmute).
+    pub fn set_muted(&mut self, muted: bool) {
+        if muted {
+            self.mute();
+        } else {
+            self.unmute();
+        }
+    }
+
+    /// Scale the elapsed time this ticker reports, e.g. `0.25` to run its
+    /// animation at quarter speed.
+    ///
+    /// Combines with the global
+    /// [`set_time_dilation`](crate::config::set_time_dilation): the ticker
+    /// advances at `scale / time_dilation()`. The change applies from now
+    /// on: time already elapsed is kept, so the animation slows down rather
+    /// than jumping.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidTimeScale`] unless `scale` is positive and finite.
+    pub fn set_time_scale(&mut self, scale: f64) -> Result<(), InvalidTimeScale> {
+        let scale = validate_time_scale(scale)?;
+        let mut inner = self.inner.lock();
+        if inner.anchor.is_some() {
+            let elapsed = inner.current_elapsed();
+            inner.resume_from(elapsed);
+        }
+        inner.time_scale = scale;
+        Ok(())
+    }
+
+    /// This ticker's own time scale (default `1.0`).
+    pub fn time_scale(&self) -> f64 {
+        self.inner.lock().time_scale
+    }
+
     /// Toggle mute state
     pub fn toggle_mute(&mut self) {
         let state = self.inner.lock().state;
@@ -553,10 +687,7 @@ pub fn tick<T: TickerProvider>(&self, _provider: &T) {
             return;
         }
 
-        let Some(start) = inner.start_time else {
-            return;
-        };
-        let elapsed = start.elapsed().as_secs_f64();
+        let elapsed = inner.current_elapsed().value();
 
         // Take callback to avoid borrowing inner during invocation
         let Some(mut callback) = inner.callback.take() else {
@@ -600,13 +731,10 @@ pub fn is_running(&self) -> bool {
 
     /// Get elapsed time as type-safe Seconds
     pub fn elapsed(&self) -> Seconds {
-        let inner = self.inner.lock();
+        let mut inner = self.inner.lock();
         match inner.state {
             TickerState::Idle | TickerState::Stopped => Seconds::ZERO,
-            TickerState::Muted => inner.muted_elapsed,
-            TickerState::Active => inner
-                .start_time
-                .map_or(Seconds::ZERO, |s| Seconds::new(s.elapsed().as_secs_f64())),
+            TickerState::Muted | TickerState::Active => inner.current_elapsed(),
         }
     }
 
@@ -627,9 +755,9 @@ pub fn reset(&mut self) {
         let (pending_id, active_future) = {
             let mut inner = self.inner.lock();
             inner.state = TickerState::Idle;
-            inner.start_time = None;
+            inner.anchor = None;
+            inner.anchor_elapsed = Seconds::ZERO;
             inner.callback = None;
-            inner.muted_elapsed = Seconds::ZERO;
             (
                 inner.scheduled_callback_id.take(),
                 inner.active_future.take(),
@@ -708,10 +836,7 @@ fn tick_and_reschedule_static(
             if guard.state != TickerState::Active {
                 return;
             }
-            let Some(start) = guard.start_time else {
-                return;
-            };
-            let elapsed = start.elapsed().as_secs_f64();
+            let elapsed = guard.current_elapsed().value();
             // Take callback to release the lock before invoking. Restore
             // afterwards if still active.
             (elapsed, guard.callback.take())
@@ -1639,4 +1764,69 @@ fn test_auto_scheduling_ticker_elapsed() {
         assert!(elapsed.value() > 0.0);
         assert!(elapsed.value() < 1.0);
     }
+
+    #[test]
+    fn test_muted_ticker_skips_callbacks_and_resumes_at_frozen_elapsed() {
+        use std::time::Duration;
+
+        let ticks = Arc::new(Mutex::new(Vec::new()));
+        let mut ticker = Ticker::new();
+        let sink = Arc::clone(&ticks);
+        ticker.start(move |elapsed| sink.lock().push(elapsed));
+
+        std::thread::sleep(Duration::from_millis(20));
+        ticker.tick(&MockProvider);
+        ticker.set_muted(true);
+        let frozen = ticker.elapsed();
+        assert_eq!(ticker.state(), TickerState::Muted);
+
+        // Muted: no callbacks, and elapsed doesn't move.
+        std::thread::sleep(Duration::from_millis(50));
+        ticker.tick(&MockProvider);
+        assert_eq!(ticks.lock().len(), 1);
+        assert_eq!(ticker.elapsed(), frozen);
+
+        // Unmuted: picks up at the frozen time, not the wall-clock time.
+        ticker.set_muted(false);
+        ticker.tick(&MockProvider);
+        let resumed = ticks.lock()[1];
+        assert!(resumed >= frozen.value());
+        assert!(
+            resumed < frozen.value() + 0.04,
+            "the 50ms muted span leaked into elapsed: {resumed} vs {}",
+            frozen.value()
+        );
+    }
+
+    #[test]
+    fn test_time_scale_slows_elapsed_without_jumping() {
+        use std::time::Duration;
+
+        let mut ticker = Ticker::new();
+        ticker.start(|_| {});
+        std::thread::sleep(Duration::from_millis(20));
+
+        let before = ticker.elapsed();
+        ticker
+            .set_time_scale(0.25)
+            .expect("positive finite time scale");
+        assert!(ticker.elapsed() >= before, "rescaling never rewinds time");
+
+        let wall = Instant::now();
+        std::thread::sleep(Duration::from_millis(40));
+        let scaled = ticker.elapsed().value() - before.value();
+        let real = wall.elapsed().as_secs_f64();
+        assert!(scaled >= 0.040 * 0.25);
+        assert!(scaled <= real * 0.25 + 0.005, "{scaled} vs real {real}");
+
+        assert!(matches!(
+            ticker.set_time_scale(0.0),
+            Err(InvalidTimeScale::NonPositive(_))
+        ));
+        assert!(matches!(
+            ticker.set_time_scale(f64::NAN),
+            Err(InvalidTimeScale::NonFinite(_))
+        ));
+        assert!((ticker.time_scale() - 0.25).abs() < f64::EPSILON);
+    }
 }
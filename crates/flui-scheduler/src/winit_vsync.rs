@@ -0,0 +1,384 @@
+//! Winit-backed vsync - redraw requests with an occlusion fallback timer
+//!
+//! [`WinitVsyncScheduler`] turns `request_frame()` calls into winit redraw
+//! requests and the resulting `RedrawRequested` events into vsync ticks:
+//!
+//! - **Coalescing**: any number of `request_frame()` calls before the next
+//!   tick produce one redraw request and one vsync callback.
+//! - **Fallback**: while the window is occluded, compositors stop delivering
+//!   `RedrawRequested`. A pending frame is then ticked by a timer pegged to
+//!   the display refresh rate instead of waiting forever.
+//!
+//! The event loop is reached through [`RedrawProxy`], implemented for winit's
+//! [`EventLoopProxy`]; tests and embedders can supply their own.
+//!
+//! ```rust,ignore
+//! use std::sync::Arc;
+//! use flui_scheduler::winit_vsync::WinitVsyncScheduler;
+//!
+//! let vsync = Arc::new(WinitVsyncScheduler::try_new(event_loop.create_proxy(), 60)?);
+//! vsync.vsync().set_callback(|now| Scheduler::instance().handle_begin_frame(now));
+//! vsync.spawn_fallback_timer()?;
+//!
+//! // In `ApplicationHandler::user_event` / `WindowEvent::RedrawRequested`:
+//! vsync.handle_redraw();
+//! ```
+
+use std::time::Duration;
+
+use parking_lot::{Condvar, Mutex};
+use web_time::Instant;
+use winit::event_loop::EventLoopProxy;
+
+use crate::vsync::{InvalidVsyncConfig, VsyncScheduler};
+
+/// How long the fallback timer sleeps when no deadline is armed before it
+/// re-checks whether its scheduler is still alive.
+#[cfg(not(target_arch = "wasm32"))]
+const FALLBACK_IDLE_RECHECK: Duration = Duration::from_millis(250);
+
+/// Wakes the event loop so it delivers a redraw.
+pub trait RedrawProxy: Send + Sync {
+    /// Ask the event loop for a redraw.
+    ///
+    /// Returns `false` if the loop is gone and the request was dropped.
+    fn request_redraw(&self) -> bool;
+}
+
+impl<T: Default + Send + 'static> RedrawProxy for EventLoopProxy<T> {
+    fn request_redraw(&self) -> bool {
+        self.send_event(T::default()).is_ok()
+    }
+}
+
+/// Pacing state behind one lock.
+#[derive(Debug, Default)]
+struct PacingState {
+    /// A redraw was requested and its tick has not fired yet.
+    pending: bool,
+    /// When the pending request was made.
+    requested_at: Option<Instant>,
+    /// The window is occluded; redraws may never arrive.
+    occluded: bool,
+    /// When the last tick fired.
+    last_tick: Option<Instant>,
+}
+
+/// Vsync source driven by winit redraws, with a refresh-pegged fallback
+/// timer for occluded windows.
+///
+/// # Examples
+///
+/// ```
+/// use flui_scheduler::winit_vsync::{RedrawProxy, WinitVsyncScheduler};
+///
+/// struct Proxy;
+/// impl RedrawProxy for Proxy {
+///     fn request_redraw(&self) -> bool {
+///         true
+///     }
+/// }
+///
+/// let vsync = WinitVsyncScheduler::try_new(Proxy, 60).expect("refresh > 0");
+/// assert!(vsync.request_frame());
+/// assert!(!vsync.request_frame()); // coalesced
+/// assert!(vsync.handle_redraw());
+/// ```
+pub struct WinitVsyncScheduler<P> {
+    proxy: P,
+    vsync: VsyncScheduler,
+    state: Mutex<PacingState>,
+    /// Signalled whenever the fallback deadline may have changed.
+    rearm: Condvar,
+}
+
+impl<P: RedrawProxy> WinitVsyncScheduler<P> {
+    /// Create a scheduler that requests redraws through `proxy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidVsyncConfig::ZeroRefreshRate`] if `refresh_rate == 0`.
+    pub fn try_new(proxy: P, refresh_rate: u32) -> Result<Self, InvalidVsyncConfig> {
+        Ok(Self {
+            proxy,
+            vsync: VsyncScheduler::try_new(refresh_rate)?,
+            state: Mutex::new(PacingState::default()),
+            rearm: Condvar::new(),
+        })
+    }
+
+    /// The underlying vsync scheduler: callback, refresh rate and stats.
+    pub fn vsync(&self) -> &VsyncScheduler {
+        &self.vsync
+    }
+
+    /// The redraw proxy.
+    pub fn proxy(&self) -> &P {
+        &self.proxy
+    }
+
+    /// Request a frame.
+    ///
+    /// Returns `true` if this call issued a redraw request, `false` if one
+    /// was already pending (coalesced) or the event loop is gone.
+    pub fn request_frame(&self) -> bool {
+        {
+            let mut state = self.state.lock();
+            if state.pending {
+                return false;
+            }
+            state.pending = true;
+            state.requested_at = Some(Instant::now());
+        }
+        self.rearm.notify_all();
+
+        if self.proxy.request_redraw() {
+            return true;
+        }
+
+        tracing::warn!("WinitVsyncScheduler: event loop closed, redraw request dropped");
+        let mut state = self.state.lock();
+        state.pending = false;
+        state.requested_at = None;
+        false
+    }
+
+    /// Whether a requested frame has not ticked yet.
+    pub fn is_frame_pending(&self) -> bool {
+        self.state.lock().pending
+    }
+
+    /// Handle a redraw delivered by the event loop.
+    ///
+    /// Fires the vsync callback if a frame was pending. Returns `false` for
+    /// redraws nobody asked for, or whose frame the fallback already ticked.
+    pub fn handle_redraw(&self) -> bool {
+        self.tick(Instant::now())
+    }
+
+    /// Mark the window occluded (or visible again).
+    ///
+    /// Redraw requests are still forwarded while occluded, but a pending
+    /// frame no longer waits on them: the fallback timer ticks it.
+    pub fn set_occluded(&self, occluded: bool) {
+        self.state.lock().occluded = occluded;
+        self.rearm.notify_all();
+    }
+
+    /// Whether the window is occluded.
+    pub fn is_occluded(&self) -> bool {
+        self.state.lock().occluded
+    }
+
+    /// When the fallback timer will tick the pending frame.
+    ///
+    /// `None` unless the window is occluded with a frame pending. The
+    /// deadline is the first refresh boundary after the request, counted
+    /// from the last tick.
+    pub fn fallback_deadline(&self) -> Option<Instant> {
+        self.fallback_deadline_locked(&self.state.lock())
+    }
+
+    /// Tick the pending frame if its fallback deadline has passed.
+    ///
+    /// Returns `true` if the vsync callback fired.
+    pub fn poll_fallback(&self, now: Instant) -> bool {
+        match self.fallback_deadline() {
+            Some(deadline) if deadline <= now => self.tick(now),
+            _ => false,
+        }
+    }
+
+    fn fallback_deadline_locked(&self, state: &PacingState) -> Option<Instant> {
+        if !(state.occluded && state.pending) {
+            return None;
+        }
+        let requested_at = state.requested_at?;
+        let interval = self.vsync.frame_interval_duration();
+        let Some(last_tick) = state.last_tick else {
+            return Some(requested_at + interval);
+        };
+        let since_tick = requested_at.saturating_duration_since(last_tick);
+        let ticks = since_tick.as_nanos() / interval.as_nanos().max(1) + 1;
+        let ticks = u32::try_from(ticks).unwrap_or(u32::MAX);
+        Some(last_tick + interval.saturating_mul(ticks))
+    }
+
+    fn tick(&self, now: Instant) -> bool {
+        {
+            let mut state = self.state.lock();
+            if !state.pending {
+                return false;
+            }
+            state.pending = false;
+            state.requested_at = None;
+            state.last_tick = Some(now);
+        }
+        self.vsync.signal_vsync();
+        true
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<P: RedrawProxy + 'static> WinitVsyncScheduler<P> {
+    /// Spawn the fallback timer thread.
+    ///
+    /// The thread sleeps until [`fallback_deadline`](Self::fallback_deadline)
+    /// and ticks the pending frame from there, so the vsync callback runs on
+    /// the timer thread in that case. It exits once the scheduler is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns the OS error if the thread cannot be spawned.
+    pub fn spawn_fallback_timer(
+        self: &std::sync::Arc<Self>,
+    ) -> std::io::Result<std::thread::JoinHandle<()>> {
+        let weak = std::sync::Arc::downgrade(self);
+        std::thread::Builder::new()
+            .name("flui-vsync-fallback".into())
+            .spawn(move || {
+                while let Some(this) = weak.upgrade() {
+                    this.wait_for_fallback();
+                }
+            })
+    }
+
+    /// One timer iteration: sleep until the deadline (or a re-arm), then tick.
+    fn wait_for_fallback(&self) {
+        let mut state = self.state.lock();
+        let Some(deadline) = self.fallback_deadline_locked(&state) else {
+            self.rearm.wait_for(&mut state, FALLBACK_IDLE_RECHECK);
+            return;
+        };
+        let now = Instant::now();
+        if let Some(remaining) = deadline.checked_duration_since(now)
+            && !remaining.is_zero()
+        {
+            self.rearm.wait_for(&mut state, remaining);
+            return;
+        }
+        drop(state);
+        self.poll_fallback(now);
+    }
+}
+
+impl<P> std::fmt::Debug for WinitVsyncScheduler<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.lock();
+        f.debug_struct("WinitVsyncScheduler")
+            .field("refresh_rate", &self.vsync.refresh_rate())
+            .field("pending", &state.pending)
+            .field("occluded", &state.occluded)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    };
+
+    use super::*;
+
+    /// Counts redraw requests; never delivers them.
+    #[derive(Default)]
+    struct MockProxy {
+        requests: AtomicU32,
+    }
+
+    impl RedrawProxy for MockProxy {
+        fn request_redraw(&self) -> bool {
+            self.requests.fetch_add(1, Ordering::Relaxed);
+            true
+        }
+    }
+
+    fn counting(vsync: &WinitVsyncScheduler<MockProxy>) -> Arc<AtomicU32> {
+        let ticks = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&ticks);
+        vsync.vsync().set_callback(move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+        ticks
+    }
+
+    #[test]
+    fn requests_within_one_vsync_coalesce_into_one_callback() {
+        let vsync = WinitVsyncScheduler::try_new(MockProxy::default(), 60).expect("refresh > 0");
+        let ticks = counting(&vsync);
+
+        assert!(vsync.request_frame());
+        assert!(!vsync.request_frame());
+        assert!(!vsync.request_frame());
+        assert_eq!(vsync.proxy().requests.load(Ordering::Relaxed), 1);
+
+        assert!(vsync.handle_redraw());
+        assert!(!vsync.handle_redraw(), "a spurious redraw must not tick");
+        assert_eq!(ticks.load(Ordering::Relaxed), 1);
+
+        assert!(
+            vsync.request_frame(),
+            "the next vsync takes a fresh request"
+        );
+        assert_eq!(vsync.proxy().requests.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn occluded_window_ticks_on_the_refresh_pegged_fallback() {
+        let vsync = WinitVsyncScheduler::try_new(MockProxy::default(), 60).expect("refresh > 0");
+        let ticks = counting(&vsync);
+        let interval = vsync.vsync().frame_interval_duration();
+
+        assert!(vsync.request_frame());
+        assert_eq!(
+            vsync.fallback_deadline(),
+            None,
+            "visible: wait for the redraw"
+        );
+        assert!(vsync.handle_redraw());
+        let last_tick = Instant::now();
+
+        vsync.set_occluded(true);
+        assert!(vsync.request_frame());
+        let deadline = vsync
+            .fallback_deadline()
+            .expect("occluded + pending arms the timer");
+        assert!(deadline > last_tick);
+        assert!(deadline.duration_since(last_tick) <= interval * 2);
+
+        let early = deadline
+            .checked_sub(Duration::from_micros(1))
+            .expect("BUG: deadline > 1µs");
+        assert!(!vsync.poll_fallback(early));
+        assert!(vsync.poll_fallback(deadline));
+        assert_eq!(ticks.load(Ordering::Relaxed), 2);
+
+        assert!(!vsync.handle_redraw(), "the late redraw was already ticked");
+        assert_eq!(ticks.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn fallback_timer_thread_ticks_an_occluded_frame() {
+        let vsync =
+            Arc::new(WinitVsyncScheduler::try_new(MockProxy::default(), 120).expect("refresh > 0"));
+        let ticks = counting(&vsync);
+        let timer = vsync.spawn_fallback_timer().expect("spawn timer");
+
+        vsync.set_occluded(true);
+        assert!(vsync.request_frame());
+
+        let give_up = Instant::now() + Duration::from_secs(2);
+        while ticks.load(Ordering::Relaxed) == 0 && Instant::now() < give_up {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(ticks.load(Ordering::Relaxed), 1);
+        assert!(!vsync.is_frame_pending());
+
+        drop(vsync);
+        timer
+            .join()
+            .expect("timer exits once the scheduler is dropped");
+    }
+}
@@ -21,13 +21,13 @@
 };
 
 use flui_scheduler::{
-    FrameBudget,
+    FrameBudget, Instant,
     config::PerformanceMode,
     duration::{FrameDuration, Milliseconds},
     frame::{AppLifecycleState, SchedulerPhase},
     scheduler::{FrameSkipPolicy, Scheduler, SchedulerBuilder},
     task::{Priority, TaskQueue},
-    ticker::{Ticker, TickerCanceled, TickerFuture, TickerState},
+    ticker::{Ticker, TickerCanceled, TickerFuture, TickerProvider, TickerState},
     vsync::{VsyncMode, VsyncScheduler},
 };
 
@@ -3046,3 +3046,48 @@ fn test_ticker_state_can_start_values() {
 fn test_ticker_state_default_idle() {
     assert_eq!(TickerState::default(), TickerState::Idle);
 }
+
+/// The global time dilation slows every ticker at once. Lives here, not in
+/// the unit tests, because it is process-wide and would skew timing
+/// assertions in tests running alongside it.
+#[test]
+fn test_time_dilation_slows_tickers() {
+    use flui_scheduler::config::{set_time_dilation, time_dilation};
+
+    let mut slow = Ticker::new();
+    let mut slower = Ticker::new();
+    slow.start(|_| {});
+    slower.start(|_| {});
+    slower
+        .set_time_scale(0.5)
+        .expect("positive finite time scale");
+    std::thread::sleep(Duration::from_millis(10));
+    let before_change = slow.elapsed();
+
+    // The global time scale is the reciprocal of the time dilation.
+    Scheduler::set_global_time_scale(0.5).expect("positive finite time scale");
+    assert!((time_dilation() - 2.0).abs() < f64::EPSILON);
+    assert!((Scheduler::global_time_scale() - 0.5).abs() < f64::EPSILON);
+
+    let wall = Instant::now();
+    let (slow_start, slower_start) = (slow.elapsed(), slower.elapsed());
+    std::thread::sleep(Duration::from_millis(40));
+    let real = wall.elapsed().as_secs_f64();
+    let slow_delta = slow.elapsed().value() - slow_start.value();
+    let slower_delta = slower.elapsed().value() - slower_start.value();
+
+    set_time_dilation(1.0).expect("positive finite time dilation");
+
+    assert!(
+        slow_start >= before_change,
+        "a dilation change never rewinds a ticker"
+    );
+    assert!(
+        slow_delta <= real * 0.5 + 0.005,
+        "{slow_delta} vs real {real}"
+    );
+    assert!(slow_delta >= 0.040 * 0.5);
+    // The per-ticker scale multiplies with the dilated rate.
+    assert!(slower_delta <= real * 0.25 + 0.005);
+    assert!(slower_delta >= 0.040 * 0.25);
+}
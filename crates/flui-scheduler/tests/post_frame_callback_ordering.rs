@@ -140,6 +140,63 @@ fn a_post_frame_callback_registered_from_a_post_frame_callback_defers_to_the_nex
     assert_eq!(log.get(), vec!["outer", "inner"]);
 }
 
+/// Callbacks queued for the same frame run in registration order.
+#[test]
+fn post_frame_callbacks_run_in_registration_order() {
+    let scheduler = Scheduler::new();
+    let log = Log::default();
+
+    for name in ["first", "second", "third"] {
+        let log = log.clone();
+        scheduler.add_post_frame_callback(Box::new(move |_| log.push(name)));
+    }
+
+    scheduler.drive_frame(Instant::now(), || {});
+    assert_eq!(log.get(), vec!["first", "second", "third"]);
+}
+
+/// A queued callback can be cancelled through the id it was registered with;
+/// once it is gone, cancelling again reports `false`.
+#[test]
+fn a_cancelled_post_frame_callback_never_runs() {
+    let scheduler = Scheduler::new();
+    let log = Log::default();
+
+    let log_cb = log.clone();
+    let id = scheduler.add_post_frame_callback(Box::new(move |_| log_cb.push("cancelled")));
+    assert!(scheduler.cancel_post_frame_callback(id));
+
+    scheduler.drive_frame(Instant::now(), || {});
+    assert!(log.get().is_empty());
+    assert!(!scheduler.cancel_post_frame_callback(id));
+}
+
+/// Cancelling a callback that was already drained into the running frame still
+/// skips it, so an earlier callback can veto a later one.
+#[test]
+fn a_post_frame_callback_cancelled_mid_frame_is_skipped() {
+    let scheduler = Scheduler::new();
+    let log = Log::default();
+    let victim = Arc::new(Mutex::new(None));
+
+    let canceller = scheduler.clone();
+    let victim_id = Arc::clone(&victim);
+    let log_first = log.clone();
+    scheduler.add_post_frame_callback(Box::new(move |_| {
+        log_first.push("first");
+        let id = victim_id
+            .lock()
+            .expect("victim registered before the frame");
+        assert!(!canceller.cancel_post_frame_callback(id), "already drained");
+    }));
+    let log_victim = log.clone();
+    *victim.lock() =
+        Some(scheduler.add_post_frame_callback(Box::new(move |_| log_victim.push("victim"))));
+
+    scheduler.drive_frame(Instant::now(), || {});
+    assert_eq!(log.get(), vec!["first"]);
+}
+
 /// The frame closes cleanly.
 #[test]
 fn phase_is_idle_after_a_successful_frame() {
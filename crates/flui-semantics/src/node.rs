@@ -66,10 +66,13 @@ pub struct SemanticsNode {
     config: SemanticsConfiguration,
 
     // ========== Geometry ==========
-    /// Bounding rectangle in global coordinates.
+    /// Bounding rectangle in this node's coordinate space (global when no
+    /// node on the path from the root has a [`transform`](Self::transform);
+    /// see [`SemanticsTree::global_rect`](crate::SemanticsTree::global_rect)).
     rect: Rect<Pixels>,
 
-    /// Transform matrix.
+    /// Transform from this node's coordinate space into its parent's
+    /// (`None` = identity).
     ///
     /// Stored as the workspace-canonical [`Matrix4`] from `flui_types`
     /// — the same representation `to_node_data()` exports. Previously
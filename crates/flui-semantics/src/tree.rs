@@ -9,6 +9,7 @@
     TreeNav, TreeRead, TreeWrite,
     iter::{Ancestors, DescendantsWithDepth},
 };
+use flui_types::{Matrix4, Offset, Pixels, Rect};
 use slab::Slab;
 
 use crate::node::SemanticsNode;
@@ -309,6 +310,70 @@ pub fn children(&self, id: SemanticsId) -> Option<&[SemanticsId]> {
         self.get(id).map(SemanticsNode::children)
     }
 
+    // ========== Geometry ==========
+
+    /// Returns the transform from `id`'s coordinate space to global
+    /// coordinates: the node's own transform, then each ancestor's up to
+    /// the root. Nodes without a transform contribute identity.
+    pub fn global_transform(&self, id: SemanticsId) -> Option<Matrix4> {
+        self.get(id)?;
+        Some(
+            TreeNav::ancestors(self, id)
+                .filter_map(|ancestor| self.get(ancestor)?.transform().copied())
+                .fold(Matrix4::IDENTITY, |acc, transform| transform * acc),
+        )
+    }
+
+    /// Returns `id`'s rect in global coordinates (the bounding box of the
+    /// transformed rect when a rotation or skew is involved).
+    pub fn global_rect(&self, id: SemanticsId) -> Option<Rect<Pixels>> {
+        let rect = self.get(id)?.rect();
+        Some(self.global_transform(id)?.transform_rect(&rect))
+    }
+
+    /// Returns the topmost node whose rect contains the global `point`, for
+    /// explore-by-touch.
+    ///
+    /// Later children paint over earlier ones, so they are tested first, and
+    /// a hit child wins over its parent (the innermost match is returned).
+    /// Hidden nodes ([`SemanticsConfiguration::is_hidden`]) are skipped
+    /// together with their subtree; nodes with an empty rect or a
+    /// non-invertible transform are never hit.
+    ///
+    /// [`SemanticsConfiguration::is_hidden`]: crate::SemanticsConfiguration::is_hidden
+    pub fn node_at(&self, point: Offset<Pixels>) -> Option<SemanticsId> {
+        self.hit_test(self.root?, Matrix4::IDENTITY, point)
+    }
+
+    fn hit_test(
+        &self,
+        id: SemanticsId,
+        parent_transform: Matrix4,
+        point: Offset<Pixels>,
+    ) -> Option<SemanticsId> {
+        let node = self.get(id)?;
+        if node.config().is_hidden() {
+            return None;
+        }
+        let transform = match node.transform() {
+            Some(local) => parent_transform * *local,
+            None => parent_transform,
+        };
+
+        if let Some(hit) = node
+            .children()
+            .iter()
+            .rev()
+            .find_map(|&child| self.hit_test(child, transform, point))
+        {
+            return Some(hit);
+        }
+
+        let rect = node.rect();
+        let (x, y) = transform.try_inverse()?.transform_point(point.dx, point.dy);
+        (!rect.is_empty() && rect.contains_offset(Offset::new(x, y))).then_some(id)
+    }
+
     // ========== Dirty Tracking ==========
 
     /// Returns all dirty node ids in the tree.
@@ -874,6 +939,76 @@ fn test_tree_nav_depth() {
         assert_eq!(TreeNav::depth(&tree, child_id), 1);
         assert_eq!(TreeNav::depth(&tree, grandchild_id), 2);
     }
+
+    // ========== Geometry / hit testing ==========
+
+    fn node_with_rect(l: f32, t: f32, r: f32, b: f32) -> SemanticsNode {
+        use flui_types::geometry::px;
+
+        let mut node = SemanticsNode::new();
+        node.set_rect(Rect::from_ltrb(px(l), px(t), px(r), px(b)));
+        node
+    }
+
+    fn at(x: f32, y: f32) -> Offset<Pixels> {
+        Offset::new(Pixels(x), Pixels(y))
+    }
+
+    /// root (0,0)-(200,200)
+    /// ├─ panel, translated by (100,0), local (0,0)-(100,100)
+    /// │  └─ button, local (10,10)-(50,50)  → global (110,10)-(150,50)
+    /// └─ overlay (0,150)-(200,200), painted last
+    fn nested_tree() -> (SemanticsTree, [SemanticsId; 4]) {
+        let mut tree = SemanticsTree::new();
+        let root = tree.insert(node_with_rect(0.0, 0.0, 200.0, 200.0));
+        let mut panel = node_with_rect(0.0, 0.0, 100.0, 100.0);
+        panel.set_transform(Some(Matrix4::translation(100.0, 0.0, 0.0)));
+        let panel = tree.insert(panel);
+        let button = tree.insert(node_with_rect(10.0, 10.0, 50.0, 50.0));
+        let overlay = tree.insert(node_with_rect(0.0, 150.0, 200.0, 200.0));
+        tree.add_child(root, panel);
+        tree.add_child(panel, button);
+        tree.add_child(root, overlay);
+        tree.set_root(Some(root));
+        (tree, [root, panel, button, overlay])
+    }
+
+    #[test]
+    fn global_rect_accumulates_ancestor_transforms() {
+        use flui_types::geometry::px;
+
+        let (mut tree, [root, _, button, _]) = nested_tree();
+        tree.get_mut(root)
+            .unwrap()
+            .set_transform(Some(Matrix4::translation(0.0, 5.0, 0.0)));
+
+        assert_eq!(
+            tree.global_rect(button),
+            Some(Rect::from_ltrb(px(110.0), px(15.0), px(150.0), px(55.0)))
+        );
+        assert_eq!(tree.global_rect(SemanticsId::new(999)), None);
+    }
+
+    #[test]
+    fn node_at_resolves_innermost_topmost_node() {
+        let (tree, [root, panel, button, overlay]) = nested_tree();
+
+        assert_eq!(tree.node_at(at(120.0, 20.0)), Some(button));
+        assert_eq!(tree.node_at(at(180.0, 80.0)), Some(panel));
+        assert_eq!(tree.node_at(at(20.0, 20.0)), Some(root));
+        assert_eq!(tree.node_at(at(120.0, 160.0)), Some(overlay));
+        assert_eq!(tree.node_at(at(250.0, 20.0)), None);
+        assert_eq!(tree.node_at(at(-1.0, -1.0)), None);
+    }
+
+    #[test]
+    fn node_at_skips_hidden_subtrees() {
+        let (mut tree, [root, panel, _, _]) = nested_tree();
+        tree.get_mut(panel).unwrap().config_mut().set_hidden(true);
+
+        assert_eq!(tree.node_at(at(120.0, 20.0)), Some(root));
+        assert_eq!(SemanticsTree::new().node_at(at(0.0, 0.0)), None);
+    }
 }
 
 // ============================================================================
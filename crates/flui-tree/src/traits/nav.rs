@@ -3,12 +3,16 @@
 //! This module provides the [`TreeNav`] trait for tree navigation
 //! operations using advanced Rust type system features.
 
+use std::collections::HashSet;
+use std::ops::ControlFlow;
+
 use flui_foundation::TreeId;
 
 use smallvec::SmallVec;
 
 use crate::{
-    depth::{Depth, INLINE_TREE_DEPTH},
+    depth::{Depth, INLINE_TREE_DEPTH, MAX_TREE_DEPTH},
+    error::{TreeError, TreeResult},
     iter::slot::Slot,
 };
 
@@ -336,11 +340,31 @@ fn find_child_where<P>(&self, parent: I, mut predicate: P) -> Option<I>
 
     /// Find first descendant matching a predicate.
     ///
-    /// Performs depth-first search with early termination.
+    /// Performs depth-first search with early termination. Debug builds walk
+    /// through [`visit_depth_first`](Self::visit_depth_first)'s guard and
+    /// stop (logging the [`TreeError`]) on a cycle or runaway depth.
     fn find_descendant_where<P>(&self, root: I, mut predicate: P) -> Option<I>
     where
         P: FnMut(&Self::Node) -> bool,
     {
+        if cfg!(debug_assertions) {
+            let walk = guarded_walk(self, root, |id, node, _| {
+                if predicate(node) {
+                    ControlFlow::Break(id)
+                } else {
+                    ControlFlow::Continue(())
+                }
+            });
+            return match walk {
+                Ok(ControlFlow::Break(id)) => Some(id),
+                Ok(ControlFlow::Continue(())) => None,
+                Err(error) => {
+                    tracing::error!(%error, "TreeNavExt::find_descendant_where aborted");
+                    None
+                }
+            };
+        }
+
         for (id, _depth) in self.descendants(root) {
             if let Some(node) = self.get(id)
                 && predicate(node)
@@ -353,6 +377,10 @@ fn find_descendant_where<P>(&self, root: I, mut predicate: P) -> Option<I>
 
     /// Visit all nodes in a subtree with a closure using HRTB.
     ///
+    /// Debug builds walk through
+    /// [`visit_depth_first`](Self::visit_depth_first)'s guard and stop
+    /// (logging the [`TreeError`]) on a cycle or runaway depth.
+    ///
     /// # Arguments
     ///
     /// * `root` - Root of subtree to visit
@@ -361,6 +389,13 @@ fn visit_subtree<F>(&self, root: I, mut visitor: F)
     where
         F: FnMut(I, &Self::Node, usize),
     {
+        if cfg!(debug_assertions) {
+            if let Err(error) = self.visit_depth_first(root, visitor) {
+                tracing::error!(%error, "TreeNavExt::visit_subtree aborted");
+            }
+            return;
+        }
+
         for (id, depth) in self.descendants(root) {
             if let Some(node) = self.get(id) {
                 visitor(id, node, depth);
@@ -368,11 +403,58 @@ fn visit_subtree<F>(&self, root: I, mut visitor: F)
         }
     }
 
+    /// Depth-first pre-order visit of the subtree at `root` that refuses to
+    /// follow a corrupted structure.
+    ///
+    /// Walks `children` iteratively (no recursion), so a malformed tree can
+    /// neither overflow the stack nor loop forever:
+    ///
+    /// - a node reached a second time is reported as
+    ///   [`TreeError::CycleDetected`] (in a well-formed tree every node has
+    ///   exactly one path from `root`);
+    /// - a node deeper than [`MAX_TREE_DEPTH`] below `root` is reported as
+    ///   [`TreeError::MaxDepthExceeded`].
+    ///
+    /// Nodes visited before the error was found have already been passed to
+    /// `visitor`. Ids that are not in the tree are skipped.
+    ///
+    /// # Errors
+    ///
+    /// `CycleDetected` or `MaxDepthExceeded`, carrying the offending id's
+    /// [`debug_value`](TreeId::debug_value).
+    fn visit_depth_first<F>(&self, root: I, mut visitor: F) -> TreeResult<()>
+    where
+        F: FnMut(I, &Self::Node, usize),
+    {
+        guarded_walk(self, root, |id, node, depth| {
+            visitor(id, node, depth);
+            ControlFlow::<()>::Continue(())
+        })
+        .map(|_| ())
+    }
+
     /// Count descendants matching a predicate.
+    ///
+    /// Debug builds count through
+    /// [`visit_depth_first`](Self::visit_depth_first)'s guard; on a cycle or
+    /// runaway depth the [`TreeError`] is logged and the partial count
+    /// returned.
     fn count_descendants_where<P>(&self, root: I, mut predicate: P) -> usize
     where
         P: FnMut(&Self::Node) -> bool,
     {
+        if cfg!(debug_assertions) {
+            let mut count = 0;
+            if let Err(error) = self.visit_depth_first(root, |_, node, _| {
+                if predicate(node) {
+                    count += 1;
+                }
+            }) {
+                tracing::error!(%error, "TreeNavExt::count_descendants_where aborted");
+            }
+            return count;
+        }
+
         self.descendants(root)
             .filter_map(|(id, _)| self.get(id))
             .filter(|node| predicate(node))
@@ -420,6 +502,42 @@ fn first_and_last_child(&self, parent: I) -> Option<(I, I)> {
 // Blanket implementation for all TreeNav types
 impl<I: TreeId, T: TreeNav<I>> TreeNavExt<I> for T {}
 
+/// The cycle- and depth-guarded walk behind
+/// [`TreeNavExt::visit_depth_first`]; `visit` may break early.
+fn guarded_walk<I, T, B, F>(tree: &T, root: I, mut visit: F) -> TreeResult<ControlFlow<B>>
+where
+    I: TreeId,
+    T: TreeNav<I> + ?Sized,
+    F: FnMut(I, &T::Node, usize) -> ControlFlow<B>,
+{
+    let mut visited: HashSet<I> = HashSet::new();
+    let mut stack: SmallVec<[(I, usize); INLINE_TREE_DEPTH]> = SmallVec::new();
+    stack.push((root, 0));
+
+    while let Some((id, depth)) = stack.pop() {
+        if depth > MAX_TREE_DEPTH {
+            return Err(TreeError::max_depth_exceeded(
+                id.debug_value(),
+                MAX_TREE_DEPTH,
+            ));
+        }
+        if !visited.insert(id) {
+            return Err(TreeError::cycle_detected(id.debug_value()));
+        }
+        let Some(node) = tree.get(id) else {
+            continue;
+        };
+        if let ControlFlow::Break(value) = visit(id, node, depth) {
+            return Ok(ControlFlow::Break(value));
+        }
+
+        // Reverse push so the first child is visited first.
+        let children: SmallVec<[I; 8]> = tree.children(id).collect();
+        stack.extend(children.into_iter().rev().map(|child| (child, depth + 1)));
+    }
+    Ok(ControlFlow::Continue(()))
+}
+
 // ============================================================================
 // BLANKET IMPLEMENTATIONS
 // ============================================================================
@@ -718,4 +836,144 @@ fn test_lowest_common_ancestor() {
         );
         assert_eq!(tree.lowest_common_ancestor(left_child, left), Some(left));
     }
+
+    // ------------------------------------------------------------------
+    // Guarded traversal
+    // ------------------------------------------------------------------
+
+    /// Adjacency-list graph with no parent links, so it can hold shapes a
+    /// real tree never should (cycles, shared children).
+    struct GraphTree {
+        children: std::collections::HashMap<ElementId, Vec<ElementId>>,
+    }
+
+    impl GraphTree {
+        fn new(edges: &[(usize, usize)]) -> Self {
+            let mut children: std::collections::HashMap<ElementId, Vec<ElementId>> =
+                std::collections::HashMap::new();
+            for &(parent, child) in edges {
+                children.entry(ElementId::new(child)).or_default();
+                children
+                    .entry(ElementId::new(parent))
+                    .or_default()
+                    .push(ElementId::new(child));
+            }
+            Self { children }
+        }
+    }
+
+    impl super::super::TreeRead<ElementId> for GraphTree {
+        type Node = Vec<ElementId>;
+
+        fn get(&self, id: ElementId) -> Option<&Vec<ElementId>> {
+            self.children.get(&id)
+        }
+
+        fn len(&self) -> usize {
+            self.children.len()
+        }
+
+        fn node_ids(&self) -> impl Iterator<Item = ElementId> + '_ {
+            self.children.keys().copied()
+        }
+    }
+
+    impl TreeNav<ElementId> for GraphTree {
+        fn parent(&self, _id: ElementId) -> Option<ElementId> {
+            None
+        }
+
+        fn children(&self, id: ElementId) -> impl Iterator<Item = ElementId> + '_ {
+            self.children.get(&id).into_iter().flatten().copied()
+        }
+
+        fn ancestors(&self, start: ElementId) -> impl Iterator<Item = ElementId> + '_ {
+            Ancestors::new(self, start)
+        }
+
+        fn descendants(&self, root: ElementId) -> impl Iterator<Item = (ElementId, usize)> + '_ {
+            DescendantsWithDepth::new(self, root)
+        }
+
+        fn siblings(&self, _id: ElementId) -> impl Iterator<Item = ElementId> + '_ {
+            std::iter::empty()
+        }
+    }
+
+    fn id(n: usize) -> ElementId {
+        ElementId::new(n)
+    }
+
+    #[test]
+    fn test_visit_depth_first_preorder_on_well_formed_tree() {
+        let tree = GraphTree::new(&[(1, 2), (1, 3), (2, 4)]);
+        let mut seen = Vec::new();
+        tree.visit_depth_first(id(1), |node, _, depth| seen.push((node, depth)))
+            .unwrap();
+        assert_eq!(seen, vec![(id(1), 0), (id(2), 1), (id(4), 2), (id(3), 1)]);
+    }
+
+    #[test]
+    fn test_visit_depth_first_reports_cycle_instead_of_hanging() {
+        // 1 → 2 → 3 → 1
+        let tree = GraphTree::new(&[(1, 2), (2, 3), (3, 1)]);
+        let mut visits = 0;
+        let result = tree.visit_depth_first(id(1), |_, _, _| visits += 1);
+
+        assert_eq!(result, Err(TreeError::CycleDetected(id(1).debug_value())));
+        assert_eq!(
+            visits, 3,
+            "each node is visited once before the cycle is found"
+        );
+    }
+
+    #[test]
+    fn test_visit_depth_first_reports_self_loop() {
+        let tree = GraphTree::new(&[(1, 2), (2, 2)]);
+        assert_eq!(
+            tree.visit_depth_first(id(1), |_, _, _| {}),
+            Err(TreeError::CycleDetected(id(2).debug_value()))
+        );
+    }
+
+    #[test]
+    fn test_visit_depth_first_enforces_max_depth() {
+        let edges: Vec<_> = (1..=MAX_TREE_DEPTH + 10).map(|n| (n, n + 1)).collect();
+        let tree = GraphTree::new(&edges);
+
+        // The node at depth MAX_TREE_DEPTH + 1 below the root is id
+        // MAX_TREE_DEPTH + 2.
+        assert_eq!(
+            tree.visit_depth_first(id(1), |_, _, _| {}),
+            Err(TreeError::MaxDepthExceeded {
+                element: id(MAX_TREE_DEPTH + 2).debug_value(),
+                max: MAX_TREE_DEPTH,
+            })
+        );
+
+        // Exactly MAX_TREE_DEPTH deep is still fine.
+        let edges: Vec<_> = (1..=MAX_TREE_DEPTH).map(|n| (n, n + 1)).collect();
+        assert!(
+            GraphTree::new(&edges)
+                .visit_depth_first(id(1), |_, _, _| {})
+                .is_ok()
+        );
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn test_convenience_walks_terminate_on_cycle_in_debug() {
+        let tree = GraphTree::new(&[(1, 2), (2, 3), (3, 1)]);
+
+        let mut visits = 0;
+        tree.visit_subtree(id(1), |_, _, _| visits += 1);
+        assert_eq!(visits, 3);
+
+        assert_eq!(tree.count_descendants_where(id(1), |_| true), 3);
+        assert_eq!(tree.find_descendant_where(id(1), Vec::is_empty), None);
+        assert_eq!(
+            tree.find_descendant_where(id(1), |children| children.contains(&id(1))),
+            Some(id(3))
+        );
+    }
 }
@@ -6,7 +6,7 @@
 use flui_foundation::TreeId;
 
 use super::TreeRead;
-use crate::depth::INLINE_TREE_DEPTH;
+use crate::depth::{Depth, DepthAware, DepthError, INLINE_TREE_DEPTH, MAX_TREE_DEPTH};
 use crate::error::{TreeError, TreeResult};
 
 /// Mutable access to tree nodes and structure.
@@ -394,6 +394,42 @@ fn move_children(&mut self, from: I, to: I) -> TreeResult<()>
         Ok(())
     }
 
+    /// Rewrites the stored depth of `root` and every node below it so that
+    /// `root` sits at `new_depth` — the fix-up after a subtree is
+    /// reparented, so depth-ordered build/layout scheduling stays correct.
+    ///
+    /// The subtree is walked once; nothing is written unless its deepest
+    /// node still fits within [`MAX_TREE_DEPTH`] at the new position.
+    /// Returns how many nodes were updated (0 if `root` is not in the tree).
+    ///
+    /// # Errors
+    ///
+    /// [`DepthError::MaxDepthExceeded`] if the move would push a node past
+    /// [`MAX_TREE_DEPTH`]; the tree is left untouched.
+    fn rebalance_depths(&mut self, root: I, new_depth: Depth) -> Result<usize, DepthError>
+    where
+        Self: Sized,
+        Self::Node: DepthAware,
+    {
+        let subtree: Vec<(I, usize)> = self.descendants(root).collect();
+
+        let deepest = subtree.iter().map(|&(_, relative)| relative).max();
+        let attempted = new_depth.get().saturating_add(deepest.unwrap_or(0));
+        if attempted > MAX_TREE_DEPTH {
+            return Err(DepthError::MaxDepthExceeded {
+                attempted,
+                max: MAX_TREE_DEPTH,
+            });
+        }
+
+        for &(id, relative) in &subtree {
+            if let Some(node) = self.get_mut(id) {
+                node.set_depth(Depth::new(new_depth.get() + relative));
+            }
+        }
+        Ok(subtree.len())
+    }
+
     /// Inserts a node as a child of the given parent.
     ///
     /// Convenience method combining `insert` and `set_parent`.
@@ -506,6 +542,17 @@ struct TestNode {
         value: i32,
         parent: Option<ViewId>,
         children: Vec<ViewId>,
+        depth: Depth,
+    }
+
+    impl DepthAware for TestNode {
+        fn depth(&self) -> Depth {
+            self.depth
+        }
+
+        fn set_depth(&mut self, depth: Depth) {
+            self.depth = depth;
+        }
     }
 
     struct TestTree {
@@ -939,4 +986,89 @@ fn remove_cascade_is_stack_safe_on_deep_chain() {
         assert!(removed.is_some());
         assert_eq!(tree.len(), 0, "{DEPTH}-deep chain must cascade");
     }
+
+    // ========================================================================
+    // DEPTH REBALANCING
+    // ========================================================================
+
+    /// Builds `len` nodes chained under `parent`, returning their ids.
+    fn chain(tree: &mut TestTree, parent: Option<ViewId>, len: usize) -> Vec<ViewId> {
+        let mut ids = Vec::with_capacity(len);
+        let mut parent = parent;
+        for _ in 0..len {
+            let id = tree.insert_child(TestNode::default(), parent).unwrap();
+            ids.push(id);
+            parent = Some(id);
+        }
+        ids
+    }
+
+    fn depth_of(tree: &TestTree, id: ViewId) -> usize {
+        DepthAware::depth(tree.get(id).unwrap()).get()
+    }
+
+    #[test]
+    fn rebalance_depths_shifts_moved_subtree() {
+        let mut tree = TestTree::new();
+        // root → a → (subtree: s0 → s1 → … → s9, plus a side branch on s2)
+        let spine = chain(&mut tree, None, 2);
+        let subtree = chain(&mut tree, Some(spine[1]), 10);
+        let side = tree
+            .insert_child(TestNode::default(), Some(subtree[2]))
+            .unwrap();
+        // And a separate deeper branch to move it under.
+        let target = chain(&mut tree, Some(spine[0]), 6);
+
+        assert_eq!(tree.rebalance_depths(spine[0], Depth::root()), Ok(19));
+        let before: Vec<usize> = subtree
+            .iter()
+            .chain([&side])
+            .map(|&id| depth_of(&tree, id))
+            .collect();
+        assert_eq!(before[0], 2);
+
+        let new_parent = *target.last().unwrap();
+        tree.set_parent(subtree[0], Some(new_parent)).unwrap();
+        let new_depth = Depth::new(depth_of(&tree, new_parent)).child_depth();
+        assert_eq!(tree.rebalance_depths(subtree[0], new_depth), Ok(11));
+
+        let delta = new_depth.get() - before[0];
+        assert_eq!(delta, 5);
+        for (&id, old) in subtree.iter().chain([&side]).zip(before) {
+            assert_eq!(depth_of(&tree, id), old + delta, "node {id:?}");
+        }
+        // Nodes outside the moved subtree keep their depth.
+        assert_eq!(depth_of(&tree, spine[1]), 1);
+        assert_eq!(depth_of(&tree, new_parent), 6);
+    }
+
+    #[test]
+    fn rebalance_depths_rejects_overflow_without_writing() {
+        let mut tree = TestTree::new();
+        let subtree = chain(&mut tree, None, 10);
+        tree.rebalance_depths(subtree[0], Depth::root()).unwrap();
+
+        let too_deep = Depth::new(MAX_TREE_DEPTH - 5);
+        assert_eq!(
+            tree.rebalance_depths(subtree[0], too_deep),
+            Err(DepthError::MaxDepthExceeded {
+                attempted: MAX_TREE_DEPTH + 4,
+                max: MAX_TREE_DEPTH,
+            })
+        );
+        for (expected, &id) in subtree.iter().enumerate() {
+            assert_eq!(depth_of(&tree, id), expected);
+        }
+
+        // The deepest node landing exactly on the limit is allowed.
+        let at_limit = Depth::new(MAX_TREE_DEPTH - 9);
+        assert_eq!(tree.rebalance_depths(subtree[0], at_limit), Ok(10));
+        assert_eq!(depth_of(&tree, subtree[9]), MAX_TREE_DEPTH);
+    }
+
+    #[test]
+    fn rebalance_depths_on_missing_root_is_a_no_op() {
+        let mut tree = TestTree::new();
+        assert_eq!(tree.rebalance_depths(ViewId::new(7), Depth::new(3)), Ok(0));
+    }
 }
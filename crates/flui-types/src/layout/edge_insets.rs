@@ -0,0 +1,183 @@
+//! Text-direction-aware insets
+//!
+//! [`EdgeInsets`] is absolute (left/right). [`EdgeInsetsDirectional`] names
+//! its horizontal sides by reading order (start/end) and is resolved against a
+//! [`TextDirection`] before layout; [`EdgeInsetsGeometry`] accepts either.
+
+use crate::geometry::{EdgeInsets, Pixels};
+use crate::typography::TextDirection;
+
+/// Insets whose horizontal sides depend on text direction.
+///
+/// Mirrors Flutter's `EdgeInsetsDirectional`: `start` is the reading edge
+/// (left in LTR, right in RTL) and `end` the trailing one. Call
+/// [`resolve`](Self::resolve) to obtain an absolute [`EdgeInsets`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeInsetsDirectional {
+    /// Inset on the start side (left in LTR, right in RTL).
+    pub start: Pixels,
+    /// Inset on the top side.
+    pub top: Pixels,
+    /// Inset on the end side (right in LTR, left in RTL).
+    pub end: Pixels,
+    /// Inset on the bottom side.
+    pub bottom: Pixels,
+}
+
+impl EdgeInsetsDirectional {
+    /// No insets on any side.
+    pub const ZERO: Self = Self::all(Pixels::ZERO);
+
+    /// Create insets from start, top, end, and bottom (Flutter's
+    /// `EdgeInsetsDirectional.fromSTEB`).
+    #[inline]
+    pub const fn from_steb(start: Pixels, top: Pixels, end: Pixels, bottom: Pixels) -> Self {
+        Self {
+            start,
+            top,
+            end,
+            bottom,
+        }
+    }
+
+    /// The same inset on all four sides.
+    #[inline]
+    pub const fn all(value: Pixels) -> Self {
+        Self::from_steb(value, value, value, value)
+    }
+
+    /// `horizontal` on start/end, `vertical` on top/bottom.
+    #[inline]
+    pub const fn symmetric(horizontal: Pixels, vertical: Pixels) -> Self {
+        Self::from_steb(horizontal, vertical, horizontal, vertical)
+    }
+
+    /// Inset on the start side only.
+    #[inline]
+    pub const fn only_start(value: Pixels) -> Self {
+        Self::from_steb(value, Pixels::ZERO, Pixels::ZERO, Pixels::ZERO)
+    }
+
+    /// Inset on the end side only.
+    #[inline]
+    pub const fn only_end(value: Pixels) -> Self {
+        Self::from_steb(Pixels::ZERO, Pixels::ZERO, value, Pixels::ZERO)
+    }
+
+    /// Resolve to absolute insets for the given text direction.
+    #[inline]
+    pub const fn resolve(&self, direction: TextDirection) -> EdgeInsets {
+        match direction {
+            TextDirection::Ltr => EdgeInsets::new(self.top, self.end, self.bottom, self.start),
+            TextDirection::Rtl => EdgeInsets::new(self.top, self.start, self.bottom, self.end),
+        }
+    }
+}
+
+/// Either absolute or text-direction-relative insets.
+///
+/// Mirrors Flutter's `EdgeInsetsGeometry` base class as a Rust enum; call
+/// [`resolve`](Self::resolve) to obtain an absolute [`EdgeInsets`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EdgeInsetsGeometry {
+    /// Absolute insets (left/right).
+    Absolute(EdgeInsets),
+    /// Directional insets (start/end).
+    Directional(EdgeInsetsDirectional),
+}
+
+impl EdgeInsetsGeometry {
+    /// Resolve to absolute insets for the given text direction. Absolute
+    /// insets are returned unchanged.
+    #[inline]
+    pub const fn resolve(&self, direction: TextDirection) -> EdgeInsets {
+        match self {
+            EdgeInsetsGeometry::Absolute(insets) => *insets,
+            EdgeInsetsGeometry::Directional(insets) => insets.resolve(direction),
+        }
+    }
+
+    /// Returns `true` if resolving depends on the text direction.
+    #[inline]
+    pub const fn is_directional(&self) -> bool {
+        matches!(self, EdgeInsetsGeometry::Directional(_))
+    }
+}
+
+impl From<EdgeInsets> for EdgeInsetsGeometry {
+    #[inline]
+    fn from(insets: EdgeInsets) -> Self {
+        EdgeInsetsGeometry::Absolute(insets)
+    }
+}
+
+impl From<EdgeInsetsDirectional> for EdgeInsetsGeometry {
+    #[inline]
+    fn from(insets: EdgeInsetsDirectional) -> Self {
+        EdgeInsetsGeometry::Directional(insets)
+    }
+}
+
+impl Default for EdgeInsetsGeometry {
+    #[inline]
+    fn default() -> Self {
+        EdgeInsetsGeometry::Absolute(EdgeInsets::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::px;
+
+    #[test]
+    fn directional_resolves_start_end_by_text_direction() {
+        let insets = EdgeInsetsDirectional::from_steb(px(8.0), px(1.0), px(4.0), px(2.0));
+
+        let ltr = insets.resolve(TextDirection::Ltr);
+        assert_eq!(ltr, EdgeInsets::new(px(1.0), px(4.0), px(2.0), px(8.0)));
+        assert_eq!((ltr.left, ltr.right), (px(8.0), px(4.0)));
+
+        let rtl = insets.resolve(TextDirection::Rtl);
+        assert_eq!((rtl.left, rtl.right), (px(4.0), px(8.0)));
+        assert_eq!((rtl.top, rtl.bottom), (px(1.0), px(2.0)));
+    }
+
+    #[test]
+    fn geometry_resolves_absolute_unchanged() {
+        let absolute = EdgeInsets::new(px(1.0), px(2.0), px(3.0), px(4.0));
+        let geometry = EdgeInsetsGeometry::from(absolute);
+
+        assert!(!geometry.is_directional());
+        assert_eq!(geometry.resolve(TextDirection::Rtl), absolute);
+    }
+
+    #[test]
+    fn geometry_forwards_directional() {
+        let geometry = EdgeInsetsGeometry::from(EdgeInsetsDirectional::only_start(px(6.0)));
+
+        assert!(geometry.is_directional());
+        assert_eq!(geometry.resolve(TextDirection::Ltr).left, px(6.0));
+        assert_eq!(geometry.resolve(TextDirection::Rtl).right, px(6.0));
+    }
+
+    #[test]
+    fn const_constructors_match_their_sides() {
+        const SYMMETRIC: EdgeInsetsDirectional =
+            EdgeInsetsDirectional::symmetric(Pixels(3.0), Pixels(5.0));
+        assert_eq!(
+            SYMMETRIC,
+            EdgeInsetsDirectional::from_steb(px(3.0), px(5.0), px(3.0), px(5.0))
+        );
+        assert_eq!(
+            EdgeInsetsDirectional::ZERO,
+            EdgeInsetsDirectional::default()
+        );
+        assert_eq!(
+            EdgeInsetsDirectional::only_end(px(2.0)).resolve(TextDirection::Ltr),
+            EdgeInsets::new(px(0.0), px(2.0), px(0.0), px(0.0))
+        );
+    }
+}
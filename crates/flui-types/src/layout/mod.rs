@@ -5,6 +5,7 @@
 pub mod baseline;
 pub mod r#box;
 pub mod constraints;
+pub mod edge_insets;
 pub mod flex;
 pub mod fractional_offset;
 pub mod stack;
@@ -20,6 +21,7 @@
 pub use baseline::TextBaseline;
 pub use r#box::{BoxFit, BoxShape, FittedSizes};
 pub use constraints::BoxConstraints;
+pub use edge_insets::{EdgeInsetsDirectional, EdgeInsetsGeometry};
 pub use flex::FlexFit;
 pub use fractional_offset::FractionalOffset;
 pub use stack::StackFit;
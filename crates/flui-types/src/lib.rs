@@ -115,7 +115,7 @@
 pub use geometry::{EdgeInsets, Edges, Matrix4, Offset, Pixels, Point, RRect, Rect, Size};
 pub use haptics::HapticFeedback;
 pub use ime::ImeEvent;
-pub use layout::{Alignment, Axis};
+pub use layout::{Alignment, Axis, EdgeInsetsDirectional, EdgeInsetsGeometry};
 pub use styling::{Color, Color32, Oklab};
 
 /// Prelude module for convenient glob imports
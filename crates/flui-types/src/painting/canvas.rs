@@ -171,7 +171,7 @@ pub enum StrokeJoin {
 }
 
 /// How a list of vertices is interpreted when drawing a triangle mesh.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VertexMode {
     /// Draw each sequence of three vertices as a separate triangle.
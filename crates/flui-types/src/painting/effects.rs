@@ -5,7 +5,7 @@
 
 use std::sync::Arc;
 
-use super::canvas::{StrokeCap, StrokeJoin};
+use super::canvas::{StrokeCap, StrokeJoin, TileMode};
 use crate::geometry::Pixels;
 
 /// Blur quality/algorithm level.
@@ -545,17 +545,14 @@ pub enum PathPaintMode {
 /// use flui_types::painting::effects::{ImageFilter, ColorAdjustment};
 ///
 /// // Blur filter
-/// let blur = ImageFilter::Blur {
-///     sigma_x: 5.0,
-///     sigma_y: 5.0,
-/// };
+/// let blur = ImageFilter::blur(5.0);
 ///
 /// // Color adjustment
 /// let grayscale = ImageFilter::ColorAdjust(ColorAdjustment::Grayscale(1.0));
 ///
 /// // Combined filters
 /// let filters = vec![
-///     ImageFilter::Blur { sigma_x: 3.0, sigma_y: 3.0 },
+///     ImageFilter::blur(3.0),
 ///     ImageFilter::ColorAdjust(ColorAdjustment::Brightness(0.1)),
 /// ];
 /// ```
@@ -564,12 +561,21 @@ pub enum PathPaintMode {
 pub enum ImageFilter {
     /// Gaussian blur with horizontal and vertical sigma.
     ///
-    /// Similar to CSS `blur()` function.
+    /// Similar to CSS `blur()` function and Flutter's `ImageFilter.blur`.
     Blur {
         /// Horizontal blur radius (sigma)
         sigma_x: f32,
         /// Vertical blur radius (sigma)
         sigma_y: f32,
+        /// How pixels beyond the input bounds are sampled.
+        ///
+        /// `None` uses the context default, like Flutter's `tileMode: null`:
+        /// [`TileMode::Decal`] for layer filters, [`TileMode::Clamp`] for
+        /// backdrop filters. With any mode other than `Decal` the output is
+        /// limited to the input bounds. Backdrop filters only support
+        /// `Clamp`; the engine renders a backdrop blur with any other mode
+        /// as no blur.
+        tile_mode: Option<TileMode>,
     },
 
     /// Dilate (expand bright areas) with specified radius.
@@ -630,17 +636,31 @@ impl ImageFilter {
     #[inline]
     #[must_use]
     pub fn blur(sigma: f32) -> Self {
-        Self::Blur {
-            sigma_x: sigma,
-            sigma_y: sigma,
-        }
+        Self::blur_directional(sigma, sigma)
     }
 
     /// Create a blur filter with different horizontal and vertical sigma.
     #[inline]
     #[must_use]
     pub fn blur_directional(sigma_x: f32, sigma_y: f32) -> Self {
-        Self::Blur { sigma_x, sigma_y }
+        Self::Blur {
+            sigma_x,
+            sigma_y,
+            tile_mode: None,
+        }
+    }
+
+    /// Create a blur filter with an explicit edge [`TileMode`].
+    ///
+    /// Matches Flutter's `ImageFilter.blur(sigmaX:, sigmaY:, tileMode:)`.
+    #[inline]
+    #[must_use]
+    pub fn blur_with_tile_mode(sigma_x: f32, sigma_y: f32, tile_mode: TileMode) -> Self {
+        Self::Blur {
+            sigma_x,
+            sigma_y,
+            tile_mode: Some(tile_mode),
+        }
     }
 
     /// Create a dilate filter with specified radius.
@@ -708,6 +728,22 @@ fn test_blur_mode_default() {
         assert_eq!(BlurMode::default(), BlurMode::Content);
     }
 
+    #[test]
+    fn test_blur_constructors() {
+        assert_eq!(
+            ImageFilter::blur(3.0),
+            ImageFilter::blur_directional(3.0, 3.0)
+        );
+        assert_eq!(
+            ImageFilter::blur_with_tile_mode(2.0, 4.0, TileMode::Mirror),
+            ImageFilter::Blur {
+                sigma_x: 2.0,
+                sigma_y: 4.0,
+                tile_mode: Some(TileMode::Mirror),
+            }
+        );
+    }
+
     #[test]
     fn test_color_matrix_identity() {
         let identity = ColorMatrix::identity();
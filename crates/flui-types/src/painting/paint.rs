@@ -4,8 +4,9 @@
 //! for controlling how shapes and paths are rendered.
 
 use crate::{
+    geometry::{Pixels, Rect},
     painting::{BlendMode, Shader, StrokeCap, StrokeJoin},
-    styling::Color,
+    styling::{Color, Gradient},
 };
 
 /// Dash pattern for stroked paths.
@@ -220,6 +221,14 @@ pub fn with_shader(mut self, shader: Shader) -> Self {
         self
     }
 
+    /// Sets the shader to `gradient` resolved against `rect`.
+    ///
+    /// Shorthand for `with_shader(gradient.create_shader(rect))`.
+    #[must_use]
+    pub fn with_gradient(self, gradient: impl Into<Gradient>, rect: Rect<Pixels>) -> Self {
+        self.with_shader(gradient.into().create_shader(rect))
+    }
+
     /// Sets the dash pattern for stroked paths.
     ///
     /// The dash pattern defines alternating dash/gap lengths.
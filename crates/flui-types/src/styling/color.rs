@@ -3,6 +3,8 @@
 //! This module provides a comprehensive Color type with conversions between
 //! different color spaces (RGB, HSL, HSV), similar to Flutter's Color system.
 
+use super::hsl_hsv::HSLColor;
+
 /// An RGBA color with four 8-bit channels and straight (unmultiplied) alpha.
 ///
 /// Channels are in sRGB gamma space, matching Flutter's `Color`. For the
@@ -850,6 +852,98 @@ pub fn lerp_oklab(a: Color, b: Color, t: f32) -> Color {
         Color::from_oklab(mixed, alpha)
     }
 
+    // ===== Linear-light conversion and interpolation =====
+
+    /// Convert to linear-light RGB with straight alpha, each in `[0, 1]`.
+    ///
+    /// The color channels go through [`srgb_to_linear`]; alpha is already
+    /// linear and is only rescaled.
+    #[must_use]
+    pub fn to_linear(&self) -> [f32; 4] {
+        [
+            srgb_to_linear(f32::from(self.r) / 255.0),
+            srgb_to_linear(f32::from(self.g) / 255.0),
+            srgb_to_linear(f32::from(self.b) / 255.0),
+            f32::from(self.a) / 255.0,
+        ]
+    }
+
+    /// Convert from linear-light RGB with straight alpha (inverse of
+    /// [`to_linear`](Self::to_linear)). Channels are clamped to `[0, 1]`.
+    #[must_use]
+    pub fn from_linear(linear: [f32; 4]) -> Color {
+        // `.round() as u8` saturates after the clamp.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // saturating by design
+        #[inline]
+        fn to_channel(c: f32) -> u8 {
+            (c.clamp(0.0, 1.0) * 255.0).round() as u8
+        }
+
+        let [r, g, b, a] = linear;
+        Color::rgba(
+            to_channel(linear_to_srgb(r.clamp(0.0, 1.0))),
+            to_channel(linear_to_srgb(g.clamp(0.0, 1.0))),
+            to_channel(linear_to_srgb(b.clamp(0.0, 1.0))),
+            to_channel(a),
+        )
+    }
+
+    /// Interpolation in linear light — the physically correct mix of two
+    /// colors, as light (and a GPU blending in a linear target) combines them.
+    ///
+    /// Componentwise sRGB lerp ([`Color::lerp`]) averages gamma-encoded
+    /// values, so midpoints come out too dark: black→white at `0.5` lands on
+    /// sRGB 128 rather than the ~188 that half the light encodes to. Use this
+    /// for gradients and theme transitions; use [`Color::lerp_oklab`] when
+    /// evenly *perceived* steps matter more than physical mixing.
+    ///
+    /// `t` is clamped to `0.0..=1.0`. Alpha interpolates linearly, matching
+    /// [`Color::lerp`].
+    #[must_use]
+    pub fn lerp_perceptual(a: Color, b: Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let la = a.to_linear();
+        let lb = b.to_linear();
+        Color::from_linear(std::array::from_fn(|i| la[i] + (lb[i] - la[i]) * t))
+    }
+
+    // ===== HSL adjustments =====
+
+    /// Raises HSL lightness by `amount` (an absolute step, so `0.1` turns 40%
+    /// lightness into 50%), keeping hue and saturation.
+    ///
+    /// Unlike [`lighten`](Self::lighten), which mixes toward white, this keeps
+    /// the color's saturation as it brightens. Lightness clamps to `0..=1`;
+    /// alpha is preserved.
+    #[must_use]
+    pub fn lighten_hsl(&self, amount: f32) -> Color {
+        let hsl = HSLColor::from(*self);
+        let lightness = hsl.lightness + amount;
+        self.with_hsl(hsl.with_lightness(lightness))
+    }
+
+    /// Lowers HSL lightness by `amount`; the inverse of
+    /// [`lighten_hsl`](Self::lighten_hsl).
+    #[must_use]
+    pub fn darken_hsl(&self, amount: f32) -> Color {
+        self.lighten_hsl(-amount)
+    }
+
+    /// Raises HSL saturation by `amount` (negative desaturates), keeping hue
+    /// and lightness. Saturation clamps to `0..=1`; alpha is preserved.
+    #[must_use]
+    pub fn saturate(&self, amount: f32) -> Color {
+        let hsl = HSLColor::from(*self);
+        let saturation = hsl.saturation + amount;
+        self.with_hsl(hsl.with_saturation(saturation))
+    }
+
+    /// Converts an adjusted HSL color back, restoring this color's exact
+    /// alpha (the HSL round trip stores alpha as `f32`).
+    fn with_hsl(&self, hsl: HSLColor) -> Color {
+        Color::from(hsl).with_alpha(self.a)
+    }
+
     /// Samples a multi-stop color ramp at position `t` (clamped to
     /// `0.0..=1.0`), like evaluating a gradient.
     ///
@@ -1555,6 +1649,70 @@ fn oklab_lerp_interpolates_alpha_linearly() {
         assert_eq!(Color::lerp_oklab(a, b, 0.5).a, 100);
     }
 
+    #[test]
+    fn linear_roundtrip_preserves_color() {
+        for color in [
+            Color::rgba(0, 0, 0, 0),
+            Color::rgba(12, 34, 56, 78),
+            Color::rgba(128, 128, 128, 128),
+            Color::rgba(200, 100, 50, 255),
+            Color::WHITE,
+        ] {
+            let linear = color.to_linear();
+            assert!(linear.iter().all(|c| (0.0..=1.0).contains(c)));
+            assert_eq!(Color::from_linear(linear), color);
+        }
+    }
+
+    #[test]
+    fn linear_matches_transfer_function() {
+        let [r, g, b, a] = Color::rgba(255, 128, 0, 51).to_linear();
+        assert!((r - 1.0).abs() < 1e-6);
+        assert!((g - srgb_to_linear(128.0 / 255.0)).abs() < 1e-6);
+        assert!(b.abs() < 1e-6);
+        assert!((a - 0.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn perceptual_lerp_midpoint_is_lighter_than_srgb_lerp() {
+        let naive = Color::lerp(Color::BLACK, Color::WHITE, 0.5);
+        let perceptual = Color::lerp_perceptual(Color::BLACK, Color::WHITE, 0.5);
+
+        assert!(perceptual.r > naive.r, "{perceptual:?} vs {naive:?}");
+        // Half the light of white encodes to ~188 in sRGB.
+        assert!((i32::from(perceptual.r) - 188).abs() <= 1);
+        assert_eq!(
+            Color::lerp_perceptual(Color::BLACK, Color::WHITE, 0.0),
+            Color::BLACK
+        );
+        assert_eq!(
+            Color::lerp_perceptual(Color::BLACK, Color::WHITE, 1.0),
+            Color::WHITE
+        );
+    }
+
+    #[test]
+    fn hsl_adjustments_move_only_their_component() {
+        let base = Color::rgba(51, 102, 153, 77); // hsl(210°, 50%, 40%)
+        let base_hsl = HSLColor::from(base);
+
+        let lighter = HSLColor::from(base.lighten_hsl(0.2));
+        assert!((lighter.lightness - 0.6).abs() < 0.01);
+        assert!((lighter.hue - base_hsl.hue).abs() < 1.0);
+        assert!((lighter.saturation - base_hsl.saturation).abs() < 0.02);
+        assert_eq!(base.lighten_hsl(0.2).a, 77);
+
+        let darker = HSLColor::from(base.darken_hsl(0.3));
+        assert!((darker.lightness - 0.1).abs() < 0.01);
+
+        let gray = base.saturate(-1.0);
+        assert_eq!(gray.r, gray.g);
+        assert_eq!(gray.g, gray.b);
+        assert!(HSLColor::from(base.saturate(0.3)).saturation > base_hsl.saturation);
+
+        assert_eq!(Color::WHITE.lighten_hsl(0.5), Color::WHITE);
+    }
+
     #[test]
     fn test_approx_eq_alpha_channel() {
         let c1 = Color::rgba(100, 150, 200, 255);
@@ -2,7 +2,12 @@
 
 // Re-export TileMode from painting module
 pub use crate::painting::TileMode;
-use crate::{layout::Alignment, styling::Color};
+use crate::{
+    geometry::{Offset, Pixels, Rect},
+    layout::Alignment,
+    painting::Shader,
+    styling::Color,
+};
 
 /// A description of a color gradient, similar to Flutter's `Gradient`.
 #[derive(Clone, Debug, PartialEq)]
@@ -39,6 +44,21 @@ pub fn stops(&self) -> Option<&[f32]> {
         }
     }
 
+    /// Checks that the colors and stops describe a usable gradient.
+    pub fn validate(&self) -> Result<(), GradientError> {
+        validate_color_stops(self.colors(), self.stops())
+    }
+
+    /// Creates a shader that paints this gradient into `rect`, similar to
+    /// Flutter's `Gradient.createShader`.
+    pub fn create_shader(&self, rect: Rect<Pixels>) -> Shader {
+        match self {
+            Gradient::Linear(g) => g.create_shader(rect),
+            Gradient::Radial(g) => g.create_shader(rect),
+            Gradient::Sweep(g) => g.create_shader(rect),
+        }
+    }
+
     /// Linearly interpolate between two gradients.
     ///
     /// Returns None if the gradients are of different types or have
@@ -61,6 +81,27 @@ pub fn lerp(a: &Self, b: &Self, t: f32) -> Option<Self> {
     }
 }
 
+impl From<LinearGradient> for Gradient {
+    #[inline]
+    fn from(gradient: LinearGradient) -> Self {
+        Gradient::Linear(gradient)
+    }
+}
+
+impl From<RadialGradient> for Gradient {
+    #[inline]
+    fn from(gradient: RadialGradient) -> Self {
+        Gradient::Radial(gradient)
+    }
+}
+
+impl From<SweepGradient> for Gradient {
+    #[inline]
+    fn from(gradient: SweepGradient) -> Self {
+        Gradient::Sweep(gradient)
+    }
+}
+
 /// A gradient that transitions colors along a line between two alignment
 /// points, similar to Flutter's `LinearGradient`.
 #[derive(Clone, Debug, PartialEq)]
@@ -84,6 +125,11 @@ pub struct LinearGradient {
     /// How this gradient should tile the plane beyond the region defined by
     /// begin and end.
     pub tile_mode: TileMode,
+
+    /// An optional rotation applied about the center of the paint box when
+    /// the shader is created.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub transform: Option<GradientRotation>,
 }
 
 impl LinearGradient {
@@ -102,6 +148,7 @@ pub fn new(
             colors,
             stops,
             tile_mode,
+            transform: None,
         }
     }
 
@@ -183,6 +230,47 @@ pub fn diagonal(colors: Vec<Color>) -> Self {
         )
     }
 
+    /// Sets the color stops.
+    #[must_use]
+    #[inline]
+    pub fn with_stops(mut self, stops: Vec<f32>) -> Self {
+        self.stops = Some(stops);
+        self
+    }
+
+    /// Sets how the gradient tiles outside its defined region.
+    #[must_use]
+    #[inline]
+    pub fn with_tile_mode(mut self, tile_mode: TileMode) -> Self {
+        self.tile_mode = tile_mode;
+        self
+    }
+
+    /// Rotates the gradient about the center of the paint box.
+    #[must_use]
+    #[inline]
+    pub fn with_transform(mut self, transform: GradientRotation) -> Self {
+        self.transform = Some(transform);
+        self
+    }
+
+    /// Checks that stops are sorted within `0.0..=1.0` and match the colors.
+    pub fn validate(&self) -> Result<(), GradientError> {
+        validate_color_stops(&self.colors, self.stops.as_deref())
+    }
+
+    /// Creates a shader that paints this gradient into `rect`, with `begin`
+    /// and `end` resolved against the rect.
+    pub fn create_shader(&self, rect: Rect<Pixels>) -> Shader {
+        Shader::LinearGradient {
+            from: resolve_point(self.begin, rect, self.transform),
+            to: resolve_point(self.end, rect, self.transform),
+            colors: self.colors.clone(),
+            stops: self.stops.clone(),
+            tile_mode: self.tile_mode,
+        }
+    }
+
     /// Linearly interpolate between two linear gradients.
     ///
     /// Returns None if the gradients have different numbers of colors.
@@ -217,6 +305,7 @@ pub fn lerp(a: &Self, b: &Self, t: f32) -> Option<Self> {
             colors,
             stops,
             tile_mode: if t < 0.5 { a.tile_mode } else { b.tile_mode },
+            transform: if t < 0.5 { a.transform } else { b.transform },
         })
     }
 }
@@ -253,6 +342,11 @@ pub struct RadialGradient {
     /// The radius of the focal point of gradient, as a fraction of the shortest
     /// side.
     pub focal_radius: Option<f32>,
+
+    /// An optional rotation applied about the center of the paint box when
+    /// the shader is created.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub transform: Option<GradientRotation>,
 }
 
 impl RadialGradient {
@@ -276,6 +370,7 @@ pub fn new(
             tile_mode,
             focal,
             focal_radius,
+            transform: None,
         }
     }
 
@@ -320,6 +415,52 @@ pub fn circular(colors: Vec<Color>) -> Self {
         )
     }
 
+    /// Sets the color stops.
+    #[must_use]
+    #[inline]
+    pub fn with_stops(mut self, stops: Vec<f32>) -> Self {
+        self.stops = Some(stops);
+        self
+    }
+
+    /// Sets how the gradient tiles outside its defined region.
+    #[must_use]
+    #[inline]
+    pub fn with_tile_mode(mut self, tile_mode: TileMode) -> Self {
+        self.tile_mode = tile_mode;
+        self
+    }
+
+    /// Rotates the gradient about the center of the paint box.
+    #[must_use]
+    #[inline]
+    pub fn with_transform(mut self, transform: GradientRotation) -> Self {
+        self.transform = Some(transform);
+        self
+    }
+
+    /// Checks that stops are sorted within `0.0..=1.0` and match the colors.
+    pub fn validate(&self) -> Result<(), GradientError> {
+        validate_color_stops(&self.colors, self.stops.as_deref())
+    }
+
+    /// Creates a shader that paints this gradient into `rect`. `radius` and
+    /// `focal_radius` scale with the rect's shortest side.
+    pub fn create_shader(&self, rect: Rect<Pixels>) -> Shader {
+        let shortest_side = rect.width().get().min(rect.height().get());
+        Shader::RadialGradient {
+            center: resolve_point(self.center, rect, self.transform),
+            radius: self.radius * shortest_side,
+            colors: self.colors.clone(),
+            stops: self.stops.clone(),
+            tile_mode: self.tile_mode,
+            focal: self
+                .focal
+                .map(|focal| resolve_point(focal, rect, self.transform)),
+            focal_radius: self.focal_radius.map(|r| r * shortest_side),
+        }
+    }
+
     /// Linearly interpolate between two radial gradients.
     #[inline]
     pub fn lerp(a: &Self, b: &Self, t: f32) -> Option<Self> {
@@ -364,6 +505,7 @@ pub fn lerp(a: &Self, b: &Self, t: f32) -> Option<Self> {
             tile_mode: if t < 0.5 { a.tile_mode } else { b.tile_mode },
             focal,
             focal_radius,
+            transform: if t < 0.5 { a.transform } else { b.transform },
         })
     }
 }
@@ -391,6 +533,11 @@ pub struct SweepGradient {
 
     /// The angle in radians at which stop 1.0 of the gradient is placed.
     pub end_angle: f32,
+
+    /// An optional rotation applied about the center of the paint box when
+    /// the shader is created.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub transform: Option<GradientRotation>,
 }
 
 impl SweepGradient {
@@ -411,6 +558,7 @@ pub fn new(
             tile_mode,
             start_angle,
             end_angle,
+            transform: None,
         }
     }
 
@@ -428,6 +576,49 @@ pub fn centered(colors: Vec<Color>) -> Self {
         )
     }
 
+    /// Sets the color stops.
+    #[must_use]
+    #[inline]
+    pub fn with_stops(mut self, stops: Vec<f32>) -> Self {
+        self.stops = Some(stops);
+        self
+    }
+
+    /// Sets how the gradient tiles outside its defined region.
+    #[must_use]
+    #[inline]
+    pub fn with_tile_mode(mut self, tile_mode: TileMode) -> Self {
+        self.tile_mode = tile_mode;
+        self
+    }
+
+    /// Rotates the gradient about the center of the paint box.
+    #[must_use]
+    #[inline]
+    pub fn with_transform(mut self, transform: GradientRotation) -> Self {
+        self.transform = Some(transform);
+        self
+    }
+
+    /// Checks that stops are sorted within `0.0..=1.0` and match the colors.
+    pub fn validate(&self) -> Result<(), GradientError> {
+        validate_color_stops(&self.colors, self.stops.as_deref())
+    }
+
+    /// Creates a shader that paints this gradient into `rect`. A rotation
+    /// transform also offsets the start and end angles.
+    pub fn create_shader(&self, rect: Rect<Pixels>) -> Shader {
+        let rotation = self.transform.map_or(0.0, |t| t.radians);
+        Shader::SweepGradient {
+            center: resolve_point(self.center, rect, self.transform),
+            colors: self.colors.clone(),
+            stops: self.stops.clone(),
+            tile_mode: self.tile_mode,
+            start_angle: self.start_angle + rotation,
+            end_angle: self.end_angle + rotation,
+        }
+    }
+
     /// Linearly interpolate between two sweep gradients.
     #[inline]
     pub fn lerp(a: &Self, b: &Self, t: f32) -> Option<Self> {
@@ -461,6 +652,7 @@ pub fn lerp(a: &Self, b: &Self, t: f32) -> Option<Self> {
             tile_mode: if t < 0.5 { a.tile_mode } else { b.tile_mode },
             start_angle: a.start_angle + (b.start_angle - a.start_angle) * t,
             end_angle: a.end_angle + (b.end_angle - a.end_angle) * t,
+            transform: if t < 0.5 { a.transform } else { b.transform },
         })
     }
 }
@@ -501,3 +693,180 @@ impl GradientTransform for GradientRotation {
         [[cos, -sin, 0.0], [sin, cos, 0.0], [0.0, 0.0, 1.0]]
     }
 }
+
+/// Error returned by the gradient `validate` methods when the colors and
+/// stops don't describe a usable gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradientError {
+    /// A gradient needs at least two colors.
+    TooFewColors,
+    /// `stops` was given but its length differs from `colors`.
+    StopCountMismatch {
+        /// Number of colors.
+        colors: usize,
+        /// Number of stops.
+        stops: usize,
+    },
+    /// A stop lies outside `0.0..=1.0` (or is NaN).
+    StopOutOfRange {
+        /// Index of the offending stop.
+        index: usize,
+        /// The stop value.
+        value: f32,
+    },
+    /// A stop is smaller than the one before it.
+    StopsNotSorted {
+        /// Index of the first stop that decreases.
+        index: usize,
+    },
+}
+
+impl std::fmt::Display for GradientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GradientError::TooFewColors => write!(f, "gradient needs at least two colors"),
+            GradientError::StopCountMismatch { colors, stops } => {
+                write!(f, "gradient has {colors} colors but {stops} stops")
+            }
+            GradientError::StopOutOfRange { index, value } => {
+                write!(f, "gradient stop {index} ({value}) is outside 0.0..=1.0")
+            }
+            GradientError::StopsNotSorted { index } => {
+                write!(f, "gradient stop {index} is smaller than the previous stop")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GradientError {}
+
+fn validate_color_stops(colors: &[Color], stops: Option<&[f32]>) -> Result<(), GradientError> {
+    if colors.len() < 2 {
+        return Err(GradientError::TooFewColors);
+    }
+    let Some(stops) = stops else {
+        return Ok(());
+    };
+    if stops.len() != colors.len() {
+        return Err(GradientError::StopCountMismatch {
+            colors: colors.len(),
+            stops: stops.len(),
+        });
+    }
+    for (index, &value) in stops.iter().enumerate() {
+        if !(0.0..=1.0).contains(&value) {
+            return Err(GradientError::StopOutOfRange { index, value });
+        }
+        if index > 0 && value < stops[index - 1] {
+            return Err(GradientError::StopsNotSorted { index });
+        }
+    }
+    Ok(())
+}
+
+/// Resolves `alignment` against `rect`, then applies the optional rotation
+/// about the rect's center.
+fn resolve_point(
+    alignment: Alignment,
+    rect: Rect<Pixels>,
+    transform: Option<GradientRotation>,
+) -> Offset<Pixels> {
+    let point = alignment.align_within(rect);
+    let Some(rotation) = transform else {
+        return point;
+    };
+    let center = rect.center();
+    let (sin, cos) = rotation.radians.sin_cos();
+    let dx = (point.dx - center.x).get();
+    let dy = (point.dy - center.y).get();
+    Offset::new(
+        center.x + Pixels(dx * cos - dy * sin),
+        center.y + Pixels(dx * sin + dy * cos),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::px;
+    use crate::painting::Paint;
+
+    fn three_stop() -> LinearGradient {
+        LinearGradient::horizontal(vec![Color::RED, Color::GREEN, Color::BLUE])
+            .with_stops(vec![0.0, 0.25, 1.0])
+            .with_tile_mode(TileMode::Mirror)
+    }
+
+    #[test]
+    fn three_stop_linear_shader_carries_stop_positions() {
+        let gradient = three_stop();
+        assert_eq!(gradient.validate(), Ok(()));
+
+        let rect = Rect::from_ltwh(px(10.0), px(0.0), px(100.0), px(50.0));
+        let Shader::LinearGradient {
+            from,
+            to,
+            colors,
+            stops,
+            tile_mode,
+        } = gradient.create_shader(rect)
+        else {
+            panic!("expected a linear gradient shader");
+        };
+        assert_eq!(from, Offset::new(px(10.0), px(25.0)));
+        assert_eq!(to, Offset::new(px(110.0), px(25.0)));
+        assert_eq!(colors, vec![Color::RED, Color::GREEN, Color::BLUE]);
+        assert_eq!(stops, Some(vec![0.0, 0.25, 1.0]));
+        assert_eq!(tile_mode, TileMode::Mirror);
+
+        let paint = Paint::fill(Color::BLACK).with_gradient(three_stop(), rect);
+        assert_eq!(paint.shader, Some(three_stop().create_shader(rect)));
+    }
+
+    #[test]
+    fn validate_rejects_bad_stops() {
+        let colors = vec![Color::RED, Color::GREEN, Color::BLUE];
+        let check = |stops: Vec<f32>| {
+            LinearGradient::horizontal(colors.clone())
+                .with_stops(stops)
+                .validate()
+        };
+
+        assert_eq!(
+            check(vec![0.0, 1.0]),
+            Err(GradientError::StopCountMismatch {
+                colors: 3,
+                stops: 2
+            })
+        );
+        assert_eq!(
+            check(vec![0.0, 0.7, 0.5]),
+            Err(GradientError::StopsNotSorted { index: 2 })
+        );
+        assert_eq!(
+            check(vec![-0.1, 0.5, 1.0]),
+            Err(GradientError::StopOutOfRange {
+                index: 0,
+                value: -0.1
+            })
+        );
+        assert_eq!(
+            Gradient::from(SweepGradient::centered(vec![Color::RED])).validate(),
+            Err(GradientError::TooFewColors)
+        );
+    }
+
+    #[test]
+    fn rotation_transform_rotates_about_rect_center() {
+        let rect = Rect::from_ltwh(px(0.0), px(0.0), px(100.0), px(100.0));
+        let gradient = LinearGradient::horizontal(vec![Color::RED, Color::BLUE])
+            .with_transform(GradientRotation::new(std::f32::consts::FRAC_PI_2));
+
+        let Shader::LinearGradient { from, to, .. } = gradient.create_shader(rect) else {
+            panic!("expected a linear gradient shader");
+        };
+        // Left-to-right becomes top-to-bottom after a quarter turn.
+        assert!((from.dx.get() - 50.0).abs() < 1e-4 && from.dy.get().abs() < 1e-4);
+        assert!((to.dx.get() - 50.0).abs() < 1e-4 && (to.dy.get() - 100.0).abs() < 1e-4);
+    }
+}
@@ -26,8 +26,8 @@
     BlendMode, BoxDecoration, BoxFit, ColorFilter, Decoration, DecorationImage, ImageRepeat,
 };
 pub use gradient::{
-    Gradient, GradientRotation, GradientTransform, LinearGradient, RadialGradient, SweepGradient,
-    TileMode,
+    Gradient, GradientError, GradientRotation, GradientTransform, LinearGradient, RadialGradient,
+    SweepGradient, TileMode,
 };
 pub use hsl_hsv::{HSLColor, HSVColor};
 pub use material_colors::MaterialColors;
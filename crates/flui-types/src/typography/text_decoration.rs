@@ -7,7 +7,7 @@
 /// Decorations are stored as a bitfield, so multiple decorations can be
 /// combined via [`TextDecoration::combine`] (mirroring Flutter's
 /// `TextDecoration.combine`).
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextDecoration {
     /// Bitfield of decoration flags.
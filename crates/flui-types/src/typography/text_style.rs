@@ -1,5 +1,6 @@
 //! Text styling types.
 
+use super::{TextDecoration, TextDecorationStyle};
 use crate::Color;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -250,6 +251,14 @@ pub struct TextStyle {
     pub background: Option<Color>,
     /// Shadows.
     pub shadows: Vec<TextShadow>,
+    /// Lines drawn near the glyphs (underline, overline, line-through).
+    pub decoration: Option<TextDecoration>,
+    /// Color of the decoration lines; falls back to the text color.
+    pub decoration_color: Option<Color>,
+    /// How the decoration lines are drawn (solid when unset).
+    pub decoration_style: Option<TextDecorationStyle>,
+    /// Multiplier of the font's default decoration thickness (1.0 when unset).
+    pub decoration_thickness: Option<f64>,
 }
 
 impl TextStyle {
@@ -264,7 +273,7 @@ pub fn new() -> Self {
     /// font size, spacing, and line height.
     ///
     /// Two styles that differ only in paint attributes (colors,
-    /// foreground/background paints, shadows) produce byte-identical
+    /// foreground/background paints, shadows, decoration) produce byte-identical
     /// glyph geometry, so a text engine may keep its shaped layout and
     /// only re-emit draw commands. This is the single source of truth
     /// for that partition: a new `TextStyle` field MUST be classified
@@ -339,6 +348,34 @@ pub fn with_height(mut self, height: f64) -> Self {
         self
     }
 
+    /// Sets the text decoration.
+    #[inline]
+    pub fn with_decoration(mut self, decoration: TextDecoration) -> Self {
+        self.decoration = Some(decoration);
+        self
+    }
+
+    /// Sets the color of the decoration lines.
+    #[inline]
+    pub fn with_decoration_color(mut self, color: Color) -> Self {
+        self.decoration_color = Some(color);
+        self
+    }
+
+    /// Sets how the decoration lines are drawn.
+    #[inline]
+    pub fn with_decoration_style(mut self, style: TextDecorationStyle) -> Self {
+        self.decoration_style = Some(style);
+        self
+    }
+
+    /// Sets the decoration thickness, as a multiple of the default.
+    #[inline]
+    pub fn with_decoration_thickness(mut self, thickness: f64) -> Self {
+        self.decoration_thickness = Some(thickness);
+        self
+    }
+
     /// Adds a font feature.
     #[inline]
     pub fn with_font_feature(mut self, feature: FontFeature) -> Self {
@@ -398,6 +435,10 @@ pub fn merge(&self, other: &TextStyle) -> Self {
             } else {
                 other.shadows.clone()
             },
+            decoration: other.decoration.or(self.decoration),
+            decoration_color: other.decoration_color.or(self.decoration_color),
+            decoration_style: other.decoration_style.or(self.decoration_style),
+            decoration_thickness: other.decoration_thickness.or(self.decoration_thickness),
         }
     }
 }
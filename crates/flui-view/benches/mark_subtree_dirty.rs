@@ -0,0 +1,123 @@
+//! Bulk subtree dirty-marking vs. per-element marking.
+//!
+//! `ElementTree::mark_subtree_dirty` walks the subtree once, flipping each
+//! element's atomic dirty flag through a shared borrow and pushing it onto
+//! the owner's dirty heap. The baseline is what a caller had to write before
+//! it existed: collect the subtree ids, then for each one take `&mut tree`
+//! for `mark_needs_build` and schedule it on the owner separately.
+//!
+//! The tree is a stateless chain built through the real `build_scope`, so
+//! `child_ids` are the reconciler's, not hand-wired. Each iteration gets a
+//! fresh `BuildOwner` (empty heap) via `iter_batched`, so dedup never
+//! short-circuits the work being measured.
+
+// Bench harness, not public API; `criterion_group!` generates the
+// undocumentable entry fn.
+#![allow(missing_docs)]
+
+use std::hint::black_box;
+
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
+use flui_foundation::ElementId;
+use flui_objects::RenderSizedBox;
+use flui_rendering::protocol::BoxProtocol;
+use flui_view::{BuildContext, BuildOwner, ElementTree, IntoView, RenderView, StatelessView, View};
+
+/// Chain link: builds `remaining - 1` more links, then a render leaf.
+#[derive(Clone)]
+struct Chain {
+    remaining: usize,
+}
+
+impl StatelessView for Chain {
+    fn build(&self, _ctx: &dyn BuildContext) -> impl IntoView {
+        if self.remaining == 0 {
+            Box::new(Leaf) as Box<dyn View>
+        } else {
+            Box::new(Chain {
+                remaining: self.remaining - 1,
+            })
+        }
+    }
+}
+
+impl View for Chain {
+    fn create_element(&self) -> flui_view::element::ElementKind {
+        flui_view::element::ElementKind::stateless(self)
+    }
+}
+
+/// Terminal render leaf — returns no child views, so the build bottoms out.
+#[derive(Clone)]
+struct Leaf;
+
+impl RenderView for Leaf {
+    type Protocol = BoxProtocol;
+    type RenderObject = RenderSizedBox;
+
+    fn create_render_object(&self, _ctx: &flui_view::RenderObjectContext<'_>) -> RenderSizedBox {
+        RenderSizedBox::shrink()
+    }
+
+    fn update_render_object(
+        &self,
+        _ctx: &flui_view::RenderObjectContext<'_>,
+        _render_object: &mut RenderSizedBox,
+    ) {
+    }
+}
+
+impl View for Leaf {
+    fn create_element(&self) -> flui_view::element::ElementKind {
+        flui_view::element::ElementKind::render_variable(self)
+    }
+}
+
+fn build_chain(len: usize) -> (ElementTree, ElementId) {
+    let mut tree = ElementTree::new();
+    let mut owner = BuildOwner::new();
+    let root = tree.mount_root(&Chain { remaining: len }, &mut owner.element_owner_mut());
+    owner.schedule_build_for(root, 0);
+    owner.build_scope(&mut tree);
+    (tree, root)
+}
+
+fn bench_mark_subtree(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mark_subtree_dirty");
+    for len in [100_usize, 1_000] {
+        let (mut tree, root) = build_chain(len);
+
+        group.bench_with_input(BenchmarkId::new("bulk", len), &len, |b, _| {
+            b.iter_batched(
+                BuildOwner::new,
+                |mut owner| {
+                    let marked = tree.mark_subtree_dirty(root, &mut owner.element_owner_mut());
+                    black_box(marked);
+                },
+                BatchSize::SmallInput,
+            );
+        });
+
+        group.bench_with_input(BenchmarkId::new("individual", len), &len, |b, _| {
+            b.iter_batched(
+                BuildOwner::new,
+                |mut owner| {
+                    let ids: Vec<(ElementId, usize)> = tree
+                        .descendants(root)
+                        .map(|(id, _)| (id, tree.get(id).map_or(0, flui_view::ElementNode::depth)))
+                        .collect();
+                    for (id, depth) in ids {
+                        tree.mark_needs_build(id);
+                        owner.schedule_build_for(id, depth);
+                    }
+                    black_box(owner.dirty_count());
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_mark_subtree);
+criterion_main!(benches);
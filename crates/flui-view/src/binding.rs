@@ -934,8 +934,12 @@ pub fn handle_build_scheduled(&self) {
     }
 
     /// Check if there are pending builds.
+    ///
+    /// Counts elements queued through a `RebuildHandle` as well as the dirty
+    /// heap: both are drained by the next [`draw_frame`](Self::draw_frame).
     pub fn has_pending_builds(&self) -> bool {
-        self.inner.read().build_owner.has_dirty_elements()
+        let inner = self.inner.read();
+        inner.build_owner.has_dirty_elements() || inner.build_owner.pending_external_builds() > 0
     }
 
     /// Get the number of dirty elements.
@@ -1006,8 +1010,10 @@ pub fn draw_frame(&self) {
 
         inner.build_scheduled = false;
 
-        // Build phase: rebuild all dirty elements
-        if inner.build_owner.has_dirty_elements() {
+        // Build phase: rebuild all dirty elements, including those queued
+        // out of frame through a `RebuildHandle`.
+        if inner.build_owner.has_dirty_elements() || inner.build_owner.pending_external_builds() > 0
+        {
             tracing::debug!(
                 dirty_count = inner.build_owner.dirty_count(),
                 "Building dirty elements"
@@ -160,6 +160,31 @@ fn depend_on_inherited(
         callback: &mut dyn FnMut(&dyn std::any::Any),
     ) -> bool;
 
+    /// [`depend_on_inherited`] narrowed to one aspect of an
+    /// [`InheritedModel`](crate::InheritedModel).
+    ///
+    /// The dependency only triggers a rebuild when the provider reports the
+    /// registered `aspect` as affected. Repeated calls accumulate aspects; a
+    /// plain [`depend_on_inherited`] on the same provider widens the
+    /// dependency back to every change. The typed wrapper is
+    /// [`BuildContextExt::depend_on_inherited_view_of_exact_type_with_aspect`].
+    ///
+    /// The default ignores the aspect and depends on every change, which is
+    /// always correct, only less selective.
+    ///
+    /// Flutter parity: `InheritedModel.inheritFrom(context, aspect:)`.
+    ///
+    /// [`depend_on_inherited`]: BuildContext::depend_on_inherited
+    fn depend_on_inherited_with_aspect(
+        &self,
+        type_id: TypeId,
+        aspect: crate::InheritedAspect,
+        callback: &mut dyn FnMut(&dyn std::any::Any),
+    ) -> bool {
+        let _ = aspect;
+        self.depend_on_inherited(type_id, callback)
+    }
+
     /// Look up data from an ancestor InheritedView WITHOUT registering a
     /// dependency.
     ///
@@ -224,6 +249,13 @@ fn find_ancestor_view(
     /// Same callback shape as [`find_ancestor_view`]: synchronous
     /// callback while the read-lock is held, no borrow extension.
     ///
+    /// The lookup records no dependency, so it is for imperative
+    /// coordination — a descendant field registering with its form, a
+    /// button asking the scaffold to open a drawer — from event handlers
+    /// and lifecycle hooks. A `build()` that reads ancestor state this way
+    /// is not rebuilt when that state changes; expose reactive data
+    /// through an [`InheritedView`](crate::InheritedView) instead.
+    ///
     /// Flutter parity: `framework.dart:5132`
     /// `findAncestorStateOfType<T>`.
     ///
@@ -301,7 +333,13 @@ fn pipeline_owner(
     /// The visitor returns `true` to continue, `false` to stop.
     fn visit_ancestor_elements(&self, visitor: &mut dyn FnMut(ElementId) -> bool);
 
-    /// Visit child Elements of this Element.
+    /// Visit the direct child Elements of this Element, in slot order.
+    ///
+    /// For imperative traversal of an already-built subtree (e.g. a form
+    /// walking its fields); the children are whatever the last build
+    /// produced.
+    ///
+    /// Flutter parity: `framework.dart` `visitChildElements`.
     ///
     /// # Note
     ///
@@ -380,6 +418,46 @@ fn depend_on<T: 'static, R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
         result
     }
 
+    /// Look up an ancestor [`InheritedModel`](crate::InheritedModel),
+    /// depending only on `aspect` of it.
+    ///
+    /// Like [`depend_on`](Self::depend_on), but the element is only rebuilt
+    /// when the provider's
+    /// [`update_should_notify_dependent`](crate::InheritedModel::update_should_notify_dependent)
+    /// reports `aspect` as affected.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Rebuilds when the theme's colour changes, not its text style.
+    /// let color = ctx.depend_on_inherited_view_of_exact_type_with_aspect::<Theme, _, _>(
+    ///     ThemeAspect::Color,
+    ///     |t| t.data().color,
+    /// );
+    /// ```
+    fn depend_on_inherited_view_of_exact_type_with_aspect<V, A, R>(
+        &self,
+        aspect: A,
+        f: impl FnOnce(&V) -> R,
+    ) -> Option<R>
+    where
+        V: crate::InheritedModel<A>,
+        A: PartialEq + Send + Sync + 'static,
+    {
+        let mut result: Option<R> = None;
+        let mut once = Some(f);
+        self.depend_on_inherited_with_aspect(
+            TypeId::of::<V>(),
+            crate::InheritedAspect::new::<V, A>(aspect),
+            &mut |any| {
+                if let (Some(typed), Some(call)) = (any.downcast_ref::<V>(), once.take()) {
+                    result = Some(call(typed));
+                }
+            },
+        );
+        result
+    }
+
     /// Look up data from an ancestor InheritedView (without dependency).
     ///
     /// Typed callback wrapper over [`BuildContext::get_inherited`]. Does
@@ -441,7 +519,9 @@ fn find_ancestor<V: 'static, R>(&self, f: impl FnOnce(&V) -> R) -> Option<R> {
     /// the same: it keys off the State runtime type.
     ///
     /// Same callback contract as [`find_ancestor`]: synchronous run, no
-    /// borrow extension. Does NOT register a dependency.
+    /// borrow extension. Does NOT register a dependency — see
+    /// [`BuildContext::find_ancestor_state`] for why this belongs in event
+    /// handlers rather than in `build()`.
     ///
     /// Flutter parity: `framework.dart:5132`
     /// `findAncestorStateOfType<T>`.
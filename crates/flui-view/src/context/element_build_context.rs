@@ -150,6 +150,77 @@ fn find_inherited_provider(&self, type_id: std::any::TypeId) -> Option<ElementId
             .inherited_provider(type_id)
     }
 
+    /// Shared body of `depend_on_inherited` / `depend_on_inherited_with_aspect`.
+    fn depend_on_inherited_impl(
+        &self,
+        type_id: TypeId,
+        aspect: Option<crate::InheritedAspect>,
+        callback: &mut dyn FnMut(&dyn Any),
+    ) -> bool {
+        // Walk ancestors looking for an Element whose view_type_id
+        // matches; the first one is the nearest InheritedView<T>.
+        //
+        // Records this element in the matched InheritedElement's
+        // dependent map so a subsequent rebuild with
+        // `update_should_notify == true` schedules us for rebuild.
+        //
+        // Flutter parity: `framework.dart:5081`
+        // `dependOnInheritedWidgetOfExactType` -> the matched
+        // `InheritedElement` then has `updateDependencies(self, null)`
+        // called on it (`framework.dart:5034`).
+        let Some(ancestor_id) = self.find_inherited_provider(type_id) else {
+            return false;
+        };
+
+        // Acquire a write lock so we can mutate the matched
+        // InheritedElement's dependent map AND invoke the callback with
+        // the inherited view in the same critical section. Reading the
+        // view itself only needs a read lock, but we need write access
+        // to record the dependency, so a single write lock is
+        // sufficient.
+        let mut tree = self.tree.write();
+        let Some(node) = tree.get_mut(ancestor_id) else {
+            // Tree shape changed between lookup and write-lock; treat
+            // as miss. Should not happen under normal flow.
+            return false;
+        };
+
+        // Capture self's depth before we hand `node` out so we can
+        // record it as the dependent's depth (used by
+        // BuildOwner::schedule_build_for during dependency-change notify).
+        let self_depth = self.depth;
+        let self_id = self.element_id;
+
+        // The element behind `node.element_mut()` is a
+        // `Box<dyn ElementBase>`; downcast to the parametric
+        // `InheritedElement<V>` using its TypeId. Because we only have
+        // the user-facing TypeId (which is `TypeId::of::<V>()`, the
+        // view's TypeId), we can't directly downcast to
+        // `InheritedElement<V>` without `V` in scope. Instead we use
+        // the `InheritedElementAccess` object-safe protocol surface
+        // declared on `ElementBase` via the optional helper trait.
+        let Some(accessor) = node.element_mut().as_inherited_mut() else {
+            // Matched view-type but not actually an InheritedElement;
+            // means a non-inherited view shares the TypeId, which is
+            // impossible under TypeId semantics — defensive return.
+            return false;
+        };
+
+        // Register dependency (id + depth, narrowed to `aspect` if given).
+        match aspect {
+            Some(aspect) => accessor.record_dependent_aspect(self_id, self_depth, aspect),
+            None => accessor.record_dependent(self_id, self_depth),
+        }
+
+        // Hand the view out to the callback. The view reference is
+        // borrowed for the lifetime of the callback only; it cannot
+        // escape into `build()` because the closure is `FnOnce`.
+        let view_any = accessor.view_as_any();
+        callback(view_any);
+
+        true
+    }
+
     /// Walk strict-ancestors (parent and up) of `self.element_id`,
     /// invoking `predicate(&dyn ElementBase) -> ControlFlow<R>` for each
     /// visited element. Returns `Some(R)` if the predicate breaks with
@@ -238,65 +309,16 @@ fn text_input_handle(&self) -> Option<flui_interaction::TextInputHandle> {
     }
 
     fn depend_on_inherited(&self, type_id: TypeId, callback: &mut dyn FnMut(&dyn Any)) -> bool {
-        // Walk ancestors looking for an Element whose view_type_id
-        // matches; the first one is the nearest InheritedView<T>.
-        //
-        // Records this element in the matched InheritedElement's
-        // dependent map so a subsequent rebuild with
-        // `update_should_notify == true` schedules us for rebuild.
-        //
-        // Flutter parity: `framework.dart:5081`
-        // `dependOnInheritedWidgetOfExactType` -> the matched
-        // `InheritedElement` then has `updateDependencies(self, null)`
-        // called on it (`framework.dart:5034`).
-        let Some(ancestor_id) = self.find_inherited_provider(type_id) else {
-            return false;
-        };
-
-        // Acquire a write lock so we can mutate the matched
-        // InheritedElement's dependent map AND invoke the callback with
-        // the inherited view in the same critical section. Reading the
-        // view itself only needs a read lock, but we need write access
-        // to record the dependency, so a single write lock is
-        // sufficient.
-        let mut tree = self.tree.write();
-        let Some(node) = tree.get_mut(ancestor_id) else {
-            // Tree shape changed between lookup and write-lock; treat
-            // as miss. Should not happen under normal flow.
-            return false;
-        };
-
-        // Capture self's depth before we hand `node` out so we can
-        // record it as the dependent's depth (used by
-        // BuildOwner::schedule_build_for during dependency-change notify).
-        let self_depth = self.depth;
-        let self_id = self.element_id;
-
-        // The element behind `node.element_mut()` is a
-        // `Box<dyn ElementBase>`; downcast to the parametric
-        // `InheritedElement<V>` using its TypeId. Because we only have
-        // the user-facing TypeId (which is `TypeId::of::<V>()`, the
-        // view's TypeId), we can't directly downcast to
-        // `InheritedElement<V>` without `V` in scope. Instead we use
-        // the `InheritedElementAccess` object-safe protocol surface
-        // declared on `ElementBase` via the optional helper trait.
-        let Some(accessor) = node.element_mut().as_inherited_mut() else {
-            // Matched view-type but not actually an InheritedElement;
-            // means a non-inherited view shares the TypeId, which is
-            // impossible under TypeId semantics — defensive return.
-            return false;
-        };
-
-        // Register dependency (id + depth).
-        accessor.record_dependent(self_id, self_depth);
-
-        // Hand the view out to the callback. The view reference is
-        // borrowed for the lifetime of the callback only; it cannot
-        // escape into `build()` because the closure is `FnOnce`.
-        let view_any = accessor.view_as_any();
-        callback(view_any);
+        self.depend_on_inherited_impl(type_id, None, callback)
+    }
 
-        true
+    fn depend_on_inherited_with_aspect(
+        &self,
+        type_id: TypeId,
+        aspect: crate::InheritedAspect,
+        callback: &mut dyn FnMut(&dyn Any),
+    ) -> bool {
+        self.depend_on_inherited_impl(type_id, Some(aspect), callback)
     }
 
     fn get_inherited(&self, type_id: TypeId, callback: &mut dyn FnMut(&dyn Any)) -> bool {
@@ -599,6 +621,9 @@ pub(crate) struct DependentRecord {
     pub(crate) dependent: ElementId,
     /// The dependent's tree depth (for dirty-heap ordering).
     pub(crate) depth: usize,
+    /// The aspect the dependent narrowed to, or `None` for a dependency on
+    /// every change.
+    pub(crate) aspect: Option<crate::InheritedAspect>,
 }
 
 /// Build-time [`BuildContext`] backed by a live, borrowed read view of the
@@ -693,6 +718,45 @@ fn walk_strict_ancestors<R>(
         }
     }
 
+    /// Shared body of `depend_on_inherited` / `depend_on_inherited_with_aspect`.
+    fn depend_on_inherited_impl(
+        &self,
+        type_id: TypeId,
+        aspect: Option<crate::InheritedAspect>,
+        callback: &mut dyn FnMut(&dyn Any),
+    ) -> bool {
+        let Some(provider_id) = self.find_inherited_provider(type_id) else {
+            return false;
+        };
+        let Some(accessor) = self
+            .tree
+            .get(provider_id)
+            .and_then(super::super::tree::ElementNode::element_opt)
+            .and_then(crate::view::ElementBase::as_inherited)
+        else {
+            return false;
+        };
+        // Buffer the dependent BEFORE invoking the user callback. The tree is
+        // read-only here, so the write itself is deferred to the `build_scope`
+        // drain (see [`DependentRecord`]) — but it is *recorded* first, matching
+        // `ElementBuildContext::depend_on_inherited` and Flutter
+        // (`dependOnInheritedElement` calls `updateDependencies` before
+        // returning the widget). This matters on the error path: if the user
+        // `build()` panics after this `depend_on` (caught by `build_or_recover`,
+        // which substitutes an `ErrorView`), the element stays registered as a
+        // dependent, so a later inherited change reschedules it and it recovers.
+        // Recording only after the callback would drop the registration on that
+        // panic and strand the element on the `ErrorView`.
+        self.dep_sink.lock().push(DependentRecord {
+            provider: provider_id,
+            dependent: self.element_id,
+            depth: self.depth,
+            aspect,
+        });
+        callback(accessor.view_as_any());
+        true
+    }
+
     /// Nearest in-scope `InheritedElement` whose view type is `type_id`, in
     /// **O(1)**.
     ///
@@ -741,35 +805,16 @@ fn text_input_handle(&self) -> Option<flui_interaction::TextInputHandle> {
     }
 
     fn depend_on_inherited(&self, type_id: TypeId, callback: &mut dyn FnMut(&dyn Any)) -> bool {
-        let Some(provider_id) = self.find_inherited_provider(type_id) else {
-            return false;
-        };
-        let Some(accessor) = self
-            .tree
-            .get(provider_id)
-            .and_then(super::super::tree::ElementNode::element_opt)
-            .and_then(crate::view::ElementBase::as_inherited)
-        else {
-            return false;
-        };
-        // Buffer the dependent BEFORE invoking the user callback. The tree is
-        // read-only here, so the write itself is deferred to the `build_scope`
-        // drain (see [`DependentRecord`]) — but it is *recorded* first, matching
-        // `ElementBuildContext::depend_on_inherited` and Flutter
-        // (`dependOnInheritedElement` calls `updateDependencies` before
-        // returning the widget). This matters on the error path: if the user
-        // `build()` panics after this `depend_on` (caught by `build_or_recover`,
-        // which substitutes an `ErrorView`), the element stays registered as a
-        // dependent, so a later inherited change reschedules it and it recovers.
-        // Recording only after the callback would drop the registration on that
-        // panic and strand the element on the `ErrorView`.
-        self.dep_sink.lock().push(DependentRecord {
-            provider: provider_id,
-            dependent: self.element_id,
-            depth: self.depth,
-        });
-        callback(accessor.view_as_any());
-        true
+        self.depend_on_inherited_impl(type_id, None, callback)
+    }
+
+    fn depend_on_inherited_with_aspect(
+        &self,
+        type_id: TypeId,
+        aspect: crate::InheritedAspect,
+        callback: &mut dyn FnMut(&dyn Any),
+    ) -> bool {
+        self.depend_on_inherited_impl(type_id, Some(aspect), callback)
     }
 
     fn get_inherited(&self, type_id: TypeId, callback: &mut dyn FnMut(&dyn Any)) -> bool {
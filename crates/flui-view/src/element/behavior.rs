@@ -196,6 +196,23 @@ fn on_view_updated(
     ) {
     }
 
+    /// Whether replacing `old_view` with the (already stored) new view
+    /// should rebuild this element's child subtree.
+    ///
+    /// Consulted by `Element::update` after the view is swapped in. When it
+    /// returns `false` and the element was clean before the update, the
+    /// dirty flag set by the swap is cleared again, so the reconciler does
+    /// not schedule the element and its child is not re-diffed. Default
+    /// `true`. The proxy-style behaviors forward to
+    /// [`ProxyView::should_rebuild`] / [`ParentDataView::should_rebuild`].
+    ///
+    /// [`ProxyView::should_rebuild`]: crate::view::ProxyView::should_rebuild
+    /// [`ParentDataView::should_rebuild`]: crate::view::ParentDataView::should_rebuild
+    #[allow(unused_variables)]
+    fn should_rebuild_on_update(&self, core: &ElementCore<V, A>, old_view: &V) -> bool {
+        true
+    }
+
     /// The kind name used when formatting the parent `Element` with `Debug`.
     ///
     /// Defaults to `"Element"`. Behaviors override this so that type aliases
@@ -281,17 +298,18 @@ fn parent_data_config(
     /// during bubble dispatch.
     ///
     /// Default returns `false` — non-listener behaviors are skipped
-    /// cleanly. A future production `NotificationListener<N>` widget will
-    /// override this in a dedicated `NotificationListenerBehavior<N>`
-    /// (out of scope for now — the integration tests in
-    /// `tests/notifications.rs` exercise the protocol via a hand-rolled
-    /// `ElementBase` impl so the wiring is validated end-to-end without
-    /// adding production scaffolding the framework doesn't yet need).
+    /// cleanly. `NotificationListenerBehavior` overrides it to filter on
+    /// its `N` and forward to the callback on `core`'s view.
     ///
     /// Flutter parity: `notification_listener.dart:127`
     /// (`_NotificationElement.onNotification`).
-    fn on_notification(&self, type_id: std::any::TypeId, notification: &dyn std::any::Any) -> bool {
-        let _ = (type_id, notification);
+    fn on_notification(
+        &self,
+        core: &ElementCore<V, A>,
+        type_id: std::any::TypeId,
+        notification: &dyn std::any::Any,
+    ) -> bool {
+        let _ = (core, type_id, notification);
         false
     }
 
@@ -434,6 +452,10 @@ fn build_into_views(
     ) -> Vec<Box<dyn View>> {
         super::behavior_commons::proxy_style_views(core, "ProxyBehavior", V::child)
     }
+
+    fn should_rebuild_on_update(&self, core: &ElementCore<V, A>, old_view: &V) -> bool {
+        core.view().should_rebuild(old_view)
+    }
 }
 
 // ============================================================================
@@ -470,6 +492,10 @@ fn build_into_views(
         super::behavior_commons::proxy_style_views(core, "ParentDataBehavior", V::child)
     }
 
+    fn should_rebuild_on_update(&self, core: &ElementCore<V, A>, old_view: &V) -> bool {
+        core.view().should_rebuild(old_view)
+    }
+
     fn parent_data_config(
         &self,
         core: &ElementCore<V, A>,
@@ -873,10 +899,12 @@ fn insert_render_object_helper<R, P>(render_object: R, owner: &mut PipelineOwner
 /// time because we only see `ElementCore<V, A>`).
 ///
 /// Flutter parity: `framework.dart:6252` `_dependents:
-/// HashMap<Element, Object?>` in `InheritedElement`. Flutter uses a
-/// HashMap because dependents may attach dependency aspects (the
-/// `Object?` value); we will gain that capability if we expand
-/// `aspect` support — for now the value slot holds the depth.
+/// HashMap<Element, Object?>` in `InheritedElement`. Flutter keeps a
+/// dependent's aspects in the `Object?` value; here the value slot holds
+/// the depth and aspects live in the separate
+/// [`aspects`](Self::aspects) map, consulted only for dependents that
+/// narrowed their dependency through an
+/// [`InheritedModel`](crate::InheritedModel).
 #[derive(Debug)]
 pub struct InheritedBehavior<V: InheritedView> {
     /// Cached data for dependents.
@@ -899,6 +927,12 @@ pub struct InheritedBehavior<V: InheritedView> {
     /// for `BuildOwner::schedule_build_for(id, depth)` so the rebuild
     /// heap orders dependents correctly without a separate tree walk.
     pub dependents: HashMap<ElementId, usize>,
+    /// Aspects registered by dependents that depend on only part of the
+    /// data.
+    ///
+    /// A dependent absent from this map depends on every change. One
+    /// present is notified only if any of its aspects is affected.
+    pub aspects: HashMap<ElementId, Vec<crate::InheritedAspect>>,
     /// Marker for view type.
     _phantom: PhantomData<V>,
 }
@@ -911,6 +945,7 @@ pub fn new(view: &V) -> Self {
             data: view.data().clone(),
             view_cache: view.clone(),
             dependents: HashMap::new(),
+            aspects: HashMap::new(),
             _phantom: PhantomData,
         }
     }
@@ -925,13 +960,41 @@ pub fn data(&self) -> &V::Data {
     /// Idempotent: re-registering the same `element` overwrites its
     /// stored depth (depths can change across reconciliation, so the
     /// latest call wins). HashMap inherently dedups on key.
+    ///
+    /// The dependent depends on every change from now on, dropping any
+    /// aspects it registered earlier.
     pub fn add_dependent(&mut self, element: ElementId, depth: usize) {
         self.dependents.insert(element, depth);
+        self.aspects.remove(&element);
+    }
+
+    /// Register a dependent narrowed to `aspect`.
+    ///
+    /// Distinct aspects accumulate; re-registering an equal aspect (as a
+    /// dependent does on every rebuild) is a no-op. A dependent that already
+    /// depends on every change (registered via [`add_dependent`](Self::add_dependent)) keeps
+    /// doing so; only its depth is refreshed.
+    pub fn add_dependent_aspect(
+        &mut self,
+        element: ElementId,
+        depth: usize,
+        aspect: crate::InheritedAspect,
+    ) {
+        let depends_on_all =
+            self.dependents.contains_key(&element) && !self.aspects.contains_key(&element);
+        self.dependents.insert(element, depth);
+        if !depends_on_all {
+            let aspects = self.aspects.entry(element).or_default();
+            if !aspects.iter().any(|existing| existing.same_as(&aspect)) {
+                aspects.push(aspect);
+            }
+        }
     }
 
     /// Remove a dependent element.
     pub fn remove_dependent(&mut self, element: ElementId) {
         self.dependents.remove(&element);
+        self.aspects.remove(&element);
     }
 
     /// Get all dependent elements (id -> depth map).
@@ -954,6 +1017,21 @@ fn view_as_any(&self) -> &dyn std::any::Any {
     fn record_dependent(&mut self, dependent: ElementId, depth: usize) {
         self.add_dependent(dependent, depth);
     }
+
+    fn record_dependent_aspect(
+        &mut self,
+        dependent: ElementId,
+        depth: usize,
+        aspect: crate::InheritedAspect,
+    ) {
+        self.add_dependent_aspect(dependent, depth, aspect);
+    }
+
+    fn for_each_dependent(&self, visitor: &mut dyn FnMut(ElementId, usize)) {
+        for (&dep_id, &dep_depth) in &self.dependents {
+            visitor(dep_id, dep_depth);
+        }
+    }
 }
 
 impl<V, A> ElementBehavior<V, A> for InheritedBehavior<V>
@@ -1006,7 +1084,17 @@ fn on_view_updated(
                 "InheritedBehavior::on_view_updated notifying {} dependents",
                 self.dependents.len()
             );
+            let new_any: &dyn std::any::Any = core.view();
+            let old_any: &dyn std::any::Any = old_view;
             for (&dep_id, &dep_depth) in &self.dependents {
+                // An aspect-narrowed dependent is skipped unless one of its
+                // aspects is affected (Flutter `InheritedModel`
+                // `updateShouldNotifyDependent`).
+                if let Some(aspects) = self.aspects.get(&dep_id)
+                    && !aspects.iter().any(|a| a.is_affected(new_any, old_any))
+                {
+                    continue;
+                }
                 // Flutter parity (`framework.dart:6371-6374`):
                 // `notifyDependent` calls `dependent.didChangeDependencies`.
                 // We split this across two phases — the set-flag part
@@ -1033,6 +1121,7 @@ fn on_unmount(&mut self, _core: &mut ElementCore<V, A>, _owner: &mut crate::Elem
         // view-update.
         let count = self.dependents.len();
         self.dependents.clear();
+        self.aspects.clear();
         tracing::debug!("InheritedBehavior::on_unmount cleared {} dependents", count);
     }
 }
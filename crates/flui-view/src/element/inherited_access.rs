@@ -52,4 +52,28 @@ pub trait InheritedElementAccess {
     /// (HashMap keyed by id) so reconciliation-driven depth changes are
     /// captured without leaving stale entries.
     fn record_dependent(&mut self, dependent: ElementId, depth: usize);
+
+    /// Register a dependent that only cares about `aspect` of the
+    /// provided data (see [`InheritedModel`](crate::InheritedModel)).
+    ///
+    /// Aspects accumulate across calls. A dependent already registered
+    /// through [`record_dependent`](Self::record_dependent) depends on every
+    /// change and stays that way — the aspect is dropped.
+    ///
+    /// Flutter parity: `InheritedModel.updateDependencies`.
+    fn record_dependent_aspect(
+        &mut self,
+        dependent: ElementId,
+        depth: usize,
+        aspect: crate::InheritedAspect,
+    );
+
+    /// Visit every registered dependent as `(dependent, depth)`.
+    ///
+    /// Visitor-shaped rather than returning a collection so a caller
+    /// scheduling the dependents (e.g.
+    /// [`ElementTree::mark_dependents_dirty`](crate::tree::ElementTree::mark_dependents_dirty))
+    /// walks the map in place without an intermediate allocation. Order is
+    /// unspecified — the dirty heap re-sorts by depth anyway.
+    fn for_each_dependent(&self, visitor: &mut dyn FnMut(ElementId, usize));
 }
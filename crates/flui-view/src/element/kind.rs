@@ -649,6 +649,10 @@ fn is_dirty(&self) -> bool {
         self.element().is_dirty()
     }
 
+    fn mark_needs_build_shared(&self) {
+        self.element().mark_needs_build_shared();
+    }
+
     fn build_into_views(
         &mut self,
         owner: &mut crate::ElementOwner<'_>,
@@ -14,6 +14,7 @@
 pub(crate) mod layout_builder;
 mod lifecycle;
 mod notification;
+pub(crate) mod notification_listener;
 mod render_object_element;
 mod root;
 pub(crate) mod sliver_adaptor;
@@ -64,6 +65,7 @@
     LayoutChangedNotification, NotifiableElement, Notification, ScrollNotification,
     SizeChangedNotification,
 };
+pub use notification_listener::NotificationListener;
 pub use render_object_element::{RenderObjectElement, RenderSlot, RenderTreeRootElement};
 pub use root::{RootElement, RootElementImpl};
 pub use sliver_adaptor::{SliverGridLazy, SliverList};
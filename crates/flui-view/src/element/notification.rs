@@ -9,7 +9,8 @@
 //! This corresponds to Flutter's `Notification` class and
 //! `NotifiableElementMixin`:
 //! - `Notification.dispatch()` → start bubbling
-//! - `NotificationListener` → widget that handles notifications
+//! - `NotificationListener` → [`NotificationListener`](super::NotificationListener),
+//!   the widget that handles notifications
 //! - `NotifiableElementMixin` → element mixin for notification handling
 
 use std::any::{Any, TypeId};
@@ -0,0 +1,165 @@
+//! [`NotificationListener`] view + element — the catching end of notification
+//! bubbling.
+//!
+//! [`BuildContext::dispatch_notification`](crate::BuildContext::dispatch_notification)
+//! walks the strict ancestors of the dispatching element and calls each one's
+//! object-safe [`ElementBase::on_notification`](crate::view::ElementBase::on_notification)
+//! with `(TypeId, &dyn Any)`. Every element answers `false` except this one:
+//! [`NotificationListenerBehavior`] checks the `TypeId` against its `N`,
+//! downcasts, and hands the typed notification to the view's callback. The
+//! callback's return value is the bubble's stop flag.
+//!
+//! Otherwise the element is a transparent single-child proxy — no render
+//! object, child taken straight from the view — built with the same
+//! `proxy_style_views` helper as [`ProxyBehavior`](super::ProxyBehavior).
+//!
+//! Flutter parity: `widgets/notification_listener.dart` —
+//! `NotificationListener<T>` and `_NotificationElement<T>.onNotification`.
+
+use std::{
+    any::{Any, TypeId},
+    marker::PhantomData,
+    rc::Rc,
+};
+
+use super::{
+    Single, behavior::ElementBehavior, behavior_commons::proxy_style_views, generic::ElementCore,
+    notification::Notification, unified::Element,
+};
+use crate::{
+    BoxedView,
+    view::{IntoView, View, ViewExt},
+};
+
+/// The erased callback stored on [`NotificationListener`].
+///
+/// `Rc` keeps the view cheap to clone on rebuild while leaving the callback
+/// UI-owner-local under ADR-0027 — it may capture `!Send` state.
+type NotificationCallback<N> = Rc<dyn Fn(&N) -> bool>;
+
+/// Catches notifications of type `N` bubbling up from its descendants.
+///
+/// `on_notification` runs for every `N` dispatched from inside `child`. Return
+/// `true` to stop the notification here; return `false` to let it keep
+/// bubbling to listeners further up. Notifications of any other type pass
+/// through untouched, so listeners for different types can nest freely.
+///
+/// ```
+/// use flui_view::element::NotificationListener;
+/// use flui_view::view::ErrorView;
+/// use flui_view::ScrollNotification;
+///
+/// let listener = NotificationListener::<ScrollNotification>::new(
+///     |scroll| scroll.offset > 0.0,
+///     ErrorView::new("scrollable"),
+/// );
+/// ```
+///
+/// The callback sees notifications only from descendants: a notification
+/// dispatched from the listener's own context starts at its parent.
+pub struct NotificationListener<N: Notification> {
+    on_notification: NotificationCallback<N>,
+    child: BoxedView,
+    _notification: PhantomData<fn(&N)>,
+}
+
+impl<N: Notification> NotificationListener<N> {
+    /// Call `on_notification` for each `N` dispatched below `child`; a `true`
+    /// return stops the notification from bubbling further.
+    pub fn new<F>(on_notification: F, child: impl IntoView) -> Self
+    where
+        F: Fn(&N) -> bool + 'static,
+    {
+        Self {
+            on_notification: Rc::new(on_notification),
+            child: child.into_view().boxed(),
+            _notification: PhantomData,
+        }
+    }
+
+    /// The wrapped child.
+    pub fn child(&self) -> &dyn View {
+        &self.child
+    }
+}
+
+// Manual impls: deriving would bound `N: Clone`, which the callback and
+// marker do not need.
+impl<N: Notification> Clone for NotificationListener<N> {
+    fn clone(&self) -> Self {
+        Self {
+            on_notification: Rc::clone(&self.on_notification),
+            child: self.child.clone(),
+            _notification: PhantomData,
+        }
+    }
+}
+
+impl<N: Notification> std::fmt::Debug for NotificationListener<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NotificationListener")
+            .field("notification", &std::any::type_name::<N>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<N: Notification> View for NotificationListener<N> {
+    fn create_element(&self) -> crate::element::ElementKind {
+        crate::element::ElementKind::Notification(Box::new(NotificationListenerElement::new(
+            self,
+            NotificationListenerBehavior,
+        )))
+    }
+}
+
+/// The concrete element type for [`NotificationListener`].
+pub(crate) type NotificationListenerElement<N> =
+    Element<NotificationListener<N>, Single, NotificationListenerBehavior>;
+
+impl<N: Notification> crate::element::NotificationElementBase for NotificationListenerElement<N> {}
+
+// ============================================================================
+// BEHAVIOR
+// ============================================================================
+
+/// Behavior for [`NotificationListener`] elements: a pass-through proxy that
+/// answers the notification hook.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct NotificationListenerBehavior;
+
+impl<N: Notification> ElementBehavior<NotificationListener<N>, Single>
+    for NotificationListenerBehavior
+{
+    fn debug_kind(&self) -> &'static str {
+        "NotificationListenerElement"
+    }
+
+    fn build_into_views(
+        &mut self,
+        core: &mut ElementCore<NotificationListener<N>, Single>,
+        _owner: &mut crate::ElementOwner<'_>,
+    ) -> Vec<Box<dyn View>> {
+        proxy_style_views(
+            core,
+            "NotificationListenerBehavior",
+            NotificationListener::child,
+        )
+    }
+
+    /// Filters on `N` before downcasting, so a listener for another type
+    /// answers `false` and the bubble walks past it.
+    fn on_notification(
+        &self,
+        core: &ElementCore<NotificationListener<N>, Single>,
+        type_id: TypeId,
+        notification: &dyn Any,
+    ) -> bool {
+        if type_id != TypeId::of::<N>() {
+            return false;
+        }
+        let Some(typed) = notification.downcast_ref::<N>() else {
+            return false;
+        };
+        (core.view().on_notification)(typed)
+    }
+}
@@ -183,6 +183,10 @@ fn is_dirty(&self) -> bool {
         self.core.is_dirty()
     }
 
+    fn mark_needs_build_shared(&self) {
+        self.core.mark_dirty_for_dispatch();
+    }
+
     fn set_pipeline_owner_any(&mut self, owner: Arc<dyn Any + Send + Sync>) {
         self.core.set_pipeline_owner_any(owner);
     }
@@ -269,16 +273,17 @@ fn parent_data_config(&self) -> Option<Box<dyn flui_rendering::parent_data::Pare
     // Notification handler protocol
     //
     // Routes through the behavior. The trait-default `false` keeps
-    // non-listener elements out of the bubble walk. Production
-    // listener behaviors (e.g. a future NotificationListener<N> widget)
-    // override `ElementBehavior::on_notification` to translate the
-    // object-safe `(TypeId, &dyn Any)` shape into the typed
-    // `NotifiableElement<N>` callback at the impl site — single dyn
-    // boundary at dispatch only (Constitution Principle 4).
+    // non-listener elements out of the bubble walk. Listener behaviors
+    // (`NotificationListenerBehavior`) override
+    // `ElementBehavior::on_notification` to translate the object-safe
+    // `(TypeId, &dyn Any)` shape into their typed callback, read off the
+    // core's view — single dyn boundary at dispatch only (Constitution
+    // Principle 4).
     // ========================================================================
 
     fn on_notification(&self, type_id: std::any::TypeId, notification: &dyn Any) -> bool {
-        self.behavior.on_notification(type_id, notification)
+        self.behavior
+            .on_notification(&self.core, type_id, notification)
     }
 
     // ========================================================================
@@ -304,7 +309,18 @@ fn update(&mut self, new_view: &dyn View, owner: &mut crate::ElementOwner<'_>) {
         // hooks (e.g. `ViewState::did_update_view`,
         // `InheritedBehavior::on_view_updated` for dependent notification).
         let old_view = self.core.view().clone();
+        let was_dirty = self.core.is_dirty();
         if self.core.update_view(new_view) {
+            // A proxy whose config is unchanged keeps the new view but skips
+            // re-diffing its child. Only undo the swap's own dirty mark — a
+            // rebuild that was already pending must still run.
+            if !was_dirty
+                && !self
+                    .behavior
+                    .should_rebuild_on_update(&self.core, &old_view)
+            {
+                self.core.clear_dirty();
+            }
             // Notify behavior of update
             self.behavior.on_update(&self.core, owner);
             self.behavior.on_view_updated(&self.core, &old_view, owner);
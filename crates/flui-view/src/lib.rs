@@ -162,8 +162,8 @@ pub fn test_only_clear_global_key_registry() {
 // Notification system
 pub use element::{
     DragEndNotification, DragStartNotification, FocusNotification, KeepAliveNotification,
-    LayoutChangedNotification, NotifiableElement, Notification, ScrollNotification,
-    SizeChangedNotification,
+    LayoutChangedNotification, NotifiableElement, Notification, NotificationListener,
+    ScrollNotification, SizeChangedNotification,
 };
 // Slot types for multi-child elements (re-exported from flui-tree, canonical home)
 pub use element::{ElementSlot, IndexedSlot};
@@ -189,11 +189,12 @@ pub fn test_only_clear_global_key_registry() {
 pub use tree::{ElementNode, ElementTree};
 pub use view::{
     AnimatedElement, AnimatedView, BoxedElement, BoxedView, ElementBase, ElementExt, ErrorElement,
-    ErrorView, ErrorViewBuilder, FlutterError, InheritedElement, InheritedView, IntoElement,
-    IntoView, Memo, ParentDataConfig, ParentDataElement, ParentDataView, ProxyElement, ProxyView,
-    RenderElement, RenderObjectContext, RenderObjectContextError, RenderView, RootRenderElement,
-    RootRenderView, StatefulElement, StatefulView, StatelessElement, StatelessView, View, ViewExt,
-    ViewState, clear_error_view_builder, set_error_view_builder,
+    ErrorView, ErrorViewBuilder, FlutterError, InheritedAspect, InheritedElement, InheritedModel,
+    InheritedView, IntoElement, IntoView, Memo, ParentDataConfig, ParentDataElement,
+    ParentDataView, ProxyElement, ProxyView, RenderElement, RenderObjectContext,
+    RenderObjectContextError, RenderView, RootRenderElement, RootRenderView, StatefulElement,
+    StatefulView, StatelessElement, StatelessView, View, ViewExt, ViewState,
+    clear_error_view_builder, set_error_view_builder,
 };
 
 // ============================================================================
@@ -232,15 +233,16 @@ pub mod prelude {
         child::{Child, Children},
         element::{
             ElementSlot, IndexedSlot, LayoutChangedNotification, Lifecycle, NotifiableElement,
-            Notification, RootElement,
+            Notification, NotificationListener, RootElement,
         },
         key::{GlobalKey, GlobalKeyId, ObjectKey, ValueKey},
         owner::{BuildOwner, ElementOwner},
         tree::{ElementNode, ElementTree},
         view::{
-            AnimatedView, BoxedView, InheritedView, IntoView, Memo, ParentDataConfig,
-            ParentDataView, ProxyView, RenderObjectContext, RenderObjectContextError, RenderView,
-            StatefulView, StatelessView, View, ViewExt, ViewState,
+            AnimatedView, BoxedView, InheritedModel, InheritedView, IntoView, Memo,
+            ParentDataConfig, ParentDataView, ProxyView, RenderObjectContext,
+            RenderObjectContextError, RenderView, StatefulView, StatelessView, View, ViewExt,
+            ViewState,
         },
     };
 }
@@ -176,6 +176,15 @@ pub struct BuildOwner {
     /// split-borrow.
     pub(crate) global_keys: HashMap<u64, ElementId>,
 
+    /// `GlobalKey` hash -> the parent that configured a child with that key
+    /// during the current `build_scope`. A second parent claiming the same
+    /// key in one build is the "multiple widgets used the same GlobalKey"
+    /// error; cleared when the scope ends.
+    ///
+    /// `pub(crate)` for the [`ElementOwner`](super::ElementOwner)
+    /// split-borrow.
+    pub(crate) global_key_claims: HashMap<u64, ElementId>,
+
     /// Elements that have been deactivated and are pending unmount.
     /// These are unmounted in `finalize_tree()`.
     ///
@@ -318,6 +327,7 @@ pub fn new() -> Self {
             dirty_elements: BinaryHeap::new(),
             dirty_set: std::collections::HashSet::new(),
             global_keys: HashMap::new(),
+            global_key_claims: HashMap::new(),
             inactive_elements: Vec::new(),
             pending_dependency_changes: std::collections::HashSet::new(),
             #[cfg(debug_assertions)]
@@ -482,6 +492,7 @@ fn rekey_dirty_depths(&mut self, tree: &ElementTree) {
     pub fn element_owner_mut(&mut self) -> super::ElementOwner<'_> {
         super::ElementOwner {
             global_keys: &mut self.global_keys,
+            global_key_claims: None,
             dirty_elements: &mut self.dirty_elements,
             dirty_set: &mut self.dirty_set,
             inactive_elements: &mut self.inactive_elements,
@@ -686,6 +697,7 @@ pub fn build_scope(&mut self, tree: &mut ElementTree) {
             let build_outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
                 let mut element_owner = super::ElementOwner {
                     global_keys: &mut self.global_keys,
+                    global_key_claims: None,
                     dirty_elements: &mut self.dirty_elements,
                     dirty_set: &mut self.dirty_set,
                     inactive_elements: &mut self.inactive_elements,
@@ -732,16 +744,27 @@ pub fn build_scope(&mut self, tree: &mut ElementTree) {
                 if let Some(node) = tree.get_mut(record.provider)
                     && let Some(accessor) = node.element_mut().as_inherited_mut()
                 {
-                    accessor.record_dependent(record.dependent, record.depth);
+                    match record.aspect {
+                        Some(aspect) => {
+                            accessor.record_dependent_aspect(
+                                record.dependent,
+                                record.depth,
+                                aspect,
+                            );
+                        }
+                        None => accessor.record_dependent(record.dependent, record.depth),
+                    }
                 }
             }
 
             // ── Phase 2: reconcile the returned views against the node's
             // slab-resident children with a fresh `&mut tree`. Newly inserted
             // children are scheduled inside the reconciler so this same drain
-            // loop reaches them.
+            // loop reaches them. Only this handle tracks GlobalKey claims:
+            // a claim is "this parent's fresh build configures the key".
             let mut element_owner = super::ElementOwner {
                 global_keys: &mut self.global_keys,
+                global_key_claims: Some(&mut self.global_key_claims),
                 dirty_elements: &mut self.dirty_elements,
                 dirty_set: &mut self.dirty_set,
                 inactive_elements: &mut self.inactive_elements,
@@ -771,6 +794,11 @@ pub fn build_scope(&mut self, tree: &mut ElementTree) {
         // before a component-deferred sibling does not invert their layout.
         tree.reorder_render_children_after_build();
 
+        // Claims are per build: a parent that is not rebuilt next scope keeps
+        // its (possibly stale) configuration without blocking a legitimate
+        // move of its keyed child.
+        self.global_key_claims.clear();
+
         #[cfg(debug_assertions)]
         {
             self.building = false;
@@ -907,6 +935,7 @@ pub fn service_child_requests(
             // Inline split-borrow (same pattern as `build_scope` catch_unwind).
             let mut inline_owner = super::ElementOwner {
                 global_keys: &mut self.global_keys,
+                global_key_claims: None,
                 dirty_elements: &mut self.dirty_elements,
                 dirty_set: &mut self.dirty_set,
                 inactive_elements: &mut self.inactive_elements,
@@ -1034,6 +1063,7 @@ pub fn finalize_tree(&mut self, tree: &mut ElementTree) {
         // build-time tree handle is absent.
         let mut element_owner = super::ElementOwner {
             global_keys: &mut self.global_keys,
+            global_key_claims: None,
             dirty_elements: &mut self.dirty_elements,
             dirty_set: &mut self.dirty_set,
             inactive_elements: &mut self.inactive_elements,
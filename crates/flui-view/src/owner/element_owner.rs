@@ -105,6 +105,11 @@ pub struct ElementOwner<'a> {
     /// register / unregister.
     pub(crate) global_keys: &'a mut HashMap<u64, ElementId>,
 
+    /// Per-build `GlobalKey` claims: key hash → the parent whose rebuild
+    /// configured it. `Some` only on the `build_scope` reconcile handle;
+    /// lifecycle paths outside a build do not claim.
+    pub(crate) global_key_claims: Option<&'a mut HashMap<u64, ElementId>>,
+
     /// Dirty heap, sorted by depth (shallowest first). Pushed by
     /// `schedule_build_for`, drained by `BuildOwner::build_scope` at
     /// frame start.
@@ -229,6 +234,35 @@ pub fn take_global_key_for_reparent(&mut self, key_hash: u64) -> Option<ElementI
         self.global_keys.remove(&key_hash)
     }
 
+    /// Record that `parent`'s current build configures the child carrying
+    /// the `GlobalKey` hashed to `key_hash`.
+    ///
+    /// Returns the OTHER parent when a different parent already claimed the
+    /// key in this build — two live configurations for one key. Always
+    /// `None` outside a `build_scope` reconcile.
+    ///
+    /// Flutter parity: `BuildOwner._debugReserveGlobalKeyFor`
+    /// (`framework.dart`).
+    pub(crate) fn claim_global_key(
+        &mut self,
+        key_hash: u64,
+        parent: ElementId,
+    ) -> Option<ElementId> {
+        let claims = self.global_key_claims.as_deref_mut()?;
+        match claims.insert(key_hash, parent) {
+            Some(previous) if previous != parent => Some(previous),
+            _ => None,
+        }
+    }
+
+    /// Drop every claim `parent` made earlier in this build, so a parent
+    /// rebuilt twice in one scope re-claims from its newest configuration.
+    pub(crate) fn release_global_key_claims_of(&mut self, parent: ElementId) {
+        if let Some(claims) = self.global_key_claims.as_deref_mut() {
+            claims.retain(|_, claimant| *claimant != parent);
+        }
+    }
+
     /// Schedule an element for rebuild at the next frame.
     ///
     /// Pushed onto the depth-sorted heap so parents rebuild before
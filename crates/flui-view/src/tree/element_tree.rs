@@ -8,7 +8,7 @@
 use std::num::NonZeroU32;
 use std::sync::Arc;
 
-use flui_foundation::{ElementId, RenderId, ViewKey};
+use flui_foundation::{DiagnosticsNode, ElementId, RenderId, ViewKey};
 use flui_rendering::{parent_data::SliverMultiBoxAdaptorParentData, pipeline::PipelineOwner};
 use parking_lot::RwLock;
 use slab::Slab;
@@ -320,6 +320,22 @@ pub(crate) fn child_ids(&self) -> &[ElementId] {
     pub(crate) fn set_child_ids(&mut self, ids: Vec<ElementId>) {
         self.child_ids = ids;
     }
+
+    /// Flat (childless) diagnostics entry for this node, used by
+    /// [`ElementTree::debug_dump`].
+    fn debug_diagnostics(&self, id: ElementId) -> DiagnosticsNode {
+        let Some(kind) = self.kind.as_ref() else {
+            return DiagnosticsNode::new(format!("<building> {id:?}"))
+                .property("slot", self.slot)
+                .property("depth", self.depth);
+        };
+        let element = kind.element();
+        DiagnosticsNode::new(format!("{} {id:?}", kind.variant_name()))
+            .property("lifecycle", format_args!("{:?}", element.lifecycle()))
+            .property("dirty", element.is_dirty())
+            .property("slot", self.slot)
+            .property("depth", self.depth)
+    }
 }
 
 impl std::fmt::Debug for ElementNode {
@@ -1260,11 +1276,45 @@ pub fn update(&mut self, id: ElementId, view: &dyn View, owner: &mut crate::Elem
             node.element_mut().update(view, owner);
             node.set_key(view.key().map(ViewKey::clone_key));
         }
-        // A reconfigured `ParentDataView` ancestor reaches this render child via
-        // its own re-`update` (the reconciler walks children after their
-        // parent), so re-deriving parent data here keeps it current — e.g.
-        // `Expanded`'s `flex` changing between frames.
+        // A render child re-derives the parent data its nearest
+        // `ParentDataView` ancestor contributes whenever it is itself updated.
         self.apply_ancestor_parent_data(id);
+        // A reconfigured `ParentDataView` (e.g. `Expanded`'s `flex` changing
+        // between frames) may skip rebuilding its child via `should_rebuild`,
+        // in which case no render child below it is re-`update`d. Push the new
+        // configuration down to them directly.
+        if self.get(id).is_some_and(|node| {
+            node.element().render_id().is_none() && node.element().parent_data_config().is_some()
+        }) {
+            for child_id in self.nearest_render_descendants(id) {
+                self.apply_ancestor_parent_data(child_id);
+            }
+        }
+    }
+
+    /// The render-bearing elements directly below `id`: every descendant
+    /// that owns a render node with no render-bearing element between it and
+    /// `id`. These are the render children a `ParentDataView` at `id`
+    /// configures.
+    ///
+    /// O(component-nesting depth) for the usual single-child proxy.
+    fn nearest_render_descendants(&self, id: ElementId) -> Vec<ElementId> {
+        let mut found = Vec::new();
+        let mut stack: Vec<ElementId> = self
+            .get(id)
+            .map(|node| node.child_ids().to_vec())
+            .unwrap_or_default();
+        while let Some(child_id) = stack.pop() {
+            let Some(node) = self.get(child_id) else {
+                continue;
+            };
+            if node.element().render_id().is_some() {
+                found.push(child_id);
+            } else {
+                stack.extend_from_slice(node.child_ids());
+            }
+        }
+        found
     }
 
     /// Mark an element as needing rebuild.
@@ -1274,6 +1324,171 @@ pub fn mark_needs_build(&mut self, id: ElementId) {
         }
     }
 
+    /// Iterate over `root` and all of its descendants in pre-order (parent
+    /// before children, children in slot order).
+    ///
+    /// Yields `(id, depth)` where `depth` is relative to `root` (the root
+    /// itself is `0`) — the same contract as `flui_tree::TreeNav::descendants`.
+    /// `ElementTree` cannot implement `TreeNav` itself: that trait requires
+    /// `Send + Sync` and the element graph is owner-thread-only, so the walk
+    /// lives here as an inherent method.
+    ///
+    /// Lazy: the work-stack holds only the pending siblings along the current
+    /// path, so nothing proportional to the subtree size is allocated up
+    /// front. An unknown or stale `root` yields nothing.
+    pub fn descendants(&self, root: ElementId) -> impl Iterator<Item = (ElementId, usize)> + '_ {
+        let mut stack: Vec<(ElementId, usize)> = Vec::new();
+        if self.contains(root) {
+            stack.push((root, 0));
+        }
+        std::iter::from_fn(move || {
+            let (id, depth) = stack.pop()?;
+            if let Some(node) = self.get(id) {
+                // Reverse slot order so the leftmost child is popped next.
+                stack.extend(
+                    node.child_ids()
+                        .iter()
+                        .rev()
+                        .map(|&child| (child, depth + 1)),
+                );
+            }
+            Some((id, depth))
+        })
+    }
+
+    /// The render object of the element currently keyed by `key`: its own
+    /// when it is a render element, otherwise the first one below it.
+    ///
+    /// Flutter's `GlobalKey.currentContext?.findRenderObject()`. Tooltips,
+    /// anchored popovers and the inspector read it after layout and hand the
+    /// id to [`PipelineOwner::global_paint_bounds`] for screen-space bounds.
+    ///
+    /// The key resolves against the registration each node records at mount
+    /// ([`ElementNode::registered_global_key_hash`], the per-node mirror of
+    /// `BuildOwner`'s global-key registry) rather than the realm-scoped
+    /// [`GlobalKey::current_element`](crate::GlobalKey::current_element), so it
+    /// answers for *this* tree whether or not a realm is active. The lookup is
+    /// a linear scan — fine for tooling, not meant for per-frame code.
+    ///
+    /// `None` when no active element carries `key`, or when its subtree has
+    /// no render object.
+    pub fn find_render_object<T: 'static>(&self, key: &crate::GlobalKey<T>) -> Option<RenderId> {
+        let keyed = self.iter_nodes().find_map(|(id, node)| {
+            (node.registered_global_key_hash() == Some(key.id())
+                && node.element().lifecycle() == crate::element::Lifecycle::Active)
+                .then_some(id)
+        })?;
+        // A component element owns no render object; its subtree's first one
+        // is what it paints as (Flutter's `Element.renderObject`).
+        self.descendants(keyed)
+            .find_map(|(id, _)| self.get(id)?.element().render_id())
+    }
+
+    /// Mark `root` and every descendant as needing a rebuild, in one pass.
+    ///
+    /// Each element's dirty flag is flipped through
+    /// [`ElementBase::mark_needs_build_shared`] and the element is pushed onto
+    /// the owner's dirty heap at its tree depth, so the next
+    /// [`BuildOwner::build_scope`](crate::BuildOwner::build_scope) rebuilds
+    /// the whole subtree shallowest-first. Flipping the flag alone would be
+    /// inert — `build_scope` only visits elements that are on the heap.
+    ///
+    /// Takes `&self`: the tree structure is only read and the flags are
+    /// atomic, so this can run while other shared borrows of the tree (e.g.
+    /// `BuildContext` ancestor lookups) are live. An element that is
+    /// currently extracted for its own build is skipped — it is already
+    /// being rebuilt.
+    ///
+    /// Returns the number of elements scheduled. Already-scheduled elements
+    /// are counted but not re-queued (`schedule_build_for` dedups).
+    pub fn mark_subtree_dirty(
+        &self,
+        root: ElementId,
+        owner: &mut crate::ElementOwner<'_>,
+    ) -> usize {
+        let mut marked = 0;
+        for (id, _) in self.descendants(root) {
+            let Some(node) = self.get(id) else {
+                continue;
+            };
+            let Some(element) = node.element_opt() else {
+                continue;
+            };
+            element.mark_needs_build_shared();
+            owner.schedule_build_for(id, node.depth);
+            marked += 1;
+        }
+        marked
+    }
+
+    /// Mark only the elements that registered a dependency on the
+    /// [`InheritedView`](crate::view::InheritedView) provider `provider`.
+    ///
+    /// Same notification the provider itself sends when
+    /// `update_should_notify` returns `true`: each live dependent gets a
+    /// pending `did_change_dependencies` and is scheduled for rebuild (the
+    /// dirty flag is set by `build_scope` when it consumes the pending
+    /// change). Unlike [`mark_subtree_dirty`](Self::mark_subtree_dirty),
+    /// non-dependent descendants of the provider are left alone.
+    ///
+    /// Returns the number of dependents scheduled; `0` when `provider` is
+    /// not a live inherited element. Dependent ids that no longer resolve
+    /// (unmounted since they registered) are skipped.
+    ///
+    /// Flutter parity: `InheritedElement.notifyClients`
+    /// (`framework.dart:6414`).
+    pub fn mark_dependents_dirty(
+        &self,
+        provider: ElementId,
+        owner: &mut crate::ElementOwner<'_>,
+    ) -> usize {
+        let Some(inherited) = self
+            .get(provider)
+            .and_then(ElementNode::element_opt)
+            .and_then(|element| element.as_inherited())
+        else {
+            return 0;
+        };
+        let mut marked = 0;
+        inherited.for_each_dependent(&mut |dep_id, dep_depth| {
+            if !self.contains(dep_id) {
+                return;
+            }
+            owner.note_dependency_change(dep_id);
+            owner.schedule_build_for(dep_id, dep_depth);
+            marked += 1;
+        });
+        marked
+    }
+
+    /// Render the element tree as an indented, human-readable dump.
+    ///
+    /// One entry per element, in pre-order from the root: the element kind
+    /// and id, followed by its lifecycle, dirty flag, slot and depth. Each
+    /// entry is a flat [`DiagnosticsNode`] formatted at its tree depth, so
+    /// the output matches the rest of the diagnostics surface. An element
+    /// extracted for an in-flight build prints as `<building>`.
+    ///
+    /// Explicitly opt-in and never called by the framework. Nodes are
+    /// formatted one at a time into a single buffer rather than assembled
+    /// into a nested `DiagnosticsNode` tree first, so memory stays linear
+    /// in the output size even for very deep trees.
+    ///
+    /// Flutter parity: `debugDumpApp` (`binding.dart`).
+    pub fn debug_dump(&self) -> String {
+        let Some(root) = self.root else {
+            return String::from("<empty element tree>\n");
+        };
+        let mut out = String::new();
+        for (id, depth) in self.descendants(root) {
+            let Some(node) = self.get(id) else {
+                continue;
+            };
+            out.push_str(&node.debug_diagnostics(id).format_deep(depth));
+        }
+        out
+    }
+
     /// Deactivate an element (temporary removal).
     pub fn deactivate(&mut self, id: ElementId) {
         if let Some(node) = self.get_mut(id) {
@@ -2127,4 +2342,174 @@ fn globalkey_retake_recomputes_inherited_scope() {
 
         crate::test_only_clear_global_key_registry();
     }
+
+    // ========================================================================
+    // Bulk dirty marking — `mark_subtree_dirty` / `mark_dependents_dirty`
+    // ========================================================================
+
+    /// root -> [a -> [a1, a2], b]; returns `(root, a, a1, a2, b)` with the
+    /// `child_ids` the reconciler would have written.
+    fn subtree_fixture(
+        tree: &mut ElementTree,
+        owner: &mut BuildOwner,
+    ) -> (ElementId, ElementId, ElementId, ElementId, ElementId) {
+        let root = tree.mount_root(&leaf("root"), &mut owner.element_owner_mut());
+        let a = tree.insert(&leaf("a"), root, 0, &mut owner.element_owner_mut());
+        let b = tree.insert(&leaf("b"), root, 1, &mut owner.element_owner_mut());
+        let a1 = tree.insert(&leaf("a1"), a, 0, &mut owner.element_owner_mut());
+        let a2 = tree.insert(&leaf("a2"), a, 1, &mut owner.element_owner_mut());
+        tree.get_mut(root).unwrap().set_child_ids(vec![a, b]);
+        tree.get_mut(a).unwrap().set_child_ids(vec![a1, a2]);
+        (root, a, a1, a2, b)
+    }
+
+    #[test]
+    fn descendants_is_preorder_with_relative_depth() {
+        let mut tree = ElementTree::new();
+        let mut owner = BuildOwner::new();
+        let (root, a, a1, a2, b) = subtree_fixture(&mut tree, &mut owner);
+
+        let walk: Vec<_> = tree.descendants(root).collect();
+        assert_eq!(walk, vec![(root, 0), (a, 1), (a1, 2), (a2, 2), (b, 1)]);
+
+        let sub: Vec<_> = tree.descendants(a).collect();
+        assert_eq!(sub, vec![(a, 0), (a1, 1), (a2, 1)]);
+
+        tree.remove(b, &mut owner.element_owner_mut());
+        assert_eq!(tree.descendants(b).count(), 0, "stale root yields nothing");
+    }
+
+    #[test]
+    fn mark_subtree_dirty_schedules_exactly_the_subtree() {
+        let mut tree = ElementTree::new();
+        let mut owner = BuildOwner::new();
+        let (root, a, a1, a2, b) = subtree_fixture(&mut tree, &mut owner);
+        owner.dirty_elements.clear();
+        owner.dirty_set.clear();
+
+        let marked = tree.mark_subtree_dirty(a, &mut owner.element_owner_mut());
+
+        assert_eq!(marked, 3);
+        for id in [a, a1, a2] {
+            assert!(owner.dirty_set.contains(&id), "{id:?} must be scheduled");
+            assert!(tree.get(id).unwrap().element().is_dirty());
+        }
+        for id in [root, b] {
+            assert!(
+                !owner.dirty_set.contains(&id),
+                "{id:?} is outside the subtree"
+            );
+        }
+        // Heap entries carry the authoritative tree depth.
+        let mut depths: Vec<_> = owner
+            .dirty_elements
+            .iter()
+            .map(|std::cmp::Reverse(e)| (e.id(), e.depth()))
+            .collect();
+        depths.sort_by_key(|&(_, depth)| depth);
+        assert_eq!(depths[0], (a, 1));
+        assert!(depths[1..].iter().all(|&(_, depth)| depth == 2));
+    }
+
+    #[test]
+    fn mark_subtree_dirty_dedups_against_already_scheduled() {
+        let mut tree = ElementTree::new();
+        let mut owner = BuildOwner::new();
+        let (root, ..) = subtree_fixture(&mut tree, &mut owner);
+        owner.dirty_elements.clear();
+        owner.dirty_set.clear();
+
+        tree.mark_subtree_dirty(root, &mut owner.element_owner_mut());
+        tree.mark_subtree_dirty(root, &mut owner.element_owner_mut());
+
+        assert_eq!(owner.dirty_count(), 5, "a second pass must not re-queue");
+    }
+
+    #[test]
+    fn mark_dependents_dirty_marks_only_registered_dependents() {
+        let mut tree = ElementTree::new();
+        let mut owner = BuildOwner::new();
+        let provider = tree.mount_root(&theme(1), &mut owner.element_owner_mut());
+        let dependent = tree.insert(&leaf("dep"), provider, 0, &mut owner.element_owner_mut());
+        let bystander = tree.insert(&leaf("other"), provider, 1, &mut owner.element_owner_mut());
+        let gone = tree.insert(&leaf("gone"), provider, 2, &mut owner.element_owner_mut());
+        {
+            let access = tree
+                .get_mut(provider)
+                .unwrap()
+                .element_mut()
+                .as_inherited_mut()
+                .unwrap();
+            access.record_dependent(dependent, 1);
+            access.record_dependent(gone, 1);
+        }
+        tree.remove(gone, &mut owner.element_owner_mut());
+        owner.dirty_elements.clear();
+        owner.dirty_set.clear();
+
+        let marked = tree.mark_dependents_dirty(provider, &mut owner.element_owner_mut());
+
+        assert_eq!(marked, 1, "the unmounted dependent is skipped");
+        assert!(owner.dirty_set.contains(&dependent));
+        assert!(owner.pending_dependency_changes.contains(&dependent));
+        assert!(!owner.dirty_set.contains(&bystander));
+        assert!(!owner.dirty_set.contains(&provider));
+
+        // A non-inherited element has no dependents to mark.
+        assert_eq!(
+            tree.mark_dependents_dirty(dependent, &mut owner.element_owner_mut()),
+            0
+        );
+    }
+
+    // ========================================================================
+    // debug_dump
+    // ========================================================================
+
+    #[test]
+    fn debug_dump_is_indented_preorder_with_annotations() {
+        let mut tree = ElementTree::new();
+        let mut owner = BuildOwner::new();
+        let (root, a, a1, a2, b) = subtree_fixture(&mut tree, &mut owner);
+        tree.deactivate(b);
+
+        let dump = tree.debug_dump();
+        let headers: Vec<&str> = dump
+            .lines()
+            .filter(|line| line.trim_start().starts_with("Stateless"))
+            .collect();
+        assert_eq!(
+            headers,
+            vec![
+                format!("Stateless {root:?}"),
+                format!("  Stateless {a:?}"),
+                format!("    Stateless {a1:?}"),
+                format!("    Stateless {a2:?}"),
+                format!("  Stateless {b:?}"),
+            ]
+        );
+
+        let a1_entry = dump
+            .split_once(&format!("    Stateless {a1:?}\n"))
+            .map(|(_, rest)| rest.lines().take(4).collect::<Vec<_>>())
+            .unwrap();
+        assert_eq!(
+            a1_entry,
+            vec![
+                "      lifecycle: Active",
+                "      dirty: true",
+                "      slot: 0",
+                "      depth: 2",
+            ]
+        );
+        // Freshly mounted elements are dirty until their first build.
+        assert!(dump.contains(&format!(
+            "  Stateless {b:?}\n    lifecycle: Inactive\n    dirty: true\n    slot: 1\n    depth: 1\n"
+        )));
+    }
+
+    #[test]
+    fn debug_dump_of_empty_tree() {
+        assert_eq!(ElementTree::new().debug_dump(), "<empty element tree>\n");
+    }
 }
@@ -125,6 +125,17 @@ pub(crate) fn reconcile_children_by_id(
     new_views: &[Box<dyn View>],
     owner: &mut crate::ElementOwner<'_>,
 ) {
+    // Duplicate sibling keys make the keyed match ambiguous. Like Flutter's
+    // `debugChildrenHaveDuplicateKeys` — and like a duplicated GlobalKey in
+    // `claim_global_keys` — this is fatal in debug builds. Release builds
+    // skip the extra pass over the child list and fall through to
+    // first-wins.
+    #[cfg(debug_assertions)]
+    if let Err(err) = check_duplicate_keys(new_views) {
+        panic!("{err} (children of {parent_id:?})");
+    }
+    claim_global_keys(parent_id, new_views, owner);
+
     // ── Step 1: extract. Clone the parent's current child ids into an
     // OWNED vec, then DROP the parent borrow. From here on no reference
     // into the slab outlives a single statement.
@@ -363,6 +374,107 @@ pub(crate) fn reconcile_children_by_id(
     }
 }
 
+/// Claim every `GlobalKey` in `new_views` for `parent_id` for the current
+/// build. A key another parent already claimed in this build means two live
+/// views carry it: the second claim would silently steal the first parent's
+/// element. Panics in debug builds; logs and lets the later claim win in
+/// release.
+///
+/// Flutter parity: `BuildOwner._debugVerifyGlobalKeyReservation`
+/// ("Multiple widgets used the same GlobalKey").
+fn claim_global_keys(
+    parent_id: ElementId,
+    new_views: &[Box<dyn View>],
+    owner: &mut crate::ElementOwner<'_>,
+) {
+    owner.release_global_key_claims_of(parent_id);
+    for view in new_views {
+        let Some(key) = view.key().filter(|key| key.is_global_key()) else {
+            continue;
+        };
+        if let Some(other_parent) = owner.claim_global_key(key.key_hash(), parent_id) {
+            tracing::error!(
+                key = ?key,
+                first_parent = ?other_parent,
+                second_parent = ?parent_id,
+                "Multiple widgets used the same GlobalKey in one build"
+            );
+            #[cfg(debug_assertions)]
+            {
+                panic!(
+                    "Multiple widgets used the same GlobalKey: {key:?} is configured under \
+                     both {other_parent:?} and {parent_id:?} in the same build. A GlobalKey \
+                     can only be specified on one widget at a time in the widget tree."
+                );
+            }
+        }
+    }
+}
+
+/// Two sibling views in one child list carry equal keys.
+///
+/// Returned by [`check_duplicate_keys`]. Keys only have to be unique among
+/// the children of one parent; the reconciler matches a keyed view to the
+/// old element with an equal key, so a duplicate leaves the match
+/// ambiguous and would silently re-home state onto the wrong child.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "Duplicate keys found: {key} appears at child slots {first_slot} and \
+     {duplicate_slot}. If multiple keyed views exist as children of another \
+     view, they must have unique keys."
+)]
+pub struct DuplicateKeyError {
+    /// `Debug` rendering of the duplicated key.
+    pub key: String,
+    /// Slot of the first view carrying the key.
+    pub first_slot: usize,
+    /// Slot of the later view repeating it.
+    pub duplicate_slot: usize,
+}
+
+/// Check that no two views in a sibling list carry equal keys.
+///
+/// Keyless views are ignored. Equality is the semantic
+/// [`ViewKey::key_eq`](flui_foundation::ViewKey::key_eq), so two distinct
+/// keys that merely collide on `key_hash` are not reported. Reports the
+/// first duplicate pair in slot order.
+///
+/// The production reconciler runs this on every child list in debug builds
+/// and panics on what it finds; multi-child views can call it themselves to
+/// validate a list before handing it over.
+///
+/// Flutter parity: `debugChildrenHaveDuplicateKeys`
+/// (`widgets/debug.dart`).
+///
+/// # Complexity
+///
+/// `O(n)` average over `n` views (one hash-bucketed pass); `O(n²)` only if
+/// every key lands in one `key_hash` bucket.
+pub fn check_duplicate_keys(views: &[Box<dyn View>]) -> Result<(), DuplicateKeyError> {
+    // Bucket slots by hash; confirm a hit with `key_eq` before reporting.
+    let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+    for (slot, view) in views.iter().enumerate() {
+        let Some(key) = view.key() else {
+            continue;
+        };
+        let bucket = seen.entry(key.key_hash()).or_default();
+        for &first_slot in bucket.iter() {
+            let first_key = views[first_slot]
+                .key()
+                .expect("BUG: only keyed slots are bucketed");
+            if first_key.key_eq(key) {
+                return Err(DuplicateKeyError {
+                    key: format!("{key:?}"),
+                    first_slot,
+                    duplicate_slot: slot,
+                });
+            }
+        }
+        bucket.push(slot);
+    }
+    Ok(())
+}
+
 /// Whether the slab child `old_id` can be updated in place by `new` —
 /// same concrete view type AND matching key.
 ///
@@ -1225,11 +1337,27 @@ fn stale_parent_is_noop() {
         assert_eq!(tree.len(), 0, "stale-parent reconcile must insert nothing");
     }
 
-    /// Duplicate keys in the NEW list: the first occurrence claims the
-    /// matching old element, every later duplicate mints a fresh one
+    /// Duplicate keys in the NEW list are a debug-build panic, the same as
+    /// a duplicated GlobalKey.
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "Duplicate keys found")]
+    fn duplicate_keys_in_new_list_panic_in_debug() {
+        let (mut tree, mut owner, root) = fixture();
+        reconcile_children_by_id(
+            &mut tree,
+            root,
+            &keyed_views(&[1, 1, 2]),
+            &mut owner.element_owner_mut(),
+        );
+    }
+
+    /// Release builds skip the duplicate check: the first occurrence claims
+    /// the matching old element, every later duplicate mints a fresh one
     /// (first-wins), and the call never panics. Ports the unique
     /// error-path case from the retired box-reconciler corpus onto the
     /// slab.
+    #[cfg(not(debug_assertions))]
     #[test]
     fn duplicate_keys_in_new_list_first_wins() {
         let (mut tree, mut owner, root) = fixture();
@@ -1266,6 +1394,136 @@ fn duplicate_keys_in_new_list_first_wins() {
         }
     }
 
+    #[test]
+    fn check_duplicate_keys_reports_first_duplicate_pair() {
+        let err =
+            check_duplicate_keys(&keyed_views(&[4, 7, 9, 7, 4])).expect_err("keys 7 and 4 repeat");
+        assert_eq!(err.first_slot, 1);
+        assert_eq!(err.duplicate_slot, 3);
+        assert_eq!(err.key, "ValueKey(7)");
+        assert!(
+            err.to_string().contains("must have unique keys"),
+            "the message must tell the author how to fix it: {err}"
+        );
+    }
+
+    #[test]
+    fn check_duplicate_keys_ignores_keyless_and_hash_collisions() {
+        assert_eq!(check_duplicate_keys(&plain_views(&[1, 1, 1])), Ok(()));
+        // Distinct keys sharing one `key_hash` are not duplicates.
+        let colliders: Vec<Box<dyn View>> = vec![
+            Box::new(ColliderView::new(1)),
+            Box::new(ColliderView::new(2)),
+        ];
+        assert_eq!(check_duplicate_keys(&colliders), Ok(()));
+        let mut mixed = keyed_views(&[1, 2]);
+        mixed.extend(plain_views(&[3]));
+        assert_eq!(check_duplicate_keys(&mixed), Ok(()));
+    }
+
+    /// Keyed stateful counter: each element's `CounterState` captures a
+    /// process-unique serial at creation, so a test can tell whether a
+    /// reconcile kept the element (same serial) or recreated it.
+    #[derive(Clone)]
+    struct KeyedCounter {
+        key: ValueKey<u32>,
+    }
+
+    struct CounterState {
+        serial: u32,
+    }
+
+    impl crate::StatefulView for KeyedCounter {
+        type State = CounterState;
+
+        fn create_state(&self) -> CounterState {
+            static NEXT: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(1);
+            CounterState {
+                serial: NEXT.fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+            }
+        }
+    }
+
+    impl crate::ViewState<KeyedCounter> for CounterState {
+        fn build(&self, view: &KeyedCounter, _ctx: &dyn BuildContext) -> impl IntoView {
+            view.clone().boxed()
+        }
+    }
+
+    impl View for KeyedCounter {
+        fn create_element(&self) -> crate::element::ElementKind {
+            crate::element::ElementKind::stateful(self)
+        }
+
+        fn key(&self) -> Option<&dyn ViewKey> {
+            Some(&self.key)
+        }
+    }
+
+    fn counters(keys: &[u32]) -> Vec<Box<dyn View>> {
+        keys.iter()
+            .map(|&k| {
+                Box::new(KeyedCounter {
+                    key: ValueKey::new(k),
+                }) as Box<dyn View>
+            })
+            .collect()
+    }
+
+    fn serial_of(tree: &ElementTree, id: ElementId) -> u32 {
+        tree.get(id)
+            .unwrap()
+            .element()
+            .state_as_any()
+            .and_then(|state| state.downcast_ref::<CounterState>())
+            .expect("KeyedCounter element owns a CounterState")
+            .serial
+    }
+
+    /// A reordered keyed stateful list keeps every `CounterState`: the
+    /// state follows its key to the new slot instead of staying at the old
+    /// index, and a keyed element inserted in the middle gets fresh state.
+    #[test]
+    fn keyed_reorder_preserves_stateful_state() {
+        let (mut tree, mut owner, root) = fixture();
+        reconcile_children_by_id(
+            &mut tree,
+            root,
+            &counters(&[1, 2, 3]),
+            &mut owner.element_owner_mut(),
+        );
+        let before = tree.get(root).unwrap().child_ids().to_vec();
+        let serials: Vec<u32> = before.iter().map(|&id| serial_of(&tree, id)).collect();
+
+        reconcile_children_by_id(
+            &mut tree,
+            root,
+            &counters(&[3, 4, 1, 2]),
+            &mut owner.element_owner_mut(),
+        );
+        let after = tree.get(root).unwrap().child_ids().to_vec();
+
+        assert_eq!(
+            serial_of(&tree, after[0]),
+            serials[2],
+            "key 3 keeps its state"
+        );
+        assert_eq!(
+            serial_of(&tree, after[2]),
+            serials[0],
+            "key 1 keeps its state"
+        );
+        assert_eq!(
+            serial_of(&tree, after[3]),
+            serials[1],
+            "key 2 keeps its state"
+        );
+        assert!(
+            !serials.contains(&serial_of(&tree, after[1])),
+            "the new key 4 gets fresh state"
+        );
+    }
+
     /// `flui::reconcile` emission coverage on the LIVE slab path
     /// (catalog #3). The production reconciler
     /// emits one typed [`ReconcileEvent`](super::ReconcileEvent) per
@@ -5,6 +5,8 @@
 //! - `reconcile_children_by_id` (in the crate-private `id_reconcile`
 //!   module) - O(N) linear child reconciliation over the slab-resident
 //!   element graph (the production reconciler after the E3 box→arena swap)
+//! - [`check_duplicate_keys`] / [`DuplicateKeyError`] - the sibling-key
+//!   uniqueness check that reconciler enforces
 //! - [`ReconcileEvent`] - structured trace stream for the keyed
 //!   reconciler (FR-035)
 
@@ -21,4 +23,5 @@
 pub mod test_utils;
 
 pub use element_tree::{ElementNode, ElementTree};
+pub use id_reconcile::{DuplicateKeyError, check_duplicate_keys};
 pub use reconcile_event::{RECONCILE_TARGET, ReconcileEvent, ReconcileEventKind};
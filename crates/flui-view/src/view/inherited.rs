@@ -5,6 +5,12 @@
 //! (`provider view TypeId → provider ElementId`) built at mount, so
 //! `ctx.depend_on::<T>()` is one hash lookup rather than an O(depth) parent
 //! walk. Mirrors Flutter's per-element `_inheritedElements` map.
+//!
+//! [`InheritedModel`] narrows dependencies to an *aspect* of the provided
+//! data, so a dependent only rebuilds when the part it reads changes.
+
+use std::any::Any;
+use std::fmt;
 
 use super::view::View;
 
@@ -89,6 +95,127 @@ pub trait InheritedView: Clone + 'static + Sized {
     fn update_should_notify(&self, old: &Self) -> bool;
 }
 
+/// An [`InheritedView`] whose dependents can depend on a single *aspect*
+/// of the provided data.
+///
+/// A dependent registers through
+/// [`BuildContextExt::depend_on_inherited_view_of_exact_type_with_aspect`](crate::BuildContextExt::depend_on_inherited_view_of_exact_type_with_aspect)
+/// with an aspect value of type `A`. When the provider updates and
+/// [`InheritedView::update_should_notify`] returns `true`, a dependent that
+/// registered aspects is only rebuilt if
+/// [`update_should_notify_dependent`](Self::update_should_notify_dependent)
+/// returns `true` for at least one of them. Dependents that used the plain
+/// [`depend_on`](crate::BuildContextExt::depend_on) still rebuild on every
+/// notifying change.
+///
+/// A provider may implement this for several aspect types.
+///
+/// # Flutter Equivalent
+///
+/// Flutter's `InheritedModel<T>` with `updateShouldNotifyDependent`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(Clone, Copy, PartialEq, Eq)]
+/// enum ThemeAspect { Color, TextStyle }
+///
+/// impl InheritedModel<ThemeAspect> for ThemeProvider {
+///     fn update_should_notify_dependent(&self, old: &Self, aspect: &ThemeAspect) -> bool {
+///         match aspect {
+///             ThemeAspect::Color => self.theme.color != old.theme.color,
+///             ThemeAspect::TextStyle => self.theme.text_style != old.theme.text_style,
+///         }
+///     }
+/// }
+/// ```
+pub trait InheritedModel<A: 'static>: InheritedView {
+    /// Does the change from `old` to `self` affect a dependent that
+    /// registered `aspect`?
+    ///
+    /// Only consulted after [`InheritedView::update_should_notify`] returned
+    /// `true`.
+    fn update_should_notify_dependent(&self, old: &Self, aspect: &A) -> bool;
+}
+
+/// A type-erased aspect a dependent registered on an [`InheritedModel`].
+///
+/// Captures the aspect value together with the provider type, so the
+/// provider element can ask whether an update affects it without naming
+/// `A`, and can recognise a re-registration of an equal aspect. Built by the
+/// typed
+/// [`depend_on_inherited_view_of_exact_type_with_aspect`](crate::BuildContextExt::depend_on_inherited_view_of_exact_type_with_aspect)
+/// wrapper and handed to
+/// [`BuildContext::depend_on_inherited_with_aspect`](crate::BuildContext::depend_on_inherited_with_aspect).
+pub struct InheritedAspect {
+    aspect: Box<dyn Any + Send + Sync>,
+    /// `(new provider, old provider, aspect) -> affected?`.
+    affected_by: fn(&dyn Any, &dyn Any, &dyn Any) -> bool,
+    /// `(aspect, other aspect) -> equal?`.
+    same_as: fn(&dyn Any, &dyn Any) -> bool,
+}
+
+impl InheritedAspect {
+    /// Wrap `aspect` for dependents of the provider type `V`.
+    pub fn new<V, A>(aspect: A) -> Self
+    where
+        V: InheritedModel<A>,
+        A: PartialEq + Send + Sync + 'static,
+    {
+        fn affected_by<V: InheritedModel<A>, A: 'static>(
+            new: &dyn Any,
+            old: &dyn Any,
+            aspect: &dyn Any,
+        ) -> bool {
+            match (
+                new.downcast_ref::<V>(),
+                old.downcast_ref::<V>(),
+                aspect.downcast_ref::<A>(),
+            ) {
+                (Some(new), Some(old), Some(aspect)) => {
+                    new.update_should_notify_dependent(old, aspect)
+                }
+                // Not the provider this aspect was registered on; rebuild
+                // rather than risk a stale dependent.
+                _ => true,
+            }
+        }
+
+        fn same_as<A: PartialEq + 'static>(aspect: &dyn Any, other: &dyn Any) -> bool {
+            matches!(
+                (aspect.downcast_ref::<A>(), other.downcast_ref::<A>()),
+                (Some(a), Some(b)) if a == b
+            )
+        }
+
+        Self {
+            aspect: Box::new(aspect),
+            affected_by: affected_by::<V, A>,
+            same_as: same_as::<A>,
+        }
+    }
+
+    /// Does the provider change `old` -> `new` (both the provider view as
+    /// `&dyn Any`) affect this aspect?
+    pub(crate) fn is_affected(&self, new: &dyn Any, old: &dyn Any) -> bool {
+        (self.affected_by)(new, old, self.aspect.as_ref())
+    }
+
+    /// Is `other` an equal aspect value of the same type?
+    ///
+    /// Used to keep a dependent's aspect set free of duplicates when it
+    /// registers the same aspect on every rebuild.
+    pub(crate) fn same_as(&self, other: &Self) -> bool {
+        (self.same_as)(self.aspect.as_ref(), other.aspect.as_ref())
+    }
+}
+
+impl fmt::Debug for InheritedAspect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InheritedAspect").finish_non_exhaustive()
+    }
+}
+
 /// Implement View for an InheritedView type.
 ///
 /// This macro creates the View implementation for an InheritedView type.
@@ -234,6 +361,62 @@ fn test_inherited_element_dependents() {
         assert!(element.behavior().dependents().contains_key(&dep2));
     }
 
+    #[derive(Clone, Copy, PartialEq)]
+    struct AnyColorChange;
+
+    impl InheritedModel<AnyColorChange> for TestThemeProvider {
+        fn update_should_notify_dependent(&self, old: &Self, _aspect: &AnyColorChange) -> bool {
+            self.theme.color != old.theme.color
+        }
+    }
+
+    #[test]
+    fn test_inherited_element_aspect_dependents() {
+        let provider = TestThemeProvider {
+            theme: TestTheme { color: 0x00FF_0000 },
+            child: DummyView,
+        };
+        let mut element = InheritedElement::new(&provider, InheritedBehavior::new(&provider));
+        let narrowed = ElementId::new(1);
+        let widened = ElementId::new(2);
+        let aspect = || InheritedAspect::new::<TestThemeProvider, _>(AnyColorChange);
+
+        // Re-registering an equal aspect (every rebuild does) does not grow
+        // the narrowed dependent's aspect set.
+        for _ in 0..3 {
+            element
+                .behavior_mut()
+                .add_dependent_aspect(narrowed, 1, aspect());
+        }
+        assert_eq!(element.behavior().aspects[&narrowed].len(), 1);
+
+        // A plain dependency widens to "every change" and stays wide.
+        element.behavior_mut().add_dependent(widened, 1);
+        element
+            .behavior_mut()
+            .add_dependent_aspect(widened, 3, aspect());
+        assert!(!element.behavior().aspects.contains_key(&widened));
+        assert_eq!(element.behavior().dependents().get(&widened), Some(&3));
+
+        element.behavior_mut().add_dependent(narrowed, 1);
+        assert!(element.behavior().aspects.is_empty());
+    }
+
+    #[test]
+    fn test_inherited_aspect_checks_the_registered_provider() {
+        let old = TestThemeProvider {
+            theme: TestTheme { color: 1 },
+            child: DummyView,
+        };
+        let new = TestThemeProvider {
+            theme: TestTheme { color: 2 },
+            child: DummyView,
+        };
+        let aspect = InheritedAspect::new::<TestThemeProvider, _>(AnyColorChange);
+        assert!(aspect.is_affected(&new, &old));
+        assert!(!aspect.is_affected(&old, &old));
+    }
+
     #[test]
     fn test_inherited_element_update_should_notify() {
         let provider1 = TestThemeProvider {
@@ -34,7 +34,7 @@
     ErrorElement, ErrorView, ErrorViewBuilder, FlutterError, clear_error_view_builder,
     set_error_view_builder,
 };
-pub use inherited::InheritedView;
+pub use inherited::{InheritedAspect, InheritedModel, InheritedView};
 pub use into_view::{BoxedElement, BoxedView, ElementExt, IntoElement, IntoView, ViewExt};
 pub use memo::Memo;
 pub use parent_data::{ParentDataConfig, ParentDataView};
@@ -137,6 +137,20 @@ pub trait ParentDataView: Clone + 'static + Sized {
     fn apply_parent_data(&self, parent_data: &mut Self::ParentData) {
         *parent_data = self.create_parent_data();
     }
+
+    /// Whether updating from `old` to `self` should rebuild the child.
+    ///
+    /// Same contract as [`ProxyView::should_rebuild`](super::ProxyView::should_rebuild).
+    /// Parent data is re-applied to the render objects below this view on
+    /// every update either way (see `ElementTree::update`), so a view that
+    /// only changes its parent data (e.g. a new `flex`) can return `false`
+    /// while the child is unchanged.
+    ///
+    /// Defaults to `true` (always rebuild).
+    fn should_rebuild(&self, old: &Self) -> bool {
+        let _ = old;
+        true
+    }
 }
 
 /// Implement View for a ParentDataView type.
@@ -240,6 +254,12 @@ fn create_parent_data(&self) -> Self::ParentData {
                 fit: self.fit,
             }
         }
+
+        // The wrapped `DummyChild` carries no configuration, so an update
+        // only ever changes the parent data.
+        fn should_rebuild(&self, _old: &Self) -> bool {
+            false
+        }
     }
 
     impl_parent_data_view!(TestFlexible);
@@ -312,4 +332,56 @@ fn regression_parent_data_reconciles_child_through_build_scope() {
             "is_dirty() is false after the build hands the child off",
         );
     }
+
+    /// Reads the `flex` the tree wrote onto `render_id`.
+    fn render_flex(
+        pipeline: &std::sync::Arc<parking_lot::RwLock<flui_rendering::pipeline::PipelineOwner>>,
+        render_id: flui_foundation::RenderId,
+    ) -> f64 {
+        let owner = pipeline.read();
+        owner
+            .render_tree()
+            .get(render_id)
+            .and_then(|node| node.parent_data())
+            .and_then(|data| data.as_any().downcast_ref::<TestParentData>()) // PORT-CHECK-OK-DOWNCAST: test reads back the concrete parent-data type
+            .expect("the render child carries the view's parent data")
+            .flex
+    }
+
+    /// Changing only the parent data (`flex`) of a view whose
+    /// `should_rebuild` skips the child still reaches the child's render
+    /// object — the child element itself is never re-`update`d.
+    #[test]
+    fn parent_data_change_reaches_render_child_without_rebuild() {
+        let pipeline = std::sync::Arc::new(parking_lot::RwLock::new(
+            flui_rendering::pipeline::PipelineOwner::new(),
+        ));
+        let view = |flex| TestFlexible {
+            flex,
+            fit: true,
+            child: DummyChild,
+        };
+
+        let mut tree = crate::ElementTree::new();
+        let mut owner = crate::BuildOwner::new();
+        let root = tree.mount_root_with_pipeline_owner(
+            &view(2.0),
+            Some(std::sync::Arc::clone(&pipeline)),
+            &mut owner.element_owner_mut(),
+        );
+        owner.schedule_build_for(root, 0);
+        owner.build_scope(&mut tree);
+
+        let child = tree.get(root).unwrap().child_ids()[0];
+        let render_id = tree
+            .get(child)
+            .and_then(|node| node.element().render_id())
+            .expect("the wrapped child owns a render node");
+        assert!((render_flex(&pipeline, render_id) - 2.0).abs() < f64::EPSILON);
+
+        tree.update(root, &view(5.0), &mut owner.element_owner_mut());
+        owner.build_scope(&mut tree);
+
+        assert!((render_flex(&pipeline, render_id) - 5.0).abs() < f64::EPSILON);
+    }
 }
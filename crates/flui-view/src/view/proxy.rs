@@ -38,6 +38,20 @@
 pub trait ProxyView: Clone + 'static + Sized {
     /// Get the child View.
     fn child(&self) -> &dyn View;
+
+    /// Whether updating from `old` to `self` should rebuild the child.
+    ///
+    /// Called when the element is updated with a new configuration. Return
+    /// `false` when `self` is interchangeable with `old` so the element keeps
+    /// the new view but does not re-diff its child subtree. A rebuild that
+    /// was already pending for other reasons still runs.
+    ///
+    /// Defaults to `true` (always rebuild). Returning `false` while the child
+    /// view differs leaves the child with its old configuration.
+    fn should_rebuild(&self, old: &Self) -> bool {
+        let _ = old;
+        true
+    }
 }
 
 /// Implement View for a ProxyView type.
@@ -68,6 +82,11 @@ fn create_element(&self) -> $crate::element::ElementKind {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
     use flui_objects::RenderSizedBox;
     use flui_rendering::protocol::BoxProtocol;
 
@@ -187,4 +206,131 @@ fn test_proxy_element_unmount() {
 
         assert_eq!(element.lifecycle(), Lifecycle::Defunct);
     }
+
+    // ========================================================================
+    // should_rebuild short-circuit
+    // ========================================================================
+
+    /// Stateless child that counts its own builds.
+    #[derive(Clone)]
+    struct CountedChild {
+        builds: Arc<AtomicUsize>,
+    }
+
+    impl crate::StatelessView for CountedChild {
+        fn build(&self, _ctx: &dyn crate::BuildContext) -> impl crate::IntoView {
+            self.builds.fetch_add(1, Ordering::Relaxed);
+            DummyChild
+        }
+    }
+
+    impl View for CountedChild {
+        fn create_element(&self) -> crate::element::ElementKind {
+            crate::element::ElementKind::stateless(self)
+        }
+    }
+
+    /// Proxy that reports itself unchanged whenever `version` is equal.
+    #[derive(Clone)]
+    struct MemoProxy {
+        version: u32,
+        memoize: bool,
+        child: CountedChild,
+    }
+
+    impl ProxyView for MemoProxy {
+        fn child(&self) -> &dyn View {
+            &self.child
+        }
+
+        fn should_rebuild(&self, old: &Self) -> bool {
+            !self.memoize || self.version != old.version
+        }
+    }
+
+    impl View for MemoProxy {
+        fn create_element(&self) -> crate::element::ElementKind {
+            crate::element::ElementKind::proxy(self)
+        }
+    }
+
+    /// Root that rebuilds the same `MemoProxy` configuration every time.
+    #[derive(Clone)]
+    struct Host {
+        proxy: MemoProxy,
+    }
+
+    impl crate::StatelessView for Host {
+        fn build(&self, _ctx: &dyn crate::BuildContext) -> impl crate::IntoView {
+            self.proxy.clone()
+        }
+    }
+
+    impl View for Host {
+        fn create_element(&self) -> crate::element::ElementKind {
+            crate::element::ElementKind::stateless(self)
+        }
+    }
+
+    /// Mount `Host` and build it, then rebuild the root once; returns the
+    /// child's build count after each pass.
+    fn builds_across_root_rebuild(memoize: bool) -> (usize, usize) {
+        let builds = Arc::new(AtomicUsize::new(0));
+        let host = Host {
+            proxy: MemoProxy {
+                version: 1,
+                memoize,
+                child: CountedChild {
+                    builds: Arc::clone(&builds),
+                },
+            },
+        };
+        let mut tree = crate::ElementTree::new();
+        let mut owner = crate::BuildOwner::new();
+        let root = tree.mount_root(&host, &mut owner.element_owner_mut());
+        owner.schedule_build_for(root, 0);
+        owner.build_scope(&mut tree);
+        let first = builds.load(Ordering::Relaxed);
+
+        tree.mark_needs_build(root);
+        owner.schedule_build_for(root, 0);
+        owner.build_scope(&mut tree);
+        (first, builds.load(Ordering::Relaxed))
+    }
+
+    #[test]
+    fn unchanged_proxy_skips_child_rebuild() {
+        let (first, second) = builds_across_root_rebuild(true);
+        assert_eq!(first, 1);
+        assert_eq!(
+            second, 1,
+            "should_rebuild == false must not re-diff the child"
+        );
+    }
+
+    #[test]
+    fn default_proxy_rebuilds_child() {
+        let (first, second) = builds_across_root_rebuild(false);
+        assert_eq!(first, 1);
+        assert_eq!(second, 2);
+    }
+
+    #[test]
+    fn skipped_update_keeps_pending_rebuild() {
+        let view = MemoProxy {
+            version: 1,
+            memoize: true,
+            child: CountedChild {
+                builds: Arc::new(AtomicUsize::new(0)),
+            },
+        };
+        let mut element = ProxyElement::new(&view, ProxyBehavior);
+        let mut owner = crate::BuildOwner::new();
+        element.mount(None, 0, &mut owner.element_owner_mut());
+
+        // Already dirty (fresh mount): an equal config must not clear it.
+        assert!(element.is_dirty());
+        element.update(&view.clone(), &mut owner.element_owner_mut());
+        assert!(element.is_dirty());
+    }
 }
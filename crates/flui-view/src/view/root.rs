@@ -10,7 +10,10 @@
 
 use std::{
     any::{Any, TypeId},
-    sync::Arc,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
 };
 
 use flui_foundation::{ElementId, RenderId};
@@ -114,8 +117,9 @@ pub struct RootRenderElement<V: View + Clone> {
     /// honest dirty flag the root would be popped, skipped, and its child
     /// never reconciled — the app would render nothing. This flag is the
     /// root's dirty bit: `true` at construction + mount, cleared once
-    /// `build_into_views` has handed the child to the reconciler.
-    needs_build: bool,
+    /// `build_into_views` has handed the child to the reconciler. Atomic so
+    /// [`ElementBase::mark_needs_build_shared`] can set it through `&self`.
+    needs_build: AtomicBool,
 }
 
 impl<V: View + Clone + 'static> RootRenderElement<V> {
@@ -128,7 +132,7 @@ pub fn new(view: &RootRenderView<V>) -> Self {
             lifecycle: Lifecycle::Initial,
             depth: 0,
             slot: RenderSlot::Single,
-            needs_build: true,
+            needs_build: AtomicBool::new(true),
         }
     }
 
@@ -189,7 +193,7 @@ fn mount(
         self.lifecycle = Lifecycle::Active;
         // The child reconcile has not run yet — stay dirty so the scheduled
         // build in `BuildOwner::build_scope` is not skipped by its dirty guard.
-        self.needs_build = true;
+        self.needs_build.store(true, Ordering::Relaxed);
 
         // Create RenderView and insert into RenderTree. The attach
         // size is LOGICAL; the DPR comes from the pipeline owner (set
@@ -198,11 +202,12 @@ fn mount(
         let (width, height) = self.view.size;
         let mut render_view = RenderViewObject::new();
         let logical_size = Size::new(px(width), px(height));
-        let dpr = self
-            .pipeline_owner
-            .as_ref()
-            .map_or(1.0, |owner| owner.read().device_pixel_ratio());
-        let config = ViewConfiguration::from_size(logical_size, dpr);
+        let (dpr, text_scale_factor) = self.pipeline_owner.as_ref().map_or((1.0, 1.0), |owner| {
+            let owner = owner.read();
+            (owner.device_pixel_ratio(), owner.text_scale_factor())
+        });
+        let config = ViewConfiguration::from_size(logical_size, dpr)
+            .with_text_scale_factor(text_scale_factor);
         render_view.set_configuration(config);
         // Bootstrap the root transform + root layer. Without this,
         // RenderView::perform_layout asserts on the missing transform
@@ -276,7 +281,7 @@ fn update(&mut self, new_view: &dyn View, _element_owner: &mut crate::ElementOwn
         if let Some(v) = new_view.as_any().downcast_ref::<RootRenderView<V>>() {
             self.view = v.clone();
             // The child config may have changed — re-reconcile next build.
-            self.needs_build = true;
+            self.needs_build.store(true, Ordering::Relaxed);
             // Update configuration if size changed
             if let (Some(pipeline_owner), Some(render_id)) = (&self.pipeline_owner, self.render_id)
             {
@@ -293,29 +298,42 @@ fn update(&mut self, new_view: &dyn View, _element_owner: &mut crate::ElementOwn
                 // since the config `mount` built with the real DPR would be
                 // silently overwritten by this one.
                 let dpr = owner.device_pixel_ratio();
-                if let Some(node) = owner.render_tree_mut().get_mut(render_id) {
-                    // RenderView uses BoxProtocol
-                    let render_object = node.box_render_object_mut();
-                    if let Some(render_view) = render_object
-                        .as_any_mut()
-                        .downcast_mut::<RenderViewObject>()
-                    {
-                        let (width, height) = self.view.size;
-                        let logical_size = Size::new(px(width), px(height));
-                        let config = ViewConfiguration::from_size(logical_size, dpr);
-                        render_view.set_configuration(config);
-                    }
+                let text_scale_factor = owner.text_scale_factor();
+                let (width, height) = self.view.size;
+                let logical_size = Size::new(px(width), px(height));
+                let config = ViewConfiguration::from_size(logical_size, dpr)
+                    .with_text_scale_factor(text_scale_factor);
+                // The render view lives in the owner's tree, and the owner
+                // is already write-locked here: install the configuration
+                // without going through the view's owner pointer, then push
+                // it into the held guard once the node borrow ends.
+                let changed = owner
+                    .render_tree_mut()
+                    .get_mut(render_id)
+                    .and_then(|node| {
+                        // RenderView uses BoxProtocol
+                        node.box_render_object_mut()
+                            .as_any_mut()
+                            .downcast_mut::<RenderViewObject>()
+                    })
+                    .is_some_and(|render_view| render_view.update_configuration(config.clone()));
+                if changed {
+                    owner.apply_view_configuration(&config);
                 }
             }
         }
     }
 
     fn is_dirty(&self) -> bool {
-        self.needs_build
+        self.needs_build.load(Ordering::Relaxed)
     }
 
     fn mark_needs_build(&mut self) {
-        self.needs_build = true;
+        self.needs_build.store(true, Ordering::Relaxed);
+    }
+
+    fn mark_needs_build_shared(&self) {
+        self.needs_build.store(true, Ordering::Relaxed);
     }
 
     fn build_into_views(
@@ -334,7 +352,7 @@ fn build_into_views(
         // `build_scope` only calls this when `is_dirty()` is true, so the
         // returned child is always the one to reconcile; clear the flag now
         // that it has been handed off.
-        self.needs_build = false;
+        self.needs_build.store(false, Ordering::Relaxed);
         vec![dyn_clone::clone_box(&self.view.child as &dyn View)]
     }
 
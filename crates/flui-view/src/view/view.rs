@@ -343,6 +343,17 @@ fn is_dirty(&self) -> bool {
         false
     }
 
+    /// Mark this Element as needing a rebuild through a shared borrow.
+    ///
+    /// Lets a bulk walk such as
+    /// [`ElementTree::mark_subtree_dirty`](crate::tree::ElementTree::mark_subtree_dirty)
+    /// flip dirty flags while the tree is only read-borrowed. The unified
+    /// `Element<V, A, B>` and the render-tree root back their flag with an
+    /// atomic and override this. The default is a no-op, matching the
+    /// `false` default of [`Self::is_dirty`] for hand-rolled elements that
+    /// track no dirty state.
+    fn mark_needs_build_shared(&self) {}
+
     /// Run this element's build half and return its OWNED child view(s).
     ///
     /// E3 (atomic box→arena swap): this replaces the old
@@ -7,6 +7,7 @@
 //!   State match.
 //! - `find_render_object` (R9) — nearest `RenderId` from a
 //!   `RenderElement` ancestor.
+//! - `visit_child_elements` (R10) — direct children in slot order.
 //!
 //! Test fixtures use the same `mount_root` / `insert` shape as
 //! `inherited_dependency.rs`. The dependent-tracking concerns of the
@@ -21,8 +22,10 @@
 // Send + Sync to satisfy clippy. Future UiRealm/Rc migration should remove this.
 #![allow(clippy::arc_with_non_send_sync)]
 
+use std::cell::Cell;
 use std::sync::Arc;
 
+use flui_foundation::{ElementId, ValueKey, ViewKey};
 use flui_objects::RenderSizedBox;
 use flui_rendering::pipeline::PipelineOwner;
 use flui_types::geometry::px;
@@ -140,12 +143,20 @@ struct CounterView {
 
 struct CounterState {
     count: i32,
+    /// Bumped by descendants through `find_state`, the way a form field
+    /// registers with its enclosing form.
+    registrations: Cell<u32>,
 }
 
 impl CounterState {
     fn snapshot(&self) -> i32 {
         self.count
     }
+
+    fn register(&self) -> u32 {
+        self.registrations.set(self.registrations.get() + 1);
+        self.registrations.get()
+    }
 }
 
 impl StatefulView for CounterView {
@@ -154,6 +165,7 @@ impl StatefulView for CounterView {
     fn create_state(&self) -> Self::State {
         CounterState {
             count: self.initial,
+            registrations: Cell::new(0),
         }
     }
 }
@@ -170,6 +182,91 @@ fn create_element(&self) -> flui_view::element::ElementKind {
     }
 }
 
+/// A keyed render leaf, for R10: keys let a rebuild reorder the children
+/// without remounting them.
+#[derive(Clone)]
+struct TaggedBox {
+    key: ValueKey<u32>,
+}
+
+impl TaggedBox {
+    fn new(tag: u32) -> Self {
+        Self {
+            key: ValueKey::new(tag),
+        }
+    }
+}
+
+impl RenderView for TaggedBox {
+    type Protocol = flui_rendering::protocol::BoxProtocol;
+    type RenderObject = RenderSizedBox;
+
+    fn create_render_object(
+        &self,
+        _ctx: &flui_view::RenderObjectContext<'_>,
+    ) -> Self::RenderObject {
+        RenderSizedBox::shrink()
+    }
+
+    fn update_render_object(
+        &self,
+        _ctx: &flui_view::RenderObjectContext<'_>,
+        _render_object: &mut Self::RenderObject,
+    ) {
+    }
+}
+
+impl View for TaggedBox {
+    fn create_element(&self) -> flui_view::element::ElementKind {
+        flui_view::element::ElementKind::render_variable(self)
+    }
+
+    fn key(&self) -> Option<&dyn ViewKey> {
+        Some(&self.key)
+    }
+}
+
+/// A variable-arity render container of [`TaggedBox`]es, for R10.
+#[derive(Clone)]
+struct TaggedRow {
+    tags: Vec<u32>,
+}
+
+impl RenderView for TaggedRow {
+    type Protocol = flui_rendering::protocol::BoxProtocol;
+    type RenderObject = RenderSizedBox;
+
+    fn create_render_object(
+        &self,
+        _ctx: &flui_view::RenderObjectContext<'_>,
+    ) -> Self::RenderObject {
+        RenderSizedBox::shrink()
+    }
+
+    fn update_render_object(
+        &self,
+        _ctx: &flui_view::RenderObjectContext<'_>,
+        _render_object: &mut Self::RenderObject,
+    ) {
+    }
+
+    fn has_children(&self) -> bool {
+        !self.tags.is_empty()
+    }
+
+    fn visit_child_views(&self, visitor: &mut dyn FnMut(&dyn View)) {
+        for &tag in &self.tags {
+            visitor(&TaggedBox::new(tag));
+        }
+    }
+}
+
+impl View for TaggedRow {
+    fn create_element(&self) -> flui_view::element::ElementKind {
+        flui_view::element::ElementKind::render_variable(self)
+    }
+}
+
 // ============================================================================
 // Helpers
 // ============================================================================
@@ -426,6 +523,36 @@ fn find_ancestor_state_excludes_stateless_ancestors() {
     );
 }
 
+#[test]
+fn find_state_lets_a_descendant_act_on_the_ancestor_state() {
+    // The callback gets the live State, so a descendant can drive it
+    // through `&self` methods (interior mutability) — the form-field
+    // registration pattern. Both calls reach the same State instance.
+    let (tree, owner) = create_tree_and_owner();
+
+    let counter_id = tree.write().mount_root(
+        &CounterView { initial: 0 },
+        &mut owner.write().element_owner_mut(),
+    );
+    let child_id = tree.write().insert(
+        &DummyChild,
+        counter_id,
+        0,
+        &mut owner.write().element_owner_mut(),
+    );
+
+    let ctx = ElementBuildContext::for_element(child_id, tree, owner).unwrap();
+
+    assert_eq!(
+        ctx.find_state::<CounterState, u32>(CounterState::register),
+        Some(1)
+    );
+    assert_eq!(
+        ctx.find_state::<CounterState, u32>(CounterState::register),
+        Some(2)
+    );
+}
+
 // ============================================================================
 // R8: find_root_ancestor_state returns the ROOT-MOST match (not nearest)
 // ============================================================================
@@ -703,3 +830,63 @@ fn find_ancestor_view_callback_runs_at_most_once() {
         "find_ancestor must invoke the typed closure exactly once on a match"
     );
 }
+
+// ============================================================================
+// R10: visit_child_elements visits direct children in slot order
+// ============================================================================
+
+fn visited_children(
+    tree: &Arc<RwLock<ElementTree>>,
+    owner: &Arc<RwLock<BuildOwner>>,
+    parent: ElementId,
+) -> Vec<ElementId> {
+    let ctx = ElementBuildContext::for_element(parent, tree.clone(), owner.clone())
+        .expect("parent is mounted");
+    let mut visited = Vec::new();
+    ctx.visit_child_elements(&mut |id| visited.push(id));
+    visited
+}
+
+#[test]
+fn visit_child_elements_follows_slot_order_across_a_reorder() {
+    let (tree, owner) = create_tree_and_owner();
+
+    let row_id = tree.write().mount_root(
+        &TaggedRow {
+            tags: vec![1, 2, 3],
+        },
+        &mut owner.write().element_owner_mut(),
+    );
+    owner.write().schedule_build_for(row_id, 0);
+    owner.write().build_scope(&mut tree.write());
+
+    let before = visited_children(&tree, &owner, row_id);
+    assert_eq!(before.len(), 3, "one visit per direct child");
+    {
+        let tree = tree.read();
+        for (slot, id) in before.iter().enumerate() {
+            assert_eq!(
+                tree.get(*id).map(flui_view::ElementNode::slot),
+                Some(slot),
+                "visit order is slot order"
+            );
+        }
+    }
+
+    // Keyed children keep their elements when reordered, so the same
+    // ids come back in the new configuration's order.
+    tree.write().update(
+        row_id,
+        &TaggedRow {
+            tags: vec![3, 1, 2],
+        },
+        &mut owner.write().element_owner_mut(),
+    );
+    owner.write().schedule_build_for(row_id, 0);
+    owner.write().build_scope(&mut tree.write());
+
+    assert_eq!(
+        visited_children(&tree, &owner, row_id),
+        vec![before[2], before[0], before[1]]
+    );
+}
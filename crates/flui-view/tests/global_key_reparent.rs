@@ -13,6 +13,10 @@
 //! Cross-parent same-frame ACTIVE reparent (case 2 below) is locked here too:
 //! the tree forgets the active element from its old parent, moves it under the
 //! new parent, and emits `from_parent: Some(old_parent)`.
+//!
+//! The `build_scope` tests drive the same moves through a real rebuild of two
+//! containers, and lock the one-claim-per-build guard: two parents
+//! configuring the same `GlobalKey` in one build is an error.
 
 #![cfg(feature = "test-utils")]
 // ADR-0027: ElementBuildContext's current test/prod seam still takes
@@ -23,9 +27,11 @@
 use std::sync::Arc;
 
 use flui_foundation::ViewKey;
+use flui_objects::RenderSizedBox;
+use flui_rendering::protocol::BoxProtocol;
 use flui_view::{
-    BuildContext, BuildOwner, ElementTree, GlobalKey, IntoView, StatefulView, View, ViewExt,
-    ViewState,
+    BoxedView, BuildContext, BuildOwner, ElementTree, GlobalKey, IntoView, RenderView,
+    StatefulView, View, ViewExt, ViewState,
     tree::{
         ReconcileEventKind,
         test_utils::{CollectedEvent, ReconcileEventCollector},
@@ -99,7 +105,11 @@ fn create_state(&self) -> Self::State {
 
 impl ViewState<KeyedCounter> for CounterState {
     fn build(&self, _v: &KeyedCounter, _ctx: &dyn BuildContext) -> impl IntoView {
-        Spacer.boxed()
+        // A childless render leaf, so `build_scope` terminates (a `Spacer`
+        // would rebuild itself forever).
+        Host {
+            children: Vec::new(),
+        }
     }
 }
 
@@ -112,6 +122,66 @@ fn key(&self) -> Option<&dyn ViewKey> {
     }
 }
 
+/// Variable-arity render container, so a rebuild of the root reconciles two
+/// sibling containers that the keyed counter can move between.
+#[derive(Clone)]
+struct Host {
+    children: Vec<BoxedView>,
+}
+
+impl RenderView for Host {
+    type Protocol = BoxProtocol;
+    type RenderObject = RenderSizedBox;
+
+    fn create_render_object(
+        &self,
+        _ctx: &flui_view::RenderObjectContext<'_>,
+    ) -> Self::RenderObject {
+        RenderSizedBox::shrink()
+    }
+
+    fn update_render_object(
+        &self,
+        _ctx: &flui_view::RenderObjectContext<'_>,
+        _render_object: &mut Self::RenderObject,
+    ) {
+    }
+
+    fn has_children(&self) -> bool {
+        !self.children.is_empty()
+    }
+
+    fn visit_child_views(&self, visitor: &mut dyn FnMut(&dyn View)) {
+        for child in &self.children {
+            visitor(child);
+        }
+    }
+}
+
+impl View for Host {
+    fn create_element(&self) -> flui_view::element::ElementKind {
+        flui_view::element::ElementKind::render_variable(self)
+    }
+}
+
+/// A root holding two containers; `in_first` / `in_second` say which of
+/// them configures `counter`.
+fn two_containers(counter: &KeyedCounter, in_first: bool, in_second: bool) -> Host {
+    let container = |present: bool| {
+        Host {
+            children: if present {
+                vec![counter.clone().boxed()]
+            } else {
+                Vec::new()
+            },
+        }
+        .boxed()
+    };
+    Host {
+        children: vec![container(in_first), container(in_second)],
+    }
+}
+
 // ============================================================================
 // Helpers
 // ============================================================================
@@ -480,6 +550,104 @@ fn active_to_active_reparent_emits_from_parent_and_preserves_state() {
     flui_view::test_only_clear_global_key_registry();
 }
 
+/// Production path: the root rebuilds with the keyed counter moved from its
+/// first container to its second. The element (and its state) is adopted by
+/// the new container instead of being recreated from the new view's
+/// `initial`.
+#[test]
+#[serial_test::serial(global_key_registry)]
+fn build_scope_moves_a_keyed_stateful_subtree_between_containers() {
+    let (tree, owner) = fresh_tree();
+    let key = GlobalKey::<CounterState>::new();
+    let before = KeyedCounter {
+        key: key.clone(),
+        initial: 17,
+    };
+
+    let root = tree.write().mount_root(
+        &two_containers(&before, true, false),
+        &mut owner.write().element_owner_mut(),
+    );
+    owner.write().schedule_build_for(root, 0);
+    owner.write().build_scope(&mut tree.write());
+
+    let (first, second) = {
+        let tree = tree.read();
+        let containers = direct_children_in_slot_order(&tree, root);
+        (containers[0], containers[1])
+    };
+    let counter_id = direct_children_in_slot_order(&tree.read(), first)[0];
+    assert_eq!(key.with_current_state::<i32>(CounterState::count), Some(17));
+
+    // A fresh element would start from 99; an adopted one keeps 17.
+    let after = KeyedCounter {
+        key: key.clone(),
+        initial: 99,
+    };
+    tree.write().update(
+        root,
+        &two_containers(&after, false, true),
+        &mut owner.write().element_owner_mut(),
+    );
+    owner.write().schedule_build_for(root, 0);
+    owner.write().build_scope(&mut tree.write());
+
+    {
+        let tree = tree.read();
+        assert!(
+            direct_children_in_slot_order(&tree, first).is_empty(),
+            "the first container no longer holds the counter",
+        );
+        assert_eq!(
+            direct_children_in_slot_order(&tree, second),
+            vec![counter_id],
+            "the second container adopted the same element",
+        );
+    }
+    assert_eq!(
+        key.with_current_state::<i32>(CounterState::count),
+        Some(17),
+        "the moved subtree keeps its state",
+    );
+
+    flui_view::test_only_clear_global_key_registry();
+}
+
+/// Two containers configuring the same `GlobalKey` in one build would make
+/// the second silently steal the first's element — the guard rejects it.
+#[test]
+#[serial_test::serial(global_key_registry)]
+#[cfg_attr(
+    debug_assertions,
+    should_panic(expected = "Multiple widgets used the same GlobalKey")
+)]
+fn build_scope_rejects_one_global_key_claimed_by_two_containers() {
+    let (tree, owner) = fresh_tree();
+    let counter = KeyedCounter {
+        key: GlobalKey::new(),
+        initial: 0,
+    };
+
+    let root = tree.write().mount_root(
+        &two_containers(&counter, true, false),
+        &mut owner.write().element_owner_mut(),
+    );
+    owner.write().schedule_build_for(root, 0);
+    owner.write().build_scope(&mut tree.write());
+
+    // Clear before the panic so the registry does not leak into the next
+    // serial test.
+    flui_view::test_only_clear_global_key_registry();
+
+    tree.write().update(
+        root,
+        &two_containers(&counter, true, true),
+        &mut owner.write().element_owner_mut(),
+    );
+    owner.write().schedule_build_for(root, 0);
+    owner.write().build_scope(&mut tree.write());
+}
+
 // Suppress the unused-import warning for bump (the field exists in
 // the fixture for future tests that exercise mutable state).
 #[allow(dead_code)]
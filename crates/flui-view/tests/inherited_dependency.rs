@@ -1332,3 +1332,195 @@ fn init_state_panic_leaves_the_slot_intact_not_a_hole() {
         );
     }
 }
+
+// ============================================================================
+// InheritedModel aspects: a dependent rebuilds only when its aspect changes
+// ============================================================================
+
+mod inherited_model_aspects {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use flui_view::{
+        BuildContext, BuildContextExt, BuildOwner, ElementTree, InheritedModel, InheritedView,
+        IntoView, StatelessView, View, ViewExt,
+    };
+
+    use super::LeafView;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct StyleData {
+        color: u32,
+        font_size: u32,
+    }
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum StyleAspect {
+        Color,
+        TextStyle,
+    }
+
+    #[derive(Clone)]
+    struct StyleProvider {
+        data: StyleData,
+        child: ColorDependent,
+    }
+
+    impl InheritedView for StyleProvider {
+        type Data = StyleData;
+
+        fn data(&self) -> &StyleData {
+            &self.data
+        }
+
+        fn child(&self) -> &dyn View {
+            &self.child
+        }
+
+        fn update_should_notify(&self, old: &Self) -> bool {
+            self.data != old.data
+        }
+    }
+
+    impl InheritedModel<StyleAspect> for StyleProvider {
+        fn update_should_notify_dependent(&self, old: &Self, aspect: &StyleAspect) -> bool {
+            match aspect {
+                StyleAspect::Color => self.data.color != old.data.color,
+                StyleAspect::TextStyle => self.data.font_size != old.data.font_size,
+            }
+        }
+    }
+
+    impl View for StyleProvider {
+        fn create_element(&self) -> flui_view::element::ElementKind {
+            flui_view::element::ElementKind::inherited(self)
+        }
+    }
+
+    /// Build counters shared by both dependents.
+    #[derive(Clone, Default)]
+    struct Counts {
+        color: Arc<AtomicUsize>,
+        text: Arc<AtomicUsize>,
+    }
+
+    /// Reads only the colour; builds the text dependent below it.
+    ///
+    /// `should_skip_rebuild` stands in for Flutter's identical-widget
+    /// short-circuit, so a parent rebuild alone does not rebuild it and the
+    /// build counters isolate dependency-driven rebuilds.
+    #[derive(Clone)]
+    struct ColorDependent {
+        counts: Counts,
+    }
+
+    impl StatelessView for ColorDependent {
+        fn build(&self, ctx: &dyn BuildContext) -> impl IntoView {
+            self.counts.color.fetch_add(1, Ordering::Relaxed);
+            let color = ctx
+                .depend_on_inherited_view_of_exact_type_with_aspect::<StyleProvider, _, _>(
+                    StyleAspect::Color,
+                    |p| p.data().color,
+                );
+            assert!(color.is_some());
+            TextDependent {
+                counts: self.counts.clone(),
+            }
+        }
+    }
+
+    impl View for ColorDependent {
+        fn create_element(&self) -> flui_view::element::ElementKind {
+            flui_view::element::ElementKind::stateless(self)
+        }
+
+        fn should_skip_rebuild(&self, _prev: &Self) -> bool {
+            true
+        }
+    }
+
+    /// Reads only the text style.
+    #[derive(Clone)]
+    struct TextDependent {
+        counts: Counts,
+    }
+
+    impl StatelessView for TextDependent {
+        fn build(&self, ctx: &dyn BuildContext) -> impl IntoView {
+            self.counts.text.fetch_add(1, Ordering::Relaxed);
+            let size = ctx
+                .depend_on_inherited_view_of_exact_type_with_aspect::<StyleProvider, _, _>(
+                    StyleAspect::TextStyle,
+                    |p| p.data().font_size,
+                );
+            assert!(size.is_some());
+            LeafView.boxed()
+        }
+    }
+
+    impl View for TextDependent {
+        fn create_element(&self) -> flui_view::element::ElementKind {
+            flui_view::element::ElementKind::stateless(self)
+        }
+
+        fn should_skip_rebuild(&self, _prev: &Self) -> bool {
+            true
+        }
+    }
+
+    fn provider(counts: &Counts, color: u32, font_size: u32) -> StyleProvider {
+        StyleProvider {
+            data: StyleData { color, font_size },
+            child: ColorDependent {
+                counts: counts.clone(),
+            },
+        }
+    }
+
+    fn builds(counts: &Counts) -> (usize, usize) {
+        (
+            counts.color.load(Ordering::Relaxed),
+            counts.text.load(Ordering::Relaxed),
+        )
+    }
+
+    #[test]
+    fn dependent_rebuilds_only_for_its_aspect() {
+        let counts = Counts::default();
+        let mut tree = ElementTree::new();
+        let mut owner = BuildOwner::new();
+        let root = tree.mount_root(&provider(&counts, 1, 12), &mut owner.element_owner_mut());
+        owner.schedule_build_for(root, 0);
+        owner.build_scope(&mut tree);
+        assert_eq!(builds(&counts), (1, 1));
+
+        // Text style only: the colour dependent stays put.
+        tree.update(
+            root,
+            &provider(&counts, 1, 14),
+            &mut owner.element_owner_mut(),
+        );
+        owner.build_scope(&mut tree);
+        assert_eq!(builds(&counts), (1, 2));
+
+        // Colour only: the text dependent stays put.
+        tree.update(
+            root,
+            &provider(&counts, 2, 14),
+            &mut owner.element_owner_mut(),
+        );
+        owner.build_scope(&mut tree);
+        assert_eq!(builds(&counts), (2, 2));
+
+        // Both aspects change: both rebuild.
+        tree.update(
+            root,
+            &provider(&counts, 3, 16),
+            &mut owner.element_owner_mut(),
+        );
+        owner.build_scope(&mut tree);
+        assert_eq!(builds(&counts), (3, 3));
+    }
+}
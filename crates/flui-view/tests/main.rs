@@ -45,6 +45,8 @@
 mod key_roundtrip;
 #[path = "lifecycle_tests.rs"]
 mod lifecycle_tests;
+#[path = "notification_listener.rs"]
+mod notification_listener;
 #[path = "notifications.rs"]
 mod notifications;
 #[path = "production_reconcile_emits.rs"]
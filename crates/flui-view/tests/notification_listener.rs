@@ -0,0 +1,209 @@
+//! Production `NotificationListener<N>` catching notifications dispatched
+//! from a built descendant.
+//!
+//! `notifications.rs` validates the dispatch protocol against a hand-rolled
+//! listener element; these tests mount the shipped widget through
+//! `BuildOwner::build_scope` and dispatch from the leaf it built, so the
+//! element kind, behavior routing and type filter are all the real ones.
+//!
+//! Flutter parity: `notification_listener.dart` `NotificationListener<T>`.
+
+// ADR-0027: ElementBuildContext's current test/prod seam still takes
+// Arc<RwLock<ElementTree/BuildOwner>>. The owner graph is !Send; do not restore
+// Send + Sync to satisfy clippy. Future UiRealm/Rc migration should remove this.
+#![allow(clippy::arc_with_non_send_sync)]
+
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+use flui_foundation::ElementId;
+use flui_objects::RenderSizedBox;
+use flui_rendering::protocol::BoxProtocol;
+use flui_types::Axis;
+use flui_view::{
+    BuildContext, BuildOwner, ElementBuildContext, ElementTree, FocusNotification, Notification,
+    NotificationListener, RenderView, ScrollNotification, View,
+};
+use parking_lot::RwLock;
+
+// ============================================================================
+// Fixtures
+// ============================================================================
+
+/// Childless render leaf the notifications are dispatched from.
+#[derive(Clone)]
+struct Leaf;
+
+impl RenderView for Leaf {
+    type Protocol = BoxProtocol;
+    type RenderObject = RenderSizedBox;
+
+    fn create_render_object(
+        &self,
+        _ctx: &flui_view::RenderObjectContext<'_>,
+    ) -> Self::RenderObject {
+        RenderSizedBox::shrink()
+    }
+
+    fn update_render_object(
+        &self,
+        _ctx: &flui_view::RenderObjectContext<'_>,
+        _render_object: &mut Self::RenderObject,
+    ) {
+    }
+}
+
+impl View for Leaf {
+    fn create_element(&self) -> flui_view::element::ElementKind {
+        flui_view::element::ElementKind::render_variable(self)
+    }
+}
+
+/// Records the offsets a listener saw, in call order.
+type Log = Rc<RefCell<Vec<f64>>>;
+
+/// A `ScrollNotification` listener that logs each offset and answers `stop`.
+fn scroll_listener(
+    log: &Log,
+    stop: bool,
+    child: impl View,
+) -> NotificationListener<ScrollNotification> {
+    let log = Rc::clone(log);
+    NotificationListener::new(
+        move |scroll: &ScrollNotification| {
+            log.borrow_mut().push(scroll.offset);
+            stop
+        },
+        child,
+    )
+}
+
+fn scroll(offset: f64) -> ScrollNotification {
+    ScrollNotification {
+        offset,
+        axis: Axis::Vertical,
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Mount `root`, build it, and return the tree, owner and the id of the
+/// deepest element (the `Leaf`).
+fn build(root: &dyn View) -> (Arc<RwLock<ElementTree>>, Arc<RwLock<BuildOwner>>, ElementId) {
+    let tree = Arc::new(RwLock::new(ElementTree::new()));
+    let owner = Arc::new(RwLock::new(BuildOwner::new()));
+
+    let root_id = tree
+        .write()
+        .mount_root(root, &mut owner.write().element_owner_mut());
+    owner.write().schedule_build_for(root_id, 0);
+    owner.write().build_scope(&mut tree.write());
+
+    let leaf_id = tree
+        .read()
+        .iter_nodes()
+        .max_by_key(|(_, node)| node.depth())
+        .map(|(id, _)| id)
+        .expect("the built tree has a leaf");
+    (tree, owner, leaf_id)
+}
+
+fn dispatch_from(
+    tree: &Arc<RwLock<ElementTree>>,
+    owner: &Arc<RwLock<BuildOwner>>,
+    element: ElementId,
+    notification: &ScrollNotification,
+) {
+    let ctx = ElementBuildContext::for_element(element, Arc::clone(tree), Arc::clone(owner))
+        .expect("element is mounted");
+    notification.dispatch(&ctx as &dyn BuildContext);
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn ancestor_listener_catches_a_descendants_scroll_notification() {
+    let log = Log::default();
+    let (tree, owner, leaf) = build(&scroll_listener(&log, true, Leaf));
+
+    dispatch_from(&tree, &owner, leaf, &scroll(42.0));
+
+    assert_eq!(*log.borrow(), vec![42.0]);
+}
+
+#[test]
+fn listener_for_another_type_lets_the_notification_pass() {
+    // Outer ScrollNotification listener → inner FocusNotification listener
+    // → Leaf. The inner listener must neither see the scroll nor stop it.
+    let focus_calls = Rc::new(RefCell::new(0_u32));
+    let focus_listener = {
+        let focus_calls = Rc::clone(&focus_calls);
+        NotificationListener::new(
+            move |_: &FocusNotification| {
+                *focus_calls.borrow_mut() += 1;
+                true
+            },
+            Leaf,
+        )
+    };
+    let log = Log::default();
+    let (tree, owner, leaf) = build(&scroll_listener(&log, true, focus_listener));
+
+    dispatch_from(&tree, &owner, leaf, &scroll(7.0));
+
+    assert_eq!(
+        *focus_calls.borrow(),
+        0,
+        "type filter skipped the focus listener"
+    );
+    assert_eq!(
+        *log.borrow(),
+        vec![7.0],
+        "the scroll reached the outer listener"
+    );
+}
+
+#[test]
+fn returning_true_stops_bubbling_and_false_continues() {
+    let outer = Log::default();
+
+    // Inner listener handles the notification: the outer never sees it.
+    let inner = Log::default();
+    let (tree, owner, leaf) = build(&scroll_listener(
+        &outer,
+        true,
+        scroll_listener(&inner, true, Leaf),
+    ));
+    dispatch_from(&tree, &owner, leaf, &scroll(1.0));
+    assert_eq!(*inner.borrow(), vec![1.0]);
+    assert!(outer.borrow().is_empty(), "`true` halts the bubble");
+
+    // Inner listener only observes: the notification continues upward.
+    let inner = Log::default();
+    let (tree, owner, leaf) = build(&scroll_listener(
+        &outer,
+        true,
+        scroll_listener(&inner, false, Leaf),
+    ));
+    dispatch_from(&tree, &owner, leaf, &scroll(2.0));
+    assert_eq!(*inner.borrow(), vec![2.0]);
+    assert_eq!(*outer.borrow(), vec![2.0], "`false` lets it keep bubbling");
+}
+
+#[test]
+fn listener_does_not_see_notifications_dispatched_from_itself() {
+    let log = Log::default();
+    let (tree, owner, leaf) = build(&scroll_listener(&log, true, Leaf));
+    let listener = tree
+        .read()
+        .get(leaf)
+        .and_then(flui_view::ElementNode::parent)
+        .expect("leaf has the listener as parent");
+
+    dispatch_from(&tree, &owner, listener, &scroll(3.0));
+
+    assert!(log.borrow().is_empty(), "bubbling starts at the parent");
+}
@@ -118,7 +118,8 @@ fn create_element(&self) -> flui_view::element::ElementKind {
 
 /// Hand-rolled `ElementBase` impl that overrides `on_notification` to
 /// match the marker-trait dispatch protocol. Used only by integration
-/// tests — production NotificationListener wiring is out of scope here.
+/// tests — the shipped `flui_view::NotificationListener` is covered in
+/// `notification_listener.rs`.
 ///
 /// The element keeps just enough state to participate in the ancestor
 /// walk (depth + lifecycle) and to expose its `view_type_id`. Building
@@ -15,12 +15,20 @@
 //! `disableAnimations`, `boldText`, `displayFeatures`, `navigationMode`.
 //! These require platform event plumbing (accessibility bridge, IME state)
 //! that lives above this layer.
+//!
+//! ## Aspects
+//!
+//! [`MediaQuery`] is an [`InheritedModel`] over [`MediaQueryAspect`]. The
+//! field accessors ([`MediaQuery::size_of`],
+//! [`MediaQuery::platform_brightness_of`], …) depend on a single field, so a
+//! widget that only reads the size is not rebuilt when the brightness flips.
 
 use flui_geometry::{EdgeInsets, px};
+use flui_rendering::view::ViewConfiguration;
 use flui_types::Size;
 use flui_types::platform::Brightness;
 use flui_view::prelude::*;
-use flui_view::{BoxedView, InheritedView, impl_inherited_view};
+use flui_view::{BoxedView, InheritedModel, InheritedView, impl_inherited_view};
 
 /// Ambient logical-screen data provided to descendants by a [`MediaQuery`]
 /// ancestor.
@@ -92,6 +100,46 @@ fn default() -> Self {
     }
 }
 
+impl MediaQueryData {
+    /// Data for a root view described by `configuration`.
+    ///
+    /// Takes the size from the configuration's largest logical size and the
+    /// device pixel ratio and text scale from the configuration itself.
+    /// `padding`, `view_insets` and `platform_brightness` are not part of a
+    /// [`ViewConfiguration`] and keep their defaults; the embedder sets them
+    /// from its platform window.
+    ///
+    /// Flutter parity: `MediaQueryData.fromView`.
+    #[must_use]
+    pub fn from_view_configuration(configuration: &ViewConfiguration) -> Self {
+        Self {
+            size: configuration.logical_constraints().biggest(),
+            device_pixel_ratio: configuration.device_pixel_ratio(),
+            text_scale_factor: configuration.text_scale_factor(),
+            ..Self::default()
+        }
+    }
+}
+
+/// A single [`MediaQueryData`] field a dependent can depend on.
+///
+/// Flutter parity: `_MediaQueryAspect`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MediaQueryAspect {
+    /// [`MediaQueryData::size`].
+    Size,
+    /// [`MediaQueryData::device_pixel_ratio`].
+    DevicePixelRatio,
+    /// [`MediaQueryData::text_scale_factor`].
+    TextScaleFactor,
+    /// [`MediaQueryData::padding`].
+    Padding,
+    /// [`MediaQueryData::view_insets`].
+    ViewInsets,
+    /// [`MediaQueryData::platform_brightness`].
+    PlatformBrightness,
+}
+
 /// Provides [`MediaQueryData`] to its subtree via FLUI's inherited-data
 /// mechanism.
 ///
@@ -159,6 +207,103 @@ pub fn of(ctx: &dyn BuildContext) -> MediaQueryData {
     pub fn maybe_of(ctx: &dyn BuildContext) -> Option<MediaQueryData> {
         ctx.depend_on::<Self, _>(|mq| mq.data.clone())
     }
+
+    /// Read one field of the nearest ancestor's data, depending only on
+    /// `aspect` of it.
+    fn aspect_of<R>(
+        ctx: &dyn BuildContext,
+        aspect: MediaQueryAspect,
+        f: impl FnOnce(&MediaQueryData) -> R,
+    ) -> R {
+        ctx.depend_on_inherited_view_of_exact_type_with_aspect::<Self, _, _>(aspect, |mq| {
+            f(&mq.data)
+        })
+        .expect("MediaQuery aspect read with no MediaQuery ancestor in the tree")
+    }
+
+    /// [`MediaQueryData::size`] of the nearest ancestor; rebuilds only when
+    /// the size changes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no [`MediaQuery`] ancestor.
+    ///
+    /// Flutter parity: `MediaQuery.sizeOf(context)`.
+    #[must_use]
+    pub fn size_of(ctx: &dyn BuildContext) -> Size {
+        Self::aspect_of(ctx, MediaQueryAspect::Size, |data| data.size)
+    }
+
+    /// [`MediaQueryData::device_pixel_ratio`] of the nearest ancestor;
+    /// rebuilds only when the ratio changes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no [`MediaQuery`] ancestor.
+    ///
+    /// Flutter parity: `MediaQuery.devicePixelRatioOf(context)`.
+    #[must_use]
+    pub fn device_pixel_ratio_of(ctx: &dyn BuildContext) -> f32 {
+        Self::aspect_of(ctx, MediaQueryAspect::DevicePixelRatio, |data| {
+            data.device_pixel_ratio
+        })
+    }
+
+    /// [`MediaQueryData::text_scale_factor`] of the nearest ancestor;
+    /// rebuilds only when the factor changes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no [`MediaQuery`] ancestor.
+    ///
+    /// Flutter parity: `MediaQuery.textScalerOf(context)`.
+    #[must_use]
+    pub fn text_scale_factor_of(ctx: &dyn BuildContext) -> f32 {
+        Self::aspect_of(ctx, MediaQueryAspect::TextScaleFactor, |data| {
+            data.text_scale_factor
+        })
+    }
+
+    /// [`MediaQueryData::padding`] of the nearest ancestor; rebuilds only
+    /// when the padding changes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no [`MediaQuery`] ancestor.
+    ///
+    /// Flutter parity: `MediaQuery.paddingOf(context)`.
+    #[must_use]
+    pub fn padding_of(ctx: &dyn BuildContext) -> EdgeInsets {
+        Self::aspect_of(ctx, MediaQueryAspect::Padding, |data| data.padding)
+    }
+
+    /// [`MediaQueryData::view_insets`] of the nearest ancestor; rebuilds only
+    /// when the insets change.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no [`MediaQuery`] ancestor.
+    ///
+    /// Flutter parity: `MediaQuery.viewInsetsOf(context)`.
+    #[must_use]
+    pub fn view_insets_of(ctx: &dyn BuildContext) -> EdgeInsets {
+        Self::aspect_of(ctx, MediaQueryAspect::ViewInsets, |data| data.view_insets)
+    }
+
+    /// [`MediaQueryData::platform_brightness`] of the nearest ancestor;
+    /// rebuilds only when the brightness changes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no [`MediaQuery`] ancestor.
+    ///
+    /// Flutter parity: `MediaQuery.platformBrightnessOf(context)`.
+    #[must_use]
+    pub fn platform_brightness_of(ctx: &dyn BuildContext) -> Brightness {
+        Self::aspect_of(ctx, MediaQueryAspect::PlatformBrightness, |data| {
+            data.platform_brightness
+        })
+    }
 }
 
 impl std::fmt::Debug for MediaQuery {
@@ -187,4 +332,20 @@ fn update_should_notify(&self, old: &Self) -> bool {
     }
 }
 
+impl InheritedModel<MediaQueryAspect> for MediaQuery {
+    fn update_should_notify_dependent(&self, old: &Self, aspect: &MediaQueryAspect) -> bool {
+        let (new, old) = (&self.data, &old.data);
+        match aspect {
+            MediaQueryAspect::Size => new.size != old.size,
+            MediaQueryAspect::DevicePixelRatio => new.device_pixel_ratio != old.device_pixel_ratio,
+            MediaQueryAspect::TextScaleFactor => new.text_scale_factor != old.text_scale_factor,
+            MediaQueryAspect::Padding => new.padding != old.padding,
+            MediaQueryAspect::ViewInsets => new.view_insets != old.view_insets,
+            MediaQueryAspect::PlatformBrightness => {
+                new.platform_brightness != old.platform_brightness
+            }
+        }
+    }
+}
+
 impl_inherited_view!(MediaQuery);
@@ -19,5 +19,5 @@
 mod safe_area;
 
 pub use inherited_theme::InheritedTheme;
-pub use media_query::{MediaQuery, MediaQueryData};
+pub use media_query::{MediaQuery, MediaQueryAspect, MediaQueryData};
 pub use safe_area::SafeArea;
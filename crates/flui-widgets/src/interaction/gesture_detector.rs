@@ -1,13 +1,18 @@
 //! [`GestureDetector`] — recognizes high-level gestures (tap, long-press,
-//! double-tap, and pan/drag) from the raw pointer stream a [`Listener`] delivers.
+//! double-tap, pan/drag, and scale) from the raw pointer stream a [`Listener`]
+//! delivers.
 
 use std::{cell::RefCell, rc::Rc, sync::Arc};
 
 use flui_interaction::arena::{GestureArena, SweepModel};
+use flui_interaction::recognizers::long_press::{LongPressDetails, LongPressStartDetails};
+use flui_interaction::recognizers::scale::{
+    ScaleEndDetails, ScaleStartDetails, ScaleUpdateDetails,
+};
 use flui_interaction::{
     DoubleTapGestureRecognizer, DragAxis, DragDownDetails, DragEndDetails, DragGestureRecognizer,
     DragStartDetails, DragUpdateDetails, GestureRecognizer, LongPressGestureRecognizer,
-    PointerEvent, PointerEventExt, TapGestureRecognizer,
+    PointerEvent, PointerEventExt, ScaleGestureRecognizer, TapGestureRecognizer,
 };
 use flui_rendering::hit_testing::HitTestBehavior;
 use flui_view::prelude::*;
@@ -17,6 +22,10 @@
 /// A no-argument gesture callback (Flutter's `onTap` / `onLongPress` /
 /// `onDoubleTap`) — fired with no details when the gesture is recognized.
 type GestureCallback = Rc<dyn Fn()>;
+/// Long-press start carries where the hold was recognized; move-update / end
+/// carry the contact's current position.
+type LongPressStartHandler = Rc<dyn Fn(LongPressStartDetails)>;
+type LongPressHandler = Rc<dyn Fn(LongPressDetails)>;
 /// Pan callbacks carry the drag's details (position, delta, velocity).
 type PanStartHandler = Rc<dyn Fn(DragStartDetails)>;
 type PanUpdateHandler = Rc<dyn Fn(DragUpdateDetails)>;
@@ -30,6 +39,10 @@
 type HorizontalDragUpdateHandler = Rc<dyn Fn(DragUpdateDetails)>;
 type HorizontalDragEndHandler = Rc<dyn Fn(DragEndDetails)>;
 type HorizontalDragCancelHandler = Rc<dyn Fn()>;
+/// Scale callbacks carry the focal point and the running scale / rotation.
+type ScaleStartHandler = Rc<dyn Fn(ScaleStartDetails)>;
+type ScaleUpdateHandler = Rc<dyn Fn(ScaleUpdateDetails)>;
+type ScaleEndHandler = Rc<dyn Fn(ScaleEndDetails)>;
 
 /// Detects gestures on its child and invokes the matching callback.
 ///
@@ -38,12 +51,15 @@
 /// pointer stream to every recognizer; an arena resolves the competition and the
 /// winning recognizer fires its callback.
 ///
-/// Five gesture families are wired:
-/// - **tap** (`on_tap`) / **secondary tap** (`on_secondary_tap`) — a primary- /
-///   secondary-button down + up without moving past the touch slop.
-/// - **long press** (`on_long_press`) — the contact held still past the
-///   long-press deadline. Deadline-driven: it needs a [`GestureArenaScope`] +
-///   binding above to poll the deadline (see [arena acquisition](#arena-acquisition)).
+/// Six gesture families are wired:
+/// - **tap** (`on_tap`) / **secondary tap** (`on_secondary_tap`) / **tertiary
+///   tap** (`on_tertiary_tap`) — a primary- / secondary- / tertiary-button
+///   down + up without moving past the touch slop.
+/// - **long press** (`on_long_press` / `on_long_press_start` /
+///   `on_long_press_move_update` / `on_long_press_end`) — the contact held
+///   still past the long-press deadline, then optionally dragged and released.
+///   Deadline-driven: it needs a [`GestureArenaScope`] + binding above to poll
+///   the deadline (see [arena acquisition](#arena-acquisition)).
 /// - **double tap** (`on_double_tap`) — two quick taps within the double-tap
 ///   window. Combines correctly with `on_tap` under a [`GestureArenaScope`] +
 ///   binding: the double-tap recognizer holds the arena across the inter-tap
@@ -62,6 +78,10 @@
 ///   [`DragGestureRecognizer`] ([`DragAxis::Horizontal`]) from the free-axis pan
 ///   recognizer above; see the [conflict](#pan-and-horizontal-drag-conflict)
 ///   note on why the two are mutually exclusive on one detector.
+/// - **scale** (`on_scale_start` / `on_scale_update` / `on_scale_end`) — two
+///   or more contacts whose span changes, reported with the focal point and
+///   the running scale and rotation. Scale is a superset of pan, so the two
+///   are mutually exclusive on one detector (Flutter asserts the same).
 ///
 /// Only the recognizers whose callback is set participate in the arena for a
 /// contact (Flutter parity: a recognizer is constructed only when its callback
@@ -118,7 +138,11 @@
 pub struct GestureDetector {
     on_tap: Option<GestureCallback>,
     on_secondary_tap: Option<GestureCallback>,
+    on_tertiary_tap: Option<GestureCallback>,
     on_long_press: Option<GestureCallback>,
+    on_long_press_start: Option<LongPressStartHandler>,
+    on_long_press_move_update: Option<LongPressHandler>,
+    on_long_press_end: Option<LongPressHandler>,
     on_double_tap: Option<GestureCallback>,
     on_pan_start: Option<PanStartHandler>,
     on_pan_update: Option<PanUpdateHandler>,
@@ -128,6 +152,9 @@ pub struct GestureDetector {
     on_horizontal_drag_update: Option<HorizontalDragUpdateHandler>,
     on_horizontal_drag_end: Option<HorizontalDragEndHandler>,
     on_horizontal_drag_cancel: Option<HorizontalDragCancelHandler>,
+    on_scale_start: Option<ScaleStartHandler>,
+    on_scale_update: Option<ScaleUpdateHandler>,
+    on_scale_end: Option<ScaleEndHandler>,
     /// How the underlying [`Listener`] participates in hit-testing.
     behavior: HitTestBehavior,
     child: Child,
@@ -138,7 +165,11 @@ fn default() -> Self {
         Self {
             on_tap: None,
             on_secondary_tap: None,
+            on_tertiary_tap: None,
             on_long_press: None,
+            on_long_press_start: None,
+            on_long_press_move_update: None,
+            on_long_press_end: None,
             on_double_tap: None,
             on_pan_start: None,
             on_pan_update: None,
@@ -148,6 +179,9 @@ fn default() -> Self {
             on_horizontal_drag_update: None,
             on_horizontal_drag_end: None,
             on_horizontal_drag_cancel: None,
+            on_scale_start: None,
+            on_scale_update: None,
+            on_scale_end: None,
             behavior: HitTestBehavior::DeferToChild,
             child: Child::empty(),
         }
@@ -159,7 +193,14 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("GestureDetector")
             .field("on_tap", &self.on_tap.is_some())
             .field("on_secondary_tap", &self.on_secondary_tap.is_some())
+            .field("on_tertiary_tap", &self.on_tertiary_tap.is_some())
             .field("on_long_press", &self.on_long_press.is_some())
+            .field("on_long_press_start", &self.on_long_press_start.is_some())
+            .field(
+                "on_long_press_move_update",
+                &self.on_long_press_move_update.is_some(),
+            )
+            .field("on_long_press_end", &self.on_long_press_end.is_some())
             .field("on_double_tap", &self.on_double_tap.is_some())
             .field("on_pan_start", &self.on_pan_start.is_some())
             .field("on_pan_update", &self.on_pan_update.is_some())
@@ -184,6 +225,9 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 "on_horizontal_drag_cancel",
                 &self.on_horizontal_drag_cancel.is_some(),
             )
+            .field("on_scale_start", &self.on_scale_start.is_some())
+            .field("on_scale_update", &self.on_scale_update.is_some())
+            .field("on_scale_end", &self.on_scale_end.is_some())
             .field("behavior", &self.behavior)
             .finish_non_exhaustive()
     }
@@ -211,6 +255,14 @@ pub fn on_secondary_tap(mut self, callback: impl Fn() + 'static) -> Self {
         self
     }
 
+    /// Called when the child receives a tertiary-button tap (middle-click down
+    /// + up without moving past the touch slop).
+    #[must_use]
+    pub fn on_tertiary_tap(mut self, callback: impl Fn() + 'static) -> Self {
+        self.on_tertiary_tap = Some(Rc::new(callback));
+        self
+    }
+
     /// Called when the child is long-pressed (the contact held still past the
     /// long-press deadline).
     ///
@@ -224,6 +276,38 @@ pub fn on_long_press(mut self, callback: impl Fn() + 'static) -> Self {
         self
     }
 
+    /// Called when a long press is recognized, with the position the hold
+    /// was recognized at. Fires alongside [`on_long_press`](Self::on_long_press)
+    /// and shares its deadline requirement.
+    #[must_use]
+    pub fn on_long_press_start(
+        mut self,
+        callback: impl Fn(LongPressStartDetails) + 'static,
+    ) -> Self {
+        self.on_long_press_start = Some(Rc::new(callback));
+        self
+    }
+
+    /// Called for each pointer move after a long press was recognized,
+    /// carrying the contact's current position. Moving no longer cancels the
+    /// gesture once it has started.
+    #[must_use]
+    pub fn on_long_press_move_update(
+        mut self,
+        callback: impl Fn(LongPressDetails) + 'static,
+    ) -> Self {
+        self.on_long_press_move_update = Some(Rc::new(callback));
+        self
+    }
+
+    /// Called once when the contact of a recognized long press is released,
+    /// carrying the release position.
+    #[must_use]
+    pub fn on_long_press_end(mut self, callback: impl Fn(LongPressDetails) + 'static) -> Self {
+        self.on_long_press_end = Some(Rc::new(callback));
+        self
+    }
+
     /// Called when the child is double-tapped (two quick taps within the
     /// double-tap window). The inter-tap timing reads the arena clock.
     ///
@@ -314,6 +398,33 @@ pub fn on_horizontal_drag_cancel(mut self, callback: impl Fn() + 'static) -> Sel
         self
     }
 
+    /// Called once when a scale gesture begins (a second contact lands and
+    /// the span starts to change). Mutually exclusive with `on_pan_*` on one
+    /// detector; see the type docs.
+    #[must_use]
+    pub fn on_scale_start(mut self, callback: impl Fn(ScaleStartDetails) + 'static) -> Self {
+        self.on_scale_start = Some(Rc::new(callback));
+        self
+    }
+
+    /// Called for each pointer move while a scale gesture is in progress,
+    /// carrying the focal point and the scale and rotation relative to the
+    /// start. Mutually exclusive with `on_pan_*` on one detector.
+    #[must_use]
+    pub fn on_scale_update(mut self, callback: impl Fn(ScaleUpdateDetails) + 'static) -> Self {
+        self.on_scale_update = Some(Rc::new(callback));
+        self
+    }
+
+    /// Called once when the scale gesture ends (fewer than two contacts
+    /// remain), carrying the final scale and its velocity. Mutually
+    /// exclusive with `on_pan_*` on one detector.
+    #[must_use]
+    pub fn on_scale_end(mut self, callback: impl Fn(ScaleEndDetails) + 'static) -> Self {
+        self.on_scale_end = Some(Rc::new(callback));
+        self
+    }
+
     /// Override the hit-test behavior (default:
     /// [`DeferToChild`](HitTestBehavior::DeferToChild)). Set
     /// [`Opaque`](HitTestBehavior::Opaque) for a scroll area or any gesture
@@ -341,6 +452,17 @@ struct PanCallbacks {
     end: Option<PanEndHandler>,
 }
 
+/// The long-press callbacks the long-press recognizer reads, refreshed from the
+/// view on every `build`. `press` is the detail-less `on_long_press`, fired
+/// together with `start`.
+#[derive(Clone, Default)]
+struct LongPressCallbacks {
+    press: Option<GestureCallback>,
+    start: Option<LongPressStartHandler>,
+    move_update: Option<LongPressHandler>,
+    end: Option<LongPressHandler>,
+}
+
 /// The horizontal-drag callbacks the axis-constrained recognizer reads,
 /// refreshed from the view on every `build`. Mirrors [`PanCallbacks`] plus the
 /// `down`/`cancel` pair Flutter's `onHorizontalDrag*` family also exposes.
@@ -353,6 +475,15 @@ struct HorizontalDragCallbacks {
     cancel: Option<HorizontalDragCancelHandler>,
 }
 
+/// The scale callbacks the scale recognizer reads, refreshed from the view on
+/// every `build`.
+#[derive(Clone, Default)]
+struct ScaleCallbacks {
+    start: Option<ScaleStartHandler>,
+    update: Option<ScaleUpdateHandler>,
+    end: Option<ScaleEndHandler>,
+}
+
 /// The recognizers + the arena they share, built once in
 /// [`GestureDetectorState::init_state`] against the ambient (or private) arena.
 ///
@@ -382,6 +513,9 @@ struct Recognizers {
     /// Horizontal-drag recognizer (axis-constrained) — mutually exclusive with
     /// `drag` on one detector, see [`GestureDetector`]'s conflict doc.
     horizontal_drag: Arc<DragGestureRecognizer>,
+    /// Scale recognizer — needs a second contact, so a single-pointer drag
+    /// never reaches it.
+    scale: Arc<ScaleGestureRecognizer>,
 }
 
 /// Persistent gesture state: the recognizers + their shared arena survive
@@ -396,14 +530,18 @@ pub struct GestureDetectorState {
     tap_slot: Rc<RefCell<Option<GestureCallback>>>,
     /// The live `on_secondary_tap`, refreshed each `build`.
     secondary_tap_slot: Rc<RefCell<Option<GestureCallback>>>,
-    /// The live `on_long_press`, refreshed each `build`.
-    long_press_slot: Rc<RefCell<Option<GestureCallback>>>,
+    /// The live `on_tertiary_tap`, refreshed each `build`.
+    tertiary_tap_slot: Rc<RefCell<Option<GestureCallback>>>,
+    /// The live long-press callbacks, refreshed each `build`.
+    long_press_slot: Rc<RefCell<LongPressCallbacks>>,
     /// The live `on_double_tap`, refreshed each `build`.
     double_tap_slot: Rc<RefCell<Option<GestureCallback>>>,
     /// The live pan callbacks, refreshed each `build`.
     pan_slot: Rc<RefCell<PanCallbacks>>,
     /// The live horizontal-drag callbacks, refreshed each `build`.
     horizontal_drag_slot: Rc<RefCell<HorizontalDragCallbacks>>,
+    /// The live scale callbacks, refreshed each `build`.
+    scale_slot: Rc<RefCell<ScaleCallbacks>>,
     /// The recognizers + arena, built once in `init_state`. `None` only in the
     /// window between `create_state` and the first `init_state` — never observed
     /// by `build`, which always runs after `init_state`.
@@ -427,7 +565,13 @@ fn create_state(&self) -> Self::State {
         GestureDetectorState {
             tap_slot: Rc::new(RefCell::new(self.on_tap.clone())),
             secondary_tap_slot: Rc::new(RefCell::new(self.on_secondary_tap.clone())),
-            long_press_slot: Rc::new(RefCell::new(self.on_long_press.clone())),
+            tertiary_tap_slot: Rc::new(RefCell::new(self.on_tertiary_tap.clone())),
+            long_press_slot: Rc::new(RefCell::new(LongPressCallbacks {
+                press: self.on_long_press.clone(),
+                start: self.on_long_press_start.clone(),
+                move_update: self.on_long_press_move_update.clone(),
+                end: self.on_long_press_end.clone(),
+            })),
             double_tap_slot: Rc::new(RefCell::new(self.on_double_tap.clone())),
             pan_slot: Rc::new(RefCell::new(PanCallbacks {
                 start: self.on_pan_start.clone(),
@@ -441,6 +585,11 @@ fn create_state(&self) -> Self::State {
                 end: self.on_horizontal_drag_end.clone(),
                 cancel: self.on_horizontal_drag_cancel.clone(),
             })),
+            scale_slot: Rc::new(RefCell::new(ScaleCallbacks {
+                start: self.on_scale_start.clone(),
+                update: self.on_scale_update.clone(),
+                end: self.on_scale_end.clone(),
+            })),
             recognizers: None,
         }
     }
@@ -467,6 +616,7 @@ fn init_state(&mut self, ctx: &dyn BuildContext) {
         let tap = {
             let primary_slot = Rc::clone(&self.tap_slot);
             let secondary_slot = Rc::clone(&self.secondary_tap_slot);
+            let tertiary_slot = Rc::clone(&self.tertiary_tap_slot);
             TapGestureRecognizer::new(arena.clone())
                 .with_on_tap(move |_details| {
                     if let Some(handler) = primary_slot.borrow().clone() {
@@ -478,15 +628,43 @@ fn init_state(&mut self, ctx: &dyn BuildContext) {
                         handler();
                     }
                 })
+                .with_on_tertiary_tap(move |_details| {
+                    if let Some(handler) = tertiary_slot.borrow().clone() {
+                        handler();
+                    }
+                })
         };
 
         let long_press = {
-            let slot = Rc::clone(&self.long_press_slot);
-            LongPressGestureRecognizer::new(arena.clone()).with_on_long_press(move || {
-                if let Some(handler) = slot.borrow().clone() {
-                    handler();
-                }
-            })
+            let press_slot = Rc::clone(&self.long_press_slot);
+            let start_slot = Rc::clone(&self.long_press_slot);
+            let move_slot = Rc::clone(&self.long_press_slot);
+            let end_slot = Rc::clone(&self.long_press_slot);
+            LongPressGestureRecognizer::new(arena.clone())
+                .with_on_long_press(move || {
+                    let callback = press_slot.borrow().press.clone();
+                    if let Some(callback) = callback {
+                        callback();
+                    }
+                })
+                .with_on_long_press_start(move |details| {
+                    let callback = start_slot.borrow().start.clone();
+                    if let Some(callback) = callback {
+                        callback(details);
+                    }
+                })
+                .with_on_long_press_move_update(move |details| {
+                    let callback = move_slot.borrow().move_update.clone();
+                    if let Some(callback) = callback {
+                        callback(details);
+                    }
+                })
+                .with_on_long_press_end(move |details| {
+                    let callback = end_slot.borrow().end.clone();
+                    if let Some(callback) = callback {
+                        callback(details);
+                    }
+                })
         };
 
         let double_tap = {
@@ -562,6 +740,31 @@ fn init_state(&mut self, ctx: &dyn BuildContext) {
                 })
         };
 
+        let scale = {
+            let start_slot = Rc::clone(&self.scale_slot);
+            let update_slot = Rc::clone(&self.scale_slot);
+            let end_slot = Rc::clone(&self.scale_slot);
+            ScaleGestureRecognizer::new(arena.clone())
+                .with_on_scale_start(move |details| {
+                    let callback = start_slot.borrow().start.clone();
+                    if let Some(callback) = callback {
+                        callback(details);
+                    }
+                })
+                .with_on_scale_update(move |details| {
+                    let callback = update_slot.borrow().update.clone();
+                    if let Some(callback) = callback {
+                        callback(details);
+                    }
+                })
+                .with_on_scale_end(move |details| {
+                    let callback = end_slot.borrow().end.clone();
+                    if let Some(callback) = callback {
+                        callback(details);
+                    }
+                })
+        };
+
         self.recognizers = Some(Recognizers {
             arena,
             self_close,
@@ -570,11 +773,13 @@ fn init_state(&mut self, ctx: &dyn BuildContext) {
             double_tap,
             drag,
             horizontal_drag,
+            scale,
         });
     }
 
     fn build(&self, view: &GestureDetector, _ctx: &dyn BuildContext) -> impl IntoView {
         assert_no_pan_horizontal_drag_conflict(view);
+        assert_no_pan_scale_conflict(view);
 
         // Refresh the live callbacks the recognizers read, so a rebuild with new
         // closures is honored (the recognizers themselves persist).
@@ -582,9 +787,16 @@ fn build(&self, view: &GestureDetector, _ctx: &dyn BuildContext) -> impl IntoVie
         self.secondary_tap_slot
             .borrow_mut()
             .clone_from(&view.on_secondary_tap);
-        self.long_press_slot
+        self.tertiary_tap_slot
             .borrow_mut()
-            .clone_from(&view.on_long_press);
+            .clone_from(&view.on_tertiary_tap);
+        {
+            let mut slot = self.long_press_slot.borrow_mut();
+            slot.press.clone_from(&view.on_long_press);
+            slot.start.clone_from(&view.on_long_press_start);
+            slot.move_update.clone_from(&view.on_long_press_move_update);
+            slot.end.clone_from(&view.on_long_press_end);
+        }
         self.double_tap_slot
             .borrow_mut()
             .clone_from(&view.on_double_tap);
@@ -602,6 +814,12 @@ fn build(&self, view: &GestureDetector, _ctx: &dyn BuildContext) -> impl IntoVie
             slot.end.clone_from(&view.on_horizontal_drag_end);
             slot.cancel.clone_from(&view.on_horizontal_drag_cancel);
         }
+        {
+            let mut slot = self.scale_slot.borrow_mut();
+            slot.start.clone_from(&view.on_scale_start);
+            slot.update.clone_from(&view.on_scale_update);
+            slot.end.clone_from(&view.on_scale_end);
+        }
 
         // `init_state` runs exactly once before the first `build`, so the
         // recognizers are always present here.
@@ -625,6 +843,7 @@ fn dispose(&mut self) {
             recognizers.double_tap.dispose();
             recognizers.drag.dispose();
             recognizers.horizontal_drag.dispose();
+            recognizers.scale.dispose();
         }
     }
 }
@@ -652,6 +871,23 @@ fn assert_no_pan_horizontal_drag_conflict(view: &GestureDetector) {
     );
 }
 
+/// Debug-only conflict guard for `on_pan_*` and `on_scale_*`: scale is a
+/// superset of pan, so both recognizers would claim the same single-pointer
+/// drag. Flutter asserts the same pairing (`gesture_detector.dart`).
+fn assert_no_pan_scale_conflict(view: &GestureDetector) {
+    let have_pan =
+        view.on_pan_start.is_some() || view.on_pan_update.is_some() || view.on_pan_end.is_some();
+    let have_scale = view.on_scale_start.is_some()
+        || view.on_scale_update.is_some()
+        || view.on_scale_end.is_some();
+    debug_assert!(
+        !(have_pan && have_scale),
+        "GestureDetector: on_pan_* and on_scale_* are both configured on one detector. \
+         Having both a pan and a scale recognizer is redundant; scale is a superset of pan. \
+         Use on_scale_* alone.",
+    );
+}
+
 impl GestureDetectorState {
     /// Build the [`Listener`] that drives the recognizers from the pointer
     /// stream.
@@ -671,12 +907,15 @@ fn make_listener(&self, recognizers: &Recognizers) -> Listener {
             double_tap: Arc::clone(&recognizers.double_tap),
             drag: Arc::clone(&recognizers.drag),
             horizontal_drag: Arc::clone(&recognizers.horizontal_drag),
+            scale: Arc::clone(&recognizers.scale),
             tap_slot: Rc::clone(&self.tap_slot),
             secondary_tap_slot: Rc::clone(&self.secondary_tap_slot),
+            tertiary_tap_slot: Rc::clone(&self.tertiary_tap_slot),
             long_press_slot: Rc::clone(&self.long_press_slot),
             double_tap_slot: Rc::clone(&self.double_tap_slot),
             pan_slot: Rc::clone(&self.pan_slot),
             horizontal_drag_slot: Rc::clone(&self.horizontal_drag_slot),
+            scale_slot: Rc::clone(&self.scale_slot),
         };
 
         let down = group.clone();
@@ -703,24 +942,34 @@ struct RecognizerGroup {
     double_tap: Arc<DoubleTapGestureRecognizer>,
     drag: Arc<DragGestureRecognizer>,
     horizontal_drag: Arc<DragGestureRecognizer>,
+    scale: Arc<ScaleGestureRecognizer>,
     tap_slot: Rc<RefCell<Option<GestureCallback>>>,
     secondary_tap_slot: Rc<RefCell<Option<GestureCallback>>>,
-    long_press_slot: Rc<RefCell<Option<GestureCallback>>>,
+    tertiary_tap_slot: Rc<RefCell<Option<GestureCallback>>>,
+    long_press_slot: Rc<RefCell<LongPressCallbacks>>,
     double_tap_slot: Rc<RefCell<Option<GestureCallback>>>,
     pan_slot: Rc<RefCell<PanCallbacks>>,
     horizontal_drag_slot: Rc<RefCell<HorizontalDragCallbacks>>,
+    scale_slot: Rc<RefCell<ScaleCallbacks>>,
 }
 
 impl RecognizerGroup {
-    /// The tap recognizer participates iff a primary- OR secondary-tap callback
-    /// is currently set.
+    /// The tap recognizer participates iff a primary-, secondary-, OR
+    /// tertiary-tap callback is currently set.
     fn tap_active(&self) -> bool {
-        slot_is_some(&self.tap_slot) || slot_is_some(&self.secondary_tap_slot)
+        slot_is_some(&self.tap_slot)
+            || slot_is_some(&self.secondary_tap_slot)
+            || slot_is_some(&self.tertiary_tap_slot)
     }
 
-    /// The long-press recognizer participates iff `on_long_press` is set.
+    /// The long-press recognizer participates iff any long-press callback is
+    /// set.
     fn long_press_active(&self) -> bool {
-        slot_is_some(&self.long_press_slot)
+        let long_press = self.long_press_slot.borrow();
+        long_press.press.is_some()
+            || long_press.start.is_some()
+            || long_press.move_update.is_some()
+            || long_press.end.is_some()
     }
 
     /// The double-tap recognizer participates iff `on_double_tap` is set.
@@ -745,6 +994,12 @@ fn horizontal_drag_active(&self) -> bool {
             || horizontal.cancel.is_some()
     }
 
+    /// The scale recognizer participates iff any scale callback is set.
+    fn scale_active(&self) -> bool {
+        let scale = self.scale_slot.borrow();
+        scale.start.is_some() || scale.update.is_some() || scale.end.is_some()
+    }
+
     /// Register every participating recognizer for this contact (tap first so it
     /// is the arena's front member), then — in standalone mode only — close the
     /// arena. In shared mode the binding closes the arena after the down has been
@@ -772,6 +1027,9 @@ fn handle_down(&self, event: &PointerEvent) {
         if self.horizontal_drag_active() {
             self.horizontal_drag.add_pointer(pointer, position);
         }
+        if self.scale_active() {
+            self.scale.add_pointer(pointer, position);
+        }
         if self.self_close {
             self.arena.close(pointer);
         }
@@ -794,6 +1052,9 @@ fn forward(&self, event: &PointerEvent) {
         if self.horizontal_drag_active() {
             self.horizontal_drag.handle_event(event);
         }
+        if self.scale_active() {
+            self.scale.handle_event(event);
+        }
     }
 }
 
@@ -806,6 +1067,21 @@ fn slot_is_some(slot: &Rc<RefCell<Option<GestureCallback>>>) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn tertiary_tap_and_long_press_builders_store_the_callback() {
+        let detector = GestureDetector::new()
+            .on_tertiary_tap(|| {})
+            .on_long_press_start(|_| {})
+            .on_long_press_move_update(|_| {})
+            .on_long_press_end(|_| {});
+
+        assert!(detector.on_tertiary_tap.is_some());
+        assert!(detector.on_long_press.is_none());
+        assert!(detector.on_long_press_start.is_some());
+        assert!(detector.on_long_press_move_update.is_some());
+        assert!(detector.on_long_press_end.is_some());
+    }
+
     #[test]
     fn on_horizontal_drag_builders_store_the_callback() {
         let detector = GestureDetector::new()
@@ -866,6 +1142,29 @@ fn conflict_guard_panics_when_pan_and_horizontal_drag_coexist() {
         assert_no_pan_horizontal_drag_conflict(&detector);
     }
 
+    #[test]
+    fn scale_builders_store_the_callback() {
+        let detector = GestureDetector::new()
+            .on_scale_start(|_| {})
+            .on_scale_update(|_| {})
+            .on_scale_end(|_| {});
+
+        assert!(detector.on_scale_start.is_some());
+        assert!(detector.on_scale_update.is_some());
+        assert!(detector.on_scale_end.is_some());
+        assert!(detector.on_pan_start.is_none());
+        assert_no_pan_scale_conflict(&detector);
+    }
+
+    #[test]
+    #[should_panic(expected = "on_pan_* and on_scale_* are both configured")]
+    fn scale_conflict_guard_panics_when_pan_and_scale_coexist() {
+        let detector = GestureDetector::new()
+            .on_pan_update(|_| {})
+            .on_scale_update(|_| {});
+        assert_no_pan_scale_conflict(&detector);
+    }
+
     #[test]
     #[should_panic(expected = "on_pan_* and on_horizontal_drag_* are both configured")]
     fn conflict_guard_panics_with_update_and_end_variants_too() {
@@ -4,18 +4,21 @@
 use flui_rendering::protocol::BoxProtocol;
 use flui_types::Alignment;
 use flui_types::layout::BoxFit;
-use flui_types::painting::Clip;
+use flui_types::painting::{Clip, FilterQuality};
 use flui_view::{Child, IntoView, RenderView, View, impl_render_view};
 
 /// Scales and positions its child within itself according to a [`BoxFit`].
 ///
 /// Flutter parity: `widgets/basic.dart` `FittedBox` over `RenderFittedBox`.
 /// Defaults match Flutter: `BoxFit::Contain`, `Alignment::CENTER`, `Clip::None`.
+/// With a clip set, a child the fit crops (`Cover`, `FitWidth`, `FitHeight`,
+/// an overflowing `None`) is clipped to the box.
 #[derive(Clone, Debug)]
 pub struct FittedBox {
     fit: BoxFit,
     alignment: Alignment,
     clip: Clip,
+    filter_quality: Option<FilterQuality>,
     child: Child,
 }
 
@@ -25,6 +28,7 @@ fn default() -> Self {
             fit: BoxFit::Contain,
             alignment: Alignment::CENTER,
             clip: Clip::None,
+            filter_quality: None,
             child: Child::empty(),
         }
     }
@@ -57,6 +61,13 @@ pub fn clip(mut self, clip: Clip) -> Self {
         self
     }
 
+    /// Set the sampling quality used when the child is scaled.
+    #[must_use]
+    pub fn filter_quality(mut self, filter_quality: FilterQuality) -> Self {
+        self.filter_quality = Some(filter_quality);
+        self
+    }
+
     /// Set the fitted child.
     #[must_use]
     pub fn child(mut self, child: impl IntoView) -> Self {
@@ -74,6 +85,7 @@ fn create_render_object(
         _ctx: &flui_view::RenderObjectContext<'_>,
     ) -> Self::RenderObject {
         RenderFittedBox::new(self.fit, self.alignment, self.clip)
+            .with_filter_quality(self.filter_quality)
     }
 
     fn update_render_object(
@@ -84,6 +96,7 @@ fn update_render_object(
         render_object.set_fit(self.fit);
         render_object.set_alignment(self.alignment);
         render_object.set_clip_behavior(self.clip);
+        render_object.set_filter_quality(self.filter_quality);
     }
 
     fn has_children(&self) -> bool {
@@ -113,6 +126,23 @@ fn create_render_object_defaults_match_flutter() {
         assert_eq!(render_object.fit(), BoxFit::Contain);
         assert_eq!(render_object.alignment(), Alignment::CENTER);
         assert_eq!(render_object.clip_behavior(), Clip::None);
+        assert_eq!(render_object.filter_quality(), None);
+    }
+
+    #[test]
+    fn filter_quality_reaches_the_render_object_on_create_and_update() {
+        let mut render_object = FittedBox::new()
+            .filter_quality(FilterQuality::Medium)
+            .create_render_object(&flui_view::RenderObjectContext::detached());
+        assert_eq!(render_object.filter_quality(), Some(FilterQuality::Medium));
+
+        FittedBox::new()
+            .filter_quality(FilterQuality::High)
+            .update_render_object(
+                &flui_view::RenderObjectContext::detached(),
+                &mut render_object,
+            );
+        assert_eq!(render_object.filter_quality(), Some(FilterQuality::High));
     }
 
     #[test]
@@ -117,7 +117,7 @@
 // `InheritedTheme` trait a theme widget (e.g. `flui_material::Theme`)
 // implements. The Material `Theme`/`ThemeData` widget itself lives in
 // `flui-material` — see `app` module docs.
-pub use app::{InheritedTheme, MediaQuery, MediaQueryData, SafeArea};
+pub use app::{InheritedTheme, MediaQuery, MediaQueryAspect, MediaQueryData, SafeArea};
 // `Brightness` is the value type `MediaQueryData` (and any theme's
 // brightness field) uses; re-exported here so callers need only
 // `use flui_widgets::Brightness`.
@@ -215,9 +215,9 @@
     TextField,
 };
 pub use transitions::{
-    AnimatedBuilder, AnimatedBuilderState, FadeTransition, FadeTransitionState, RotationTransition,
-    RotationTransitionState, ScaleTransition, ScaleTransitionState, SlideTransition,
-    SlideTransitionState,
+    AnimatedBuilder, AnimatedBuilderState, FadeTransition, FadeTransitionState, ListenableBuilder,
+    ListenableBuilderState, RotationTransition, RotationTransitionState, ScaleTransition,
+    ScaleTransitionState, SlideTransition, SlideTransitionState, TransitionBuilder,
 };
 pub use value_listenable_builder::{
     ValueListenableBuilder, ValueListenableBuilderState, ValueWidgetBuilder,
@@ -269,6 +269,10 @@
 pub use flui_interaction::{
     DragEndDetails, DragStartDetails, DragUpdateDetails, PointerPanZoomEvent,
 };
+// Scale details surfaced by `GestureDetector`'s `on_scale_*` callbacks.
+pub use flui_interaction::recognizers::scale::{
+    ScaleEndDetails, ScaleStartDetails, ScaleUpdateDetails,
+};
 pub use flui_rendering::semantics::{
     SemanticsConfiguration, SemanticsProperties, SemanticsRole,
     TextDirection as SemanticsTextDirection,
@@ -300,21 +304,21 @@ pub mod prelude {
         HeroController, HeroMode, Icon, IconData, IconTheme, IconThemeData, IgnorePointer, Image,
         ImageAlignment, ImageFit, ImageProvider, IndexedStack, InheritedTheme, Intent,
         IntrinsicHeight, IntrinsicWidth, LayoutBuilder, LayoutId, LimitedBox, ListBody, ListView,
-        Listener, Localizations, LocalizationsDelegate, MediaQuery, MediaQueryData, MergeSemantics,
-        MouseRegion, Navigator, NavigatorHandle, NextFocusAction, NextFocusIntent, Offstage,
-        Opacity, OverflowBox, OverflowBoxFit, Overlay, OverlayEntry, OverlayEntryId, OverlayHandle,
-        Padding, PageController, PageRoute, PageScrollPhysics, PageView, PopScope, PopupRoute,
-        Positioned, PreferredSize, PreferredSizeView, PreviousFocusAction, PreviousFocusIntent,
-        RepaintBoundary, RichText, RotatedBox, Row, SafeArea, ScrollController, Scrollable,
-        Scrollbar, Semantics, Shortcuts, ShrinkWrappingViewport, SimpleRoute, SingleActivator,
-        SingleChildScrollView, SizedBox, SizedOverflowBox, SliverChildBuilderDelegate,
-        SliverFillRemaining, SliverFillRemainingAndOverscroll, SliverFillRemainingWithScrollable,
-        SliverFillViewport, SliverFixedExtentList, SliverGrid, SliverIgnorePointer, SliverList,
-        SliverOffstage, SliverOpacity, SliverPadding, SliverToBoxAdapter, Spacer, Stack,
-        StreamBuilder, Table, TableCell, TableRow, Text, TextEditingController, TextField,
-        TickerMode, Transform, ValueListenableBuilder, Viewport, Visibility, WidgetState,
-        WidgetStateConstraint, WidgetStateProperty, WidgetStates, WidgetStatesController,
-        WidgetsLocalizations, Wrap,
+        ListenableBuilder, Listener, Localizations, LocalizationsDelegate, MediaQuery,
+        MediaQueryData, MergeSemantics, MouseRegion, Navigator, NavigatorHandle, NextFocusAction,
+        NextFocusIntent, Offstage, Opacity, OverflowBox, OverflowBoxFit, Overlay, OverlayEntry,
+        OverlayEntryId, OverlayHandle, Padding, PageController, PageRoute, PageScrollPhysics,
+        PageView, PopScope, PopupRoute, Positioned, PreferredSize, PreferredSizeView,
+        PreviousFocusAction, PreviousFocusIntent, RepaintBoundary, RichText, RotatedBox, Row,
+        SafeArea, ScrollController, Scrollable, Scrollbar, Semantics, Shortcuts,
+        ShrinkWrappingViewport, SimpleRoute, SingleActivator, SingleChildScrollView, SizedBox,
+        SizedOverflowBox, SliverChildBuilderDelegate, SliverFillRemaining,
+        SliverFillRemainingAndOverscroll, SliverFillRemainingWithScrollable, SliverFillViewport,
+        SliverFixedExtentList, SliverGrid, SliverIgnorePointer, SliverList, SliverOffstage,
+        SliverOpacity, SliverPadding, SliverToBoxAdapter, Spacer, Stack, StreamBuilder, Table,
+        TableCell, TableRow, Text, TextEditingController, TextField, TickerMode, Transform,
+        ValueListenableBuilder, Viewport, Visibility, WidgetState, WidgetStateConstraint,
+        WidgetStateProperty, WidgetStates, WidgetStatesController, WidgetsLocalizations, Wrap,
     };
 
     // Common configuration value types, so an app author needs only this import.
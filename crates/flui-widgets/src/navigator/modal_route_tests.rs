@@ -23,15 +23,17 @@
 use std::time::Duration;
 
 use flui_animation::{Animation, AnimationStatus};
+use flui_rendering::hit_testing::{HitTestBehavior, HitTestResult};
 use flui_types::Color;
+use flui_types::geometry::{Offset, px};
 use flui_view::prelude::*;
 
 use super::modal_route::{ModalHandle, ModalRoute};
 use super::navigator::{Navigator, NavigatorHandle};
 use super::overlay_route::SimpleRoute;
 use super::route::RouteId;
-use crate::SizedBox;
 use crate::test_harness::{Harness, mount};
+use crate::{Listener, SizedBox};
 
 const FRAME: Duration = Duration::from_millis(300);
 
@@ -463,6 +465,78 @@ fn modal_barrier_absorbs_pointers_and_a_dismissible_one_adds_a_gesture_detector(
     );
 }
 
+/// Pointer-down at `(x, y)` through the harness's real hit-testing pipeline
+/// (`PipelineOwner::hit_test` + `HitTestResult::dispatch`).
+fn tap_down(harness: &Harness, x: f32, y: f32) {
+    let position = Offset::new(px(x), px(y));
+    let owner = harness.pipeline_owner();
+    let mut result = HitTestResult::new();
+    owner.read().hit_test(position, &mut result);
+    let event = flui_interaction::events::make_down_event(
+        position,
+        flui_interaction::events::PointerType::Touch,
+    );
+    harness.enter_owner_scope(|| result.dispatch(&event));
+}
+
+/// The barrier's `AbsorbPointer` stops a real hit-test from reaching a route
+/// beneath it, and popping the modal hands input back to that route — the
+/// behaviour the render-name check above only implies.
+///
+/// Non-opaque modal, so the page below stays mounted and hit-testable the
+/// whole time: the barrier alone is what must stop the pointer.
+///
+/// Red-check: build the barrier with `absorbing(false)` — the second tap
+/// reaches the page.
+#[test]
+fn modal_barrier_blocks_hits_to_lower_routes_until_the_modal_is_removed() {
+    let downs = Arc::new(AtomicUsize::new(0));
+    let page_downs = Arc::clone(&downs);
+    let handle = NavigatorHandle::new();
+    handle.seed_initial(SimpleRoute::<i32>::new(move |_ctx| {
+        let downs = Arc::clone(&page_downs);
+        Listener::new()
+            .behavior(HitTestBehavior::Opaque)
+            .on_pointer_down(move |_event| {
+                downs.fetch_add(1, Ordering::Relaxed);
+            })
+            .child(SizedBox::expand())
+            .into_view()
+            .boxed()
+    }));
+    let mut harness = mount(Navigator::new(handle.clone()));
+
+    tap_down(&harness, 400.0, 300.0);
+    assert_eq!(downs.load(Ordering::Relaxed), 1, "the bare page is hit");
+
+    let route = modal(&Built::default(), &Arc::new(AtomicUsize::new(0)));
+    let transition = route.transition_handle();
+    let _result = harness.enter_owner_scope(|| handle.push(route));
+    complete_entrance(&transition, &mut harness);
+
+    tap_down(&harness, 400.0, 300.0);
+    assert_eq!(
+        downs.load(Ordering::Relaxed),
+        1,
+        "the modal barrier must absorb the pointer before it reaches the page"
+    );
+
+    assert!(harness.enter_owner_scope(|| handle.pop()));
+    let controller = transition.controller().expect("installed");
+    controller.set_value(0.0);
+    assert_eq!(controller.status(), AnimationStatus::Dismissed);
+    harness.tick();
+    harness.tick();
+    assert_eq!(handle.route_ids().len(), 1, "the modal is gone");
+
+    tap_down(&harness, 400.0, 300.0);
+    assert_eq!(
+        downs.load(Ordering::Relaxed),
+        2,
+        "removing the modal restores input to the page beneath"
+    );
+}
+
 // ============================================================================
 // Privacy
 // ============================================================================
@@ -55,7 +55,7 @@
 
 use std::fmt;
 use std::rc::Rc;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU64, Ordering};
 use std::sync::{Arc, Weak};
 
 use flui_view::{BoxedView, BuildContext, RebuildHandle};
@@ -115,6 +115,13 @@ struct EntryInner {
     /// [`opaque`]: EntryInner::opaque
     maintain_state: AtomicBool,
 
+    /// Z-order band. The overlay keeps its entries sorted by priority, so an
+    /// entry always sits above every lower-priority one regardless of
+    /// insertion order (dialogs over tooltips); insertion order and
+    /// `above`/`below` placement only apply within a band. No Flutter
+    /// analogue — `OverlayEntry` there is ordered purely by insertion.
+    priority: AtomicI32,
+
     /// The overlay currently holding this entry, or `None` when detached.
     ///
     /// `Weak`, so an entry outliving its overlay does not keep the overlay's
@@ -146,6 +153,7 @@ pub(crate) fn new(builder: impl Fn(&dyn BuildContext) -> BoxedView + 'static) ->
                 rebuild: Mutex::new(None),
                 opaque: AtomicBool::new(false),
                 maintain_state: AtomicBool::new(false),
+                priority: AtomicI32::new(0),
                 overlay: Mutex::new(None),
             }),
         }
@@ -166,6 +174,22 @@ pub(crate) fn with_maintain_state(self, maintain_state: bool) -> Self {
         self
     }
 
+    /// Builder form of the entry's z-order priority (default `0`). Higher
+    /// priorities always paint above lower ones; see
+    /// [`InsertPosition`](super::InsertPosition) for how placement is clamped
+    /// to the entry's band.
+    ///
+    /// Fixed once the entry is inserted: the overlay sorts on insertion only.
+    pub(crate) fn with_priority(self, priority: i32) -> Self {
+        self.inner.priority.store(priority, Ordering::Relaxed);
+        self
+    }
+
+    /// This entry's z-order priority.
+    pub(crate) fn priority(&self) -> i32 {
+        self.inner.priority.load(Ordering::Relaxed)
+    }
+
     /// Whether this entry occludes the entire overlay.
     pub(crate) fn opaque(&self) -> bool {
         self.inner.opaque.load(Ordering::Relaxed)
@@ -340,6 +364,7 @@ impl fmt::Debug for OverlayEntry {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("OverlayEntry")
             .field("id", &self.inner.id.get())
+            .field("priority", &self.priority())
             .field("mounted", &self.is_mounted())
             .field("attached", &self.inner.overlay.lock().is_some())
             .finish()
@@ -91,7 +91,10 @@
 ///
 /// Flutter passes `above:`/`below:` named arguments and asserts they are not both
 /// given (`overlay.dart:661`); an enum makes that unrepresentable instead.
-/// Resolves to Flutter's `_insertionIndex` (`overlay.dart:660-669`).
+/// Resolves to Flutter's `_insertionIndex` (`overlay.dart:660-669`), then
+/// clamps into the inserted entry's [priority](OverlayEntry::with_priority)
+/// band: `Top` means the top of that band, and an `Above`/`Below` reference in
+/// another band places the entry at the nearest edge of its own.
 #[derive(Debug, Clone)]
 pub(crate) enum InsertPosition {
     /// Append — the new entry paints above every existing one. Flutter's default.
@@ -228,6 +231,9 @@ pub(crate) fn insert_all(&self, entries: &[OverlayEntry], position: &InsertPosit
             let mut list = self.shared.entries.lock();
             let index = insertion_index(&list, position);
             list.splice(index..index, entries.iter().cloned());
+            // Stable: a group of one priority stays contiguous and in order,
+            // and lands at `index` when that is inside its band.
+            list.sort_by_key(OverlayEntry::priority);
         }
         self.shared.schedule_rebuild();
     }
@@ -245,6 +251,10 @@ pub(crate) fn insert_all(&self, entries: &[OverlayEntry], position: &InsertPosit
     /// the `listEquals` short-circuit (`:833`) that makes a no-op reorder cost
     /// **no rebuild** — pinned by `overlay_rearrange_to_the_same_order_is_a_noop`.
     ///
+    /// The resulting order is then stably sorted by
+    /// [priority](OverlayEntry::with_priority), so `new_entries` decides the
+    /// order within each band but cannot move an entry out of its band.
+    ///
     /// **Deferred:** the `above:` / `below:` placement of the unmentioned group.
     /// Nothing needs it yet; `Navigator` never passes either.
     pub(crate) fn rearrange(&self, new_entries: &[OverlayEntry]) {
@@ -280,6 +290,8 @@ pub(crate) fn rearrange(&self, new_entries: &[OverlayEntry]) {
             list.clear();
             list.extend(new_entries.iter().cloned());
             list.extend(unmentioned);
+            // The requested order holds within each priority band only.
+            list.sort_by_key(OverlayEntry::priority);
         }
 
         self.shared.schedule_rebuild();
@@ -623,6 +623,91 @@ fn overlay_rearrange_inserts_unknown_entries() {
     assert!(entry_b.is_attached());
 }
 
+// ============================================================================
+// priority
+// ============================================================================
+
+/// A higher-priority entry stays above lower-priority ones whatever order they
+/// arrive in: a tooltip inserted on `Top` after a dialog still lands below it.
+#[test]
+fn overlay_priority_keeps_higher_entries_on_top() {
+    let calls = Calls::default();
+    let page = counting_entry(&calls);
+    let dialog = counting_entry(&calls).with_priority(10);
+    let (handle, overlay) = overlay_with(&[page.clone(), dialog.clone()]);
+    let mut harness = mount(overlay);
+
+    let tooltip = counting_entry(&calls).with_priority(5);
+    handle.insert(&tooltip, &InsertPosition::Top);
+    harness.tick();
+    assert_eq!(
+        handle.entry_ids(),
+        vec![page.id(), tooltip.id(), dialog.id()]
+    );
+
+    // Equal priority: insertion order (and `Top`) applies within the band.
+    let second_dialog = counting_entry(&calls).with_priority(10);
+    handle.insert(&second_dialog, &InsertPosition::Top);
+    harness.tick();
+    assert_eq!(
+        handle.entry_ids(),
+        vec![page.id(), tooltip.id(), dialog.id(), second_dialog.id()]
+    );
+    assert_eq!(
+        layer_elements(&mut harness),
+        vec![
+            page.element_id().expect("page mounted"),
+            tooltip.element_id().expect("tooltip mounted"),
+            dialog.element_id().expect("dialog mounted"),
+            second_dialog.element_id().expect("second dialog mounted"),
+        ],
+        "paint order follows priority"
+    );
+}
+
+/// `Above`/`Below` relative to an entry in another band clamp to the nearest
+/// edge of the inserted entry's own band.
+#[test]
+fn overlay_priority_clamps_relative_insertion_to_the_band() {
+    let calls = Calls::default();
+    let (low, high) = (
+        counting_entry(&calls),
+        counting_entry(&calls).with_priority(1),
+    );
+    let (handle, overlay) = overlay_with(&[low.clone(), high.clone()]);
+    let mut harness = mount(overlay);
+
+    // Asked to go above `high`, a priority-0 entry tops its own band instead.
+    let under = counting_entry(&calls);
+    handle.insert(&under, &InsertPosition::Above(high.clone()));
+    // Asked to go below `low`, a priority-1 entry bottoms its own band.
+    let over = counting_entry(&calls).with_priority(1);
+    handle.insert(&over, &InsertPosition::Below(low.clone()));
+    harness.tick();
+
+    assert_eq!(
+        handle.entry_ids(),
+        vec![low.id(), under.id(), over.id(), high.id()]
+    );
+}
+
+/// `rearrange` cannot lift an entry out of its band.
+#[test]
+fn overlay_rearrange_respects_priority() {
+    let calls = Calls::default();
+    let (low, high) = (
+        counting_entry(&calls),
+        counting_entry(&calls).with_priority(1),
+    );
+    let (handle, overlay) = overlay_with(&[low.clone(), high.clone()]);
+    let mut harness = mount(overlay);
+
+    handle.rearrange(&[high.clone(), low.clone()]);
+    harness.tick();
+
+    assert_eq!(handle.entry_ids(), vec![low.id(), high.id()]);
+}
+
 // ============================================================================
 // opaque / maintainState / skipCount
 // ============================================================================
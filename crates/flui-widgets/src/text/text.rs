@@ -7,23 +7,33 @@
 use super::default_text_style::DefaultTextStyle;
 use super::rich_text::RichText;
 
-/// Displays a string of text with a single style.
+/// Displays a string of text with a single style, or a [`TextSpan`] tree.
 ///
 /// Flutter parity: `widgets/text.dart` `Text` — a `StatelessWidget` that merges
 /// the ambient [`DefaultTextStyle`] with its own and builds a
-/// [`RichText`] (`text.dart:716-765`), as Flutter's does. For
-/// multi-style runs, use `RichText` with a [`TextSpan`] tree directly — it reads
-/// no ambient style, also as in Flutter.
+/// [`RichText`] (`text.dart:716-765`), as Flutter's does. [`Text::rich`]
+/// takes a span tree whose spans inherit that merged style, so mixed-style
+/// runs still pick up the ambient font; `RichText` used directly reads no
+/// ambient style, also as in Flutter.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # use flui_widgets::prelude::*;
+/// # use flui_types::typography::{FontWeight, TextSpan, TextStyle};
 /// let _ = Text::new("Hello, world");
+/// let _ = Text::rich(TextSpan::new("Hello ").with_child(TextSpan::styled(
+///     "world",
+///     TextStyle {
+///         font_weight: Some(FontWeight::BOLD),
+///         ..Default::default()
+///     },
+/// )));
 /// ```
 #[derive(Clone, Debug)]
 pub struct Text {
     data: String,
+    span: Option<TextSpan>,
     style: Option<TextStyle>,
     align: Option<TextAlign>,
     direction: TextDirection,
@@ -36,6 +46,7 @@ impl Text {
     pub fn new(data: impl Into<String>) -> Self {
         Self {
             data: data.into(),
+            span: None,
             style: None,
             align: None,
             direction: TextDirection::Ltr,
@@ -43,6 +54,18 @@ pub fn new(data: impl Into<String>) -> Self {
         }
     }
 
+    /// Create text displaying a [`TextSpan`] tree in one paragraph.
+    ///
+    /// The spans' own styles merge over this widget's effective style (the
+    /// ambient [`DefaultTextStyle`] merged with [`style`](Self::style)), so
+    /// fields a span leaves unset are inherited. Flutter's `Text.rich`.
+    pub fn rich(span: TextSpan) -> Self {
+        Self {
+            span: Some(span),
+            ..Self::new(String::new())
+        }
+    }
+
     /// Apply a [`TextStyle`] to the whole run. Merged **over** the ambient
     /// [`DefaultTextStyle`], field by field (`text.dart:718-720`).
     #[must_use]
@@ -98,12 +121,17 @@ fn build(&self, ctx: &dyn BuildContext) -> impl IntoView {
             Some(own) => ambient_style.merge(own),
             None => ambient_style,
         };
-        // An all-unset style is byte-for-byte the unstyled span.
-        let span = if effective_style == TextStyle::default() {
-            TextSpan::new(self.data.clone())
-        } else {
-            TextSpan::styled(self.data.clone(), effective_style)
+        // `TextSpan(style: effectiveTextStyle, text: data, children:
+        // textSpan != null ? [textSpan] : null)` in `Text.build`: a rich run
+        // hangs under a text-less root that carries the inherited style.
+        let mut span = match &self.span {
+            Some(rich) => TextSpan::with_children(vec![rich.clone()]),
+            None => TextSpan::new(self.data.clone()),
         };
+        // An all-unset style is byte-for-byte the unstyled span.
+        if effective_style != TextStyle::default() {
+            span = span.with_style(effective_style);
+        }
 
         let align = self
             .align
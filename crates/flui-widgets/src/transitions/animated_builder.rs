@@ -2,6 +2,7 @@
 
 use std::{rc::Rc, sync::Arc};
 
+use flui_animation::Animation;
 use flui_foundation::Listenable;
 use flui_view::prelude::BuildContext;
 use flui_view::{
@@ -44,6 +45,22 @@ pub fn new<V, F>(listenable: Arc<dyn Listenable>, builder: F) -> Self
             builder: Rc::new(move || builder().into_view().boxed()),
         }
     }
+
+    /// Rebuild `builder(value)` whenever `animation` ticks, handing the
+    /// closure the animation's current value so it need not capture the
+    /// animation itself.
+    pub fn from_animation<T, V, F>(animation: Arc<dyn Animation<T>>, builder: F) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+        V: IntoView,
+        F: Fn(T) -> V + 'static,
+    {
+        let source = Arc::clone(&animation);
+        Self {
+            listenable: animation as Arc<dyn Listenable>,
+            builder: Rc::new(move || builder(source.value()).into_view().boxed()),
+        }
+    }
 }
 
 impl std::fmt::Debug for AnimatedBuilder {
@@ -0,0 +1,103 @@
+//! [`ListenableBuilder`] — rebuilds a subtree from a context-aware builder each
+//! time a [`Listenable`] notifies.
+
+use std::{rc::Rc, sync::Arc};
+
+use flui_foundation::Listenable;
+use flui_view::prelude::BuildContext;
+use flui_view::{
+    AnimatedView, BoxedView, IntoView, StatefulView, ViewExt, ViewState, impl_animated_view,
+};
+
+/// Builds a subtree from the build context and an optional pre-built child.
+///
+/// `child` is the value handed to [`ListenableBuilder::child`], passed back on
+/// every call so the listenable-independent part of the subtree is built once
+/// by the caller rather than on every notification.
+///
+/// Flutter parity: `TransitionBuilder`.
+pub type TransitionBuilder = Rc<dyn Fn(&dyn BuildContext, Option<BoxedView>) -> BoxedView>;
+
+/// Rebuilds whatever its `builder` returns whenever `listenable` notifies.
+///
+/// Flutter parity: `widgets/transitions.dart` `ListenableBuilder` — the
+/// context-aware sibling of [`AnimatedBuilder`](crate::AnimatedBuilder). The
+/// element subscribes to `listenable` on mount and unsubscribes on unmount (or
+/// when a rebuild swaps in a different listenable instance); a notification
+/// schedules a rebuild of this builder alone, never of the widget that
+/// created it.
+///
+/// Reach for it to bridge a [`ChangeNotifier`](flui_foundation::ChangeNotifier),
+/// a signal or any other [`Listenable`] into the view tree without making the
+/// enclosing widget stateful.
+///
+/// ```rust,ignore
+/// let notifier = Arc::new(ChangeNotifier::new());
+/// let counter = ListenableBuilder::new(notifier.clone(), move |_ctx, _child| {
+///     Text::new(format!("{}", model.count()))
+/// });
+/// ```
+#[derive(Clone)]
+pub struct ListenableBuilder {
+    listenable: Arc<dyn Listenable>,
+    builder: TransitionBuilder,
+    child: Option<BoxedView>,
+}
+
+impl ListenableBuilder {
+    /// Rebuild `builder(ctx, child)` whenever `listenable` notifies.
+    pub fn new<V, F>(listenable: Arc<dyn Listenable>, builder: F) -> Self
+    where
+        V: IntoView,
+        F: Fn(&dyn BuildContext, Option<BoxedView>) -> V + 'static,
+    {
+        Self {
+            listenable,
+            builder: Rc::new(move |ctx, child| builder(ctx, child).into_view().boxed()),
+            child: None,
+        }
+    }
+
+    /// A listenable-independent subtree handed back to `builder` on every
+    /// rebuild.
+    #[must_use]
+    pub fn child(mut self, child: impl IntoView) -> Self {
+        self.child = Some(child.into_view().boxed());
+        self
+    }
+}
+
+impl std::fmt::Debug for ListenableBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListenableBuilder")
+            .field("has_child", &self.child.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// State for [`ListenableBuilder`]. Stateless beyond the listenable
+/// subscription [`AnimatedView`] manages.
+#[derive(Debug)]
+pub struct ListenableBuilderState;
+
+impl ViewState<ListenableBuilder> for ListenableBuilderState {
+    fn build(&self, view: &ListenableBuilder, ctx: &dyn BuildContext) -> impl IntoView {
+        (view.builder)(ctx, view.child.clone())
+    }
+}
+
+impl StatefulView for ListenableBuilder {
+    type State = ListenableBuilderState;
+
+    fn create_state(&self) -> Self::State {
+        ListenableBuilderState
+    }
+}
+
+impl AnimatedView for ListenableBuilder {
+    fn listenable(&self) -> Arc<dyn Listenable> {
+        self.listenable.clone()
+    }
+}
+
+impl_animated_view!(ListenableBuilder);
@@ -6,12 +6,14 @@
 
 mod animated_builder;
 mod fade_transition;
+mod listenable_builder;
 mod rotation_transition;
 mod scale_transition;
 mod slide_transition;
 
 pub use animated_builder::{AnimatedBuilder, AnimatedBuilderState};
 pub use fade_transition::{FadeTransition, FadeTransitionState};
+pub use listenable_builder::{ListenableBuilder, ListenableBuilderState, TransitionBuilder};
 pub use rotation_transition::{RotationTransition, RotationTransitionState};
 pub use scale_transition::{ScaleTransition, ScaleTransitionState};
 pub use slide_transition::{SlideTransition, SlideTransitionState};
@@ -18,6 +18,7 @@
 use flui_foundation::{ElementId, RenderId};
 use flui_geometry::Matrix4;
 use flui_interaction::PointerId;
+use flui_interaction::events::pointer::PointerButton;
 use flui_interaction::events::{
     PointerEvent, PointerType, make_cancel_event_for_id, make_down_event_for_id,
     make_move_event_for_id, make_up_event_for_id,
@@ -278,6 +279,21 @@ pub fn absolute_offset(&self, id: RenderId) -> Offset {
         offset(x, y)
     }
 
+    /// The render object of the element keyed by `key`
+    /// ([`ElementTree::find_render_object`]).
+    pub fn render_object_for_key<T: 'static>(
+        &mut self,
+        key: &flui_view::GlobalKey<T>,
+    ) -> Option<RenderId> {
+        self.binding.tree_mut().find_render_object(key)
+    }
+
+    /// The root-space bounds of `id` after every ancestor transform
+    /// ([`PipelineOwner::global_paint_bounds`]).
+    pub fn global_paint_bounds(&self, id: RenderId) -> Option<Rect> {
+        self.pipeline_owner.read().global_paint_bounds(id)
+    }
+
     /// Drive one more frame after external state has changed — the headless
     /// equivalent of what `setState` schedules: mark the root dirty, then pump a
     /// zero-time frame (rebuild the subtree + re-run layout/paint). Used by the
@@ -408,9 +424,8 @@ pub fn opacity_skip_paint(&self, id: RenderId) -> bool {
 
     /// The [`Clip`] behavior of a clip-family render node (`RenderClipRect`,
     /// `RenderClipRRect`, `RenderClipOval`, `RenderClipPath`) or a
-    /// [`RenderFittedBox`] (which stores `clip_behavior` today even though
-    /// active clip-painting is still pending — see its module doc). Panics
-    /// if `id` is none of the five.
+    /// [`RenderFittedBox`] (which clips only when its fit crops the child).
+    /// Panics if `id` is none of the five.
     pub fn clip_behavior(&self, id: RenderId) -> Clip {
         let mut owner = self.pipeline_owner.write();
         let node = owner
@@ -437,6 +452,18 @@ pub fn clip_behavior(&self, id: RenderId) -> Clip {
         );
     }
 
+    /// The `(sx, sy)` scale a [`RenderFittedBox`] applied to its child at the
+    /// last layout. Panics if `id` is not a `RenderFittedBox`.
+    pub fn fitted_box_scale(&self, id: RenderId) -> (f32, f32) {
+        let mut owner = self.pipeline_owner.write();
+        owner
+            .render_tree_mut()
+            .get_mut(id)
+            .and_then(|node| node.downcast_render_object_mut::<RenderFittedBox>())
+            .map(|render| render.scale_factors())
+            .expect("render node should be a RenderFittedBox")
+    }
+
     /// The installed [`BorderRadius`] of a `RenderClipRRect` node. Panics if
     /// `id` is not a `RenderClipRRect`, or it carries no border radius.
     pub fn clip_rrect_border_radius(&self, id: RenderId) -> BorderRadius {
@@ -652,6 +679,25 @@ pub fn find_by_render_type(&self, render_type_name: &str) -> RenderId {
         }
     }
 
+    /// Short base type names from `id` down its first-child chain to the
+    /// first leaf, e.g. `["RenderPadding", "RenderColoredBox"]` — the
+    /// composed render-object stack of a single-child widget.
+    pub fn render_chain(&self, id: RenderId) -> Vec<String> {
+        let owner = self.pipeline_owner.read();
+        let render_tree = owner.render_tree();
+        let mut chain = Vec::new();
+        let mut current = Some(id);
+        while let Some(node) = current {
+            let name = owner
+                .debug_node_diagnostics(node)
+                .and_then(|diagnostics| diagnostics.name().map(|n| base_type_name(n).to_owned()))
+                .unwrap_or_default();
+            chain.push(name);
+            current = render_tree.children(node).first().copied();
+        }
+        chain
+    }
+
     /// Find the unique `RenderParagraph` node that contains `text` as its
     /// plain-text content.
     ///
@@ -817,41 +863,47 @@ pub fn dispatch_pointer_cancel(&self, x: f32, y: f32) {
     /// button press reaching the framework. Used by `GestureDetector` tests to
     /// assert `on_secondary_tap` fires on right-click.
     pub fn dispatch_secondary_down(&self, x: f32, y: f32) {
-        use flui_interaction::events::pointer::PointerButton;
-        use flui_rendering::hit_testing::HitTestResult;
-
-        let position = Offset::new(px(x), px(y));
-        let mut result = HitTestResult::new();
-        let event = flui_interaction::events::make_down_event_with_button(
-            position,
-            flui_interaction::events::PointerType::Mouse,
-            PointerButton::Secondary,
-        );
-        self.binding.enter_owner_scope(|| {
-            // See `route_event`'s comment: the read guard must not span
-            // `dispatch`, which can reenter `pipeline_owner.read()` from a
-            // gesture callback.
-            {
-                let owner = self.pipeline_owner.read();
-                owner.hit_test(position, &mut result);
-            }
-            result.dispatch(&event);
-        });
+        self.dispatch_mouse_button(x, y, PointerButton::Secondary, false);
     }
 
     /// As [`dispatch_secondary_down`](Self::dispatch_secondary_down), but a
     /// secondary-button pointer-up — to complete the right-click gesture.
     pub fn dispatch_secondary_up(&self, x: f32, y: f32) {
-        use flui_interaction::events::pointer::PointerButton;
+        self.dispatch_mouse_button(x, y, PointerButton::Secondary, true);
+    }
+
+    /// As [`dispatch_secondary_down`](Self::dispatch_secondary_down), but a
+    /// tertiary-button (middle-click) pointer-down.
+    pub fn dispatch_tertiary_down(&self, x: f32, y: f32) {
+        self.dispatch_mouse_button(x, y, PointerButton::Auxiliary, false);
+    }
+
+    /// As [`dispatch_tertiary_down`](Self::dispatch_tertiary_down), but a
+    /// tertiary-button pointer-up — to complete the middle-click gesture.
+    pub fn dispatch_tertiary_up(&self, x: f32, y: f32) {
+        self.dispatch_mouse_button(x, y, PointerButton::Auxiliary, true);
+    }
+
+    /// Hit-test at root-local `(x, y)` and dispatch a mouse down (or up, when
+    /// `up`) carrying `button`.
+    fn dispatch_mouse_button(&self, x: f32, y: f32, button: PointerButton, up: bool) {
         use flui_rendering::hit_testing::HitTestResult;
 
         let position = Offset::new(px(x), px(y));
         let mut result = HitTestResult::new();
-        let event = flui_interaction::events::make_up_event_with_button(
-            position,
-            flui_interaction::events::PointerType::Mouse,
-            PointerButton::Secondary,
-        );
+        let event = if up {
+            flui_interaction::events::make_up_event_with_button(
+                position,
+                PointerType::Mouse,
+                button,
+            )
+        } else {
+            flui_interaction::events::make_down_event_with_button(
+                position,
+                PointerType::Mouse,
+                button,
+            )
+        };
         self.binding.enter_owner_scope(|| {
             // See `route_event`'s comment: the read guard must not span
             // `dispatch`, which can reenter `pipeline_owner.read()` from a
@@ -2,9 +2,11 @@
 //! `StatelessView::build` composes the right widget stack so padding,
 //! sizing, and alignment combine exactly as Flutter's `Container` does.
 
-use crate::common::{lay_out, loose, offset, size};
-use flui_geometry::EdgeInsets;
-use flui_types::Alignment;
+use crate::common::{lay_out, loose, offset, size, tight};
+use flui_geometry::{EdgeInsets, px};
+use flui_rendering::constraints::BoxConstraints;
+use flui_types::styling::BoxDecoration;
+use flui_types::{Alignment, Color, Pixels};
 use flui_widgets::{Container, SizedBox};
 
 #[test]
@@ -58,3 +60,77 @@ fn container_childless_with_size_fills_to_size() {
     let laid = lay_out(Container::new().width(80.0).height(40.0), loose(1000.0));
     assert_eq!(laid.size(laid.root()), size(80.0, 40.0));
 }
+
+#[test]
+fn container_color_only_composes_a_single_colored_box() {
+    // Only `color` set: no sizing, padding or alignment layers are added.
+    let laid = lay_out(
+        Container::new()
+            .color(Color::rgb(200, 40, 40))
+            .child(SizedBox::square(30.0)),
+        loose(1000.0),
+    );
+    assert_eq!(
+        laid.render_chain(laid.root()),
+        // `ColoredBox` paints through a `RenderDecoratedBox`.
+        ["RenderDecoratedBox", "RenderConstrainedBox"],
+        "color-only container should be one paint layer over the child's SizedBox"
+    );
+    assert_eq!(laid.size(laid.root()), size(30.0, 30.0));
+}
+
+#[test]
+fn container_full_featured_composes_in_flutter_order() {
+    let laid = lay_out(
+        Container::new()
+            .margin(EdgeInsets::all(px(5.0)))
+            .width(100.0)
+            .height(80.0)
+            .decoration(BoxDecoration::new().set_color(Some(Color::rgb(0, 0, 255))))
+            .padding(EdgeInsets::all(px(10.0)))
+            .alignment(Alignment::BOTTOM_RIGHT)
+            .child(SizedBox::square(20.0)),
+        loose(1000.0),
+    );
+    assert_eq!(
+        laid.render_chain(laid.root()),
+        [
+            "RenderPadding",        // margin
+            "RenderConstrainedBox", // width/height
+            "RenderDecoratedBox",
+            "RenderPadding", // padding
+            "RenderAlign",
+            "RenderConstrainedBox", // the SizedBox child
+        ]
+    );
+    assert_eq!(laid.size(laid.root()), size(110.0, 90.0));
+
+    // Child sits bottom-right inside the 80×60 padded content box.
+    let leaf = laid.find_all_by_render_type("RenderConstrainedBox")[1];
+    assert_eq!(laid.size(leaf), size(20.0, 20.0));
+    assert_eq!(laid.offset(leaf), offset(60.0, 40.0));
+}
+
+#[test]
+fn container_empty_collapses_to_its_constraints() {
+    // Unbounded: the childless placeholder collapses to the minimum.
+    let unbounded = lay_out(
+        Container::new().constraints(BoxConstraints::new(
+            px(12.0),
+            Pixels::INFINITY,
+            px(8.0),
+            Pixels::INFINITY,
+        )),
+        BoxConstraints::new(
+            Pixels::ZERO,
+            Pixels::INFINITY,
+            Pixels::ZERO,
+            Pixels::INFINITY,
+        ),
+    );
+    assert_eq!(unbounded.size(unbounded.root()), size(12.0, 8.0));
+
+    // Bounded: it expands to fill the incoming constraints.
+    let bounded = lay_out(Container::new(), tight(64.0, 48.0));
+    assert_eq!(bounded.size(bounded.root()), size(64.0, 48.0));
+}
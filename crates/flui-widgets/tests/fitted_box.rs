@@ -1,18 +1,16 @@
-//! Tests for `FittedBox`'s own sizing contract.
+//! Tests for `FittedBox`'s sizing contract and the scale it applies per fit.
 //!
 //! `RenderFittedBox::perform_layout` (`crates/flui-objects/.../fitted_box.rs`)
 //! computes its OWN size via `constrain_size_and_attempt_to_preserve_aspect_ratio`
 //! for every `BoxFit` variant except `ScaleDown` -- `fit` only changes how the
-//! child is later scaled/positioned *within* that size (a paint-time
-//! transform this headless harness cannot introspect for a `RenderFittedBox`
-//! node, unlike the dedicated `RenderTransform` accessor). These tests verify
-//! the two size invariants that hold regardless of `fit`, plus that the
-//! widget genuinely mounts `RenderFittedBox` -- the fit-dependent scale/
-//! alignment math itself is already covered by `RenderFittedBox`'s own
-//! harness tests in `flui-objects`.
+//! child is later scaled/positioned *within* that size. The size tests pin
+//! the invariants that hold regardless of `fit`; the scale tests read the
+//! cached `(sx, sy)` back through `LaidOut::fitted_box_scale` for every
+//! variant against several parent sizes.
 
 use crate::common::{lay_out, loose, size, tight};
 use flui_types::layout::BoxFit;
+use flui_types::painting::Clip;
 use flui_widgets::{FittedBox, SizedBox};
 
 #[test]
@@ -64,3 +62,48 @@ fn fitted_box_takes_the_child_natural_size_when_it_already_fits_the_loose_bound(
     );
     assert_eq!(laid.size(laid.root()), size(100.0, 50.0));
 }
+
+/// `(fit, parent width, parent height, expected sx, expected sy)` for a fixed
+/// 100x50 child under tight parent constraints.
+const SCALE_CASES: &[(BoxFit, f32, f32, f32, f32)] = &[
+    // 200x200: width ratio 2, height ratio 4.
+    (BoxFit::Contain, 200.0, 200.0, 2.0, 2.0),
+    (BoxFit::Cover, 200.0, 200.0, 4.0, 4.0),
+    (BoxFit::Fill, 200.0, 200.0, 2.0, 4.0),
+    (BoxFit::FitWidth, 200.0, 200.0, 2.0, 2.0),
+    (BoxFit::FitHeight, 200.0, 200.0, 4.0, 4.0),
+    (BoxFit::ScaleDown, 200.0, 200.0, 1.0, 1.0),
+    // 50x50: width ratio 0.5, height ratio 1.
+    (BoxFit::Contain, 50.0, 50.0, 0.5, 0.5),
+    (BoxFit::Cover, 50.0, 50.0, 1.0, 1.0),
+    (BoxFit::Fill, 50.0, 50.0, 0.5, 1.0),
+    (BoxFit::FitWidth, 50.0, 50.0, 0.5, 0.5),
+    (BoxFit::FitHeight, 50.0, 50.0, 1.0, 1.0),
+    (BoxFit::ScaleDown, 50.0, 50.0, 0.5, 0.5),
+    // 300x100: width ratio 3, height ratio 2.
+    (BoxFit::Contain, 300.0, 100.0, 2.0, 2.0),
+    (BoxFit::Cover, 300.0, 100.0, 3.0, 3.0),
+    (BoxFit::Fill, 300.0, 100.0, 3.0, 2.0),
+    (BoxFit::FitWidth, 300.0, 100.0, 3.0, 3.0),
+    (BoxFit::FitHeight, 300.0, 100.0, 2.0, 2.0),
+    (BoxFit::ScaleDown, 300.0, 100.0, 1.0, 1.0),
+];
+
+#[test]
+fn fitted_box_scales_the_child_per_fit_for_each_parent_size() {
+    for &(fit, width, height, expected_sx, expected_sy) in SCALE_CASES {
+        let laid = lay_out(
+            FittedBox::new()
+                .fit(fit)
+                .clip(Clip::HardEdge)
+                .child(SizedBox::new(100.0, 50.0)),
+            tight(width, height),
+        );
+        let (sx, sy) = laid.fitted_box_scale(laid.root());
+        assert!(
+            (sx - expected_sx).abs() < 1e-4 && (sy - expected_sy).abs() < 1e-4,
+            "fit={fit:?} in {width}x{height}: expected scale ({expected_sx}, {expected_sy}), \
+             got ({sx}, {sy})",
+        );
+    }
+}
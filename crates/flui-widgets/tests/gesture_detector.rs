@@ -276,6 +276,40 @@ fn secondary_tap_fires_on_secondary_down_up() {
     );
 }
 
+#[test]
+fn tertiary_tap_fires_on_middle_click_only() {
+    let secondary_taps = Arc::new(AtomicUsize::new(0));
+    let tertiary_taps = Arc::new(AtomicUsize::new(0));
+    let (secondary_cb, tertiary_cb) = (Arc::clone(&secondary_taps), Arc::clone(&tertiary_taps));
+
+    let laid = lay_out(
+        GestureDetector::new()
+            .on_secondary_tap(move || {
+                secondary_cb.fetch_add(1, Ordering::SeqCst);
+            })
+            .on_tertiary_tap(move || {
+                tertiary_cb.fetch_add(1, Ordering::SeqCst);
+            })
+            .child(ColoredBox::new(Color::rgb(10, 20, 30))),
+        tight(100.0, 100.0),
+    );
+
+    // A middle-button down + up fires on_tertiary_tap.
+    laid.dispatch_tertiary_down(50.0, 50.0);
+    laid.dispatch_tertiary_up(50.0, 50.0);
+
+    assert_eq!(
+        tertiary_taps.load(Ordering::SeqCst),
+        1,
+        "a tertiary down+up fires on_tertiary_tap exactly once",
+    );
+    assert_eq!(
+        secondary_taps.load(Ordering::SeqCst),
+        0,
+        "a tertiary tap must NOT fire on_secondary_tap",
+    );
+}
+
 /// Flutter parity (tag `3.44.0`): `widgets/gesture_detector.dart`'s
 /// `onHorizontalDrag*` family — an axis-constrained recognizer distinct from
 /// `onPan*`, exercised end to end (down/start/update/end) here for the first
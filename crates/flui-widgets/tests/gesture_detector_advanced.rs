@@ -13,7 +13,8 @@
 use std::time::Duration;
 
 use crate::common::{lay_out, lay_out_with_arena, tight};
-use flui_types::Color;
+use flui_types::geometry::px;
+use flui_types::{Color, Offset};
 use flui_widgets::{ColoredBox, GestureDetector};
 
 /// A hit-testable child so the detector's `DeferToChild` listener registers.
@@ -89,6 +90,51 @@ fn quick_release_does_not_fire_long_press() {
     );
 }
 
+#[test]
+fn long_press_reports_start_move_update_and_end() {
+    let starts = Arc::new(AtomicUsize::new(0));
+    let updates = Arc::new(AtomicUsize::new(0));
+    let ends = Arc::new(AtomicUsize::new(0));
+    let (start_cb, update_cb, end_cb) =
+        (Arc::clone(&starts), Arc::clone(&updates), Arc::clone(&ends));
+
+    let mut scoped = lay_out_with_arena(
+        GestureDetector::new()
+            .on_long_press_start(move |details| {
+                assert_eq!(details.global_position, Offset::new(px(50.0), px(50.0)));
+                start_cb.fetch_add(1, Ordering::SeqCst);
+            })
+            .on_long_press_move_update(move |_| {
+                update_cb.fetch_add(1, Ordering::SeqCst);
+            })
+            .on_long_press_end(move |details| {
+                assert_eq!(details.global_position, Offset::new(px(80.0), px(50.0)));
+                end_cb.fetch_add(1, Ordering::SeqCst);
+            })
+            .child(target()),
+        tight(100.0, 100.0),
+    );
+
+    scoped.dispatch_pointer_down(50.0, 50.0);
+    scoped.pump(Duration::from_millis(600));
+    assert_eq!(
+        starts.load(Ordering::SeqCst),
+        1,
+        "the hold deadline fires on_long_press_start without on_long_press set",
+    );
+
+    // Once started, moving past the slop updates instead of cancelling.
+    scoped.dispatch_pointer_move(80.0, 50.0);
+    scoped.dispatch_pointer_up(80.0, 50.0);
+
+    assert_eq!(updates.load(Ordering::SeqCst), 1, "one move, one update");
+    assert_eq!(
+        ends.load(Ordering::SeqCst),
+        1,
+        "release fires on_long_press_end"
+    );
+}
+
 // ============================================================================
 // (2) Double tap — two quick virtual-clock taps.
 // ============================================================================
@@ -460,3 +506,52 @@ fn overlapping_detectors_held_press_resolves_to_the_outer_long_press() {
         "the long press rejected the inner tap, so the tap must NOT fire",
     );
 }
+
+// ============================================================================
+// (5) Lazy installation — only the configured recognizers join the arena.
+// ============================================================================
+
+/// Route a down at the centre WITHOUT the binding's arena close, so the
+/// members that joined the shared arena are still observable.
+fn arena_members_after_down(detector: GestureDetector) -> usize {
+    let scoped = lay_out_with_arena(detector.child(target()), tight(100.0, 100.0));
+    let event = flui_interaction::events::make_down_event(
+        Offset::new(px(50.0), px(50.0)),
+        flui_interaction::events::PointerType::Mouse,
+    );
+    scoped.laid().route_event(&event, 50.0, 50.0);
+    scoped
+        .laid()
+        .arena()
+        .member_count(flui_interaction::PointerId::PRIMARY)
+}
+
+#[test]
+fn only_configured_recognizers_join_the_arena() {
+    assert_eq!(
+        arena_members_after_down(GestureDetector::new()),
+        0,
+        "a detector with no callbacks installs no recognizer",
+    );
+    assert_eq!(
+        arena_members_after_down(GestureDetector::new().on_tap(|| {})),
+        1,
+        "a tap-only detector adds just the tap recognizer",
+    );
+    assert_eq!(
+        arena_members_after_down(GestureDetector::new().on_tap(|| {}).on_scale_update(|_| {})),
+        2,
+        "tap + scale adds exactly those two recognizers",
+    );
+    assert_eq!(
+        arena_members_after_down(
+            GestureDetector::new()
+                .on_tap(|| {})
+                .on_double_tap(|| {})
+                .on_long_press(|| {})
+                .on_pan_update(|_| {})
+        ),
+        4,
+        "each configured family contributes one member",
+    );
+}
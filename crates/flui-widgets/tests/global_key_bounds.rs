@@ -0,0 +1,113 @@
+//! `ElementTree::find_render_object` + `PipelineOwner::global_paint_bounds`:
+//! a `GlobalKey`-tagged widget's screen-space bounds after a layout pass — the
+//! lookup tooltips and anchored popovers build on.
+
+use crate::common::{lay_out, tight};
+use flui_foundation::ViewKey;
+use flui_types::{Alignment, Rect, geometry::px};
+use flui_view::BoxedView;
+use flui_view::{BuildContext, GlobalKey, IntoView, StatelessView, View, ViewExt};
+use flui_widgets::{Align, Padding, SizedBox, Transform};
+
+/// A component that carries a `GlobalKey` and builds `child` — the keyed
+/// element owns no render object, so the lookup has to descend to the child's.
+#[derive(Clone)]
+struct Keyed {
+    key: GlobalKey<()>,
+    child: BoxedView,
+}
+
+impl Keyed {
+    fn new(key: &GlobalKey<()>, child: impl IntoView) -> Self {
+        Self {
+            key: key.clone(),
+            child: child.into_view().boxed(),
+        }
+    }
+}
+
+impl StatelessView for Keyed {
+    fn build(&self, _ctx: &dyn BuildContext) -> impl IntoView {
+        self.child.clone()
+    }
+}
+
+impl View for Keyed {
+    fn create_element(&self) -> flui_view::element::ElementKind {
+        flui_view::element::ElementKind::stateless(self)
+    }
+
+    fn key(&self) -> Option<&dyn ViewKey> {
+        Some(&self.key)
+    }
+}
+
+fn rect(left: f32, top: f32, right: f32, bottom: f32) -> Rect {
+    Rect::from_ltrb(px(left), px(top), px(right), px(bottom))
+}
+
+#[test]
+fn keyed_widget_bounds_match_its_laid_out_position() {
+    let key = GlobalKey::new();
+    let mut laid = lay_out(
+        Padding::only(20.0, 30.0, 0.0, 0.0).child(
+            Align::new(Alignment::TOP_LEFT).child(Keyed::new(&key, SizedBox::new(40.0, 60.0))),
+        ),
+        tight(200.0, 200.0),
+    );
+
+    let sized_box = laid
+        .render_object_for_key(&key)
+        .expect("the keyed widget's subtree has a render object");
+    assert_eq!(laid.find_by_render_type("RenderConstrainedBox"), sized_box);
+
+    let bounds = laid.global_paint_bounds(sized_box).expect("laid out");
+    assert_eq!(bounds, rect(20.0, 30.0, 60.0, 90.0));
+
+    // Same answer as summing the committed offsets, for a translate-only path.
+    let origin = laid.absolute_offset(sized_box);
+    let size = laid.size(sized_box);
+    assert_eq!(bounds.left(), origin.dx);
+    assert_eq!(bounds.top(), origin.dy);
+    assert_eq!(bounds.width(), size.width);
+    assert_eq!(bounds.height(), size.height);
+}
+
+#[test]
+fn keyed_widget_bounds_include_ancestor_transforms() {
+    // The Transform sits at (20, 30) and doubles everything below it about its
+    // top-left corner, so the 40×60 box paints as 80×120 from (20, 30).
+    let key = GlobalKey::new();
+    let mut laid = lay_out(
+        Padding::only(20.0, 30.0, 0.0, 0.0).child(
+            Transform::scale(2.0, 2.0)
+                .alignment(Alignment::TOP_LEFT)
+                .child(
+                    Align::new(Alignment::TOP_LEFT)
+                        .child(Keyed::new(&key, SizedBox::new(40.0, 60.0))),
+                ),
+        ),
+        tight(200.0, 200.0),
+    );
+
+    let sized_box = laid
+        .render_object_for_key(&key)
+        .expect("keyed render object");
+    assert_eq!(
+        laid.global_paint_bounds(sized_box),
+        Some(rect(20.0, 30.0, 100.0, 150.0))
+    );
+}
+
+#[test]
+fn unmounted_key_finds_no_render_object() {
+    let mounted = GlobalKey::new();
+    let stray = GlobalKey::<()>::new();
+    let mut laid = lay_out(
+        Keyed::new(&mounted, SizedBox::new(10.0, 10.0)),
+        tight(100.0, 100.0),
+    );
+
+    assert!(laid.render_object_for_key(&mounted).is_some());
+    assert_eq!(laid.render_object_for_key(&stray), None);
+}
@@ -15,6 +15,9 @@
 //! * `MediaQuery::maybe_of` MUST return the *provided* data, not a default —
 //!   the assertions fail if `maybe_of` returns any other value.
 //! * `maybe_of` MUST return `None` when no ancestor exists.
+//! * Replacing the root data MUST rebuild `of` dependents, and field
+//!   accessors (`size_of`, `platform_brightness_of`) only when their field
+//!   changed.
 //!
 //! ## False-pass prevention
 //!
@@ -35,8 +38,11 @@
 
 use crate::common::{lay_out, loose};
 use flui_geometry::px;
+use flui_rendering::view::ViewConfiguration;
 use flui_types::{Size, platform::Brightness};
+use flui_view::element::ElementKind;
 use flui_view::prelude::*;
+use flui_view::{View, ViewExt};
 use flui_widgets::{MediaQuery, MediaQueryData, SizedBox};
 
 // ============================================================================
@@ -127,6 +133,141 @@ fn media_query_maybe_of_returns_none_without_ancestor() {
     );
 }
 
+/// Data for a root view of `width × height` physical pixels at `dpr`.
+fn root_data(width: f32, height: f32, dpr: f32) -> MediaQueryData {
+    MediaQueryData::from_view_configuration(&ViewConfiguration::from_size(
+        Size::new(px(width), px(height)),
+        dpr,
+    ))
+}
+
+/// What each reader saw, one entry per build.
+#[derive(Clone, Default)]
+struct Reads {
+    of: Arc<Mutex<Vec<MediaQueryData>>>,
+    size: Arc<Mutex<Vec<Size>>>,
+    brightness: Arc<Mutex<Vec<Brightness>>>,
+}
+
+/// Reads the whole data with [`MediaQuery::of`], then builds
+/// [`SizeReader`].
+///
+/// The readers skip parent-driven rebuilds (`should_skip_rebuild`), so every
+/// recorded build after the first comes from the `MediaQuery` dependency.
+#[derive(Clone)]
+struct OfReader(Reads);
+
+impl StatelessView for OfReader {
+    fn build(&self, ctx: &dyn BuildContext) -> impl IntoView {
+        self.0.of.lock().unwrap().push(MediaQuery::of(ctx));
+        SizeReader(self.0.clone())
+    }
+}
+
+impl View for OfReader {
+    fn create_element(&self) -> ElementKind {
+        ElementKind::stateless(self)
+    }
+
+    fn should_skip_rebuild(&self, _prev: &Self) -> bool {
+        true
+    }
+}
+
+/// Reads only the size aspect, then builds [`BrightnessReader`].
+#[derive(Clone)]
+struct SizeReader(Reads);
+
+impl StatelessView for SizeReader {
+    fn build(&self, ctx: &dyn BuildContext) -> impl IntoView {
+        self.0.size.lock().unwrap().push(MediaQuery::size_of(ctx));
+        BrightnessReader(self.0.clone())
+    }
+}
+
+impl View for SizeReader {
+    fn create_element(&self) -> ElementKind {
+        ElementKind::stateless(self)
+    }
+
+    fn should_skip_rebuild(&self, _prev: &Self) -> bool {
+        true
+    }
+}
+
+/// Reads only the platform-brightness aspect.
+#[derive(Clone)]
+struct BrightnessReader(Reads);
+
+impl StatelessView for BrightnessReader {
+    fn build(&self, ctx: &dyn BuildContext) -> impl IntoView {
+        self.0
+            .brightness
+            .lock()
+            .unwrap()
+            .push(MediaQuery::platform_brightness_of(ctx));
+        SizedBox::shrink().boxed()
+    }
+}
+
+impl View for BrightnessReader {
+    fn create_element(&self) -> ElementKind {
+        ElementKind::stateless(self)
+    }
+
+    fn should_skip_rebuild(&self, _prev: &Self) -> bool {
+        true
+    }
+}
+
+/// New `MediaQuery` data rebuilds only the dependents of the aspects that
+/// changed: a size change skips brightness readers and vice versa. The
+/// window-driven root `MediaQuery` itself is covered by the app binding.
+#[test]
+fn media_query_aspect_dependents_rebuild_independently() {
+    let reads = Reads::default();
+    let initial = root_data(1600.0, 1200.0, 2.0);
+    assert_eq!(initial.size, Size::new(px(800.0), px(600.0)));
+    assert!((initial.device_pixel_ratio - 2.0).abs() < f32::EPSILON);
+
+    let mut laid = lay_out(
+        MediaQuery::new(initial.clone(), OfReader(reads.clone())),
+        loose(100.0),
+    );
+    assert_eq!(*reads.of.lock().unwrap(), vec![initial]);
+    assert_eq!(reads.brightness.lock().unwrap().len(), 1);
+
+    // The size changes: `of` and `size_of` readers see the new logical size.
+    let resized = root_data(2000.0, 1200.0, 2.0);
+    laid.pump_widget(MediaQuery::new(resized.clone(), OfReader(reads.clone())));
+    assert_eq!(reads.of.lock().unwrap().last(), Some(&resized));
+    assert_eq!(
+        *reads.size.lock().unwrap(),
+        vec![
+            Size::new(px(800.0), px(600.0)),
+            Size::new(px(1000.0), px(600.0))
+        ]
+    );
+    assert_eq!(
+        reads.brightness.lock().unwrap().len(),
+        1,
+        "a size change must not rebuild a brightness-only dependent"
+    );
+
+    // The brightness flips: the size reader stays put.
+    let dark = MediaQueryData {
+        platform_brightness: Brightness::Dark,
+        ..resized
+    };
+    laid.pump_widget(MediaQuery::new(dark, OfReader(reads.clone())));
+    assert_eq!(reads.of.lock().unwrap().len(), 3);
+    assert_eq!(reads.size.lock().unwrap().len(), 2);
+    assert_eq!(
+        *reads.brightness.lock().unwrap(),
+        vec![Brightness::Light, Brightness::Dark]
+    );
+}
+
 // ============================================================================
 // Value-type unit tests
 // ============================================================================
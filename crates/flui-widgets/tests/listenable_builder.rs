@@ -0,0 +1,127 @@
+//! `ListenableBuilder` / `AnimatedBuilder::from_animation`: a listenable
+//! notification rebuilds the builder subtree alone — the widget that created
+//! the builder is not rebuilt.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use crate::common::{lay_out, tight};
+use flui_animation::{Animation, AnimationController};
+use flui_foundation::{ChangeNotifier, Listenable};
+use flui_scheduler::Scheduler;
+use flui_widgets::prelude::{BuildContext, IntoView, StatelessView};
+use flui_widgets::{AnimatedBuilder, ListenableBuilder, SizedBox};
+
+/// A stateless parent that counts its own builds and returns a
+/// `ListenableBuilder` counting the builder's.
+#[derive(Clone, Debug, StatelessView)]
+struct CountingParent {
+    listenable: Arc<ChangeNotifier>,
+    parent_builds: Arc<AtomicUsize>,
+    builder_builds: Arc<AtomicUsize>,
+}
+
+impl StatelessView for CountingParent {
+    fn build(&self, _ctx: &dyn BuildContext) -> impl IntoView {
+        self.parent_builds.fetch_add(1, Ordering::SeqCst);
+        let builder_builds = Arc::clone(&self.builder_builds);
+        let listenable: Arc<dyn Listenable> = self.listenable.clone();
+        ListenableBuilder::new(listenable, move |_ctx, child| {
+            builder_builds.fetch_add(1, Ordering::SeqCst);
+            child.expect("the pre-built child is handed back on every build")
+        })
+        .child(SizedBox::new(20.0, 10.0))
+    }
+}
+
+#[test]
+fn notification_rebuilds_only_the_builder_subtree() {
+    let notifier = Arc::new(ChangeNotifier::new());
+    let parent_builds = Arc::new(AtomicUsize::new(0));
+    let builder_builds = Arc::new(AtomicUsize::new(0));
+
+    let mut laid = lay_out(
+        CountingParent {
+            listenable: Arc::clone(&notifier),
+            parent_builds: Arc::clone(&parent_builds),
+            builder_builds: Arc::clone(&builder_builds),
+        },
+        tight(20.0, 10.0),
+    );
+    assert!(
+        notifier.has_listeners(),
+        "mount subscribes to the listenable"
+    );
+    assert_eq!(parent_builds.load(Ordering::SeqCst), 1);
+    assert_eq!(builder_builds.load(Ordering::SeqCst), 1);
+
+    // `tick()` does not mark the root dirty, so only the listenable
+    // subscription can schedule this rebuild.
+    notifier.notify_listeners();
+    laid.tick();
+    assert_eq!(
+        builder_builds.load(Ordering::SeqCst),
+        2,
+        "the notification rebuilt the builder",
+    );
+    assert_eq!(
+        parent_builds.load(Ordering::SeqCst),
+        1,
+        "the parent that created the builder is not rebuilt",
+    );
+
+    notifier.notify_listeners();
+    laid.tick();
+    assert_eq!(builder_builds.load(Ordering::SeqCst), 3);
+    assert_eq!(parent_builds.load(Ordering::SeqCst), 1);
+
+    // A frame with no notification rebuilds nothing.
+    laid.tick();
+    assert_eq!(builder_builds.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn unmount_unsubscribes_from_the_listenable() {
+    let notifier = Arc::new(ChangeNotifier::new());
+    let listenable: Arc<dyn Listenable> = notifier.clone();
+    let mut laid = lay_out(
+        SizedBox::new(10.0, 10.0).child(ListenableBuilder::new(listenable, |_ctx, _child| {
+            SizedBox::new(10.0, 10.0)
+        })),
+        tight(10.0, 10.0),
+    );
+    assert!(notifier.has_listeners());
+
+    laid.pump_widget(SizedBox::new(10.0, 10.0).child(SizedBox::new(10.0, 10.0)));
+    assert!(
+        !notifier.has_listeners(),
+        "replacing the builder unsubscribes it",
+    );
+}
+
+#[test]
+fn animated_builder_from_animation_reads_the_live_value() {
+    let scheduler = Arc::new(Scheduler::new());
+    let controller = AnimationController::new(Duration::from_millis(300), scheduler);
+    controller.set_value(0.25);
+    let animation: Arc<dyn Animation<f32>> = Arc::new(controller.clone());
+
+    let seen = Arc::new(parking_lot::Mutex::new(Vec::new()));
+    let record = Arc::clone(&seen);
+    let mut laid = lay_out(
+        AnimatedBuilder::from_animation(animation, move |value: f32| {
+            record.lock().push(value);
+            SizedBox::new(100.0 * value, 10.0)
+        }),
+        tight(100.0, 10.0),
+    );
+
+    controller.set_value(0.5);
+    laid.tick();
+
+    let seen = seen.lock();
+    assert_eq!(seen.len(), 2, "one build on mount, one per tick: {seen:?}");
+    assert!((seen[0] - 0.25).abs() < 1e-4);
+    assert!((seen[1] - 0.5).abs() < 1e-4);
+}
@@ -44,6 +44,8 @@
 mod gesture_detector;
 #[path = "gesture_detector_advanced.rs"]
 mod gesture_detector_advanced;
+#[path = "global_key_bounds.rs"]
+mod global_key_bounds;
 #[path = "image.rs"]
 mod image;
 #[path = "implicit_animations.rs"]
@@ -62,6 +64,8 @@
 mod lazy_list;
 #[path = "list_body.rs"]
 mod list_body;
+#[path = "listenable_builder.rs"]
+mod listenable_builder;
 #[path = "listener.rs"]
 mod listener;
 #[path = "modifiers.rs"]
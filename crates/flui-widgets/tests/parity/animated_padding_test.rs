@@ -70,7 +70,7 @@ fn padding_of(laid: &common::LaidOut, id: flui_foundation::RenderId) -> EdgeInse
         .get_mut(id)
         .and_then(|node| node.downcast_render_object_mut::<RenderPadding>())
         .expect("render node should be a RenderPadding")
-        .padding()
+        .resolved_padding()
 }
 
 /// Probe that rebuilds `AnimatedPadding` with `padding`'s current value on
@@ -3,7 +3,7 @@
 //! inside other widgets.
 
 use crate::common::{lay_out, loose, tight};
-use flui_types::typography::{TextDirection, TextStyle};
+use flui_types::typography::{FontWeight, TextDirection, TextSpan, TextStyle};
 use flui_widgets::{Center, DefaultTextStyle, Padding, Text};
 
 #[test]
@@ -196,3 +196,70 @@ fn ambient_max_lines_caps_a_run_that_sets_none() {
          (ambient={ambient_height}, own={own_height}, unlimited={unlimited_height})"
     );
 }
+
+// ============================================================================
+// Text::rich
+// ============================================================================
+
+fn bold() -> TextStyle {
+    TextStyle {
+        font_weight: Some(FontWeight::BOLD),
+        ..Default::default()
+    }
+}
+
+/// A two-span `Text::rich` lays both spans out in one paragraph, so it is
+/// wider than either span on its own.
+#[test]
+fn a_two_span_rich_text_is_wider_than_either_span() {
+    let hello = lay_out(Text::rich(TextSpan::new("Hello ")), loose(1000.0));
+    let world = lay_out(Text::rich(TextSpan::styled("world", bold())), loose(1000.0));
+    let both = lay_out(
+        Text::rich(TextSpan::new("Hello ").with_child(TextSpan::styled("world", bold()))),
+        loose(1000.0),
+    );
+
+    let both_width = both.size(both.root()).width.get();
+    for (name, single) in [("hello", &hello), ("world", &world)] {
+        let width = single.size(single.root()).width.get();
+        assert!(
+            both_width > width,
+            "the two-span run ({both_width}) must be wider than {name} alone ({width})"
+        );
+    }
+}
+
+/// Spans of a rich run inherit the ambient style for fields they leave
+/// unset: a bold span under an ambient 40pt measures like a span that sets
+/// both itself.
+///
+/// Red-check: build the rich root without the effective style — the span
+/// falls back to the default size and the box is shorter.
+#[test]
+fn rich_spans_inherit_unspecified_fields_from_the_ambient_style() {
+    let explicit = lay_out(
+        Text::rich(TextSpan::styled("inherit", bold().with_font_size(40.0))),
+        loose(1000.0),
+    );
+    let inherited = lay_out(
+        DefaultTextStyle::new(
+            TextStyle::default().with_font_size(40.0),
+            Text::rich(TextSpan::styled("inherit", bold())),
+        ),
+        loose(1000.0),
+    );
+    let bare = lay_out(
+        Text::rich(TextSpan::styled("inherit", bold())),
+        loose(1000.0),
+    );
+
+    assert_eq!(
+        inherited.size(inherited.root()),
+        explicit.size(explicit.root()),
+        "the ambient 40pt must fill the span's unset font_size"
+    );
+    assert!(
+        inherited.size(inherited.root()).height.get() > bare.size(bare.root()).height.get(),
+        "without the ambient style the span must measure at the default size"
+    );
+}
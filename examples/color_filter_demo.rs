@@ -282,6 +282,8 @@ fn main() {
         decorated: true,
         min_size: None,
         max_size: None,
+        position: None,
+        fullscreen: false,
     };
 
     let window: Arc<dyn PlatformWindow> = Arc::from(
@@ -43,6 +43,8 @@ fn main() {
             decorated: true,
             min_size: None,
             max_size: None,
+            position: None,
+            fullscreen: false,
         };
 
         let window = platform
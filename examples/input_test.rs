@@ -59,6 +59,8 @@ fn main() {
         decorated: true,
         min_size: Some(Size::new(px(400.0), px(300.0))),
         max_size: None,
+        position: None,
+        fullscreen: false,
     };
 
     // Create window before running the event loop
@@ -144,6 +144,8 @@ fn main() {
         decorated: true,
         min_size: None,
         max_size: None,
+        position: None,
+        fullscreen: false,
     };
 
     // Create window before running the event loop (run() takes ownership)
@@ -54,6 +54,8 @@ fn main() -> anyhow::Result<()> {
         decorated: true,
         min_size: Some(Size::new(px(600.0), px(400.0))),
         max_size: None,
+        position: None,
+        fullscreen: false,
     };
 
     let window = platform.open_window(window_options)?;
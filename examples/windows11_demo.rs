@@ -49,6 +49,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         decorated: true,
         min_size: Some(Size::new(px(600.0), px(400.0))),
         max_size: None,
+        position: None,
+        fullscreen: false,
     };
 
     let _window = platform.open_window(options)?;
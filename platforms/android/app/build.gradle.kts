@@ -35,6 +35,22 @@ android {
         }
     }
 
+    // `flui build android` passes the ABIs it built as -Pflui.abis so Gradle
+    // emits one APK per ABI. Release signing is injected by the same command.
+    val fluiAbis = (project.findProperty("flui.abis") as String?)
+        ?.split(",")
+        ?.filter { it.isNotBlank() }
+    if (!fluiAbis.isNullOrEmpty()) {
+        splits {
+            abi {
+                isEnable = true
+                reset()
+                include(*fluiAbis.toTypedArray())
+                isUniversalApk = false
+            }
+        }
+    }
+
     // This is where Rust-generated native libraries will be placed
     sourceSets {
         getByName("main") {